@@ -1,6 +1,9 @@
+use std::collections::HashSet;
+use std::path::PathBuf;
+
 use gpui::{
     actions, div, prelude::*, px, size, App, Application, Bounds, Context, Entity, FocusHandle,
-    Subscription, Window, WindowBounds, WindowOptions,
+    SharedString, Subscription, Window, WindowBounds, WindowOptions,
 };
 use gpui_component::theme::{ActiveTheme, Theme, ThemeMode};
 use gpui_component::Root;
@@ -12,7 +15,7 @@ mod stories;
 
 use stories::Story;
 
-actions!(storybook, [Quit, CloseWindow]);
+actions!(storybook, [Quit, CloseWindow, ToggleDebugOverlay]);
 
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum ThemePreference {
@@ -25,38 +28,116 @@ pub enum ThemePreference {
 pub struct Storybook {
     selected_story: Story,
     theme_preference: ThemePreference,
+    theme_picker: Entity<PickerState<Vec<&'static str>>>,
     #[allow(dead_code)]
     appearance_subscription: Subscription,
     toggle_value: bool,
+    saving: bool,
+    airplane_mode: bool,
     tap_count: u32,
     show_content: bool,
     selected_fruit: Option<usize>,
     focus_handle: FocusHandle,
     text_input: Entity<InputState>,
     text_input_cleanable: Entity<InputState>,
+    device_name: Entity<InputState>,
+    email_input: Entity<InputState>,
+    contact_name_input: Entity<InputState>,
+    contact_email_input: Entity<InputState>,
+    contact_email_focus: FocusHandle,
+    contact_email_focused: bool,
+    email: String,
+    amount_input: Entity<InputState>,
     password_input: Entity<InputState>,
+    fruit_input: Entity<InputState>,
+    story_search: Entity<InputState>,
+    picked_fruit: Option<SharedString>,
+    tag_input: Entity<InputState>,
+    tags: Vec<SharedString>,
     text_editor_input: Entity<InputState>,
+    notes_input: Entity<InputState>,
+    greeting_name: String,
     slider_state: Entity<SliderState>,
     slider_value: f32,
-    stepper_input: Entity<InputState>,
-    stepper_value: i32,
+    price_range: (f32, f32),
+    eq_gains: [f32; 3],
+    text_scale_state: Entity<SliderState>,
+    text_scale: f32,
+    stepper_value: f64,
+    stepper_count: i32,
+    quantity: Option<f64>,
+    unit_price: Option<f64>,
+    shipping_speed: Option<usize>,
+    card_color: Color,
+    contacts_scroll: VirtualListScrollHandle,
+    chat_scroll: VirtualListScrollHandle,
+    numbers_scroll: VirtualListScrollHandle,
+    visible_numbers: Option<ScrollInfo>,
+    log_scroll: ScrollViewProxy,
+    onboarding_page: usize,
+    inbox_count: usize,
+    mailbox: Vec<&'static str>,
+    archived: usize,
+    groceries: Vec<&'static str>,
+    editing_groceries: bool,
+    project_tree: Entity<OutlineState>,
+    selected_files: HashSet<RowId>,
+    navigation_path: Entity<NavigationPath>,
+    presenting_sheet: bool,
+    presenting_cover: bool,
+    event_all_day: bool,
+    confirming_delete: bool,
+    showing_alert: bool,
+    delete_status: &'static str,
+    showing_filters: bool,
+    filter_unread: bool,
+    filter_flagged: bool,
+    menu_status: &'static str,
+    save_count: u32,
+    sort_by_date: bool,
+    show_hidden: bool,
+    settings_page: Option<&'static str>,
+    contacts_cleared: bool,
+    contacts_loading: bool,
+    todo_cards: Vec<&'static str>,
+    done_cards: Vec<&'static str>,
+    drop_column: Option<usize>,
+    dropped_image: Option<PathBuf>,
+    files_over_drop_zone: bool,
 }
 
 impl Storybook {
     fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
         let text_input = cx.new(|cx| InputState::new(window, cx).placeholder("Enter your name..."));
+        let device_name = cx.new(|cx| {
+            InputState::new(window, cx)
+                .placeholder("Device name")
+                .default_value("Jane's Mac")
+        });
         let text_input_cleanable =
             cx.new(|cx| InputState::new(window, cx).placeholder("Type here to see X button..."));
+        let email_input = cx.new(|cx| InputState::new(window, cx).placeholder("name@example.com"));
+        let contact_name_input = cx.new(|cx| InputState::new(window, cx).placeholder("Name"));
+        let contact_email_input = cx.new(|cx| InputState::new(window, cx).placeholder("Email"));
+        let amount_input = cx.new(|cx| InputState::new(window, cx).placeholder("0.00"));
         let password_input = cx.new(|cx| {
             InputState::new(window, cx)
                 .placeholder("Enter password...")
                 .masked(true)
         });
+        let fruit_input = cx.new(|cx| InputState::new(window, cx).placeholder("Search fruits..."));
+        let story_search = cx.new(|cx| InputState::new(window, cx).placeholder("Search"));
+        let tag_input = cx.new(|cx| InputState::new(window, cx).placeholder("Add a tag..."));
         let text_editor_input = cx.new(|cx| {
             InputState::new(window, cx)
-                .multi_line(true)
+                .code_editor("text")
                 .placeholder("Enter notes here...")
         });
+        let notes_input = cx.new(|cx| {
+            InputState::new(window, cx)
+                .multi_line(true)
+                .placeholder("What's happening?")
+        });
 
         let slider_state = cx.new(|_| {
             SliderState::new()
@@ -73,22 +154,47 @@ impl Storybook {
         })
         .detach();
 
-        let stepper_input = cx.new(|cx| {
-            InputState::new(window, cx)
-                .default_value("5")
-                .placeholder("Qty")
+        let text_scale_state = cx.new(|_| {
+            SliderState::new()
+                .min(0.8)
+                .max(1.6)
+                .default_value(1.0)
+                .step(0.1)
         });
 
-        cx.subscribe(&stepper_input, |this, _, event: &StepperEvent, cx| {
-            let StepperEvent::Step(action) = event;
-            match action {
-                StepAction::Increment => this.stepper_value += 1,
-                StepAction::Decrement => this.stepper_value -= 1,
-            }
+        cx.subscribe(&text_scale_state, |this, _, event: &SliderEvent, cx| {
+            let SliderEvent::Change(value) = event;
+            this.text_scale = value.start();
             cx.notify();
         })
         .detach();
 
+        let theme_picker = cx.new(|cx| {
+            PickerState::new(
+                ThemePreference::ALL.map(ThemePreference::label).to_vec(),
+                Some(IndexPath::default()),
+                window,
+                cx,
+            )
+        });
+
+        cx.subscribe_in(
+            &theme_picker,
+            window,
+            |this, _, event: &PickerEvent<Vec<&'static str>>, window, cx| {
+                let PickerEvent::Confirm(Some(label)) = event else {
+                    return;
+                };
+                if let Some(preference) = ThemePreference::ALL
+                    .into_iter()
+                    .find(|preference| preference.label() == *label)
+                {
+                    this.set_theme_preference(preference, window, cx);
+                }
+            },
+        )
+        .detach();
+
         let entity = cx.entity().clone();
         let appearance_subscription = window.observe_window_appearance(move |window, cx| {
             entity.update(cx, |this, cx| {
@@ -102,25 +208,114 @@ impl Storybook {
         Self {
             selected_story: Story::default(),
             theme_preference: ThemePreference::default(),
+            theme_picker,
             appearance_subscription,
             toggle_value: false,
+            saving: false,
+            airplane_mode: false,
             tap_count: 0,
             show_content: true,
             selected_fruit: Some(0),
             focus_handle: cx.focus_handle(),
             text_input,
             text_input_cleanable,
+            device_name,
+            email_input,
+            contact_name_input,
+            contact_email_input,
+            contact_email_focus: cx.focus_handle(),
+            contact_email_focused: false,
+            email: String::new(),
+            amount_input,
             password_input,
+            fruit_input,
+            story_search,
+            picked_fruit: None,
+            tag_input,
+            tags: vec!["rust".into(), "gpui".into()],
             text_editor_input,
+            notes_input,
+            greeting_name: String::new(),
             slider_state,
             slider_value: 50.0_f32,
-            stepper_input,
-            stepper_value: 5,
+            price_range: (100.0, 400.0),
+            eq_gains: [0.0; 3],
+            text_scale_state,
+            text_scale: 1.0,
+            stepper_value: 5.0,
+            stepper_count: 5,
+            quantity: Some(3.0),
+            unit_price: Some(1249.5),
+            shipping_speed: Some(0),
+            card_color: Color::blue(),
+            contacts_scroll: VirtualListScrollHandle::new(),
+            chat_scroll: VirtualListScrollHandle::new(),
+            numbers_scroll: VirtualListScrollHandle::new(),
+            visible_numbers: None,
+            log_scroll: ScrollViewProxy::new(),
+            onboarding_page: 0,
+            inbox_count: 3,
+            mailbox: vec![
+                "Lunch on Friday?",
+                "Your order has shipped",
+                "Quarterly report draft",
+                "Re: Design review notes",
+                "Weekend hiking plans",
+            ],
+            archived: 0,
+            groceries: vec![
+                "Apples", "Bread", "Coffee", "Eggs", "Milk", "Rice", "Spinach",
+            ],
+            editing_groceries: false,
+            project_tree: cx.new(|_| OutlineState::new()),
+            selected_files: HashSet::new(),
+            navigation_path: cx.new(|_| NavigationPath::new()),
+            presenting_sheet: false,
+            presenting_cover: false,
+            event_all_day: false,
+            confirming_delete: false,
+            showing_alert: false,
+            delete_status: "No choice yet",
+            showing_filters: false,
+            filter_unread: false,
+            filter_flagged: false,
+            menu_status: "Edited",
+            save_count: 0,
+            sort_by_date: false,
+            show_hidden: false,
+            settings_page: None,
+            contacts_cleared: false,
+            contacts_loading: false,
+            todo_cards: vec![
+                "Write release notes",
+                "Fix login bug",
+                "Review pull request",
+            ],
+            done_cards: vec!["Set up CI"],
+            drop_column: None,
+            dropped_image: None,
+            files_over_drop_zone: false,
         }
     }
 
     pub fn cycle_theme(&mut self, window: &mut Window, cx: &mut Context<Self>) {
-        self.theme_preference = self.theme_preference.next();
+        let preference = self.theme_preference.next();
+        self.set_theme_preference(preference, window, cx);
+
+        // Keep the theme picker in the More Inputs story in sync
+        let row = ThemePreference::ALL.iter().position(|p| *p == preference);
+        self.theme_picker.update(cx, |picker, cx| {
+            picker.set_selected_index(row.map(|row| IndexPath::default().row(row)), window, cx);
+        });
+    }
+
+    fn set_theme_preference(
+        &mut self,
+        preference: ThemePreference,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.theme_preference = preference;
         match self.theme_preference {
             ThemePreference::System => {
                 Theme::sync_system_appearance(Some(window), cx);
@@ -135,60 +330,63 @@ impl Storybook {
         cx.notify();
     }
 
-    fn render_content(&self, cx: &mut Context<Self>) -> impl IntoElement {
+    fn render_content(&self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         ScrollView::new("content-scroll")
             .axes(ScrollAxes::vertical())
             .child(
                 VStack::new()
                     .spacing(16.0)
                     .alignment(HorizontalAlignment::Leading)
-                    .child(
+                    .child(self.render_story(window, cx))
+                    .padding(16.0)
+                    .frame(Frame::fill_width().alignment(Alignment::top_leading())),
+            )
+            .toolbar(
+                Toolbar::new()
+                    .leading(
                         Text::new(format!("{:?}", self.selected_story))
-                            .font(Font::title())
+                            .font(Font::headline())
                             .font_weight(FontWeight::Bold),
                     )
-                    .child(self.render_story(cx))
-                    .padding(16.0)
-                    .frame(Frame::fill_width().alignment(Alignment::top_leading())),
+                    .trailing(self.render_theme_toggle(cx)),
             )
     }
 
-    fn render_story(&self, cx: &mut Context<Self>) -> gpui::AnyElement {
+    fn render_story(&self, window: &mut Window, cx: &mut Context<Self>) -> gpui::AnyElement {
         use stories::*;
 
         match self.selected_story {
             Story::VStack => render_vstack_story().into_any_element(),
             Story::HStack => render_hstack_story().into_any_element(),
             Story::ZStack => render_zstack_story().into_any_element(),
+            Story::Flow => render_flow_story().into_any_element(),
             Story::Spacer => render_spacer_story().into_any_element(),
-            Story::Text => render_text_story().into_any_element(),
-            Story::Button => render_button_story().into_any_element(),
+            Story::Padding => render_padding_story().into_any_element(),
+            Story::Text => {
+                render_text_story(&self.text_scale_state, self.text_scale).into_any_element()
+            }
+            Story::Button => render_button_story(self, cx).into_any_element(),
             Story::Modifiers => render_modifiers_story().into_any_element(),
+            Story::Colors => render_colors_story().into_any_element(),
             Story::Toggle => render_toggle_story(self, cx).into_any_element(),
             Story::TapGesture => render_tap_gesture_story(self, cx).into_any_element(),
-            Story::TextFields => render_textfields_story(
-                &self.text_input,
-                &self.text_input_cleanable,
-                &self.password_input,
-            )
-            .into_any_element(),
-            Story::Sliders => {
-                render_sliders_story(&self.slider_state, self.slider_value).into_any_element()
-            }
-            Story::MoreInputs => render_more_inputs_story(
-                &self.text_editor_input,
-                &self.stepper_input,
-                self.stepper_value,
-            )
-            .into_any_element(),
+            Story::TextFields => render_textfields_story(self, cx).into_any_element(),
+            Story::Sliders => render_sliders_story(self, cx).into_any_element(),
+            Story::MoreInputs => render_more_inputs_story(self, cx).into_any_element(),
+            Story::ColorPicker => render_color_picker_story(self, cx).into_any_element(),
             Story::DisplayComponents => render_display_components_story().into_any_element(),
-            Story::ScrollView => render_scrollview_story().into_any_element(),
-            Story::List => render_list_story().into_any_element(),
+            Story::Markdown => render_markdown_story().into_any_element(),
+            Story::ScrollView => render_scrollview_story(self, cx).into_any_element(),
+            Story::LazyVStack => render_lazy_vstack_story(self, window, cx).into_any_element(),
+            Story::List => render_list_story(self, cx).into_any_element(),
             Story::ListConfig => render_list_config_story().into_any_element(),
+            Story::NavigationStack => render_navigation_stack_story(self, cx).into_any_element(),
             Story::ForEach => render_foreach_story().into_any_element(),
             Story::Conditional => render_conditional_story(self, cx).into_any_element(),
+            Story::DragAndDrop => render_drag_drop_story(self, cx).into_any_element(),
+            Story::Windows => render_windows_story().into_any_element(),
             Story::Grid => render_grid_story().into_any_element(),
-            Story::LazyVGrid => render_lazy_vgrid_story().into_any_element(),
+            Story::LazyVGrid => render_lazy_vgrid_story(self, window, cx).into_any_element(),
             Story::LazyHGrid => render_lazy_hgrid_story().into_any_element(),
             Story::BothAxesScroll => {
                 render_both_axes_scroll_story(cx.theme().secondary).into_any_element()
@@ -198,7 +396,7 @@ impl Storybook {
 }
 
 impl Render for Storybook {
-    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+    fn render(&mut self, window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
         let theme = cx.theme();
 
         div()
@@ -214,8 +412,13 @@ impl Render for Storybook {
             .on_action(|_: &CloseWindow, window, _cx| {
                 window.remove_window();
             })
+            .on_action(|_: &ToggleDebugOverlay, _window, cx| {
+                allui::debug::toggle_overlay(cx);
+            })
             .child(self.render_sidebar(cx))
-            .child(self.render_content(cx))
+            .child(self.render_content(window, cx))
+            .child(ToastCenter::new())
+            .child(DebugOverlay::new())
     }
 }
 
@@ -224,6 +427,7 @@ fn main() {
         .with_assets(gpui_component_assets::Assets)
         .run(|cx: &mut App| {
             gpui_component::init(cx);
+            stories::register_palette(cx);
 
             let bounds = Bounds::centered(None, size(px(1000.0), px(700.0)), cx);
 
@@ -236,6 +440,7 @@ fn main() {
                     cx.bind_keys([
                         gpui::KeyBinding::new("cmd-q", Quit, None),
                         gpui::KeyBinding::new("cmd-w", CloseWindow, None),
+                        gpui::KeyBinding::new("cmd-d", ToggleDebugOverlay, None),
                     ]);
 
                     let storybook = cx.new(|cx| {
@@ -1,9 +1,12 @@
+use std::collections::HashSet;
+
 use allui::prelude::*;
-use gpui::{div, prelude::*, px, Context, SharedString};
-use gpui_component::scroll::ScrollableElement;
+use gpui::{div, prelude::*, px, Context};
 use gpui_component::theme::ActiveTheme;
 
-use crate::stories::{component_stories, container_stories, grid_stories, layout_stories, Story};
+use crate::stories::{
+    component_stories, container_stories, grid_stories, layout_stories, Story, StoryInfo,
+};
 use crate::{Storybook, ThemePreference};
 
 impl Storybook {
@@ -11,7 +14,6 @@ impl Storybook {
         let theme = cx.theme();
         let sidebar_bg = theme.sidebar;
         let border_color = theme.sidebar_border;
-        let muted_fg = theme.muted_foreground;
 
         div()
             .flex()
@@ -25,70 +27,60 @@ impl Storybook {
             .child(
                 div()
                     .p_2()
+                    .mb_2()
+                    .border_b_1()
+                    .border_color(border_color)
                     .child(
                         div()
                             .text_lg()
                             .font_weight(gpui::FontWeight::BOLD)
                             .child("Allui Storybook"),
-                    )
-                    .child(
-                        div()
-                            .flex()
-                            .flex_row()
-                            .items_center()
-                            .justify_between()
-                            .pt_2()
-                            .pb_2()
-                            .mb_2()
-                            .border_b_1()
-                            .border_color(border_color)
-                            .child(
-                                Text::new("Theme:")
-                                    .font(Font::caption())
-                                    .foreground_color(Color::secondary_label()),
-                            )
-                            .child(self.render_theme_toggle(cx)),
-                    ),
-            )
-            .child(
-                div()
-                    .id("sidebar-scroll")
-                    .flex_1()
-                    .min_h_0()
-                    .overflow_y_scrollbar()
-                    .p_2()
-                    .pt_0()
-                    .gap_1()
-                    .flex()
-                    .flex_col()
-                    .child(self.render_section_header("Layout", muted_fg))
-                    .children(
-                        layout_stories()
-                            .iter()
-                            .map(|info| self.render_sidebar_item(info.name, info.story, cx)),
-                    )
-                    .child(self.render_section_header("Components", muted_fg))
-                    .children(
-                        component_stories()
-                            .iter()
-                            .map(|info| self.render_sidebar_item(info.name, info.story, cx)),
-                    )
-                    .child(self.render_section_header("Containers", muted_fg))
-                    .children(
-                        container_stories()
-                            .iter()
-                            .map(|info| self.render_sidebar_item(info.name, info.story, cx)),
-                    )
-                    .child(self.render_section_header("Grids", muted_fg))
-                    .children(
-                        grid_stories()
-                            .iter()
-                            .map(|info| self.render_sidebar_item(info.name, info.story, cx)),
                     ),
             )
+            .child(div().flex_1().min_h_0().child(self.render_story_list(cx)))
     }
 
-    fn render_theme_toggle(&self, cx: &mut Context<Self>) -> impl IntoElement {
+    fn render_story_list(&self, cx: &mut Context<Self>) -> impl IntoElement {
+        let sections = [
+            ("Layout", layout_stories()),
+            ("Components", component_stories()),
+            ("Containers", container_stories()),
+            ("Grids", grid_stories()),
+        ];
+        let selection = HashSet::from([RowId::from(story_name(self.selected_story))]);
+
+        List::new("sidebar-stories")
+            .list_style(ListStyle::sidebar())
+            .list_section_spacing(8.0)
+            .list_row_insets(EdgeInsets::init(0.0, 12.0, 0.0, 12.0))
+            .min_row_height(28.0)
+            .searchable(&self.story_search, |query, ix| {
+                let query = query.to_lowercase();
+                all_stories()
+                    .nth(ix)
+                    .is_some_and(|info| info.name.to_lowercase().contains(&query))
+            })
+            .selectable(SelectionMode::Single)
+            .selection(&selection)
+            .on_selection_change(cx.listener(|this, selection: &HashSet<RowId>, _, cx| {
+                // Keep the current story when its row is deselected
+                let story = all_stories().find(|info| selection.contains(&RowId::from(info.name)));
+                if let Some(info) = story {
+                    this.selected_story = info.story;
+                    cx.notify();
+                }
+            }))
+            .sections(sections.into_iter().map(|(title, stories)| {
+                stories
+                    .iter()
+                    .fold(Section::new().header(title), |section, info| {
+                        section.row_id(info.name, Text::new(info.name))
+                    })
+            }))
+    }
+
+    /// The button cycling the theme, in the content's toolbar.
+    pub fn render_theme_toggle(&self, cx: &mut Context<Self>) -> impl IntoElement {
         let entity = cx.entity().clone();
         let secondary_bg = cx.theme().secondary;
         let secondary_hover = cx.theme().secondary_hover;
@@ -101,51 +93,40 @@ impl Storybook {
             .rounded_md()
             .bg(secondary_bg)
             .hover(|d| d.bg(secondary_hover))
-            .child(Text::new(self.theme_preference.label()).font(Font::caption()))
+            .child(
+                Text::new(format!("Theme: {}", self.theme_preference.label()))
+                    .font(Font::caption()),
+            )
             .on_click(move |_, window, cx| {
                 entity.update(cx, |this, cx| {
                     this.cycle_theme(window, cx);
                 });
             })
     }
+}
 
-    fn render_section_header(&self, title: &'static str, muted_fg: gpui::Hsla) -> impl IntoElement {
-        div()
-            .text_xs()
-            .text_color(muted_fg)
-            .mt_3()
-            .mb_1()
-            .px_2()
-            .child(title.to_uppercase())
-    }
-
-    fn render_sidebar_item(
-        &self,
-        name: &'static str,
-        story: Story,
-        cx: &mut Context<Self>,
-    ) -> impl IntoElement {
-        let is_selected = self.selected_story == story;
-        let theme = cx.theme();
-        let selection_bg = theme.selection;
-        let hover_bg = theme.list_hover;
+/// Every story, in sidebar order.
+fn all_stories() -> impl Iterator<Item = &'static StoryInfo> {
+    [
+        layout_stories(),
+        component_stories(),
+        container_stories(),
+        grid_stories(),
+    ]
+    .into_iter()
+    .flatten()
+}
 
-        div()
-            .id(SharedString::from(name))
-            .cursor_pointer()
-            .px_3()
-            .py_1()
-            .rounded_md()
-            .when(is_selected, |d| d.bg(selection_bg))
-            .hover(|d| d.bg(hover_bg))
-            .on_click(cx.listener(move |this, _, _, _cx| {
-                this.selected_story = story;
-            }))
-            .child(name)
-    }
+/// The sidebar name of `story`.
+fn story_name(story: Story) -> &'static str {
+    all_stories()
+        .find(|info| info.story == story)
+        .map_or("", |info| info.name)
 }
 
 impl ThemePreference {
+    pub const ALL: [Self; 3] = [Self::System, Self::Light, Self::Dark];
+
     pub fn next(self) -> Self {
         match self {
             Self::System => Self::Light,
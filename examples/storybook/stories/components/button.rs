@@ -1,17 +1,37 @@
 //! Button story.
 //!
-//! Demonstrates button styles: BorderedProminent, Bordered, Plain.
+//! Demonstrates button styles: BorderedProminent, Bordered, Plain, roles,
+//! custom label views, control sizes, and theme-backed colors that restyle
+//! when the storybook theme is cycled. Enabled buttons darken on hover and
+//! while pressed. A "Save" button shows the loading state, and a custom
+//! `ButtonStyling` draws a gradient pill, a toolbar's "⋯" button opens a
+//! menu, and the toast buttons show each toast style. A second Save button
+//! is bound to Cmd-S, and the menu's Duplicate item to Cmd-D.
 //!
 //! ```rust,ignore
 //! Button::new("Click me", || println!("Clicked!"))
 //!     .button_style(ButtonStyle::BorderedProminent)
 //!     .disabled(false)
+//!
+//! Menu::new(Image::system_name("ellipsis"))
+//!     .item(MenuItem::new("Rename", rename))
+//!     .item(MenuItem::destructive("Delete", delete))
+//!
+//! Button::new("Save", save).keyboard_shortcut("cmd-s")
+//!
+//! ToastCenter::show(cx, Toast::new("Saved").style(ToastStyle::Success))
 //! ```
 
+use std::time::Duration;
+
 use allui::prelude::*;
-use gpui::prelude::*;
+use gpui::{div, prelude::*, px, AnyElement, App, Context, Window};
+
+use crate::Storybook;
+
+pub fn render_button_story(storybook: &Storybook, cx: &mut Context<Storybook>) -> impl IntoElement {
+    let saving = storybook.saving;
 
-pub fn render_button_story() -> impl IntoElement {
     VStack::new()
         .spacing(12.0)
         .alignment(HorizontalAlignment::Leading)
@@ -38,4 +58,266 @@ pub fn render_button_story() -> impl IntoElement {
                 .button_style(ButtonStyle::BorderedProminent)
                 .disabled(true),
         )
+        .child(Text::new("Roles:"))
+        .child(
+            HStack::new()
+                .spacing(12.0)
+                .child(
+                    Button::new("Delete", || println!("Delete clicked!"))
+                        .button_style(ButtonStyle::BorderedProminent)
+                        .role(ButtonRole::Destructive),
+                )
+                .child(
+                    Button::new("Remove", || println!("Remove clicked!"))
+                        .button_style(ButtonStyle::Bordered)
+                        .role(ButtonRole::Destructive),
+                )
+                .child(
+                    Button::new("Delete (disabled)", || {})
+                        .button_style(ButtonStyle::BorderedProminent)
+                        .role(ButtonRole::Destructive)
+                        .disabled(true),
+                )
+                .child(
+                    Button::new("Cancel", || println!("Cancel clicked!"))
+                        .button_style(ButtonStyle::Bordered)
+                        .role(ButtonRole::Cancel),
+                ),
+        )
+        .child(Text::new("Custom label:"))
+        .child(
+            Button::with_label(Label::new("plus", "Add Item"), || {
+                println!("Add Item clicked!");
+            })
+            .button_style(ButtonStyle::BorderedProminent)
+            // A label view isn't read as the button's title
+            .accessibility_label("Add Item"),
+        )
+        .child(Text::new("Loading:"))
+        .child(
+            Button::with_id("save")
+                .label("Save")
+                .button_style(ButtonStyle::BorderedProminent)
+                .loading(saving)
+                .on_click_with(cx.listener(|this: &mut Storybook, _, _window, cx| {
+                    // Simulate a save that takes two seconds
+                    this.saving = true;
+                    cx.notify();
+                    cx.spawn(async move |this, cx| {
+                        cx.background_executor().timer(Duration::from_secs(2)).await;
+                        this.update(cx, |this, cx| {
+                            this.saving = false;
+                            cx.notify();
+                        })
+                        .ok();
+                    })
+                    .detach();
+                })),
+        )
+        .child(Text::new("Keyboard shortcut (press Cmd-S):"))
+        .child(
+            HStack::new()
+                .spacing(12.0)
+                .child(
+                    Button::with_id("save-shortcut")
+                        .label("Save")
+                        .button_style(ButtonStyle::Bordered)
+                        .on_click_with(cx.listener(|this: &mut Storybook, _, _, cx| {
+                            this.save_count += 1;
+                            cx.notify();
+                        }))
+                        .keyboard_shortcut("cmd-s"),
+                )
+                .child(
+                    Text::new(format!("Saved {} times", storybook.save_count))
+                        .foreground_color(Color::secondary_label()),
+                ),
+        )
+        .child(Text::new("Custom styles:"))
+        .child(
+            HStack::new()
+                .spacing(12.0)
+                .child(
+                    Button::new("Card", || println!("Card clicked!"))
+                        .button_style(ButtonStyle::Card),
+                )
+                .child(
+                    Button::new("Gradient Pill", || println!("Gradient Pill clicked!"))
+                        .style_with(GradientPillStyle),
+                ),
+        )
+        .child(Text::new("Control sizes:"))
+        .child(
+            HStack::new()
+                .spacing(12.0)
+                .child(
+                    Button::new("Mini", || {})
+                        .button_style(ButtonStyle::Bordered)
+                        .control_size(ControlSize::Mini),
+                )
+                .child(
+                    Button::new("Small", || {})
+                        .button_style(ButtonStyle::Bordered)
+                        .control_size(ControlSize::Small),
+                )
+                .child(
+                    Button::new("Regular", || {})
+                        .button_style(ButtonStyle::Bordered)
+                        .control_size(ControlSize::Regular),
+                )
+                .child(
+                    Button::new("Large", || {})
+                        .button_style(ButtonStyle::Bordered)
+                        .control_size(ControlSize::Large),
+                ),
+        )
+        .child(Text::new(
+            "Theme colors (cycle the theme from the sidebar):",
+        ))
+        .child(
+            HStack::new()
+                .spacing(12.0)
+                .child(
+                    Button::new("Accent", || println!("Accent clicked!"))
+                        .button_style(ButtonStyle::BorderedProminent),
+                )
+                .child(Link::new("Accent link", || {
+                    println!("Accent link clicked!")
+                }))
+                .child(theme_swatch("destructive", Color::destructive()))
+                .child(theme_swatch("muted", Color::muted()))
+                .child(theme_swatch("selection", Color::selection_background())),
+        )
+        .child(Text::new("Menu:"))
+        .child(document_toolbar(storybook, cx))
+        .child(Text::new("Toasts:"))
+        .child(
+            HStack::new()
+                .spacing(12.0)
+                .child(toast_button(
+                    "Info",
+                    Toast::new("Sync starts in a minute").style(ToastStyle::Info),
+                ))
+                .child(toast_button(
+                    "Success",
+                    Toast::new("Message deleted")
+                        .style(ToastStyle::Success)
+                        .duration_secs(5.0)
+                        .action("Undo", |_, cx| {
+                            ToastCenter::show(cx, Toast::new("Message restored"))
+                        }),
+                ))
+                .child(toast_button(
+                    "Warning",
+                    Toast::new("Storage almost full").style(ToastStyle::Warning),
+                ))
+                .child(toast_button(
+                    "Error",
+                    Toast::new("Couldn't save the document").style(ToastStyle::Error),
+                )),
+        )
+}
+
+/// A button that shows `toast`.
+fn toast_button(label: &'static str, toast: Toast) -> impl IntoElement {
+    Button::with_id(label)
+        .label(label)
+        .button_style(ButtonStyle::Bordered)
+        .on_click_with(move |_, _, cx| ToastCenter::show(cx, toast.clone()))
+}
+
+/// A toolbar with a "⋯" menu of document actions.
+fn document_toolbar(storybook: &Storybook, cx: &mut Context<Storybook>) -> impl IntoElement {
+    let sort_by_date = storybook.sort_by_date;
+    let show_hidden = storybook.show_hidden;
+    let menu = Menu::new(Image::system_name("ellipsis"))
+        .item(MenuItem::new(
+            "Rename",
+            menu_action(cx, |this| this.menu_status = "Renamed"),
+        ))
+        .item(
+            MenuItem::new(
+                "Duplicate",
+                menu_action(cx, |this| this.menu_status = "Duplicated"),
+            )
+            .keyboard_shortcut("cmd-d"),
+        )
+        .item(MenuItem::submenu(
+            "Sort By",
+            [
+                MenuItem::new("Name", menu_action(cx, |this| this.sort_by_date = false))
+                    .checked(!sort_by_date),
+                MenuItem::new("Date", menu_action(cx, |this| this.sort_by_date = true))
+                    .checked(sort_by_date),
+            ],
+        ))
+        .item(
+            MenuItem::new(
+                "Show Hidden Files",
+                menu_action(cx, |this| this.show_hidden = !this.show_hidden),
+            )
+            .checked(show_hidden),
+        )
+        .item(MenuItem::separator())
+        .item(MenuItem::destructive(
+            "Delete",
+            menu_action(cx, |this| this.menu_status = "Deleted"),
+        ));
+
+    HStack::new()
+        .spacing(8.0)
+        .child(Text::new("Untitled Document").font(Font::headline()))
+        .child(Text::new(storybook.menu_status).foreground_color(Color::secondary_label()))
+        .child(Spacer::new())
+        .child(menu)
+        .padding(8.0)
+        .frame_width(360.0)
+        .background(Color::tertiary_system_background())
+        .corner_radius(8.0)
+}
+
+/// A menu item handler that updates the storybook.
+fn menu_action(
+    cx: &mut Context<Storybook>,
+    update: fn(&mut Storybook),
+) -> impl Fn(&mut Window, &mut App) + 'static {
+    let storybook = cx.entity().downgrade();
+    move |_, cx| {
+        storybook
+            .update(cx, |this, cx| {
+                update(this);
+                cx.notify();
+            })
+            .ok();
+    }
+}
+
+fn theme_swatch(name: &'static str, color: Color) -> impl IntoElement {
+    Text::new(name)
+        .padding_edges(4.0, 8.0, 4.0, 8.0)
+        .background(color)
+        .corner_radius(6.0)
+}
+
+/// A user-defined button style: a gradient capsule that dims while pressed.
+struct GradientPillStyle;
+
+impl ButtonStyling for GradientPillStyle {
+    fn make_body(&self, configuration: ButtonStyleConfiguration, cx: &App) -> AnyElement {
+        let gradient = LinearGradient::new(Color::blue(), Color::purple())
+            .start_point(UnitPoint::leading())
+            .end_point(UnitPoint::trailing());
+
+        div()
+            .px(px(20.0))
+            .py(px(8.0))
+            .rounded_full()
+            .bg(gradient.resolve_background(cx))
+            .text_color(Color::white().resolve_in(cx))
+            .when(configuration.is_hovered, |this| this.shadow_md())
+            .when(configuration.is_pressed, |this| this.opacity(0.75))
+            .when(configuration.is_disabled, |this| this.opacity(0.5))
+            .child(configuration.label)
+            .into_any_element()
+    }
 }
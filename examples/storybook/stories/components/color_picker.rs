@@ -0,0 +1,92 @@
+//! ColorPicker story.
+//!
+//! Demonstrates picking a color from a popover and applying it live.
+//!
+//! ```rust,ignore
+//! ColorPicker::new("card-color", card_color,
+//!     cx.listener(|this, color: &Color, _, cx| {
+//!         this.card_color = color.clone();
+//!         cx.notify();
+//!     })
+//! )
+//! ```
+
+use allui::prelude::*;
+use gpui::{prelude::*, Context};
+
+use crate::Storybook;
+
+pub fn render_color_picker_story(
+    storybook: &Storybook,
+    cx: &mut Context<Storybook>,
+) -> impl IntoElement {
+    let card_color = &storybook.card_color;
+
+    VStack::new()
+        .spacing(16.0)
+        .alignment(HorizontalAlignment::Leading)
+        .child(Text::new("ColorPicker - Swatch with a picking popover:"))
+        .child(
+            VStack::new()
+                .spacing(12.0)
+                .alignment(HorizontalAlignment::Leading)
+                .child(
+                    HStack::new()
+                        .spacing(12.0)
+                        .child(ColorPicker::new(
+                            "card-color",
+                            card_color,
+                            cx.listener(|this: &mut Storybook, color: &Color, _, cx| {
+                                this.card_color = color.clone();
+                                cx.notify();
+                            }),
+                        ))
+                        .child(Text::new("Card background, with opacity")),
+                )
+                .child(
+                    HStack::new()
+                        .spacing(12.0)
+                        .child(
+                            ColorPicker::new(
+                                "card-color-opaque",
+                                card_color,
+                                cx.listener(|this: &mut Storybook, color: &Color, _, cx| {
+                                    this.card_color = color.clone();
+                                    cx.notify();
+                                }),
+                            )
+                            .supports_opacity(false)
+                            .presets([
+                                Color::red(),
+                                Color::green(),
+                                Color::blue(),
+                            ]),
+                        )
+                        .child(Text::new("Opaque, with custom presets")),
+                )
+                .child(
+                    HStack::new()
+                        .spacing(12.0)
+                        .child(
+                            ColorPicker::new("disabled-color", Color::gray(), |_, _, _| {})
+                                .disabled(true),
+                        )
+                        .child(Text::new("Disabled").foreground_color(Color::gray())),
+                )
+                .padding(16.0)
+                .background(Color::tertiary_system_background())
+                .corner_radius(8.0),
+        )
+        .child(Text::new("Live preview:"))
+        .child(
+            VStack::new()
+                .spacing(4.0)
+                .alignment(HorizontalAlignment::Leading)
+                .child(Text::new("Card").font(Font::headline()))
+                .child(Text::new("The background follows the picker.").font(Font::caption()))
+                .padding(16.0)
+                .frame_width(300.0)
+                .background(card_color.clone())
+                .corner_radius(12.0),
+        )
+}
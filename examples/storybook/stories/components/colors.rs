@@ -0,0 +1,140 @@
+//! Colors story.
+//!
+//! Demonstrates app-defined adaptive colors, named palette tokens and
+//! per-view color scheme overrides.
+//!
+//! ```rust,ignore
+//! ColorPalette::set(cx, "brandSurface", Color::hex(0xF2F0FF), Color::hex(0x1E1B2E));
+//!
+//! Text::new("Card").padding(12.0).background(Color::named("brandSurface"))
+//! Text::new("Inline").background(Color::adaptive(Color::yellow(), Color::indigo()))
+//! preview_card().color_scheme(ColorScheme::Dark)
+//! ```
+
+use allui::prelude::*;
+use gpui::{prelude::*, App};
+
+/// Named tokens registered at startup by [`register_palette`].
+const TOKENS: &[&str] = &["brandSurface", "brandAccent", "brandOnSurface"];
+
+/// Register the storybook's custom palette tokens.
+pub fn register_palette(cx: &mut App) {
+    ColorPalette::set(
+        cx,
+        "brandSurface",
+        Color::hex(0xF2F0FF),
+        Color::hex(0x1E1B2E),
+    );
+    ColorPalette::set(
+        cx,
+        "brandAccent",
+        Color::hex(0x6B4EFF),
+        Color::hex(0xA996FF),
+    );
+    ColorPalette::set(
+        cx,
+        "brandOnSurface",
+        Color::hex(0x1E1B2E),
+        Color::hex(0xF2F0FF),
+    );
+}
+
+fn swatch(color: Color) -> impl IntoElement {
+    VStack::new()
+        .frame_size(56.0, 32.0)
+        .background(color)
+        .corner_radius(6.0)
+        .border(Color::separator(), 1.0, BorderStyle::Solid)
+}
+
+fn swatch_row(name: &'static str, color: Color) -> GridRow {
+    GridRow::new()
+        .child(Text::new(name))
+        .child(swatch(Color::from(color.resolve(false))))
+        .child(swatch(Color::from(color.resolve(true))))
+        .child(swatch(color))
+}
+
+/// A card built only from semantic colors, so it follows its color scheme.
+fn preview_card(title: &'static str) -> impl IntoElement + Modifier {
+    VStack::new()
+        .spacing(8.0)
+        .alignment(HorizontalAlignment::Leading)
+        .child(Text::new(title).bold())
+        .child(
+            Text::new("Semantic colors follow the scheme")
+                .foreground_color(Color::secondary_label()),
+        )
+        .child(Divider::new())
+        .child(
+            HStack::new()
+                .spacing(8.0)
+                .child(swatch(Color::system_background()))
+                .child(swatch(Color::label()))
+                .child(swatch(Color::named("brandAccent"))),
+        )
+        .padding(16.0)
+        .frame_width(240.0)
+        .background(Color::secondary_system_background())
+        .corner_radius(8.0)
+}
+
+pub fn render_colors_story() -> impl IntoElement {
+    let header = |title: &'static str| {
+        Text::new(title)
+            .bold()
+            .foreground_color(Color::secondary_label())
+    };
+
+    let mut grid = Grid::new()
+        .horizontal_spacing(16.0)
+        .vertical_spacing(8.0)
+        .child(
+            GridRow::new()
+                .child(header("Token"))
+                .child(header("Light"))
+                .child(header("Dark"))
+                .child(header("Current")),
+        );
+    for name in TOKENS {
+        grid = grid.child(swatch_row(name, Color::named(*name)));
+    }
+    grid = grid.child(swatch_row(
+        "adaptive(yellow, indigo)",
+        Color::adaptive(Color::yellow(), Color::indigo()),
+    ));
+
+    VStack::new()
+        .spacing(16.0)
+        .alignment(HorizontalAlignment::Leading)
+        .child(Text::new("Custom adaptive colors:"))
+        .child(
+            Text::new("The Current column follows the theme; cycle it from the sidebar.")
+                .foreground_color(Color::gray()),
+        )
+        .child(
+            grid.padding(16.0)
+                .background(Color::tertiary_system_background())
+                .corner_radius(8.0),
+        )
+        .child(Text::new("Named tokens in use:"))
+        .child(
+            Text::new("Brand surface card")
+                .foreground_color(Color::named("brandOnSurface"))
+                .padding(16.0)
+                .background(Color::named("brandSurface"))
+                .border(Color::named("brandAccent"), 2.0, BorderStyle::Solid)
+                .corner_radius(8.0),
+        )
+        .child(Text::new("Color scheme override:"))
+        .child(
+            Text::new("The same card forced light and forced dark, whatever the theme.")
+                .foreground_color(Color::gray()),
+        )
+        .child(
+            HStack::new()
+                .spacing(16.0)
+                .child(preview_card("Forced light").color_scheme(ColorScheme::Light))
+                .child(preview_card("Forced dark").color_scheme(ColorScheme::Dark)),
+        )
+}
@@ -1,16 +1,39 @@
 //! Display components story.
 //!
-//! Demonstrates Divider, Label, Link, ProgressView, and Image.
+//! Demonstrates Divider, Label, Link, ProgressView, Image, shapes, Canvas,
+//! Avatar, and copying to the clipboard.
 //!
 //! ```rust,ignore
 //! Divider::new()
+//! Divider::labeled("OR")
+//! HStack::new().child(left).child(Divider::new().vertical()).child(right)
 //! Label::new("star.fill", "Favorites")
+//! Label::new("arrow.right", "Next").icon_trailing(true).label_style(LabelStyle::TitleAndIcon)
+//! VStack::new().child(Label::new("star", "Fav")).foreground_color(Color::red())
+//! Link::url("Docs", "https://docs.rs/allui").external_icon(true)
 //! Link::new("Click here", || println!("clicked"))
 //! ProgressView::new().value(0.65).progress_view_style(ProgressViewStyle::Linear)
+//! ProgressView::new().current(42.0).total(120.0).show_percent(true)
+//! Circle::new().fill(Color::red()).frame_width(40.0)
+//! RoundedRectangle::new(8.0).stroke(Color::blue(), 2.0).frame_size(80.0, 50.0)
+//! Canvas::new(|painter, bounds| { painter.circle(bounds.center(), px(8.0)); painter.fill(Color::red()) })
+//! Avatar::new("Ada Lovelace").size(Avatar::LARGE).status(AvatarStatus::Online)
+//! AvatarStack::new(vec![Avatar::new("Ada"), Avatar::new("Grace")])
+//! HStack::new().child(Text::new(API_KEY)).child(CopyButton::new(API_KEY))
+//! Text::new("order-4821").copyable("order-4821")
 //! ```
 
 use allui::prelude::*;
-use gpui::prelude::*;
+use std::f32::consts::TAU;
+
+use gpui::{point, prelude::*, px};
+
+/// A made-up key for the copy row.
+const API_KEY: &str = "sk-live-4f9a2c71e8b3d605";
+
+const SPARKLINE: [f32; 12] = [
+    4.0, 6.0, 5.0, 8.0, 7.0, 9.0, 6.0, 10.0, 12.0, 9.0, 11.0, 14.0,
+];
 
 pub fn render_display_components_story() -> impl IntoElement {
     VStack::new()
@@ -29,6 +52,44 @@ pub fn render_display_components_story() -> impl IntoElement {
                 .corner_radius(8.0)
                 .frame_width(300.0),
         )
+        .child(
+            VStack::new()
+                .spacing(12.0)
+                .child(Button::new("Sign in", || {}).button_style(ButtonStyle::BorderedProminent))
+                .child(Divider::labeled("OR"))
+                .child(Button::new("Continue as guest", || {}).button_style(ButtonStyle::Bordered))
+                .padding(16.0)
+                .background(Color::tertiary_system_background())
+                .corner_radius(8.0)
+                .frame_width(300.0),
+        )
+        .child(
+            HStack::new()
+                .spacing(16.0)
+                .child(
+                    VStack::new()
+                        .alignment(HorizontalAlignment::Leading)
+                        .child(Text::new("Left column"))
+                        .child(Text::new("Second line")),
+                )
+                .child(Divider::new().vertical())
+                .child(
+                    VStack::new()
+                        .alignment(HorizontalAlignment::Leading)
+                        .child(Text::new("Right column"))
+                        .child(Text::new("Thick blue divider")),
+                )
+                .child(
+                    Divider::new()
+                        .vertical()
+                        .thickness(3.0)
+                        .color(Color::blue()),
+                )
+                .padding(16.0)
+                .background(Color::tertiary_system_background())
+                .corner_radius(8.0)
+                .frame_height(80.0),
+        )
         .child(Text::new("Label").font(Font::headline()))
         .child(
             VStack::new()
@@ -41,16 +102,68 @@ pub fn render_display_components_story() -> impl IntoElement {
                 .background(Color::tertiary_system_background())
                 .corner_radius(8.0),
         )
-        .child(Text::new("Link").font(Font::headline()))
+        .child(Text::new("Label Styles").font(Font::headline()))
+        .child(
+            VStack::new()
+                .spacing(8.0)
+                .alignment(HorizontalAlignment::Leading)
+                .child(
+                    Label::new("star.fill", "Title and icon").label_style(LabelStyle::TitleAndIcon),
+                )
+                .child(Label::new("star.fill", "Icon only").label_style(LabelStyle::IconOnly))
+                .child(Label::new("star.fill", "Title only").label_style(LabelStyle::TitleOnly))
+                .child(
+                    Label::new("arrow.right", "Trailing icon")
+                        .icon_trailing(true)
+                        .spacing(4.0),
+                )
+                .child(
+                    Label::new("bell", "Large icon, wide spacing")
+                        .icon_size(24.0)
+                        .spacing(12.0),
+                )
+                .child(Label::with_view(
+                    VStack::new()
+                        .frame_size(12.0, 12.0)
+                        .background(Color::green())
+                        .corner_radius(6.0),
+                    "Custom view icon (online)",
+                ))
+                .child(Label::with_image("avatar.png", "Image icon (avatar)"))
+                .padding(16.0)
+                .background(Color::tertiary_system_background())
+                .corner_radius(8.0),
+        )
+        .child(Text::new("Foreground cascade").font(Font::headline()))
         .child(
             VStack::new()
                 .spacing(8.0)
                 .alignment(HorizontalAlignment::Leading)
-                .child(Link::new("Visit Allui", || {
-                    println!("Link clicked: Visit Allui");
+                .child(Label::new("star.fill", "Inherits red icon and text"))
+                .child(Link::new("Inherited link color", || {
+                    println!("Link clicked: Inherited link color");
                 }))
-                .child(Link::new("Documentation", || {
-                    println!("Link clicked: Documentation");
+                .child(
+                    Button::new("Bordered inherits red", || {}).button_style(ButtonStyle::Bordered),
+                )
+                .child(Label::new("heart", "Nearest color wins").foreground_color(Color::green()))
+                .foreground_color(Color::red())
+                .padding(16.0)
+                .background(Color::tertiary_system_background())
+                .corner_radius(8.0),
+        )
+        .child(Text::new("Link").font(Font::headline()))
+        .child(
+            VStack::new()
+                .spacing(8.0)
+                .alignment(HorizontalAlignment::Leading)
+                .child(Link::url(
+                    "Visit Allui",
+                    "https://github.com/AntimaterialLabs/allui-rs",
+                ))
+                .child(Link::url("Documentation", "https://docs.rs/allui").external_icon(true))
+                .child(Link::new("Custom action", || {
+                    println!("Link clicked: Custom action");
                 }))
                 .padding(16.0)
                 .background(Color::tertiary_system_background())
@@ -65,9 +178,26 @@ pub fn render_display_components_story() -> impl IntoElement {
                 .child(
                     ProgressView::new()
                         .progress_view_style(ProgressViewStyle::Linear)
-                        .value(0.65)
+                        .current(42.0)
+                        .total(120.0)
+                        .label("Downloading...")
+                        .show_percent(true)
                         .frame_width(200.0),
                 )
+                .child(Text::new("Linear (indeterminate):").foreground_color(Color::gray()))
+                .child(
+                    ProgressView::new()
+                        .progress_view_style(ProgressViewStyle::Linear)
+                        .label("Connecting...")
+                        .frame_width(200.0),
+                )
+                .child(Text::new("Circular (determinate):").foreground_color(Color::gray()))
+                .child(
+                    ProgressView::new()
+                        .progress_view_style(ProgressViewStyle::Circular)
+                        .value(0.65)
+                        .current_value_label("13 of 20 files"),
+                )
                 .child(Text::new("Circular (indeterminate):").foreground_color(Color::gray()))
                 .child(ProgressView::new().progress_view_style(ProgressViewStyle::Circular))
                 .padding(16.0)
@@ -88,4 +218,222 @@ pub fn render_display_components_story() -> impl IntoElement {
                 .background(Color::tertiary_system_background())
                 .corner_radius(8.0),
         )
+        .child(Text::new("Shapes").font(Font::headline()))
+        .child(
+            VStack::new()
+                .spacing(12.0)
+                .alignment(HorizontalAlignment::Leading)
+                .child(
+                    HStack::new()
+                        .spacing(12.0)
+                        .child(Rectangle::new().fill(Color::blue()).frame_size(60.0, 40.0))
+                        .child(
+                            RoundedRectangle::new(10.0)
+                                .fill(Color::green())
+                                .frame_size(60.0, 40.0),
+                        )
+                        .child(Circle::new().fill(Color::red()).frame_width(40.0))
+                        .child(Capsule::new().fill(Color::orange()).frame_size(80.0, 32.0))
+                        .child(Ellipse::new().fill(Color::purple()).frame_size(70.0, 40.0)),
+                )
+                .child(
+                    Text::new("Stroked, gradient, and foreground-colored:")
+                        .foreground_color(Color::gray()),
+                )
+                .child(
+                    HStack::new()
+                        .spacing(12.0)
+                        .child(
+                            RoundedRectangle::new(10.0)
+                                .stroke(Color::blue(), 2.0)
+                                .frame_size(60.0, 40.0),
+                        )
+                        .child(
+                            Ellipse::new()
+                                .stroke(Color::pink(), 3.0)
+                                .frame_size(70.0, 40.0),
+                        )
+                        .child(
+                            Capsule::new()
+                                .fill(
+                                    LinearGradient::new(Color::teal(), Color::indigo())
+                                        .start_point(UnitPoint::leading())
+                                        .end_point(UnitPoint::trailing()),
+                                )
+                                .stroke(Color::white(), 2.0)
+                                .frame_size(80.0, 32.0),
+                        )
+                        .child(
+                            Circle::new()
+                                .frame_height(40.0)
+                                .foreground_color(Color::mint()),
+                        ),
+                )
+                .child(Text::new("clip_shape(Circle):").foreground_color(Color::gray()))
+                .child(
+                    Text::new("Clipped")
+                        .padding(20.0)
+                        .background(Color::yellow())
+                        .clip_shape(Circle::new()),
+                )
+                .padding(16.0)
+                .background(Color::tertiary_system_background())
+                .corner_radius(8.0),
+        )
+        .child(Text::new("Canvas").font(Font::headline()))
+        .child(
+            HStack::new()
+                .spacing(24.0)
+                .child(
+                    VStack::new()
+                        .spacing(4.0)
+                        .alignment(HorizontalAlignment::Leading)
+                        .child(Text::new("Sparkline:").foreground_color(Color::gray()))
+                        .child(sparkline(SPARKLINE.to_vec()).frame_size(160.0, 40.0)),
+                )
+                .child(
+                    VStack::new()
+                        .spacing(4.0)
+                        .alignment(HorizontalAlignment::Leading)
+                        .child(Text::new("Pie chart:").foreground_color(Color::gray()))
+                        .child(pie_chart(vec![0.45, 0.3, 0.25]).frame_size(60.0, 60.0)),
+                )
+                .padding(16.0)
+                .background(Color::tertiary_system_background())
+                .corner_radius(8.0),
+        )
+        .child(Text::new("Avatar").font(Font::headline()))
+        .child(
+            VStack::new()
+                .spacing(12.0)
+                .alignment(HorizontalAlignment::Leading)
+                .child(Text::new("Sizes, with status:").foreground_color(Color::gray()))
+                .child(
+                    HStack::new()
+                        .spacing(12.0)
+                        .child(Avatar::new("Ada Lovelace").size(Avatar::SMALL))
+                        .child(Avatar::new("Grace Hopper").status(AvatarStatus::Online))
+                        .child(
+                            Avatar::new("Alan Turing")
+                                .size(Avatar::LARGE)
+                                .status(AvatarStatus::Away),
+                        )
+                        .child(
+                            Avatar::new("Katherine Johnson")
+                                .size(Avatar::EXTRA_LARGE)
+                                .status(AvatarStatus::Offline),
+                        ),
+                )
+                .child(
+                    Text::new("Missing image falls back to initials:")
+                        .foreground_color(Color::gray()),
+                )
+                .child(
+                    Avatar::new("Edsger Dijkstra")
+                        .image(std::path::PathBuf::from("missing-photo.png"))
+                        .size(Avatar::LARGE),
+                )
+                .child(Text::new("AvatarStack:").foreground_color(Color::gray()))
+                .child(AvatarStack::new(
+                    [
+                        "Ada Lovelace",
+                        "Grace Hopper",
+                        "Alan Turing",
+                        "Barbara Liskov",
+                    ]
+                    .into_iter()
+                    .map(Avatar::new)
+                    .collect(),
+                ))
+                .padding(16.0)
+                .background(Color::tertiary_system_background())
+                .corner_radius(8.0),
+        )
+        .child(Text::new("Copy to Clipboard").font(Font::headline()))
+        .child(
+            VStack::new()
+                .spacing(12.0)
+                .alignment(HorizontalAlignment::Leading)
+                .child(Text::new("API key:").foreground_color(Color::gray()))
+                .child(api_key_row())
+                .child(Text::new("Disabled:").foreground_color(Color::gray()))
+                .child(api_key_row().disabled(true))
+                .child(
+                    Text::new("Click the order number to copy it:").foreground_color(Color::gray()),
+                )
+                .child(
+                    Text::new("order-4821")
+                        .font(Font::body().monospaced())
+                        .foreground_color(Color::accent())
+                        .copyable("order-4821"),
+                )
+                .padding(16.0)
+                .background(Color::tertiary_system_background())
+                .corner_radius(8.0),
+        )
+}
+
+/// A row showing [`API_KEY`] with a button that copies it.
+#[track_caller]
+fn api_key_row() -> impl IntoElement + Modifier {
+    HStack::new()
+        .spacing(8.0)
+        .child(Text::new(API_KEY).font(Font::body().monospaced()))
+        .child(CopyButton::new(API_KEY))
+        .padding_edges(6.0, 12.0, 6.0, 12.0)
+        .background(Color::secondary_system_background())
+        .corner_radius(6.0)
+}
+
+/// A line through `values`, scaled to fill the canvas, with a dot on the last.
+fn sparkline(values: Vec<f32>) -> Canvas {
+    Canvas::new(move |painter, bounds| {
+        let (min, max) = values.iter().fold((f32::MAX, f32::MIN), |(min, max), v| {
+            (min.min(*v), max.max(*v))
+        });
+        let range = (max - min).max(f32::EPSILON);
+        let step = bounds.size.width / (values.len().max(2) - 1) as f32;
+        let points: Vec<_> = values
+            .iter()
+            .enumerate()
+            .map(|(ix, value)| {
+                let y = bounds.bottom() - bounds.size.height * ((value - min) / range);
+                point(bounds.left() + step * ix as f32, y)
+            })
+            .collect();
+
+        for (ix, point) in points.iter().enumerate() {
+            if ix == 0 {
+                painter.move_to(*point);
+            } else {
+                painter.line_to(*point);
+            }
+        }
+        painter.stroke(Color::green(), 1.5);
+
+        if let Some(last) = points.last() {
+            painter.begin_path();
+            painter.circle(*last, px(3.0));
+            painter.fill(Color::green());
+        }
+    })
+}
+
+/// Wedges for `shares` of a whole, clockwise from the top.
+fn pie_chart(shares: Vec<f32>) -> Canvas {
+    Canvas::new(move |painter, bounds| {
+        let colors = [Color::blue(), Color::orange(), Color::purple()];
+        let center = bounds.center();
+        let radius = bounds.size.width.min(bounds.size.height) / 2.0;
+        let mut angle = -TAU / 4.0;
+        for (ix, share) in shares.iter().enumerate() {
+            let end = angle + share * TAU;
+            painter.begin_path();
+            painter.move_to(center);
+            painter.arc(center, radius, angle, end);
+            painter.close();
+            painter.fill(colors[ix % colors.len()].clone());
+            angle = end;
+        }
+    })
 }
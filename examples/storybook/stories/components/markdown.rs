@@ -0,0 +1,74 @@
+//! Markdown story.
+//!
+//! Demonstrates rendering a CommonMark document with a custom style.
+//!
+//! ```rust,ignore
+//! Markdown::new("# Title\n\nSome **bold** text and a [link](https://example.com).")
+//!     .markdown_style(MarkdownStyle::new().link_color(Color::purple()))
+//! ```
+
+use allui::prelude::*;
+use gpui::prelude::*;
+
+const SAMPLE: &str = "# Allui
+
+A **SwiftUI-inspired** UI framework for Rust, built on *GPUI*. Views are \
+composed with builders like `VStack::new()` and styled with modifiers.
+
+## Getting started
+
+1. Add `allui` to your `Cargo.toml`
+2. Import the prelude with `use allui::prelude::*;`
+3. Read the [GPUI docs](https://www.gpui.rs) for the underlying model
+
+### Highlights
+
+- Familiar layout primitives: stacks, grids, and lists
+- Semantic colors that follow the *light and dark* theme
+- Inline code like `Text::new(\"Hi\")` and escaped \\*asterisks\\*
+
+---
+
+```
+VStack::new()
+    .spacing(12.0)
+    .child(Text::new(\"Hello\"))
+```";
+
+pub fn render_markdown_story() -> impl IntoElement {
+    VStack::new()
+        .spacing(16.0)
+        .alignment(HorizontalAlignment::Leading)
+        .child(Text::new("Document").font(Font::headline()))
+        .child(
+            Markdown::new(SAMPLE)
+                .padding(16.0)
+                .background(Color::tertiary_system_background())
+                .corner_radius(8.0)
+                .frame_width(480.0),
+        )
+        .child(Text::new("Custom Style").font(Font::headline()))
+        .child(
+            Markdown::new(
+                "Links can be [handled in-app](allui://settings) instead of opening a browser.",
+            )
+            .markdown_style(
+                MarkdownStyle::new()
+                    .link_color(Color::purple())
+                    .code_background(Color::yellow().opacity(0.3)),
+            )
+            .on_open_url(|url, _, _| println!("Open URL: {url}"))
+            .padding(16.0)
+            .background(Color::tertiary_system_background())
+            .corner_radius(8.0)
+            .frame_width(480.0),
+        )
+        .child(
+            Markdown::new("Inherits `.foreground_color()` from its container.")
+                .foreground_color(Color::secondary_label())
+                .padding(16.0)
+                .background(Color::tertiary_system_background())
+                .corner_radius(8.0)
+                .frame_width(480.0),
+        )
+}
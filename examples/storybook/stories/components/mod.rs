@@ -1,7 +1,10 @@
-//! Component story modules - Text, Button, Toggle, inputs, and display components.
+//! Component story modules - Text, Button, Toggle, inputs, display components, and Markdown.
 
 mod button;
+mod color_picker;
+mod colors;
 mod display_components;
+mod markdown;
 mod modifiers;
 mod more_inputs;
 mod sliders;
@@ -11,7 +14,10 @@ mod text_fields;
 mod toggle;
 
 pub use button::*;
+pub use color_picker::*;
+pub use colors::*;
 pub use display_components::*;
+pub use markdown::*;
 pub use modifiers::*;
 pub use more_inputs::*;
 pub use sliders::*;
@@ -1,13 +1,22 @@
 //! Modifiers story.
 //!
-//! Demonstrates how modifier order affects rendering (padding, background, etc.).
+//! Demonstrates how modifier order affects rendering (padding, background, etc.),
+//! border styles, gradient backgrounds, opacity applied to a subtree of
+//! overlapping children, hover and pressed styles, and tasks and timers that
+//! run while a view is shown.
 //!
 //! ```rust,ignore
 //! // Order matters!
 //! Text::new("A").padding(16.0).background(Color::red())  // padding inside
 //! Text::new("B").background(Color::red()).padding(16.0)  // padding outside
+//!
+//! card.when_hovered(|style| style.corner_radius(12.0).shadow(12.0).offset(0.0, -4.0))
+//! Text::new(time).on_timer(1.0, move |_, cx| time.set(clock_time(), cx))
+//! ProgressView::new().value(value).task(run, async move |cx| { ... })
 //! ```
 
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
 use allui::prelude::*;
 use gpui::prelude::*;
 
@@ -42,4 +51,180 @@ pub fn render_modifiers_story() -> impl IntoElement {
                 .background(Color::green())
                 .corner_radius(12.0),
         )
+        .child(Text::new("Borders:"))
+        .child(
+            HStack::new()
+                .spacing(16.0)
+                .child(Text::new("Solid 3px").padding(12.0).border(
+                    Color::blue(),
+                    3.0,
+                    BorderStyle::Solid,
+                ))
+                .child(Text::new("Drop files here").padding(12.0).border(
+                    Color::gray(),
+                    2.0,
+                    BorderStyle::Dashed,
+                ))
+                .child(Text::new("Bottom only").padding(12.0).border_edges(
+                    Color::separator(),
+                    1.0,
+                    EdgeSet::bottom(),
+                )),
+        )
+        .child(Text::new("Gradient backgrounds:"))
+        .child(
+            HStack::new()
+                .spacing(16.0)
+                .child(
+                    Text::new("Top to bottom")
+                        .foreground_color(Color::white())
+                        .padding(12.0)
+                        .background(LinearGradient::new(Color::blue(), Color::purple()))
+                        .corner_radius(8.0),
+                )
+                .child(
+                    Text::new("Leading to trailing")
+                        .padding(12.0)
+                        .background(
+                            LinearGradient::new(
+                                Color::system_background(),
+                                Color::secondary_label(),
+                            )
+                            .start_point(UnitPoint::leading())
+                            .end_point(UnitPoint::trailing()),
+                        )
+                        .corner_radius(8.0),
+                ),
+        )
+        .child(Text::new("Opacity applies to the whole subtree:"))
+        .child(
+            HStack::new()
+                .spacing(32.0)
+                .child(overlapping_cards().frame_size(140.0, 100.0))
+                .child(
+                    overlapping_cards()
+                        .frame_size(140.0, 100.0)
+                        .padding(8.0)
+                        .background(Color::yellow())
+                        .opacity(0.5),
+                ),
+        )
+        .child(Text::new("Hover and pressed styles:"))
+        .child(
+            HStack::new()
+                .spacing(16.0)
+                .alignment(VerticalAlignment::Top)
+                .child(pricing_card("Starter", "$0", "For side projects"))
+                .child(pricing_card("Pro", "$12", "For growing teams"))
+                .child(pricing_card("Enterprise", "$49", "For large organizations")),
+        )
+        .child(Text::new("Tasks and timers:"))
+        .child(
+            HStack::new()
+                .spacing(32.0)
+                .child(live_clock())
+                .child(simulated_progress()),
+        )
+}
+
+/// A pricing plan card that lifts on hover and dims while pressed.
+fn pricing_card(
+    plan: &'static str,
+    price: &'static str,
+    summary: &'static str,
+) -> impl IntoElement {
+    VStack::new()
+        .spacing(8.0)
+        .alignment(HorizontalAlignment::Leading)
+        .child(Text::new(plan).font(Font::headline()))
+        .child(Text::new(price).font(Font::title()))
+        .child(Text::new(summary).foreground_color(Color::secondary_label()))
+        .padding(16.0)
+        .frame_width(160.0)
+        .background(Color::secondary_system_background())
+        .corner_radius(12.0)
+        .when_hovered(|style| {
+            style
+                .corner_radius(12.0)
+                .shadow(12.0)
+                .border(Color::accent(), 1.0)
+                .offset(0.0, -4.0)
+        })
+        .when_pressed(|style| style.opacity(0.85))
+}
+
+/// A clock that ticks every second while it's shown.
+fn live_clock() -> impl IntoElement {
+    WithState::new(clock_time(), |time: StateHandle<String>, cx| {
+        Text::new(time.get(cx))
+            .font(Font::title().monospaced())
+            .on_timer(1.0, move |_, cx| time.set(clock_time(), cx))
+    })
+}
+
+/// The time of day in UTC, as hours, minutes and seconds.
+fn clock_time() -> String {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_secs())
+        % 86_400;
+    format!(
+        "{:02}:{:02}:{:02} UTC",
+        secs / 3600,
+        secs / 60 % 60,
+        secs % 60
+    )
+}
+
+/// A progress bar that fills over five seconds, starting again when the
+/// button changes the task's key.
+fn simulated_progress() -> impl IntoElement {
+    WithState::new(0u32, |run: StateHandle<u32>, cx| {
+        let key = run.get(cx);
+        WithState::new(0.0, move |progress: StateHandle<f32>, cx| {
+            let value = progress.get(cx);
+            HStack::new()
+                .spacing(12.0)
+                .child(ProgressView::new().value(value).frame_width(160.0).task(
+                    key,
+                    async move |cx| {
+                        for step in 0..=50 {
+                            let value = step as f32 / 50.0;
+                            if cx.update(|_, cx| progress.set(value, cx)).is_err() {
+                                break;
+                            }
+                            cx.background_executor()
+                                .timer(Duration::from_millis(100))
+                                .await;
+                        }
+                    },
+                ))
+                .child(
+                    Button::with_id("restart-progress")
+                        .label("Restart")
+                        .on_click_with(move |_, _, cx| run.update(cx, |run| *run += 1)),
+                )
+        })
+    })
+}
+
+/// Two opaque cards overlapping each other, used to check subtree opacity.
+fn overlapping_cards() -> impl IntoElement + Modifier {
+    ZStack::new()
+        .alignment(Alignment::top_leading())
+        .child(
+            Text::new("Back")
+                .padding(12.0)
+                .frame_size(90.0, 60.0)
+                .background(Color::blue())
+                .corner_radius(8.0),
+        )
+        .child(
+            Text::new("Front")
+                .padding(12.0)
+                .frame_size(90.0, 60.0)
+                .background(Color::red())
+                .corner_radius(8.0)
+                .padding_edges(40.0, 50.0, 0.0, 0.0),
+        )
 }
@@ -1,21 +1,33 @@
 //! More inputs story.
 //!
-//! Demonstrates TextEditor (multi-line), Stepper, and Picker usage.
+//! Demonstrates TextEditor (multi-line), NumberField, Stepper, and Picker styles.
 //!
 //! ```rust,ignore
 //! let editor = cx.new(|cx| InputState::new(window, cx).multi_line(true));
-//! TextEditor::new(&editor).height(150.0)
-//! Stepper::new(&stepper_input)  // Subscribe to StepperEvent
+//! TextEditor::new(&editor).height(150.0).max_length(280).character_count(true)
+//! NumberField::new(price).fraction_digits(2).on_value_change(handler)
+//! Stepper::bound(Binding::entity_field(&view, |this| &mut this.count)).range(0.0..=10.0)
+//! Stepper::value(amount).step(0.5).editable(true).on_change(handler)
+//! Picker::new(&theme).picker_style(PickerStyle::Segmented).labels(["System", "Light", "Dark"])
 //! ```
 
 use allui::prelude::*;
-use gpui::{prelude::*, Entity};
+use gpui::{prelude::*, App, Context, SharedString, Window};
+
+use crate::{Storybook, ThemePreference};
+
+const SHIPPING_SPEEDS: [&str; 3] = ["Standard", "Express", "Overnight"];
 
 pub fn render_more_inputs_story(
-    text_editor_input: &Entity<InputState>,
-    stepper_input: &Entity<InputState>,
-    stepper_value: i32,
+    storybook: &Storybook,
+    cx: &mut Context<Storybook>,
 ) -> impl IntoElement {
+    let stepper_value = storybook.stepper_value;
+    let total = match (storybook.quantity, storybook.unit_price) {
+        (Some(quantity), Some(price)) => format!("Total: ${:.2}", quantity * price),
+        _ => "Total: -".to_string(),
+    };
+
     VStack::new()
         .spacing(16.0)
         .alignment(HorizontalAlignment::Leading)
@@ -25,12 +37,72 @@ pub fn render_more_inputs_story(
                 .spacing(8.0)
                 .alignment(HorizontalAlignment::Leading)
                 .child(
-                    TextEditor::new(text_editor_input)
+                    TextEditor::new(&storybook.text_editor_input)
                         .height(150.0)
+                        .show_line_numbers(true)
+                        .frame_width(300.0),
+                )
+                .child(Text::new("Notes, limited to 280 characters:"))
+                .child(
+                    TextEditor::new(&storybook.notes_input)
+                        .height(100.0)
+                        .max_length(280)
+                        .character_count(true)
+                        .word_count(true)
                         .frame_width(300.0),
                 )
                 .child(
-                    Text::new("Note: Create InputState with .multi_line(true)")
+                    Text::new(
+                        "Note: Create InputState with .multi_line(true), or .code_editor(\"text\") for line numbers",
+                    )
+                        .foreground_color(Color::gray()),
+                )
+                .padding(16.0)
+                .background(Color::tertiary_system_background())
+                .corner_radius(8.0),
+        )
+        .child(Text::new("NumberField - Numeric input:"))
+        .child(
+            VStack::new()
+                .spacing(12.0)
+                .alignment(HorizontalAlignment::Leading)
+                .child(
+                    HStack::new()
+                        .spacing(12.0)
+                        .child(Text::new("Quantity").frame_width(80.0))
+                        .child(
+                            NumberField::new(storybook.quantity)
+                                .placeholder("0")
+                                .range(0.0..=999.0)
+                                .fraction_digits(0)
+                                .on_value_change(cx.listener(|this, value: &Option<f64>, _, cx| {
+                                    this.quantity = *value;
+                                    cx.notify();
+                                }))
+                                .frame_width(160.0),
+                        ),
+                )
+                .child(
+                    HStack::new()
+                        .spacing(12.0)
+                        .child(Text::new("Unit price").frame_width(80.0))
+                        .child(
+                            NumberField::new(storybook.unit_price)
+                                .placeholder("0.00")
+                                .prefix(Text::new("$").foreground_color(Color::secondary_label()))
+                                .range(0.0..=1_000_000.0)
+                                .fraction_digits(2)
+                                .thousands_separator(true)
+                                .on_value_change(cx.listener(|this, value: &Option<f64>, _, cx| {
+                                    this.unit_price = *value;
+                                    cx.notify();
+                                }))
+                                .frame_width(160.0),
+                        ),
+                )
+                .child(Text::new(total).bold())
+                .child(
+                    Text::new("Letters are rejected; \"1.\" and prices over the range resolve on Enter or blur")
                         .foreground_color(Color::gray()),
                 )
                 .padding(16.0)
@@ -45,11 +117,28 @@ pub fn render_more_inputs_story(
                 .child(
                     HStack::new()
                         .spacing(16.0)
-                        .child(Text::new(format!("Value: {}", stepper_value)))
-                        .child(Stepper::new(stepper_input)),
+                        .child(Text::new(format!("Count: {}", storybook.stepper_count)))
+                        .child(
+                            Stepper::bound(Binding::entity_field(&cx.entity(), |this| {
+                                &mut this.stepper_count
+                            }))
+                            .range(0.0..=10.0),
+                        ),
+                )
+                .child(
+                    HStack::new()
+                        .spacing(16.0)
+                        .child(Text::new(format!("Editable, step 0.5: {}", stepper_value)))
+                        .child(
+                            Stepper::value(stepper_value)
+                                .range(0.0..=10.0)
+                                .step(0.5)
+                                .editable(true)
+                                .on_change(set_stepper_value(cx)),
+                        ),
                 )
                 .child(
-                    Text::new("Stepper triggers increment/decrement callbacks")
+                    Text::new("The buttons disable at the ends of the 0-10 range")
                         .foreground_color(Color::gray()),
                 )
                 .padding(16.0)
@@ -59,34 +148,67 @@ pub fn render_more_inputs_story(
         .child(Text::new("Picker - Selection from options:"))
         .child(
             VStack::new()
-                .spacing(8.0)
+                .spacing(12.0)
                 .alignment(HorizontalAlignment::Leading)
+                .child(Text::new("Theme, as a segmented control:"))
                 .child(
-                    Text::new(
-                        "Picker requires PickerState + PickerDelegate. See picker.rs for usage.",
-                    )
-                    .foreground_color(Color::gray()),
+                    Picker::new(&storybook.theme_picker)
+                        .picker_style(PickerStyle::Segmented)
+                        .labels(ThemePreference::ALL.map(ThemePreference::label)),
                 )
+                .child(Text::new("The same state as radio buttons and a menu:"))
                 .child(
-                    VStack::new()
-                        .spacing(4.0)
-                        .child(Text::new("Usage:").bold())
+                    HStack::new()
+                        .spacing(24.0)
+                        .alignment(VerticalAlignment::Top)
                         .child(
-                            Text::new("let state = PickerState::new(...);")
-                                .font(Font::caption())
-                                .foreground_color(Color::tertiary_label()),
+                            Picker::new(&storybook.theme_picker)
+                                .picker_style(PickerStyle::Radio)
+                                .labels(ThemePreference::ALL.map(ThemePreference::label)),
                         )
+                        .child(Picker::new(&storybook.theme_picker).width(160.0)),
+                )
+                .child(Text::new("Inline, from a list of options and an index:"))
+                .child(
+                    HStack::new()
+                        .spacing(12.0)
                         .child(
-                            Text::new("Picker::new(\"id\", &state).build(window, cx)")
-                                .font(Font::caption())
-                                .foreground_color(Color::tertiary_label()),
+                            Picker::inline(
+                                "shipping-speed",
+                                SHIPPING_SPEEDS.map(SharedString::from).to_vec(),
+                                storybook.shipping_speed,
+                                cx.listener(|this, ix: &usize, _, cx| {
+                                    this.shipping_speed = Some(*ix);
+                                    cx.notify();
+                                }),
+                            )
+                            .width(160.0),
                         )
-                        .padding(12.0)
-                        .background(Color::secondary_system_background())
-                        .corner_radius(8.0),
+                        .child(
+                            Text::new(format!(
+                                "Selected: {}",
+                                storybook
+                                    .shipping_speed
+                                    .map_or("None", |ix| SHIPPING_SPEEDS[ix])
+                            ))
+                            .foreground_color(Color::gray()),
+                        ),
                 )
                 .padding(16.0)
                 .background(Color::tertiary_system_background())
                 .corner_radius(8.0),
         )
 }
+
+/// A stepper handler that stores the value in the storybook.
+fn set_stepper_value(cx: &mut Context<Storybook>) -> impl Fn(f64, &mut Window, &mut App) + 'static {
+    let storybook = cx.entity().downgrade();
+    move |value, _, cx| {
+        storybook
+            .update(cx, |this, cx| {
+                this.stepper_value = value;
+                cx.notify();
+            })
+            .ok();
+    }
+}
@@ -1,6 +1,9 @@
 //! Slider story.
 //!
-//! Demonstrates range value selection with SliderState.
+//! Demonstrates range value selection with SliderState. The value readout uses
+//! monospaced digits so it doesn't jitter while dragging. A two-thumb price
+//! range slider shows end labels and the dragged value, and a 3-band EQ uses
+//! vertical sliders that snap to tick marks.
 //!
 //! ```rust,ignore
 //! let slider = cx.new(|_| SliderState::new().min(0.0).max(100.0).default_value(50.0));
@@ -9,12 +12,17 @@
 //! ```
 
 use allui::prelude::*;
-use gpui::{prelude::*, Entity};
+use gpui::{prelude::*, Context};
+
+use crate::Storybook;
 
 pub fn render_sliders_story(
-    slider_state: &Entity<SliderState>,
-    slider_value: f32,
+    storybook: &Storybook,
+    cx: &mut Context<Storybook>,
 ) -> impl IntoElement {
+    let slider_value = storybook.slider_value;
+    let (min_price, max_price) = storybook.price_range;
+
     VStack::new()
         .spacing(16.0)
         .alignment(HorizontalAlignment::Leading)
@@ -27,9 +35,10 @@ pub fn render_sliders_story(
                 .child(
                     HStack::new()
                         .spacing(16.0)
-                        .child(Slider::new(slider_state).frame_width(200.0))
+                        .child(Slider::new(&storybook.slider_state).frame_width(200.0))
                         .child(
                             Text::new(format!("{:.0}", slider_value))
+                                .monospaced_digit()
                                 .foreground_color(Color::green()),
                         ),
                 )
@@ -41,8 +50,82 @@ pub fn render_sliders_story(
                 .background(Color::tertiary_system_background())
                 .corner_radius(8.0),
         )
+        .child(Text::new("Range slider - price filter:"))
+        .child(
+            VStack::new()
+                .spacing(12.0)
+                .alignment(HorizontalAlignment::Leading)
+                .child(
+                    Slider::range_values(min_price, max_price)
+                        .bounds(0.0..=500.0)
+                        .step(10.0)
+                        .show_value(true)
+                        .min_label(Text::new("$0").foreground_color(Color::gray()))
+                        .max_label(Text::new("$500").foreground_color(Color::gray()))
+                        .on_range_change(cx.listener(
+                            |this: &mut Storybook, range: &(f32, f32), _, cx| {
+                                this.price_range = *range;
+                                cx.notify();
+                            },
+                        ))
+                        .frame_width(300.0),
+                )
+                .child(
+                    Text::new(format!("${:.0} - ${:.0}", min_price, max_price))
+                        .monospaced_digit()
+                        .foreground_color(Color::green()),
+                )
+                .padding_edges(32.0, 16.0, 16.0, 16.0)
+                .background(Color::tertiary_system_background())
+                .corner_radius(8.0),
+        )
+        .child(Text::new("Vertical sliders with ticks - 3-band EQ:"))
+        .child(
+            HStack::new()
+                .spacing(32.0)
+                .children(
+                    ["Low", "Mid", "High"]
+                        .into_iter()
+                        .enumerate()
+                        .map(|(band, name)| eq_band(storybook, band, name, cx)),
+                )
+                .padding(16.0)
+                .background(Color::tertiary_system_background())
+                .corner_radius(8.0),
+        )
         .child(
             Text::new("Note: Subscribe to SliderEvent for value changes")
                 .foreground_color(Color::gray()),
         )
 }
+
+fn eq_band(
+    storybook: &Storybook,
+    band: usize,
+    name: &'static str,
+    cx: &mut Context<Storybook>,
+) -> impl IntoElement {
+    let gain = storybook.eq_gains[band];
+
+    VStack::new()
+        .spacing(8.0)
+        .child(
+            Text::new(format!("{:+.0} dB", gain))
+                .monospaced_digit()
+                .foreground_color(Color::green()),
+        )
+        .child(
+            Slider::value(gain)
+                .id(name)
+                .bounds(-12.0..=12.0)
+                .orientation(Axis::Vertical)
+                .ticks(5)
+                .snap_to_ticks(true)
+                .on_change(cx.listener(move |this: &mut Storybook, gain: &f32, _, cx| {
+                    this.eq_gains[band] = *gain;
+                    cx.notify();
+                }))
+                .frame_height(160.0),
+        )
+        .child(Text::new(name).foreground_color(Color::gray()))
+}
@@ -1,6 +1,7 @@
 //! Tap gesture story.
 //!
-//! Demonstrates adding tap handlers to any view using on_tap_gesture.
+//! Demonstrates adding tap handlers to any view using on_tap_gesture, telling
+//! single and double taps apart, and the feedback shown while pressed.
 //!
 //! ```rust,ignore
 //! Text::new("Tap me!")
@@ -10,6 +11,11 @@
 //!             cx.notify();
 //!         });
 //!     })
+//!
+//! Card::new()
+//!     .on_tap_gesture_count("card-double", 2, |_, _, cx| ...)
+//!     .on_tap_gesture_with("card-single", |_, _, cx| ...)
+//!     .tap_highlight(Color::black().opacity(0.1))
 //! ```
 
 use allui::prelude::*;
@@ -50,8 +56,36 @@ pub fn render_tap_gesture_story(
                 .background(Color::tertiary_system_background())
                 .corner_radius(8.0),
         )
+        .child(Text::new("on_tap_gesture_count - Single and double taps:"))
+        .child(tap_count_card())
         .child(
             Text::new("Any view can be made tappable with .on_tap_gesture()")
                 .foreground_color(Color::gray()),
         )
 }
+
+/// A card counting its single and double taps separately, highlighted
+/// while pressed.
+fn tap_count_card() -> impl IntoElement {
+    WithState::new((0, 0), |taps: StateHandle<(u32, u32)>, cx| {
+        let (single, double) = taps.get(cx);
+        let single_taps = taps.clone();
+        VStack::new()
+            .spacing(8.0)
+            .alignment(HorizontalAlignment::Leading)
+            .child(Text::new("Tap or double-tap this card").font(Font::headline()))
+            .child(Text::new(format!("Single taps: {}", single)))
+            .child(Text::new(format!("Double taps: {}", double)))
+            .padding(16.0)
+            .frame_width(260.0)
+            .on_tap_gesture_count("tap-count-double", 2, move |_, _, cx| {
+                taps.update(cx, |(_, double)| *double += 1);
+            })
+            .on_tap_gesture_with("tap-count-single", move |_, _, cx| {
+                single_taps.update(cx, |(single, _)| *single += 1);
+            })
+            .tap_highlight(Color::black().opacity(0.1))
+            .background(Color::tertiary_system_background())
+            .corner_radius(8.0)
+    })
+}
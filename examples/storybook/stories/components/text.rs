@@ -1,6 +1,7 @@
 //! Text story.
 //!
-//! Demonstrates text rendering with fonts, colors, decorations, and line limits.
+//! Demonstrates text rendering with fonts, custom families, colors, decorations,
+//! concatenation, line limits, and a live text scale slider.
 //!
 //! ```rust,ignore
 //! Text::new("Hello")
@@ -8,12 +9,37 @@
 //!     .foreground_color(Color::blue())
 //!     .strikethrough(true)
 //!     .line_limit(2)
+//!
+//! Text::new("Hello ").bold() + Text::new("world").foreground_color(Color::red())
+//!
+//! VStack::new().child(Text::new("Body").font(Font::body())).text_scale(1.4)
 //! ```
 
 use allui::prelude::*;
-use gpui::prelude::*;
+use gpui::{prelude::*, Entity};
+
+pub fn render_text_story(scale_state: &Entity<SliderState>, scale: f32) -> impl IntoElement {
+    VStack::new()
+        .spacing(16.0)
+        .alignment(HorizontalAlignment::Leading)
+        .child(
+            Text::new("Text Scale")
+                .font(Font::headline())
+                .fixed_size_font(),
+        )
+        .child(
+            HStack::new()
+                .spacing(16.0)
+                .child(Slider::new(scale_state).frame_width(200.0))
+                .child(Text::new(format!("{:.1}x", scale)).fixed_size_font())
+                .padding(16.0)
+                .background(Color::tertiary_system_background())
+                .corner_radius(8.0),
+        )
+        .child(render_text_samples().text_scale(scale))
+}
 
-pub fn render_text_story() -> impl IntoElement {
+fn render_text_samples() -> impl IntoElement + Modifier {
     VStack::new()
         .spacing(16.0)
         .alignment(HorizontalAlignment::Leading)
@@ -70,6 +96,60 @@ pub fn render_text_story() -> impl IntoElement {
                 .background(Color::tertiary_system_background())
                 .corner_radius(8.0),
         )
+        .child(Text::new("Custom Families").font(Font::headline()))
+        .child(
+            VStack::new()
+                .spacing(8.0)
+                .alignment(HorizontalAlignment::Leading)
+                .child(Text::new("Inter: Sphinx of black quartz, judge my vow").font_family("Inter"))
+                .child(
+                    Text::new("Georgia: Sphinx of black quartz, judge my vow")
+                        .font(Font::custom("Georgia").italic()),
+                )
+                .child(
+                    Text::new("Not installed (falls back): Sphinx of black quartz, judge my vow")
+                        .font_family("Allui Missing Font"),
+                )
+                .padding(16.0)
+                .background(Color::tertiary_system_background())
+                .corner_radius(8.0),
+        )
+        .child(Text::new("Paragraph Styles").font(Font::headline()))
+        .child(
+            VStack::new()
+                .spacing(12.0)
+                .alignment(HorizontalAlignment::Leading)
+                .child(
+                    Text::new(
+                        "Centered paragraph with extra line spacing. The lines of this text \
+                         wrap within the frame, and each one is centered rather than hugging \
+                         the leading edge.",
+                    )
+                    .multiline_text_alignment(TextAlignment::Center)
+                    .line_spacing(8.0)
+                    .frame_width(320.0),
+                )
+                .child(
+                    HStack::new()
+                        .spacing(12.0)
+                        .child(
+                            Text::new("Section label")
+                                .text_case(TextCase::Upper)
+                                .font(Font::caption())
+                                .foreground_color(Color::secondary_label()),
+                        )
+                        .child(Text::new("MIXED Case").text_case(TextCase::Lower))
+                        .child(
+                            Text::new("Tracked")
+                                .text_case(TextCase::Upper)
+                                .tracking(3.0)
+                                .font(Font::caption()),
+                        ),
+                )
+                .padding(16.0)
+                .background(Color::tertiary_system_background())
+                .corner_radius(8.0),
+        )
         .child(Text::new("Text Decorations").font(Font::headline()))
         .child(
             VStack::new()
@@ -77,6 +157,9 @@ pub fn render_text_story() -> impl IntoElement {
                 .alignment(HorizontalAlignment::Leading)
                 .child(Text::new("Normal text"))
                 .child(Text::new("Strikethrough text").strikethrough(true))
+                .child(Text::new("Underlined text").underline(true))
+                .child(Text::new("Underlined in blue").underline_color(Color::blue()))
+                .child(Text::new("Tabular digits: 1111 / 8888").monospaced_digit())
                 .child(
                     Text::new("Strikethrough + Red")
                         .strikethrough(true)
@@ -86,6 +169,28 @@ pub fn render_text_story() -> impl IntoElement {
                 .background(Color::tertiary_system_background())
                 .corner_radius(8.0),
         )
+        .child(Text::new("Concatenation").font(Font::headline()))
+        .child(
+            VStack::new()
+                .spacing(8.0)
+                .alignment(HorizontalAlignment::Leading)
+                .child(
+                    (Text::new("This sentence has one ")
+                        + Text::new("bold").bold()
+                        + Text::new(" word and one ")
+                        + Text::new("red").foreground_color(Color::red())
+                        + Text::new(" word, and it wraps as a single paragraph across its segments."))
+                    .frame_width(300.0),
+                )
+                .child(
+                    Text::new("Price: ")
+                        + Text::new("$24.99").strikethrough(true).foreground_color(Color::gray())
+                        + Text::new(" $19.99").bold().underline(true),
+                )
+                .padding(16.0)
+                .background(Color::tertiary_system_background())
+                .corner_radius(8.0),
+        )
         .child(Text::new("Line Limit").font(Font::headline()))
         .child(
             VStack::new()
@@ -113,6 +218,30 @@ pub fn render_text_story() -> impl IntoElement {
                                 .frame_width(300.0)
                         ),
                 )
+                .child(
+                    VStack::new()
+                        .spacing(4.0)
+                        .alignment(HorizontalAlignment::Leading)
+                        .child(Text::new(".truncation_mode(.head):").foreground_color(Color::gray()))
+                        .child(
+                            Text::new("src/components/containers/navigation/stack/detail_view.rs")
+                                .line_limit(1)
+                                .truncation_mode(TruncationMode::Head)
+                                .frame_width(300.0),
+                        ),
+                )
+                .child(
+                    VStack::new()
+                        .spacing(4.0)
+                        .alignment(HorizontalAlignment::Leading)
+                        .child(Text::new(".truncation_mode(.middle):").foreground_color(Color::gray()))
+                        .child(
+                            Text::new("src/components/containers/navigation/stack/detail_view.rs")
+                                .line_limit(1)
+                                .truncation_mode(TruncationMode::Middle)
+                                .frame_width(300.0),
+                        ),
+                )
                 .padding(16.0)
                 .background(Color::tertiary_system_background())
                 .corner_radius(8.0),
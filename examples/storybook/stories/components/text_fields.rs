@@ -1,21 +1,106 @@
 //! TextField and SecureField story.
 //!
-//! Demonstrates single-line text input and password fields.
+//! Demonstrates single-line text input and password fields. The basic field
+//! drives a live greeting through `.on_change`; other fields show prefix and
+//! suffix accessories and an email field validated as you type. A contact
+//! form moves the cursor into its email field from a button, and Tab moves
+//! between its fields and a focusable box with a focus ring.
 //!
 //! ```rust,ignore
 //! let input = cx.new(|cx| InputState::new(window, cx).placeholder("Email..."));
 //! TextField::new(&input).cleanable(true).frame_width(300.0)
-//! SecureField::new(&password_input).show_toggle(true)
+//! TextField::new(&input).on_change(cx.listener(|this, text: &str, _, cx| { ... }))
+//! TextField::new(&email_input).focused(&email_focus) // email_focus.focus(window)
+//! SecureField::new(&password_input).reveal_on_hold(true).strength_meter(true)
+//! ComboBox::new("fruit", &fruit_input, fruits).on_select(handler)
+//! TagField::new("tags", &tag_input).tags(tags).max_tags(5).on_tags_change(handler)
 //! ```
 
 use allui::prelude::*;
-use gpui::{prelude::*, Entity};
+use gpui::{prelude::*, Context, SharedString};
+
+use crate::Storybook;
+
+const FRUITS: [&str; 12] = [
+    "Apple",
+    "Apricot",
+    "Banana",
+    "Blackberry",
+    "Blueberry",
+    "Cherry",
+    "Grape",
+    "Kiwi",
+    "Mango",
+    "Orange",
+    "Pineapple",
+    "Strawberry",
+];
+
+/// A two-field form whose button moves the cursor into the email field.
+fn contact_form(storybook: &Storybook, cx: &mut Context<Storybook>) -> impl IntoElement {
+    let email_focus = storybook.contact_email_focus.clone();
+    let status = if storybook.contact_email_focused {
+        "Email field focused"
+    } else {
+        "Email field not focused"
+    };
+
+    VStack::new()
+        .spacing(12.0)
+        .alignment(HorizontalAlignment::Leading)
+        .child(TextField::new(&storybook.contact_name_input).frame_width(300.0))
+        .child(
+            TextField::new(&storybook.contact_email_input)
+                .frame_width(300.0)
+                .focused(&storybook.contact_email_focus)
+                .on_focus_change({
+                    let storybook = cx.entity().downgrade();
+                    move |focused, _, cx| {
+                        storybook
+                            .update(cx, |this, cx| {
+                                this.contact_email_focused = focused;
+                                cx.notify();
+                            })
+                            .ok();
+                    }
+                }),
+        )
+        .child(
+            HStack::new()
+                .spacing(12.0)
+                .child(
+                    Button::with_id("focus-email")
+                        .label("Focus email")
+                        .button_style(ButtonStyle::Bordered)
+                        .on_click_with(move |_, window, _| email_focus.focus(window)),
+                )
+                .child(Text::new(status).foreground_color(Color::gray())),
+        )
+        .child(
+            Text::new("Tab here for a focus ring")
+                .padding(8.0)
+                .background(Color::secondary_system_background())
+                .corner_radius(6.0)
+                .focusable(),
+        )
+        .padding(16.0)
+        .background(Color::tertiary_system_background())
+        .corner_radius(8.0)
+}
 
 pub fn render_textfields_story(
-    text_input: &Entity<InputState>,
-    text_input_cleanable: &Entity<InputState>,
-    password_input: &Entity<InputState>,
+    storybook: &Storybook,
+    cx: &mut Context<Storybook>,
 ) -> impl IntoElement {
+    let greeting = if storybook.greeting_name.is_empty() {
+        "Type your name above".to_string()
+    } else {
+        format!("Hello, {}!", storybook.greeting_name)
+    };
+    let email = &storybook.email;
+    let email_error =
+        (!email.is_empty() && !email.contains('@')).then(|| "An email address needs an @".into());
+
     VStack::new()
         .spacing(16.0)
         .alignment(HorizontalAlignment::Leading)
@@ -25,30 +110,125 @@ pub fn render_textfields_story(
                 .spacing(12.0)
                 .alignment(HorizontalAlignment::Leading)
                 .child(Text::new("Basic TextField:").foreground_color(Color::gray()))
-                .child(TextField::new(text_input).frame_width(300.0))
+                .child(
+                    TextField::new(&storybook.text_input)
+                        .on_change(cx.listener(|this: &mut Storybook, text: &str, _, cx| {
+                            this.greeting_name = text.trim().to_string();
+                            cx.notify();
+                        }))
+                        .frame_width(300.0),
+                )
+                .child(Text::new(greeting).foreground_color(Color::green()))
                 .child(
                     Text::new("TextField with cleanable (x button):")
                         .foreground_color(Color::gray()),
                 )
                 .child(
-                    TextField::new(text_input_cleanable)
+                    TextField::new(&storybook.text_input_cleanable)
+                        .prefix(Image::system_name("magnifyingglass"))
                         .cleanable(true)
                         .frame_width(300.0),
                 )
+                .child(Text::new("Prefix and suffix:").foreground_color(Color::gray()))
+                .child(
+                    TextField::new(&storybook.amount_input)
+                        .prefix(Text::new("$"))
+                        .suffix(Text::new("USD").foreground_color(Color::secondary_label()))
+                        .frame_width(300.0),
+                )
+                .child(Text::new("Validated email:").foreground_color(Color::gray()))
+                .child(
+                    TextField::new(&storybook.email_input)
+                        .success(email.contains('@'))
+                        .error(email_error)
+                        .on_change(cx.listener(|this: &mut Storybook, text: &str, _, cx| {
+                            this.email = text.to_string();
+                            cx.notify();
+                        }))
+                        .frame_width(300.0),
+                )
                 .padding(16.0)
                 .background(Color::tertiary_system_background())
                 .corner_radius(8.0),
         )
+        .child(Text::new(
+            "Focus - Move focus from code, Tab between fields:",
+        ))
+        .child(contact_form(storybook, cx))
         .child(Text::new("SecureField - Password input (masked):"))
         .child(
             VStack::new()
                 .spacing(12.0)
                 .alignment(HorizontalAlignment::Leading)
                 .child(
-                    SecureField::new(password_input)
-                        .show_toggle(true)
+                    SecureField::new(&storybook.password_input)
+                        .reveal_on_hold(true)
+                        .strength_meter(true)
+                        .frame_width(300.0),
+                )
+                .child(
+                    Text::new("Hold the eye icon to peek; the meter updates as you type")
+                        .foreground_color(Color::gray()),
+                )
+                .padding(16.0)
+                .background(Color::tertiary_system_background())
+                .corner_radius(8.0),
+        )
+        .child(Text::new("ComboBox - Text field with suggestions:"))
+        .child(
+            VStack::new()
+                .spacing(12.0)
+                .alignment(HorizontalAlignment::Leading)
+                .child(
+                    ComboBox::new(
+                        "fruit",
+                        &storybook.fruit_input,
+                        FRUITS.map(SharedString::from).to_vec(),
+                    )
+                    .max_visible(5)
+                    .on_select(
+                        cx.listener(|this: &mut Storybook, fruit: &SharedString, _, cx| {
+                            this.picked_fruit = Some(fruit.clone());
+                            cx.notify();
+                        }),
+                    )
+                    .frame_width(300.0),
+                )
+                .child(
+                    Text::new(match &storybook.picked_fruit {
+                        Some(fruit) => format!("Picked: {fruit}"),
+                        None => "Type to filter, arrows to move, Enter to pick".to_string(),
+                    })
+                    .foreground_color(Color::gray()),
+                )
+                .padding(16.0)
+                .background(Color::tertiary_system_background())
+                .corner_radius(8.0),
+        )
+        .child(Text::new("TagField - Tokens from typed text:"))
+        .child(
+            VStack::new()
+                .spacing(12.0)
+                .alignment(HorizontalAlignment::Leading)
+                .child(
+                    TagField::new("tags", &storybook.tag_input)
+                        .tags(storybook.tags.clone())
+                        .max_tags(5)
+                        .on_tags_change(cx.listener(
+                            |this: &mut Storybook, tags: &Vec<SharedString>, _, cx| {
+                                this.tags = tags.clone();
+                                cx.notify();
+                            },
+                        ))
                         .frame_width(300.0),
                 )
+                .child(
+                    Text::new(format!(
+                        "{} of 5 tags; Enter or comma adds, Backspace removes",
+                        storybook.tags.len()
+                    ))
+                    .foreground_color(Color::gray()),
+                )
                 .padding(16.0)
                 .background(Color::tertiary_system_background())
                 .corner_radius(8.0),
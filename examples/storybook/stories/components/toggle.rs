@@ -1,14 +1,11 @@
 //! Toggle story.
 //!
-//! Demonstrates boolean switch with label and state handling.
+//! Demonstrates boolean switch with label and state handling, and the
+//! switch, checkbox and button styles bound to the same value.
 //!
 //! ```rust,ignore
-//! Toggle::new_with_handler("Dark Mode", is_enabled,
-//!     cx.listener(|this, checked: &bool, _, cx| {
-//!         this.is_enabled = *checked;
-//!         cx.notify();
-//!     })
-//! )
+//! let view = cx.entity();
+//! Toggle::bound("Dark Mode", Binding::entity_field(&view, |this| &mut this.is_enabled))
 //! ```
 
 use allui::prelude::*;
@@ -18,6 +15,7 @@ use crate::Storybook;
 
 pub fn render_toggle_story(storybook: &Storybook, cx: &mut Context<Storybook>) -> impl IntoElement {
     let toggle_value = storybook.toggle_value;
+    let binding = Binding::entity_field(&cx.entity(), |this| &mut this.toggle_value);
 
     VStack::new()
         .spacing(16.0)
@@ -29,14 +27,7 @@ pub fn render_toggle_story(storybook: &Storybook, cx: &mut Context<Storybook>) -
                 .child(
                     HStack::new()
                         .spacing(12.0)
-                        .child(Toggle::new_with_handler(
-                            "Dark Mode",
-                            toggle_value,
-                            cx.listener(|this: &mut Storybook, checked: &bool, _window, cx| {
-                                this.toggle_value = *checked;
-                                cx.notify();
-                            }),
-                        ))
+                        .child(Toggle::bound("Dark Mode", binding.clone()))
                         .child(
                             Text::new(if toggle_value { "ON" } else { "OFF" }).foreground_color(
                                 if toggle_value {
@@ -52,8 +43,21 @@ pub fn render_toggle_story(storybook: &Storybook, cx: &mut Context<Storybook>) -
                 .background(Color::tertiary_system_background())
                 .corner_radius(8.0),
         )
+        .child(Text::new("Toggle styles - bound to the same value:"))
         .child(
-            Text::new("Note: Toggle wraps gpui-component's Switch.")
+            HStack::new()
+                .spacing(16.0)
+                .child(Toggle::bound("Switch", binding.clone()).toggle_style(ToggleStyle::Switch))
+                .child(
+                    Toggle::bound("Checkbox", binding.clone()).toggle_style(ToggleStyle::Checkbox),
+                )
+                .child(Toggle::bound("Button", binding.clone()).toggle_style(ToggleStyle::Button))
+                .padding(16.0)
+                .background(Color::tertiary_system_background())
+                .corner_radius(8.0),
+        )
+        .child(
+            Text::new("Note: Toggle wraps gpui-component's Switch and Checkbox.")
                 .foreground_color(Color::gray()),
         )
 }
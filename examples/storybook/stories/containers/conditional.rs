@@ -1,6 +1,8 @@
 //! Conditional rendering story.
 //!
-//! Demonstrates If and IfLet for conditional view rendering.
+//! Demonstrates If, IfLet and Switch for conditional view rendering,
+//! WithState for state kept by the view itself, and presenting sheets,
+//! full-screen covers, alerts, confirmation dialogs and popovers.
 //!
 //! ```rust,ignore
 //! If::new(is_logged_in)
@@ -8,13 +10,220 @@
 //!     .otherwise(|| LoginView::new())
 //!
 //! IfLet::new(selected_item, |item| Text::new(item.name))
+//!
+//! Switch::on(delivery)
+//!     .case(Delivery::Standard, || Text::new("3-5 business days"))
+//!     .default(|| Text::new("Pick up tomorrow"))
+//!
+//! WithState::new(None, |expanded: StateHandle<Option<usize>>, cx| ...)
+//!
+//! Button::new("Add", || {}).sheet(is_adding, dismiss, || AddForm::new())
+//!
+//! Button::new("Delete", || {}).confirmation_dialog(
+//!     is_confirming,
+//!     AlertConfig::new("Delete Item?")
+//!         .button(AlertButton::destructive("Delete", delete))
+//!         .button(AlertButton::cancel("Cancel", close)),
+//! )
 //! ```
 
 use allui::prelude::*;
-use gpui::{prelude::*, Context, SharedString};
+use gpui::{prelude::*, App, Context, SharedString, Window};
 
 use crate::Storybook;
 
+/// Sections that open one at a time, keeping which one is open itself.
+#[derive(IntoElement)]
+struct Accordion {
+    sections: Vec<(&'static str, &'static str)>,
+}
+
+impl Modifier for Accordion {}
+
+impl RenderOnce for Accordion {
+    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+        WithState::new(None, move |expanded: StateHandle<Option<usize>>, cx| {
+            let open = expanded.get(cx);
+            VStack::new()
+                .spacing(8.0)
+                .alignment(HorizontalAlignment::Leading)
+                .children(
+                    self.sections
+                        .into_iter()
+                        .enumerate()
+                        .map(|(idx, (title, body))| {
+                            let is_open = open == Some(idx);
+                            let expanded = expanded.clone();
+                            VStack::new()
+                                .spacing(4.0)
+                                .alignment(HorizontalAlignment::Leading)
+                                .child(
+                                    Text::new(format!(
+                                        "{} {}",
+                                        if is_open { "▾" } else { "▸" },
+                                        title
+                                    ))
+                                    .on_tap_gesture_with(
+                                        SharedString::from(format!("accordion-{}", idx)),
+                                        move |_, _, cx| {
+                                            expanded.set((!is_open).then_some(idx), cx);
+                                        },
+                                    ),
+                                )
+                                .child(If::new(is_open).then(|| {
+                                    Text::new(body).foreground_color(Color::secondary_label())
+                                }))
+                        }),
+                )
+        })
+    }
+}
+
+/// How an order is delivered, for the Switch example.
+#[derive(Clone, Copy, PartialEq)]
+enum Delivery {
+    Standard,
+    Express,
+    Pickup,
+}
+
+/// Delivery options, with details for the picked one shown by a Switch.
+fn delivery_options() -> impl IntoElement + Modifier {
+    WithState::new(Delivery::Standard, |delivery: StateHandle<Delivery>, cx| {
+        let picked = delivery.get(cx);
+        let options = [
+            (Delivery::Standard, "Standard"),
+            (Delivery::Express, "Express"),
+            (Delivery::Pickup, "Pickup"),
+        ];
+        VStack::new()
+            .spacing(12.0)
+            .alignment(HorizontalAlignment::Leading)
+            .child(
+                HStack::new()
+                    .spacing(8.0)
+                    .children(options.map(|(option, title)| {
+                        let delivery = delivery.clone();
+                        Button::with_id(format!("delivery-{title}"))
+                            .label(title)
+                            .button_style(if option == picked {
+                                ButtonStyle::BorderedProminent
+                            } else {
+                                ButtonStyle::Bordered
+                            })
+                            .on_click_with(move |_, _, cx| delivery.set(option, cx))
+                    })),
+            )
+            .child(
+                Switch::on(picked)
+                    .case(Delivery::Standard, || {
+                        Text::new("Arrives in 3-5 business days. Free.")
+                    })
+                    .case(Delivery::Express, || {
+                        HStack::new()
+                            .child(Text::new("Arrives tomorrow."))
+                            .child(Text::new("$9.99").foreground_color(Color::orange()))
+                    })
+                    .default(|| {
+                        Text::new("Pick up from the store after 2 PM.")
+                            .foreground_color(Color::secondary_label())
+                    }),
+            )
+    })
+}
+
+/// A handler that stops presenting the sheet and the cover.
+fn dismiss(cx: &mut Context<Storybook>) -> impl Fn(&mut Window, &mut App) + 'static {
+    let storybook = cx.entity().downgrade();
+    move |_, cx| {
+        storybook
+            .update(cx, |this, cx| {
+                this.presenting_sheet = false;
+                this.presenting_cover = false;
+                cx.notify();
+            })
+            .ok();
+    }
+}
+
+/// A handler that closes the delete dialog and the alert, showing `status`.
+fn resolve(
+    status: &'static str,
+    cx: &mut Context<Storybook>,
+) -> impl Fn(&mut Window, &mut App) + 'static {
+    let storybook = cx.entity().downgrade();
+    move |_, cx| {
+        storybook
+            .update(cx, |this, cx| {
+                this.confirming_delete = false;
+                this.showing_alert = false;
+                this.delete_status = status;
+                cx.notify();
+            })
+            .ok();
+    }
+}
+
+/// Filter toggles shown in a popover.
+fn filter_menu(storybook: &Storybook, cx: &mut Context<Storybook>) -> impl IntoElement {
+    VStack::new()
+        .spacing(8.0)
+        .alignment(HorizontalAlignment::Leading)
+        .child(Toggle::new_with_handler(
+            "Unread Only",
+            storybook.filter_unread,
+            cx.listener(|this: &mut Storybook, on: &bool, _, cx| {
+                this.filter_unread = *on;
+                cx.notify();
+            }),
+        ))
+        .child(Toggle::new_with_handler(
+            "Flagged Only",
+            storybook.filter_flagged,
+            cx.listener(|this: &mut Storybook, on: &bool, _, cx| {
+                this.filter_flagged = *on;
+                cx.notify();
+            }),
+        ))
+        .padding(12.0)
+}
+
+/// A new event form, with a Done button that dismisses it.
+fn event_form(storybook: &Storybook, cx: &mut Context<Storybook>) -> impl IntoElement {
+    VStack::new()
+        .child(
+            HStack::new()
+                .child(Text::new("New Event").font(Font::headline()))
+                .child(Spacer::new())
+                .child(
+                    Button::with_id("sheet-done")
+                        .label("Done")
+                        .on_click_with(cx.listener(|this: &mut Storybook, _, _, cx| {
+                            this.presenting_sheet = false;
+                            cx.notify();
+                        })),
+                )
+                .padding(16.0),
+        )
+        .child(
+            List::new("event-form")
+                .list_style(ListStyle::inset_grouped())
+                .section(
+                    Section::new()
+                        .row(TextField::new(&storybook.text_input))
+                        .toggle_row(
+                            "All Day",
+                            storybook.event_all_day,
+                            cx.listener(|this: &mut Storybook, on: &bool, _, cx| {
+                                this.event_all_day = *on;
+                                cx.notify();
+                            }),
+                        ),
+                )
+                .frame_height(160.0),
+        )
+}
+
 pub fn render_conditional_story(
     storybook: &Storybook,
     cx: &mut Context<Storybook>,
@@ -25,6 +234,15 @@ pub fn render_conditional_story(
     let entity2 = cx.entity().clone();
 
     let fruits = ["Apple", "Banana", "Cherry"];
+    // Built up front, as the listeners in it need the context
+    let form = event_form(storybook, cx);
+    let filters = filter_menu(storybook, cx);
+    let filter_summary = match (storybook.filter_unread, storybook.filter_flagged) {
+        (false, false) => "Showing all mail",
+        (true, false) => "Showing unread mail",
+        (false, true) => "Showing flagged mail",
+        (true, true) => "Showing unread, flagged mail",
+    };
 
     VStack::new()
         .spacing(16.0)
@@ -67,27 +285,28 @@ pub fn render_conditional_story(
                 .child(
                     HStack::new()
                         .spacing(8.0)
-                        .children(fruits.iter().enumerate().map(|(idx, fruit)| {
-                            let is_selected = selected_fruit == Some(idx);
-                            let entity = entity.clone();
-                            Text::new(*fruit)
-                                .padding(8.0)
-                                .background(if is_selected {
-                                    Color::blue()
-                                } else {
-                                    Color::secondary()
-                                })
-                                .corner_radius(4.0)
-                                .on_tap_gesture_with(
-                                    SharedString::from(format!("fruit-{}", idx)),
-                                    move |_, _, cx| {
+                        .children(ForEach::with_id(
+                            fruits.into_iter().enumerate(),
+                            |(_, fruit)| format!("fruit-{fruit}"),
+                            |id, &(idx, fruit)| {
+                                let is_selected = selected_fruit == Some(idx);
+                                let entity = entity.clone();
+                                Text::new(fruit)
+                                    .padding(8.0)
+                                    .background(if is_selected {
+                                        Color::blue()
+                                    } else {
+                                        Color::secondary()
+                                    })
+                                    .corner_radius(4.0)
+                                    .on_tap_gesture_with(id, move |_, _, cx| {
                                         entity.update(cx, |this, cx| {
                                             this.selected_fruit = Some(idx);
                                             cx.notify();
                                         });
-                                    },
-                                )
-                        }))
+                                    })
+                            },
+                        ))
                         .child(
                             Text::new("None")
                                 .padding(8.0)
@@ -125,4 +344,135 @@ pub fn render_conditional_story(
                 .background(Color::tertiary_system_background())
                 .corner_radius(8.0),
         )
+        .child(Text::new(
+            "Switch - One of several views picked by a value:",
+        ))
+        .child(
+            delivery_options()
+                .padding(16.0)
+                .background(Color::tertiary_system_background())
+                .corner_radius(8.0),
+        )
+        .child(Text::new("WithState - State kept by the view itself:"))
+        .child(
+            Accordion {
+                sections: vec![
+                    ("Shipping", "Orders ship within two business days."),
+                    ("Returns", "Return unused items within 30 days."),
+                    ("Warranty", "Devices are covered for one year."),
+                ],
+            }
+            .padding(16.0)
+            .background(Color::tertiary_system_background())
+            .corner_radius(8.0),
+        )
+        .child(Text::new("Sheet and full-screen cover:"))
+        .child(
+            HStack::new()
+                .spacing(12.0)
+                .child(
+                    Button::with_id("present-sheet")
+                        .label("Add Event")
+                        .on_click_with(cx.listener(|this: &mut Storybook, _, _, cx| {
+                            this.presenting_sheet = true;
+                            cx.notify();
+                        }))
+                        .sheet(storybook.presenting_sheet, dismiss(cx), move || form),
+                )
+                .child(
+                    Button::with_id("present-cover")
+                        .label("Full Screen")
+                        .on_click_with(cx.listener(|this: &mut Storybook, _, _, cx| {
+                            this.presenting_cover = true;
+                            cx.notify();
+                        }))
+                        .full_screen_cover(storybook.presenting_cover, dismiss(cx), || {
+                            VStack::new()
+                                .spacing(8.0)
+                                .child(Text::new("Full Screen Cover").font(Font::title()))
+                                .child(
+                                    Text::new("Press Escape to close")
+                                        .foreground_color(Color::secondary_label()),
+                                )
+                                .frame(Frame::fill())
+                        }),
+                )
+                .padding(16.0)
+                .background(Color::tertiary_system_background())
+                .corner_radius(8.0),
+        )
+        .child(Text::new("Alert and confirmation dialog:"))
+        .child(
+            HStack::new()
+                .spacing(12.0)
+                .child(
+                    Button::with_id("present-dialog")
+                        .label("Delete Item")
+                        .on_click_with(cx.listener(|this: &mut Storybook, _, _, cx| {
+                            this.confirming_delete = true;
+                            cx.notify();
+                        }))
+                        .confirmation_dialog(
+                            storybook.confirming_delete,
+                            AlertConfig::new("Delete Item?")
+                                .message("This item will be deleted from all your devices.")
+                                .button(AlertButton::destructive("Delete", resolve("Deleted", cx)))
+                                .button(AlertButton::cancel("Cancel", resolve("Kept", cx))),
+                        ),
+                )
+                .child(
+                    Button::with_id("present-alert")
+                        .label("Sign Out")
+                        .on_click_with(cx.listener(|this: &mut Storybook, _, _, cx| {
+                            this.showing_alert = true;
+                            cx.notify();
+                        }))
+                        .alert(
+                            storybook.showing_alert,
+                            AlertConfig::new("Sign Out?")
+                                .message("You can sign back in at any time.")
+                                .button(AlertButton::cancel("Cancel", resolve("Signed in", cx)))
+                                .button(AlertButton::new("Sign Out", resolve("Signed out", cx))),
+                        ),
+                )
+                .child(
+                    Text::new(storybook.delete_status).foreground_color(Color::secondary_label()),
+                )
+                .padding(16.0)
+                .background(Color::tertiary_system_background())
+                .corner_radius(8.0),
+        )
+        .child(Text::new("Popover:"))
+        .child(
+            HStack::new()
+                .spacing(12.0)
+                .child(
+                    Button::with_id("present-popover")
+                        .label("Filter")
+                        .on_click_with(cx.listener(|this: &mut Storybook, _, _, cx| {
+                            this.showing_filters = true;
+                            cx.notify();
+                        }))
+                        .popover(
+                            storybook.showing_filters,
+                            Alignment::bottom(),
+                            {
+                                let storybook = cx.entity().downgrade();
+                                move |_, cx| {
+                                    storybook
+                                        .update(cx, |this, cx| {
+                                            this.showing_filters = false;
+                                            cx.notify();
+                                        })
+                                        .ok();
+                                }
+                            },
+                            move || filters,
+                        ),
+                )
+                .child(Text::new(filter_summary).foreground_color(Color::secondary_label()))
+                .padding(16.0)
+                .background(Color::tertiary_system_background())
+                .corner_radius(8.0),
+        )
 }
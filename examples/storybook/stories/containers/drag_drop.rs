@@ -0,0 +1,180 @@
+//! Drag and drop story.
+//!
+//! Demonstrates a two-column board whose cards are dragged between columns
+//! with draggable and on_drop, highlighting the column under a dragged card,
+//! and a drop zone for image files dragged in with on_file_drop.
+//!
+//! ```rust,ignore
+//! CardView::new(title)
+//!     .draggable(BoardCard(title))
+//!     .drag_preview(move || CardView::new(title))
+//!     .id(title)
+//!
+//! column
+//!     .on_drop(cx.listener(|this, card: &BoardCard, _, cx| { ... }))
+//!     .on_drag_over(move |is_over, _, cx| { ... })
+//! ```
+
+use std::path::{Path, PathBuf};
+
+use allui::prelude::*;
+use gpui::{prelude::*, Context, SharedString};
+
+use crate::Storybook;
+
+/// The payload of a dragged card: its title.
+#[derive(Clone, Copy)]
+struct BoardCard(&'static str);
+
+/// A card on the board, also shown under the pointer while dragging.
+fn card_view(title: &'static str) -> impl IntoElement + Modifier {
+    Text::new(title)
+        .padding(12.0)
+        .frame(Frame::fill_width())
+        .background(Color::secondary_system_background())
+        .corner_radius(8.0)
+}
+
+/// Column `index` of the board, titled `title`, which cards are dropped on.
+fn column(
+    index: usize,
+    title: &'static str,
+    cards: &[&'static str],
+    storybook: &Storybook,
+    cx: &mut Context<Storybook>,
+) -> impl IntoElement {
+    let highlighted = storybook.drop_column == Some(index);
+    let entity = cx.entity().downgrade();
+
+    VStack::new()
+        .spacing(8.0)
+        .alignment(HorizontalAlignment::Leading)
+        .child(Text::new(format!("{} ({})", title, cards.len())).font(Font::headline()))
+        .children(cards.iter().map(|&title| {
+            card_view(title)
+                .draggable(BoardCard(title))
+                .drag_preview(move || card_view(title).frame_width(220.0).opacity(0.8))
+                .id(SharedString::from(title))
+        }))
+        .padding(12.0)
+        .frame(
+            Frame::width(240.0)
+                .min_height(240.0)
+                .alignment(Alignment::top()),
+        )
+        .background(if highlighted {
+            Color::accent().opacity(0.15)
+        } else {
+            Color::tertiary_system_background()
+        })
+        .corner_radius(12.0)
+        .on_drop(cx.listener(move |this, card: &BoardCard, _, cx| {
+            this.todo_cards.retain(|title| *title != card.0);
+            this.done_cards.retain(|title| *title != card.0);
+            let cards = if index == 0 {
+                &mut this.todo_cards
+            } else {
+                &mut this.done_cards
+            };
+            cards.push(card.0);
+            cx.notify();
+        }))
+        .on_drag_over(move |is_over, _, cx| {
+            entity
+                .update(cx, |this, cx| {
+                    if is_over {
+                        this.drop_column = Some(index);
+                    } else if this.drop_column == Some(index) {
+                        this.drop_column = None;
+                    }
+                    cx.notify();
+                })
+                .ok();
+        })
+        .id(("board-column", index))
+}
+
+pub fn render_drag_drop_story(
+    storybook: &Storybook,
+    cx: &mut Context<Storybook>,
+) -> impl IntoElement {
+    let todo = storybook.todo_cards.clone();
+    let done = storybook.done_cards.clone();
+
+    VStack::new()
+        .spacing(16.0)
+        .alignment(HorizontalAlignment::Leading)
+        .child(Text::new("Drag cards between the columns:"))
+        .child(
+            HStack::new()
+                .spacing(16.0)
+                .alignment(VerticalAlignment::Top)
+                .child(column(0, "To Do", &todo, storybook, cx))
+                .child(column(1, "Done", &done, storybook, cx)),
+        )
+        .child(
+            Text::new("Columns only accept cards; other drags pass over them.")
+                .foreground_color(Color::gray()),
+        )
+        .child(Text::new("Drop an image file from your file manager:"))
+        .child(image_drop_zone(storybook, cx))
+}
+
+/// Whether `path` names an image file, by its extension.
+fn is_image(path: &Path) -> bool {
+    path.extension()
+        .and_then(|extension| extension.to_str())
+        .is_some_and(|extension| {
+            ["png", "jpg", "jpeg", "gif", "webp", "svg"]
+                .contains(&extension.to_ascii_lowercase().as_str())
+        })
+}
+
+/// A dashed drop zone showing the first image dropped on it.
+fn image_drop_zone(storybook: &Storybook, cx: &mut Context<Storybook>) -> impl IntoElement {
+    let dropped = cx.entity().downgrade();
+    let over = dropped.clone();
+    let content = match &storybook.dropped_image {
+        Some(path) => VStack::new()
+            .spacing(8.0)
+            .child(Image::new(path.to_string_lossy()))
+            .child(Text::new(
+                path.file_name()
+                    .map(|name| name.to_string_lossy().into_owned())
+                    .unwrap_or_default(),
+            ))
+            .into_any_element(),
+        None => Text::new("No image yet")
+            .foreground_color(Color::gray())
+            .into_any_element(),
+    };
+    let border = if storybook.files_over_drop_zone {
+        Color::accent()
+    } else {
+        Color::gray()
+    };
+
+    VStack::new()
+        .child(content)
+        .frame(Frame::width(496.0).min_height(160.0))
+        .border(border, 2.0, BorderStyle::Dashed)
+        .corner_radius(12.0)
+        .on_file_drop(move |paths: Vec<PathBuf>, _, cx| {
+            let Some(image) = paths.into_iter().find(|path| is_image(path)) else {
+                return;
+            };
+            dropped
+                .update(cx, |this, cx| {
+                    this.dropped_image = Some(image);
+                    cx.notify();
+                })
+                .ok();
+        })
+        .on_drag_over(move |is_over, _, cx| {
+            over.update(cx, |this, cx| {
+                this.files_over_drop_zone = is_over;
+                cx.notify();
+            })
+            .ok();
+        })
+}
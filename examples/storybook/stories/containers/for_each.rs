@@ -1,12 +1,15 @@
 //! ForEach story.
 //!
-//! Demonstrates iterating over collections to generate views.
+//! Demonstrates iterating over collections to generate views, with indices
+//! and without cloning the items.
 //!
 //! ```rust,ignore
 //! let items = vec!["Apple", "Banana", "Cherry"];
 //! VStack::new().children(ForEach::new(items, |item| {
 //!     Text::new(*item)
 //! }))
+//! ForEach::enumerated(items, |index, item| Text::new(format!("{}. {}", index + 1, item)))
+//! ForEach::from_slice(&self.items, |item| Text::new(item.name.clone()))
 //! ```
 
 use allui::prelude::*;
@@ -15,6 +18,7 @@ use gpui::{div, prelude::*, px, rgb};
 pub fn render_foreach_story() -> impl IntoElement {
     let fruits = vec!["Apple", "Banana", "Cherry", "Date", "Elderberry"];
     let numbers = vec![1, 2, 3, 4, 5];
+    let podium = ["Ada", "Grace", "Alan"];
 
     VStack::new()
         .spacing(16.0)
@@ -62,6 +66,37 @@ pub fn render_foreach_story() -> impl IntoElement {
                                 .background(Color::tertiary_system_background())
                                 .corner_radius(8.0),
                         ),
+                )
+                .child(
+                    VStack::new()
+                        .spacing(8.0)
+                        .alignment(HorizontalAlignment::Leading)
+                        .child(Text::new("With indices:").foreground_color(Color::gray()))
+                        .child(
+                            VStack::new()
+                                .spacing(4.0)
+                                .alignment(HorizontalAlignment::Leading)
+                                .children(ForEach::enumerated(podium, |index, name| {
+                                    Text::new(format!("{}. {}", index + 1, name))
+                                }))
+                                .padding(16.0)
+                                .background(Color::tertiary_system_background())
+                                .corner_radius(8.0),
+                        )
+                        .child(Text::new("Borrowed slice:").foreground_color(Color::gray()))
+                        .child(
+                            HStack::new()
+                                .spacing(4.0)
+                                .children(ForEach::from_slice(&podium, |name| {
+                                    Text::new(name.chars().take(1).collect::<String>())
+                                        .padding(8.0)
+                                        .background(Color::accent())
+                                        .corner_radius(4.0)
+                                }))
+                                .padding(16.0)
+                                .background(Color::tertiary_system_background())
+                                .corner_radius(8.0),
+                        ),
                 ),
         )
         .child(
@@ -0,0 +1,157 @@
+//! LazyVStack story.
+//!
+//! Demonstrates a virtualized chat transcript where every message has its
+//! own height, so the scrollbar matches the real content length, and
+//! jumping to an item in a 10,000 item list.
+//!
+//! ```rust,ignore
+//! LazyVStack::new(cx.entity().clone(), "chat", &scroll_handle)
+//!     .item_count(messages.len())
+//!     .item_size_for(move |ix| heights[ix])
+//!     .render_item(|view, ix, _, _| { ... })
+//!     .build(window, cx)
+//!
+//! scroll_handle.scroll_to(5_000, ScrollAnchor::Center);
+//! ```
+
+use allui::prelude::*;
+use gpui::{prelude::*, Context, Window};
+
+use crate::Storybook;
+
+const PHRASES: [&str; 6] = [
+    "Sounds good!",
+    "Did you see the new build?",
+    "I pushed the layout fixes, the grid columns no longer stretch when a divider spans them.",
+    "Lunch?",
+    "The virtual list now sizes every row on its own, so long messages like this one take \
+     the space they need and short ones stay compact. Scroll down and watch the scrollbar: \
+     it should track the transcript exactly instead of guessing from a uniform row height.",
+    "Let's ship it after review.",
+];
+
+const MESSAGE_COUNT: usize = 300;
+const BUBBLE_WIDTH: f32 = 240.0;
+
+/// The text of message `ix`, picked by a fixed stride so the story is
+/// stable.
+fn message(ix: usize) -> &'static str {
+    PHRASES[(ix * 5 + ix / 4) % PHRASES.len()]
+}
+
+/// The height of message `ix`'s bubble, from 40 to 200pt.
+fn bubble_height(ix: usize) -> f32 {
+    // Roughly 34 characters fit on a 15pt line in the bubble
+    let lines = message(ix).len().div_ceil(34) as f32;
+    (16.0 + lines * 20.0).clamp(40.0, 200.0)
+}
+
+pub fn render_lazy_vstack_story(
+    storybook: &Storybook,
+    window: &mut Window,
+    cx: &mut Context<Storybook>,
+) -> impl IntoElement {
+    let total: f32 = (0..MESSAGE_COUNT).map(|ix| bubble_height(ix) + 8.0).sum();
+
+    let transcript = LazyVStack::new(
+        cx.entity().clone(),
+        "chat-transcript",
+        &storybook.chat_scroll,
+    )
+    .item_count(MESSAGE_COUNT)
+    .item_size_for(bubble_height)
+    .spacing(8.0)
+    .render_item(|_, ix, _, _| {
+        let outgoing = ix % 3 == 0;
+        let bubble = Text::new(message(ix))
+            .foreground_color(if outgoing {
+                Color::white()
+            } else {
+                Color::label()
+            })
+            .padding_edges(8.0, 12.0, 8.0, 12.0)
+            .frame(Frame::size(BUBBLE_WIDTH, bubble_height(ix)).alignment(Alignment::top_leading()))
+            .background(if outgoing {
+                Color::blue()
+            } else {
+                Color::secondary_system_background()
+            })
+            .corner_radius(16.0);
+        bubble
+            .frame(Frame::fill_width().alignment(if outgoing {
+                Alignment::top_trailing()
+            } else {
+                Alignment::top_leading()
+            }))
+            .padding_edges(0.0, 12.0, 0.0, 12.0)
+    })
+    .build(window, cx);
+
+    let numbers = LazyVStack::new(
+        cx.entity().clone(),
+        "numbers-list",
+        &storybook.numbers_scroll,
+    )
+    .item_count(10_000)
+    .item_height(32.0)
+    .alignment(HorizontalAlignment::Trailing)
+    .on_scroll(cx.listener(|this, info: &ScrollInfo, _, cx| {
+        this.visible_numbers = Some(*info);
+        cx.notify();
+    }))
+    .render_item(|_, ix, _, _| Text::new(format!("Item {ix}")).padding_edges(6.0, 12.0, 6.0, 12.0))
+    .build(window, cx);
+
+    let handle = storybook.numbers_scroll.clone();
+    let jump = Button::new("Jump to item 5,000", || {}).on_click_with(move |_, window, _| {
+        handle.scroll_to(5_000, ScrollAnchor::Center);
+        window.refresh();
+    });
+    let visible = match storybook.visible_numbers {
+        Some(info) => format!(
+            "Showing items {} to {}, {:.0}% scrolled",
+            info.first_visible,
+            info.last_visible,
+            info.offset_fraction * 100.0
+        ),
+        None => "Scroll the list".to_string(),
+    };
+
+    VStack::new()
+        .spacing(16.0)
+        .alignment(HorizontalAlignment::Leading)
+        .child(Text::new(
+            "LazyVStack - A chat transcript with per-message heights:",
+        ))
+        .child(
+            // The list fills the frame it is given
+            ZStack::new()
+                .child(transcript)
+                .frame(Frame::size(360.0, 400.0))
+                .background(Color::tertiary_system_background())
+                .corner_radius(8.0),
+        )
+        .child(
+            Text::new(format!(
+                "{MESSAGE_COUNT} messages from 40 to 200pt, {total:.0}pt of content. \
+                 Only the visible bubbles are rendered."
+            ))
+            .foreground_color(Color::gray()),
+        )
+        .child(Text::new(
+            "scroll_to - Jump a 10,000 item list with trailing-aligned rows, and follow it with \
+             on_scroll:",
+        ))
+        .child(
+            HStack::new()
+                .child(jump)
+                .child(Text::new(visible).foreground_color(Color::gray())),
+        )
+        .child(
+            ZStack::new()
+                .child(numbers)
+                .frame(Frame::size(360.0, 240.0))
+                .background(Color::tertiary_system_background())
+                .corner_radius(8.0),
+        )
+}
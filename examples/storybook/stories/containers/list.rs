@@ -1,6 +1,9 @@
 //! List story.
 //!
-//! Demonstrates iOS-style grouped lists with sections, headers, and footers.
+//! Demonstrates iOS-style grouped lists with sections, headers, and footers,
+//! navigation and button rows, a settings form, pull to refresh,
+//! swipe actions, deleting and reordering rows, pinned section headers,
+//! empty and loading states, and an expandable file tree.
 //!
 //! ```rust,ignore
 //! List::new("settings")
@@ -11,10 +14,350 @@
 //!         .footer("Manage your account"))
 //! ```
 
+use std::collections::HashSet;
+use std::time::Duration;
+
 use allui::prelude::*;
-use gpui::prelude::*;
+use gpui::{prelude::*, App, ClickEvent, Context, Window};
+
+use crate::Storybook;
+
+/// A list that gains a message 1.5s after each pull to refresh.
+fn inbox(count: usize, cx: &mut Context<Storybook>) -> impl IntoElement {
+    let storybook = cx.entity().downgrade();
+    List::new("inbox-list")
+        .list_style(ListStyle::plain())
+        .refreshable(move |completion, _, cx| {
+            let storybook = storybook.clone();
+            cx.spawn(async move |cx| {
+                cx.background_executor()
+                    .timer(Duration::from_millis(1500))
+                    .await;
+                storybook
+                    .update(cx, |this, cx| {
+                        this.inbox_count += 1;
+                        completion.finish(cx);
+                        cx.notify();
+                    })
+                    .ok();
+            })
+            .detach();
+        })
+        .section(
+            Section::new()
+                .footer("Pull down past the top to check for new mail")
+                .rows(
+                    (1..=count)
+                        .rev()
+                        .map(|ix| Text::new(format!("Message {ix}"))),
+                ),
+        )
+        .frame(Frame::size(200.0, 200.0))
+        .background(Color::system_background())
+        .corner_radius(8.0)
+}
+
+/// A mailbox whose rows swipe open to archive or delete a message.
+fn mailbox(storybook: &Storybook, cx: &mut Context<Storybook>) -> impl IntoElement {
+    let entity = cx.entity().downgrade();
+    let rows = storybook.mailbox.iter().enumerate().map(|(ix, subject)| {
+        let (delete, archive) = (entity.clone(), entity.clone());
+        (
+            Text::new(*subject),
+            vec![
+                RowAction::new("Delete", move |_, cx| {
+                    delete
+                        .update(cx, |this, cx| {
+                            this.mailbox.remove(ix);
+                            cx.notify();
+                        })
+                        .ok();
+                })
+                .system_image("trash")
+                .role(ButtonRole::Destructive),
+                RowAction::new("Archive", move |_, cx| {
+                    archive
+                        .update(cx, |this, cx| {
+                            this.mailbox.remove(ix);
+                            this.archived += 1;
+                            cx.notify();
+                        })
+                        .ok();
+                })
+                .system_image("archivebox")
+                .tint(Color::indigo()),
+            ],
+        )
+    });
+    let section = rows.fold(
+        Section::new().allows_full_swipe(true).footer(format!(
+            "Swipe left, or hover, for actions. {} archived.",
+            storybook.archived
+        )),
+        |section, (row, actions)| section.row_with_actions(row, actions),
+    );
+
+    List::new("mailbox-list")
+        .list_style(ListStyle::inset_grouped())
+        .section(section)
+        .frame(Frame::size(320.0, 320.0))
+        .background(Color::system_background())
+        .corner_radius(12.0)
+}
+
+/// A grocery list that can be reordered and trimmed in edit mode.
+fn groceries(storybook: &Storybook, cx: &mut Context<Storybook>) -> impl IntoElement {
+    let editing = storybook.editing_groceries;
+    let entity = cx.entity().downgrade();
+
+    VStack::new()
+        .spacing(8.0)
+        .alignment(HorizontalAlignment::Leading)
+        .child(
+            Button::with_id("edit-groceries")
+                .label(if editing { "Done" } else { "Edit" })
+                .on_click_with(cx.listener(|this: &mut Storybook, _, _, cx| {
+                    this.editing_groceries = !this.editing_groceries;
+                    cx.notify();
+                })),
+        )
+        .child(
+            List::new("grocery-list")
+                .list_style(ListStyle::inset_grouped())
+                .edit_mode(if editing {
+                    EditMode::Active
+                } else {
+                    EditMode::Inactive
+                })
+                .on_move(move |from, to, _, cx| {
+                    entity
+                        .update(cx, |this, cx| {
+                            let item = this.groceries.remove(from);
+                            this.groceries.insert(to, item);
+                            cx.notify();
+                        })
+                        .ok();
+                })
+                .section(
+                    Section::new()
+                        .footer("Edit to reorder by the grips, or swipe to delete")
+                        .on_delete(cx.listener(|this, ix: &usize, _, cx| {
+                            this.groceries.remove(*ix);
+                            cx.notify();
+                        }))
+                        .rows(storybook.groceries.iter().map(|item| Text::new(*item))),
+                )
+                .frame(Frame::size(280.0, 320.0))
+                .background(Color::system_background())
+                .corner_radius(12.0),
+        )
+}
+
+const CONTACTS: &[&str] = &[
+    "Aaron", "Abigail", "Adam", "Alice", "Amelia", "Ben", "Bella", "Bruno", "Carla", "Chen",
+    "Chloe", "Daniel", "Dana", "Diego", "Elena", "Emma", "Ethan", "Fatima", "Felix", "Grace",
+    "Hana", "Hugo", "Isla", "Ivan", "Jack", "Julia", "Kai", "Lena", "Liam", "Maya", "Noah",
+    "Olivia", "Omar", "Priya", "Quinn", "Rosa", "Sam", "Sofia", "Theo", "Uma", "Victor", "Wen",
+    "Yara", "Zoe",
+];
+
+/// A plain contact list grouped by initial, whose headers pin while scrolling,
+/// with buttons to clear it and to show it loading.
+fn contacts(storybook: &Storybook, cx: &mut Context<Storybook>) -> impl IntoElement {
+    let cleared = storybook.contacts_cleared;
+    let loading = storybook.contacts_loading;
+    let mut sections: Vec<(char, Vec<&str>)> = Vec::new();
+    for name in CONTACTS {
+        let initial = name.chars().next().unwrap_or('#');
+        match sections.last_mut() {
+            Some((letter, names)) if *letter == initial => names.push(name),
+            _ => sections.push((initial, vec![name])),
+        }
+    }
+    if cleared {
+        // Empty sections are hidden, leaving the empty view
+        sections.iter_mut().for_each(|(_, names)| names.clear());
+    }
+
+    VStack::new()
+        .spacing(8.0)
+        .alignment(HorizontalAlignment::Leading)
+        .child(
+            HStack::new()
+                .spacing(8.0)
+                .child(
+                    Button::with_id("clear-contacts")
+                        .label(if cleared { "Restore" } else { "Clear" })
+                        .on_click_with(cx.listener(|this: &mut Storybook, _, _, cx| {
+                            this.contacts_cleared = !this.contacts_cleared;
+                            cx.notify();
+                        })),
+                )
+                .child(
+                    Button::with_id("load-contacts")
+                        .label(if loading { "Loaded" } else { "Loading" })
+                        .on_click_with(cx.listener(|this: &mut Storybook, _, _, cx| {
+                            this.contacts_loading = !this.contacts_loading;
+                            cx.notify();
+                        })),
+                ),
+        )
+        .child(
+            List::new("contacts-list")
+                .list_style(ListStyle::plain())
+                .loading(loading)
+                .empty_view(
+                    VStack::new()
+                        .spacing(4.0)
+                        .child(Text::new("No Contacts").font(Font::headline()))
+                        .child(
+                            Text::new("Contacts you add appear here")
+                                .foreground_color(Color::secondary_label()),
+                        ),
+                )
+                .sections(sections.into_iter().map(|(letter, names)| {
+                    Section::new()
+                        .header(letter.to_string())
+                        .rows(names.into_iter().map(Text::new))
+                }))
+                .frame(Frame::size(240.0, 320.0))
+                .background(Color::system_background())
+                .corner_radius(8.0),
+        )
+}
+
+/// A file or folder of the project tree.
+struct FileNode {
+    name: &'static str,
+    children: &'static [FileNode],
+}
+
+const fn file(name: &'static str) -> FileNode {
+    FileNode {
+        name,
+        children: &[],
+    }
+}
+
+const PROJECT: &[FileNode] = &[
+    FileNode {
+        name: "src",
+        children: &[
+            FileNode {
+                name: "components",
+                children: &[file("button.rs"), file("text.rs"), file("toggle.rs")],
+            },
+            FileNode {
+                name: "layout",
+                children: &[file("list.rs"), file("outline_group.rs"), file("vstack.rs")],
+            },
+            file("lib.rs"),
+        ],
+    },
+    FileNode {
+        name: "examples",
+        children: &[FileNode {
+            name: "storybook",
+            children: &[file("main.rs"), file("sidebar.rs")],
+        }],
+    },
+    file("Cargo.toml"),
+    file("README.md"),
+];
+
+/// The project tree as rows of a selectable sidebar list, whose folders
+/// expand and collapse.
+fn project_files(storybook: &Storybook, cx: &mut Context<Storybook>) -> impl IntoElement {
+    let tree = OutlineGroup::new(
+        "project-files",
+        PROJECT,
+        |node: &&FileNode| (!node.children.is_empty()).then(|| node.children.iter().collect()),
+        |node| {
+            let symbol = if node.children.is_empty() {
+                "doc"
+            } else {
+                "folder"
+            };
+            Label::new(symbol, node.name)
+        },
+    )
+    .node_id(|node| RowId::from(node.name))
+    .state(&storybook.project_tree);
+
+    List::new("project-list")
+        .list_style(ListStyle::sidebar())
+        .min_row_height(28.0)
+        .list_row_insets(EdgeInsets::init(0.0, 8.0, 0.0, 8.0))
+        .selectable(SelectionMode::Single)
+        .selection(&storybook.selected_files)
+        .on_selection_change(cx.listener(|this, selection: &HashSet<RowId>, _, cx| {
+            this.selected_files = selection.clone();
+            cx.notify();
+        }))
+        .section(tree.into_section(cx).header("Project"))
+        .frame(Frame::size(260.0, 320.0))
+        .corner_radius(8.0)
+}
+
+/// A settings form: toggles and a text field, a collapsible group and
+/// read-only details.
+fn settings_form(storybook: &Storybook, cx: &mut Context<Storybook>) -> impl IntoElement {
+    let airplane_mode = storybook.airplane_mode;
+    Form::new()
+        .id("settings-form")
+        .section(
+            Section::new()
+                .header("Connectivity")
+                .footer("Tap anywhere on a row to toggle it")
+                .row(Toggle::new_with_handler(
+                    "Airplane Mode",
+                    airplane_mode,
+                    cx.listener(|this: &mut Storybook, on: &bool, _, cx| {
+                        this.airplane_mode = *on;
+                        cx.notify();
+                    }),
+                ))
+                .row(Toggle::new("Bluetooth", true, |_| {}).disabled(airplane_mode))
+                .row(DisclosureGroup::new(
+                    "advanced-settings",
+                    Text::new("Advanced"),
+                    VStack::new()
+                        .spacing(8.0)
+                        .child(Toggle::new("Debug Logging", false, |_| {}))
+                        .child(Toggle::new("Beta Features", true, |_| {})),
+                )),
+        )
+        .section(
+            Section::new()
+                .header("Device")
+                .row(TextField::new(&storybook.device_name))
+                .row(LabeledContent::new("Version", Text::new("1.2.0")))
+                .row(LabeledContent::new("Storage", Text::new("42 GB free"))),
+        )
+        .frame(Frame::size(280.0, 420.0))
+        .background(Color::system_background())
+        .corner_radius(12.0)
+}
+
+/// A handler that records `page` as the settings page opened.
+fn open_page(
+    page: &'static str,
+    cx: &mut Context<Storybook>,
+) -> impl Fn(&ClickEvent, &mut Window, &mut App) + 'static {
+    let storybook = cx.entity().downgrade();
+    move |_, _, cx| {
+        storybook
+            .update(cx, |this, cx| {
+                this.settings_page = Some(page);
+                cx.notify();
+            })
+            .ok();
+    }
+}
+
+pub fn render_list_story(storybook: &Storybook, cx: &mut Context<Storybook>) -> impl IntoElement {
+    let inbox_count = storybook.inbox_count;
 
-pub fn render_list_story() -> impl IntoElement {
     VStack::new()
         .spacing(16.0)
         .alignment(HorizontalAlignment::Leading)
@@ -33,9 +376,26 @@ pub fn render_list_story() -> impl IntoElement {
                                 .section(
                                     Section::new()
                                         .header("Account")
-                                        .row(Text::new("Profile"))
-                                        .row(Text::new("Privacy"))
-                                        .row(Text::new("Security")),
+                                        .footer(match storybook.settings_page {
+                                            Some(page) => format!("Opened {page}"),
+                                            None => "Tap a row to open it".to_string(),
+                                        })
+                                        .navigation_row(
+                                            "Profile",
+                                            Some("Jane Appleseed".into()),
+                                            open_page("Profile", cx),
+                                        )
+                                        .navigation_row("Privacy", None, open_page("Privacy", cx))
+                                        .navigation_row(
+                                            "Security",
+                                            Some("On".into()),
+                                            open_page("Security", cx),
+                                        )
+                                        .button_row(
+                                            "Sign Out",
+                                            Some(ButtonRole::Destructive),
+                                            open_page("Sign Out", cx),
+                                        ),
                                 )
                                 .section(
                                     Section::new()
@@ -67,6 +427,54 @@ pub fn render_list_story() -> impl IntoElement {
                                 .background(Color::system_background())
                                 .corner_radius(8.0),
                         ),
+                )
+                .child(
+                    VStack::new()
+                        .spacing(8.0)
+                        .alignment(HorizontalAlignment::Leading)
+                        .child(Text::new("Form:").foreground_color(Color::gray()))
+                        .child(settings_form(storybook, cx))
+                        .child(GroupBox::new(
+                            Label::new("info", "About"),
+                            Text::new("Settings are saved as you change them.")
+                                .foreground_color(Color::secondary_label()),
+                        )),
+                )
+                .child(
+                    VStack::new()
+                        .spacing(8.0)
+                        .child(Text::new("Pull to refresh:").foreground_color(Color::gray()))
+                        .child(inbox(inbox_count, cx)),
                 ),
         )
+        .child(
+            VStack::new()
+                .spacing(8.0)
+                .alignment(HorizontalAlignment::Leading)
+                .child(Text::new("Swipe actions:").foreground_color(Color::gray()))
+                .child(mailbox(storybook, cx)),
+        )
+        .child(
+            VStack::new()
+                .spacing(8.0)
+                .alignment(HorizontalAlignment::Leading)
+                .child(Text::new("Deleting and reordering:").foreground_color(Color::gray()))
+                .child(groceries(storybook, cx)),
+        )
+        .child(
+            VStack::new()
+                .spacing(8.0)
+                .alignment(HorizontalAlignment::Leading)
+                .child(
+                    Text::new("Pinned headers, empty and loading:").foreground_color(Color::gray()),
+                )
+                .child(contacts(storybook, cx)),
+        )
+        .child(
+            VStack::new()
+                .spacing(8.0)
+                .alignment(HorizontalAlignment::Leading)
+                .child(Text::new("Outline:").foreground_color(Color::gray()))
+                .child(project_files(storybook, cx)),
+        )
 }
@@ -1,13 +1,22 @@
-//! Container story modules - ScrollView, List, ForEach, Conditional.
+//! Container story modules - ScrollView, LazyVStack, List, NavigationStack, ForEach,
+//! Conditional, drag and drop, windows.
 
 mod conditional;
+mod drag_drop;
 mod for_each;
+mod lazy_vstack;
 mod list;
 mod list_config;
+mod navigation_stack;
 mod scroll_view;
+mod windows;
 
 pub use conditional::*;
+pub use drag_drop::*;
 pub use for_each::*;
+pub use lazy_vstack::*;
 pub use list::*;
 pub use list_config::*;
+pub use navigation_stack::*;
 pub use scroll_view::*;
+pub use windows::*;
@@ -0,0 +1,122 @@
+//! NavigationStack story.
+//!
+//! Demonstrates drilling down from categories to items to an item's
+//! details, with a back button titled after the previous screen. The
+//! details screen adds a "Share" button to the bar with `.toolbar()`.
+//!
+//! ```rust,ignore
+//! NavigationStack::new(
+//!     &self.path,
+//!     List::new("categories").section(
+//!         Section::new().navigation_link(&self.path, "Fruit", |_, _| fruit_list()),
+//!     ),
+//! )
+//! .navigation_title("Categories")
+//! ```
+
+use allui::prelude::*;
+use gpui::{prelude::*, Context, Entity};
+
+use crate::Storybook;
+
+/// Categories, their items, and a description of each item.
+const CATEGORIES: &[(&str, &[(&str, &str)])] = &[
+    (
+        "Fruit",
+        &[
+            ("Apple", "Crisp and sweet, picked in autumn."),
+            ("Banana", "Soft and sweet, best with a few brown spots."),
+            ("Cherry", "Small and dark, with a single stone."),
+        ],
+    ),
+    (
+        "Vegetables",
+        &[
+            ("Carrot", "Crunchy, and sweeter once roasted."),
+            ("Leek", "A mild onion, good in soups."),
+        ],
+    ),
+    (
+        "Grains",
+        &[
+            ("Barley", "Chewy, and the base of many stews."),
+            ("Oats", "Rolled for porridge or baked into bars."),
+            ("Rice", "Long or short grained, steamed or fried."),
+        ],
+    ),
+];
+
+/// The items of a category, each leading to its details.
+fn items(path: &Entity<NavigationPath>, items: &'static [(&str, &str)]) -> impl IntoElement {
+    List::new("navigation-items")
+        .list_style(ListStyle::inset_grouped())
+        .section(
+            items
+                .iter()
+                .fold(Section::new(), |section, &(name, description)| {
+                    section.navigation_link(path, name, move |_, _| detail(name, description))
+                }),
+        )
+}
+
+/// The details of an item.
+fn detail(name: &'static str, description: &'static str) -> impl IntoElement {
+    VStack::new()
+        .spacing(8.0)
+        .alignment(HorizontalAlignment::Leading)
+        .child(Text::new(name).font(Font::title()))
+        .child(Text::new(description).foreground_color(Color::secondary_label()))
+        .padding(16.0)
+        .toolbar(
+            Toolbar::new().trailing(
+                Button::with_id("share-item")
+                    .label("Share")
+                    .button_style(ButtonStyle::Borderless)
+                    .on_click_with(move |_, _, cx| {
+                        ToastCenter::show(cx, Toast::new(format!("Shared {name}")));
+                    }),
+            ),
+        )
+}
+
+pub fn render_navigation_stack_story(
+    storybook: &Storybook,
+    cx: &mut Context<Storybook>,
+) -> impl IntoElement {
+    let path = &storybook.navigation_path;
+    let categories = List::new("navigation-categories")
+        .list_style(ListStyle::inset_grouped())
+        .section(
+            CATEGORIES
+                .iter()
+                .fold(Section::new(), |section, &(name, category)| {
+                    let path_for_items = path.clone();
+                    section
+                        .navigation_link(path, name, move |_, _| items(&path_for_items, category))
+                }),
+        );
+
+    VStack::new()
+        .spacing(16.0)
+        .alignment(HorizontalAlignment::Leading)
+        .child(Text::new(
+            "NavigationStack - drill down from categories to items to details:",
+        ))
+        .child(
+            NavigationStack::new(path, categories)
+                .navigation_title("Categories")
+                .frame(Frame::size(320.0, 400.0))
+                .background(Color::system_background())
+                .corner_radius(12.0),
+        )
+        .child(
+            Button::with_id("pop-to-root")
+                .label("Pop to Root")
+                .on_click_with(cx.listener(|this: &mut Storybook, _, _, cx| {
+                    this.navigation_path.update(cx, |path, cx| {
+                        path.pop_to_root();
+                        cx.notify();
+                    });
+                })),
+        )
+}
@@ -1,18 +1,150 @@
 //! ScrollView story.
 //!
-//! Demonstrates scrollable containers with vertical and horizontal axes.
+//! Demonstrates scrollable containers with vertical and horizontal axes,
+//! content insets, indicator styles, paging and scrolling from code.
 //!
 //! ```rust,ignore
 //! ScrollView::new("my-scroll")
 //!     .axes(ScrollAxes::vertical())
+//!     .track(&proxy)
 //!     .child(VStack::new().children(...))
 //!     .frame(Frame::size(300.0, 200.0))
+//!
+//! proxy.animated().scroll_to_bottom(window);
 //! ```
 
 use allui::prelude::*;
-use gpui::{div, prelude::*, px, rgb};
+use gpui::{div, prelude::*, px, rgb, Context};
+
+use crate::Storybook;
+
+const CHAT: [(&str, &str); 6] = [
+    ("Ada", "Morning! Is the build green?"),
+    ("Grace", "Almost, one flaky test left."),
+    ("Ada", "Which one?"),
+    ("Grace", "The scroll offset one. It passes on a rerun."),
+    ("Ada", "Let's pin the window size in the harness."),
+    ("Grace", "Done, pushing now."),
+];
+const CHAT_LENGTH: usize = 40;
+
+const ONBOARDING: [(&str, &str); 4] = [
+    (
+        "Welcome",
+        "Build native-feeling apps with SwiftUI-style views.",
+    ),
+    ("Stacks", "Compose layouts from VStack, HStack and ZStack."),
+    (
+        "Modifiers",
+        "Chain padding, frames and backgrounds onto any view.",
+    ),
+    ("Ship it", "Swipe back any time to revisit these tips."),
+];
+const CAROUSEL_WIDTH: f32 = 360.0;
+
+/// A paged carousel with dots that follow the current page.
+fn onboarding(page: usize, cx: &mut Context<Storybook>) -> impl IntoElement {
+    let cards = ONBOARDING.iter().map(|(title, body)| {
+        VStack::new()
+            .spacing(8.0)
+            .child(Text::new(*title).font(Font::title()))
+            .child(Text::new(*body).foreground_color(Color::secondary_label()))
+            .padding(24.0)
+            .frame(Frame::size(CAROUSEL_WIDTH, 160.0))
+            .background(Color::secondary_system_background())
+    });
+    let dots = (0..ONBOARDING.len()).map(|ix| {
+        Circle::new()
+            .fill(if ix == page {
+                Color::blue()
+            } else {
+                Color::gray()
+            })
+            .frame_width(8.0)
+    });
 
-pub fn render_scrollview_story() -> impl IntoElement {
+    VStack::new()
+        .spacing(8.0)
+        .child(
+            ScrollView::new("onboarding")
+                .axes(ScrollAxes::horizontal())
+                .shows_indicators(false)
+                .snap(SnapBehavior::Paging)
+                .on_snap(cx.listener(|this, page: &usize, _, cx| {
+                    this.onboarding_page = *page;
+                    cx.notify();
+                }))
+                .child(HStack::new().spacing(0.0).children(cards))
+                .frame(Frame::size(CAROUSEL_WIDTH, 160.0))
+                .corner_radius(12.0),
+        )
+        .child(HStack::new().spacing(6.0).children(dots))
+}
+
+/// A mock chat log with buttons that scroll it from code.
+fn chat_log(scroll: &ScrollViewProxy) -> impl IntoElement {
+    let messages = (0..CHAT_LENGTH).map(|ix| {
+        let (author, text) = CHAT[ix % CHAT.len()];
+        let outgoing = author == "Ada";
+        Text::new(format!("{}. {}", ix + 1, text))
+            .foreground_color(if outgoing {
+                Color::white()
+            } else {
+                Color::label()
+            })
+            .padding_edges(6.0, 10.0, 6.0, 10.0)
+            .background(if outgoing {
+                Color::blue()
+            } else {
+                Color::secondary_system_background()
+            })
+            .corner_radius(12.0)
+            .scroll_id(("message", ix))
+            .frame(Frame::fill_width().alignment(if outgoing {
+                Alignment::trailing()
+            } else {
+                Alignment::leading()
+            }))
+    });
+
+    let latest = Button::new("Jump to latest", || {})
+        .button_style(ButtonStyle::BorderedProminent)
+        .on_click_with({
+            let scroll = scroll.clone();
+            move |_, window, _| scroll.animated().scroll_to_bottom(window)
+        });
+    let middle = Button::new("Message 20", || {}).on_click_with({
+        let scroll = scroll.clone();
+        move |_, window, _| {
+            scroll
+                .animated()
+                .scroll_to_id(("message", 19usize), ScrollAnchor::Center, window);
+        }
+    });
+    let top = Button::new("Top", || {}).on_click_with({
+        let scroll = scroll.clone();
+        move |_, window, _| scroll.scroll_to_top(window)
+    });
+
+    VStack::new()
+        .spacing(8.0)
+        .alignment(HorizontalAlignment::Leading)
+        .child(HStack::new().child(latest).child(middle).child(top))
+        .child(
+            ScrollView::new("chat-log")
+                .track(scroll)
+                .content_insets(12.0)
+                .child(VStack::new().spacing(6.0).children(messages))
+                .frame(Frame::size(360.0, 240.0))
+                .background(Color::tertiary_system_background())
+                .corner_radius(8.0),
+        )
+}
+
+pub fn render_scrollview_story(
+    storybook: &Storybook,
+    cx: &mut Context<Storybook>,
+) -> impl IntoElement {
     VStack::new()
         .spacing(16.0)
         .alignment(HorizontalAlignment::Leading)
@@ -48,6 +180,40 @@ pub fn render_scrollview_story() -> impl IntoElement {
                         .frame(Frame::size(400.0, 120.0))
                         .background(Color::tertiary_system_background())
                         .corner_radius(8.0),
-                ),
+                )
+                .child(Text::new(
+                    "Inset carousel (content_insets, hidden indicators):",
+                ))
+                .child(
+                    ScrollView::new("inset-carousel")
+                        .axes(ScrollAxes::horizontal())
+                        .shows_indicators(false)
+                        .content_insets((12.0, 24.0, 12.0, 24.0))
+                        .child(HStack::new().spacing(12.0).children((1..=10).map(|i| {
+                            Text::new(format!("Card {}", i))
+                                .frame(Frame::size(140.0, 80.0))
+                                .background(Color::blue())
+                                .foreground_color(Color::white())
+                                .corner_radius(12.0)
+                        })))
+                        .frame(Frame::size(400.0, 104.0))
+                        .background(Color::tertiary_system_background())
+                        .corner_radius(8.0),
+                )
+                .child(Text::new("Always-visible indicators:"))
+                .child(
+                    ScrollView::new("always-scroll")
+                        .indicator_style(ScrollIndicatorStyle::Always)
+                        .child(VStack::new().spacing(8.0).children(
+                            (1..=20).map(|i| Text::new(format!("Row {}", i)).padding(8.0)),
+                        ))
+                        .frame(Frame::size(300.0, 120.0))
+                        .background(Color::tertiary_system_background())
+                        .corner_radius(8.0),
+                )
+                .child(Text::new("Paged carousel (snap):"))
+                .child(onboarding(storybook.onboarding_page, cx))
+                .child(Text::new("Scrolling from code (ScrollViewProxy):"))
+                .child(chat_log(&storybook.log_scroll)),
         )
 }
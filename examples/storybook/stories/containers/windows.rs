@@ -0,0 +1,153 @@
+//! Windows story.
+//!
+//! Demonstrates opening secondary windows with window::open: a preferences
+//! dialog, and an inspector with a transparent titlebar, whose header
+//! background runs under the traffic lights while its buttons stay clear of
+//! them with safe_area_padding and ignores_safe_area.
+//!
+//! ```rust,ignore
+//! window::open(cx, WindowConfig::dialog("Preferences"), |_, cx| {
+//!     cx.new(|_| Preferences::default())
+//! })
+//!
+//! header
+//!     .safe_area_padding()
+//!     .background(Color::secondary_system_background())
+//!     .ignores_safe_area(EdgeSet::top())
+//! ```
+
+use allui::prelude::*;
+use allui::window;
+use gpui::{prelude::*, App, Context, Window};
+
+/// A small settings window.
+#[derive(Default)]
+struct Preferences {
+    notifications: bool,
+    sounds: bool,
+    launch_at_login: bool,
+}
+
+impl Render for Preferences {
+    fn render(&mut self, _window: &mut Window, cx: &mut Context<Self>) -> impl IntoElement {
+        let view = cx.entity();
+        VStack::new()
+            .spacing(12.0)
+            .alignment(HorizontalAlignment::Trailing)
+            .child(
+                Form::new()
+                    .id("preferences")
+                    .section(
+                        Section::new()
+                            .header("Notifications")
+                            .row(Toggle::bound(
+                                "Show notifications",
+                                Binding::entity_field(&view, |this| &mut this.notifications),
+                            ))
+                            .row(Toggle::bound(
+                                "Play sounds",
+                                Binding::entity_field(&view, |this| &mut this.sounds),
+                            )),
+                    )
+                    .section(Section::new().header("General").row(Toggle::bound(
+                        "Open at login",
+                        Binding::entity_field(&view, |this| &mut this.launch_at_login),
+                    )))
+                    .frame(Frame::fill()),
+            )
+            .child(
+                Button::new("Done", || {})
+                    .button_style(ButtonStyle::BorderedProminent)
+                    .on_click_with(|_, _, cx| window::close_window(cx)),
+            )
+            .padding(16.0)
+            .frame(Frame::fill())
+    }
+}
+
+/// Open the preferences window.
+fn open_preferences(cx: &mut App) {
+    window::open(cx, WindowConfig::dialog("Preferences"), |_, cx| {
+        cx.new(|_| Preferences {
+            notifications: true,
+            ..Preferences::default()
+        })
+    })
+    .ok();
+}
+
+/// A window with a transparent titlebar and a full-bleed header.
+struct InspectorWindow;
+
+impl Render for InspectorWindow {
+    fn render(&mut self, _window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+        let header = HStack::new()
+            .spacing(8.0)
+            .child(Text::new("Inspector").font(Font::headline()))
+            .child(Spacer::new())
+            .child(Button::new("Share", || {}).button_style(ButtonStyle::Bordered))
+            .child(Button::new("Done", || {}).button_style(ButtonStyle::BorderedProminent))
+            .padding(12.0)
+            .frame(Frame::fill_width())
+            // The background runs under the titlebar, the buttons don't
+            .safe_area_padding()
+            .background(Color::secondary_system_background())
+            .ignores_safe_area(EdgeSet::top());
+
+        VStack::new()
+            .spacing(0.0)
+            .child(header)
+            .child(
+                Text::new(
+                    "The header's background extends under the traffic lights, \
+                     while its title and buttons sit below the titlebar.",
+                )
+                .foreground_color(Color::secondary_label())
+                .padding(16.0),
+            )
+            .child(Spacer::new())
+            .frame(Frame::fill())
+            .safe_area_padding()
+    }
+}
+
+/// Open the inspector window, whose content extends under its titlebar.
+fn open_inspector(cx: &mut App) {
+    let config = WindowConfig::panel("Inspector")
+        .size(480.0, 320.0)
+        .chrome(WindowChrome::transparent_titlebar());
+    window::open(cx, config, |_, cx| cx.new(|_| InspectorWindow)).ok();
+}
+
+pub fn render_windows_story() -> impl IntoElement {
+    VStack::new()
+        .spacing(16.0)
+        .alignment(HorizontalAlignment::Leading)
+        .child(Text::new("Secondary windows:"))
+        .child(
+            Text::new(
+                "window::open wraps the view in gpui-component's Root, focuses it and keeps \
+                 it in sync with the theme. Done closes the window with close_window.",
+            )
+            .foreground_color(Color::secondary_label()),
+        )
+        .child(
+            Button::new("Open Preferences…", || {})
+                .button_style(ButtonStyle::Bordered)
+                .on_click_with(|_, _, cx| open_preferences(cx)),
+        )
+        .child(Text::new("Transparent titlebar:"))
+        .child(
+            Text::new(
+                "On macOS, the window's content extends under the titlebar. \
+                 safe_area_padding keeps the header's buttons clear of the traffic \
+                 lights, and ignores_safe_area lets its background run under them.",
+            )
+            .foreground_color(Color::secondary_label()),
+        )
+        .child(
+            Button::new("Open Inspector", || {})
+                .button_style(ButtonStyle::Bordered)
+                .on_click_with(|_, _, cx| open_inspector(cx)),
+        )
+}
@@ -67,13 +67,70 @@ pub fn render_grid_story() -> impl IntoElement {
                                 .child(Text::new("Rust"))
                                 .child(Text::new("2.3 KB")),
                         )
+                        .child(
+                            GridRow::new()
+                                .cell_spanning(Text::new("Totals").bold(), 2)
+                                .child(Text::new("16.3 KB").bold()),
+                        )
+                        .column_alignment(2, HorizontalAlignment::Trailing)
                         .padding(16.0)
                         .background(Color::tertiary_system_background())
                         .corner_radius(8.0),
                 )
                 .child(
-                    Text::new("Columns auto-size based on content width.")
+                    Text::new(
+                        "Columns auto-size based on content width. Totals spans two columns, \
+                         and the Size column is right-aligned.",
+                    )
+                    .foreground_color(Color::gray()),
+                ),
+        )
+        .child(
+            VStack::new()
+                .spacing(16.0)
+                .child(
+                    Text::new("Row separators and an unsized divider cell:")
                         .foreground_color(Color::gray()),
+                )
+                .child(
+                    Grid::new()
+                        .horizontal_spacing(16.0)
+                        .vertical_spacing(8.0)
+                        .alignment(Alignment::leading())
+                        .row_separators(Color::separator())
+                        .column_separators(Color::separator())
+                        .child(
+                            GridRow::new()
+                                .child(Text::new("Planet").bold())
+                                .child(Text::new("Moons").bold()),
+                        )
+                        .child(
+                            GridRow::new()
+                                .child(Text::new("Earth"))
+                                .child(Text::new("1")),
+                        )
+                        .child(
+                            GridRow::new()
+                                .child(Text::new("Mars"))
+                                .child(Text::new("2")),
+                        )
+                        .child(
+                            GridRow::new().cell_spanning(
+                                Divider::labeled("Gas giants")
+                                    .color(Color::blue())
+                                    .grid_cell_unsized_axes(Axis::Horizontal),
+                                2,
+                            ),
+                        )
+                        .child(
+                            GridRow::new()
+                                .child(Text::new("Jupiter"))
+                                .child(Text::new("95")),
+                        )
+                        .column_alignment(1, HorizontalAlignment::Trailing)
+                        .padding(16.0)
+                        .background(Color::tertiary_system_background())
+                        .corner_radius(8.0),
                 ),
         )
 }
@@ -12,7 +12,7 @@
 //! ```
 
 use allui::prelude::*;
-use gpui::{div, prelude::*, px, rgb};
+use gpui::prelude::*;
 
 pub fn render_lazy_hgrid_story() -> impl IntoElement {
     VStack::new()
@@ -33,18 +33,22 @@ pub fn render_lazy_hgrid_story() -> impl IntoElement {
                         .child(HStack::new().spacing(12.0).children((0..8).map(|col| {
                             VStack::new().spacing(12.0).children((0..2).map(move |row| {
                                 let index = col * 2 + row;
-                                let colors =
-                                    [0x007AFF, 0x34C759, 0xFF9500, 0xFF3B30, 0xAF52DE, 0x5856D6];
-                                let color = colors[index % colors.len()];
+                                let colors = [
+                                    Color::blue(),
+                                    Color::green(),
+                                    Color::orange(),
+                                    Color::red(),
+                                    Color::purple(),
+                                    Color::indigo(),
+                                ];
+                                let color = colors[index % colors.len()].clone();
                                 let categories =
                                     ["Music", "Movies", "Books", "Games", "Apps", "Podcasts"];
                                 VStack::new()
                                     .child(
-                                        div()
-                                            .w(px(70.0))
-                                            .h(px(50.0))
-                                            .bg(rgb(color as u32))
-                                            .rounded(px(8.0)),
+                                        RoundedRectangle::new(8.0)
+                                            .fill(color)
+                                            .frame_size(70.0, 50.0),
                                     )
                                     .child(
                                         Text::new(categories[index % categories.len()])
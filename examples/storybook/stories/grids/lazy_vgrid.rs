@@ -1,6 +1,8 @@
 //! LazyVGrid story.
 //!
-//! Demonstrates vertically-scrolling grid with fixed columns.
+//! Demonstrates vertically-scrolling grid with fixed columns, a contacts
+//! grid grouped A-Z with pinned section headers, and adaptive columns sized
+//! by a `GeometryReader`.
 //!
 //! ```rust,ignore
 //! LazyVGrid::new(cx.entity().clone(), "id", &scroll_handle)
@@ -9,12 +11,113 @@
 //!     .item_count(50)
 //!     .render_item(|view, idx, _, _| { ... })
 //!     .build(window, cx)
+//!
+//! GeometryReader::new(|size| columns_for(size.width))
 //! ```
 
 use allui::prelude::*;
-use gpui::{div, prelude::*, px, rgb};
+use gpui::{prelude::*, Context, Window};
+
+use crate::Storybook;
+
+/// Contacts grouped by the first letter of their name.
+const CONTACTS: &[(&str, &[&str])] = &[
+    (
+        "A",
+        &["Ada Lovelace", "Alan Turing", "Alan Kay", "Anita Borg"],
+    ),
+    ("B", &["Barbara Liskov", "Bjarne Stroustrup"]),
+    (
+        "D",
+        &[
+            "Dennis Ritchie",
+            "Donald Knuth",
+            "Dorothy Vaughan",
+            "Dana Scott",
+            "Daniel Bernstein",
+        ],
+    ),
+    ("E", &["Edsger Dijkstra"]),
+    ("F", &["Frances Allen", "Fred Brooks", "Fernando Corbató"]),
+    (
+        "G",
+        &[
+            "Grace Hopper",
+            "Guido van Rossum",
+            "Gordon Moore",
+            "Gary Kildall",
+            "Gene Amdahl",
+            "Geoffrey Hinton",
+        ],
+    ),
+    (
+        "J",
+        &["John McCarthy", "John Backus", "Jean Sammet", "Jim Gray"],
+    ),
+    (
+        "K",
+        &["Ken Thompson", "Katherine Johnson", "Kristen Nygaard"],
+    ),
+    ("L", &["Linus Torvalds", "Leslie Lamport", "Larry Wall"]),
+    (
+        "M",
+        &["Margaret Hamilton", "Marvin Minsky", "Mary Allen Wilkes"],
+    ),
+    ("N", &["Niklaus Wirth"]),
+    (
+        "R",
+        &[
+            "Radia Perlman",
+            "Richard Hamming",
+            "Rob Pike",
+            "Robin Milner",
+        ],
+    ),
+    ("T", &["Tim Berners-Lee", "Tony Hoare"]),
+    ("V", &["Vint Cerf"]),
+];
+
+pub fn render_lazy_vgrid_story(
+    storybook: &Storybook,
+    window: &mut Window,
+    cx: &mut Context<Storybook>,
+) -> impl IntoElement {
+    let contacts = LazyVGrid::new(
+        cx.entity().clone(),
+        "contacts-grid",
+        &storybook.contacts_scroll,
+    )
+    .columns(vec![GridItem::flexible(); 3])
+    .horizontal_spacing(8.0)
+    .vertical_spacing(4.0)
+    .row_height(72.0)
+    .header_height(28.0)
+    .sections(
+        CONTACTS
+            .iter()
+            .map(|(letter, names)| {
+                LazyGridSection::new(names.len()).header(move |_, _| {
+                    Text::new(*letter)
+                        .bold()
+                        .foreground_color(Color::secondary_label())
+                        .padding_edges(4.0, 8.0, 4.0, 8.0)
+                        .frame(Frame::fill_width().alignment(Alignment::leading()))
+                        .background(Color::secondary_system_background())
+                })
+            })
+            .collect(),
+    )
+    .pinned_headers(true)
+    .render_section_item(|_, path, _, _| {
+        let name = CONTACTS[path.section].1[path.row];
+        VStack::new()
+            .spacing(4.0)
+            .child(Avatar::new(name))
+            .child(Text::new(name).font(Font::caption()).line_limit(1))
+            .padding(4.0)
+    })
+    .build(window, cx);
 
-pub fn render_lazy_vgrid_story() -> impl IntoElement {
     VStack::new()
         .spacing(16.0)
         .alignment(HorizontalAlignment::Leading)
@@ -33,11 +136,19 @@ pub fn render_lazy_vgrid_story() -> impl IntoElement {
                         .child(VStack::new().spacing(8.0).children((0..6).map(|row| {
                             HStack::new().spacing(8.0).children((0..3).map(move |col| {
                                 let index = row * 3 + col;
-                                let colors = [0x007AFF, 0x34C759, 0xFF9500, 0xFF3B30, 0xAF52DE];
-                                let color = colors[index % colors.len()];
+                                let colors = [
+                                    Color::blue(),
+                                    Color::green(),
+                                    Color::orange(),
+                                    Color::red(),
+                                    Color::purple(),
+                                ];
+                                let color = colors[index % colors.len()].clone();
                                 VStack::new()
                                     .child(
-                                        div().size(px(80.0)).bg(rgb(color as u32)).rounded(px(8.0)),
+                                        RoundedRectangle::new(8.0)
+                                            .fill(color)
+                                            .frame_size(80.0, 80.0),
                                     )
                                     .child(
                                         Text::new(format!("Photo {}", index + 1))
@@ -91,4 +202,50 @@ pub fn render_lazy_vgrid_story() -> impl IntoElement {
                         .corner_radius(8.0),
                 ),
         )
+        .child(Text::new(
+            "Sections - Contacts grouped A-Z, with the current letter pinned:",
+        ))
+        .child(
+            // The grid fills the frame it is given
+            ZStack::new()
+                .child(contacts)
+                .frame(Frame::size(360.0, 320.0))
+                .background(Color::tertiary_system_background())
+                .corner_radius(8.0),
+        )
+        .child(Text::new(
+            "GeometryReader - Adaptive columns from the measured width:",
+        ))
+        .child(
+            GeometryReader::new(|size| {
+                // As many 72pt columns as fit, like GridItem::adaptive(72.0)
+                let width = f32::from(size.width);
+                let columns = ((width + 8.0) / (72.0 + 8.0)).floor().max(1.0) as usize;
+                let column_width = (width - 8.0 * (columns - 1) as f32) / columns as f32;
+                VStack::new()
+                    .spacing(8.0)
+                    .alignment(HorizontalAlignment::Leading)
+                    .child(
+                        Text::new(format!("{width:.0}pt wide: {columns} columns"))
+                            .foreground_color(Color::gray()),
+                    )
+                    .child(HStack::new().spacing(8.0).children((0..columns).map(|ix| {
+                        RoundedRectangle::new(6.0)
+                            .fill(if ix % 2 == 0 {
+                                Color::blue()
+                            } else {
+                                Color::teal()
+                            })
+                            .frame_size(column_width, 40.0)
+                    })))
+            })
+            .frame(Frame::fill_width().min_height(72.0).max_height(72.0))
+            .padding(12.0)
+            .background(Color::tertiary_system_background())
+            .corner_radius(8.0),
+        )
+        .child(
+            Text::new("Resize the window to see the column count follow the width.")
+                .foreground_color(Color::gray()),
+        )
 }
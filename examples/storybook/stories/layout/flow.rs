@@ -0,0 +1,73 @@
+//! Flow story.
+//!
+//! Demonstrates children wrapping onto new lines when a row is full.
+//!
+//! ```rust,ignore
+//! Flow::new()
+//!     .spacing(6.0)
+//!     .line_spacing(6.0)
+//!     .children(chips)
+//! ```
+
+use allui::prelude::*;
+use gpui::prelude::*;
+
+const WORDS: [&str; 12] = [
+    "rust",
+    "ui",
+    "layout",
+    "gpui",
+    "swift",
+    "declarative",
+    "a11y",
+    "components",
+    "theme",
+    "storybook",
+    "flex",
+    "wrapping layouts",
+];
+
+pub fn render_flow_story() -> impl IntoElement {
+    // Thirty chips of varied lengths, picked by a fixed stride so the
+    // story looks the same every run
+    let chips = (0..30).map(|ix| chip(WORDS[(ix * 7 + ix / 3) % WORDS.len()], ix % 5 == 0));
+
+    VStack::new()
+        .spacing(16.0)
+        .alignment(HorizontalAlignment::Leading)
+        .child(Text::new("Flow wraps children inside a 300pt frame:"))
+        .child(
+            Flow::new()
+                .spacing(6.0)
+                .line_spacing(6.0)
+                .children(chips)
+                .padding(8.0)
+                .frame_width(300.0)
+                .background(Color::tertiary_system_background())
+                .corner_radius(8.0),
+        )
+        .child(Text::new(
+            "Lines can be centered, and align to their tallest child:",
+        ))
+        .child(
+            Flow::new()
+                .alignment(HorizontalAlignment::Center)
+                .children((0..9).map(|ix| chip(WORDS[ix], ix % 4 == 1)))
+                .padding(8.0)
+                .frame_width(300.0)
+                .background(Color::tertiary_system_background())
+                .corner_radius(8.0),
+        )
+}
+
+fn chip(title: &'static str, tall: bool) -> impl IntoElement {
+    Text::new(title)
+        .font(if tall {
+            Font::title3()
+        } else {
+            Font::caption()
+        })
+        .padding_edges(4.0, 10.0, 4.0, 10.0)
+        .background(Color::blue().opacity(0.15))
+        .corner_radius(12.0)
+}
@@ -1,11 +1,15 @@
-//! Layout story modules - VStack, HStack, ZStack, Spacer.
+//! Layout story modules - VStack, HStack, ZStack, Flow, Spacer, Padding.
 
+mod flow;
 mod hstack;
+mod padding;
 mod spacer;
 mod vstack;
 mod zstack;
 
+pub use flow::*;
 pub use hstack::*;
+pub use padding::*;
 pub use spacer::*;
 pub use vstack::*;
 pub use zstack::*;
@@ -0,0 +1,67 @@
+//! Padding story.
+//!
+//! Demonstrates that padded views hug their content inside stacks and leave
+//! the free space to `Spacer`.
+//!
+//! ```rust,ignore
+//! HStack::new()
+//!     .child(Text::new("Padded").padding(8.0).background(Color::blue()))
+//!     .child(Spacer::new())
+//! ```
+
+use allui::prelude::*;
+use gpui::{div, prelude::*};
+
+pub fn render_padding_story() -> impl IntoElement {
+    VStack::new()
+        .spacing(16.0)
+        .alignment(HorizontalAlignment::Leading)
+        .child(Text::new(
+            "Before: a growing wrapper steals the Spacer's room:",
+        ))
+        .child(
+            HStack::new()
+                .child(
+                    div()
+                        .flex_grow()
+                        .child(Text::new("Padded").padding(8.0).background(Color::red())),
+                )
+                .child(Spacer::new())
+                .child(Text::new("Right").padding(8.0).background(Color::blue()))
+                .padding(16.0)
+                .background(Color::tertiary_system_background())
+                .corner_radius(8.0)
+                .frame_width(400.0),
+        )
+        .child(Text::new(
+            "After: padding hugs the text, Spacer takes the rest:",
+        ))
+        .child(
+            HStack::new()
+                .child(Text::new("Padded").padding(8.0).background(Color::green()))
+                .child(Spacer::new())
+                .child(Text::new("Right").padding(8.0).background(Color::blue()))
+                .padding(16.0)
+                .background(Color::tertiary_system_background())
+                .corner_radius(8.0)
+                .frame_width(400.0),
+        )
+        .child(Text::new(
+            "Opting in: a fill frame inside the padding expands:",
+        ))
+        .child(
+            HStack::new()
+                .child(
+                    Text::new("Fill width")
+                        .frame(Frame::fill_width())
+                        .padding(8.0)
+                        .background(Color::orange())
+                        .frame(Frame::fill_width()),
+                )
+                .child(Text::new("Right").padding(8.0).background(Color::blue()))
+                .padding(16.0)
+                .background(Color::tertiary_system_background())
+                .corner_radius(8.0)
+                .frame_width(400.0),
+        )
+}
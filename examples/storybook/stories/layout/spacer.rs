@@ -1,12 +1,19 @@
 //! Spacer story.
 //!
-//! Demonstrates flexible space that expands to fill available space in stacks.
+//! Demonstrates flexible space that expands to fill available space in stacks,
+//! and stack distributions that spread children without spacers.
 //!
 //! ```rust,ignore
 //! HStack::new()
 //!     .child(Text::new("Left"))
 //!     .child(Spacer::new())
 //!     .child(Text::new("Right"))
+//!
+//! Spacer::new().min_length(20.0)  // never closer than 20pt
+//! Spacer::fixed(16.0)             // exactly 16pt
+//!
+//! HStack::new().distribution(Distribution::SpaceBetween)  // no spacers needed
+//! HStack::new().spacing_between(2, 24.0)  // 24pt after the third child
 //! ```
 
 use allui::prelude::*;
@@ -38,4 +45,81 @@ pub fn render_spacer_story() -> impl IntoElement {
                 .corner_radius(8.0)
                 .frame_width(400.0),
         )
+        .child(Text::new("min_length keeps a gap when space is tight:"))
+        .child(
+            HStack::new()
+                .spacing(0.0)
+                .child(
+                    Text::new("A long leading label")
+                        .padding(8.0)
+                        .background(Color::orange()),
+                )
+                .child(Spacer::new().min_length(20.0))
+                .child(
+                    Text::new("A long trailing label")
+                        .padding(8.0)
+                        .background(Color::orange()),
+                )
+                .padding(16.0)
+                .background(Color::tertiary_system_background())
+                .corner_radius(8.0)
+                .frame_width(260.0),
+        )
+        .child(Text::new("fixed spaces without growing:"))
+        .child(
+            HStack::new()
+                .spacing(0.0)
+                .child(Text::new("Icon").padding(8.0).background(Color::purple()))
+                .child(Spacer::fixed(24.0))
+                .child(Text::new("Title").padding(8.0).background(Color::purple()))
+                .padding(16.0)
+                .background(Color::tertiary_system_background())
+                .corner_radius(8.0)
+                .frame_width(400.0),
+        )
+        .child(Text::new("Distribution::SpaceBetween, without spacers:"))
+        .child(
+            HStack::new()
+                .distribution(Distribution::SpaceBetween)
+                .child(Text::new("Left").padding(8.0).background(Color::blue()))
+                .child(Text::new("Middle").padding(8.0).background(Color::blue()))
+                .child(Text::new("Right").padding(8.0).background(Color::blue()))
+                .padding(16.0)
+                .background(Color::tertiary_system_background())
+                .corner_radius(8.0)
+                .frame_width(400.0),
+        )
+        .child(Text::new("EqualSpacing and SpaceAround:"))
+        .children(
+            [Distribution::EqualSpacing, Distribution::SpaceAround].map(|distribution| {
+                HStack::new()
+                    .distribution(distribution)
+                    .child(Text::new("A").padding(8.0).background(Color::green()))
+                    .child(Text::new("B").padding(8.0).background(Color::green()))
+                    .child(Text::new("C").padding(8.0).background(Color::green()))
+                    .padding(16.0)
+                    .background(Color::tertiary_system_background())
+                    .corner_radius(8.0)
+                    .frame_width(400.0)
+            }),
+        )
+        .child(Text::new(
+            "spacing_between sets a destructive action apart:",
+        ))
+        .child(
+            HStack::new()
+                .spacing(4.0)
+                .spacing_between(2, 32.0)
+                .child(Button::new("Reply", || {}).button_style(ButtonStyle::Bordered))
+                .child(Button::new("Forward", || {}).button_style(ButtonStyle::Bordered))
+                .child(Button::new("Archive", || {}).button_style(ButtonStyle::Bordered))
+                .child(
+                    Button::new("Delete", || {})
+                        .button_style(ButtonStyle::Bordered)
+                        .role(ButtonRole::Destructive),
+                )
+                .padding(16.0)
+                .background(Color::tertiary_system_background())
+                .corner_radius(8.0),
+        )
 }
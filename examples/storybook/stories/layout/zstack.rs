@@ -9,7 +9,7 @@
 //! ```
 
 use allui::prelude::*;
-use gpui::{div, prelude::*, px, rgb};
+use gpui::prelude::*;
 
 pub fn render_zstack_story() -> impl IntoElement {
     VStack::new()
@@ -18,10 +18,48 @@ pub fn render_zstack_story() -> impl IntoElement {
         .child(Text::new("ZStack overlays children, centered by default:"))
         .child(
             ZStack::new()
-                .child(div().size(px(150.0)).bg(rgb(0x007AFF)).rounded(px(8.0)))
-                .child(div().size(px(80.0)).bg(rgb(0xFF3B30)).rounded_full())
+                .child(
+                    RoundedRectangle::new(8.0)
+                        .fill(Color::blue())
+                        .frame_size(150.0, 150.0),
+                )
+                .child(Circle::new().fill(Color::red()).frame_width(80.0))
                 .frame_size(200.0, 200.0)
                 .background(Color::tertiary_system_background())
                 .corner_radius(8.0),
         )
+        .child(Text::new(
+            "child_aligned pins each child to its own corner:",
+        ))
+        .child(
+            ZStack::new()
+                .child(
+                    RoundedRectangle::new(8.0)
+                        .fill(
+                            LinearGradient::new(Color::teal(), Color::indigo())
+                                .start_point(UnitPoint::top_leading())
+                                .end_point(UnitPoint::bottom_trailing()),
+                        )
+                        .frame_size(280.0, 180.0),
+                )
+                .child_aligned(overlay_label("Top leading"), Alignment::top_leading())
+                .child_aligned(overlay_label("Top trailing"), Alignment::top_trailing())
+                .child_aligned(overlay_label("Center"), Alignment::center())
+                .child_aligned(overlay_label("Bottom leading"), Alignment::bottom_leading())
+                .child_aligned(
+                    overlay_label("Bottom trailing"),
+                    Alignment::bottom_trailing(),
+                )
+                .frame_size(280.0, 180.0),
+        )
+}
+
+fn overlay_label(title: &'static str) -> impl IntoElement {
+    Text::new(title)
+        .font(Font::caption())
+        .foreground_color(Color::white())
+        .padding(6.0)
+        .background(Color::black().opacity(0.4))
+        .corner_radius(4.0)
+        .padding(8.0)
 }
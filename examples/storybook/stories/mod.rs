@@ -18,21 +18,30 @@ pub enum Story {
     VStack,
     HStack,
     ZStack,
+    Flow,
     Spacer,
+    Padding,
     Text,
     Button,
     Modifiers,
+    Colors,
     Toggle,
     TapGesture,
     TextFields,
     Sliders,
     MoreInputs,
+    ColorPicker,
     DisplayComponents,
+    Markdown,
     ScrollView,
+    LazyVStack,
     List,
     ListConfig,
+    NavigationStack,
     ForEach,
     Conditional,
+    DragAndDrop,
+    Windows,
     Grid,
     LazyVGrid,
     LazyHGrid,
@@ -58,10 +67,18 @@ pub fn layout_stories() -> &'static [StoryInfo] {
             name: "ZStack",
             story: Story::ZStack,
         },
+        StoryInfo {
+            name: "Flow",
+            story: Story::Flow,
+        },
         StoryInfo {
             name: "Spacer",
             story: Story::Spacer,
         },
+        StoryInfo {
+            name: "Padding",
+            story: Story::Padding,
+        },
     ]
 }
 
@@ -79,6 +96,10 @@ pub fn component_stories() -> &'static [StoryInfo] {
             name: "Modifiers",
             story: Story::Modifiers,
         },
+        StoryInfo {
+            name: "Colors",
+            story: Story::Colors,
+        },
         StoryInfo {
             name: "Toggle",
             story: Story::Toggle,
@@ -99,10 +120,18 @@ pub fn component_stories() -> &'static [StoryInfo] {
             name: "More Inputs",
             story: Story::MoreInputs,
         },
+        StoryInfo {
+            name: "ColorPicker",
+            story: Story::ColorPicker,
+        },
         StoryInfo {
             name: "Display",
             story: Story::DisplayComponents,
         },
+        StoryInfo {
+            name: "Markdown",
+            story: Story::Markdown,
+        },
     ]
 }
 
@@ -112,6 +141,10 @@ pub fn container_stories() -> &'static [StoryInfo] {
             name: "ScrollView",
             story: Story::ScrollView,
         },
+        StoryInfo {
+            name: "LazyVStack",
+            story: Story::LazyVStack,
+        },
         StoryInfo {
             name: "List",
             story: Story::List,
@@ -120,6 +153,10 @@ pub fn container_stories() -> &'static [StoryInfo] {
             name: "List Config",
             story: Story::ListConfig,
         },
+        StoryInfo {
+            name: "NavigationStack",
+            story: Story::NavigationStack,
+        },
         StoryInfo {
             name: "ForEach",
             story: Story::ForEach,
@@ -128,6 +165,14 @@ pub fn container_stories() -> &'static [StoryInfo] {
             name: "Conditional",
             story: Story::Conditional,
         },
+        StoryInfo {
+            name: "Drag and Drop",
+            story: Story::DragAndDrop,
+        },
+        StoryInfo {
+            name: "Windows",
+            story: Story::Windows,
+        },
     ]
 }
 
@@ -0,0 +1,292 @@
+//! Accessibility - what views report to assistive technology.
+//!
+//! GPUI doesn't expose an accessibility API yet, so Allui describes each
+//! view with an [`AccessibilityNode`] ready to hand to one. Controls fill
+//! in their own role, label and value, and the `.accessibility_*()`
+//! modifiers override them. With the `accessibility` feature enabled, the
+//! nodes drawn in a window can be read back with [`nodes`].
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use gpui::{
+    AnyElement, App, Bounds, Element, ElementId, GlobalElementId, InspectorElementId, IntoElement,
+    LayoutId, Pixels, SharedString, Window,
+};
+
+use crate::environment;
+
+/// What kind of element a view is, like SwiftUI's accessibility traits.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AccessibilityRole {
+    /// Performs an action when activated.
+    Button,
+    /// Switches between on and off.
+    Toggle,
+    /// Picks a value from a range.
+    Slider,
+    /// Edits text.
+    TextField,
+    /// Text that can't be changed.
+    StaticText,
+    /// A picture or icon.
+    Image,
+    /// Opens a destination when activated.
+    Link,
+    /// Titles a section of content.
+    Header,
+}
+
+/// The description of a view reported to assistive technology.
+///
+/// Fields left `None` are filled in by the control the node describes.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct AccessibilityNode {
+    /// What kind of element the view is.
+    pub role: Option<AccessibilityRole>,
+    /// What the view is, read first, such as a button's title.
+    pub label: Option<SharedString>,
+    /// What happens when the view is activated.
+    pub hint: Option<SharedString>,
+    /// The view's current value, such as a slider's position.
+    pub value: Option<SharedString>,
+    /// Whether the view and its contents are left out.
+    pub hidden: Option<bool>,
+}
+
+impl AccessibilityNode {
+    /// Describe a view with `role`.
+    pub fn new(role: AccessibilityRole) -> Self {
+        Self {
+            role: Some(role),
+            ..Self::default()
+        }
+    }
+
+    /// Set the label, leaving it unset when `label` is empty.
+    pub fn label(mut self, label: impl Into<SharedString>) -> Self {
+        let label = label.into();
+        self.label = (!label.is_empty()).then_some(label);
+        self
+    }
+
+    /// Set the value.
+    pub fn value(mut self, value: impl Into<SharedString>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Whether the view is left out of the accessibility tree.
+    pub fn is_hidden(&self) -> bool {
+        self.hidden.unwrap_or(false)
+    }
+
+    /// This node, with the fields it leaves unset taken from `defaults`.
+    pub(crate) fn or(self, defaults: AccessibilityNode) -> Self {
+        Self {
+            role: self.role.or(defaults.role),
+            label: self.label.or(defaults.label),
+            hint: self.hint.or(defaults.hint),
+            value: self.value.or(defaults.value),
+            hidden: self.hidden.or(defaults.hidden),
+        }
+    }
+}
+
+/// Where accessibility modifiers hand their node to the first control
+/// rendered inside them.
+pub(crate) type AccessibilitySlot = Rc<RefCell<Option<AccessibilityNode>>>;
+
+/// Take the node set by the nearest enclosing accessibility modifiers, if
+/// no control inside them has taken it yet.
+pub(crate) fn take(cx: &App) -> Option<AccessibilityNode> {
+    environment::accessibility_slot(cx).and_then(|slot| slot.borrow_mut().take())
+}
+
+/// A control's node: its `defaults`, overridden by the nearest enclosing
+/// accessibility modifiers.
+pub(crate) fn describe(defaults: AccessibilityNode, cx: &App) -> AccessibilitySlot {
+    let node = match take(cx) {
+        Some(overrides) => overrides.or(defaults),
+        None => defaults,
+    };
+    Rc::new(RefCell::new(Some(node)))
+}
+
+/// Wraps an element described by an [`AccessibilityNode`].
+///
+/// The node is recorded once the element is prepainted, unless a control
+/// inside took it over.
+pub(crate) struct Accessible {
+    slot: AccessibilitySlot,
+    child: AnyElement,
+}
+
+impl Accessible {
+    /// Describe a control with its `defaults`, overridden by the nearest
+    /// enclosing accessibility modifiers.
+    pub(crate) fn new(defaults: AccessibilityNode, child: impl IntoElement, cx: &App) -> Self {
+        Self::offered(describe(defaults, cx), child)
+    }
+
+    /// Describe `child` with the node in `slot`, unless a control inside
+    /// takes it.
+    pub(crate) fn offered(slot: AccessibilitySlot, child: impl IntoElement) -> Self {
+        Self {
+            slot,
+            child: child.into_any_element(),
+        }
+    }
+}
+
+impl IntoElement for Accessible {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for Accessible {
+    type RequestLayoutState = ();
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn source_location(&self) -> Option<&'static std::panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        (self.child.request_layout(window, cx), ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Self::PrepaintState {
+        self.child.prepaint(window, cx);
+        let node = self.slot.borrow_mut().take();
+        #[cfg(feature = "accessibility")]
+        if let Some(node) = node {
+            tree::record(_bounds, node, window, cx);
+        }
+        #[cfg(not(feature = "accessibility"))]
+        let _ = node;
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        _prepaint: &mut Self::PrepaintState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        self.child.paint(window, cx);
+    }
+}
+
+/// The nodes drawn in `window`'s last frame, in drawing order, with the
+/// bounds of the views they describe.
+#[cfg(feature = "accessibility")]
+pub fn nodes(window: &Window, cx: &App) -> Vec<(Bounds<Pixels>, AccessibilityNode)> {
+    tree::nodes(window, cx)
+}
+
+#[cfg(feature = "accessibility")]
+mod tree {
+    use std::collections::HashMap;
+
+    use gpui::{AnyWindowHandle, App, Bounds, Global, Pixels, Window};
+
+    use super::AccessibilityNode;
+
+    type Nodes = Vec<(Bounds<Pixels>, AccessibilityNode)>;
+
+    /// The nodes of each window's frames.
+    #[derive(Default)]
+    struct AccessibilityTree {
+        windows: HashMap<AnyWindowHandle, Frames>,
+    }
+
+    impl Global for AccessibilityTree {}
+
+    #[derive(Default)]
+    struct Frames {
+        drawing: Nodes,
+        drawn: Nodes,
+        /// Whether the frame being drawn is set to replace the last one.
+        swap_scheduled: bool,
+    }
+
+    pub(super) fn record(
+        bounds: Bounds<Pixels>,
+        node: AccessibilityNode,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        let handle = window.window_handle();
+        let tree = cx.default_global::<AccessibilityTree>();
+        let frames = tree.windows.entry(handle).or_default();
+        frames.drawing.push((bounds, node));
+        if !frames.swap_scheduled {
+            frames.swap_scheduled = true;
+            window.on_next_frame(move |_, cx| {
+                let tree = cx.default_global::<AccessibilityTree>();
+                if let Some(frames) = tree.windows.get_mut(&handle) {
+                    frames.drawn = std::mem::take(&mut frames.drawing);
+                    frames.swap_scheduled = false;
+                }
+            });
+        }
+    }
+
+    pub(super) fn nodes(window: &Window, cx: &App) -> Nodes {
+        cx.try_global::<AccessibilityTree>()
+            .and_then(|tree| tree.windows.get(&window.window_handle()))
+            .map(|frames| frames.drawn.clone())
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn modifiers_override_only_the_fields_they_set() {
+        let defaults = AccessibilityNode::new(AccessibilityRole::Button).label("Trash");
+        let overrides = AccessibilityNode {
+            hint: Some("Deletes the message".into()),
+            ..AccessibilityNode::default()
+        }
+        .label("Delete");
+
+        let node = overrides.or(defaults);
+        assert_eq!(node.role, Some(AccessibilityRole::Button));
+        assert_eq!(node.label, Some("Delete".into()));
+        assert_eq!(node.hint, Some("Deletes the message".into()));
+        assert!(!node.is_hidden());
+    }
+
+    #[test]
+    fn empty_labels_are_left_unset() {
+        assert_eq!(AccessibilityNode::default().label("").label, None);
+    }
+}
@@ -0,0 +1,126 @@
+//! Binding - two-way access to state a view owns, like SwiftUI's `@Binding`.
+//!
+//! Controls made with a `bound` constructor read their value through a
+//! binding while rendering and write changes back through it, so the view
+//! needs no change handler of its own.
+//!
+//! ```rust,ignore
+//! let view = cx.entity();
+//! Toggle::bound("Wi-Fi", Binding::entity_field(&view, |this| &mut this.wifi))
+//! ```
+
+use std::rc::Rc;
+
+use gpui::{App, Entity, Window};
+
+type Getter<T> = Rc<dyn Fn(&mut App) -> T>;
+type Setter<T> = Rc<dyn Fn(T, &mut Window, &mut App)>;
+
+/// A value read and written through a pair of closures.
+pub struct Binding<T> {
+    get: Getter<T>,
+    set: Setter<T>,
+}
+
+impl<T> Clone for Binding<T> {
+    fn clone(&self) -> Self {
+        Self {
+            get: self.get.clone(),
+            set: self.set.clone(),
+        }
+    }
+}
+
+impl<T: 'static> Binding<T> {
+    /// A binding that reads with `get` and writes with `set`.
+    pub fn new(
+        get: impl Fn(&mut App) -> T + 'static,
+        set: impl Fn(T, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        Self {
+            get: Rc::new(get),
+            set: Rc::new(set),
+        }
+    }
+
+    /// A binding to a field of `entity`, picked out by `field`.
+    ///
+    /// Writing the field notifies the entity, so its view re-renders with
+    /// the new value.
+    pub fn entity_field<E: 'static>(
+        entity: &Entity<E>,
+        field: impl Fn(&mut E) -> &mut T + 'static,
+    ) -> Self
+    where
+        T: Clone,
+    {
+        let field = Rc::new(field);
+        let reader = entity.clone();
+        let read_field = field.clone();
+        let writer = entity.clone();
+        Self::new(
+            move |cx| reader.update(cx, |state, _| read_field(state).clone()),
+            move |value, _, cx| {
+                writer.update(cx, |state, cx| {
+                    *field(state) = value;
+                    cx.notify();
+                });
+            },
+        )
+    }
+
+    /// A binding that always reads `value` and ignores writes, for
+    /// previews and read-only controls.
+    pub fn constant(value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::new(move |_| value.clone(), |_, _, _| {})
+    }
+
+    /// The current value.
+    ///
+    /// Don't call this while the bound entity is being updated, such as
+    /// from its own `render`; controls read it while they render.
+    pub fn get(&self, cx: &mut App) -> T {
+        (self.get)(cx)
+    }
+
+    /// Write a new value.
+    pub fn set(&self, value: T, window: &mut Window, cx: &mut App) {
+        (self.set)(value, window, cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::{AppContext, TestAppContext};
+
+    use super::*;
+
+    struct Settings {
+        volume: i32,
+    }
+
+    #[test]
+    fn entity_fields_are_read_and_written_in_place() {
+        let mut cx = TestAppContext::single();
+        let settings = cx.update(|cx| cx.new(|_| Settings { volume: 3 }));
+        let volume = Binding::entity_field(&settings, |settings| &mut settings.volume);
+        let window = cx.add_empty_window();
+
+        assert_eq!(window.update(|_, cx| volume.get(cx)), 3);
+        window.update(|window, cx| volume.set(7, window, cx));
+        assert_eq!(settings.read_with(window, |settings, _| settings.volume), 7);
+        assert_eq!(window.update(|_, cx| volume.get(cx)), 7);
+    }
+
+    #[test]
+    fn constants_ignore_writes() {
+        let mut cx = TestAppContext::single();
+        let window = cx.add_empty_window();
+        let binding = Binding::constant(true);
+        window.update(|window, cx| binding.set(false, window, cx));
+        assert!(window.update(|_, cx| binding.get(cx)));
+    }
+}
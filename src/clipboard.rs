@@ -0,0 +1,185 @@
+//! Clipboard - copying text to and reading it from the system clipboard.
+//!
+//! [`Clipboard`] wraps GPUI's clipboard for plain text, and
+//! `.copyable()` copies a view's text when it's clicked, confirming with a
+//! brief "Copied!" toast shown by the window's [`ToastCenter`].
+//!
+//! ```rust,ignore
+//! Text::new(&self.api_key).copyable(self.api_key.clone())
+//!
+//! if let Some(text) = Clipboard::read_text(cx) {
+//!     self.query = text;
+//! }
+//! ```
+//!
+//! [`ToastCenter`]: crate::toast::ToastCenter
+
+use gpui::{
+    div, prelude::FluentBuilder, AnyElement, App, ClipboardItem, ElementId, InteractiveElement,
+    IntoElement, ParentElement, RenderOnce, SharedString, StatefulInteractiveElement, Styled,
+    Window,
+};
+
+use crate::environment;
+use crate::modifier::{Expansion, Modifier};
+use crate::toast::{Toast, ToastCenter, ToastStyle};
+
+/// How long the "Copied!" confirmation shows, in seconds.
+const CONFIRMATION_SECS: f32 = 1.5;
+
+/// The system clipboard, for plain text.
+pub struct Clipboard;
+
+impl Clipboard {
+    /// Replace the clipboard's contents with `text`.
+    pub fn copy_text(cx: &App, text: &str) {
+        cx.write_to_clipboard(ClipboardItem::new_string(text.to_string()));
+    }
+
+    /// The clipboard's text, or `None` if it's empty or holds no text.
+    pub fn read_text(cx: &App) -> Option<String> {
+        cx.read_from_clipboard()?.text()
+    }
+}
+
+/// Copy `text`, confirming with a toast.
+pub(crate) fn copy_with_confirmation(text: &str, cx: &mut App) {
+    Clipboard::copy_text(cx, text);
+    ToastCenter::show(
+        cx,
+        Toast::new("Copied!")
+            .style(ToastStyle::Success)
+            .duration_secs(CONFIRMATION_SECS),
+    );
+}
+
+/// A view that copies its text when clicked, created by
+/// [`Modifier::copyable`].
+pub struct Copyable<V> {
+    pub(crate) child: V,
+    pub(crate) child_expansion: Expansion,
+    pub(crate) id: ElementId,
+    pub(crate) text: SharedString,
+}
+
+impl<V> Copyable<V> {
+    /// Key the click by `id` instead of by where the view was created.
+    /// Views made in a loop need ids of their own.
+    pub fn id(mut self, id: impl Into<ElementId>) -> Self {
+        self.id = id.into();
+        self
+    }
+}
+
+impl<V> Modifier for Copyable<V> {
+    fn expansion(&self) -> Expansion {
+        self.child_expansion
+    }
+}
+
+impl<V: IntoElement + 'static> IntoElement for Copyable<V> {
+    type Element = AnyElement;
+
+    fn into_element(self) -> Self::Element {
+        CopyableElement { copyable: self }.into_any_element()
+    }
+}
+
+#[derive(IntoElement)]
+struct CopyableElement<V: IntoElement + 'static> {
+    copyable: Copyable<V>,
+}
+
+impl<V: IntoElement + 'static> RenderOnce for CopyableElement<V> {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let Copyable {
+            child,
+            child_expansion: expansion,
+            id,
+            text,
+        } = self.copyable;
+
+        let container = div()
+            .id(id)
+            .when(expansion.grows, |container| container.flex_grow())
+            .when(expansion.fills_width, |container| container.w_full())
+            .when(expansion.fills_height, |container| container.h_full());
+        // Inside `.disabled(true)` the view shows as is and copies nothing
+        if !environment::is_enabled(cx) {
+            return container.child(child);
+        }
+        container
+            .cursor_pointer()
+            .on_click(move |_, _, cx| copy_with_confirmation(&text, cx))
+            .child(child)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::{
+        point, px, Context, Modifiers, MouseButton, MouseDownEvent, MouseUpEvent, Render,
+        TestAppContext,
+    };
+
+    use super::*;
+    use crate::layout::EmptyView;
+
+    struct ApiKey {
+        disabled: bool,
+    }
+
+    impl Render for ApiKey {
+        fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+            EmptyView::new()
+                .frame_size(50.0, 50.0)
+                .copyable("sk-123")
+                .disabled(self.disabled)
+        }
+    }
+
+    #[test]
+    fn text_round_trips_through_the_clipboard() {
+        let cx = TestAppContext::single();
+        cx.update(|cx| Clipboard::copy_text(cx, "hello"));
+        assert_eq!(
+            cx.update(|cx| Clipboard::read_text(cx)).as_deref(),
+            Some("hello")
+        );
+    }
+
+    #[test]
+    fn copyable_views_copy_unless_disabled() {
+        let mut cx = TestAppContext::single();
+        let (view, cx) = cx.add_window_view(|_, _| ApiKey { disabled: true });
+        let click = |cx: &mut gpui::VisualTestContext| {
+            let position = point(px(25.), px(25.));
+            cx.simulate_event(MouseDownEvent {
+                button: MouseButton::Left,
+                position,
+                modifiers: Modifiers::default(),
+                click_count: 1,
+                first_mouse: false,
+            });
+            cx.simulate_event(MouseUpEvent {
+                button: MouseButton::Left,
+                position,
+                modifiers: Modifiers::default(),
+                click_count: 1,
+            });
+        };
+
+        click(cx);
+        assert_eq!(cx.update(|_, cx| Clipboard::read_text(cx)), None);
+
+        view.update(cx, |view, cx| {
+            view.disabled = false;
+            cx.notify();
+        });
+        click(cx);
+        assert_eq!(
+            cx.update(|_, cx| Clipboard::read_text(cx)).as_deref(),
+            Some("sk-123")
+        );
+    }
+}
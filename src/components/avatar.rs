@@ -0,0 +1,290 @@
+//! Avatar - Circular picture of a person with an initials fallback.
+
+use gpui::{
+    div, img, px, AnyElement, App, ImageSource, IntoElement, ObjectFit, ParentElement, RenderOnce,
+    SharedString, Styled, StyledImage, Window,
+};
+
+use crate::modifier::Modifier;
+use crate::style::Color;
+
+/// Presence shown as a dot at the bottom trailing edge of an [`Avatar`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AvatarStatus {
+    /// Green dot.
+    Online,
+    /// Yellow dot.
+    Away,
+    /// Gray dot.
+    Offline,
+    /// No dot (default).
+    #[default]
+    None,
+}
+
+impl AvatarStatus {
+    fn color(self) -> Option<Color> {
+        match self {
+            AvatarStatus::Online => Some(Color::green()),
+            AvatarStatus::Away => Some(Color::yellow()),
+            AvatarStatus::Offline => Some(Color::gray()),
+            AvatarStatus::None => None,
+        }
+    }
+}
+
+/// A circular avatar showing a picture, or the initials of a name.
+///
+/// Without an image, or while it loads, the avatar shows up to two initials
+/// on a background color picked from the name, so the same person always
+/// gets the same color.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// Avatar::new("Ada Lovelace")
+///     .size(Avatar::LARGE)
+///     .status(AvatarStatus::Online)
+///
+/// // A picture from a file, or `Arc<gpui::Image>` for bytes in memory
+/// Avatar::new("Grace Hopper").image(PathBuf::from("grace.png"))
+/// ```
+#[derive(IntoElement)]
+pub struct Avatar {
+    name: SharedString,
+    image: Option<ImageSource>,
+    size: f32,
+    status: AvatarStatus,
+    /// Outline in the background color, drawn by [`AvatarStack`] so
+    /// overlapping avatars stay apart.
+    ring: bool,
+}
+
+impl Avatar {
+    /// Small size, for dense lists (24pt).
+    pub const SMALL: f32 = 24.0;
+    /// Medium size, the default (32pt).
+    pub const MEDIUM: f32 = 32.0;
+    /// Large size, for headers (48pt).
+    pub const LARGE: f32 = 48.0;
+    /// Extra large size, for profile pages (72pt).
+    pub const EXTRA_LARGE: f32 = 72.0;
+
+    /// Create an avatar for the person called `name`.
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: SharedString::from(name.to_string()),
+            image: None,
+            size: Self::MEDIUM,
+            status: AvatarStatus::None,
+            ring: false,
+        }
+    }
+
+    /// Show a picture instead of the initials, clipped to a circle.
+    ///
+    /// Accepts anything gpui can load an image from: a path, a URL, or an
+    /// `Arc<gpui::Image>` made from bytes.
+    pub fn image(mut self, source: impl Into<ImageSource>) -> Self {
+        self.image = Some(source.into());
+        self
+    }
+
+    /// Set the diameter in points, such as [`Avatar::SMALL`] or
+    /// [`Avatar::LARGE`] (default [`Avatar::MEDIUM`]).
+    pub fn size(mut self, size: f32) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Show a presence dot.
+    pub fn status(mut self, status: AvatarStatus) -> Self {
+        self.status = status;
+        self
+    }
+}
+
+impl Modifier for Avatar {}
+
+impl RenderOnce for Avatar {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let size = px(self.size);
+        let background = Color::system_background().resolve_in(cx);
+        let fill = name_color(&self.name).resolve_in(cx);
+
+        let initials = initials(&self.name);
+        let font_size = px(self.size * 0.4);
+        let fallback = move || {
+            div()
+                .flex()
+                .items_center()
+                .justify_center()
+                .size_full()
+                .rounded_full()
+                .bg(fill)
+                .text_color(gpui::white())
+                .text_size(font_size)
+                .font_weight(gpui::FontWeight::MEDIUM)
+                .child(initials.clone())
+                .into_any_element()
+        };
+
+        let face: AnyElement = match self.image {
+            Some(source) => img(source)
+                .size_full()
+                .rounded_full()
+                .object_fit(ObjectFit::Cover)
+                .with_loading(fallback.clone())
+                .with_fallback(fallback)
+                .into_any_element(),
+            None => fallback(),
+        };
+
+        let mut avatar = div()
+            .relative()
+            .flex_shrink_0()
+            .size(size)
+            .rounded_full()
+            .child(face);
+        if self.ring {
+            avatar = avatar.border_2().border_color(background);
+        }
+
+        if let Some(color) = self.status.color() {
+            let dot = (self.size * 0.3).max(8.0);
+            avatar = avatar.child(
+                div()
+                    .absolute()
+                    .right(px(-1.0))
+                    .bottom(px(-1.0))
+                    .size(px(dot))
+                    .rounded_full()
+                    .bg(color.resolve_in(cx))
+                    .border_2()
+                    .border_color(background),
+            );
+        }
+
+        avatar
+    }
+}
+
+/// A row of avatars overlapping each other, as for the members of a group.
+///
+/// Each avatar is outlined in the background color so the overlaps stay
+/// readable.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// AvatarStack::new(vec![
+///     Avatar::new("Ada Lovelace"),
+///     Avatar::new("Grace Hopper"),
+///     Avatar::new("Alan Turing"),
+/// ])
+/// .overlap(12.0)
+/// ```
+#[derive(IntoElement)]
+pub struct AvatarStack {
+    avatars: Vec<Avatar>,
+    overlap: Option<f32>,
+}
+
+impl AvatarStack {
+    /// Create a stack of `avatars`, each drawn over the one before it.
+    pub fn new(avatars: Vec<Avatar>) -> Self {
+        Self {
+            avatars,
+            overlap: None,
+        }
+    }
+
+    /// Set how far each avatar slides under the previous one, in points
+    /// (default a third of the avatar size).
+    pub fn overlap(mut self, overlap: f32) -> Self {
+        self.overlap = Some(overlap);
+        self
+    }
+}
+
+impl Modifier for AvatarStack {}
+
+impl RenderOnce for AvatarStack {
+    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+        let overlap = self.overlap;
+        let avatars = self
+            .avatars
+            .into_iter()
+            .enumerate()
+            .map(|(ix, mut avatar)| {
+                avatar.ring = true;
+                let overlap = overlap.unwrap_or(avatar.size / 3.0);
+                let mut item = div().flex_shrink_0().child(avatar);
+                if ix > 0 {
+                    item = item.ml(px(-overlap));
+                }
+                item
+            });
+
+        div().flex().flex_row().items_center().children(avatars)
+    }
+}
+
+/// Up to two initials for `name`: the first letters of its first and last
+/// words, or the first two letters of a single word.
+fn initials(name: &str) -> SharedString {
+    let words: Vec<&str> = name.split_whitespace().collect();
+    let letters: String = match words.as_slice() {
+        [] => "?".to_string(),
+        [word] => word.chars().take(2).collect(),
+        [first, .., last] => first.chars().take(1).chain(last.chars().take(1)).collect(),
+    };
+    SharedString::from(letters.to_uppercase())
+}
+
+/// A background color for `name`, the same on every run.
+fn name_color(name: &str) -> Color {
+    const PALETTE: [fn() -> Color; 10] = [
+        Color::red,
+        Color::orange,
+        Color::green,
+        Color::mint,
+        Color::teal,
+        Color::blue,
+        Color::indigo,
+        Color::purple,
+        Color::pink,
+        Color::brown,
+    ];
+    PALETTE[name_hash(name) as usize % PALETTE.len()]()
+}
+
+/// FNV-1a hash of `name`, stable across runs and Rust versions unlike
+/// `DefaultHasher`.
+fn name_hash(name: &str) -> u32 {
+    name.bytes().fold(0x811c_9dc5, |hash, byte| {
+        (hash ^ byte as u32).wrapping_mul(0x0100_0193)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn initials_from_first_and_last_words() {
+        assert_eq!(initials("Ada Lovelace"), "AL");
+        assert_eq!(initials("  grace  brewster murray hopper "), "GH");
+        assert_eq!(initials("alan"), "AL");
+        assert_eq!(initials("Q"), "Q");
+        assert_eq!(initials(""), "?");
+    }
+
+    #[test]
+    fn color_is_stable_per_name() {
+        assert_eq!(name_hash("Ada Lovelace"), name_hash("Ada Lovelace"));
+        assert_ne!(name_hash("Ada Lovelace"), name_hash("Grace Hopper"));
+        assert_eq!(name_hash(""), 0x811c_9dc5);
+        assert_eq!(name_hash("a"), 0xe40c_292c);
+    }
+}
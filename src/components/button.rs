@@ -1,11 +1,19 @@
 //! Button - Interactive button component.
 
+use std::rc::Rc;
+
 use gpui::{
-    div, px, rgb, App, InteractiveElement, IntoElement, ParentElement, RenderOnce, SharedString,
-    StatefulInteractiveElement, Styled, Window,
+    div, px, AnyElement, App, ClickEvent, ElementId, FontWeight, InteractiveElement, IntoElement,
+    MouseButton, ParentElement, RenderOnce, SharedString, StatefulInteractiveElement, Styled,
+    Window,
 };
 
+use crate::accessibility::{AccessibilityNode, AccessibilityRole, Accessible};
+use crate::components::ProgressView;
+use crate::environment::{self, EnvironmentScope};
 use crate::modifier::Modifier;
+use crate::shortcut;
+use crate::style::Color;
 use crate::types::ClickHandler;
 
 /// The visual style of a button.
@@ -22,6 +30,198 @@ pub enum ButtonStyle {
     BorderedProminent,
     /// Button with minimal styling.
     Borderless,
+    /// Raised card with a shadow and generous padding.
+    Card,
+}
+
+/// The semantic role of a button, which affects its appearance.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ButtonRole {
+    /// A button that deletes data or performs an irreversible action.
+    ///
+    /// Prominent buttons get a destructive fill, other styles destructive text.
+    Destructive,
+    /// A button that cancels the current operation. Its label is emphasized.
+    Cancel,
+}
+
+/// The size of a control, affecting its padding and font size.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ControlSize {
+    /// The smallest size, for dense toolbars.
+    Mini,
+    /// A compact size.
+    Small,
+    /// The default size.
+    #[default]
+    Regular,
+    /// A large size for primary actions.
+    Large,
+}
+
+impl ControlSize {
+    /// Horizontal and vertical padding in points.
+    fn padding(self) -> (f32, f32) {
+        match self {
+            ControlSize::Mini => (6.0, 2.0),
+            ControlSize::Small => (8.0, 4.0),
+            ControlSize::Regular => (12.0, 6.0),
+            ControlSize::Large => (16.0, 10.0),
+        }
+    }
+
+    /// Font size in points, or `None` to inherit the surrounding size.
+    fn font_size(self) -> Option<f32> {
+        match self {
+            ControlSize::Mini => Some(11.0),
+            ControlSize::Small => Some(12.0),
+            ControlSize::Regular => None,
+            ControlSize::Large => Some(17.0),
+        }
+    }
+}
+
+/// The properties of a button passed to a [`ButtonStyling`].
+pub struct ButtonStyleConfiguration {
+    /// The button's label.
+    pub label: AnyElement,
+    /// The button's role, if any.
+    pub role: Option<ButtonRole>,
+    /// The button's control size.
+    pub control_size: ControlSize,
+    /// Whether the pointer is pressed on the button.
+    pub is_pressed: bool,
+    /// Whether the pointer is over the button.
+    pub is_hovered: bool,
+    /// Whether the button is disabled.
+    pub is_disabled: bool,
+}
+
+/// A type that determines the appearance of buttons, like SwiftUI's `ButtonStyle`.
+///
+/// The button handles interaction and calls [`ButtonStyling::make_body`] with
+/// its current state; the returned element is drawn as the button's body.
+/// The built-in [`ButtonStyle`]s are implemented through this trait.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// struct PillStyle;
+///
+/// impl ButtonStyling for PillStyle {
+///     fn make_body(&self, configuration: ButtonStyleConfiguration, cx: &App) -> AnyElement {
+///         div()
+///             .px(px(16.0))
+///             .py(px(6.0))
+///             .rounded_full()
+///             .bg(Color::accent().resolve_in(cx))
+///             .when(configuration.is_pressed, |this| this.opacity(0.7))
+///             .child(configuration.label)
+///             .into_any_element()
+///     }
+/// }
+///
+/// Button::new("Continue", || {}).style_with(PillStyle)
+/// ```
+pub trait ButtonStyling: 'static {
+    /// Create the body of a button with the given configuration.
+    fn make_body(&self, configuration: ButtonStyleConfiguration, cx: &App) -> AnyElement;
+}
+
+impl ButtonStyling for ButtonStyle {
+    fn make_body(&self, configuration: ButtonStyleConfiguration, cx: &App) -> AnyElement {
+        let ButtonStyleConfiguration {
+            label,
+            role,
+            control_size,
+            is_pressed,
+            is_hovered,
+            is_disabled,
+        } = configuration;
+
+        let (padding_x, padding_y) = match self {
+            ButtonStyle::Card => (20.0, 16.0),
+            _ => control_size.padding(),
+        };
+        let mut body = div().px(px(padding_x)).py(px(padding_y)).child(label);
+
+        // Disabled destructive buttons look muted rather than red
+        let destructive = role == Some(ButtonRole::Destructive) && !is_disabled;
+
+        // Inherit an enclosing foreground color; the prominent style keeps its
+        // own label color for contrast against the fill
+        if *self != ButtonStyle::BorderedProminent {
+            let color = if destructive {
+                Some(Color::destructive())
+            } else {
+                environment::foreground_color(cx)
+            };
+            if let Some(color) = color {
+                body = body.text_color(color.resolve_in(cx));
+            }
+        }
+
+        if role == Some(ButtonRole::Cancel) {
+            body = body.font_weight(FontWeight::SEMIBOLD);
+        }
+
+        // A darker fill on hover and while pressed
+        let darken = if is_pressed {
+            0.06
+        } else if is_hovered {
+            0.03
+        } else {
+            0.0
+        };
+        body = match self {
+            ButtonStyle::Automatic | ButtonStyle::Bordered => {
+                body = body
+                    .border_1()
+                    .border_color(Color::border().resolve_in(cx))
+                    .rounded(px(6.0));
+                if is_pressed {
+                    body = body.bg(Color::muted().darken(0.03).resolve_in(cx));
+                } else if is_hovered {
+                    body = body.bg(Color::muted().resolve_in(cx));
+                }
+                body
+            }
+            ButtonStyle::BorderedProminent => {
+                let fill = if destructive {
+                    Color::destructive()
+                } else {
+                    Color::accent()
+                };
+                body.bg(fill.darken(darken * 2.0).resolve_in(cx))
+                    .text_color(Color::accent_foreground().resolve_in(cx))
+                    .rounded(px(6.0))
+            }
+            ButtonStyle::Plain | ButtonStyle::Borderless => {
+                if is_pressed {
+                    body = body.opacity(0.6);
+                }
+                body
+            }
+            ButtonStyle::Card => {
+                let body = body
+                    .bg(Color::secondary_system_background()
+                        .darken(darken)
+                        .resolve_in(cx))
+                    .rounded(px(12.0));
+                if is_hovered && !is_pressed {
+                    body.shadow_lg()
+                } else {
+                    body.shadow_md()
+                }
+            }
+        };
+
+        if is_disabled {
+            body = body.opacity(0.5);
+        }
+
+        body.into_any_element()
+    }
 }
 
 /// A control that initiates an action.
@@ -42,33 +242,73 @@ pub enum ButtonStyle {
 ///     .button_style(ButtonStyle::BorderedProminent)
 /// ```
 ///
+/// **Custom labels** - use `with_label()` for icons or other views:
+/// ```rust,ignore
+/// Button::with_label(Label::new("trash", "Delete"), || println!("Deleted!"))
+///     .role(ButtonRole::Destructive)
+/// ```
+///
+/// **In a loop** - give each button its own `id()`:
+/// ```rust,ignore
+/// ForEach::new(files, |file| {
+///     let path = file.path.clone();
+///     Button::new("Open", move || open(&path)).id(format!("open-{}", file.id))
+/// })
+/// ```
+///
 /// **With GPUI listener** - for access to view state:
 /// ```rust,ignore
-/// Button::new("Increment", cx.listener(|this, _, _, cx| {
-///     this.count += 1;
-///     cx.notify();
-/// }))
+/// Button::with_id("increment")
+///     .label("Increment")
+///     .on_click_with(cx.listener(|this, _, _, cx| {
+///         this.count += 1;
+///         cx.notify();
+///     }))
 /// ```
 #[derive(IntoElement)]
 pub struct Button {
     id: SharedString,
     label: SharedString,
+    label_view: Option<AnyElement>,
     action: Option<ClickHandler>,
-    style: ButtonStyle,
+    style: Box<dyn ButtonStyling>,
+    role: Option<ButtonRole>,
+    control_size: ControlSize,
     disabled: bool,
+    loading: bool,
 }
 
 impl Button {
     /// Create a new button with a label and action.
+    ///
+    /// The button's ID, which keys its hover and pressed state, is its
+    /// label. Buttons with the same label in the same parent, such as ones
+    /// built in a loop, share that state; give each a distinct
+    /// [`Button::id`].
     pub fn new(label: impl Into<SharedString>, action: impl Fn() + 'static) -> Self {
         let label_str: SharedString = label.into();
         Self {
-            id: label_str.clone(),
-            label: label_str,
-            action: Some(Box::new(move |_, _, _| action())),
-            style: ButtonStyle::default(),
-            disabled: false,
+            label: label_str.clone(),
+            ..Self::with_id(label_str)
         }
+        .on_click(action)
+    }
+
+    /// Create a button whose label is an arbitrary view.
+    ///
+    /// The button's ID is derived from the call site, so every button built
+    /// by the same call, such as in a loop, shares its hover and pressed
+    /// state; give each a distinct [`Button::id`].
+    #[track_caller]
+    pub fn with_label(label: impl IntoElement, action: impl Fn() + 'static) -> Self {
+        let location = std::panic::Location::caller();
+        let id = format!(
+            "{}:{}:{}",
+            location.file(),
+            location.line(),
+            location.column()
+        );
+        Self::with_id(id).label_view(label).on_click(action)
     }
 
     /// Create a button with a custom ID.
@@ -76,27 +316,73 @@ impl Button {
         Self {
             id: id.into(),
             label: SharedString::default(),
+            label_view: None,
             action: None,
-            style: ButtonStyle::default(),
+            style: Box::new(ButtonStyle::default()),
+            role: None,
+            control_size: ControlSize::default(),
             disabled: false,
+            loading: false,
         }
     }
 
+    /// Set the button's ID, which keys its hover and pressed state.
+    pub fn id(mut self, id: impl Into<SharedString>) -> Self {
+        self.id = id.into();
+        self
+    }
+
     /// Set the button label.
     pub fn label(mut self, label: impl Into<SharedString>) -> Self {
         self.label = label.into();
         self
     }
 
+    /// Use a view as the button label, replacing any text label.
+    pub fn label_view(mut self, label: impl IntoElement) -> Self {
+        self.label_view = Some(label.into_any_element());
+        self
+    }
+
     /// Set the action to perform when clicked.
     pub fn on_click(mut self, action: impl Fn() + 'static) -> Self {
         self.action = Some(Box::new(move |_, _, _| action()));
         self
     }
 
+    /// Set the action with GPUI context access.
+    pub fn on_click_with(
+        mut self,
+        handler: impl Fn(&ClickEvent, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.action = Some(Box::new(handler));
+        self
+    }
+
     /// Set the button style.
     pub fn button_style(mut self, style: ButtonStyle) -> Self {
-        self.style = style;
+        self.style = Box::new(style);
+        self
+    }
+
+    /// Restyle the button with a custom [`ButtonStyling`].
+    ///
+    /// The button keeps its behavior (actions, disabled and loading states);
+    /// the style only decides how the body looks.
+    pub fn style_with(mut self, style: impl ButtonStyling) -> Self {
+        self.style = Box::new(style);
+        self
+    }
+
+    /// Set the button's semantic role.
+    pub fn role(mut self, role: ButtonRole) -> Self {
+        self.role = Some(role);
+        self
+    }
+
+    /// Set the control size, which scales padding and font size.
+    pub fn control_size(mut self, size: ControlSize) -> Self {
+        self.control_size = size;
         self
     }
 
@@ -105,47 +391,200 @@ impl Button {
         self.disabled = disabled;
         self
     }
+
+    /// Show a busy state while an action is in progress.
+    ///
+    /// A loading button ignores clicks and shows a spinner over its dimmed
+    /// label, so its width doesn't change when loading is toggled.
+    pub fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
+    /// How the button is described to assistive technology: by its title,
+    /// unless it has a custom label view.
+    fn accessibility(&self) -> AccessibilityNode {
+        let node = AccessibilityNode::new(AccessibilityRole::Button);
+        match self.label_view {
+            Some(_) => node,
+            None => node.label(self.label.clone()),
+        }
+    }
 }
 
 impl Modifier for Button {}
 
+/// Pointer state tracked across renders so styles can react to it.
+#[derive(Default)]
+struct ButtonInteraction {
+    hovered: bool,
+    pressed: bool,
+}
+
 impl RenderOnce for Button {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
-        let id = gpui::ElementId::Name(self.id.clone());
-
-        let mut button = div().id(id).cursor_pointer().px(px(12.0)).py(px(6.0));
-
-        // Apply style
-        button = match self.style {
-            ButtonStyle::Automatic | ButtonStyle::Bordered => button
-                .border_1()
-                .border_color(rgb(0x888888))
-                .rounded(px(6.0)),
-            ButtonStyle::BorderedProminent => button
-                .bg(rgb(0x007AFF))
-                .text_color(rgb(0xFFFFFF))
-                .rounded(px(6.0)),
-            ButtonStyle::Plain => button,
-            ButtonStyle::Borderless => button,
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let id = ElementId::Name(self.id.clone());
+        let interactive = !self.disabled && !self.loading;
+        // Take the shortcut even while disabled, so the label can't
+        let keystroke = shortcut::take(cx);
+        let accessibility = self.accessibility();
+
+        let interaction =
+            window.use_keyed_state(id.clone(), cx, |_, _| ButtonInteraction::default());
+        let (is_hovered, is_pressed) = {
+            let state = interaction.read(cx);
+            (interactive && state.hovered, interactive && state.pressed)
         };
 
-        // Add label
-        button = button.child(self.label);
+        let label = match self.label_view {
+            Some(view) => view,
+            None => self.label.into_any_element(),
+        };
+        // Keep the label in place (dimmed) so the button keeps its width
+        let label = if self.loading {
+            LoadingLabel {
+                label,
+                control_size: self.control_size,
+            }
+            .into_any_element()
+        } else {
+            label
+        };
 
-        // Add click handler
-        if let Some(action) = self.action {
-            if !self.disabled {
-                button = button.on_click(move |event, window, cx| {
-                    action(event, window, cx);
+        let body = self.style.make_body(
+            ButtonStyleConfiguration {
+                label,
+                role: self.role,
+                control_size: self.control_size,
+                is_pressed,
+                is_hovered,
+                is_disabled: self.disabled,
+            },
+            cx,
+        );
+
+        // Views in the label scale from the unscaled size, not ours
+        let font_size = self.control_size.font_size();
+        let scaled_size = environment::scaled_text_size(font_size, window, cx);
+        let unscaled_size = environment::unscaled_text_size(font_size, window, cx);
+        let mut button = div()
+            .id(id.clone())
+            .child(EnvironmentScope::new(body).scaled_text(unscaled_size, scaled_size));
+
+        if let Some(size) = scaled_size {
+            button = button.text_size(size);
+        }
+
+        if !interactive {
+            return Accessible::new(accessibility, button.cursor_default(), cx);
+        }
+
+        let hover_state = interaction.clone();
+        let down_state = interaction.clone();
+        let up_state = interaction.clone();
+        button = button
+            .cursor_pointer()
+            .on_hover(move |hovered, _, cx| {
+                hover_state.update(cx, |state, cx| {
+                    state.hovered = *hovered;
+                    cx.notify();
+                });
+            })
+            .on_mouse_down(MouseButton::Left, move |_, _, cx| {
+                down_state.update(cx, |state, cx| {
+                    state.pressed = true;
+                    cx.notify();
+                });
+            })
+            .on_mouse_up(MouseButton::Left, move |_, _, cx| {
+                up_state.update(cx, |state, cx| {
+                    state.pressed = false;
+                    cx.notify();
+                });
+            })
+            .on_mouse_up_out(MouseButton::Left, move |_, _, cx| {
+                interaction.update(cx, |state, cx| {
+                    state.pressed = false;
+                    cx.notify();
                 });
+            });
+
+        if let Some(action) = self.action {
+            let action = Rc::new(action);
+            if let Some(keystroke) = keystroke {
+                let action = action.clone();
+                shortcut::register(
+                    ElementId::NamedChild(Box::new(id.clone()), "keyboard-shortcut".into()),
+                    keystroke,
+                    Rc::new(move |window, cx| action(&ClickEvent::default(), window, cx)),
+                    window,
+                    cx,
+                );
             }
+            button = button.on_click(move |event, window, cx| {
+                action(event, window, cx);
+            });
         }
 
-        // Apply disabled state
-        if self.disabled {
-            button = button.opacity(0.5).cursor_default();
-        }
+        Accessible::new(accessibility, button, cx)
+    }
+}
+
+/// A dimmed label with a spinner over it, tinted with the inherited text color.
+#[derive(IntoElement)]
+struct LoadingLabel {
+    label: AnyElement,
+    control_size: ControlSize,
+}
+
+impl RenderOnce for LoadingLabel {
+    fn render(self, window: &mut Window, _cx: &mut App) -> impl IntoElement {
+        let tint = window.text_style().color;
+
+        div()
+            .relative()
+            .child(div().opacity(0.3).child(self.label))
+            .child(
+                div()
+                    .absolute()
+                    .inset_0()
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .child(
+                        ProgressView::new()
+                            .control_size(self.control_size)
+                            .tint(tint),
+                    ),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn explicit_ids_separate_buttons_with_the_same_label() {
+        let ids: Vec<SharedString> = (0..2)
+            .map(|i| Button::new("Open", || {}).id(format!("open-{i}")).id)
+            .collect();
+        assert_eq!(ids, vec!["open-0", "open-1"]);
+
+        let looped: Vec<SharedString> = (0..2)
+            .map(|_| Button::with_label("Open", || {}).id)
+            .collect();
+        assert_eq!(looped[0], looped[1], "call-site IDs repeat in a loop");
+    }
 
-        button
+    #[test]
+    fn buttons_are_described_by_their_title() {
+        assert_eq!(
+            Button::new("Save", || {}).accessibility(),
+            AccessibilityNode::new(AccessibilityRole::Button).label("Save")
+        );
+        let custom = Button::with_label("Save", || {}).accessibility();
+        assert_eq!(custom.role, Some(AccessibilityRole::Button));
+        assert_eq!(custom.label, None);
     }
 }
@@ -0,0 +1,272 @@
+//! Canvas - Custom 2D drawing.
+//!
+//! A view that draws with a closure at paint time, for sparklines, charts
+//! and custom indicators. Like shapes, a canvas fills the frame it is given.
+//!
+//! # Usage
+//!
+//! ```rust,ignore
+//! Canvas::new(|painter, bounds| {
+//!     painter.move_to(bounds.bottom_left());
+//!     painter.line_to(bounds.top_right());
+//!     painter.stroke(Color::blue(), 2.0);
+//! })
+//! .frame_size(120.0, 40.0)
+//! ```
+
+use std::f32::consts::{PI, TAU};
+
+use gpui::{
+    canvas, point, px, App, Bounds, IntoElement, PathBuilder, Pixels, Point, RenderOnce, Styled,
+    Window,
+};
+
+use crate::modifier::Modifier;
+use crate::style::{Color, ShapeStyle};
+
+/// Drawing closure called with a painter and the canvas bounds.
+type PaintFn = Box<dyn FnOnce(&mut Painter, Bounds<Pixels>) + 'static>;
+
+/// A view that draws itself with a closure.
+///
+/// The closure runs while the window paints, with the canvas's bounds in
+/// window pixels. Build a path with [`Painter::move_to`],
+/// [`Painter::line_to`] and friends, then [`fill`](Painter::fill) or
+/// [`stroke`](Painter::stroke) it.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// // A sparkline
+/// let values = vec![3.0, 5.0, 2.0, 8.0, 6.0];
+/// Canvas::new(move |painter, bounds| {
+///     let max = values.iter().cloned().fold(f32::MIN, f32::max);
+///     for (ix, value) in values.iter().enumerate() {
+///         let x = bounds.left() + bounds.size.width * (ix as f32 / (values.len() - 1) as f32);
+///         let y = bounds.bottom() - bounds.size.height * (value / max);
+///         if ix == 0 {
+///             painter.move_to(point(x, y));
+///         } else {
+///             painter.line_to(point(x, y));
+///         }
+///     }
+///     painter.stroke(Color::green(), 1.5);
+/// })
+/// .frame_size(120.0, 32.0)
+/// ```
+#[derive(IntoElement)]
+pub struct Canvas {
+    paint: PaintFn,
+}
+
+impl Canvas {
+    /// Create a canvas drawn by `paint`.
+    pub fn new(paint: impl FnOnce(&mut Painter, Bounds<Pixels>) + 'static) -> Self {
+        Self {
+            paint: Box::new(paint),
+        }
+    }
+}
+
+impl Modifier for Canvas {}
+
+impl RenderOnce for Canvas {
+    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+        let paint = self.paint;
+        canvas(
+            |_, _, _| {},
+            move |bounds, _, window, cx| {
+                let mut painter = Painter {
+                    window,
+                    cx,
+                    path: Vec::new(),
+                };
+                paint(&mut painter, bounds);
+            },
+        )
+        .size_full()
+    }
+}
+
+/// A step of the path being built.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum PathOp {
+    MoveTo(Point<Pixels>),
+    LineTo(Point<Pixels>),
+    QuadTo {
+        control: Point<Pixels>,
+        to: Point<Pixels>,
+    },
+    ArcTo {
+        radius: Pixels,
+        large_arc: bool,
+        to: Point<Pixels>,
+    },
+    Close,
+}
+
+/// The drawing API passed to a [`Canvas`].
+///
+/// Points are in window pixels, like the bounds the canvas is given. The
+/// current path stays after it is filled or stroked, so it can be both;
+/// call [`begin_path`](Self::begin_path) to start another.
+pub struct Painter<'a> {
+    window: &'a mut Window,
+    cx: &'a mut App,
+    path: Vec<PathOp>,
+}
+
+impl Painter<'_> {
+    /// Clear the current path.
+    pub fn begin_path(&mut self) {
+        self.path.clear();
+    }
+
+    /// Start a new subpath at `to`.
+    pub fn move_to(&mut self, to: Point<Pixels>) {
+        self.path.push(PathOp::MoveTo(to));
+    }
+
+    /// Add a straight line to `to`.
+    pub fn line_to(&mut self, to: Point<Pixels>) {
+        self.path.push(PathOp::LineTo(to));
+    }
+
+    /// Add a quadratic curve to `to`, bending towards `control`.
+    pub fn quad_to(&mut self, control: Point<Pixels>, to: Point<Pixels>) {
+        self.path.push(PathOp::QuadTo { control, to });
+    }
+
+    /// Add an arc of the circle around `center`, clockwise from
+    /// `start_angle` to `end_angle` in radians, where 0 points right.
+    ///
+    /// A line joins the current point to the start of the arc, so
+    /// `move_to(center)`, `arc(..)`, `close()` draws a pie wedge.
+    pub fn arc(&mut self, center: Point<Pixels>, radius: Pixels, start_angle: f32, end_angle: f32) {
+        let start = point_on_circle(center, radius, start_angle);
+        if self.path.is_empty() {
+            self.move_to(start);
+        } else {
+            self.line_to(start);
+        }
+        self.push_arc(center, radius, start_angle, end_angle - start_angle);
+    }
+
+    /// Add a closed circle around `center`.
+    pub fn circle(&mut self, center: Point<Pixels>, radius: Pixels) {
+        self.move_to(point_on_circle(center, radius, 0.0));
+        self.push_arc(center, radius, 0.0, TAU);
+        self.close();
+    }
+
+    /// Continue the path along the circle from `start_angle`, turning
+    /// clockwise by `sweep` radians.
+    fn push_arc(&mut self, center: Point<Pixels>, radius: Pixels, start_angle: f32, sweep: f32) {
+        // An arc can't end where it starts, so a full turn is two halves
+        let mut angle = start_angle;
+        for part in split_sweep(sweep.clamp(0.0, TAU)) {
+            angle += part;
+            self.path.push(PathOp::ArcTo {
+                radius,
+                large_arc: part > PI,
+                to: point_on_circle(center, radius, angle),
+            });
+        }
+    }
+
+    /// Add a closed rectangle.
+    pub fn rect(&mut self, bounds: Bounds<Pixels>) {
+        self.move_to(bounds.origin);
+        self.line_to(bounds.top_right());
+        self.line_to(bounds.bottom_right());
+        self.line_to(bounds.bottom_left());
+        self.close();
+    }
+
+    /// Close the current subpath with a line back to its start.
+    pub fn close(&mut self) {
+        self.path.push(PathOp::Close);
+    }
+
+    /// Fill the current path with a color or gradient.
+    pub fn fill(&mut self, style: impl ShapeStyle) {
+        let background = style.resolve_background(self.cx);
+        if let Some(path) = build_path(PathBuilder::fill(), &self.path) {
+            self.window.paint_path(path, background);
+        }
+    }
+
+    /// Draw the current path with a line `width` points wide.
+    pub fn stroke(&mut self, color: impl Into<Color>, width: f32) {
+        let color = color.into().resolve_in(self.cx);
+        if let Some(path) = build_path(PathBuilder::stroke(px(width)), &self.path) {
+            self.window.paint_path(path, color);
+        }
+    }
+
+    /// Device pixels per point, for drawings that snap to the pixel grid.
+    pub fn scale_factor(&self) -> f32 {
+        self.window.scale_factor()
+    }
+}
+
+/// Replay `ops` into `builder`, or `None` for an empty or invalid path.
+fn build_path(mut builder: PathBuilder, ops: &[PathOp]) -> Option<gpui::Path<Pixels>> {
+    if ops.is_empty() {
+        return None;
+    }
+    for op in ops {
+        match *op {
+            PathOp::MoveTo(to) => builder.move_to(to),
+            PathOp::LineTo(to) => builder.line_to(to),
+            PathOp::QuadTo { control, to } => builder.curve_to(to, control),
+            PathOp::ArcTo {
+                radius,
+                large_arc,
+                to,
+            } => builder.arc_to(point(radius, radius), px(0.0), large_arc, true, to),
+            PathOp::Close => builder.close(),
+        }
+    }
+    builder.build().ok()
+}
+
+fn point_on_circle(center: Point<Pixels>, radius: Pixels, angle: f32) -> Point<Pixels> {
+    point(
+        center.x + radius * angle.cos(),
+        center.y + radius * angle.sin(),
+    )
+}
+
+/// Split a clockwise sweep into arcs that each end away from their start.
+fn split_sweep(sweep: f32) -> Vec<f32> {
+    if sweep <= 0.0 {
+        Vec::new()
+    } else if sweep >= TAU - f32::EPSILON {
+        vec![PI, PI]
+    } else {
+        vec![sweep]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn full_turns_are_split_in_half() {
+        assert_eq!(split_sweep(0.0), Vec::<f32>::new());
+        assert_eq!(split_sweep(PI / 2.0), vec![PI / 2.0]);
+        assert_eq!(split_sweep(TAU), vec![PI, PI]);
+    }
+
+    #[test]
+    fn angles_run_clockwise_from_the_right() {
+        let center = point(px(10.0), px(10.0));
+        let right = point_on_circle(center, px(5.0), 0.0);
+        let below = point_on_circle(center, px(5.0), PI / 2.0);
+        assert_eq!(right, point(px(15.0), px(10.0)));
+        assert!((f32::from(below.x) - 10.0).abs() < 1e-4);
+        assert!((f32::from(below.y) - 15.0).abs() < 1e-4);
+    }
+}
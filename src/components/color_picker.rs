@@ -0,0 +1,677 @@
+//! ColorPicker - Color selection component.
+//!
+//! A swatch showing the current color that opens a popover with a
+//! saturation/brightness square, hue and opacity sliders, a hex field and
+//! preset swatches.
+//!
+//! # Usage
+//!
+//! ```rust,ignore
+//! ColorPicker::new("card-color", self.card_color, cx.listener(|this, color: &Color, _, cx| {
+//!     this.card_color = *color;
+//!     cx.notify();
+//! }))
+//! .supports_opacity(false)
+//! ```
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use gpui::{
+    canvas, div, linear_color_stop, linear_gradient, px, relative, App, AppContext, Bounds,
+    Context, Corner, DragMoveEvent, ElementId, EmptyView, Entity, EntityId, Hsla,
+    InteractiveElement, IntoElement, MouseButton, ParentElement, Pixels, Point, RenderOnce,
+    SharedString, StatefulInteractiveElement, Styled, Subscription, Window,
+};
+use gpui_component::input::InputEvent;
+use gpui_component::popover::Popover;
+use gpui_component::{ActiveTheme, Selectable};
+
+use crate::components::{InputState, Text, TextField};
+use crate::modifier::Modifier;
+use crate::style::{Color, Font};
+
+/// Handler called with the color picked.
+pub type ColorHandler = Rc<dyn Fn(&Color, &mut Window, &mut App) + 'static>;
+
+/// Width of the popover's square and sliders.
+const PANEL_WIDTH: Pixels = px(200.0);
+/// Diameter of the drag thumbs.
+const THUMB_SIZE: Pixels = px(14.0);
+
+/// A control for picking a color.
+///
+/// Shows a swatch of the current color. Clicking it opens a popover to pick
+/// saturation and brightness from a square, hue and opacity from sliders,
+/// type a hex value, or choose a preset.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// ColorPicker::new("accent", self.accent, cx.listener(|this, color: &Color, _, cx| {
+///     this.accent = *color;
+///     cx.notify();
+/// }))
+/// .presets([Color::red(), Color::green(), Color::blue()])
+/// ```
+#[derive(IntoElement)]
+pub struct ColorPicker {
+    id: ElementId,
+    color: Color,
+    on_change: ColorHandler,
+    supports_opacity: bool,
+    presets: Vec<Color>,
+    disabled: bool,
+}
+
+impl ColorPicker {
+    /// Create a color picker showing `color`.
+    ///
+    /// The handler is called with each new color; the picker always shows
+    /// the color it was last created with.
+    pub fn new(
+        id: impl Into<ElementId>,
+        color: impl Into<Color>,
+        on_change: impl Fn(&Color, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            color: color.into(),
+            on_change: Rc::new(on_change),
+            supports_opacity: true,
+            presets: vec![
+                Color::red(),
+                Color::orange(),
+                Color::yellow(),
+                Color::green(),
+                Color::teal(),
+                Color::blue(),
+                Color::indigo(),
+                Color::purple(),
+                Color::pink(),
+                Color::brown(),
+                Color::gray(),
+                Color::black(),
+            ],
+            disabled: false,
+        }
+    }
+
+    /// Show an opacity slider and include alpha in the hex value.
+    ///
+    /// Defaults to `true`. Without it, picked colors are fully opaque.
+    pub fn supports_opacity(mut self, supports_opacity: bool) -> Self {
+        self.supports_opacity = supports_opacity;
+        self
+    }
+
+    /// Set the preset colors shown below the hex field.
+    pub fn presets(mut self, presets: impl IntoIterator<Item = Color>) -> Self {
+        self.presets = presets.into_iter().collect();
+        self
+    }
+
+    /// Disable the color picker.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+impl Modifier for ColorPicker {}
+
+impl RenderOnce for ColorPicker {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let mut color = self.color.resolve_in(cx);
+        if !self.supports_opacity {
+            color.a = 1.0;
+        }
+
+        if self.disabled {
+            return div()
+                .opacity(0.5)
+                .child(Swatch::new(color))
+                .into_any_element();
+        }
+
+        let state_id = ElementId::NamedChild(Box::new(self.id.clone()), "color-picker".into());
+        let state = window.use_keyed_state(state_id, cx, |window, cx| {
+            ColorPickerState::new(color, window, cx)
+        });
+        state.update(cx, |state, cx| {
+            state.on_change = Some(self.on_change);
+            state.supports_opacity = self.supports_opacity;
+            state.sync(color, window, cx);
+        });
+
+        let presets: Vec<Hsla> = self
+            .presets
+            .iter()
+            .map(|preset| preset.resolve_in(cx))
+            .collect();
+        let supports_opacity = self.supports_opacity;
+
+        Popover::new(self.id)
+            .anchor(Corner::TopLeft)
+            .trigger(Swatch::new(color))
+            .content(move |_, _, cx| panel(&state, supports_opacity, &presets, cx))
+            .into_any_element()
+    }
+}
+
+/// The popover's contents.
+fn panel(
+    state: &Entity<ColorPickerState>,
+    supports_opacity: bool,
+    presets: &[Hsla],
+    cx: &App,
+) -> impl IntoElement {
+    let model = state.read(cx);
+    let hsv = model.hsv;
+    let alpha = model.alpha;
+    let hue = Hsv {
+        s: 1.0,
+        v: 1.0,
+        ..hsv
+    }
+    .to_hsla(1.0);
+    let opaque = hsv.to_hsla(1.0);
+    let hex_input = model.hex_input.clone();
+
+    let square = drag_area(state, DragTarget::SaturationBrightness)
+        .w(PANEL_WIDTH)
+        .h(px(150.0))
+        .rounded(px(6.0))
+        .overflow_hidden()
+        .bg(hue)
+        .child(div().absolute().size_full().bg(linear_gradient(
+            90.0,
+            linear_color_stop(gpui::white(), 0.0),
+            linear_color_stop(gpui::white().opacity(0.0), 1.0),
+        )))
+        .child(div().absolute().size_full().bg(linear_gradient(
+            180.0,
+            linear_color_stop(gpui::black().opacity(0.0), 0.0),
+            linear_color_stop(gpui::black(), 1.0),
+        )))
+        .child(
+            thumb(opaque)
+                .left(relative(hsv.s))
+                .top(relative(1.0 - hsv.v)),
+        );
+
+    // Two-stop gradients between the hue wheel's six primaries
+    let hue_segments = (0..6).map(|segment| {
+        let from = Hsv {
+            h: segment as f32 / 6.0,
+            s: 1.0,
+            v: 1.0,
+        }
+        .to_hsla(1.0);
+        let to = Hsv {
+            h: (segment + 1) as f32 / 6.0,
+            s: 1.0,
+            v: 1.0,
+        }
+        .to_hsla(1.0);
+        div().flex_1().h_full().bg(linear_gradient(
+            90.0,
+            linear_color_stop(from, 0.0),
+            linear_color_stop(to, 1.0),
+        ))
+    });
+    let hue_slider = slider_track(drag_area(state, DragTarget::Hue))
+        .child(
+            div()
+                .flex()
+                .size_full()
+                .rounded_full()
+                .overflow_hidden()
+                .children(hue_segments),
+        )
+        .child(thumb(hue).left(relative(hsv.h)).top(relative(0.5)));
+
+    let alpha_slider = supports_opacity.then(|| {
+        slider_track(drag_area(state, DragTarget::Alpha))
+            .child(
+                div()
+                    .absolute()
+                    .size_full()
+                    .rounded_full()
+                    .bg(gpui::white())
+                    .child(div().size_full().rounded_full().bg(linear_gradient(
+                        90.0,
+                        linear_color_stop(opaque.opacity(0.0), 0.0),
+                        linear_color_stop(opaque, 1.0),
+                    ))),
+            )
+            .child(
+                thumb(opaque.opacity(alpha))
+                    .left(relative(alpha))
+                    .top(relative(0.5)),
+            )
+    });
+
+    let preset_swatches = presets.iter().enumerate().map(|(ix, preset)| {
+        let preset = *preset;
+        let state = state.clone();
+        div()
+            .id(ix)
+            .size(px(16.0))
+            .rounded_full()
+            .border_1()
+            .border_color(gpui::black().opacity(0.1))
+            .bg(preset)
+            .cursor_pointer()
+            .on_click(move |_, window, cx| {
+                state.update(cx, |state, cx| state.set_color(preset, window, cx));
+            })
+    });
+
+    div()
+        .flex()
+        .flex_col()
+        .gap(px(12.0))
+        .w(PANEL_WIDTH)
+        .child(square)
+        .child(hue_slider)
+        .children(alpha_slider)
+        .child(
+            TextField::new(&hex_input).prefix(
+                Text::new("Hex")
+                    .font(Font::caption())
+                    .foreground_color(Color::secondary_label()),
+            ),
+        )
+        .child(
+            div()
+                .flex()
+                .flex_wrap()
+                .gap(px(8.0))
+                .children(preset_swatches),
+        )
+}
+
+/// A horizontal track for the hue and opacity sliders.
+fn slider_track(area: gpui::Stateful<gpui::Div>) -> gpui::Stateful<gpui::Div> {
+    area.w(PANEL_WIDTH).h(px(12.0))
+}
+
+/// A round thumb centered on the position set with `left` and `top`.
+fn thumb(color: Hsla) -> gpui::Div {
+    div()
+        .absolute()
+        .size(THUMB_SIZE)
+        .ml(-THUMB_SIZE / 2.0)
+        .mt(-THUMB_SIZE / 2.0)
+        .rounded_full()
+        .border_2()
+        .border_color(gpui::white())
+        .shadow_sm()
+        .bg(color)
+}
+
+/// An element that picks a value from where it is pressed and dragged.
+fn drag_area(state: &Entity<ColorPickerState>, target: DragTarget) -> gpui::Stateful<gpui::Div> {
+    let drag = ColorDrag {
+        picker: state.entity_id(),
+        target,
+    };
+    let bounds = Rc::new(Cell::new(Bounds::default()));
+
+    div()
+        .id(target as usize)
+        .relative()
+        .cursor_pointer()
+        .child(
+            canvas(
+                {
+                    let bounds = bounds.clone();
+                    move |frame, _, _| bounds.set(frame)
+                },
+                |_, _, _, _| {},
+            )
+            .absolute()
+            .size_full(),
+        )
+        .on_mouse_down(MouseButton::Left, {
+            let state = state.clone();
+            move |event, window, cx| {
+                let position = fraction(bounds.get(), event.position);
+                state.update(cx, |state, cx| state.drag(target, position, window, cx));
+            }
+        })
+        .on_drag(drag, |_, _, _, cx| cx.new(|_| EmptyView))
+        .on_drag_move({
+            let state = state.clone();
+            move |event: &DragMoveEvent<ColorDrag>, window, cx| {
+                // Every drag area sees every color drag; only follow our own
+                if *event.drag(cx) != drag {
+                    return;
+                }
+                let position = fraction(event.bounds, event.event.position);
+                state.update(cx, |state, cx| state.drag(target, position, window, cx));
+            }
+        })
+}
+
+/// Where `position` falls within `bounds`, from (0, 0) at the top left to
+/// (1, 1) at the bottom right, clamped to the bounds.
+fn fraction(bounds: Bounds<Pixels>, position: Point<Pixels>) -> Point<f32> {
+    let offset = position - bounds.origin;
+    let x = if bounds.size.width > px(0.0) {
+        offset.x / bounds.size.width
+    } else {
+        0.0
+    };
+    let y = if bounds.size.height > px(0.0) {
+        offset.y / bounds.size.height
+    } else {
+        0.0
+    };
+    Point::new(x.clamp(0.0, 1.0), y.clamp(0.0, 1.0))
+}
+
+/// The part of the popover being dragged.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum DragTarget {
+    SaturationBrightness,
+    Hue,
+    Alpha,
+}
+
+/// The value carried by a drag in a color picker.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct ColorDrag {
+    picker: EntityId,
+    target: DragTarget,
+}
+
+/// The swatch that opens the popover.
+#[derive(IntoElement)]
+struct Swatch {
+    color: Hsla,
+    selected: bool,
+}
+
+impl Swatch {
+    fn new(color: Hsla) -> Self {
+        Self {
+            color,
+            selected: false,
+        }
+    }
+}
+
+impl Selectable for Swatch {
+    fn selected(mut self, selected: bool) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    fn is_selected(&self) -> bool {
+        self.selected
+    }
+}
+
+impl RenderOnce for Swatch {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let border = if self.selected {
+            cx.theme().ring
+        } else {
+            cx.theme().border
+        };
+        div()
+            .size(px(28.0))
+            .p(px(3.0))
+            .rounded(px(6.0))
+            .border_1()
+            .border_color(border)
+            .cursor_pointer()
+            .child(div().size_full().rounded(px(4.0)).bg(self.color))
+    }
+}
+
+/// The retained state of a color picker.
+///
+/// Hue, saturation and brightness are kept separately from the color so
+/// dragging through gray or black doesn't lose the hue.
+struct ColorPickerState {
+    hsv: Hsv,
+    alpha: f32,
+    /// The color last shown or picked.
+    color: Hsla,
+    hex: SharedString,
+    hex_input: Entity<InputState>,
+    supports_opacity: bool,
+    on_change: Option<ColorHandler>,
+    _subscription: Subscription,
+}
+
+impl ColorPickerState {
+    fn new(color: Hsla, window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let hex = SharedString::from(to_hex(color, true));
+        let hex_input = cx.new(|cx| InputState::new(window, cx).default_value(hex.clone()));
+        let subscription = cx.subscribe_in(&hex_input, window, |this, input, event, window, cx| {
+            match event {
+                InputEvent::Change => {
+                    let text = input.read(cx).value();
+                    if text == this.hex {
+                        return;
+                    }
+                    // Apply complete hex values as they are typed
+                    if let Some(color) = parse_hex(&text) {
+                        this.hex = text;
+                        this.pick(Hsv::from_hsla(color, this.hsv.h), color.a, window, cx);
+                    }
+                }
+                // Tidy up partial or lowercase input once editing ends
+                InputEvent::PressEnter { .. } | InputEvent::Blur => {
+                    this.hex = SharedString::default();
+                    this.set_hex(window, cx);
+                }
+                InputEvent::Focus => {}
+            }
+        });
+
+        Self {
+            hsv: Hsv::from_hsla(color, 0.0),
+            alpha: color.a,
+            color,
+            hex,
+            hex_input,
+            supports_opacity: true,
+            on_change: None,
+            _subscription: subscription,
+        }
+    }
+
+    /// Show `color` unless it is the color already shown.
+    fn sync(&mut self, color: Hsla, window: &mut Window, cx: &mut Context<Self>) {
+        if color != self.color {
+            self.hsv = Hsv::from_hsla(color, self.hsv.h);
+            self.alpha = color.a;
+            self.color = color;
+            self.set_hex(window, cx);
+        }
+    }
+
+    /// Pick a preset color.
+    fn set_color(&mut self, color: Hsla, window: &mut Window, cx: &mut Context<Self>) {
+        self.pick(Hsv::from_hsla(color, self.hsv.h), color.a, window, cx);
+        self.set_hex(window, cx);
+    }
+
+    /// Pick from a position in the square or a slider.
+    fn drag(
+        &mut self,
+        target: DragTarget,
+        position: Point<f32>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        let mut hsv = self.hsv;
+        let mut alpha = self.alpha;
+        match target {
+            DragTarget::SaturationBrightness => {
+                hsv.s = position.x;
+                hsv.v = 1.0 - position.y;
+            }
+            DragTarget::Hue => hsv.h = position.x,
+            DragTarget::Alpha => alpha = position.x,
+        }
+        self.pick(hsv, alpha, window, cx);
+        self.set_hex(window, cx);
+    }
+
+    fn pick(&mut self, hsv: Hsv, alpha: f32, window: &mut Window, cx: &mut Context<Self>) {
+        let alpha = if self.supports_opacity { alpha } else { 1.0 };
+        self.hsv = hsv;
+        self.alpha = alpha;
+        self.color = hsv.to_hsla(alpha);
+        cx.notify();
+
+        if let Some(on_change) = self.on_change.clone() {
+            on_change(&Color::from(self.color), window, cx);
+        }
+    }
+
+    /// Show the current color in the hex field.
+    fn set_hex(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let hex = SharedString::from(to_hex(self.color, self.supports_opacity));
+        if hex != self.hex {
+            self.hex = hex.clone();
+            self.hex_input
+                .update(cx, |input, cx| input.set_value(hex, window, cx));
+        }
+    }
+}
+
+/// A color as hue, saturation and brightness, each from 0 to 1.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Hsv {
+    h: f32,
+    s: f32,
+    v: f32,
+}
+
+impl Hsv {
+    /// Convert from HSL, keeping `hue` for colors without one.
+    fn from_hsla(color: Hsla, hue: f32) -> Self {
+        let v = color.l + color.s * color.l.min(1.0 - color.l);
+        let s = if v > 0.0 {
+            2.0 * (1.0 - color.l / v)
+        } else {
+            0.0
+        };
+        let h = if s > 0.0 { color.h } else { hue };
+        Self { h, s, v }
+    }
+
+    fn to_hsla(self, alpha: f32) -> Hsla {
+        let l = self.v * (1.0 - self.s / 2.0);
+        let s = if l > 0.0 && l < 1.0 {
+            (self.v - l) / l.min(1.0 - l)
+        } else {
+            0.0
+        };
+        Hsla {
+            h: self.h.rem_euclid(1.0),
+            s,
+            l,
+            a: alpha,
+        }
+    }
+}
+
+/// Format a color as `#RRGGBB`, or `#RRGGBBAA` when translucent and
+/// `with_alpha` is set.
+fn to_hex(color: Hsla, with_alpha: bool) -> String {
+    let rgba = color.to_rgb();
+    let channel = |value: f32| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+    let mut hex = format!(
+        "#{:02X}{:02X}{:02X}",
+        channel(rgba.r),
+        channel(rgba.g),
+        channel(rgba.b)
+    );
+    if with_alpha && channel(rgba.a) < 255 {
+        hex.push_str(&format!("{:02X}", channel(rgba.a)));
+    }
+    hex
+}
+
+/// Parse `#RGB`, `#RRGGBB` or `#RRGGBBAA`, with or without the `#`.
+fn parse_hex(text: &str) -> Option<Hsla> {
+    let digits = text.trim();
+    let digits = digits.strip_prefix('#').unwrap_or(digits);
+    if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let value = match digits.len() {
+        3 => {
+            let short = u32::from_str_radix(digits, 16).ok()?;
+            let expand = |nibble: u32| (nibble & 0xF) * 0x11;
+            (expand(short >> 8) << 24) | (expand(short >> 4) << 16) | (expand(short) << 8) | 0xFF
+        }
+        6 => (u32::from_str_radix(digits, 16).ok()? << 8) | 0xFF,
+        8 => u32::from_str_radix(digits, 16).ok()?,
+        _ => return None,
+    };
+    Some(gpui::rgba(value).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: f32, b: f32) {
+        assert!((a - b).abs() < 1e-4, "{a} != {b}");
+    }
+
+    #[test]
+    fn hsv_round_trips_through_hsla() {
+        let hsv = Hsv {
+            h: 0.6,
+            s: 0.4,
+            v: 0.8,
+        };
+        let back = Hsv::from_hsla(hsv.to_hsla(1.0), 0.0);
+        assert_close(back.h, hsv.h);
+        assert_close(back.s, hsv.s);
+        assert_close(back.v, hsv.v);
+    }
+
+    #[test]
+    fn gray_keeps_the_previous_hue() {
+        let gray = Hsla {
+            h: 0.0,
+            s: 0.0,
+            l: 0.5,
+            a: 1.0,
+        };
+        let hsv = Hsv::from_hsla(gray, 0.3);
+        assert_close(hsv.h, 0.3);
+        assert_close(hsv.s, 0.0);
+        assert_close(hsv.v, 0.5);
+    }
+
+    #[test]
+    fn formats_and_parses_hex() {
+        let orange = parse_hex("#FF8000").unwrap();
+        assert_eq!(to_hex(orange, true), "#FF8000");
+        assert_eq!(to_hex(parse_hex("f80").unwrap(), true), "#FF8800");
+
+        let translucent = parse_hex("#FF800080").unwrap();
+        assert_eq!(to_hex(translucent, true), "#FF800080");
+        assert_eq!(to_hex(translucent, false), "#FF8000");
+    }
+
+    #[test]
+    fn rejects_malformed_hex() {
+        assert_eq!(parse_hex(""), None);
+        assert_eq!(parse_hex("#12345"), None);
+        assert_eq!(parse_hex("#GG0000"), None);
+        assert_eq!(parse_hex("#+12345"), None);
+    }
+}
@@ -0,0 +1,408 @@
+//! ComboBox - Text field with filtered suggestions.
+//!
+//! A text field that shows a dropdown of matching suggestions as you type,
+//! like a country selector or tag input.
+//!
+//! # Usage
+//!
+//! ```rust,ignore
+//! struct FormView {
+//!     country_input: Entity<InputState>,
+//! }
+//!
+//! // In render:
+//! ComboBox::new("country", &self.country_input, countries.clone())
+//!     .max_visible(6)
+//!     .on_select(cx.listener(|this, country: &SharedString, _, cx| {
+//!         this.country = Some(country.clone());
+//!         cx.notify();
+//!     }))
+//! ```
+
+use std::rc::Rc;
+
+use gpui::{
+    anchored, canvas, deferred, div, point, px, App, Bounds, Context, ElementId, Entity,
+    InteractiveElement, IntoElement, MouseButton, ParentElement, Pixels, RenderOnce, ScrollHandle,
+    SharedString, StatefulInteractiveElement, Styled, Subscription, Window,
+};
+use gpui_component::input::{Escape, InputEvent, MoveDown, MoveUp};
+use gpui_component::ActiveTheme;
+
+use crate::components::{InputState, TextField};
+use crate::modifier::Modifier;
+
+/// Handler called with the suggestion picked.
+pub type SuggestionHandler = Rc<dyn Fn(&SharedString, &mut Window, &mut App) + 'static>;
+
+/// Decides whether a suggestion matches the text typed, called as
+/// `(suggestion, query)`.
+pub type SuggestionFilter = Rc<dyn Fn(&str, &str) -> bool + 'static>;
+
+/// Height of a row in the suggestion list.
+const ROW_HEIGHT: Pixels = px(28.0);
+
+/// A text field with a dropdown of suggestions filtered by what is typed.
+///
+/// Typing opens the dropdown below the field. Up and down move through the
+/// matches, Enter or a click picks one, and Escape closes the dropdown.
+/// Picking a suggestion puts it in the field and calls the
+/// [`on_select`](Self::on_select) handler.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// ComboBox::new("fruit", &fruit_input, fruits)
+///     // Match suggestions that start with the query
+///     .filter(|suggestion, query| {
+///         suggestion.to_lowercase().starts_with(&query.to_lowercase())
+///     })
+///     .on_select(|fruit, _, _| println!("Picked {fruit}"))
+/// ```
+#[derive(IntoElement)]
+pub struct ComboBox {
+    id: ElementId,
+    state: Entity<InputState>,
+    suggestions: Vec<SharedString>,
+    max_visible: usize,
+    filter: SuggestionFilter,
+    on_select: Option<SuggestionHandler>,
+    disabled: bool,
+}
+
+impl ComboBox {
+    /// Create a combo box editing `state` and suggesting from `suggestions`.
+    pub fn new(
+        id: impl Into<ElementId>,
+        state: &Entity<InputState>,
+        suggestions: Vec<SharedString>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            state: state.clone(),
+            suggestions,
+            max_visible: 8,
+            filter: Rc::new(contains_ignoring_case),
+            on_select: None,
+            disabled: false,
+        }
+    }
+
+    /// Set how many suggestions show before the list scrolls.
+    ///
+    /// Defaults to 8.
+    pub fn max_visible(mut self, max_visible: usize) -> Self {
+        self.max_visible = max_visible.max(1);
+        self
+    }
+
+    /// Set how suggestions are matched against the text typed.
+    ///
+    /// The function is called with the suggestion and the query. Defaults to
+    /// a case-insensitive substring match.
+    pub fn filter(mut self, filter: impl Fn(&str, &str) -> bool + 'static) -> Self {
+        self.filter = Rc::new(filter);
+        self
+    }
+
+    /// Call a handler with the suggestion picked.
+    pub fn on_select(
+        mut self,
+        handler: impl Fn(&SharedString, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_select = Some(Rc::new(handler));
+        self
+    }
+
+    /// Disable the combo box.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+impl Modifier for ComboBox {}
+
+impl RenderOnce for ComboBox {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let field = TextField::new(&self.state).disabled(self.disabled);
+        if self.disabled {
+            return div().child(field).into_any_element();
+        }
+
+        let query = self.state.read(cx).value();
+        let matches: Vec<SharedString> = self
+            .suggestions
+            .into_iter()
+            .filter(|suggestion| (self.filter)(suggestion, &query))
+            .collect();
+
+        let state_id = ElementId::NamedChild(Box::new(self.id.clone()), "combo-box".into());
+        let input = self.state.clone();
+        let combo = window.use_keyed_state(state_id, cx, |window, cx| {
+            ComboBoxState::new(&input, window, cx)
+        });
+        combo.update(cx, |combo, _| {
+            combo.on_select = self.on_select;
+            combo.set_matches(matches);
+        });
+
+        let model = combo.read(cx);
+        let open = model.open && !model.matches.is_empty();
+        let popup = open.then(|| popup(&combo, &self.state, self.max_visible, cx));
+
+        div()
+            .id(self.id)
+            .relative()
+            .capture_action({
+                let combo = combo.clone();
+                move |_: &MoveUp, _, cx| {
+                    combo.update(cx, |combo, cx| {
+                        if combo.move_highlight(-1, cx) {
+                            cx.stop_propagation();
+                        }
+                    })
+                }
+            })
+            .capture_action({
+                let combo = combo.clone();
+                move |_: &MoveDown, _, cx| {
+                    combo.update(cx, |combo, cx| {
+                        if combo.move_highlight(1, cx) {
+                            cx.stop_propagation();
+                        }
+                    })
+                }
+            })
+            .capture_action({
+                let combo = combo.clone();
+                move |_: &Escape, _, cx| {
+                    combo.update(cx, |combo, cx| {
+                        if combo.open {
+                            combo.close(cx);
+                            cx.stop_propagation();
+                        }
+                    })
+                }
+            })
+            .child(field)
+            .child(
+                canvas(
+                    move |bounds, _, cx| combo.update(cx, |combo, _| combo.bounds = bounds),
+                    |_, _, _, _| {},
+                )
+                .absolute()
+                .size_full(),
+            )
+            .children(popup)
+            .into_any_element()
+    }
+}
+
+/// The dropdown of matches, anchored below the field at its width.
+fn popup(
+    combo: &Entity<ComboBoxState>,
+    input: &Entity<InputState>,
+    max_visible: usize,
+    cx: &App,
+) -> impl IntoElement {
+    let model = combo.read(cx);
+    let theme = cx.theme();
+    let bounds = model.bounds;
+    let visible = model.matches.len().min(max_visible);
+
+    let rows = model.matches.iter().enumerate().map(|(ix, suggestion)| {
+        let highlighted = model.highlighted == Some(ix);
+        let combo = combo.clone();
+        let input = input.clone();
+        let suggestion_value = suggestion.clone();
+        let mut row = div()
+            .id(ix)
+            .flex()
+            .items_center()
+            .h(ROW_HEIGHT)
+            .px(px(8.0))
+            .rounded(px(4.0))
+            .cursor_pointer()
+            .child(suggestion.clone())
+            // Pick on mouse down, before the field loses focus and closes us
+            .on_mouse_down(MouseButton::Left, move |_, window, cx| {
+                combo.update(cx, |combo, cx| {
+                    combo.select(&input, suggestion_value.clone(), window, cx)
+                });
+                cx.stop_propagation();
+            });
+        if highlighted {
+            row = row.bg(theme.accent).text_color(theme.accent_foreground);
+        } else {
+            let hover = theme.accent;
+            row = row.hover(move |style| style.bg(hover));
+        }
+        row
+    });
+
+    deferred(
+        anchored()
+            .snap_to_window_with_margin(px(8.0))
+            .position(bounds.bottom_left() + point(px(0.0), px(4.0)))
+            .child(
+                div()
+                    .id("combo-box-suggestions")
+                    .track_scroll(&model.scroll_handle)
+                    .occlude()
+                    .w(bounds.size.width)
+                    .max_h(ROW_HEIGHT * visible as f32 + px(10.0))
+                    .overflow_y_scroll()
+                    .p(px(4.0))
+                    .bg(theme.popover)
+                    .text_color(theme.popover_foreground)
+                    .border_1()
+                    .border_color(theme.border)
+                    .rounded(px(8.0))
+                    .shadow_md()
+                    .children(rows),
+            ),
+    )
+    .with_priority(1)
+}
+
+/// The retained state of a combo box.
+struct ComboBoxState {
+    open: bool,
+    matches: Vec<SharedString>,
+    highlighted: Option<usize>,
+    /// The field's bounds in the window, for placing the dropdown.
+    bounds: Bounds<Pixels>,
+    scroll_handle: ScrollHandle,
+    /// Set while a pick is written to the field, so the change it causes
+    /// doesn't reopen the dropdown.
+    selecting: bool,
+    on_select: Option<SuggestionHandler>,
+    _subscription: Subscription,
+}
+
+impl ComboBoxState {
+    fn new(input: &Entity<InputState>, window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let subscription =
+            cx.subscribe_in(
+                input,
+                window,
+                |this, input, event, window, cx| match event {
+                    InputEvent::Change => {
+                        if std::mem::take(&mut this.selecting) {
+                            return;
+                        }
+                        this.open = true;
+                        this.highlighted = None;
+                        cx.notify();
+                    }
+                    InputEvent::PressEnter { .. } => {
+                        let highlighted = this
+                            .highlighted
+                            .filter(|_| this.open)
+                            .and_then(|ix| this.matches.get(ix).cloned());
+                        if let Some(suggestion) = highlighted {
+                            this.select(input, suggestion, window, cx);
+                        }
+                    }
+                    InputEvent::Blur => this.close(cx),
+                    InputEvent::Focus => {}
+                },
+            );
+
+        Self {
+            open: false,
+            matches: Vec::new(),
+            highlighted: None,
+            bounds: Bounds::default(),
+            scroll_handle: ScrollHandle::new(),
+            selecting: false,
+            on_select: None,
+            _subscription: subscription,
+        }
+    }
+
+    fn set_matches(&mut self, matches: Vec<SharedString>) {
+        if matches != self.matches {
+            self.highlighted = None;
+            self.matches = matches;
+        }
+    }
+
+    /// Move the highlight by `delta` rows, returning whether the dropdown
+    /// handled the key.
+    fn move_highlight(&mut self, delta: isize, cx: &mut Context<Self>) -> bool {
+        if !self.open || self.matches.is_empty() {
+            return false;
+        }
+        self.highlighted = Some(step(self.highlighted, delta, self.matches.len()));
+        if let Some(ix) = self.highlighted {
+            self.scroll_handle.scroll_to_item(ix);
+        }
+        cx.notify();
+        true
+    }
+
+    fn select(
+        &mut self,
+        input: &Entity<InputState>,
+        suggestion: SharedString,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) {
+        self.selecting = true;
+        input.update(cx, |input, cx| {
+            input.set_value(suggestion.clone(), window, cx)
+        });
+        self.close(cx);
+
+        if let Some(on_select) = self.on_select.clone() {
+            on_select(&suggestion, window, cx);
+        }
+    }
+
+    fn close(&mut self, cx: &mut Context<Self>) {
+        self.open = false;
+        self.highlighted = None;
+        cx.notify();
+    }
+}
+
+/// The row highlighted after moving `delta` rows from `current` in a list of
+/// `count` rows, wrapping around at the ends.
+fn step(current: Option<usize>, delta: isize, count: usize) -> usize {
+    let count = count as isize;
+    let next = match current {
+        Some(ix) => ix as isize + delta,
+        // Down starts at the top, up at the bottom
+        None if delta > 0 => 0,
+        None => count - 1,
+    };
+    next.rem_euclid(count) as usize
+}
+
+/// The default filter: `query` appears in `suggestion`, ignoring case.
+fn contains_ignoring_case(suggestion: &str, query: &str) -> bool {
+    suggestion.to_lowercase().contains(&query.to_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_substrings_ignoring_case() {
+        assert!(contains_ignoring_case("Blueberry", "BERRY"));
+        assert!(contains_ignoring_case("Blueberry", ""));
+        assert!(!contains_ignoring_case("Blueberry", "cherry"));
+    }
+
+    #[test]
+    fn highlight_wraps_around() {
+        assert_eq!(step(None, 1, 3), 0);
+        assert_eq!(step(None, -1, 3), 2);
+        assert_eq!(step(Some(2), 1, 3), 0);
+        assert_eq!(step(Some(0), -1, 3), 2);
+        assert_eq!(step(Some(1), 1, 3), 2);
+    }
+}
@@ -0,0 +1,70 @@
+//! CopyButton - Small button that copies text to the clipboard.
+
+use gpui::{App, IntoElement, RenderOnce, SharedString, Styled, Window};
+use gpui_component::{Icon, IconName};
+
+use crate::clipboard;
+use crate::components::{Button, ButtonStyle, ControlSize};
+use crate::environment;
+use crate::modifier::Modifier;
+
+/// A small icon button that copies text to the clipboard, confirming with
+/// a brief "Copied!" toast.
+///
+/// The button is disabled inside `.disabled(true)`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// HStack::new()
+///     .child(Text::new(&self.api_key))
+///     .child(CopyButton::new(self.api_key.clone()))
+/// ```
+#[derive(IntoElement)]
+pub struct CopyButton {
+    id: SharedString,
+    text: SharedString,
+}
+
+impl CopyButton {
+    /// Create a button that copies `text`.
+    ///
+    /// Like [`Button::with_label`], the button's ID is derived from the
+    /// call site; give each one built in a loop a distinct
+    /// [`CopyButton::id`].
+    #[track_caller]
+    pub fn new(text: impl Into<SharedString>) -> Self {
+        let location = std::panic::Location::caller();
+        Self {
+            id: format!(
+                "{}:{}:{}",
+                location.file(),
+                location.line(),
+                location.column()
+            )
+            .into(),
+            text: text.into(),
+        }
+    }
+
+    /// Set the button's ID, which keys its hover and pressed state.
+    pub fn id(mut self, id: impl Into<SharedString>) -> Self {
+        self.id = id.into();
+        self
+    }
+}
+
+impl Modifier for CopyButton {}
+
+impl RenderOnce for CopyButton {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let text = self.text;
+        Button::with_id(self.id)
+            .label_view(Icon::new(IconName::Copy).size_4())
+            .button_style(ButtonStyle::Borderless)
+            .control_size(ControlSize::Small)
+            .disabled(!environment::is_enabled(cx))
+            .on_click_with(move |_, _, cx| clipboard::copy_with_confirmation(&text, cx))
+            .accessibility_label("Copy")
+    }
+}
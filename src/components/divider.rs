@@ -1,8 +1,11 @@
 //! Divider - Visual separator line.
 
-use gpui::{div, px, rgb, App, IntoElement, RenderOnce, Styled, Window};
-use gpui_component::ActiveTheme;
+use gpui::{
+    div, px, rems, AlignSelf, App, IntoElement, ParentElement, RenderOnce, SharedString, Styled,
+    Window,
+};
 
+use crate::environment;
 use crate::modifier::Modifier;
 use crate::style::Color;
 
@@ -15,23 +18,59 @@ use crate::style::Color;
 ///     .child(Text::new("Section 1"))
 ///     .child(Divider::new())
 ///     .child(Text::new("Section 2"))
+///
+/// // Between columns of an HStack
+/// HStack::new().child(left).child(Divider::new().vertical()).child(right)
+///
+/// // Centered text with lines on both sides
+/// Divider::labeled("OR")
 /// ```
 #[derive(IntoElement)]
 pub struct Divider {
     color: Option<Color>,
+    vertical: bool,
+    thickness: f32,
+    label: Option<SharedString>,
 }
 
 impl Divider {
     /// Create a new divider.
     pub fn new() -> Self {
-        Self { color: None }
+        Self {
+            color: None,
+            vertical: false,
+            thickness: 1.0,
+            label: None,
+        }
+    }
+
+    /// Create a divider with text centered between two lines.
+    pub fn labeled(label: impl Into<SharedString>) -> Self {
+        Self {
+            label: Some(label.into()),
+            ..Self::new()
+        }
     }
 
-    /// Set the divider color.
+    /// Set the divider color (default: `Color::separator()`).
     pub fn color(mut self, color: impl Into<Color>) -> Self {
         self.color = Some(color.into());
         self
     }
+
+    /// Draw a vertical line that stretches to the height of its container.
+    ///
+    /// Use inside an `HStack` to separate columns.
+    pub fn vertical(mut self) -> Self {
+        self.vertical = true;
+        self
+    }
+
+    /// Set the line thickness in points (default 1pt).
+    pub fn thickness(mut self, thickness: f32) -> Self {
+        self.thickness = thickness;
+        self
+    }
 }
 
 impl Default for Divider {
@@ -44,12 +83,39 @@ impl Modifier for Divider {}
 
 impl RenderOnce for Divider {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let is_dark = cx.theme().is_dark();
-        let color = self
-            .color
-            .map(|c| c.resolve(is_dark))
-            .unwrap_or_else(|| rgb(0x3c3c3c).into());
+        let color = self.color.unwrap_or_else(Color::separator).resolve_in(cx);
+        let thickness = px(self.thickness);
+        let vertical = self.vertical;
+        let line = move || {
+            let line = div().bg(color);
+            if vertical {
+                line.w(thickness).flex_1()
+            } else {
+                line.h(thickness).flex_1()
+            }
+        };
+
+        let mut container = div().flex().items_center().gap_2();
+        if vertical {
+            // Stretch across the cross axis of the enclosing HStack
+            container.style().align_self = Some(AlignSelf::Stretch);
+            container = container.flex_col();
+        } else {
+            container = container.w_full().flex_row();
+        }
 
-        div().w_full().h(px(1.0)).bg(color)
+        match self.label {
+            Some(label) => container
+                .child(line())
+                .child(
+                    div()
+                        .flex_shrink_0()
+                        .text_size(rems(0.75 * environment::text_scale(cx)))
+                        .text_color(Color::secondary_label().resolve_in(cx))
+                        .child(label),
+                )
+                .child(line()),
+            None => container.gap_0().child(line()),
+        }
     }
 }
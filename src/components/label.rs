@@ -1,8 +1,13 @@
 //! Label - Text with icon.
 
-use gpui::{div, px, App, IntoElement, ParentElement, RenderOnce, SharedString, Styled, Window};
-use gpui_component::{ActiveTheme, Icon, IconName};
-
+use gpui::{
+    div, px, AnyElement, App, IntoElement, ParentElement, RenderOnce, SharedString, Styled, Window,
+};
+use gpui_component::{Icon, IconName};
+
+use crate::components::symbols::icon_for_symbol;
+use crate::components::Image;
+use crate::environment::{self, EnvironmentScope};
 use crate::modifier::Modifier;
 use crate::style::Color;
 
@@ -23,6 +28,22 @@ pub struct Label {
     title: SharedString,
     icon: Option<LabelIcon>,
     color: Option<Color>,
+    style: LabelStyle,
+    icon_size: Option<f32>,
+    spacing: f32,
+    icon_trailing: bool,
+}
+
+/// Which parts of a [`Label`] are shown.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LabelStyle {
+    /// Show both the icon and the title.
+    #[default]
+    TitleAndIcon,
+    /// Show only the icon. The title is kept as the label's description.
+    IconOnly,
+    /// Show only the title.
+    TitleOnly,
 }
 
 /// The icon to display in a Label.
@@ -31,6 +52,10 @@ enum LabelIcon {
     Name(IconName),
     /// A string name that will be mapped to an IconName if possible.
     String(SharedString),
+    /// An arbitrary view, rendered as-is.
+    View(AnyElement),
+    /// An image path, rendered with [`Image`].
+    Image(SharedString),
 }
 
 impl Label {
@@ -48,20 +73,40 @@ impl Label {
     /// - "minus" -> Minus
     /// - "checkmark" -> Check
     /// - "xmark" -> Close
+    ///
+    /// Unmapped names render as `[name]`; see [`register_symbol_mapping`] to
+    /// add your own.
+    ///
+    /// [`register_symbol_mapping`]: crate::components::register_symbol_mapping
     pub fn new(system_image: impl Into<SharedString>, title: impl Into<SharedString>) -> Self {
         Self {
-            title: title.into(),
             icon: Some(LabelIcon::String(system_image.into())),
-            color: None,
+            ..Self::title(title)
         }
     }
 
     /// Create a label with a specific IconName.
     pub fn with_icon(icon: IconName, title: impl Into<SharedString>) -> Self {
         Self {
-            title: title.into(),
             icon: Some(LabelIcon::Name(icon)),
-            color: None,
+            ..Self::title(title)
+        }
+    }
+
+    /// Create a label with a custom view as its icon, e.g. an `svg()`.
+    ///
+    /// Custom views are not tinted by `.foreground_color()`.
+    pub fn with_view(icon: impl IntoElement, title: impl Into<SharedString>) -> Self {
+        Self::title(title).icon_view(icon)
+    }
+
+    /// Create a label whose icon is an image, e.g. a user avatar.
+    ///
+    /// The image is shown at 16pt square unless `.icon_size()` is set.
+    pub fn with_image(path: impl Into<SharedString>, title: impl Into<SharedString>) -> Self {
+        Self {
+            icon: Some(LabelIcon::Image(path.into())),
+            ..Self::title(title)
         }
     }
 
@@ -71,6 +116,10 @@ impl Label {
             title: title.into(),
             icon: None,
             color: None,
+            style: LabelStyle::default(),
+            icon_size: None,
+            spacing: 6.0,
+            icon_trailing: false,
         }
     }
 
@@ -79,96 +128,103 @@ impl Label {
         self.color = Some(color.into());
         self
     }
+
+    /// Replace the icon with a custom view.
+    pub fn icon_view(mut self, icon: impl IntoElement) -> Self {
+        self.icon = Some(LabelIcon::View(icon.into_any_element()));
+        self
+    }
+
+    /// Choose whether the icon, the title, or both are shown.
+    pub fn label_style(mut self, style: LabelStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the icon's size in points. Defaults to the text size.
+    pub fn icon_size(mut self, size: f32) -> Self {
+        self.icon_size = Some(size);
+        self
+    }
+
+    /// Set the space between the icon and the title (default 6pt).
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Place the icon after the title instead of before it.
+    pub fn icon_trailing(mut self, trailing: bool) -> Self {
+        self.icon_trailing = trailing;
+        self
+    }
 }
 
 impl Modifier for Label {}
 
 impl RenderOnce for Label {
-    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let is_dark = cx.theme().is_dark();
-        let mut container = div().flex().flex_row().items_center().gap(px(6.0));
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let mut container = div().flex().flex_row().items_center().gap(px(self.spacing));
 
-        let color = self.color.map(|c| c.resolve(is_dark));
+        let scaled_size = environment::scaled_text_size(None, window, cx);
+        let unscaled_size = environment::unscaled_text_size(None, window, cx);
+        if let Some(size) = scaled_size {
+            container = container.text_size(size);
+        }
+
+        let color = self
+            .color
+            .or_else(|| environment::foreground_color(cx))
+            .map(|c| c.resolve_in(cx));
 
         // Apply color to container for text
         if let Some(c) = color {
             container = container.text_color(c);
         }
 
-        // Add icon if present
-        if let Some(label_icon) = self.icon {
-            let (icon_name, fallback_text) = match &label_icon {
-                LabelIcon::Name(name) => (Some(name.clone()), None),
-                LabelIcon::String(s) => (map_system_image_to_icon(s), Some(s.clone())),
-            };
-
-            if let Some(name) = icon_name {
-                let mut icon = Icon::new(name);
-                if let Some(c) = color {
-                    icon = icon.text_color(c);
-                }
-                container = container.child(icon);
-            } else if let Some(text) = fallback_text {
+        let icon_size = self.icon_size;
+        let tinted_icon = |name: IconName| {
+            let mut icon = Icon::new(name);
+            if let Some(size) = icon_size {
+                icon = icon.size(px(size));
+            }
+            if let Some(c) = color {
+                icon = icon.text_color(c);
+            }
+            icon.into_any_element()
+        };
+
+        let icon = match self.icon {
+            _ if self.style == LabelStyle::TitleOnly => None,
+            None => None,
+            Some(LabelIcon::Name(name)) => Some(tinted_icon(name)),
+            Some(LabelIcon::String(s)) => Some(match icon_for_symbol(&s) {
+                Some(name) => tinted_icon(name),
                 // Fallback to text placeholder for unmapped icons
-                container = container.child(div().text_size(px(12.0)).child(format!("[{}]", text)));
+                None => div()
+                    .text_size(px(12.0))
+                    .child(format!("[{}]", s))
+                    .into_any_element(),
+            }),
+            Some(LabelIcon::View(view)) => Some(view),
+            Some(LabelIcon::Image(path)) => {
+                let size = icon_size.unwrap_or(16.0);
+                Some(
+                    Image::new(path.to_string())
+                        .frame_size(size, size)
+                        .into_any_element(),
+                )
             }
-        }
-
-        // Add title
-        container = container.child(self.title);
+        };
+        let title = (self.style != LabelStyle::IconOnly).then_some(self.title);
 
-        container
-    }
-}
+        let container = if self.icon_trailing {
+            container.children(title).children(icon)
+        } else {
+            container.children(icon).children(title)
+        };
 
-/// Maps SF Symbol-style names to gpui-component IconName.
-fn map_system_image_to_icon(name: &str) -> Option<IconName> {
-    // Normalize: remove ".fill" suffix and convert to lowercase
-    let normalized = name.to_lowercase().replace(".fill", "");
-
-    match normalized.as_str() {
-        "star" => Some(IconName::Star),
-        "star.off" | "staroff" => Some(IconName::StarOff),
-        "folder" => Some(IconName::Folder),
-        "folder.open" | "folderopen" => Some(IconName::FolderOpen),
-        "gear" | "gearshape" | "settings" => Some(IconName::Settings),
-        "heart" => Some(IconName::Heart),
-        "heart.off" | "heartoff" => Some(IconName::HeartOff),
-        "bell" => Some(IconName::Bell),
-        "magnifyingglass" | "search" => Some(IconName::Search),
-        "plus" | "plus.circle" => Some(IconName::Plus),
-        "minus" | "minus.circle" => Some(IconName::Minus),
-        "checkmark" | "check" | "checkmark.circle" => Some(IconName::Check),
-        "xmark" | "close" | "xmark.circle" => Some(IconName::Close),
-        "trash" | "delete" => Some(IconName::Delete),
-        "doc" | "file" | "doc.text" => Some(IconName::File),
-        "info" | "info.circle" => Some(IconName::Info),
-        "globe" => Some(IconName::Globe),
-        "sun" | "sun.max" => Some(IconName::Sun),
-        "moon" => Some(IconName::Moon),
-        "person" | "person.circle" | "user" => Some(IconName::User),
-        "eye" => Some(IconName::Eye),
-        "eye.slash" | "eyeoff" => Some(IconName::EyeOff),
-        "arrow.up" | "arrowup" => Some(IconName::ArrowUp),
-        "arrow.down" | "arrowdown" => Some(IconName::ArrowDown),
-        "arrow.left" | "arrowleft" => Some(IconName::ArrowLeft),
-        "arrow.right" | "arrowright" => Some(IconName::ArrowRight),
-        "chevron.up" | "chevronup" => Some(IconName::ChevronUp),
-        "chevron.down" | "chevrondown" => Some(IconName::ChevronDown),
-        "chevron.left" | "chevronleft" => Some(IconName::ChevronLeft),
-        "chevron.right" | "chevronright" => Some(IconName::ChevronRight),
-        "calendar" => Some(IconName::Calendar),
-        "link" | "link.circle" => Some(IconName::ExternalLink),
-        "doc.on.doc" | "copy" => Some(IconName::Copy),
-        "ellipsis" | "ellipsis.circle" => Some(IconName::Ellipsis),
-        "line.horizontal.3" | "menu" => Some(IconName::Menu),
-        "square.and.arrow.up" | "redo" => Some(IconName::Redo),
-        "square.and.arrow.down" | "undo" => Some(IconName::Undo),
-        "exclamationmark.triangle" | "warning" => Some(IconName::TriangleAlert),
-        "hand.thumbsup" | "thumbsup" => Some(IconName::ThumbsUp),
-        "hand.thumbsdown" | "thumbsdown" => Some(IconName::ThumbsDown),
-        "tray" | "inbox" => Some(IconName::Inbox),
-        "book" | "book.closed" => Some(IconName::BookOpen),
-        _ => None,
+        // A view used as the icon scales from the unscaled size, not ours
+        EnvironmentScope::new(container).scaled_text(unscaled_size, scaled_size)
     }
 }
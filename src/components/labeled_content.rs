@@ -0,0 +1,53 @@
+//! LabeledContent - A label with a value at the trailing edge.
+
+use gpui::{
+    div, px, AnyElement, App, IntoElement, ParentElement, RenderOnce, SharedString, Styled, Window,
+};
+
+use crate::environment::EnvironmentScope;
+use crate::modifier::Modifier;
+use crate::style::Color;
+
+/// A label leading a row with a value at its trailing edge, like SwiftUI's
+/// `LabeledContent`.
+///
+/// The value is drawn in the secondary label color unless it sets its own.
+/// Useful for read-only rows in a `Form`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// LabeledContent::new("Version", Text::new("1.2.0"))
+/// ```
+#[derive(IntoElement)]
+pub struct LabeledContent {
+    label: SharedString,
+    content: AnyElement,
+}
+
+impl LabeledContent {
+    /// Create a row labeled `label` showing `content`.
+    pub fn new(label: impl Into<SharedString>, content: impl IntoElement) -> Self {
+        Self {
+            label: label.into(),
+            content: content.into_any_element(),
+        }
+    }
+}
+
+impl Modifier for LabeledContent {}
+
+impl RenderOnce for LabeledContent {
+    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+        div()
+            .flex()
+            .flex_row()
+            .items_center()
+            .gap(px(8.0))
+            .w_full()
+            .child(div().flex_1().min_w_0().child(self.label))
+            .child(div().flex_shrink_0().child(
+                EnvironmentScope::new(self.content).foreground(Some(Color::secondary_label())),
+            ))
+    }
+}
@@ -1,11 +1,12 @@
 //! Link - Tappable text that triggers an action.
 
 use gpui::{
-    div, App, InteractiveElement, IntoElement, ParentElement, RenderOnce, SharedString,
-    StatefulInteractiveElement, Styled, Window,
+    div, App, InteractiveElement, IntoElement, MouseButton, ParentElement, RenderOnce,
+    SharedString, StatefulInteractiveElement, Styled, Window,
 };
-use gpui_component::ActiveTheme;
+use gpui_component::{Icon, IconName};
 
+use crate::environment;
 use crate::modifier::Modifier;
 use crate::style::Color;
 use crate::types::ClickHandler;
@@ -15,16 +16,20 @@ use crate::types::ClickHandler;
 /// # Example
 ///
 /// ```rust,ignore
-/// Link::new("Visit Website", || {
-///     open_url("https://example.com");
-/// })
+/// // Open a URL in the browser
+/// Link::url("Visit Website", "https://example.com").external_icon(true)
+///
+/// // Run a custom action
+/// Link::new("Show Details", || println!("details"))
 /// ```
 #[derive(IntoElement)]
 pub struct Link {
     id: SharedString,
     label: SharedString,
     action: Option<ClickHandler>,
+    destination: Option<SharedString>,
     color: Option<Color>,
+    external_icon: bool,
 }
 
 impl Link {
@@ -35,10 +40,31 @@ impl Link {
             id: label_str.clone(),
             label: label_str,
             action: Some(Box::new(move |_, _, _| action())),
+            destination: None,
+            color: None,
+            external_icon: false,
+        }
+    }
+
+    /// Create a link that opens `destination` in the default browser.
+    pub fn url(label: impl Into<SharedString>, destination: impl Into<SharedString>) -> Self {
+        let label_str: SharedString = label.into();
+        Self {
+            id: label_str.clone(),
+            label: label_str,
+            action: None,
+            destination: Some(destination.into()),
             color: None,
+            external_icon: false,
         }
     }
 
+    /// Show an external-link glyph after the label.
+    pub fn external_icon(mut self, show: bool) -> Self {
+        self.external_icon = show;
+        self
+    }
+
     /// Set the link color.
     pub fn foreground_color(mut self, color: impl Into<Color>) -> Self {
         self.color = Some(color.into());
@@ -49,17 +75,43 @@ impl Link {
 impl Modifier for Link {}
 
 impl RenderOnce for Link {
-    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let is_dark = cx.theme().is_dark();
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
         let id = gpui::ElementId::Name(self.id.clone());
-        let color = self.color.unwrap_or(Color::blue());
+        let color = self
+            .color
+            .or_else(|| environment::foreground_color(cx))
+            .unwrap_or(Color::accent());
 
+        let color = color.resolve_in(cx);
         let mut link = div()
             .id(id)
+            .flex()
+            .flex_row()
+            .items_center()
+            .gap_1()
             .cursor_pointer()
-            .text_color(color.resolve(is_dark))
+            .text_color(color)
+            .hover(|style| style.underline())
             .child(self.label);
 
+        if self.external_icon {
+            link = link.child(Icon::new(IconName::ExternalLink).text_color(color));
+        }
+
+        if let Some(size) = environment::scaled_text_size(None, window, cx) {
+            link = link.text_size(size);
+        }
+
+        // Left and middle clicks both open the destination
+        if let Some(destination) = self.destination {
+            let middle_destination = destination.clone();
+            link = link
+                .on_click(move |_, _, cx| cx.open_url(&destination))
+                .on_mouse_up(MouseButton::Middle, move |_, _, cx| {
+                    cx.open_url(&middle_destination)
+                });
+        }
+
         if let Some(action) = self.action {
             link = link.on_click(move |event, window, cx| {
                 action(event, window, cx);
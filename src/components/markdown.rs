@@ -0,0 +1,655 @@
+//! Markdown - Rendered CommonMark text.
+
+use std::rc::Rc;
+
+use gpui::{
+    div, px, AnyElement, App, ElementId, InteractiveElement, IntoElement, ParentElement,
+    RenderOnce, SharedString, Styled, Window,
+};
+
+use crate::alignment::{HorizontalAlignment, VerticalAlignment};
+use crate::components::{Divider, Text};
+use crate::layout::{HStack, VStack};
+use crate::modifier::Modifier;
+use crate::style::{Color, Font};
+
+/// Colors and spacing used to render [`Markdown`].
+#[derive(Clone, Debug)]
+pub struct MarkdownStyle {
+    /// Body text color. Defaults to the inherited foreground color.
+    pub text_color: Option<Color>,
+    /// Color of link text.
+    pub link_color: Color,
+    /// Color of inline code and code blocks. Defaults to the text color.
+    pub code_color: Option<Color>,
+    /// Background behind inline code and code blocks.
+    pub code_background: Color,
+    /// Vertical space between blocks (paragraphs, headings, lists).
+    pub block_spacing: f32,
+    /// Vertical space between list items.
+    pub list_item_spacing: f32,
+}
+
+impl Default for MarkdownStyle {
+    fn default() -> Self {
+        Self {
+            text_color: None,
+            link_color: Color::accent(),
+            code_color: None,
+            code_background: Color::secondary_system_background(),
+            block_spacing: 12.0,
+            list_item_spacing: 4.0,
+        }
+    }
+}
+
+impl MarkdownStyle {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn text_color(mut self, color: impl Into<Color>) -> Self {
+        self.text_color = Some(color.into());
+        self
+    }
+
+    #[must_use]
+    pub fn link_color(mut self, color: impl Into<Color>) -> Self {
+        self.link_color = color.into();
+        self
+    }
+
+    #[must_use]
+    pub fn code_color(mut self, color: impl Into<Color>) -> Self {
+        self.code_color = Some(color.into());
+        self
+    }
+
+    #[must_use]
+    pub fn code_background(mut self, color: impl Into<Color>) -> Self {
+        self.code_background = color.into();
+        self
+    }
+
+    #[must_use]
+    pub fn block_spacing(mut self, spacing: f32) -> Self {
+        self.block_spacing = spacing;
+        self
+    }
+
+    #[must_use]
+    pub fn list_item_spacing(mut self, spacing: f32) -> Self {
+        self.list_item_spacing = spacing;
+        self
+    }
+}
+
+type OpenUrlHandler = Rc<dyn Fn(&str, &mut Window, &mut App)>;
+
+/// A view that renders a subset of CommonMark.
+///
+/// Supports headings, paragraphs, `**bold**`, `*italic*`, `` `code` ``,
+/// `[links](url)`, bulleted and numbered lists, fenced code blocks, and
+/// thematic breaks. Anything else renders as plain text.
+///
+/// The document is built from [`Text`] and stacks, so fonts, the
+/// inherited foreground color, and `.text_scale()` apply as usual.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// Markdown::new("# Release notes\n\n- **Faster** startup\n- See [docs](https://example.com)")
+///     .markdown_style(MarkdownStyle::new().block_spacing(8.0))
+///     .foreground_color(Color::secondary_label())
+/// ```
+///
+/// Links open in the browser unless `.on_open_url()` is set.
+#[derive(IntoElement)]
+pub struct Markdown {
+    id: SharedString,
+    source: SharedString,
+    style: MarkdownStyle,
+    on_open_url: Option<OpenUrlHandler>,
+}
+
+impl Markdown {
+    /// Create a markdown view from CommonMark source.
+    ///
+    /// The view is identified by its call site, so documents built by the
+    /// same call, such as in a loop, share link click state; give each a
+    /// distinct [`Markdown::id`].
+    #[track_caller]
+    pub fn new(source: impl Into<SharedString>) -> Self {
+        let location = std::panic::Location::caller();
+        let id = format!(
+            "{}:{}:{}",
+            location.file(),
+            location.line(),
+            location.column()
+        );
+        Self {
+            id: id.into(),
+            source: source.into(),
+            style: MarkdownStyle::default(),
+            on_open_url: None,
+        }
+    }
+
+    /// Set a stable identifier for this view.
+    pub fn id(mut self, id: impl Into<SharedString>) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    /// Set the colors and spacing used to render the document.
+    pub fn markdown_style(mut self, style: MarkdownStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Handle link clicks instead of opening the URL in the browser.
+    pub fn on_open_url(mut self, handler: impl Fn(&str, &mut Window, &mut App) + 'static) -> Self {
+        self.on_open_url = Some(Rc::new(handler));
+        self
+    }
+}
+
+impl Modifier for Markdown {}
+
+impl RenderOnce for Markdown {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let on_open_url: OpenUrlHandler = self
+            .on_open_url
+            .unwrap_or_else(|| Rc::new(|url, _, cx| cx.open_url(url)));
+        let renderer = BlockRenderer {
+            style: &self.style,
+            on_open_url,
+        };
+
+        let mut root = VStack::new()
+            .spacing(self.style.block_spacing)
+            .alignment(HorizontalAlignment::Leading);
+        for (ix, block) in parse_blocks(&self.source).into_iter().enumerate() {
+            root = root.child(renderer.block(ix, block, cx));
+        }
+        div().id(ElementId::Name(self.id)).child(root)
+    }
+}
+
+/// Renders parsed blocks with a document's style.
+struct BlockRenderer<'a> {
+    style: &'a MarkdownStyle,
+    on_open_url: OpenUrlHandler,
+}
+
+impl BlockRenderer<'_> {
+    fn block(&self, ix: usize, block: Block, cx: &App) -> AnyElement {
+        match block {
+            Block::Heading { level, spans } => {
+                let font = match level {
+                    1 => Font::title(),
+                    2 => Font::title2(),
+                    3 => Font::title3(),
+                    _ => Font::headline(),
+                };
+                self.paragraph(ix.into(), spans, Some(font.bold()))
+            }
+            Block::Paragraph(spans) => self.paragraph(ix.into(), spans, None),
+            Block::List { start, items } => {
+                let mut list = VStack::new()
+                    .spacing(self.style.list_item_spacing)
+                    .alignment(HorizontalAlignment::Leading);
+                for (item_ix, spans) in items.into_iter().enumerate() {
+                    let marker = match start {
+                        Some(start) => format!("{}.", start + item_ix as u64),
+                        None => "•".to_string(),
+                    };
+                    let id = ElementId::NamedInteger(format!("item-{ix}").into(), item_ix as u64);
+                    list = list.child(
+                        HStack::new()
+                            .spacing(6.0)
+                            .alignment(VerticalAlignment::Top)
+                            .child(self.text(Text::new(marker)))
+                            .child(
+                                div()
+                                    .flex_1()
+                                    .min_w_0()
+                                    .child(self.paragraph(id, spans, None)),
+                            ),
+                    );
+                }
+                list.into_any_element()
+            }
+            Block::Code(code) => {
+                let mut text = self.text(Text::new(code).font(Font::body().monospaced()));
+                if let Some(color) = &self.style.code_color {
+                    text = text.foreground_color(color);
+                }
+                div()
+                    .w_full()
+                    .p(px(12.0))
+                    .rounded(px(6.0))
+                    .bg(self.style.code_background.resolve_in(cx))
+                    .child(text)
+                    .into_any_element()
+            }
+            Block::Rule => div().w_full().child(Divider::new()).into_any_element(),
+        }
+    }
+
+    /// Apply the style's text color, if any; otherwise the color is inherited.
+    fn text(&self, text: Text) -> Text {
+        match &self.style.text_color {
+            Some(color) => text.foreground_color(color),
+            None => text,
+        }
+    }
+
+    /// Styled text for a span.
+    fn span(&self, content: &str, span: &Span, font: Option<&Font>) -> Text {
+        let mut font = font.cloned();
+        if span.bold {
+            font = Some(font.unwrap_or_default().bold());
+        }
+        if span.italic {
+            font = Some(font.unwrap_or_default().italic());
+        }
+        if span.code {
+            font = Some(font.unwrap_or_default().monospaced());
+        }
+
+        let mut text = self.text(Text::new(content.to_string()));
+        if let Some(font) = font {
+            text = text.font(font);
+        }
+        if span.code {
+            if let Some(color) = &self.style.code_color {
+                text = text.foreground_color(color);
+            }
+            text = text.highlight(self.style.code_background.clone());
+        }
+        text
+    }
+
+    /// Render inline spans as one paragraph of styled runs, with each link a
+    /// click target within it.
+    fn paragraph(&self, id: ElementId, spans: Vec<Span>, font: Option<Font>) -> AnyElement {
+        spans
+            .iter()
+            .map(|span| {
+                let text = self.span(&span.text, span, font.as_ref());
+                match &span.link {
+                    Some(url) => {
+                        let url = url.clone();
+                        let on_open_url = self.on_open_url.clone();
+                        text.foreground_color(self.style.link_color.clone())
+                            .link(move |window, cx| on_open_url(&url, window, cx))
+                    }
+                    None => text,
+                }
+            })
+            // Start from an empty run so a lone link is still concatenated
+            .fold(Text::new(""), |paragraph, text| paragraph + text)
+            .id(id)
+            .into_any_element()
+    }
+}
+
+/// A block-level markdown element.
+#[derive(Clone, Debug, PartialEq)]
+enum Block {
+    Heading {
+        level: u8,
+        spans: Vec<Span>,
+    },
+    Paragraph(Vec<Span>),
+    /// A list; `start` is the first number of an ordered list.
+    List {
+        start: Option<u64>,
+        items: Vec<Vec<Span>>,
+    },
+    Code(String),
+    Rule,
+}
+
+/// A run of inline text with uniform styling.
+#[derive(Clone, Debug, Default, PartialEq)]
+struct Span {
+    text: String,
+    bold: bool,
+    italic: bool,
+    code: bool,
+    link: Option<SharedString>,
+}
+
+/// Accumulates lines into blocks.
+#[derive(Default)]
+struct BlockParser {
+    blocks: Vec<Block>,
+    paragraph: Vec<String>,
+    list: Option<(Option<u64>, Vec<String>)>,
+    code: Option<Vec<String>>,
+}
+
+impl BlockParser {
+    fn flush(&mut self) {
+        if !self.paragraph.is_empty() {
+            let text = self.paragraph.join(" ");
+            self.blocks.push(Block::Paragraph(parse_inlines(&text)));
+            self.paragraph.clear();
+        }
+        if let Some((start, items)) = self.list.take() {
+            let items = items.iter().map(|item| parse_inlines(item)).collect();
+            self.blocks.push(Block::List { start, items });
+        }
+    }
+
+    fn line(&mut self, line: &str) {
+        let trimmed = line.trim();
+
+        if let Some(code) = &mut self.code {
+            if trimmed.starts_with("```") {
+                let code = self.code.take().unwrap_or_default();
+                self.blocks.push(Block::Code(code.join("\n")));
+            } else {
+                code.push(line.to_string());
+            }
+            return;
+        }
+
+        if trimmed.is_empty() {
+            self.flush();
+        } else if trimmed.starts_with("```") {
+            self.flush();
+            self.code = Some(Vec::new());
+        } else if let Some((level, title)) = heading(trimmed) {
+            self.flush();
+            self.blocks.push(Block::Heading {
+                level,
+                spans: parse_inlines(title),
+            });
+        } else if is_rule(trimmed) {
+            self.flush();
+            self.blocks.push(Block::Rule);
+        } else if let Some((number, item)) = list_item(trimmed) {
+            let continues =
+                matches!(&self.list, Some((start, _)) if start.is_some() == number.is_some());
+            if !continues {
+                self.flush();
+                self.list = Some((number, Vec::new()));
+            }
+            if let Some((_, items)) = &mut self.list {
+                items.push(item.to_string());
+            }
+        } else if let Some((_, items)) = &mut self.list {
+            // Lazy continuation of the last list item
+            if let Some(last) = items.last_mut() {
+                last.push(' ');
+                last.push_str(trimmed);
+            }
+        } else {
+            self.paragraph.push(trimmed.to_string());
+        }
+    }
+
+    fn finish(mut self) -> Vec<Block> {
+        if let Some(code) = self.code.take() {
+            self.blocks.push(Block::Code(code.join("\n")));
+        }
+        self.flush();
+        self.blocks
+    }
+}
+
+fn parse_blocks(source: &str) -> Vec<Block> {
+    let mut parser = BlockParser::default();
+    for line in source.lines() {
+        parser.line(line);
+    }
+    parser.finish()
+}
+
+/// Parse an ATX heading (`# Title`) into its level and title.
+fn heading(line: &str) -> Option<(u8, &str)> {
+    let level = line.chars().take_while(|&c| c == '#').count();
+    let rest = &line[level..];
+    if (1..=6).contains(&level) && (rest.is_empty() || rest.starts_with(' ')) {
+        Some((level as u8, rest.trim().trim_end_matches('#').trim_end()))
+    } else {
+        None
+    }
+}
+
+/// Whether the line is a thematic break (`---`, `***`, `___`).
+fn is_rule(line: &str) -> bool {
+    let marks: Vec<char> = line.chars().filter(|c| !c.is_whitespace()).collect();
+    marks.len() >= 3 && matches!(marks[0], '-' | '*' | '_') && marks.iter().all(|&c| c == marks[0])
+}
+
+/// Parse a list item marker, returning the item number for ordered lists.
+fn list_item(line: &str) -> Option<(Option<u64>, &str)> {
+    for marker in ["- ", "* ", "+ "] {
+        if let Some(item) = line.strip_prefix(marker) {
+            return Some((None, item.trim_start()));
+        }
+    }
+
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+    let rest = &line[digits..];
+    if (1..=9).contains(&digits) && (rest.starts_with(". ") || rest.starts_with(") ")) {
+        let number = line[..digits].parse().ok()?;
+        return Some((Some(number), rest[2..].trim_start()));
+    }
+    None
+}
+
+/// Parse inline emphasis, code spans, and links.
+fn parse_inlines(text: &str) -> Vec<Span> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut spans = Vec::new();
+    let mut current = Span::default();
+    let mut i = 0;
+
+    fn flush(spans: &mut Vec<Span>, current: &mut Span) {
+        if !current.text.is_empty() {
+            let next = Span {
+                text: String::new(),
+                ..current.clone()
+            };
+            spans.push(std::mem::replace(current, next));
+        }
+    }
+
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            '\\' if chars.get(i + 1).is_some_and(char::is_ascii_punctuation) => {
+                current.text.push(chars[i + 1]);
+                i += 2;
+            }
+            '`' => match find(&chars, i + 1, &['`']) {
+                Some(end) => {
+                    flush(&mut spans, &mut current);
+                    spans.push(Span {
+                        text: chars[i + 1..end].iter().collect(),
+                        code: true,
+                        ..current.clone()
+                    });
+                    i = end + 1;
+                }
+                None => {
+                    current.text.push(c);
+                    i += 1;
+                }
+            },
+            '*' | '_' => {
+                let double = chars.get(i + 1) == Some(&c);
+                let marker: &[char] = if double { &[c, c] } else { &[c] };
+                let active = if double { current.bold } else { current.italic };
+                // Underscores inside words (snake_case) are not emphasis
+                let intraword = c == '_' && i > 0 && chars[i - 1].is_alphanumeric();
+                let toggles = if active {
+                    true
+                } else {
+                    !intraword && find(&chars, i + marker.len(), marker).is_some()
+                };
+
+                if toggles {
+                    flush(&mut spans, &mut current);
+                    if double {
+                        current.bold = !current.bold;
+                    } else {
+                        current.italic = !current.italic;
+                    }
+                    i += marker.len();
+                } else {
+                    current.text.push(c);
+                    i += 1;
+                }
+            }
+            '[' => match link(&chars, i) {
+                Some((label, url, end)) => {
+                    flush(&mut spans, &mut current);
+                    let url: SharedString = url.into();
+                    for span in parse_inlines(&label) {
+                        spans.push(Span {
+                            bold: span.bold || current.bold,
+                            italic: span.italic || current.italic,
+                            link: Some(url.clone()),
+                            ..span
+                        });
+                    }
+                    i = end;
+                }
+                None => {
+                    current.text.push(c);
+                    i += 1;
+                }
+            },
+            _ => {
+                current.text.push(c);
+                i += 1;
+            }
+        }
+    }
+    flush(&mut spans, &mut current);
+    spans
+}
+
+/// Find the next occurrence of `pattern` at or after `from`.
+fn find(chars: &[char], from: usize, pattern: &[char]) -> Option<usize> {
+    (from..chars.len().saturating_sub(pattern.len() - 1))
+        .find(|&j| chars[j..j + pattern.len()] == *pattern)
+}
+
+/// Parse `[label](url)` starting at `start`, returning the label, URL, and
+/// the index just past the closing parenthesis.
+fn link(chars: &[char], start: usize) -> Option<(String, String, usize)> {
+    let label_end = find(chars, start + 1, &[']'])?;
+    if chars.get(label_end + 1) != Some(&'(') {
+        return None;
+    }
+    let url_end = find(chars, label_end + 2, &[')'])?;
+    let label = chars[start + 1..label_end].iter().collect();
+    let url = chars[label_end + 2..url_end].iter().collect::<String>();
+    Some((label, url.trim().to_string(), url_end + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn plain(text: &str) -> Span {
+        Span {
+            text: text.into(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn parses_emphasis_and_code() {
+        let spans = parse_inlines("a **bold** and *italic* `code`");
+
+        assert_eq!(
+            spans,
+            vec![
+                plain("a "),
+                Span {
+                    bold: true,
+                    ..plain("bold")
+                },
+                plain(" and "),
+                Span {
+                    italic: true,
+                    ..plain("italic")
+                },
+                plain(" "),
+                Span {
+                    code: true,
+                    ..plain("code")
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn unmatched_markers_are_literal() {
+        assert_eq!(parse_inlines("2 * 3 = 6"), vec![plain("2 * 3 = 6")]);
+        assert_eq!(
+            parse_inlines("snake_case_name"),
+            vec![plain("snake_case_name")]
+        );
+    }
+
+    #[test]
+    fn parses_links() {
+        let spans = parse_inlines("see [the docs](https://example.com).");
+
+        assert_eq!(
+            spans,
+            vec![
+                plain("see "),
+                Span {
+                    link: Some("https://example.com".into()),
+                    ..plain("the docs")
+                },
+                plain("."),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_blocks() {
+        let source = "# Title\n\nFirst line\ncontinues.\n\n- one\n- two\n\n1. first\n2. second\n\n---\n\n```\nlet x = 1;\n```";
+        let blocks = parse_blocks(source);
+
+        assert_eq!(
+            blocks,
+            vec![
+                Block::Heading {
+                    level: 1,
+                    spans: vec![plain("Title")]
+                },
+                Block::Paragraph(vec![plain("First line continues.")]),
+                Block::List {
+                    start: None,
+                    items: vec![vec![plain("one")], vec![plain("two")]]
+                },
+                Block::List {
+                    start: Some(1),
+                    items: vec![vec![plain("first")], vec![plain("second")]]
+                },
+                Block::Rule,
+                Block::Code("let x = 1;".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn heading_requires_space() {
+        assert_eq!(heading("## Setup ##"), Some((2, "Setup")));
+        assert_eq!(heading("#hashtag"), None);
+    }
+}
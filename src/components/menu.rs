@@ -0,0 +1,700 @@
+//! Menu - A button that opens a menu of actions.
+
+use std::rc::Rc;
+
+use gpui::{
+    canvas, div, prelude::FluentBuilder, px, App, Bounds, Div, ElementId, Entity, FocusHandle,
+    InteractiveElement, IntoElement, KeyDownEvent, Keystroke, MouseButton, ParentElement, Pixels,
+    RenderOnce, SharedString, StatefulInteractiveElement, Styled, Window,
+};
+use gpui_component::{Icon, IconName};
+
+use crate::alignment::Alignment;
+use crate::components::ButtonRole;
+use crate::modifier::{Expansion, Modifier};
+use crate::presentation::{Presentation, Presented};
+use crate::shortcut;
+use crate::style::Color;
+
+/// Called when a menu item is picked.
+type MenuAction = Rc<dyn Fn(&mut Window, &mut App)>;
+
+/// The width of a menu, in points.
+const MENU_WIDTH: f32 = 220.0;
+
+/// The height of a menu row, in points.
+const ROW_HEIGHT: f32 = 28.0;
+
+/// The padding around a menu's rows, in points.
+const MENU_PADDING: f32 = 4.0;
+
+/// The closest a submenu comes to the window's right edge, in points.
+const SUBMENU_MARGIN: f32 = 8.0;
+
+#[derive(Clone)]
+enum MenuItemKind {
+    Action(MenuAction),
+    Submenu(Vec<MenuItem>),
+    Separator,
+}
+
+/// An entry in a [`Menu`]: an action, a submenu of actions, or a separator.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// MenuItem::new("Rename", rename)
+/// MenuItem::destructive("Delete", delete)
+/// MenuItem::submenu("Sort By", [
+///     MenuItem::new("Name", sort_by_name).checked(true),
+///     MenuItem::new("Date", sort_by_date).checked(false),
+/// ])
+/// MenuItem::new("Duplicate", duplicate).keyboard_shortcut("cmd-d")
+/// ```
+#[derive(Clone)]
+pub struct MenuItem {
+    label: SharedString,
+    kind: MenuItemKind,
+    role: Option<ButtonRole>,
+    checked: Option<bool>,
+    disabled: bool,
+    shortcut: Option<Keystroke>,
+}
+
+impl MenuItem {
+    /// Create an item that calls `handler` when picked.
+    pub fn new(
+        label: impl Into<SharedString>,
+        handler: impl Fn(&mut Window, &mut App) + 'static,
+    ) -> Self {
+        Self::with_kind(label.into(), MenuItemKind::Action(Rc::new(handler)))
+    }
+
+    /// Create an item with the destructive role, labeled in red.
+    pub fn destructive(
+        label: impl Into<SharedString>,
+        handler: impl Fn(&mut Window, &mut App) + 'static,
+    ) -> Self {
+        Self::new(label, handler).role(ButtonRole::Destructive)
+    }
+
+    /// Create an item that opens `items` beside the menu.
+    ///
+    /// Submenus go one level deep: submenus inside a submenu can't be opened.
+    pub fn submenu(
+        label: impl Into<SharedString>,
+        items: impl IntoIterator<Item = MenuItem>,
+    ) -> Self {
+        Self::with_kind(
+            label.into(),
+            MenuItemKind::Submenu(items.into_iter().collect()),
+        )
+    }
+
+    /// Create a line dividing groups of items.
+    pub fn separator() -> Self {
+        Self::with_kind(SharedString::default(), MenuItemKind::Separator)
+    }
+
+    fn with_kind(label: SharedString, kind: MenuItemKind) -> Self {
+        Self {
+            label,
+            kind,
+            role: None,
+            checked: None,
+            disabled: false,
+            shortcut: None,
+        }
+    }
+
+    /// Set the item's role.
+    pub fn role(mut self, role: ButtonRole) -> Self {
+        self.role = Some(role);
+        self
+    }
+
+    /// Show a checkmark beside the item, or leave room for one.
+    pub fn checked(mut self, checked: bool) -> Self {
+        self.checked = Some(checked);
+        self
+    }
+
+    /// Show the item dimmed, and skip it when picking.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Pick the item when `keystroke` is pressed, even while the menu is
+    /// closed, and show the keys beside its label.
+    ///
+    /// See [`Modifier::keyboard_shortcut`] for how keystrokes are written.
+    pub fn keyboard_shortcut(mut self, keystroke: &str) -> Self {
+        self.shortcut = shortcut::parse(keystroke);
+        self
+    }
+
+    /// Whether the item can be highlighted and picked.
+    fn is_selectable(&self) -> bool {
+        !self.disabled && !matches!(self.kind, MenuItemKind::Separator)
+    }
+}
+
+/// Kept across frames for each menu.
+struct MenuState {
+    open: bool,
+    focus: FocusHandle,
+    highlighted: Option<usize>,
+    /// The row whose submenu is open, and the submenu's highlighted row.
+    submenu: Option<(usize, Option<usize>)>,
+    /// Where the open submenu's row was last drawn.
+    submenu_row: Option<Bounds<Pixels>>,
+}
+
+impl MenuState {
+    fn close(&mut self) {
+        self.open = false;
+        self.highlighted = None;
+        self.submenu = None;
+    }
+}
+
+/// A button that opens a menu of actions, like SwiftUI's `Menu`.
+///
+/// Clicking the label opens the menu below it, flipped above when it
+/// doesn't fit. Picking an item, clicking outside or pressing Escape closes
+/// it. While open, up and down move through the items, right opens a
+/// submenu and left closes it, and Enter or Space picks the highlighted
+/// item. The label opens the menu on Enter, Space or down while focused.
+///
+/// The menu's ID is derived from the call site; use [`id`](Self::id) when
+/// creating menus in a loop.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// Menu::new(Image::system_name("ellipsis"))
+///     .item(MenuItem::new("Rename", rename))
+///     .item(MenuItem::new("Duplicate", duplicate))
+///     .item(MenuItem::separator())
+///     .item(MenuItem::destructive("Delete", delete))
+/// ```
+#[derive(IntoElement)]
+pub struct Menu {
+    id: ElementId,
+    label: gpui::AnyElement,
+    items: Vec<MenuItem>,
+}
+
+impl Menu {
+    /// Create a menu opened by clicking `label`.
+    #[track_caller]
+    pub fn new(label: impl IntoElement) -> Self {
+        let location = std::panic::Location::caller();
+        Self {
+            id: ElementId::CodeLocation(*location),
+            label: label.into_any_element(),
+            items: Vec::new(),
+        }
+    }
+
+    /// Set the menu's ID, which keeps its state.
+    pub fn id(mut self, id: impl Into<ElementId>) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    /// Add an item.
+    pub fn item(mut self, item: MenuItem) -> Self {
+        self.items.push(item);
+        self
+    }
+}
+
+impl Modifier for Menu {}
+
+impl RenderOnce for Menu {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let state = window.use_keyed_state(self.id.clone(), cx, |_, cx| MenuState {
+            open: false,
+            focus: cx.focus_handle(),
+            highlighted: None,
+            submenu: None,
+            submenu_row: None,
+        });
+        let (open, focus) = {
+            let state = state.read(cx);
+            (state.open, state.focus.clone())
+        };
+        let items = Rc::new(self.items);
+        bind_shortcuts(&self.id, &items, &state, window, cx);
+
+        let trigger = div()
+            .id(self.id.clone())
+            .track_focus(&focus)
+            .flex()
+            .items_center()
+            .px(px(6.0))
+            .py(px(2.0))
+            .rounded(px(6.0))
+            .cursor_pointer()
+            .text_color(Color::accent().resolve_in(cx))
+            .hover(|style| style.opacity(0.7))
+            .child(self.label)
+            .on_click({
+                let state = state.clone();
+                move |_, _, cx| {
+                    state.update(cx, |state, cx| {
+                        if state.open {
+                            state.close();
+                        } else {
+                            state.open = true;
+                        }
+                        cx.notify();
+                    })
+                }
+            });
+
+        let dismiss = {
+            let state = state.clone();
+            move |window: &mut Window, cx: &mut App| close(&state, window, cx)
+        };
+        let menu = Presented {
+            child: trigger,
+            child_expansion: Expansion::default(),
+            presentation: open.then(|| Presentation::Popover {
+                content: menu_panel(&items, &state, window, cx).into_any_element(),
+                attachment: Alignment::bottom_leading(),
+            }),
+            on_dismiss: Some(Rc::new(dismiss)),
+            dismiss_disabled: false,
+        };
+
+        // Keys reach here from the label while closed, and from the
+        // presented menu while open
+        div()
+            .on_key_down(move |event: &KeyDownEvent, window, cx| {
+                if handle_key(&items, &state, event.keystroke.key.as_str(), window, cx) {
+                    cx.stop_propagation();
+                }
+            })
+            .child(menu)
+    }
+}
+
+/// Bind the shortcuts of the menu's enabled items, including those in
+/// submenus.
+fn bind_shortcuts(
+    id: &ElementId,
+    items: &[MenuItem],
+    state: &Entity<MenuState>,
+    window: &mut Window,
+    cx: &mut App,
+) {
+    let submenu_items = items.iter().flat_map(|item| match &item.kind {
+        MenuItemKind::Submenu(children) if !item.disabled => children.as_slice(),
+        _ => &[],
+    });
+    let bound =
+        items
+            .iter()
+            .chain(submenu_items)
+            .filter_map(|item| match (&item.kind, &item.shortcut) {
+                (MenuItemKind::Action(action), Some(keystroke)) if !item.disabled => {
+                    Some((action.clone(), keystroke.clone()))
+                }
+                _ => None,
+            });
+    let bound: Vec<_> = bound.collect();
+    window.with_id(id.clone(), |window| {
+        for (ix, (action, keystroke)) in bound.into_iter().enumerate() {
+            let state = state.clone();
+            shortcut::register(
+                ("keyboard-shortcut", ix),
+                keystroke,
+                Rc::new(move |window, cx| {
+                    if state.read(cx).open {
+                        close(&state, window, cx);
+                    }
+                    action(window, cx);
+                }),
+                window,
+                cx,
+            );
+        }
+    });
+}
+
+/// Close the menu and give focus back to its label.
+fn close(state: &Entity<MenuState>, window: &mut Window, cx: &mut App) {
+    let focus = state.update(cx, |state, cx| {
+        state.close();
+        cx.notify();
+        state.focus.clone()
+    });
+    window.focus(&focus);
+}
+
+/// Close the menu and run the item's action.
+fn pick(state: &Entity<MenuState>, action: &MenuAction, window: &mut Window, cx: &mut App) {
+    close(state, window, cx);
+    action(window, cx);
+}
+
+/// Act on a key pressed with focus in the menu, returning whether it was
+/// handled.
+fn handle_key(
+    items: &[MenuItem],
+    state: &Entity<MenuState>,
+    key: &str,
+    window: &mut Window,
+    cx: &mut App,
+) -> bool {
+    let (open, highlighted, submenu) = {
+        let state = state.read(cx);
+        (state.open, state.highlighted, state.submenu)
+    };
+    if !open {
+        if !matches!(key, "enter" | "space" | "down") {
+            return false;
+        }
+        state.update(cx, |state, cx| {
+            state.open = true;
+            state.highlighted = step(items, None, 1);
+            cx.notify();
+        });
+        return true;
+    }
+
+    let submenu_items = |parent: usize| match &items[parent].kind {
+        MenuItemKind::Submenu(children) => children.as_slice(),
+        _ => &[],
+    };
+    let delta = match key {
+        "down" => 1,
+        "up" => -1,
+        _ => 0,
+    };
+    match (key, submenu) {
+        ("down" | "up", Some((parent, current))) => {
+            let next = step(submenu_items(parent), current, delta);
+            state.update(cx, |state, cx| {
+                state.submenu = Some((parent, next));
+                cx.notify();
+            });
+        }
+        ("down" | "up", None) => {
+            let next = step(items, highlighted, delta);
+            state.update(cx, |state, cx| {
+                state.highlighted = next;
+                cx.notify();
+            });
+        }
+        ("left", Some(_)) => state.update(cx, |state, cx| {
+            state.submenu = None;
+            cx.notify();
+        }),
+        ("enter" | "space", Some((parent, Some(ix)))) => {
+            if let MenuItemKind::Action(action) = &submenu_items(parent)[ix].kind {
+                pick(state, action, window, cx);
+            }
+        }
+        ("right" | "enter" | "space", None) => {
+            let Some(ix) = highlighted else {
+                return true;
+            };
+            match &items[ix].kind {
+                MenuItemKind::Submenu(children) => state.update(cx, |state, cx| {
+                    state.submenu = Some((ix, step(children, None, 1)));
+                    cx.notify();
+                }),
+                MenuItemKind::Action(action) if key != "right" => pick(state, action, window, cx),
+                _ => {}
+            }
+        }
+        _ => return false,
+    }
+    true
+}
+
+/// The selectable row after moving `delta` rows from `current`, skipping
+/// separators and disabled items and wrapping around at the ends.
+fn step(items: &[MenuItem], current: Option<usize>, delta: isize) -> Option<usize> {
+    let count = items.len() as isize;
+    let mut ix = match current {
+        Some(ix) => ix as isize,
+        // Down starts at the top, up at the bottom
+        None if delta >= 0 => -1,
+        None => count,
+    };
+    let delta = if delta < 0 { -1 } else { 1 };
+    for _ in 0..count {
+        ix = (ix + delta).rem_euclid(count);
+        if items[ix as usize].is_selectable() {
+            return Some(ix as usize);
+        }
+    }
+    None
+}
+
+/// The menu's rows, with the open submenu beside its row.
+fn menu_panel(
+    items: &Rc<Vec<MenuItem>>,
+    state: &Entity<MenuState>,
+    window: &Window,
+    cx: &App,
+) -> Div {
+    let (highlighted, submenu, submenu_row) = {
+        let state = state.read(cx);
+        (state.highlighted, state.submenu, state.submenu_row)
+    };
+    let checks = items.iter().any(|item| item.checked.is_some());
+
+    let rows = items.iter().enumerate().map(|(ix, item)| {
+        let row = menu_row(("menu-item", ix), item, highlighted == Some(ix), checks, cx);
+        let MenuItemKind::Submenu(children) = &item.kind else {
+            return match &item.kind {
+                MenuItemKind::Action(action) if !item.disabled => {
+                    let (state, action) = (state.clone(), action.clone());
+                    row.on_hover({
+                        let state = state.clone();
+                        move |hovered, _, cx| {
+                            if *hovered {
+                                state.update(cx, |state, cx| {
+                                    state.highlighted = Some(ix);
+                                    state.submenu = None;
+                                    cx.notify();
+                                })
+                            }
+                        }
+                    })
+                    .on_click(move |_, window, cx| pick(&state, &action, window, cx))
+                    .into_any_element()
+                }
+                _ => row.into_any_element(),
+            };
+        };
+        if item.disabled {
+            return row.into_any_element();
+        }
+
+        let open = submenu.filter(|(parent, _)| *parent == ix);
+        let panel = open.map(|(_, sub_highlighted)| {
+            // Beside the row's trailing edge, or its leading edge when
+            // there isn't room
+            let flip = submenu_row.is_some_and(|row| {
+                f32::from(row.right()) + MENU_WIDTH + SUBMENU_MARGIN
+                    > f32::from(window.viewport_size().width)
+            });
+            let offset = px(MENU_PADDING + 1.0);
+            let panel = submenu_panel(ix, children, sub_highlighted, state, cx)
+                .absolute()
+                .top(-offset);
+            if flip {
+                panel.right_full().mr(offset)
+            } else {
+                panel.left_full().ml(offset)
+            }
+        });
+        let record = open.map(|_| {
+            let state = state.clone();
+            canvas(
+                move |bounds, window, cx| {
+                    let moved = state.update(cx, |state, _| {
+                        state.submenu_row.replace(bounds) != Some(bounds)
+                    });
+                    if moved {
+                        window.request_animation_frame();
+                    }
+                },
+                |_, _, _, _| {},
+            )
+            .absolute()
+            .size_full()
+        });
+
+        let state = state.clone();
+        let first = step(children, None, 1);
+        row.relative()
+            .on_hover({
+                let state = state.clone();
+                move |hovered, _, cx| {
+                    if *hovered {
+                        state.update(cx, |state, cx| {
+                            state.highlighted = Some(ix);
+                            if !matches!(state.submenu, Some((parent, _)) if parent == ix) {
+                                state.submenu = Some((ix, None));
+                            }
+                            cx.notify();
+                        })
+                    }
+                }
+            })
+            .on_click(move |_, _, cx| {
+                state.update(cx, |state, cx| {
+                    state.submenu = Some((ix, first));
+                    cx.notify();
+                })
+            })
+            .children(record)
+            .children(panel)
+            .into_any_element()
+    });
+
+    div()
+        .flex()
+        .flex_col()
+        .w(px(MENU_WIDTH))
+        .p(px(MENU_PADDING))
+        .children(rows)
+}
+
+/// A submenu's rows, in a panel of their own.
+fn submenu_panel(
+    parent: usize,
+    items: &[MenuItem],
+    highlighted: Option<usize>,
+    state: &Entity<MenuState>,
+    cx: &App,
+) -> Div {
+    let checks = items.iter().any(|item| item.checked.is_some());
+    let rows = items.iter().enumerate().map(|(ix, item)| {
+        let row = menu_row(
+            ("submenu-item", ix),
+            item,
+            highlighted == Some(ix),
+            checks,
+            cx,
+        );
+        let MenuItemKind::Action(action) = &item.kind else {
+            return row;
+        };
+        if item.disabled {
+            return row;
+        }
+        let (state, action) = (state.clone(), action.clone());
+        row.on_hover({
+            let state = state.clone();
+            move |hovered, _, cx| {
+                if *hovered {
+                    state.update(cx, |state, cx| {
+                        state.submenu = Some((parent, Some(ix)));
+                        cx.notify();
+                    })
+                }
+            }
+        })
+        .on_click(move |_, window, cx| pick(&state, &action, window, cx))
+    });
+
+    div()
+        .occlude()
+        .flex()
+        .flex_col()
+        .w(px(MENU_WIDTH))
+        .p(px(MENU_PADDING))
+        .rounded(px(12.0))
+        .shadow_lg()
+        .border_1()
+        .border_color(Color::separator().resolve_in(cx))
+        .bg(Color::system_background().resolve_in(cx))
+        // Clicks in the submenu don't reach the popover's scrim
+        .on_mouse_down(MouseButton::Left, |_, _, cx| cx.stop_propagation())
+        .children(rows)
+}
+
+/// A row of a menu: a separator line, or a label with room for a
+/// checkmark when any item in the menu has one.
+fn menu_row(
+    id: impl Into<ElementId>,
+    item: &MenuItem,
+    highlighted: bool,
+    checks: bool,
+    cx: &App,
+) -> gpui::Stateful<Div> {
+    if let MenuItemKind::Separator = item.kind {
+        return div().id(id).child(
+            div()
+                .h(px(1.0))
+                .my(px(4.0))
+                .mx(px(8.0))
+                .bg(Color::separator().resolve_in(cx)),
+        );
+    }
+
+    let color = if highlighted {
+        Color::white()
+    } else if item.disabled {
+        Color::tertiary_label()
+    } else if item.role == Some(ButtonRole::Destructive) {
+        Color::red()
+    } else {
+        Color::label()
+    };
+    let color = color.resolve_in(cx);
+    div()
+        .id(id)
+        .flex()
+        .flex_row()
+        .items_center()
+        .gap(px(6.0))
+        .h(px(ROW_HEIGHT))
+        .px(px(8.0))
+        .rounded(px(6.0))
+        .text_color(color)
+        .when(!item.disabled, |row| row.cursor_pointer())
+        .when(highlighted, |row| row.bg(Color::accent().resolve_in(cx)))
+        .when(checks, |row| {
+            row.child(
+                div()
+                    .flex_shrink_0()
+                    .w(px(14.0))
+                    .when(item.checked == Some(true), |check| {
+                        check.child(Icon::new(IconName::Check).size_3().text_color(color))
+                    }),
+            )
+        })
+        .child(
+            div()
+                .flex_1()
+                .min_w_0()
+                .truncate()
+                .child(item.label.clone()),
+        )
+        .when_some(item.shortcut.as_ref(), |row, keystroke| {
+            let hint = if highlighted {
+                color
+            } else {
+                Color::secondary_label().resolve_in(cx)
+            };
+            row.child(
+                div()
+                    .flex_shrink_0()
+                    .text_color(hint)
+                    .child(keystroke.to_string()),
+            )
+        })
+        .when(matches!(item.kind, MenuItemKind::Submenu(_)), |row| {
+            row.child(Icon::new(IconName::ChevronRight).size_3().text_color(color))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keys_skip_separators_and_disabled_items() {
+        let items = [
+            MenuItem::new("Rename", |_, _| {}),
+            MenuItem::separator(),
+            MenuItem::new("Archive", |_, _| {}).disabled(true),
+            MenuItem::destructive("Delete", |_, _| {}),
+        ];
+        assert_eq!(step(&items, None, 1), Some(0));
+        assert_eq!(step(&items, None, -1), Some(3));
+        assert_eq!(step(&items, Some(0), 1), Some(3));
+        assert_eq!(step(&items, Some(3), 1), Some(0));
+        assert_eq!(step(&[MenuItem::separator()], None, 1), None);
+    }
+}
@@ -4,40 +4,68 @@
 //! Input components wrap gpui-component widgets.
 
 // Display components
+mod avatar;
 mod button;
+mod canvas;
+mod copy_button;
 mod divider;
 mod image;
 mod label;
+mod labeled_content;
 mod link;
+mod markdown;
+mod menu;
+mod navigation_row;
 mod progress_view;
+mod shape;
+mod symbols;
 mod text;
 
 // Input components
+mod color_picker;
+mod combo_box;
+mod number_field;
 mod picker;
 mod secure_field;
 mod slider;
 mod stepper;
+mod tag_field;
 mod text_editor;
 mod text_field;
 mod toggle;
 
 // Display exports
-pub use button::{Button, ButtonStyle};
+pub use avatar::{Avatar, AvatarStack, AvatarStatus};
+pub use button::{
+    Button, ButtonRole, ButtonStyle, ButtonStyleConfiguration, ButtonStyling, ControlSize,
+};
+pub use canvas::{Canvas, Painter};
+pub use copy_button::CopyButton;
 pub use divider::Divider;
 pub use image::Image;
-pub use label::Label;
+pub use label::{Label, LabelStyle};
+pub use labeled_content::LabeledContent;
 pub use link::Link;
+pub use markdown::{Markdown, MarkdownStyle};
+pub use menu::{Menu, MenuItem};
+pub use navigation_row::NavigationRow;
 pub use progress_view::{ProgressView, ProgressViewStyle};
-pub use text::{Text, TruncationMode};
+pub use shape::{Capsule, Circle, Ellipse, Rectangle, RoundedRectangle, Shape, ShapeOutline};
+pub use symbols::register_symbol_mapping;
+pub use text::{Text, TextAlignment, TextCase, TruncationMode};
 
 // Input exports
+pub use color_picker::{ColorHandler, ColorPicker};
+pub use combo_box::{ComboBox, SuggestionFilter, SuggestionHandler};
+pub use number_field::NumberField;
 pub use picker::{
     IndexPath, Picker, PickerDelegate, PickerEvent, PickerGroup, PickerItem, PickerState,
-    SearchableVec,
+    PickerStyle, SearchableVec,
 };
-pub use secure_field::SecureField;
-pub use slider::{Slider, SliderEvent, SliderState, SliderValue};
+pub use secure_field::{PasswordStrength, SecureField};
+pub use slider::{Axis, Slider, SliderEvent, SliderState, SliderValue};
 pub use stepper::{StepAction, Stepper, StepperEvent};
+pub use tag_field::{TagField, TagsHandler};
 pub use text_editor::TextEditor;
 pub use text_field::{InputState, TextField};
-pub use toggle::Toggle;
+pub use toggle::{LabelPlacement, Toggle, ToggleStyle};
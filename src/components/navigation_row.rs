@@ -0,0 +1,126 @@
+//! NavigationRow - A tappable row leading to more content.
+
+use gpui::{
+    div, px, App, ClickEvent, ElementId, InteractiveElement, IntoElement, ParentElement,
+    RenderOnce, SharedString, StatefulInteractiveElement, Styled, Window,
+};
+use gpui_component::{ActiveTheme, Icon, IconName};
+
+use crate::environment;
+use crate::modifier::Modifier;
+use crate::style::Color;
+use crate::types::ClickHandler;
+
+/// A row with a title, an optional detail value and a trailing chevron,
+/// like a row of the iOS Settings app.
+///
+/// The whole row is the tap target, and darkens while pressed. In a
+/// `Section`, add one with `Section::navigation_row`, which lets it span
+/// the row's insets.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// NavigationRow::new("Language")
+///     .detail("English")
+///     .on_tap(cx.listener(|this, _, _, cx| {
+///         this.page = Page::Language;
+///         cx.notify();
+///     }))
+/// ```
+#[derive(IntoElement)]
+pub struct NavigationRow {
+    id: SharedString,
+    title: SharedString,
+    detail: Option<SharedString>,
+    color: Option<Color>,
+    chevron: bool,
+    on_tap: Option<ClickHandler>,
+}
+
+impl NavigationRow {
+    /// Create a row with a title.
+    pub fn new(title: impl Into<SharedString>) -> Self {
+        let title: SharedString = title.into();
+        Self {
+            id: title.clone(),
+            title,
+            detail: None,
+            color: None,
+            chevron: true,
+            on_tap: None,
+        }
+    }
+
+    /// Show a value in gray before the chevron.
+    pub fn detail(mut self, detail: impl Into<SharedString>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+
+    /// Call `handler` when the row is clicked anywhere.
+    pub fn on_tap(
+        mut self,
+        handler: impl Fn(&ClickEvent, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_tap = Some(Box::new(handler));
+        self
+    }
+
+    /// Set the title color.
+    pub fn foreground_color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Hide the chevron, for rows that act in place.
+    pub(crate) fn chevron(mut self, shown: bool) -> Self {
+        self.chevron = shown;
+        self
+    }
+}
+
+impl Modifier for NavigationRow {}
+
+impl RenderOnce for NavigationRow {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let pressed = cx.theme().list_active;
+        let mut title = div().flex_1().min_w_0().truncate().child(self.title);
+        if let Some(color) = self.color.or_else(|| environment::foreground_color(cx)) {
+            title = title.text_color(color.resolve_in(cx));
+        }
+
+        let mut row = div()
+            .id(ElementId::Name(self.id))
+            .flex()
+            .flex_row()
+            .items_center()
+            .gap(px(8.0))
+            .w_full()
+            .min_h(px(44.0))
+            .px(px(16.0))
+            .cursor_pointer()
+            .active(move |style| style.bg(pressed))
+            .child(title)
+            .children(self.detail.map(|detail| {
+                div()
+                    .flex_shrink_0()
+                    .text_color(Color::secondary_label().resolve_in(cx))
+                    .child(detail)
+            }));
+
+        if self.chevron {
+            row = row.child(
+                Icon::new(IconName::ChevronRight)
+                    .text_color(Color::tertiary_label().resolve_in(cx)),
+            );
+        }
+        if let Some(size) = environment::scaled_text_size(None, window, cx) {
+            row = row.text_size(size);
+        }
+        if let Some(on_tap) = self.on_tap {
+            row = row.on_click(on_tap);
+        }
+        row
+    }
+}
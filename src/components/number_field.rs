@@ -0,0 +1,532 @@
+//! NumberField - Numeric text input.
+//!
+//! A SwiftUI-style `TextField(value:format:)` for numbers. The field keeps
+//! its own text; you own the number and update it in `on_value_change`.
+//!
+//! # Usage
+//!
+//! ```rust,ignore
+//! NumberField::new(self.quantity)
+//!     .placeholder("Qty")
+//!     .range(0.0..=99.0)
+//!     .fraction_digits(0)
+//!     .on_value_change(cx.listener(|this, value: &Option<f64>, _, cx| {
+//!         this.quantity = *value;
+//!         cx.notify();
+//!     }))
+//! ```
+
+use std::ops::RangeInclusive;
+use std::rc::Rc;
+
+use gpui::{
+    AnyElement, App, AppContext, Context, ElementId, Entity, IntoElement, RenderOnce, SharedString,
+    Styled, Subscription, Window,
+};
+use gpui_component::input::{Input, InputEvent};
+
+use super::text_field::InputState;
+use crate::environment;
+use crate::modifier::Modifier;
+use crate::style::{Color, ColorScheme};
+
+/// Handler called with a number field's new value, or `None` when it's empty.
+type ValueHandler = Rc<dyn Fn(&Option<f64>, &mut Window, &mut App) + 'static>;
+
+/// A text field that only accepts numbers.
+///
+/// Characters that can't be part of a number are rejected as they're typed.
+/// Incomplete text like `-` or `1.` is allowed while editing and resolved
+/// when Enter is pressed or the field loses focus: the value is clamped to
+/// the range, rounded to the fraction digits, and reformatted. Text that
+/// still isn't a number restores the previous value.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// NumberField::new(self.price)
+///     .prefix(Text::new("$"))
+///     .fraction_digits(2)
+///     .thousands_separator(true)
+///     .on_value_change(cx.listener(|this, value: &Option<f64>, _, cx| {
+///         this.price = *value;
+///         cx.notify();
+///     }))
+/// ```
+#[derive(IntoElement)]
+pub struct NumberField {
+    id: SharedString,
+    value: Option<f64>,
+    placeholder: Option<SharedString>,
+    format: NumberFormat,
+    prefix: Option<AnyElement>,
+    suffix: Option<AnyElement>,
+    on_value_change: Option<ValueHandler>,
+    disabled: bool,
+}
+
+impl NumberField {
+    /// Create a number field showing `value`, or empty for `None`.
+    ///
+    /// The field's ID is derived from the call site; use [`NumberField::id`]
+    /// when creating fields in a loop.
+    #[track_caller]
+    pub fn new(value: Option<f64>) -> Self {
+        let location = std::panic::Location::caller();
+        let id = format!(
+            "{}:{}:{}",
+            location.file(),
+            location.line(),
+            location.column()
+        );
+        Self {
+            id: id.into(),
+            value,
+            placeholder: None,
+            format: NumberFormat::default(),
+            prefix: None,
+            suffix: None,
+            on_value_change: None,
+            disabled: false,
+        }
+    }
+
+    /// Set the field's ID.
+    pub fn id(mut self, id: impl Into<SharedString>) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    /// Set the text shown when the field is empty.
+    pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Clamp the value to a range when it's committed.
+    ///
+    /// A `-` sign can only be typed when the range allows negative values.
+    pub fn range(mut self, range: RangeInclusive<f64>) -> Self {
+        self.format.range = range;
+        self
+    }
+
+    /// Limit the number of digits after the decimal point.
+    ///
+    /// Committed values are rounded and shown with exactly this many digits.
+    /// `0` accepts whole numbers only.
+    pub fn fraction_digits(mut self, digits: usize) -> Self {
+        self.format.fraction_digits = Some(digits);
+        self
+    }
+
+    /// Group thousands with commas while the field isn't being edited.
+    ///
+    /// The separators are removed while the field has focus, and ignored
+    /// when parsing.
+    pub fn thousands_separator(mut self, separator: bool) -> Self {
+        self.format.thousands_separator = separator;
+        self
+    }
+
+    /// Show a view before the number, such as a currency symbol.
+    pub fn prefix(mut self, prefix: impl IntoElement) -> Self {
+        self.prefix = Some(prefix.into_any_element());
+        self
+    }
+
+    /// Show a view after the number, such as a unit.
+    pub fn suffix(mut self, suffix: impl IntoElement) -> Self {
+        self.suffix = Some(suffix.into_any_element());
+        self
+    }
+
+    /// Set the handler called when the value changes.
+    ///
+    /// It's called as complete numbers are typed and again with the clamped,
+    /// rounded value on commit. Clearing the field reports `None`.
+    pub fn on_value_change(
+        mut self,
+        handler: impl Fn(&Option<f64>, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_value_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Disable the number field.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+impl Modifier for NumberField {}
+
+impl RenderOnce for NumberField {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let field = window.use_keyed_state(ElementId::Name(self.id.clone()), cx, NumberInput::new);
+
+        let value = self.value;
+        field.update(cx, |field, cx| {
+            field.format = self.format;
+            field.on_value_change = self.on_value_change;
+
+            if field.placeholder != self.placeholder {
+                let placeholder = self.placeholder.clone().unwrap_or_default();
+                field.placeholder = self.placeholder;
+                field.input.update(cx, |input, cx| {
+                    input.set_placeholder(placeholder, window, cx)
+                });
+            }
+
+            // Follow values set from outside, but never rewrite text being typed
+            if field.value != value && !field.focused {
+                field.value = value;
+                let text = value
+                    .map(|value| field.format.display(value))
+                    .unwrap_or_default();
+                field.show(text, window, cx);
+            }
+        });
+
+        let is_dark = environment::color_scheme(cx) == ColorScheme::Dark;
+        let mut input = Input::new(&field.read(cx).input)
+            .bg(Color::text_field_background().resolve(is_dark))
+            .border_color(Color::text_field_border().resolve(is_dark));
+
+        if let Some(prefix) = self.prefix {
+            input = input.prefix(prefix);
+        }
+
+        if let Some(suffix) = self.suffix {
+            input = input.suffix(suffix);
+        }
+
+        if self.disabled {
+            input = input.disabled(true);
+        }
+
+        input
+    }
+}
+
+/// Text field state for a number field, kept across renders.
+struct NumberInput {
+    input: Entity<InputState>,
+    /// The last accepted text, restored when a keystroke is rejected.
+    text: String,
+    /// The value last reported or shown.
+    value: Option<f64>,
+    focused: bool,
+    placeholder: Option<SharedString>,
+    format: NumberFormat,
+    on_value_change: Option<ValueHandler>,
+    _subscription: Subscription,
+}
+
+impl NumberInput {
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let input = cx.new(|cx| InputState::new(window, cx));
+        let subscription = cx.subscribe_in(&input, window, |this, _, event, window, cx| {
+            match event {
+                InputEvent::Change => this.edit(window, cx),
+                InputEvent::Focus => {
+                    this.focused = true;
+                    // Edit the raw number without separators
+                    let text = this.text.replace(SEPARATOR, "");
+                    this.show(text, window, cx);
+                }
+                InputEvent::Blur => {
+                    this.focused = false;
+                    this.commit(window, cx);
+                }
+                InputEvent::PressEnter { .. } => this.commit(window, cx),
+            }
+        });
+
+        Self {
+            input,
+            text: String::new(),
+            value: None,
+            focused: false,
+            placeholder: None,
+            format: NumberFormat::default(),
+            on_value_change: None,
+            _subscription: subscription,
+        }
+    }
+
+    /// Replace the field's text without treating it as an edit.
+    fn show(&mut self, text: String, window: &mut Window, cx: &mut Context<Self>) {
+        if self.text != text {
+            self.text = text.clone();
+            self.input
+                .update(cx, |input, cx| input.set_value(text, window, cx));
+        }
+    }
+
+    /// Accept or reject the text after a keystroke.
+    fn edit(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let text = self.input.read(cx).value().to_string();
+        if text == self.text {
+            return;
+        }
+
+        if !self.format.accepts(&text) {
+            let previous = self.text.clone();
+            self.input
+                .update(cx, |input, cx| input.set_value(previous, window, cx));
+            return;
+        }
+
+        // Report complete numbers as they're typed; `-` and `.` wait for commit
+        let value = if text.trim().is_empty() {
+            Some(None)
+        } else {
+            self.format.parse(&text).map(Some)
+        };
+        self.text = text;
+
+        if let Some(value) = value {
+            self.report(value, window, cx);
+        }
+    }
+
+    /// Resolve the text to a value and show it formatted.
+    fn commit(&mut self, window: &mut Window, cx: &mut Context<Self>) {
+        let value = self.format.resolve(&self.text, self.value);
+        let text = match value {
+            Some(value) if self.focused => self.format.edit_text(value),
+            Some(value) => self.format.display(value),
+            None => String::new(),
+        };
+        self.show(text, window, cx);
+        self.report(value, window, cx);
+    }
+
+    fn report(&mut self, value: Option<f64>, window: &mut Window, cx: &mut Context<Self>) {
+        if value != self.value {
+            self.value = value;
+            if let Some(on_value_change) = self.on_value_change.clone() {
+                on_value_change(&value, window, cx);
+            }
+        }
+    }
+}
+
+/// The thousands separator shown by [`NumberFormat::display`].
+const SEPARATOR: char = ',';
+
+/// How a number field parses, restricts and formats its text.
+#[derive(Clone, Debug, PartialEq)]
+struct NumberFormat {
+    range: RangeInclusive<f64>,
+    fraction_digits: Option<usize>,
+    thousands_separator: bool,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self {
+            range: f64::NEG_INFINITY..=f64::INFINITY,
+            fraction_digits: None,
+            thousands_separator: false,
+        }
+    }
+}
+
+impl NumberFormat {
+    /// Whether `text` is a number or could become one with more typing.
+    fn accepts(&self, text: &str) -> bool {
+        let text = text.trim().replace(SEPARATOR, "");
+        let unsigned = match text.strip_prefix('-') {
+            Some(rest) if *self.range.start() < 0.0 => rest,
+            Some(_) => return false,
+            None => text.as_str(),
+        };
+
+        let (whole, fraction) = match unsigned.split_once('.') {
+            Some((whole, fraction)) => {
+                if self.fraction_digits == Some(0) {
+                    return false;
+                }
+                (whole, fraction)
+            }
+            None => (unsigned, ""),
+        };
+
+        let digits = |part: &str| part.chars().all(|c| c.is_ascii_digit());
+        let fraction_fits = !matches!(self.fraction_digits, Some(limit) if fraction.len() > limit);
+        digits(whole) && digits(fraction) && fraction_fits
+    }
+
+    /// Parse complete numbers, ignoring separators.
+    fn parse(&self, text: &str) -> Option<f64> {
+        text.trim()
+            .replace(SEPARATOR, "")
+            .parse::<f64>()
+            .ok()
+            .filter(|value| value.is_finite())
+    }
+
+    /// The committed value for `text`: rounded, then clamped so rounding can't
+    /// leave the range, `None` when empty,
+    /// or `previous` when the text isn't a number.
+    fn resolve(&self, text: &str, previous: Option<f64>) -> Option<f64> {
+        if text.trim().is_empty() {
+            return None;
+        }
+        match self.parse(text) {
+            Some(value) => Some(
+                self.round(value)
+                    .max(*self.range.start())
+                    .min(*self.range.end()),
+            ),
+            None => previous,
+        }
+    }
+
+    /// Round to the fraction digits, turning `-0.0` into `0.0` so small
+    /// negatives don't display as "-0".
+    fn round(&self, value: f64) -> f64 {
+        let rounded = match self.fraction_digits {
+            Some(digits) => {
+                let scale = 10f64.powi(digits as i32);
+                (value * scale).round() / scale
+            }
+            None => value,
+        };
+        rounded + 0.0
+    }
+
+    /// The value as typed while editing: fixed digits, no separators.
+    fn edit_text(&self, value: f64) -> String {
+        match self.fraction_digits {
+            Some(digits) => format!("{value:.digits$}"),
+            None => value.to_string(),
+        }
+    }
+
+    /// The value as shown when the field isn't being edited.
+    fn display(&self, value: f64) -> String {
+        let text = self.edit_text(value);
+        if !self.thousands_separator {
+            return text;
+        }
+
+        let (sign, unsigned) = match text.strip_prefix('-') {
+            Some(rest) => ("-", rest),
+            None => ("", text.as_str()),
+        };
+        let (whole, fraction) = match unsigned.split_once('.') {
+            Some((whole, fraction)) => (whole, Some(fraction)),
+            None => (unsigned, None),
+        };
+
+        let mut grouped = String::with_capacity(whole.len() + whole.len() / 3);
+        for (i, digit) in whole.chars().enumerate() {
+            if i > 0 && (whole.len() - i) % 3 == 0 {
+                grouped.push(SEPARATOR);
+            }
+            grouped.push(digit);
+        }
+
+        match fraction {
+            Some(fraction) => format!("{sign}{grouped}.{fraction}"),
+            None => format!("{sign}{grouped}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_partial_numbers() {
+        let format = NumberFormat::default();
+
+        assert!(format.accepts(""));
+        assert!(format.accepts("-"));
+        assert!(format.accepts("1."));
+        assert!(format.accepts(".5"));
+        assert!(format.accepts("1,234.5"));
+        assert!(!format.accepts("1.2.3"));
+        assert!(!format.accepts("12a"));
+        assert!(!format.accepts("--1"));
+    }
+
+    #[test]
+    fn restricts_sign_and_fraction_digits() {
+        let format = NumberFormat {
+            range: 0.0..=100.0,
+            fraction_digits: Some(2),
+            thousands_separator: false,
+        };
+
+        assert!(!format.accepts("-1"));
+        assert!(format.accepts("1.25"));
+        assert!(!format.accepts("1.255"));
+
+        let whole = NumberFormat {
+            fraction_digits: Some(0),
+            ..NumberFormat::default()
+        };
+        assert!(!whole.accepts("1."));
+    }
+
+    #[test]
+    fn resolves_on_commit() {
+        let format = NumberFormat {
+            range: 0.0..=10.0,
+            fraction_digits: Some(1),
+            thousands_separator: false,
+        };
+
+        assert_eq!(format.resolve("1.", Some(4.0)), Some(1.0));
+        assert_eq!(format.resolve("42", None), Some(10.0));
+        assert_eq!(format.resolve("2.46", None), Some(2.5));
+        assert_eq!(format.resolve("-", Some(4.0)), Some(4.0));
+        assert_eq!(format.resolve("", Some(4.0)), None);
+    }
+
+    #[test]
+    fn clamps_after_rounding() {
+        let format = NumberFormat {
+            range: 0.0..=0.95,
+            fraction_digits: Some(1),
+            thousands_separator: false,
+        };
+
+        assert_eq!(format.resolve("0.96", None), Some(0.95));
+        assert_eq!(format.resolve("0.94", None), Some(0.9));
+    }
+
+    #[test]
+    fn rounds_negative_zero_to_zero() {
+        let format = NumberFormat {
+            range: -1.0..=1.0,
+            fraction_digits: Some(1),
+            thousands_separator: false,
+        };
+
+        let value = format.resolve("-0.04", None).unwrap();
+        assert!(value.is_sign_positive());
+        assert_eq!(format.display(value), "0.0");
+    }
+
+    #[test]
+    fn groups_thousands_for_display() {
+        let format = NumberFormat {
+            fraction_digits: Some(2),
+            thousands_separator: true,
+            ..NumberFormat::default()
+        };
+
+        assert_eq!(format.display(1234567.5), "1,234,567.50");
+        assert_eq!(format.display(-1000.0), "-1,000.00");
+        assert_eq!(format.display(999.0), "999.00");
+        assert_eq!(format.edit_text(1234.5), "1234.50");
+        assert_eq!(format.parse("1,234.50"), Some(1234.5));
+    }
+}
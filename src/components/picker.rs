@@ -30,10 +30,39 @@
 //! // In render:
 //! Picker::new(&self.theme_picker)
 //!     .placeholder("Select theme...")
+//!
+//! // The same state as a segmented control
+//! Picker::new(&self.theme_picker)
+//!     .picker_style(PickerStyle::Segmented)
+//!     .labels(["Light", "Dark", "System"])
+//! ```
+//!
+//! For a short, fixed list, [`Picker::inline`] keeps the state itself and
+//! reports picks to a handler:
+//!
+//! ```rust,ignore
+//! Picker::inline(
+//!     "size",
+//!     vec!["Small".into(), "Medium".into(), "Large".into()],
+//!     self.size,
+//!     cx.listener(|this, ix: &usize, _, cx| {
+//!         this.size = Some(*ix);
+//!         cx.notify();
+//!     }),
+//! )
 //! ```
 
-use gpui::{px, App, Entity, IntoElement, Pixels, RenderOnce, SharedString, Styled, Window};
-use gpui_component::select::{Select, SelectDelegate, SelectState};
+use std::rc::Rc;
+use std::time::Duration;
+
+use gpui::{
+    div, ease_in_out, px, relative, Animation, AnimationExt, AnyElement, App, AppContext, Context,
+    ElementId, Entity, InteractiveElement, IntoElement, ParentElement, Pixels, RenderOnce,
+    SharedString, StatefulInteractiveElement, Styled, Subscription, Window,
+};
+use gpui_component::radio::RadioGroup;
+use gpui_component::select::{Select, SelectDelegate, SelectEvent, SelectState};
+use gpui_component::ActiveTheme;
 
 // Re-export types for users
 pub use gpui_component::select::SearchableVec;
@@ -44,8 +73,24 @@ pub use gpui_component::select::SelectItem as PickerItem;
 pub use gpui_component::select::SelectState as PickerState;
 pub use gpui_component::IndexPath;
 
+use crate::binding::Binding;
 use crate::modifier::Modifier;
 
+/// How a picker presents its options.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum PickerStyle {
+    /// A dropdown showing the selected option.
+    #[default]
+    Menu,
+    /// A horizontal row of segments, one of which is selected.
+    Segmented,
+    /// A vertical list of labeled radio buttons.
+    Radio,
+}
+
+/// Handler called with the index of the option picked.
+pub(crate) type SelectHandler = Rc<dyn Fn(usize, &mut Window, &mut App) + 'static>;
+
 /// A control for picking from a list of options.
 ///
 /// This component wraps gpui-component's Select (dropdown).
@@ -76,10 +121,17 @@ use crate::modifier::Modifier;
 /// // Use in render
 /// Picker::new(&country)
 ///     .placeholder("Select country...")
+///
+/// // As radio buttons
+/// Picker::new(&country)
+///     .picker_style(PickerStyle::Radio)
+///     .labels(["USA", "Canada", "UK", "Germany"])
 /// ```
 #[derive(IntoElement)]
 pub struct Picker<D: SelectDelegate + 'static> {
-    state: Entity<SelectState<D>>,
+    state: PickerSource<D>,
+    style: PickerStyle,
+    labels: Vec<SharedString>,
     placeholder: Option<SharedString>,
     cleanable: bool,
     width: Option<Pixels>,
@@ -90,7 +142,9 @@ impl<D: SelectDelegate + 'static> Picker<D> {
     /// Create a new picker with the given state.
     pub fn new(state: &Entity<SelectState<D>>) -> Self {
         Self {
-            state: state.clone(),
+            state: PickerSource::State(state.clone()),
+            style: PickerStyle::default(),
+            labels: Vec::new(),
             placeholder: None,
             cleanable: false,
             width: None,
@@ -98,13 +152,35 @@ impl<D: SelectDelegate + 'static> Picker<D> {
         }
     }
 
+    /// Set how the options are presented.
+    ///
+    /// The segmented and radio styles show the [`labels`](Self::labels).
+    pub fn picker_style(mut self, style: PickerStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the titles of the options, in the order of the state's items.
+    ///
+    /// `SelectState` doesn't expose its items, so the segmented and radio
+    /// styles need them passed again. Picking the option at an index selects
+    /// the state's item at that index and emits [`PickerEvent::Confirm`].
+    pub fn labels(mut self, labels: impl IntoIterator<Item = impl Into<SharedString>>) -> Self {
+        self.labels = labels.into_iter().map(Into::into).collect();
+        self
+    }
+
     /// Set the placeholder text.
+    ///
+    /// Applies to the menu style.
     pub fn placeholder(mut self, placeholder: impl Into<SharedString>) -> Self {
         self.placeholder = Some(placeholder.into());
         self
     }
 
     /// Show a clear button when an item is selected.
+    ///
+    /// Applies to the menu style.
     pub fn cleanable(mut self, cleanable: bool) -> Self {
         self.cleanable = cleanable;
         self
@@ -123,11 +199,131 @@ impl<D: SelectDelegate + 'static> Picker<D> {
     }
 }
 
+impl Picker<Vec<SharedString>> {
+    /// Create a picker from a list of options and the selected index.
+    ///
+    /// The picker keeps its own state, so no `SelectState` needs to be made
+    /// in the view's constructor. The handler is called with the index of the
+    /// option picked; the selection shown always follows `selected`.
+    ///
+    /// ```rust,ignore
+    /// Picker::inline("speed", speeds, self.speed, cx.listener(|this, ix: &usize, _, cx| {
+    ///     this.speed = Some(*ix);
+    ///     cx.notify();
+    /// }))
+    /// .picker_style(PickerStyle::Segmented)
+    /// ```
+    pub fn inline(
+        id: impl Into<ElementId>,
+        options: Vec<SharedString>,
+        selected: Option<usize>,
+        on_select: impl Fn(&usize, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        Self::retained(
+            id.into(),
+            options,
+            move |_| selected,
+            Rc::new(move |ix, window, cx| on_select(&ix, window, cx)),
+        )
+    }
+
+    /// Create a picker from a list of options, with the index of the
+    /// selected one read and written through `binding`.
+    ///
+    /// Like [`Picker::inline`], the picker keeps its own state. Its ID is
+    /// derived from the call site.
+    ///
+    /// ```rust,ignore
+    /// let view = cx.entity();
+    /// Picker::bound(speeds, Binding::entity_field(&view, |this| &mut this.speed))
+    /// ```
+    #[track_caller]
+    pub fn bound(options: Vec<SharedString>, binding: Binding<usize>) -> Self {
+        let id = ElementId::CodeLocation(*std::panic::Location::caller());
+        let setter = binding.clone();
+        Self::retained(
+            id,
+            options,
+            move |cx| Some(binding.get(cx)),
+            Rc::new(move |ix, window, cx| setter.set(ix, window, cx)),
+        )
+    }
+
+    /// A picker keeping its own state under `id`, showing the selection
+    /// `selected` reads while rendering.
+    fn retained(
+        id: ElementId,
+        options: Vec<SharedString>,
+        selected: impl FnOnce(&mut App) -> Option<usize> + 'static,
+        on_select: SelectHandler,
+    ) -> Self {
+        let id = ElementId::NamedChild(Box::new(id), "inline-picker".into());
+        let labels = options.clone();
+        Self {
+            state: PickerSource::Inline(Box::new(move |window, cx| {
+                let selected = selected(cx);
+                let picker = window.use_keyed_state(id, cx, |window, cx| {
+                    InlinePicker::new(options.clone(), selected, window, cx)
+                });
+                picker.update(cx, |picker, cx| {
+                    picker.update(options, selected, on_select, window, cx)
+                })
+            })),
+            style: PickerStyle::default(),
+            labels,
+            placeholder: None,
+            cleanable: false,
+            width: None,
+            disabled: false,
+        }
+    }
+}
+
 impl<D: SelectDelegate + 'static> Modifier for Picker<D> {}
 
 impl<D: SelectDelegate + 'static> RenderOnce for Picker<D> {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
-        let mut select = Select::new(&self.state);
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let state = match self.state {
+            PickerSource::State(state) => state,
+            PickerSource::Inline(state) => state(window, cx),
+        };
+
+        if self.style != PickerStyle::Menu {
+            let selected = state.read(cx).selected_index(cx).map(|ix| ix.row);
+            let id = ElementId::NamedChild(
+                Box::new(ElementId::View(state.entity_id())),
+                "picker".into(),
+            );
+            let on_select: SelectHandler = Rc::new(move |ix, window, cx| {
+                state.update(cx, |state, cx| {
+                    state.set_selected_index(Some(IndexPath::default().row(ix)), window, cx);
+                    let value = state.selected_value().cloned();
+                    cx.emit(SelectEvent::Confirm(value));
+                    cx.notify();
+                });
+            });
+
+            let mut options = match self.style {
+                PickerStyle::Radio => {
+                    radio_group(id, self.labels, selected, self.disabled, on_select)
+                }
+                _ => segmented(
+                    id,
+                    self.labels,
+                    selected,
+                    self.disabled,
+                    on_select,
+                    window,
+                    cx,
+                ),
+            };
+            if let Some(width) = self.width {
+                options = div().w(width).child(options).into_any_element();
+            }
+            return options;
+        }
+
+        let mut select = Select::new(&state);
 
         if let Some(placeholder) = self.placeholder {
             select = select.placeholder(placeholder);
@@ -145,6 +341,235 @@ impl<D: SelectDelegate + 'static> RenderOnce for Picker<D> {
             select = select.disabled(true);
         }
 
-        select
+        select.into_any_element()
+    }
+}
+
+/// Where a picker's selection state comes from.
+enum PickerSource<D: SelectDelegate + 'static> {
+    /// A state owned by the view.
+    State(Entity<SelectState<D>>),
+    /// A state kept by the picker, fetched once a window is available.
+    Inline(InlineState<D>),
+}
+
+/// Fetches an inline picker's retained state during render.
+type InlineState<D> = Box<dyn FnOnce(&mut Window, &mut App) -> Entity<SelectState<D>>>;
+
+/// The retained state of an inline picker.
+struct InlinePicker {
+    state: Entity<SelectState<Vec<SharedString>>>,
+    options: Vec<SharedString>,
+    on_select: Option<SelectHandler>,
+    _subscription: Subscription,
+}
+
+impl InlinePicker {
+    fn new(
+        options: Vec<SharedString>,
+        selected: Option<usize>,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Self {
+        let index = selected.map(|row| IndexPath::default().row(row));
+        let state = cx.new(|cx| SelectState::new(options.clone(), index, window, cx));
+        let subscription = cx.subscribe_in(
+            &state,
+            window,
+            |this, state, event: &SelectEvent<Vec<SharedString>>, window, cx| {
+                // Clearing the selection isn't a pick
+                let SelectEvent::Confirm(Some(_)) = event else {
+                    return;
+                };
+                let (Some(on_select), Some(ix)) =
+                    (this.on_select.clone(), state.read(cx).selected_index(cx))
+                else {
+                    return;
+                };
+                on_select(ix.row, window, cx);
+            },
+        );
+
+        Self {
+            state,
+            options,
+            on_select: None,
+            _subscription: subscription,
+        }
+    }
+
+    /// Bring the state up to date with the latest render's arguments.
+    fn update(
+        &mut self,
+        options: Vec<SharedString>,
+        selected: Option<usize>,
+        on_select: SelectHandler,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Entity<SelectState<Vec<SharedString>>> {
+        self.on_select = Some(on_select);
+        let options_changed = options != self.options;
+        if options_changed {
+            self.options = options.clone();
+        }
+
+        self.state.update(cx, |state, cx| {
+            if options_changed {
+                state.set_items(options, window, cx);
+            }
+            let index = selected.map(|row| IndexPath::default().row(row));
+            if options_changed || state.selected_index(cx) != index {
+                state.set_selected_index(index, window, cx);
+            }
+        });
+        self.state.clone()
+    }
+}
+
+/// A vertical list of radio buttons, one per label.
+pub(crate) fn radio_group(
+    id: ElementId,
+    labels: Vec<SharedString>,
+    selected: Option<usize>,
+    disabled: bool,
+    on_select: SelectHandler,
+) -> AnyElement {
+    RadioGroup::vertical(id)
+        .children(labels)
+        .selected_index(selected)
+        .disabled(disabled)
+        .on_click(move |ix: &usize, window, cx| on_select(*ix, window, cx))
+        .into_any_element()
+}
+
+/// A row of equal-width segments with a background that slides to the
+/// selected one.
+pub(crate) fn segmented(
+    id: ElementId,
+    labels: Vec<SharedString>,
+    selected: Option<usize>,
+    disabled: bool,
+    on_select: SelectHandler,
+    window: &mut Window,
+    cx: &mut App,
+) -> AnyElement {
+    let count = labels.len().max(1) as f32;
+    let indicator_state =
+        window.use_keyed_state(id.clone(), cx, |_, _| SegmentIndicator::default());
+    let indicator = indicator_state.update(cx, |indicator, _| {
+        indicator.select(selected);
+        *indicator
+    });
+
+    let theme = cx.theme();
+    let track_color = theme.tab_bar_segmented;
+    let selected_color = theme.background;
+    let text_color = theme.tab_foreground;
+
+    let segments = labels.into_iter().enumerate().map(|(ix, label)| {
+        let mut segment = div()
+            .id(ix)
+            .flex()
+            .items_center()
+            .justify_center()
+            .px(px(12.0))
+            .py(px(4.0))
+            .text_sm()
+            .text_color(text_color)
+            .child(label);
+        if !disabled && selected != Some(ix) {
+            let on_select = on_select.clone();
+            segment = segment
+                .cursor_pointer()
+                .on_click(move |_, window, cx| on_select(ix, window, cx));
+        }
+        segment
+    });
+
+    let background = indicator.to.map(|to| {
+        let background = div()
+            .absolute()
+            .top_0()
+            .bottom_0()
+            .w(relative(1.0 / count))
+            .rounded(px(6.0))
+            .bg(selected_color)
+            .shadow_sm();
+        match indicator.from {
+            Some(from) if from != to => background
+                .with_animation(
+                    ElementId::NamedChild(
+                        Box::new(id.clone()),
+                        format!("indicator-{}", indicator.generation).into(),
+                    ),
+                    Animation::new(Duration::from_millis(200)).with_easing(ease_in_out),
+                    move |background, delta| {
+                        let position = from as f32 + (to as f32 - from as f32) * delta;
+                        background.left(relative(position / count))
+                    },
+                )
+                .into_any_element(),
+            _ => background
+                .left(relative(to as f32 / count))
+                .into_any_element(),
+        }
+    });
+
+    let mut control = div()
+        .id(id)
+        .p(px(2.0))
+        .rounded(px(8.0))
+        .bg(track_color)
+        .child(
+            div()
+                .relative()
+                .grid()
+                .grid_cols(count as u16)
+                .children(background)
+                .children(segments),
+        );
+    if disabled {
+        control = control.opacity(0.5);
+    }
+    control.into_any_element()
+}
+
+/// Where a segmented control's selection background is and where it came
+/// from, kept across renders so a new selection animates.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct SegmentIndicator {
+    from: Option<usize>,
+    to: Option<usize>,
+    /// Bumped on each change so the animation restarts.
+    generation: usize,
+}
+
+impl SegmentIndicator {
+    fn select(&mut self, selected: Option<usize>) {
+        if selected != self.to {
+            self.from = self.to;
+            self.to = selected;
+            self.generation += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn indicator_slides_from_previous_selection() {
+        let mut indicator = SegmentIndicator::default();
+        indicator.select(Some(0));
+        assert_eq!((indicator.from, indicator.to), (None, Some(0)));
+
+        indicator.select(Some(2));
+        assert_eq!((indicator.from, indicator.to), (Some(0), Some(2)));
+        let generation = indicator.generation;
+
+        // Re-rendering with the same selection keeps the running animation
+        indicator.select(Some(2));
+        assert_eq!(indicator.generation, generation);
     }
 }
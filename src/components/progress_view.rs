@@ -1,11 +1,16 @@
 //! ProgressView - Progress indicator.
 
+use std::f32::consts::TAU;
+use std::time::Duration;
+
 use gpui::{
-    div, px, relative, rgb, App, IntoElement, ParentElement, RenderOnce, SharedString, Styled,
-    Window,
+    canvas, div, ease_in_out, point, px, relative, Animation, AnimationExt, App, Bounds, Hsla,
+    IntoElement, ParentElement, PathBuilder, Pixels, RenderOnce, SharedString, Styled, Window,
 };
-use gpui_component::{spinner::Spinner, ActiveTheme};
+use gpui_component::spinner::Spinner;
+use gpui_component::{Sizable, Size};
 
+use crate::components::ControlSize;
 use crate::modifier::Modifier;
 use crate::style::Color;
 
@@ -21,6 +26,9 @@ pub enum ProgressViewStyle {
 
 /// A view that shows the progress toward completion of a task.
 ///
+/// Without a value the view is indeterminate: a spinner, or a sliding bar
+/// for the linear style.
+///
 /// # Example
 ///
 /// ```rust,ignore
@@ -31,12 +39,22 @@ pub enum ProgressViewStyle {
 /// ProgressView::new()
 ///     .value(0.65)
 ///     .label("Downloading...")
+///
+/// // Value out of a total, like SwiftUI's `ProgressView(value:total:)`
+/// ProgressView::new()
+///     .current(42.0)
+///     .total(120.0)
+///     .show_percent(true)
 /// ```
 #[derive(IntoElement)]
 pub struct ProgressView {
-    value: Option<f32>,
+    current: Option<f64>,
+    total: f64,
     label: Option<SharedString>,
+    current_value_label: Option<SharedString>,
+    show_percent: bool,
     style: ProgressViewStyle,
+    control_size: ControlSize,
     tint: Option<Color>,
 }
 
@@ -44,36 +62,91 @@ impl ProgressView {
     /// Create an indeterminate progress view.
     pub fn new() -> Self {
         Self {
-            value: None,
+            current: None,
+            total: 1.0,
             label: None,
+            current_value_label: None,
+            show_percent: false,
             style: ProgressViewStyle::default(),
+            control_size: ControlSize::default(),
             tint: None,
         }
     }
 
-    /// Set the progress value (0.0 to 1.0).
-    pub fn value(mut self, value: f32) -> Self {
-        self.value = Some(value.clamp(0.0, 1.0));
+    /// Set the completed amount, measured against [`ProgressView::total`]
+    /// (0.0 to 1.0 by default). Values outside `0.0..=total` are clamped.
+    pub fn value(self, value: f32) -> Self {
+        self.current(value.into())
+    }
+
+    /// Set the completed amount, measured against [`ProgressView::total`].
+    /// Like [`ProgressView::value`], but taking an `f64`.
+    pub fn current(mut self, current: f64) -> Self {
+        self.current = Some(current);
+        self
+    }
+
+    /// Set the amount that represents completion (default 1.0).
+    pub fn total(mut self, total: f64) -> Self {
+        self.total = total;
         self
     }
 
-    /// Set a label to display.
+    /// Set a label describing the task.
+    ///
+    /// Shown above a linear bar and below a circular indicator.
     pub fn label(mut self, label: impl Into<SharedString>) -> Self {
         self.label = Some(label.into());
         self
     }
 
+    /// Set a label describing the current progress, e.g. "3 of 10 files".
+    pub fn current_value_label(mut self, label: impl Into<SharedString>) -> Self {
+        self.current_value_label = Some(label.into());
+        self
+    }
+
+    /// Show the completed percentage when no current value label is set.
+    pub fn show_percent(mut self, show: bool) -> Self {
+        self.show_percent = show;
+        self
+    }
+
     /// Set the progress view style.
     pub fn progress_view_style(mut self, style: ProgressViewStyle) -> Self {
         self.style = style;
         self
     }
 
+    /// Set the size of the circular indicator.
+    pub fn control_size(mut self, size: ControlSize) -> Self {
+        self.control_size = size;
+        self
+    }
+
     /// Set the tint color.
     pub fn tint(mut self, color: impl Into<Color>) -> Self {
         self.tint = Some(color.into());
         self
     }
+
+    /// The completed fraction (0.0 to 1.0), or `None` when indeterminate.
+    fn fraction(&self) -> Option<f32> {
+        self.current.map(|current| {
+            if self.total > 0.0 {
+                (current.clamp(0.0, self.total) / self.total) as f32
+            } else {
+                0.0
+            }
+        })
+    }
+
+    fn value_label(&self) -> Option<SharedString> {
+        self.current_value_label.clone().or_else(|| {
+            let fraction = self.fraction().filter(|_| self.show_percent)?;
+            Some(format!("{}%", (fraction * 100.0).round()).into())
+        })
+    }
 }
 
 impl Default for ProgressView {
@@ -86,47 +159,191 @@ impl Modifier for ProgressView {}
 
 impl RenderOnce for ProgressView {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let is_dark = cx.theme().is_dark();
         let tint = self
             .tint
-            .map(|c| c.resolve(is_dark))
-            .unwrap_or_else(|| rgb(0x007AFF).into());
+            .as_ref()
+            .map_or_else(|| Color::accent().resolve_in(cx), |c| c.resolve_in(cx));
+        let track = Color::muted().resolve_in(cx);
+        let fraction = self.fraction();
+        let value_label = self.value_label().map(|label| {
+            div()
+                .text_sm()
+                .text_color(Color::secondary_label().resolve_in(cx))
+                .child(label)
+        });
 
         match self.style {
             ProgressViewStyle::Circular => {
-                let mut container = div().flex().flex_col().items_center().gap(px(8.0));
-
-                // Use gpui-component's animated Spinner
-                let spinner = Spinner::new().color(tint);
-                container = container.child(spinner);
+                let indicator = match fraction {
+                    // Use gpui-component's animated Spinner
+                    None => Spinner::new()
+                        .color(tint)
+                        .with_size(spinner_size(self.control_size))
+                        .into_any_element(),
+                    Some(fraction) => {
+                        circular_arc(fraction, self.control_size, tint, track).into_any_element()
+                    }
+                };
 
-                if let Some(label) = self.label {
-                    container = container.child(label);
-                }
-
-                container
+                div()
+                    .flex()
+                    .flex_col()
+                    .items_center()
+                    .gap(px(8.0))
+                    .child(indicator)
+                    .children(self.label)
+                    .children(value_label)
             }
             ProgressViewStyle::Linear => {
-                let progress = self.value.unwrap_or(0.0);
-
-                let mut container = div().flex().flex_col().gap(px(4.0)).w_full();
+                let bar = match fraction {
+                    Some(progress) => div()
+                        .h_full()
+                        .rounded_full()
+                        .bg(tint)
+                        .w(relative(progress))
+                        .into_any_element(),
+                    // A segment sliding across the track
+                    None => div()
+                        .absolute()
+                        .top_0()
+                        .h_full()
+                        .w(relative(0.3))
+                        .rounded_full()
+                        .bg(tint)
+                        .with_animation(
+                            "progress-view-indeterminate",
+                            Animation::new(Duration::from_millis(1200))
+                                .repeat()
+                                .with_easing(ease_in_out),
+                            |segment, delta| segment.left(relative(delta * 1.3 - 0.3)),
+                        )
+                        .into_any_element(),
+                };
 
                 // Progress bar track
                 let track = div()
+                    .relative()
+                    .overflow_hidden()
                     .w_full()
                     .h(px(4.0))
                     .rounded_full()
-                    .bg(rgb(0x333333))
-                    .child(div().h_full().rounded_full().bg(tint).w(relative(progress)));
+                    .bg(track)
+                    .child(bar);
 
-                container = container.child(track);
+                div()
+                    .flex()
+                    .flex_col()
+                    .gap(px(4.0))
+                    .w_full()
+                    .children(self.label)
+                    .child(track)
+                    .children(value_label)
+            }
+        }
+    }
+}
 
-                if let Some(label) = self.label {
-                    container = container.child(label);
-                }
+/// The gpui-component spinner size matching a control size.
+fn spinner_size(size: ControlSize) -> Size {
+    match size {
+        ControlSize::Mini => Size::XSmall,
+        ControlSize::Small => Size::Small,
+        ControlSize::Regular => Size::Medium,
+        ControlSize::Large => Size::Large,
+    }
+}
+
+/// A ring with an arc covering `fraction`, starting at the top and running
+/// clockwise.
+fn circular_arc(
+    fraction: f32,
+    control_size: ControlSize,
+    tint: Hsla,
+    track: Hsla,
+) -> impl IntoElement {
+    let size = match control_size {
+        ControlSize::Mini => 12.0,
+        ControlSize::Small => 16.0,
+        ControlSize::Regular => 24.0,
+        ControlSize::Large => 32.0,
+    };
+    let stroke = size / 8.0;
 
-                container
+    canvas(
+        |_, _, _| {},
+        move |bounds: Bounds<Pixels>, _, window, _| {
+            let center = bounds.center();
+            let radius = px((size - stroke) / 2.0);
+            let at = |angle: f32| {
+                point(
+                    center.x + radius * angle.sin(),
+                    center.y - radius * angle.cos(),
+                )
+            };
+            let radii = point(radius, radius);
+
+            let mut ring = PathBuilder::stroke(px(stroke));
+            ring.move_to(at(0.0));
+            ring.arc_to(radii, px(0.), false, true, at(TAU / 2.0));
+            ring.arc_to(radii, px(0.), false, true, at(0.0));
+            if let Ok(path) = ring.build() {
+                window.paint_path(path, track);
             }
-        }
+
+            if fraction > 0.0 {
+                let mut arc = PathBuilder::stroke(px(stroke));
+                arc.move_to(at(0.0));
+                if fraction >= 1.0 {
+                    arc.arc_to(radii, px(0.), false, true, at(TAU / 2.0));
+                    arc.arc_to(radii, px(0.), false, true, at(0.0));
+                } else {
+                    arc.arc_to(radii, px(0.), fraction > 0.5, true, at(fraction * TAU));
+                }
+                if let Ok(path) = arc.build() {
+                    window.paint_path(path, tint);
+                }
+            }
+        },
+    )
+    .size(px(size))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fraction_from_current_and_total() {
+        let progress = ProgressView::new().current(30.0).total(120.0);
+        assert_eq!(progress.fraction(), Some(0.25));
+
+        let overflow = ProgressView::new().current(200.0).total(120.0);
+        assert_eq!(overflow.fraction(), Some(1.0));
+
+        assert_eq!(ProgressView::new().fraction(), None);
+    }
+
+    #[test]
+    fn value_is_clamped_to_the_total() {
+        assert_eq!(ProgressView::new().value(1.5).fraction(), Some(1.0));
+        assert_eq!(ProgressView::new().value(-0.5).fraction(), Some(0.0));
+
+        let progress = ProgressView::new().value(3.0).total(4.0);
+        assert_eq!(progress.fraction(), Some(0.75));
+    }
+
+    #[test]
+    fn percent_label() {
+        let progress = ProgressView::new().value(0.654).show_percent(true);
+        assert_eq!(progress.value_label(), Some("65%".into()));
+
+        let custom = ProgressView::new()
+            .value(0.5)
+            .show_percent(true)
+            .current_value_label("5 of 10");
+        assert_eq!(custom.value_label(), Some("5 of 10".into()));
+
+        let indeterminate = ProgressView::new().show_percent(true);
+        assert_eq!(indeterminate.value_label(), None);
     }
 }
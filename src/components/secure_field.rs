@@ -24,21 +24,105 @@
 //! // In render:
 //! SecureField::new(&self.password_input)
 //!     .show_toggle(true)  // Optional: show reveal button
+//!     .strength_meter(true)  // Optional: rate the password as it's typed
 //! ```
 
-use gpui::{App, Entity, IntoElement, RenderOnce, Styled, Window};
-use gpui_component::input::Input;
-use gpui_component::ActiveTheme;
+use std::rc::Rc;
 
-use super::text_field::InputState;
+use gpui::{
+    div, px, App, Context, ElementId, Entity, InteractiveElement, IntoElement, MouseButton,
+    ParentElement, RenderOnce, Styled, Subscription, Window,
+};
+use gpui_component::input::{Input, InputEvent};
+use gpui_component::{Icon, IconName};
+
+use super::text_field::{InputHandlers, InputState};
+use crate::components::Text;
+use crate::environment;
+use crate::focus;
 use crate::modifier::Modifier;
-use crate::style::Color;
+use crate::style::{Color, ColorScheme, Font};
+
+/// Rates a password for the strength meter.
+type StrengthEvaluator = Rc<dyn Fn(&str) -> PasswordStrength + 'static>;
+
+/// How hard a password is to guess, shown by [`SecureField::strength_meter`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PasswordStrength {
+    Weak,
+    Fair,
+    Good,
+    Strong,
+}
+
+impl PasswordStrength {
+    /// Estimate a password's strength from its length and character classes.
+    ///
+    /// This is the meter's default. It approximates entropy as
+    /// `length × log2(alphabet size)`, where the alphabet grows with each
+    /// class used: lowercase, uppercase, digits and symbols.
+    pub fn estimate(password: &str) -> Self {
+        let mut alphabet = 0;
+        if password.chars().any(|c| c.is_lowercase()) {
+            alphabet += 26;
+        }
+        if password.chars().any(|c| c.is_uppercase()) {
+            alphabet += 26;
+        }
+        if password.chars().any(|c| c.is_ascii_digit()) {
+            alphabet += 10;
+        }
+        if password.chars().any(|c| !c.is_alphanumeric()) {
+            alphabet += 33;
+        }
+
+        let length = password.chars().count() as f64;
+        let bits = length * f64::from(alphabet.max(1)).log2();
+        match bits {
+            bits if bits < 28.0 => Self::Weak,
+            bits if bits < 40.0 => Self::Fair,
+            bits if bits < 64.0 => Self::Good,
+            _ => Self::Strong,
+        }
+    }
+
+    /// The number of meter segments filled, from 1 to 4.
+    pub fn level(self) -> usize {
+        match self {
+            Self::Weak => 1,
+            Self::Fair => 2,
+            Self::Good => 3,
+            Self::Strong => 4,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Weak => "Weak",
+            Self::Fair => "Fair",
+            Self::Good => "Good",
+            Self::Strong => "Strong",
+        }
+    }
+
+    fn color(self) -> Color {
+        match self {
+            Self::Weak => Color::red(),
+            Self::Fair => Color::orange(),
+            Self::Good => Color::yellow(),
+            Self::Strong => Color::green(),
+        }
+    }
+}
 
 /// A secure text input field for passwords.
 ///
 /// This component wraps gpui-component's Input with masking enabled.
 /// The InputState should be created with `.masked(true)`.
 ///
+/// Bind a focus handle the app keeps with [`Modifier::focused`] to move
+/// the cursor into the field from code.
+///
 /// # Example
 ///
 /// ```rust,ignore
@@ -49,12 +133,18 @@ use crate::style::Color;
 /// );
 ///
 /// SecureField::new(&password)
-///     .show_toggle(true)  // Show reveal/hide button
+///     .reveal_on_hold(true)  // Hold the eye icon to peek
+///     .strength_meter(true)
+///     .on_submit(cx.listener(|this, password: &str, _, cx| this.sign_in(password, cx)))
 /// ```
 #[derive(IntoElement)]
 pub struct SecureField {
     state: Entity<InputState>,
     show_toggle: bool,
+    reveal_on_hold: bool,
+    strength_meter: bool,
+    evaluator: StrengthEvaluator,
+    handlers: InputHandlers,
     disabled: bool,
 }
 
@@ -66,6 +156,10 @@ impl SecureField {
         Self {
             state: state.clone(),
             show_toggle: false,
+            reveal_on_hold: false,
+            strength_meter: false,
+            evaluator: Rc::new(PasswordStrength::estimate),
+            handlers: InputHandlers::default(),
             disabled: false,
         }
     }
@@ -76,6 +170,35 @@ impl SecureField {
         self
     }
 
+    /// Show an eye button that reveals the password only while it's held.
+    ///
+    /// Takes the place of [`show_toggle`](Self::show_toggle).
+    pub fn reveal_on_hold(mut self, reveal: bool) -> Self {
+        self.reveal_on_hold = reveal;
+        self
+    }
+
+    /// Show a four-segment strength meter below the field.
+    pub fn strength_meter(mut self, show: bool) -> Self {
+        self.strength_meter = show;
+        self
+    }
+
+    /// Replace the meter's [`PasswordStrength::estimate`] heuristic.
+    pub fn strength_evaluator(
+        mut self,
+        evaluator: impl Fn(&str) -> PasswordStrength + 'static,
+    ) -> Self {
+        self.evaluator = Rc::new(evaluator);
+        self
+    }
+
+    /// Call a handler with the password when Enter is pressed.
+    pub fn on_submit(mut self, handler: impl Fn(&str, &mut Window, &mut App) + 'static) -> Self {
+        self.handlers.on_submit = Some(Rc::new(handler));
+        self
+    }
+
     /// Disable the secure field.
     pub fn disabled(mut self, disabled: bool) -> Self {
         self.disabled = disabled;
@@ -86,8 +209,13 @@ impl SecureField {
 impl Modifier for SecureField {}
 
 impl RenderOnce for SecureField {
-    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let is_dark = cx.theme().is_dark();
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        self.handlers.attach(&self.state, window, cx);
+        if let Some(handle) = focus::take(cx) {
+            focus::forward_to_input(&handle, &self.state, window, cx);
+        }
+
+        let is_dark = environment::color_scheme(cx) == ColorScheme::Dark;
         let bg_color = Color::text_field_background().resolve(is_dark);
         let border_color = Color::text_field_border().resolve(is_dark);
 
@@ -95,7 +223,20 @@ impl RenderOnce for SecureField {
             .bg(bg_color)
             .border_color(border_color);
 
-        if self.show_toggle {
+        let field = (self.reveal_on_hold || self.strength_meter).then(|| {
+            let id = ElementId::NamedChild(
+                Box::new(ElementId::View(self.state.entity_id())),
+                "secure-field".into(),
+            );
+            let state = self.state.clone();
+            window.use_keyed_state(id, cx, |window, cx| {
+                SecureFieldState::new(&state, window, cx)
+            })
+        });
+
+        if let Some(field) = field.as_ref().filter(|_| self.reveal_on_hold) {
+            input = input.suffix(hold_to_reveal(&self.state, field, cx));
+        } else if self.show_toggle {
             input = input.mask_toggle();
         }
 
@@ -103,6 +244,144 @@ impl RenderOnce for SecureField {
             input = input.disabled(true);
         }
 
-        input
+        if !self.strength_meter {
+            return input.into_any_element();
+        }
+
+        let password = self.state.read(cx).value();
+        let strength = (!password.is_empty()).then(|| (self.evaluator)(&password));
+        let filled = strength.map_or(0, PasswordStrength::level);
+        let fill = strength.map(|strength| strength.color().resolve_in(cx));
+        let empty = Color::separator().resolve_in(cx);
+
+        let meter = div()
+            .flex()
+            .flex_row()
+            .gap(px(4.0))
+            .children((0..4).map(|segment| {
+                let color = match fill {
+                    Some(fill) if segment < filled => fill,
+                    _ => empty,
+                };
+                div().flex_1().h(px(4.0)).rounded(px(2.0)).bg(color)
+            }));
+
+        div()
+            .flex()
+            .flex_col()
+            .gap(px(6.0))
+            .child(input)
+            .child(meter)
+            .children(strength.map(|strength| {
+                Text::new(strength.label())
+                    .font(Font::caption())
+                    .foreground_color(strength.color())
+            }))
+            .into_any_element()
+    }
+}
+
+/// An eye button that unmasks the field while the mouse is held on it.
+fn hold_to_reveal(
+    state: &Entity<InputState>,
+    field: &Entity<SecureFieldState>,
+    cx: &App,
+) -> impl IntoElement {
+    let revealed = field.read(cx).revealed;
+    let icon_color = Color::secondary_label().resolve_in(cx);
+    let (hold, release, release_out) = (
+        (state.clone(), field.clone()),
+        (state.clone(), field.clone()),
+        (state.clone(), field.clone()),
+    );
+
+    div()
+        .id("reveal-password")
+        .flex()
+        .items_center()
+        .cursor_pointer()
+        .child(
+            Icon::new(if revealed {
+                IconName::EyeOff
+            } else {
+                IconName::Eye
+            })
+            .size_4()
+            .text_color(icon_color),
+        )
+        .on_mouse_down(MouseButton::Left, move |_, window, cx| {
+            set_revealed(&hold.0, &hold.1, true, window, cx)
+        })
+        .on_mouse_up(MouseButton::Left, move |_, window, cx| {
+            set_revealed(&release.0, &release.1, false, window, cx)
+        })
+        .on_mouse_up_out(MouseButton::Left, move |_, window, cx| {
+            set_revealed(&release_out.0, &release_out.1, false, window, cx)
+        })
+}
+
+fn set_revealed(
+    state: &Entity<InputState>,
+    field: &Entity<SecureFieldState>,
+    revealed: bool,
+    window: &mut Window,
+    cx: &mut App,
+) {
+    if field.read(cx).revealed == revealed {
+        return;
+    }
+    field.update(cx, |field, cx| {
+        field.revealed = revealed;
+        cx.notify();
+    });
+    state.update(cx, |state, cx| state.set_masked(!revealed, window, cx));
+}
+
+/// Reveal state for a secure field, and a subscription that re-renders the
+/// strength meter as the password changes.
+struct SecureFieldState {
+    revealed: bool,
+    _subscription: Subscription,
+}
+
+impl SecureFieldState {
+    fn new(state: &Entity<InputState>, window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let subscription = cx.subscribe_in(state, window, |_, _, event, _, cx| {
+            if matches!(event, InputEvent::Change) {
+                cx.notify();
+            }
+        });
+
+        Self {
+            revealed: false,
+            _subscription: subscription,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn estimates_strength_from_length_and_classes() {
+        assert_eq!(PasswordStrength::estimate("abc"), PasswordStrength::Weak);
+        assert_eq!(
+            PasswordStrength::estimate("password"),
+            PasswordStrength::Fair
+        );
+        assert_eq!(
+            PasswordStrength::estimate("Password12"),
+            PasswordStrength::Good
+        );
+        assert_eq!(
+            PasswordStrength::estimate("c0rrect-Horse-battery"),
+            PasswordStrength::Strong
+        );
+    }
+
+    #[test]
+    fn more_classes_are_stronger() {
+        assert!(PasswordStrength::estimate("abcdefgh") < PasswordStrength::estimate("abcdEF1!"));
     }
 }
@@ -0,0 +1,362 @@
+//! Shapes - Rectangles, circles, capsules and ellipses as views.
+//!
+//! Shapes fill the frame they are given, so size them with the `frame`
+//! modifiers. They fill with the foreground color unless given a
+//! [`fill`](Rectangle::fill) or a [`stroke`](Rectangle::stroke).
+//!
+//! # Usage
+//!
+//! ```rust,ignore
+//! Circle::new().fill(Color::red()).frame_width(40.0)
+//!
+//! RoundedRectangle::new(8.0)
+//!     .fill(LinearGradient::new(Color::blue(), Color::purple()))
+//!     .stroke(Color::white(), 2.0)
+//!     .frame_size(120.0, 80.0)
+//! ```
+
+use gpui::{
+    canvas, point, px, quad, App, Background, BorderStyle, Bounds, Hsla, IntoElement, PathBuilder,
+    Pixels, RenderOnce, Styled, Window,
+};
+
+use crate::modifier::Modifier;
+use crate::style::{Color, ShapeStyle};
+
+/// The outline of a shape.
+///
+/// Used by `.clip_shape()` to round a view's corners to match a shape.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ShapeOutline {
+    /// Square corners.
+    Rectangle,
+    /// Corners rounded with the given radius in points.
+    RoundedRectangle(f32),
+    /// The largest circle that fits, centered.
+    Circle,
+    /// Ends rounded to half the shorter side.
+    Capsule,
+    /// The ellipse touching all four edges.
+    Ellipse,
+}
+
+impl ShapeOutline {
+    /// The corner radius for a `width` by `height` box, or `None` for an
+    /// ellipse, which can't be drawn as a rounded rectangle.
+    fn corner_radius(self, width: f32, height: f32) -> Option<f32> {
+        match self {
+            ShapeOutline::Rectangle => Some(0.0),
+            ShapeOutline::RoundedRectangle(radius) => {
+                Some(radius.clamp(0.0, width.min(height) / 2.0))
+            }
+            ShapeOutline::Circle | ShapeOutline::Capsule => Some(width.min(height) / 2.0),
+            ShapeOutline::Ellipse => None,
+        }
+    }
+
+    /// The bounds the shape is drawn in: centered and square for a circle,
+    /// the whole of `bounds` otherwise.
+    fn fit(self, bounds: Bounds<Pixels>) -> Bounds<Pixels> {
+        match self {
+            ShapeOutline::Circle => {
+                let side = bounds.size.width.min(bounds.size.height);
+                Bounds::centered_at(bounds.center(), gpui::size(side, side))
+            }
+            _ => bounds,
+        }
+    }
+}
+
+/// A view that can be drawn as a shape, and used with `.clip_shape()`.
+pub trait Shape {
+    /// The outline of the shape.
+    fn outline(&self) -> ShapeOutline;
+}
+
+/// How a shape is painted, shared by all shapes.
+#[derive(Default)]
+struct ShapePaint {
+    fill: Option<Box<dyn ShapeStyle>>,
+    stroke: Option<(Color, f32)>,
+}
+
+macro_rules! shape_views {
+    ($($(#[$doc:meta])* $name:ident;)*) => {$(
+        $(#[$doc])*
+        #[derive(IntoElement)]
+        pub struct $name {
+            outline: ShapeOutline,
+            paint: ShapePaint,
+        }
+
+        impl $name {
+            /// Fill the shape with a color or gradient.
+            ///
+            /// Defaults to the foreground color, or no fill when the shape
+            /// has a stroke.
+            pub fn fill(mut self, style: impl ShapeStyle) -> Self {
+                self.paint.fill = Some(Box::new(style));
+                self
+            }
+
+            /// Outline the shape with a line of `width` points, drawn inside
+            /// its edge.
+            pub fn stroke(mut self, color: impl Into<Color>, width: f32) -> Self {
+                self.paint.stroke = Some((color.into(), width));
+                self
+            }
+        }
+
+        impl Shape for $name {
+            fn outline(&self) -> ShapeOutline {
+                self.outline
+            }
+        }
+
+        impl Modifier for $name {}
+
+        impl RenderOnce for $name {
+            fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+                render_shape(self.outline, self.paint, window, cx)
+            }
+        }
+    )*};
+}
+
+shape_views! {
+    /// A rectangle filling its frame.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// Rectangle::new().fill(Color::blue()).frame_size(100.0, 4.0)
+    /// ```
+    Rectangle;
+
+    /// A rectangle with rounded corners filling its frame.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// RoundedRectangle::new(12.0)
+    ///     .stroke(Color::separator(), 1.0)
+    ///     .frame_size(200.0, 120.0)
+    /// ```
+    RoundedRectangle;
+
+    /// A circle centered in its frame.
+    ///
+    /// Given only a width or a height, the frame stays square.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// Circle::new().fill(Color::green()).frame_width(10.0)
+    /// ```
+    Circle;
+
+    /// A rectangle with fully rounded ends filling its frame, like a pill.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// Capsule::new().fill(Color::orange()).frame_size(80.0, 24.0)
+    /// ```
+    Capsule;
+
+    /// An ellipse filling its frame.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// Ellipse::new().fill(Color::purple()).frame_size(120.0, 60.0)
+    /// ```
+    Ellipse;
+}
+
+impl Rectangle {
+    /// Create a rectangle.
+    pub fn new() -> Self {
+        Self {
+            outline: ShapeOutline::Rectangle,
+            paint: ShapePaint::default(),
+        }
+    }
+}
+
+impl Default for Rectangle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RoundedRectangle {
+    /// Create a rounded rectangle with a corner radius in points.
+    pub fn new(radius: f32) -> Self {
+        Self {
+            outline: ShapeOutline::RoundedRectangle(radius),
+            paint: ShapePaint::default(),
+        }
+    }
+}
+
+impl Circle {
+    /// Create a circle.
+    pub fn new() -> Self {
+        Self {
+            outline: ShapeOutline::Circle,
+            paint: ShapePaint::default(),
+        }
+    }
+}
+
+impl Default for Circle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Capsule {
+    /// Create a capsule.
+    pub fn new() -> Self {
+        Self {
+            outline: ShapeOutline::Capsule,
+            paint: ShapePaint::default(),
+        }
+    }
+}
+
+impl Default for Capsule {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Ellipse {
+    /// Create an ellipse.
+    pub fn new() -> Self {
+        Self {
+            outline: ShapeOutline::Ellipse,
+            paint: ShapePaint::default(),
+        }
+    }
+}
+
+impl Default for Ellipse {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Paint `outline` into the bounds the shape is laid out in.
+fn render_shape(
+    outline: ShapeOutline,
+    paint: ShapePaint,
+    window: &mut Window,
+    cx: &mut App,
+) -> impl IntoElement {
+    // Colors are resolved now, while the theme and environment are known
+    let fill: Option<Background> = match (paint.fill, &paint.stroke) {
+        (Some(style), _) => Some(style.resolve_background(cx)),
+        (None, Some(_)) => None,
+        (None, None) => Some(window.text_style().color.into()),
+    };
+    let stroke: Option<(Hsla, f32)> = paint
+        .stroke
+        .map(|(color, width)| (color.resolve_in(cx), width));
+
+    let mut shape = canvas(
+        |_, _, _| {},
+        move |bounds, _, window, _| {
+            let bounds = outline.fit(bounds);
+            let width = f32::from(bounds.size.width);
+            let height = f32::from(bounds.size.height);
+            match outline.corner_radius(width, height) {
+                Some(radius) => {
+                    let (stroke_color, stroke_width) = stroke.unwrap_or_default();
+                    window.paint_quad(quad(
+                        bounds,
+                        px(radius),
+                        fill.unwrap_or_else(|| gpui::transparent_black().into()),
+                        px(stroke_width),
+                        stroke_color,
+                        BorderStyle::Solid,
+                    ));
+                }
+                None => paint_ellipse(bounds, fill, stroke, window),
+            }
+        },
+    )
+    .size_full();
+    if outline == ShapeOutline::Circle {
+        // Keep square when the frame sets only one dimension
+        shape.style().aspect_ratio = Some(1.0);
+    }
+    shape
+}
+
+/// Paint an ellipse touching the edges of `bounds`, with the stroke inside
+/// them.
+fn paint_ellipse(
+    bounds: Bounds<Pixels>,
+    fill: Option<Background>,
+    stroke: Option<(Hsla, f32)>,
+    window: &mut Window,
+) {
+    let ellipse = |mut builder: PathBuilder, inset: Pixels| {
+        let radii = point(
+            bounds.size.width / 2.0 - inset,
+            bounds.size.height / 2.0 - inset,
+        );
+        let center = bounds.center();
+        let left = point(center.x - radii.x, center.y);
+        let right = point(center.x + radii.x, center.y);
+        builder.move_to(left);
+        builder.arc_to(radii, px(0.0), false, true, right);
+        builder.arc_to(radii, px(0.0), false, true, left);
+        builder.close();
+        builder.build().ok()
+    };
+
+    if let Some(fill) = fill {
+        if let Some(path) = ellipse(PathBuilder::fill(), px(0.0)) {
+            window.paint_path(path, fill);
+        }
+    }
+    if let Some((color, width)) = stroke.filter(|(_, width)| *width > 0.0) {
+        if let Some(path) = ellipse(PathBuilder::stroke(px(width)), px(width / 2.0)) {
+            window.paint_path(path, color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn corner_radius_follows_the_outline() {
+        assert_eq!(ShapeOutline::Rectangle.corner_radius(80.0, 40.0), Some(0.0));
+        assert_eq!(
+            ShapeOutline::RoundedRectangle(8.0).corner_radius(80.0, 40.0),
+            Some(8.0)
+        );
+        // Clamped so the corners don't overlap
+        assert_eq!(
+            ShapeOutline::RoundedRectangle(30.0).corner_radius(80.0, 40.0),
+            Some(20.0)
+        );
+        assert_eq!(ShapeOutline::Capsule.corner_radius(80.0, 40.0), Some(20.0));
+        assert_eq!(ShapeOutline::Ellipse.corner_radius(80.0, 40.0), None);
+    }
+
+    #[test]
+    fn circle_is_centered_and_square() {
+        let bounds = Bounds::new(point(px(10.0), px(20.0)), gpui::size(px(80.0), px(40.0)));
+        assert_eq!(
+            ShapeOutline::Circle.fit(bounds),
+            Bounds::new(point(px(30.0), px(20.0)), gpui::size(px(40.0), px(40.0)))
+        );
+        assert_eq!(ShapeOutline::Capsule.fit(bounds), bounds);
+    }
+}
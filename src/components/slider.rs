@@ -28,16 +28,101 @@
 //! // In render:
 //! Slider::new(&self.volume_slider)
 //! ```
+//!
+//! Sliders can also own their value: pass the current value and update it
+//! in a handler. For a min/max range with two thumbs:
+//!
+//! ```rust,ignore
+//! Slider::range_values(self.min_price, self.max_price)
+//!     .bounds(0.0..=500.0)
+//!     .on_range_change(cx.listener(|this, (low, high): &(f32, f32), _, cx| {
+//!         (this.min_price, this.max_price) = (*low, *high);
+//!         cx.notify();
+//!     }))
+//! ```
+//!
+//! A vertical slider with tick marks the thumb snaps to:
+//!
+//! ```rust,ignore
+//! Slider::value(self.gain)
+//!     .bounds(-12.0..=12.0)
+//!     .orientation(Axis::Vertical)
+//!     .ticks(5)
+//!     .snap_to_ticks(true)
+//!     .on_change(cx.listener(|this, gain: &f32, _, cx| {
+//!         this.gain = *gain;
+//!         cx.notify();
+//!     }))
+//!     .frame_height(160.0)
+//! ```
+
+use std::ops::RangeInclusive;
+use std::rc::Rc;
 
-use gpui::{px, App, Entity, IntoElement, Pixels, RenderOnce, Styled, Window};
+use gpui::{
+    div, px, relative, AnyElement, App, AppContext, Context, ElementId, Entity, InteractiveElement,
+    IntoElement, MouseButton, ParentElement, Pixels, RenderOnce, SharedString, Styled,
+    Subscription, Window,
+};
 use gpui_component::slider::Slider as GpuiSlider;
 
 // Re-export SliderState for users
+pub use gpui::Axis;
 pub use gpui_component::slider::SliderEvent;
 pub use gpui_component::slider::SliderState;
 pub use gpui_component::slider::SliderValue;
 
+use super::stepper::format_value;
+use crate::accessibility::{AccessibilityNode, AccessibilityRole, Accessible};
+use crate::binding::Binding;
 use crate::modifier::Modifier;
+use crate::style::Color;
+
+/// Handler called with an owned slider's new `(start, end)` values.
+///
+/// Single-value sliders report their value as both start and end.
+type ChangeHandler = Rc<dyn Fn((f32, f32), &mut Window, &mut App) + 'static>;
+
+/// Where a slider's value lives.
+enum SliderSource {
+    /// State created and subscribed to by the caller.
+    State(Entity<SliderState>),
+    /// A value owned by the caller and reported through a change handler.
+    Owned(SliderValue),
+}
+
+/// Tick marks drawn along a slider's track.
+#[derive(Clone, Debug, PartialEq)]
+enum Ticks {
+    /// Evenly spaced marks, including both ends.
+    Count(usize),
+    /// Marks at specific values.
+    Values(Vec<f32>),
+}
+
+impl Ticks {
+    /// The tick values for a slider with the given bounds.
+    fn values(&self, min: f32, max: f32) -> Vec<f32> {
+        match self {
+            Ticks::Count(0) => Vec::new(),
+            Ticks::Count(1) => vec![min],
+            Ticks::Count(count) => {
+                let interval = (max - min) / (*count - 1) as f32;
+                (0..*count).map(|i| min + interval * i as f32).collect()
+            }
+            Ticks::Values(values) => values.clone(),
+        }
+    }
+}
+
+/// The tick value nearest to `value`, or `value` itself without ticks.
+fn snap(value: f32, ticks: &[f32]) -> f32 {
+    ticks
+        .iter()
+        .copied()
+        .min_by(|a, b| (a - value).abs().total_cmp(&(b - value).abs()))
+        .unwrap_or(value)
+}
 
 /// A control for selecting a value from a bounded range.
 ///
@@ -64,32 +149,185 @@ use crate::modifier::Modifier;
 ///
 /// // Use in render
 /// Slider::new(&brightness)
+///     .min_label(Image::system_name("sun.min"))
+///     .max_label(Image::system_name("sun.max"))
 /// ```
 #[derive(IntoElement)]
 pub struct Slider {
-    state: Entity<SliderState>,
-    vertical: bool,
+    id: SharedString,
+    source: SliderSource,
+    bounds: RangeInclusive<f32>,
+    step: Option<f32>,
+    on_change: Option<ChangeHandler>,
+    ticks: Option<Ticks>,
+    snap_to_ticks: bool,
+    min_label: Option<AnyElement>,
+    max_label: Option<AnyElement>,
+    show_value: bool,
+    axis: Axis,
     height: Option<Pixels>,
     disabled: bool,
+    /// Read for the value while rendering, and written when dragged.
+    binding: Option<Binding<f32>>,
 }
 
 impl Slider {
     /// Create a new slider with the given state.
     pub fn new(state: &Entity<SliderState>) -> Self {
+        Self::with_source(SharedString::default(), SliderSource::State(state.clone()))
+    }
+
+    /// Create a slider for a value you own.
+    ///
+    /// The slider's ID is derived from the call site; use [`Slider::id`]
+    /// when creating sliders in a loop.
+    #[track_caller]
+    pub fn value(value: f32) -> Self {
+        Self::with_source(
+            caller_id(std::panic::Location::caller()),
+            SliderSource::Owned(SliderValue::Single(value)),
+        )
+    }
+
+    /// Create a slider for a value read and written through `binding`.
+    ///
+    /// The slider's ID is derived from the call site; use [`Slider::id`]
+    /// when creating sliders in a loop.
+    ///
+    /// ```rust,ignore
+    /// let view = cx.entity();
+    /// Slider::bound(Binding::entity_field(&view, |this| &mut this.volume))
+    /// ```
+    #[track_caller]
+    pub fn bound(binding: Binding<f32>) -> Self {
+        let mut slider = Self::value(0.0);
+        slider.binding = Some(binding);
+        slider
+    }
+
+    /// Create a two-thumb slider selecting the range `low..=high`.
+    ///
+    /// The slider's ID is derived from the call site; use [`Slider::id`]
+    /// when creating sliders in a loop.
+    #[track_caller]
+    pub fn range_values(low: f32, high: f32) -> Self {
+        Self::with_source(
+            caller_id(std::panic::Location::caller()),
+            SliderSource::Owned(SliderValue::Range(low, high)),
+        )
+    }
+
+    fn with_source(id: SharedString, source: SliderSource) -> Self {
         Self {
-            state: state.clone(),
-            vertical: false,
+            id,
+            source,
+            bounds: 0.0..=1.0,
+            step: None,
+            on_change: None,
+            ticks: None,
+            snap_to_ticks: false,
+            min_label: None,
+            max_label: None,
+            show_value: false,
+            axis: Axis::Horizontal,
             height: None,
             disabled: false,
+            binding: None,
         }
     }
 
-    /// Make the slider vertical.
-    pub fn vertical(mut self) -> Self {
-        self.vertical = true;
+    /// Set the slider's ID.
+    pub fn id(mut self, id: impl Into<SharedString>) -> Self {
+        self.id = id.into();
         self
     }
 
+    /// Set the selectable bounds (default 0.0 to 1.0).
+    ///
+    /// For sliders created with [`Slider::new`], the state's own min and
+    /// max apply; set matching bounds to place [`Slider::tick_values`].
+    pub fn bounds(mut self, bounds: RangeInclusive<f32>) -> Self {
+        self.bounds = bounds;
+        self
+    }
+
+    /// Set the step between selectable values of an owned slider.
+    pub fn step(mut self, step: f32) -> Self {
+        self.step = Some(step);
+        self
+    }
+
+    /// Set the handler called with an owned slider's new value.
+    pub fn on_change(mut self, handler: impl Fn(&f32, &mut Window, &mut App) + 'static) -> Self {
+        self.on_change = Some(Rc::new(move |(value, _), window, cx| {
+            handler(&value, window, cx)
+        }));
+        self
+    }
+
+    /// Set the handler called with a range slider's new `(low, high)` values.
+    pub fn on_range_change(
+        mut self,
+        handler: impl Fn(&(f32, f32), &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_change = Some(Rc::new(move |range, window, cx| {
+            handler(&range, window, cx)
+        }));
+        self
+    }
+
+    /// Draw `count` evenly spaced tick marks along the track, including both ends.
+    pub fn ticks(mut self, count: usize) -> Self {
+        self.ticks = Some(Ticks::Count(count));
+        self
+    }
+
+    /// Draw tick marks at specific values.
+    pub fn tick_values(mut self, values: &[f32]) -> Self {
+        self.ticks = Some(Ticks::Values(values.to_vec()));
+        self
+    }
+
+    /// Snap an owned slider's thumbs to the nearest tick mark.
+    pub fn snap_to_ticks(mut self, snap: bool) -> Self {
+        self.snap_to_ticks = snap;
+        self
+    }
+
+    /// Show a view before the track, like SwiftUI's `minimumValueLabel`.
+    pub fn min_label(mut self, label: impl IntoElement) -> Self {
+        self.min_label = Some(label.into_any_element());
+        self
+    }
+
+    /// Show a view after the track, like SwiftUI's `maximumValueLabel`.
+    pub fn max_label(mut self, label: impl IntoElement) -> Self {
+        self.max_label = Some(label.into_any_element());
+        self
+    }
+
+    /// Show the value above the thumb being dragged.
+    ///
+    /// Applies to horizontal owned sliders, whose bounds are known.
+    pub fn show_value(mut self, show: bool) -> Self {
+        self.show_value = show;
+        self
+    }
+
+    /// Set the direction of the track (default horizontal).
+    ///
+    /// A vertical slider has its minimum at the bottom and fills the height
+    /// given by `frame_height` unless [`Slider::height`] is set.
+    pub fn orientation(mut self, axis: Axis) -> Self {
+        self.axis = axis;
+        self
+    }
+
+    /// Make the slider vertical.
+    pub fn vertical(self) -> Self {
+        self.orientation(Axis::Vertical)
+    }
+
     /// Set the height (useful for vertical sliders).
     pub fn height(mut self, height: f32) -> Self {
         self.height = Some(px(height));
@@ -101,16 +339,103 @@ impl Slider {
         self.disabled = disabled;
         self
     }
+
+    /// How the slider is described to assistive technology: by its value,
+    /// or both ends of a range.
+    fn accessibility(&self, cx: &App) -> AccessibilityNode {
+        let config = self.config();
+        let value = match &self.source {
+            SliderSource::State(state) => state.read(cx).value(),
+            SliderSource::Owned(value) => *value,
+        };
+        let value = if value.is_range() {
+            format!(
+                "{} to {}",
+                config.format(value.start()),
+                config.format(value.end())
+            )
+        } else {
+            config.format(value.start())
+        };
+        AccessibilityNode::new(AccessibilityRole::Slider).value(value)
+    }
+
+    fn config(&self) -> SliderConfig {
+        SliderConfig {
+            min: *self.bounds.start(),
+            max: *self.bounds.end(),
+            step: self.step,
+        }
+    }
+}
+
+fn caller_id(location: &std::panic::Location) -> SharedString {
+    format!(
+        "{}:{}:{}",
+        location.file(),
+        location.line(),
+        location.column()
+    )
+    .into()
 }
 
 impl Modifier for Slider {}
 
 impl RenderOnce for Slider {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
-        let mut slider = GpuiSlider::new(&self.state);
+    fn render(mut self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        // A bound slider is an owned one that writes back through its binding
+        if let Some(binding) = self.binding.take() {
+            self.source = SliderSource::Owned(SliderValue::Single(binding.get(cx)));
+            let on_change = self.on_change.take();
+            self.on_change = Some(Rc::new(move |values, window, cx| {
+                binding.set(values.0, window, cx);
+                if let Some(on_change) = &on_change {
+                    on_change(values, window, cx);
+                }
+            }));
+        }
+
+        let vertical = self.axis == Axis::Vertical;
+        let config = self.config();
+        let accessibility = self.accessibility(cx);
+        let ticks = self
+            .ticks
+            .map(|ticks| ticks.values(config.min, config.max))
+            .unwrap_or_default();
+
+        let (state, field) = match self.source {
+            SliderSource::State(state) => (state, None),
+            SliderSource::Owned(value) => {
+                let (start, end) = (value.start(), value.end());
+                let is_range = value.is_range();
+                let field = window.use_keyed_state(
+                    ElementId::Name(format!("{}-value", self.id).into()),
+                    cx,
+                    |window, cx| OwnedSliderField::new(config, value, window, cx),
+                );
+                field.update(cx, |field, cx| {
+                    if field.config != config || field.is_range != is_range {
+                        *field = OwnedSliderField::new(config, value, window, cx);
+                    } else if field.value != (start, end) {
+                        field.value = (start, end);
+                        field.state.update(cx, |state, cx| {
+                            state.set_value(value, window, cx);
+                        });
+                    }
+                    field.snap = self.snap_to_ticks.then(|| ticks.clone());
+                    field.on_change = self.on_change.clone();
+                });
+                (field.read(cx).state.clone(), Some(field))
+            }
+        };
+
+        let mut slider = GpuiSlider::new(&state);
 
-        if self.vertical {
+        if vertical {
             slider = slider.vertical();
+            if self.height.is_none() {
+                slider = slider.h_full();
+            }
         }
 
         if let Some(height) = self.height {
@@ -121,6 +446,318 @@ impl RenderOnce for Slider {
             slider = slider.disabled(true);
         }
 
-        slider
+        let mut track = slider.into_any_element();
+
+        if !ticks.is_empty() {
+            let fractions = ticks.iter().map(|&tick| config.fraction(tick)).collect();
+            track = tick_marks(track, fractions, vertical, self.height, cx);
+        }
+
+        let show_value = self.show_value && !vertical && !self.disabled;
+        if let Some(field) = field.filter(|_| show_value) {
+            track = value_overlay(
+                ElementId::Name(format!("{}-overlay", self.id).into()),
+                track,
+                field,
+                cx,
+            );
+        }
+
+        if self.min_label.is_none() && self.max_label.is_none() {
+            return Accessible::new(accessibility, track, cx);
+        }
+
+        let mut container = div().flex().items_center().gap(px(8.0));
+        container = if vertical {
+            // The maximum is at the top of a vertical slider
+            container
+                .h_full()
+                .flex_col()
+                .children(self.max_label)
+                .child(div().flex_1().child(track))
+                .children(self.min_label)
+        } else {
+            container
+                .w_full()
+                .children(self.min_label)
+                .child(div().flex_1().child(track))
+                .children(self.max_label)
+        };
+        Accessible::new(accessibility, container, cx)
+    }
+}
+
+/// Lay out tick marks beside a track: below it, or to its right when vertical.
+fn tick_marks(
+    track: AnyElement,
+    fractions: Vec<f32>,
+    vertical: bool,
+    height: Option<Pixels>,
+    cx: &App,
+) -> AnyElement {
+    let color = Color::tertiary_label().resolve_in(cx);
+    let marks = fractions.into_iter().map(|fraction| {
+        let mark = div().absolute().bg(color);
+        if vertical {
+            mark.top(relative(1.0 - fraction)).w_full().h(px(1.0))
+        } else {
+            mark.left(relative(fraction)).h_full().w(px(1.0))
+        }
+    });
+
+    if vertical {
+        let mut column = div().flex().gap(px(4.0));
+        column = match height {
+            Some(height) => column.h(height),
+            None => column.h_full(),
+        };
+        column
+            .child(track)
+            .child(div().relative().w(px(6.0)).h_full().children(marks))
+            .into_any_element()
+    } else {
+        div()
+            .flex()
+            .flex_col()
+            .gap(px(2.0))
+            .child(track)
+            .child(div().relative().h(px(6.0)).w_full().children(marks))
+            .into_any_element()
+    }
+}
+
+/// Wrap a track with a value bubble above the dragged thumb.
+fn value_overlay(
+    id: ElementId,
+    track: AnyElement,
+    field: Entity<OwnedSliderField>,
+    cx: &App,
+) -> AnyElement {
+    let OwnedSliderField {
+        config,
+        value: (start, end),
+        active,
+        dragging,
+        ..
+    } = *field.read(cx);
+
+    let bubble = dragging.then(|| {
+        let value = match active {
+            Thumb::Start => start,
+            Thumb::End => end,
+        };
+        let fraction = config.fraction(value);
+        let text = config.format(value);
+
+        // A zero-width anchor at the thumb position, with the bubble centered on it
+        div()
+            .absolute()
+            .bottom_full()
+            .left(relative(fraction))
+            .w(px(0.0))
+            .mb(px(4.0))
+            .flex()
+            .justify_center()
+            .child(
+                div()
+                    .px(px(6.0))
+                    .py(px(2.0))
+                    .rounded(px(4.0))
+                    .text_xs()
+                    .bg(Color::secondary_system_background().resolve_in(cx))
+                    .child(text),
+            )
+    });
+
+    let down = field.clone();
+    let up = field.clone();
+    div()
+        .id(id)
+        .relative()
+        .capture_any_mouse_down(move |_, _, cx| {
+            down.update(cx, |field, cx| {
+                field.dragging = true;
+                cx.notify();
+            });
+        })
+        .capture_any_mouse_up(move |_, _, cx| {
+            up.update(cx, |field, cx| {
+                field.dragging = false;
+                cx.notify();
+            });
+        })
+        .on_mouse_up_out(MouseButton::Left, move |_, _, cx| {
+            field.update(cx, |field, cx| {
+                field.dragging = false;
+                cx.notify();
+            });
+        })
+        .child(track)
+        .children(bubble)
+        .into_any_element()
+}
+
+/// The bounds and step an owned slider's state was built with.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct SliderConfig {
+    min: f32,
+    max: f32,
+    step: Option<f32>,
+}
+
+impl SliderConfig {
+    /// The position of a value along the track (0.0 to 1.0).
+    fn fraction(&self, value: f32) -> f32 {
+        if self.max > self.min {
+            ((value - self.min) / (self.max - self.min)).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+
+    /// A value as shown to the user, to the precision of the step.
+    fn format(&self, value: f32) -> String {
+        match self.step {
+            // Widen the step through its shortest decimal form, so 0.05
+            // stays 0.05 rather than 0.0500000007
+            Some(step) => format_value(value as f64, step.to_string().parse().unwrap_or(0.0)),
+            None => format!("{value:.2}"),
+        }
+    }
+}
+
+/// Which thumb of a slider moved last.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Thumb {
+    Start,
+    End,
+}
+
+/// The slider state behind an owned slider, kept across renders.
+struct OwnedSliderField {
+    state: Entity<SliderState>,
+    config: SliderConfig,
+    is_range: bool,
+    /// The `(start, end)` values last written to or reported by the state.
+    value: (f32, f32),
+    active: Thumb,
+    dragging: bool,
+    /// Tick values to snap to, when snapping is on.
+    snap: Option<Vec<f32>>,
+    on_change: Option<ChangeHandler>,
+    _subscription: Subscription,
+}
+
+impl OwnedSliderField {
+    fn new(
+        config: SliderConfig,
+        value: SliderValue,
+        window: &mut Window,
+        cx: &mut Context<Self>,
+    ) -> Self {
+        let state = cx.new(|_| {
+            let state = SliderState::new()
+                .min(config.min)
+                .max(config.max)
+                .default_value(value);
+            match config.step {
+                Some(step) => state.step(step),
+                None => state,
+            }
+        });
+        let subscription = cx.subscribe_in(&state, window, |this, state, event, window, cx| {
+            let SliderEvent::Change(value) = event;
+            let mut value = (value.start(), value.end());
+            if let Some(ticks) = &this.snap {
+                let snapped = (snap(value.0, ticks), snap(value.1, ticks));
+                if snapped != value {
+                    value = snapped;
+                    let snapped = if this.is_range {
+                        SliderValue::Range(value.0, value.1)
+                    } else {
+                        SliderValue::Single(value.0)
+                    };
+                    state.update(cx, |state, cx| state.set_value(snapped, window, cx));
+                }
+            }
+            if value == this.value {
+                return;
+            }
+            this.active = if value.0 != this.value.0 {
+                Thumb::Start
+            } else {
+                Thumb::End
+            };
+            this.value = value;
+            cx.notify();
+            if let Some(on_change) = this.on_change.clone() {
+                on_change(value, window, cx);
+            }
+        });
+
+        Self {
+            state,
+            config,
+            is_range: value.is_range(),
+            value: (value.start(), value.end()),
+            active: Thumb::End,
+            dragging: false,
+            snap: None,
+            on_change: None,
+            _subscription: subscription,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fraction_is_clamped_to_bounds() {
+        let config = SliderConfig {
+            min: 100.0,
+            max: 300.0,
+            step: None,
+        };
+
+        assert_eq!(config.fraction(200.0), 0.5);
+        assert_eq!(config.fraction(50.0), 0.0);
+        assert_eq!(config.fraction(400.0), 1.0);
+    }
+
+    #[test]
+    fn evenly_spaced_ticks_include_both_ends() {
+        assert_eq!(
+            Ticks::Count(5).values(-12.0, 12.0),
+            vec![-12.0, -6.0, 0.0, 6.0, 12.0]
+        );
+        assert_eq!(Ticks::Count(1).values(0.0, 1.0), vec![0.0]);
+        assert!(Ticks::Count(0).values(0.0, 1.0).is_empty());
+    }
+
+    #[test]
+    fn sliders_report_their_value_to_the_steps_precision() {
+        gpui::TestAppContext::single().update(|cx| {
+            let single = Slider::value(0.25).step(0.05).accessibility(cx);
+            assert_eq!(single.role, Some(AccessibilityRole::Slider));
+            assert_eq!(single.value, Some("0.25".into()));
+
+            let range = Slider::range_values(20.0, 80.0)
+                .bounds(0.0..=100.0)
+                .step(1.0)
+                .accessibility(cx);
+            assert_eq!(range.value, Some("20 to 80".into()));
+        });
+    }
+
+    #[test]
+    fn snaps_to_nearest_tick() {
+        let ticks = [0.0, 25.0, 50.0, 75.0, 100.0];
+
+        assert_eq!(snap(30.0, &ticks), 25.0);
+        assert_eq!(snap(40.0, &ticks), 50.0);
+        assert_eq!(snap(120.0, &ticks), 100.0);
+        assert_eq!(snap(42.0, &[]), 42.0);
     }
 }
@@ -1,10 +1,28 @@
 //! Stepper - Increment/decrement control.
 //!
-//! A SwiftUI-style stepper that wraps gpui-component's NumberInput.
+//! A SwiftUI-style stepper. It either owns a numeric value with a range and
+//! step, or wraps gpui-component's NumberInput.
 //!
 //! # Usage
 //!
-//! Stepper requires state management via `Entity<InputState>`. Create the state
+//! Pass the current value and update it in `on_change`:
+//!
+//! ```rust,ignore
+//! Stepper::value(self.quantity)
+//!     .range(1.0..=10.0)
+//!     .on_change({
+//!         let view = cx.entity().downgrade();
+//!         move |value, _, cx| {
+//!             view.update(cx, |this, cx| {
+//!                 this.quantity = value;
+//!                 cx.notify();
+//!             })
+//!             .ok();
+//!         }
+//!     })
+//! ```
+//!
+//! The older `Entity<InputState>` form is still supported. Create the state
 //! in your view's constructor and pass it to Stepper.
 //!
 //! ```rust,ignore
@@ -28,9 +46,16 @@
 //! Stepper::new(&self.quantity_input)
 //! ```
 
-use gpui::{App, Entity, IntoElement, RenderOnce, Window};
-use gpui_component::input::NumberInput;
-use gpui_component::Disableable;
+use std::ops::RangeInclusive;
+use std::rc::Rc;
+
+use gpui::{
+    div, px, App, AppContext, Context, ElementId, Entity, InteractiveElement, IntoElement,
+    ParentElement, RenderOnce, SharedString, StatefulInteractiveElement, Styled, Subscription,
+    Window,
+};
+use gpui_component::input::{Input, InputEvent, NumberInput};
+use gpui_component::{Disableable, Icon, IconName};
 
 // Re-use InputState from text_field
 use super::text_field::InputState;
@@ -39,15 +64,46 @@ use super::text_field::InputState;
 pub use gpui_component::input::NumberInputEvent as StepperEvent;
 pub use gpui_component::input::StepAction;
 
+use crate::binding::Binding;
 use crate::modifier::Modifier;
+use crate::style::Color;
+
+/// Handler called with a stepper's new value.
+type ChangeHandler = Rc<dyn Fn(f64, &mut Window, &mut App) + 'static>;
+
+/// Where a stepper's value lives.
+enum StepperSource {
+    /// A gpui-component NumberInput that emits [`StepperEvent`]s.
+    Input(Entity<InputState>),
+    /// A value owned by the caller and reported through `on_change`.
+    Value(f64),
+}
 
 /// A control for incrementing and decrementing a value.
 ///
-/// This component wraps gpui-component's NumberInput.
-///
 /// # Example
 ///
 /// ```rust,ignore
+/// // Value-owning stepper
+/// Stepper::value(self.volume)
+///     .range(0.0..=1.0)
+///     .step(0.1)
+///     .editable(true)
+///     .on_change({
+///         let view = cx.entity().downgrade();
+///         move |value, _, cx| {
+///             view.update(cx, |this, cx| {
+///                 this.volume = value;
+///                 cx.notify();
+///             })
+///             .ok();
+///         }
+///     })
+/// ```
+///
+/// The NumberInput-backed form wraps gpui-component's NumberInput:
+///
+/// ```rust,ignore
 /// // Create state in your view
 /// let quantity = cx.new(|cx|
 ///     InputState::new(window, cx)
@@ -77,19 +133,107 @@ use crate::modifier::Modifier;
 /// ```
 #[derive(IntoElement)]
 pub struct Stepper {
-    state: Entity<InputState>,
+    id: SharedString,
+    source: StepperSource,
+    range: RangeInclusive<f64>,
+    step: f64,
+    on_change: Option<ChangeHandler>,
+    editable: bool,
     disabled: bool,
+    /// Read for the value while rendering, and written when stepped.
+    binding: Option<Binding<i32>>,
 }
 
 impl Stepper {
     /// Create a new stepper with the given state.
     pub fn new(state: &Entity<InputState>) -> Self {
         Self {
-            state: state.clone(),
+            id: SharedString::default(),
+            source: StepperSource::Input(state.clone()),
+            range: f64::NEG_INFINITY..=f64::INFINITY,
+            step: 1.0,
+            on_change: None,
+            editable: false,
+            disabled: false,
+            binding: None,
+        }
+    }
+
+    /// Create a stepper for a value you own.
+    ///
+    /// The stepper's ID is derived from the call site; use [`Stepper::id`]
+    /// when creating steppers in a loop.
+    #[track_caller]
+    pub fn value(value: f64) -> Self {
+        let location = std::panic::Location::caller();
+        let id = format!(
+            "{}:{}:{}",
+            location.file(),
+            location.line(),
+            location.column()
+        );
+        Self {
+            id: id.into(),
+            source: StepperSource::Value(value),
+            range: f64::NEG_INFINITY..=f64::INFINITY,
+            step: 1.0,
+            on_change: None,
+            editable: false,
             disabled: false,
+            binding: None,
         }
     }
 
+    /// Create a stepper for a whole number read and written through
+    /// `binding`.
+    ///
+    /// The stepper's ID is derived from the call site; use [`Stepper::id`]
+    /// when creating steppers in a loop.
+    ///
+    /// ```rust,ignore
+    /// let view = cx.entity();
+    /// Stepper::bound(Binding::entity_field(&view, |this| &mut this.quantity)).range(0.0..=10.0)
+    /// ```
+    #[track_caller]
+    pub fn bound(binding: Binding<i32>) -> Self {
+        let mut stepper = Self::value(0.0);
+        stepper.binding = Some(binding);
+        stepper
+    }
+
+    /// Set the stepper's ID.
+    pub fn id(mut self, id: impl Into<SharedString>) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    /// Limit the value to a range. The buttons are disabled at its bounds.
+    pub fn range(mut self, range: RangeInclusive<f64>) -> Self {
+        self.range = range;
+        self
+    }
+
+    /// Set the amount each button press changes the value (default 1.0).
+    pub fn step(mut self, step: f64) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Set the handler called with the new, clamped value.
+    pub fn on_change(mut self, handler: impl Fn(f64, &mut Window, &mut App) + 'static) -> Self {
+        self.on_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Show the value in a text field that can be typed into.
+    ///
+    /// The text is parsed when Enter is pressed or the field loses focus;
+    /// text that isn't a number restores the current value.
+    pub fn editable(mut self, editable: bool) -> Self {
+        self.editable = editable;
+        self
+    }
+
     /// Disable the stepper.
     pub fn disabled(mut self, disabled: bool) -> Self {
         self.disabled = disabled;
@@ -100,13 +244,229 @@ impl Stepper {
 impl Modifier for Stepper {}
 
 impl RenderOnce for Stepper {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
-        let mut input = NumberInput::new(&self.state);
+    fn render(mut self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        if let Some(binding) = self.binding.take() {
+            self.source = StepperSource::Value(binding.get(cx) as f64);
+            let on_change = self.on_change.take();
+            self.on_change = Some(Rc::new(move |value, window, cx| {
+                binding.set(value.round() as i32, window, cx);
+                if let Some(on_change) = &on_change {
+                    on_change(value, window, cx);
+                }
+            }));
+        }
+
+        let value = match self.source {
+            StepperSource::Input(ref state) => {
+                let mut input = NumberInput::new(state);
+
+                if self.disabled {
+                    input = input.disabled(true);
+                }
+
+                return input.into_any_element();
+            }
+            StepperSource::Value(value) => clamp_to(value, &self.range),
+        };
+
+        let on_change = self.on_change.filter(|_| !self.disabled);
+        let decrement = stepped(value, -self.step, &self.range);
+        let increment = stepped(value, self.step, &self.range);
+
+        let middle = if self.editable {
+            let field = window.use_keyed_state(
+                ElementId::Name(format!("{}-field", self.id).into()),
+                cx,
+                EditableValue::new,
+            );
+            let text = format_value(value, self.step);
+            field.update(cx, |field, cx| {
+                field.range = self.range.clone();
+                field.step = self.step;
+                field.on_commit = on_change.clone();
+                if field.value != Some(value) {
+                    field.value = Some(value);
+                    field
+                        .input
+                        .update(cx, |input, cx| input.set_value(text, window, cx));
+                }
+            });
+            let input = field.read(cx).input.clone();
+
+            Input::new(&input)
+                .appearance(false)
+                .disabled(self.disabled)
+                .w(px(64.0))
+                .into_any_element()
+        } else {
+            div()
+                .min_w(px(40.0))
+                .px(px(4.0))
+                .text_center()
+                .child(format_value(value, self.step))
+                .into_any_element()
+        };
+
+        let border = Color::border().resolve_in(cx);
+        let minus = step_button(
+            ElementId::Name(format!("{}-decrement", self.id).into()),
+            IconName::Minus,
+            (decrement != value).then_some(decrement),
+            on_change.clone(),
+            cx,
+        );
+        let plus = step_button(
+            ElementId::Name(format!("{}-increment", self.id).into()),
+            IconName::Plus,
+            (increment != value).then_some(increment),
+            on_change,
+            cx,
+        );
+
+        let mut stepper = div()
+            .flex()
+            .items_center()
+            .border_1()
+            .border_color(border)
+            .rounded(px(6.0))
+            .overflow_hidden()
+            .child(minus)
+            .child(middle)
+            .child(plus);
 
         if self.disabled {
-            input = input.disabled(true);
+            stepper = stepper.opacity(0.5);
+        }
+
+        stepper.into_any_element()
+    }
+}
+
+/// A +/- button that reports `target`, or a dimmed inert button when `None`.
+fn step_button(
+    id: ElementId,
+    icon: IconName,
+    target: Option<f64>,
+    on_change: Option<ChangeHandler>,
+    cx: &App,
+) -> impl IntoElement {
+    let button = div()
+        .id(id)
+        .flex()
+        .items_center()
+        .justify_center()
+        .px(px(8.0))
+        .py(px(6.0))
+        .child(Icon::new(icon).size_4());
+
+    match (target, on_change) {
+        (Some(target), Some(on_change)) => {
+            let hover = Color::muted().resolve_in(cx);
+            let pressed = Color::muted().darken(0.06).resolve_in(cx);
+            button
+                .cursor_pointer()
+                .hover(move |style| style.bg(hover))
+                .active(move |style| style.bg(pressed))
+                .on_click(move |_, window, cx| on_change(target, window, cx))
+        }
+        (None, _) => button.opacity(0.4),
+        (Some(_), None) => button,
+    }
+}
+
+/// Text field state for an editable stepper, kept across renders.
+struct EditableValue {
+    input: Entity<InputState>,
+    /// The value last written into the field.
+    value: Option<f64>,
+    range: RangeInclusive<f64>,
+    step: f64,
+    on_commit: Option<ChangeHandler>,
+    _subscription: Subscription,
+}
+
+impl EditableValue {
+    fn new(window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let input = cx.new(|cx| InputState::new(window, cx));
+        let subscription = cx.subscribe_in(&input, window, |this, input, event, window, cx| {
+            if matches!(event, InputEvent::PressEnter { .. } | InputEvent::Blur) {
+                this.commit(input, window, cx);
+            }
+        });
+
+        Self {
+            input,
+            value: None,
+            range: f64::NEG_INFINITY..=f64::INFINITY,
+            step: 1.0,
+            on_commit: None,
+            _subscription: subscription,
+        }
+    }
+
+    fn commit(&mut self, input: &Entity<InputState>, window: &mut Window, cx: &mut Context<Self>) {
+        let parsed = input.read(cx).value().trim().parse::<f64>().ok();
+        let value = match parsed.filter(|value| value.is_finite()) {
+            Some(parsed) => clamp_to(parsed, &self.range),
+            None => match self.value {
+                Some(value) => value,
+                None => return,
+            },
+        };
+
+        // Show the clamped value (or restore the old one) right away
+        let text = format_value(value, self.step);
+        input.update(cx, |input, cx| input.set_value(text, window, cx));
+
+        if Some(value) != self.value {
+            self.value = Some(value);
+            if let Some(on_commit) = self.on_commit.clone() {
+                on_commit(value, window, cx);
+            }
         }
+    }
+}
+
+/// Clamp a value to a range.
+fn clamp_to(value: f64, range: &RangeInclusive<f64>) -> f64 {
+    value.max(*range.start()).min(*range.end())
+}
+
+/// The value after one step of `delta`, clamped to the range.
+fn stepped(value: f64, delta: f64, range: &RangeInclusive<f64>) -> f64 {
+    clamp_to(value + delta, range)
+}
+
+/// Format a value with as many decimals as the step needs.
+pub(crate) fn format_value(value: f64, step: f64) -> String {
+    let decimals = (0..6)
+        .find(|&decimals| {
+            let scaled = step.abs() * 10f64.powi(decimals);
+            (scaled - scaled.round()).abs() < 1e-9
+        })
+        .unwrap_or(6) as usize;
+    format!("{value:.decimals$}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn steps_clamp_to_range() {
+        let range = 0.0..=10.0;
+
+        assert_eq!(stepped(5.0, 1.0, &range), 6.0);
+        assert_eq!(stepped(9.5, 1.0, &range), 10.0);
+        assert_eq!(stepped(0.0, -1.0, &range), 0.0);
+        assert_eq!(clamp_to(42.0, &range), 10.0);
+    }
 
-        input
+    #[test]
+    fn formats_with_step_precision() {
+        assert_eq!(format_value(5.0, 1.0), "5");
+        assert_eq!(format_value(0.5, 0.1), "0.5");
+        assert_eq!(format_value(1.0, 0.25), "1.00");
+        assert_eq!(format_value(-2.0, 1.0), "-2");
     }
 }
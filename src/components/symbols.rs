@@ -0,0 +1,483 @@
+//! Symbols - SF Symbol names mapped to gpui-component icons.
+//!
+//! gpui-component ships a small icon set, so several common symbols map to
+//! the closest icon with the same meaning rather than the same glyph: for
+//! example `paperplane` is an arrow, `lock` a crossed-out eye and `clock` a
+//! calendar. These are grouped under "Approximations" below. Apps that ship
+//! their own icons can replace any mapping with [`register_symbol_mapping`].
+
+use std::collections::HashMap;
+use std::sync::{OnceLock, RwLock};
+
+use gpui_component::IconName;
+
+/// Built-in SF Symbol mappings, keyed by normalized name.
+///
+/// Keys are lowercase and omit `.fill`. `.circle` and `.square` variants
+/// fall back to their base symbol, so they're only listed when a closer
+/// icon exists (e.g. `xmark.circle` -> `CircleX`).
+const SYMBOLS: &[(&str, IconName)] = &[
+    // Arrows
+    ("arrow.up", IconName::ArrowUp),
+    ("arrow.down", IconName::ArrowDown),
+    ("arrow.left", IconName::ArrowLeft),
+    ("arrow.right", IconName::ArrowRight),
+    ("arrowup", IconName::ArrowUp),
+    ("arrowdown", IconName::ArrowDown),
+    ("arrowleft", IconName::ArrowLeft),
+    ("arrowright", IconName::ArrowRight),
+    ("arrow.backward", IconName::ArrowLeft),
+    ("arrow.forward", IconName::ArrowRight),
+    ("arrowshape.up", IconName::ArrowUp),
+    ("arrowshape.down", IconName::ArrowDown),
+    ("arrowshape.left", IconName::ArrowLeft),
+    ("arrowshape.right", IconName::ArrowRight),
+    ("arrowshape.backward", IconName::ArrowLeft),
+    ("arrowshape.forward", IconName::ArrowRight),
+    ("arrowshape.turn.up.left", IconName::Undo2),
+    ("arrowshape.turn.up.right", IconName::Redo2),
+    ("arrow.uturn.backward", IconName::Undo2),
+    ("arrow.uturn.forward", IconName::Redo2),
+    ("arrow.uturn.left", IconName::Undo2),
+    ("arrow.uturn.right", IconName::Redo2),
+    ("arrow.counterclockwise", IconName::Undo),
+    ("arrow.clockwise", IconName::Redo),
+    ("arrow.left.arrow.right", IconName::Replace),
+    ("arrow.2.squarepath", IconName::Replace),
+    ("arrow.triangle.swap", IconName::Replace),
+    ("arrow.up.arrow.down", IconName::SortAscending),
+    ("arrow.down.arrow.up", IconName::SortDescending),
+    ("arrow.up.and.down", IconName::ChevronsUpDown),
+    ("arrow.up.right", IconName::ExternalLink),
+    ("arrow.up.right.square", IconName::ExternalLink),
+    ("arrow.up.forward.app", IconName::ExternalLink),
+    ("arrow.up.left.and.arrow.down.right", IconName::Maximize),
+    (
+        "arrow.up.backward.and.arrow.down.forward",
+        IconName::Maximize,
+    ),
+    ("arrow.down.right.and.arrow.up.left", IconName::Minimize),
+    (
+        "arrow.down.forward.and.arrow.up.backward",
+        IconName::Minimize,
+    ),
+    ("redo", IconName::Redo),
+    ("undo", IconName::Undo),
+    // Chevrons
+    ("chevron.up", IconName::ChevronUp),
+    ("chevron.down", IconName::ChevronDown),
+    ("chevron.left", IconName::ChevronLeft),
+    ("chevron.right", IconName::ChevronRight),
+    ("chevronup", IconName::ChevronUp),
+    ("chevrondown", IconName::ChevronDown),
+    ("chevronleft", IconName::ChevronLeft),
+    ("chevronright", IconName::ChevronRight),
+    ("chevron.backward", IconName::ChevronLeft),
+    ("chevron.forward", IconName::ChevronRight),
+    ("chevron.compact.up", IconName::ChevronUp),
+    ("chevron.compact.down", IconName::ChevronDown),
+    ("chevron.compact.left", IconName::ChevronLeft),
+    ("chevron.compact.right", IconName::ChevronRight),
+    ("chevron.up.chevron.down", IconName::ChevronsUpDown),
+    ("chevron.up.down", IconName::ChevronsUpDown),
+    // Marks
+    ("checkmark", IconName::Check),
+    ("check", IconName::Check),
+    ("checkmark.circle", IconName::CircleCheck),
+    ("checkmark.seal", IconName::CircleCheck),
+    ("xmark", IconName::Close),
+    ("close", IconName::Close),
+    ("multiply", IconName::Close),
+    ("xmark.circle", IconName::CircleX),
+    ("xmark.octagon", IconName::CircleX),
+    ("x.circle", IconName::CircleX),
+    ("plus", IconName::Plus),
+    ("plus.app", IconName::Plus),
+    ("plus.rectangle", IconName::Plus),
+    ("minus", IconName::Minus),
+    ("minus.rectangle", IconName::Minus),
+    ("asterisk", IconName::Asterisk),
+    ("staroflife", IconName::Asterisk),
+    ("ellipsis", IconName::Ellipsis),
+    ("ellipsis.rectangle", IconName::Ellipsis),
+    ("ellipsis.vertical", IconName::EllipsisVertical),
+    ("more", IconName::Ellipsis),
+    // Status
+    ("info", IconName::Info),
+    ("questionmark", IconName::Info),
+    ("questionmark.circle", IconName::Info),
+    ("exclamationmark", IconName::TriangleAlert),
+    ("exclamationmark.triangle", IconName::TriangleAlert),
+    ("exclamationmark.circle", IconName::TriangleAlert),
+    ("exclamationmark.octagon", IconName::TriangleAlert),
+    ("exclamationmark.shield", IconName::TriangleAlert),
+    ("warning", IconName::TriangleAlert),
+    ("bell", IconName::Bell),
+    ("bell.badge", IconName::Bell),
+    ("bell.and.waves.left.and.right", IconName::Bell),
+    ("hourglass", IconName::Loader),
+    ("progress.indicator", IconName::Loader),
+    ("rays", IconName::Loader),
+    // Favorites and feedback
+    ("star", IconName::Star),
+    ("star.slash", IconName::StarOff),
+    ("star.off", IconName::StarOff),
+    ("staroff", IconName::StarOff),
+    ("heart", IconName::Heart),
+    ("heart.slash", IconName::HeartOff),
+    ("heart.off", IconName::HeartOff),
+    ("heartoff", IconName::HeartOff),
+    ("hand.thumbsup", IconName::ThumbsUp),
+    ("hand.thumbsdown", IconName::ThumbsDown),
+    ("thumbsup", IconName::ThumbsUp),
+    ("thumbsdown", IconName::ThumbsDown),
+    // Files and documents
+    ("folder", IconName::Folder),
+    ("folder.open", IconName::FolderOpen),
+    ("folderopen", IconName::FolderOpen),
+    ("folder.closed", IconName::FolderClosed),
+    ("folder.badge.plus", IconName::Folder),
+    ("folder.badge.minus", IconName::Folder),
+    ("doc", IconName::File),
+    ("doc.text", IconName::File),
+    ("doc.richtext", IconName::File),
+    ("doc.plaintext", IconName::File),
+    ("note.text", IconName::File),
+    ("file", IconName::File),
+    ("doc.text.magnifyingglass", IconName::Search),
+    ("doc.on.doc", IconName::Copy),
+    ("doc.on.clipboard", IconName::Copy),
+    ("clipboard", IconName::Copy),
+    ("square.on.square", IconName::Copy),
+    ("plus.square.on.square", IconName::Copy),
+    ("copy", IconName::Copy),
+    ("trash", IconName::Delete),
+    ("trash.slash", IconName::Delete),
+    ("xmark.bin", IconName::Delete),
+    ("delete", IconName::Delete),
+    ("delete.left", IconName::Delete),
+    ("delete.backward", IconName::Delete),
+    ("tray", IconName::Inbox),
+    ("tray.full", IconName::Inbox),
+    ("tray.2", IconName::Inbox),
+    ("archivebox", IconName::Inbox),
+    ("inbox", IconName::Inbox),
+    ("book", IconName::BookOpen),
+    ("book.closed", IconName::BookOpen),
+    ("books.vertical", IconName::BookOpen),
+    ("text.book.closed", IconName::BookOpen),
+    // Settings and tools
+    ("gear", IconName::Settings),
+    ("gearshape", IconName::Settings),
+    ("settings", IconName::Settings),
+    ("gear.badge", IconName::Settings),
+    ("gearshape.2", IconName::Settings2),
+    ("wrench.and.screwdriver", IconName::Settings2),
+    ("slider.horizontal.3", IconName::Settings2),
+    ("slider.vertical.3", IconName::Settings2),
+    ("switch.2", IconName::Settings2),
+    ("magnifyingglass", IconName::Search),
+    ("search", IconName::Search),
+    ("text.magnifyingglass", IconName::Search),
+    ("plus.magnifyingglass", IconName::Search),
+    ("ladybug", IconName::Inspector),
+    ("ant", IconName::Inspector),
+    ("scope", IconName::Inspector),
+    ("terminal", IconName::SquareTerminal),
+    ("apple.terminal", IconName::SquareTerminal),
+    (
+        "chevron.left.forwardslash.chevron.right",
+        IconName::SquareTerminal,
+    ),
+    ("paintpalette", IconName::Palette),
+    ("paintbrush", IconName::Palette),
+    ("swatchpalette", IconName::Palette),
+    ("eyedropper", IconName::Palette),
+    ("textformat", IconName::CaseSensitive),
+    ("textformat.abc", IconName::CaseSensitive),
+    ("textformat.alt", IconName::CaseSensitive),
+    ("character", IconName::CaseSensitive),
+    ("textformat.size", IconName::ALargeSmall),
+    ("textformat.size.larger", IconName::ALargeSmall),
+    ("chart.pie", IconName::ChartPie),
+    // Layout and windows
+    ("line.horizontal.3", IconName::Menu),
+    ("line.3.horizontal", IconName::Menu),
+    ("list.bullet", IconName::Menu),
+    ("list.dash", IconName::Menu),
+    ("text.justify", IconName::Menu),
+    ("menu", IconName::Menu),
+    ("sidebar.left", IconName::PanelLeft),
+    ("sidebar.leading", IconName::PanelLeft),
+    ("sidebar.squares.left", IconName::PanelLeft),
+    ("sidebar.right", IconName::PanelRight),
+    ("sidebar.trailing", IconName::PanelRight),
+    ("sidebar.squares.right", IconName::PanelRight),
+    ("rectangle.bottomthird.inset.filled", IconName::PanelBottom),
+    ("rectangle.bottomhalf.inset.filled", IconName::PanelBottom),
+    ("dock.rectangle", IconName::PanelBottom),
+    ("square.grid.2x2", IconName::LayoutDashboard),
+    ("square.grid.3x3", IconName::LayoutDashboard),
+    ("rectangle.grid.2x2", IconName::LayoutDashboard),
+    ("rectangle.3.group", IconName::LayoutDashboard),
+    ("square.stack", IconName::GalleryVerticalEnd),
+    ("rectangle.stack", IconName::GalleryVerticalEnd),
+    ("square.stack.3d.up", IconName::GalleryVerticalEnd),
+    ("photo.stack", IconName::GalleryVerticalEnd),
+    ("macwindow", IconName::Frame),
+    ("crop", IconName::Frame),
+    ("square.dashed", IconName::Frame),
+    ("rectangle.dashed", IconName::Frame),
+    // People
+    ("person", IconName::User),
+    ("person.circle", IconName::User),
+    ("user", IconName::User),
+    ("person.crop.circle", IconName::CircleUser),
+    ("person.crop.square", IconName::User),
+    ("person.2", IconName::User),
+    ("person.3", IconName::User),
+    ("person.badge.plus", IconName::User),
+    // Visibility and appearance
+    ("eye", IconName::Eye),
+    ("eye.slash", IconName::EyeOff),
+    ("eyeoff", IconName::EyeOff),
+    ("sun", IconName::Sun),
+    ("sun.max", IconName::Sun),
+    ("sun.min", IconName::Sun),
+    ("sun.horizon", IconName::Sun),
+    ("sunrise", IconName::Sun),
+    ("sunset", IconName::Sun),
+    ("moon", IconName::Moon),
+    ("moon.stars", IconName::Moon),
+    ("moon.zzz", IconName::Moon),
+    // Places and web
+    ("globe", IconName::Globe),
+    ("globe.americas", IconName::Globe),
+    ("globe.europe.africa", IconName::Globe),
+    ("globe.asia.australia", IconName::Globe),
+    ("network", IconName::Globe),
+    ("safari", IconName::Globe),
+    ("link", IconName::ExternalLink),
+    ("map", IconName::Map),
+    ("mappin", IconName::Map),
+    ("mappin.and.ellipse", IconName::Map),
+    ("building", IconName::Building2),
+    ("building.2", IconName::Building2),
+    ("building.columns", IconName::Building2),
+    ("calendar", IconName::Calendar),
+    ("calendar.badge.plus", IconName::Calendar),
+    ("calendar.day.timeline.left", IconName::Calendar),
+    // Approximations: no matching glyph, so the closest meaning
+    ("paperplane", IconName::ArrowRight),
+    ("square.and.pencil", IconName::File),
+    ("pencil", IconName::File),
+    ("square.and.arrow.up", IconName::ExternalLink),
+    ("square.and.arrow.down", IconName::ArrowDown),
+    ("arrow.triangle.2.circlepath", IconName::Replace),
+    ("arrow.2.circlepath", IconName::Replace),
+    ("arrow.clockwise.circle", IconName::Redo),
+    ("bookmark", IconName::BookOpen),
+    ("clock", IconName::Calendar),
+    ("alarm", IconName::Bell),
+    ("timer", IconName::Loader),
+    ("stopwatch", IconName::Loader),
+    ("camera", IconName::Frame),
+    ("camera.viewfinder", IconName::Frame),
+    ("viewfinder", IconName::Frame),
+    ("photo", IconName::Frame),
+    ("photo.on.rectangle", IconName::GalleryVerticalEnd),
+    ("mic", IconName::Bot),
+    ("waveform", IconName::Bot),
+    ("lock", IconName::EyeOff),
+    ("lock.shield", IconName::EyeOff),
+    ("lock.open", IconName::Eye),
+    ("key", IconName::EyeOff),
+    ("wifi", IconName::Globe),
+    ("antenna.radiowaves.left.and.right", IconName::Globe),
+    ("bolt", IconName::TriangleAlert),
+    ("flag", IconName::TriangleAlert),
+    ("tag", IconName::CaseSensitive),
+    ("pin", IconName::Map),
+    ("paperclip", IconName::File),
+    ("play", IconName::ChevronRight),
+    ("pause", IconName::EllipsisVertical),
+    ("stop", IconName::Frame),
+    ("backward", IconName::ChevronLeft),
+    ("forward", IconName::ChevronRight),
+    ("speaker", IconName::Bell),
+    ("speaker.wave.2", IconName::Bell),
+    ("speaker.wave.3", IconName::Bell),
+    ("speaker.slash", IconName::Bell),
+    ("cart", IconName::Inbox),
+    ("bag", IconName::Inbox),
+    ("envelope", IconName::Inbox),
+    ("envelope.open", IconName::Inbox),
+    ("house", IconName::LayoutDashboard),
+    ("square.and.arrow.up.on.square", IconName::ExternalLink),
+];
+
+/// Symbol names registered by the app, checked before the built-in table.
+fn registry() -> &'static RwLock<HashMap<String, IconName>> {
+    static REGISTRY: OnceLock<RwLock<HashMap<String, IconName>>> = OnceLock::new();
+    REGISTRY.get_or_init(Default::default)
+}
+
+/// Map an SF Symbol name to a gpui-component icon, e.g. at app startup:
+///
+/// ```rust,ignore
+/// register_symbol_mapping("paperplane", IconName::ArrowRight);
+///
+/// Label::new("paperplane.fill", "Send")
+/// ```
+///
+/// Registered names take precedence over the built-in mappings and follow
+/// the same normalization, so `"paperplane"` also covers `"paperplane.fill"`.
+pub fn register_symbol_mapping(name: impl AsRef<str>, icon: IconName) {
+    registry()
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .insert(normalize(name.as_ref()), icon);
+}
+
+/// Resolve an SF Symbol name to a gpui-component icon.
+///
+/// Names are matched case-insensitively, ignoring `.fill`. A trailing
+/// `.circle` or `.square` falls back to the base symbol when the variant
+/// isn't mapped.
+pub(crate) fn icon_for_symbol(name: &str) -> Option<IconName> {
+    let mut name = normalize(name);
+    loop {
+        if let Some(icon) = lookup(&name) {
+            return Some(icon);
+        }
+        let base = name
+            .strip_suffix(".circle")
+            .or_else(|| name.strip_suffix(".square"))?;
+        name = base.to_string();
+    }
+}
+
+fn lookup(name: &str) -> Option<IconName> {
+    let registered = registry()
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .get(name)
+        .cloned();
+    registered.or_else(|| {
+        SYMBOLS
+            .iter()
+            .find(|(symbol, _)| *symbol == name)
+            .map(|(_, icon)| icon.clone())
+    })
+}
+
+/// Lowercase the name and drop `.fill` components.
+fn normalize(name: &str) -> String {
+    name.to_lowercase()
+        .split('.')
+        .filter(|part| *part != "fill")
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui_component::IconNamed;
+
+    use super::*;
+
+    fn path(name: &str) -> Option<gpui::SharedString> {
+        icon_for_symbol(name).map(IconName::path)
+    }
+
+    #[test]
+    fn maps_common_symbols() {
+        let cases = [
+            ("star.fill", IconName::Star),
+            ("Folder", IconName::Folder),
+            ("gearshape.fill", IconName::Settings),
+            ("magnifyingglass", IconName::Search),
+            ("xmark.circle.fill", IconName::CircleX),
+            ("checkmark.circle", IconName::CircleCheck),
+            ("plus.circle.fill", IconName::Plus),
+            ("trash.square", IconName::Delete),
+            ("arrow.up.right.square", IconName::ExternalLink),
+            ("chevron.forward", IconName::ChevronRight),
+            ("person.crop.circle.fill", IconName::CircleUser),
+            ("rectangle.bottomthird.inset.filled", IconName::PanelBottom),
+            ("exclamationmark.triangle.fill", IconName::TriangleAlert),
+            ("calendar.circle", IconName::Calendar),
+        ];
+        for (name, icon) in cases {
+            assert_eq!(path(name), Some(icon.path()), "{name}");
+        }
+    }
+
+    #[test]
+    fn unknown_symbols_are_unmapped() {
+        assert_eq!(path("definitely.not.a.symbol"), None);
+        assert_eq!(path("circle"), None);
+    }
+
+    #[test]
+    fn maps_symbols_without_a_matching_glyph() {
+        let cases = [
+            ("paperplane.fill", IconName::ArrowRight),
+            ("square.and.pencil", IconName::File),
+            ("bookmark.fill", IconName::BookOpen),
+            ("clock", IconName::Calendar),
+            ("camera.fill", IconName::Frame),
+            ("mic.circle", IconName::Bot),
+            ("lock.fill", IconName::EyeOff),
+            ("wifi", IconName::Globe),
+            ("bolt.fill", IconName::TriangleAlert),
+            ("flag", IconName::TriangleAlert),
+            ("tag", IconName::CaseSensitive),
+            ("pin.fill", IconName::Map),
+            ("paperclip", IconName::File),
+            ("photo", IconName::Frame),
+            ("play.fill", IconName::ChevronRight),
+            ("pause.circle", IconName::EllipsisVertical),
+            ("stop.fill", IconName::Frame),
+            ("speaker.wave.2.fill", IconName::Bell),
+            ("cart", IconName::Inbox),
+            ("square.and.arrow.up", IconName::ExternalLink),
+            ("arrow.triangle.2.circlepath", IconName::Replace),
+        ];
+        for (name, icon) in cases {
+            assert_eq!(path(name), Some(icon.path()), "{name}");
+        }
+    }
+
+    #[test]
+    fn table_keys_are_normalized_and_unique() {
+        let mut seen = std::collections::HashSet::new();
+        for (symbol, _) in SYMBOLS {
+            assert_eq!(normalize(symbol), *symbol, "{symbol} is not normalized");
+            assert!(
+                symbol
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '.'),
+                "{symbol} has unexpected characters"
+            );
+            assert!(seen.insert(*symbol), "{symbol} is listed twice");
+        }
+    }
+
+    #[test]
+    fn registered_mappings_take_precedence() {
+        // A name no other test uses, removed afterwards, since the registry
+        // is shared by the whole test binary
+        let name = "registered.test.symbol";
+        assert_eq!(path(name), None);
+        register_symbol_mapping(name, IconName::Bot);
+        let registered = path("registered.test.symbol.fill");
+        registry()
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(name);
+
+        assert_eq!(registered, Some(IconName::Bot.path()));
+        assert_eq!(path(name), None);
+    }
+}
@@ -0,0 +1,348 @@
+//! TagField - Token input component.
+//!
+//! A text field that turns what is typed into removable tags, like an email
+//! "To:" field or a tag editor.
+//!
+//! # Usage
+//!
+//! ```rust,ignore
+//! struct PostEditor {
+//!     tag_input: Entity<InputState>,
+//!     tags: Vec<SharedString>,
+//! }
+//!
+//! // In render:
+//! TagField::new("tags", &self.tag_input)
+//!     .tags(self.tags.clone())
+//!     .on_tags_change(cx.listener(|this, tags: &Vec<SharedString>, _, cx| {
+//!         this.tags = tags.clone();
+//!         cx.notify();
+//!     }))
+//! ```
+
+use std::rc::Rc;
+
+use gpui::{
+    div, px, App, Context, ElementId, Entity, Hsla, InteractiveElement, IntoElement, ParentElement,
+    RenderOnce, SharedString, StatefulInteractiveElement, Styled, Subscription, Window,
+};
+use gpui_component::input::{Backspace, Input, InputEvent};
+use gpui_component::{ActiveTheme, Icon, IconName};
+
+use crate::components::InputState;
+use crate::environment;
+use crate::modifier::Modifier;
+use crate::style::{Color, ColorScheme};
+
+/// Handler called with the tags after one is added or removed.
+pub type TagsHandler = Rc<dyn Fn(&Vec<SharedString>, &mut Window, &mut App) + 'static>;
+
+/// A text field that collects tags.
+///
+/// Tags are shown as removable pills before the text, wrapping onto more
+/// lines as needed. Enter or a comma turns the text into a tag, and
+/// Backspace in an empty field removes the last tag. The tags are owned by
+/// the view: pass them with [`tags`](Self::tags) and update them from
+/// [`on_tags_change`](Self::on_tags_change).
+///
+/// # Example
+///
+/// ```rust,ignore
+/// TagField::new("recipients", &to_input)
+///     .tags(self.recipients.clone())
+///     .max_tags(5)
+///     .on_tags_change(cx.listener(|this, tags: &Vec<SharedString>, _, cx| {
+///         this.recipients = tags.clone();
+///         cx.notify();
+///     }))
+/// ```
+#[derive(IntoElement)]
+pub struct TagField {
+    id: ElementId,
+    state: Entity<InputState>,
+    tags: Vec<SharedString>,
+    max_tags: Option<usize>,
+    on_tags_change: Option<TagsHandler>,
+    disabled: bool,
+}
+
+impl TagField {
+    /// Create a tag field typing into `state`.
+    pub fn new(id: impl Into<ElementId>, state: &Entity<InputState>) -> Self {
+        Self {
+            id: id.into(),
+            state: state.clone(),
+            tags: Vec::new(),
+            max_tags: None,
+            on_tags_change: None,
+            disabled: false,
+        }
+    }
+
+    /// Set the tags shown.
+    pub fn tags(mut self, tags: Vec<SharedString>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    /// Limit the number of tags.
+    ///
+    /// At the limit, typed text stays in the field instead of becoming a tag.
+    pub fn max_tags(mut self, max_tags: usize) -> Self {
+        self.max_tags = Some(max_tags);
+        self
+    }
+
+    /// Call a handler with the new tags when one is added or removed.
+    pub fn on_tags_change(
+        mut self,
+        handler: impl Fn(&Vec<SharedString>, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_tags_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Disable the tag field.
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+impl Modifier for TagField {}
+
+impl RenderOnce for TagField {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let state_id = ElementId::NamedChild(Box::new(self.id.clone()), "tag-field".into());
+        let input = self.state.clone();
+        let tag_state = window.use_keyed_state(state_id, cx, |window, cx| {
+            TagFieldState::new(&input, window, cx)
+        });
+        tag_state.update(cx, |state, _| {
+            state.tags = self.tags.clone();
+            state.max_tags = self.max_tags;
+            state.on_tags_change = self.on_tags_change.clone();
+        });
+
+        let is_dark = environment::color_scheme(cx) == ColorScheme::Dark;
+        let pill_color = cx.theme().secondary;
+        let pill_text_color = cx.theme().secondary_foreground;
+        let icon_color = Hsla {
+            a: 0.6,
+            ..pill_text_color
+        };
+
+        let pills = self.tags.iter().enumerate().map(|(ix, tag)| {
+            let mut pill = div()
+                .flex()
+                .items_center()
+                .gap(px(4.0))
+                .pl(px(8.0))
+                .pr(px(4.0))
+                .py(px(2.0))
+                .rounded_full()
+                .bg(pill_color)
+                .text_sm()
+                .text_color(pill_text_color)
+                .child(tag.clone());
+            if !self.disabled {
+                let tag_state = tag_state.clone();
+                pill = pill.child(
+                    div()
+                        .id(ix)
+                        .rounded_full()
+                        .cursor_pointer()
+                        .child(Icon::new(IconName::Close).size_3().text_color(icon_color))
+                        .on_click(move |_, window, cx| {
+                            tag_state.update(cx, |state, cx| state.remove(ix, window, cx));
+                        }),
+                );
+            }
+            pill
+        });
+
+        let mut input = Input::new(&self.state).appearance(false);
+        if self.disabled {
+            input = input.disabled(true);
+        }
+
+        let mut field = div()
+            .id(self.id)
+            .flex()
+            .flex_wrap()
+            .items_center()
+            .gap(px(4.0))
+            .min_h(px(32.0))
+            .px(px(4.0))
+            .py(px(2.0))
+            .rounded(px(6.0))
+            .border_1()
+            .border_color(Color::text_field_border().resolve(is_dark))
+            .bg(Color::text_field_background().resolve(is_dark))
+            .children(pills)
+            .child(div().flex_1().min_w(px(80.0)).child(input));
+
+        if !self.disabled {
+            let tag_state = tag_state.clone();
+            let input = self.state.clone();
+            field = field.capture_action(move |_: &Backspace, window, cx| {
+                if !input.read(cx).value().is_empty() {
+                    return;
+                }
+                let removed = tag_state.update(cx, |state, cx| match state.tags.len() {
+                    0 => false,
+                    len => {
+                        state.remove(len - 1, window, cx);
+                        true
+                    }
+                });
+                if removed {
+                    cx.stop_propagation();
+                }
+            });
+        }
+
+        field
+    }
+}
+
+/// The retained state of a tag field.
+struct TagFieldState {
+    /// The tags from the latest render.
+    tags: Vec<SharedString>,
+    max_tags: Option<usize>,
+    on_tags_change: Option<TagsHandler>,
+    /// Set while committed text is cleared from the field, so the change it
+    /// causes isn't scanned for commas again.
+    clearing: bool,
+    _subscription: Subscription,
+}
+
+impl TagFieldState {
+    fn new(input: &Entity<InputState>, window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let subscription = cx.subscribe_in(input, window, |this, input, event, window, cx| {
+            let text = input.read(cx).value();
+            let (committed, rest) = match event {
+                InputEvent::Change if std::mem::take(&mut this.clearing) => return,
+                InputEvent::Change => split_tags(&text, false),
+                InputEvent::PressEnter { .. } => split_tags(&text, true),
+                InputEvent::Focus | InputEvent::Blur => return,
+            };
+            if committed.is_empty() {
+                return;
+            }
+
+            let tags = add_tags(&this.tags, committed, this.max_tags);
+            if tags.len() == this.tags.len() {
+                return;
+            }
+            this.clearing = true;
+            input.update(cx, |input, cx| input.set_value(rest, window, cx));
+            this.set_tags(tags, window, cx);
+        });
+
+        Self {
+            tags: Vec::new(),
+            max_tags: None,
+            on_tags_change: None,
+            clearing: false,
+            _subscription: subscription,
+        }
+    }
+
+    fn remove(&mut self, ix: usize, window: &mut Window, cx: &mut App) {
+        if ix < self.tags.len() {
+            let mut tags = self.tags.clone();
+            tags.remove(ix);
+            self.set_tags(tags, window, cx);
+        }
+    }
+
+    fn set_tags(&mut self, tags: Vec<SharedString>, window: &mut Window, cx: &mut App) {
+        self.tags = tags.clone();
+        if let Some(on_tags_change) = self.on_tags_change.clone() {
+            on_tags_change(&tags, window, cx);
+        }
+    }
+}
+
+/// Split typed text into tags to commit and the text left in the field.
+///
+/// Text before the last comma is committed. With `all`, as on Enter, the
+/// text after it is committed too.
+fn split_tags(text: &str, all: bool) -> (Vec<SharedString>, SharedString) {
+    let (committed, rest) = match text.rfind(',') {
+        _ if all => (text, ""),
+        Some(ix) => (&text[..ix], &text[ix + 1..]),
+        None => ("", text),
+    };
+    let tags = committed
+        .split(',')
+        .map(str::trim)
+        .filter(|tag| !tag.is_empty())
+        .map(|tag| SharedString::from(tag.to_string()))
+        .collect();
+    (tags, SharedString::from(rest.trim_start().to_string()))
+}
+
+/// `tags` with `new` appended, skipping duplicates and stopping at
+/// `max_tags`.
+fn add_tags(
+    tags: &[SharedString],
+    new: Vec<SharedString>,
+    max_tags: Option<usize>,
+) -> Vec<SharedString> {
+    let mut tags = tags.to_vec();
+    for tag in new {
+        if max_tags.is_some_and(|max| tags.len() >= max) {
+            break;
+        }
+        if !tags.contains(&tag) {
+            tags.push(tag);
+        }
+    }
+    tags
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(tags: &[&'static str]) -> Vec<SharedString> {
+        tags.iter().map(|tag| SharedString::from(*tag)).collect()
+    }
+
+    #[test]
+    fn commas_commit_the_text_before_them() {
+        assert_eq!(split_tags("rust", false), (vec![], "rust".into()));
+        assert_eq!(split_tags("rust,", false), (strings(&["rust"]), "".into()));
+        assert_eq!(
+            split_tags("rust, gpui, ui", false),
+            (strings(&["rust", "gpui"]), "ui".into())
+        );
+        assert_eq!(split_tags(" , ", false), (vec![], "".into()));
+    }
+
+    #[test]
+    fn enter_commits_everything() {
+        assert_eq!(split_tags(" rust ", true), (strings(&["rust"]), "".into()));
+        assert_eq!(
+            split_tags("rust, gpui", true),
+            (strings(&["rust", "gpui"]), "".into())
+        );
+    }
+
+    #[test]
+    fn adding_skips_duplicates_and_stops_at_the_limit() {
+        let tags = strings(&["rust"]);
+        assert_eq!(
+            add_tags(&tags, strings(&["gpui", "rust"]), None),
+            strings(&["rust", "gpui"])
+        );
+        assert_eq!(
+            add_tags(&tags, strings(&["gpui", "ui"]), Some(2)),
+            strings(&["rust", "gpui"])
+        );
+        assert_eq!(add_tags(&tags, strings(&["gpui"]), Some(1)), tags);
+    }
+}
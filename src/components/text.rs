@@ -1,8 +1,18 @@
 //! Text - Display text content.
 
-use gpui::{div, px, App, IntoElement, ParentElement, RenderOnce, SharedString, Styled, Window};
-use gpui_component::ActiveTheme;
+use std::ops::{Add, Range};
+use std::rc::Rc;
+use std::sync::Arc;
 
+use gpui::{
+    div, fill, point, px, relative, size, App, AvailableSpace, Bounds, Div, Element, ElementId,
+    FontFeatures, FontStyle, GlobalElementId, InspectorElementId, InteractiveText, IntoElement,
+    LayoutId, ParentElement, Pixels, Refineable, RenderOnce, ShapedLine, SharedString,
+    StrikethroughStyle, Style, Styled, StyledText, TextStyle, UnderlineStyle, Window,
+};
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::environment;
 use crate::modifier::Modifier;
 use crate::style::{Color, Font, FontWeight};
 
@@ -15,9 +25,101 @@ pub enum TruncationMode {
     #[default]
     Tail,
     /// Truncate in the middle of the line, replacing removed text with an ellipsis.
+    ///
+    /// The start and end are kept in balance. When the kept start would end
+    /// in a `/`, `\\` or whitespace, that character is dropped and one more
+    /// is kept from the end instead, so paths read `very…file.rs` rather
+    /// than `very/…ile.rs`.
     Middle,
 }
 
+/// How lines of multiline text are aligned.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TextAlignment {
+    /// Align lines to the leading edge.
+    #[default]
+    Leading,
+    /// Center lines horizontally.
+    Center,
+    /// Align lines to the trailing edge.
+    Trailing,
+}
+
+/// A case transformation applied to text when rendered.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TextCase {
+    /// Render the content unchanged.
+    #[default]
+    None,
+    /// Render the content in uppercase.
+    Upper,
+    /// Render the content in lowercase.
+    Lower,
+}
+
+impl TextCase {
+    fn apply(self, content: SharedString) -> SharedString {
+        match self {
+            TextCase::None => content,
+            TextCase::Upper => content.to_uppercase().into(),
+            TextCase::Lower => content.to_lowercase().into(),
+        }
+    }
+}
+
+/// Called when a linked run of text is clicked.
+type LinkAction = Rc<dyn Fn(&mut Window, &mut App)>;
+
+/// A styled run of text inside a concatenated [`Text`].
+struct TextSegment {
+    content: SharedString,
+    font: Option<Font>,
+    color: Option<Color>,
+    strikethrough: bool,
+    underline: bool,
+    underline_color: Option<Color>,
+    highlight: Option<Color>,
+    text_case: TextCase,
+    link: Option<LinkAction>,
+}
+
+impl TextSegment {
+    /// Apply this segment's own styling on top of the paragraph style.
+    fn refine(&self, style: &mut TextStyle, cx: &App) {
+        if let Some(font) = &self.font {
+            style.font_weight = font.weight.to_gpui();
+            if font.italic {
+                style.font_style = FontStyle::Italic;
+            }
+            if let Some(family) = font.font_family() {
+                style.font_family = family;
+            }
+        }
+        if let Some(color) = &self.color {
+            style.color = color.resolve_in(cx);
+        }
+        if self.strikethrough {
+            style.strikethrough = Some(StrikethroughStyle {
+                thickness: px(1.),
+                ..Default::default()
+            });
+        }
+        if self.underline {
+            style.underline = Some(UnderlineStyle {
+                thickness: px(1.),
+                color: self
+                    .underline_color
+                    .as_ref()
+                    .map(|color| color.resolve_in(cx)),
+                ..Default::default()
+            });
+        }
+        if let Some(color) = &self.highlight {
+            style.background_color = Some(color.resolve_in(cx));
+        }
+    }
+}
+
 /// A view that displays one or more lines of read-only text.
 ///
 /// # Example
@@ -27,6 +129,13 @@ pub enum TruncationMode {
 ///     .font(Font::title())
 ///     .foreground_color(Color::primary())
 /// ```
+///
+/// Texts can be concatenated with `+` to style parts of a paragraph
+/// differently. The result wraps as a single paragraph:
+///
+/// ```rust,ignore
+/// Text::new("Hello ").bold() + Text::new("world").foreground_color(Color::red())
+/// ```
 #[derive(IntoElement)]
 pub struct Text {
     content: SharedString,
@@ -35,6 +144,18 @@ pub struct Text {
     line_limit: Option<usize>,
     truncation_mode: TruncationMode,
     strikethrough: bool,
+    underline: bool,
+    underline_color: Option<Color>,
+    highlight: Option<Color>,
+    monospaced_digit: bool,
+    fixed_size_font: bool,
+    multiline_alignment: Option<TextAlignment>,
+    line_spacing: Option<f32>,
+    kerning: Option<f32>,
+    text_case: TextCase,
+    link: Option<LinkAction>,
+    id: Option<ElementId>,
+    segments: Vec<TextSegment>,
 }
 
 impl Text {
@@ -47,7 +168,54 @@ impl Text {
             line_limit: None,
             truncation_mode: TruncationMode::default(),
             strikethrough: false,
+            underline: false,
+            underline_color: None,
+            highlight: None,
+            monospaced_digit: false,
+            fixed_size_font: false,
+            multiline_alignment: None,
+            line_spacing: None,
+            kerning: None,
+            text_case: TextCase::None,
+            link: None,
+            id: None,
+            segments: Vec::new(),
+        }
+    }
+
+    /// Split into styled segments, with this text's own styling applied to
+    /// segments that don't override it.
+    fn into_segments(self) -> Vec<TextSegment> {
+        if self.segments.is_empty() {
+            return vec![TextSegment {
+                content: self.content,
+                font: self.font,
+                color: self.color,
+                strikethrough: self.strikethrough,
+                underline: self.underline,
+                underline_color: self.underline_color,
+                highlight: self.highlight,
+                text_case: self.text_case,
+                link: self.link,
+            }];
         }
+
+        self.segments
+            .into_iter()
+            .map(|segment| TextSegment {
+                content: self.text_case.apply(segment.content),
+                font: segment.font.or_else(|| self.font.clone()),
+                color: segment.color.or_else(|| self.color.clone()),
+                strikethrough: segment.strikethrough || self.strikethrough,
+                underline: segment.underline || self.underline,
+                underline_color: segment
+                    .underline_color
+                    .or_else(|| self.underline_color.clone()),
+                highlight: segment.highlight.or_else(|| self.highlight.clone()),
+                text_case: segment.text_case,
+                link: segment.link.or_else(|| self.link.clone()),
+            })
+            .collect()
     }
 
     /// Set the font style.
@@ -69,6 +237,9 @@ impl Text {
     }
 
     /// Set how text is truncated when it doesn't fit.
+    ///
+    /// Head and middle truncation apply to single-line text (`.line_limit(1)`);
+    /// multi-line text is always truncated at the tail.
     pub fn truncation_mode(mut self, mode: TruncationMode) -> Self {
         self.truncation_mode = mode;
         self
@@ -80,6 +251,92 @@ impl Text {
         self
     }
 
+    /// Set how lines of multiline text are aligned.
+    pub fn multiline_text_alignment(mut self, alignment: TextAlignment) -> Self {
+        self.multiline_alignment = Some(alignment);
+        self
+    }
+
+    /// Add extra space between lines, in points.
+    pub fn line_spacing(mut self, spacing: f32) -> Self {
+        self.line_spacing = Some(spacing);
+        self
+    }
+
+    /// Add space between characters, in points. Negative values tighten.
+    ///
+    /// GPUI text runs have no letter spacing, so spaced text is shaped one
+    /// line at a time and the spacing is added after each grapheme cluster
+    /// when its glyphs are painted. Spaced text only breaks lines at `\n`:
+    /// it doesn't wrap, line-clamp or truncate, and concatenated text
+    /// ignores it.
+    pub fn kerning(mut self, kerning: f32) -> Self {
+        self.kerning = Some(kerning);
+        self
+    }
+
+    /// Set the tracking (letter spacing) in points. See [`Text::kerning`].
+    pub fn tracking(self, tracking: f32) -> Self {
+        self.kerning(tracking)
+    }
+
+    /// Transform the case of the content when rendered.
+    pub fn text_case(mut self, text_case: TextCase) -> Self {
+        self.text_case = text_case;
+        self
+    }
+
+    /// Apply underline styling.
+    pub fn underline(mut self, active: bool) -> Self {
+        self.underline = active;
+        self
+    }
+
+    /// Underline the text in the given color.
+    pub fn underline_color(mut self, color: impl Into<Color>) -> Self {
+        self.underline = true;
+        self.underline_color = Some(color.into());
+        self
+    }
+
+    /// Fill the background behind the glyphs, such as for inline code.
+    pub(crate) fn highlight(mut self, color: Color) -> Self {
+        self.highlight = Some(color);
+        self
+    }
+
+    /// Run `action` when this text is clicked, with a pointing-hand cursor
+    /// over it.
+    ///
+    /// Only applies once the text is concatenated with `+`, where each
+    /// linked segment is its own click target within the paragraph.
+    pub(crate) fn link(mut self, action: impl Fn(&mut Window, &mut App) + 'static) -> Self {
+        self.link = Some(Rc::new(action));
+        self
+    }
+
+    /// Identify the text, which concatenated text with links needs to track
+    /// clicks.
+    pub(crate) fn id(mut self, id: impl Into<ElementId>) -> Self {
+        self.id = Some(id.into());
+        self
+    }
+
+    /// Use fixed-width (tabular) digits so numbers don't shift as they change.
+    ///
+    /// Useful for counters and timers. Enables the OpenType `tnum` feature;
+    /// fonts without it render their default digits.
+    pub fn monospaced_digit(mut self) -> Self {
+        self.monospaced_digit = true;
+        self
+    }
+
+    /// Opt out of `.text_scale()` so the font size stays fixed.
+    pub fn fixed_size_font(mut self) -> Self {
+        self.fixed_size_font = true;
+        self
+    }
+
     // Convenience font methods
 
     /// Make the text bold.
@@ -106,6 +363,15 @@ impl Text {
         self
     }
 
+    /// Set a custom font family, e.g. `"Inter"`.
+    ///
+    /// Missing families fall back to the system font.
+    pub fn font_family(mut self, family: impl Into<SharedString>) -> Self {
+        let font = self.font.take().unwrap_or_default();
+        self.font = Some(font.family(family));
+        self
+    }
+
     /// Set font weight.
     pub fn font_weight(mut self, weight: FontWeight) -> Self {
         let font = self.font.take().unwrap_or_default();
@@ -114,38 +380,107 @@ impl Text {
     }
 }
 
+/// Concatenate two texts into a single paragraph, keeping each side's styling.
+///
+/// Paragraph settings (line limit, alignment, line spacing) come from the
+/// left-hand text. All segments share one font size: the first explicit
+/// size, since GPUI text runs can't change size mid-paragraph.
+impl Add for Text {
+    type Output = Text;
+
+    fn add(self, rhs: Text) -> Text {
+        let paragraph = Text {
+            line_limit: self.line_limit,
+            truncation_mode: self.truncation_mode,
+            monospaced_digit: self.monospaced_digit,
+            fixed_size_font: self.fixed_size_font,
+            multiline_alignment: self.multiline_alignment,
+            line_spacing: self.line_spacing,
+            kerning: self.kerning,
+            id: self.id.clone(),
+            ..Text::new("")
+        };
+        let mut segments = self.into_segments();
+        segments.extend(rhs.into_segments());
+        Text {
+            segments,
+            ..paragraph
+        }
+    }
+}
+
 impl Modifier for Text {}
 
 impl RenderOnce for Text {
-    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let is_dark = cx.theme().is_dark();
-        let mut element = div().child(self.content);
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let content = self.text_case.apply(self.content);
+        let mut element = div();
+
+        // Size, scaled by the environment's text scale unless fixed
+        let explicit_size = self.font.as_ref().and_then(|font| font.size).or_else(|| {
+            self.segments
+                .iter()
+                .find_map(|segment| segment.font.as_ref().and_then(|font| font.size))
+        });
+        let size = if self.fixed_size_font {
+            explicit_size.map(px)
+        } else {
+            environment::scaled_text_size(explicit_size, window, cx)
+        };
+        if let Some(size) = size {
+            element = element.text_size(size);
+        }
+
+        // Line spacing adds to the inherited line height at the resolved size
+        if let Some(spacing) = self.line_spacing {
+            let text_style = window.text_style();
+            let font_size =
+                size.unwrap_or_else(|| text_style.font_size.to_pixels(window.rem_size()));
+            let line_height = text_style
+                .line_height
+                .to_pixels(font_size.into(), window.rem_size());
+            element = element.line_height(line_height + px(spacing));
+        }
+
+        // Multiline alignment
+        if let Some(alignment) = self.multiline_alignment {
+            element = match alignment {
+                TextAlignment::Leading => element.text_left(),
+                TextAlignment::Center => element.text_center(),
+                TextAlignment::Trailing => element.text_right(),
+            };
+        }
 
         // Apply font properties
         if let Some(font) = &self.font {
-            // Size
-            if let Some(size) = font.size {
-                element = element.text_size(px(size));
-            }
             // Weight
             element = element.font_weight(font.weight.to_gpui());
             // Italic
             if font.italic {
                 element = element.italic();
             }
-            // Font family from design
-            if let Some(family) = font.design.font_family() {
+            // Font family: custom family, falling back to design
+            if let Some(family) = font.font_family() {
                 element = element.font_family(family);
             }
         }
 
         // Apply color
         if let Some(color) = self.color {
-            element = element.text_color(color.resolve(is_dark));
+            element = element.text_color(color.resolve_in(cx));
         }
 
+        // Head and middle truncation aren't supported by GPUI, so single-line
+        // text is shaped and ellipsized by TruncatedLine once its width is known.
+        // Concatenated text always truncates at the tail.
+        let truncate_manually = self.line_limit == Some(1)
+            && self.truncation_mode != TruncationMode::Tail
+            && self.segments.is_empty();
+
         // Apply line limit with truncation for proper ellipsis in flex layouts
-        if let Some(limit) = self.line_limit {
+        if truncate_manually {
+            element = element.flex_shrink().min_w_0().overflow_hidden();
+        } else if let Some(limit) = self.line_limit {
             element = element
                 .line_clamp(limit)
                 .flex_shrink()
@@ -159,6 +494,509 @@ impl RenderOnce for Text {
             element = element.line_through();
         }
 
-        element
+        // Apply underline
+        if self.underline {
+            element = element.underline();
+            if let Some(color) = self.underline_color {
+                element = element.text_decoration_color(color.resolve_in(cx));
+            }
+        }
+
+        if let Some(color) = &self.highlight {
+            element = element.text_bg(color.resolve_in(cx));
+        }
+
+        // Tabular digits
+        if self.monospaced_digit {
+            element
+                .text_style()
+                .get_or_insert_with(Default::default)
+                .font_features = Some(FontFeatures(Arc::new(vec![("tnum".into(), 1)])));
+        }
+
+        // Concatenated text renders as one paragraph of styled runs so that
+        // wrapping flows across segments
+        if !self.segments.is_empty() {
+            let mut paragraph_style = window.text_style();
+            if let Some(refinement) = element.text_style().as_ref() {
+                paragraph_style.refine(refinement);
+            }
+
+            let mut text = String::new();
+            let mut runs = Vec::with_capacity(self.segments.len());
+            let mut links: Vec<(Range<usize>, LinkAction)> = Vec::new();
+            for segment in &self.segments {
+                let segment_text = self
+                    .text_case
+                    .apply(segment.text_case.apply(segment.content.clone()));
+                let mut style = paragraph_style.clone();
+                segment.refine(&mut style, cx);
+                runs.push(style.to_run(segment_text.len()));
+                if let Some(link) = &segment.link {
+                    links.push((text.len()..text.len() + segment_text.len(), link.clone()));
+                }
+                text.push_str(&segment_text);
+            }
+
+            let styled = StyledText::new(text).with_runs(runs);
+            if links.is_empty() {
+                element.child(styled)
+            } else {
+                let id = self.id.unwrap_or_else(|| "text-links".into());
+                let (ranges, actions): (Vec<_>, Vec<_>) = links.into_iter().unzip();
+                element.child(
+                    InteractiveText::new(id, styled)
+                        .on_click(ranges, move |ix, window, cx| actions[ix](window, cx)),
+                )
+            }
+        } else if truncate_manually {
+            element.child(TruncatedLine {
+                text: content,
+                mode: self.truncation_mode,
+            })
+        } else if let Some(kerning) = self.kerning.filter(|kerning| *kerning != 0.0) {
+            element.child(spaced_text(
+                &content,
+                kerning,
+                self.multiline_alignment.unwrap_or_default(),
+            ))
+        } else {
+            element.child(content)
+        }
+    }
+}
+
+/// Text drawn line by line with `kerning` after each grapheme cluster.
+fn spaced_text(content: &str, kerning: f32, alignment: TextAlignment) -> Div {
+    let container = div().flex().flex_col();
+    let container = match alignment {
+        TextAlignment::Leading => container.items_start(),
+        TextAlignment::Center => container.items_center(),
+        TextAlignment::Trailing => container.items_end(),
+    };
+    container.children(content.split('\n').map(|line| SpacedLine {
+        text: SharedString::from(line.to_string()),
+        kerning,
+    }))
+}
+
+/// The byte offset where each grapheme cluster of `text` starts.
+fn cluster_starts(text: &str) -> Vec<usize> {
+    text.grapheme_indices(true)
+        .map(|(start, _)| start)
+        .collect()
+}
+
+/// The spacing added before the glyph at byte `index`: `kerning` for each
+/// cluster that ends before it, so a cluster's glyphs move together.
+fn spacing_before(cluster_starts: &[usize], index: usize, kerning: f32) -> f32 {
+    let cluster = cluster_starts
+        .partition_point(|&start| start <= index)
+        .saturating_sub(1);
+    cluster as f32 * kerning
+}
+
+/// A single line of text with extra space after each grapheme cluster.
+struct SpacedLine {
+    text: SharedString,
+    kerning: f32,
+}
+
+/// A shaped [`SpacedLine`], with where each of its clusters starts.
+struct SpacedLayout {
+    line: ShapedLine,
+    cluster_starts: Vec<usize>,
+}
+
+impl IntoElement for SpacedLine {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for SpacedLine {
+    type RequestLayoutState = (TextStyle, Option<SpacedLayout>);
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn source_location(&self) -> Option<&'static std::panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let text_style = window.text_style();
+        let font_size = text_style.font_size.to_pixels(window.rem_size());
+        let line_height = text_style.line_height_in_pixels(window.rem_size());
+        let line = window.text_system().shape_line(
+            self.text.clone(),
+            font_size,
+            &[text_style.to_run(self.text.len())],
+            None,
+        );
+        let cluster_starts = cluster_starts(&self.text);
+        let width = (line.width + px(self.kerning * cluster_starts.len() as f32)).max(px(0.));
+
+        let style = Style {
+            flex_shrink: 0.,
+            size: size(width.into(), line_height.into()),
+            ..Style::default()
+        };
+        let layout_id = window.request_layout(style, [], cx);
+        let layout = SpacedLayout {
+            line,
+            cluster_starts,
+        };
+        (layout_id, (text_style, Some(layout)))
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        _state: &mut Self::RequestLayoutState,
+        _window: &mut Window,
+        _cx: &mut App,
+    ) -> Self::PrepaintState {
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        bounds: Bounds<Pixels>,
+        (text_style, layout): &mut Self::RequestLayoutState,
+        _prepaint: &mut Self::PrepaintState,
+        window: &mut Window,
+        _cx: &mut App,
+    ) {
+        let Some(SpacedLayout {
+            line,
+            cluster_starts,
+        }) = layout.take()
+        else {
+            return;
+        };
+        let line_height = text_style.line_height_in_pixels(window.rem_size());
+        let width = bounds.size.width;
+        let origin = bounds.origin;
+        let baseline = origin.y + (line_height - line.ascent - line.descent) / 2. + line.ascent;
+
+        if let Some(background) = text_style.background_color {
+            window.paint_quad(fill(bounds, background));
+        }
+        for run in &line.runs {
+            for glyph in &run.glyphs {
+                let x = origin.x
+                    + glyph.position.x
+                    + px(spacing_before(&cluster_starts, glyph.index, self.kerning));
+                let glyph_origin = point(x, baseline);
+                if glyph.is_emoji {
+                    window
+                        .paint_emoji(glyph_origin, run.font_id, glyph.id, line.font_size)
+                        .ok();
+                } else {
+                    window
+                        .paint_glyph(
+                            glyph_origin,
+                            run.font_id,
+                            glyph.id,
+                            line.font_size,
+                            text_style.color,
+                        )
+                        .ok();
+                }
+            }
+        }
+
+        // Decorations span the spaced width, as GPUI draws them for a run
+        if let Some(underline) = &text_style.underline {
+            let style = UnderlineStyle {
+                color: underline.color.or(Some(text_style.color)),
+                ..*underline
+            };
+            let y = baseline + line.descent * 0.618;
+            window.paint_underline(point(origin.x, y), width, &style);
+        }
+        if let Some(strikethrough) = &text_style.strikethrough {
+            let style = StrikethroughStyle {
+                color: strikethrough.color.or(Some(text_style.color)),
+                ..*strikethrough
+            };
+            let y = origin.y + (line.ascent * 0.5 + (baseline - origin.y)) * 0.5;
+            window.paint_strikethrough(point(origin.x, y), width, &style);
+        }
+    }
+}
+
+const ELLIPSIS: &str = "…";
+
+/// Shorten `text` to fit `max_width`, replacing removed grapheme clusters with
+/// an ellipsis at the position given by `mode`.
+///
+/// `measure` returns the rendered width of a string. Middle truncation keeps
+/// a balanced prefix and suffix, giving any odd cluster to the suffix. A
+/// separator (see [`is_separator`]) ending the prefix is traded for one more
+/// cluster of the suffix.
+pub(crate) fn truncate_to_width(
+    text: &str,
+    mode: TruncationMode,
+    max_width: f32,
+    measure: impl Fn(&str) -> f32,
+) -> String {
+    if measure(text) <= max_width {
+        return text.to_string();
+    }
+
+    let clusters: Vec<&str> = text.graphemes(true).collect();
+    let candidate = |kept: usize| -> String {
+        let (prefix, suffix) = match mode {
+            TruncationMode::Head => (0, kept),
+            TruncationMode::Tail => (kept, 0),
+            TruncationMode::Middle => {
+                let prefix = kept / 2;
+                // A separator just before the ellipsis carries no information
+                // ("very/…"), so give that character to the suffix instead
+                if prefix > 0 && is_separator(clusters[prefix - 1]) {
+                    (prefix - 1, kept - prefix + 1)
+                } else {
+                    (prefix, kept - prefix)
+                }
+            }
+        };
+        let mut result = clusters[..prefix].concat();
+        result.push_str(ELLIPSIS);
+        result.push_str(&clusters[clusters.len() - suffix..].concat());
+        result
+    };
+
+    // Widths grow with the number of kept clusters, so binary search for
+    // the longest candidate that fits
+    let (mut low, mut high) = (0, clusters.len());
+    while low < high {
+        let mid = (low + high).div_ceil(2);
+        if measure(&candidate(mid)) <= max_width {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+    candidate(low)
+}
+
+/// Whether a cluster is a path separator or whitespace, which middle
+/// truncation keeps off the end of the prefix.
+fn is_separator(cluster: &str) -> bool {
+    matches!(cluster, "/" | "\\") || cluster.chars().all(char::is_whitespace)
+}
+
+/// A single line of text that is ellipsized to its laid-out width.
+struct TruncatedLine {
+    text: SharedString,
+    mode: TruncationMode,
+}
+
+impl IntoElement for TruncatedLine {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for TruncatedLine {
+    type RequestLayoutState = TextStyle;
+    type PrepaintState = Option<ShapedLine>;
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn source_location(&self) -> Option<&'static std::panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        window: &mut Window,
+        _cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let text_style = window.text_style();
+        let font_size = text_style.font_size.to_pixels(window.rem_size());
+        let line_height = text_style.line_height_in_pixels(window.rem_size());
+        let full_width = window
+            .text_system()
+            .shape_line(
+                self.text.clone(),
+                font_size,
+                &[text_style.to_run(self.text.len())],
+                None,
+            )
+            .width;
+
+        let mut style = Style::default();
+        style.min_size.width = px(0.).into();
+        style.max_size.width = relative(1.).into();
+        let layout_id = window.request_measured_layout(style, move |known, available, _, _| {
+            let width = known.width.unwrap_or(match available.width {
+                AvailableSpace::Definite(available) => full_width.min(available),
+                _ => full_width,
+            });
+            size(width, line_height)
+        });
+        (layout_id, text_style)
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        bounds: Bounds<Pixels>,
+        text_style: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        _cx: &mut App,
+    ) -> Self::PrepaintState {
+        let font_size = text_style.font_size.to_pixels(window.rem_size());
+        let text_system = window.text_system().clone();
+        let shape = |text: SharedString| {
+            let runs = [text_style.to_run(text.len())];
+            text_system.shape_line(text, font_size, &runs, None)
+        };
+
+        let max_width = f32::from(bounds.size.width);
+        let truncated = truncate_to_width(&self.text, self.mode, max_width, |candidate| {
+            f32::from(shape(SharedString::from(candidate.to_string())).width)
+        });
+        Some(shape(truncated.into()))
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        bounds: Bounds<Pixels>,
+        text_style: &mut Self::RequestLayoutState,
+        line: &mut Self::PrepaintState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        if let Some(line) = line.take() {
+            let line_height = text_style.line_height_in_pixels(window.rem_size());
+            line.paint(bounds.origin, line_height, window, cx).ok();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn char_width(text: &str) -> f32 {
+        text.graphemes(true).count() as f32
+    }
+
+    #[test]
+    fn concatenation_keeps_segment_styles() {
+        let text = (Text::new("Hello ").bold() + Text::new("world").foreground_color(Color::red()))
+            .foreground_color(Color::blue())
+            + Text::new("!");
+        let segments = text.into_segments();
+
+        let contents: Vec<&str> = segments.iter().map(|s| s.content.as_ref()).collect();
+        assert_eq!(contents, vec!["Hello ", "world", "!"]);
+        assert_eq!(
+            segments[0].font.as_ref().map(|f| f.weight),
+            Some(FontWeight::Bold)
+        );
+        let color = |i: usize| segments[i].color.as_ref().map(|c| c.resolve(false));
+        assert_eq!(color(0), Some(Color::blue().resolve(false)));
+        assert_eq!(color(1), Some(Color::red().resolve(false)));
+        assert_eq!(color(2), None);
+    }
+
+    #[test]
+    fn head_truncation_keeps_the_end() {
+        let path = "src/very/long/path/to/deep/file.rs";
+
+        let truncated = truncate_to_width(path, TruncationMode::Head, 14.0, char_width);
+        assert_eq!(truncated, "…/deep/file.rs");
+    }
+
+    #[test]
+    fn middle_truncation_keeps_both_ends() {
+        let path = "very/long/path/file.rs";
+
+        let truncated = truncate_to_width(path, TruncationMode::Middle, 12.0, char_width);
+        assert_eq!(truncated, "very…file.rs");
+    }
+
+    #[test]
+    fn spacing_moves_grapheme_clusters_together() {
+        // "e" with a combining acute accent is one cluster of two chars
+        let text = "e\u{301}x";
+        let starts = cluster_starts(text);
+        assert_eq!(starts, vec![0, 3]);
+        assert_eq!(spacing_before(&starts, 0, 2.0), 0.0);
+        assert_eq!(spacing_before(&starts, 1, 2.0), 0.0);
+        assert_eq!(spacing_before(&starts, 3, 2.0), 2.0);
+    }
+
+    #[test]
+    fn middle_truncation_moves_a_trailing_separator_to_the_suffix() {
+        // An even split would leave "Hello " before the ellipsis
+        let truncated =
+            truncate_to_width("Hello big world", TruncationMode::Middle, 13.0, char_width);
+        assert_eq!(truncated, "Hello…g world");
+
+        let truncated = truncate_to_width(
+            "C:\\Users\\app\\main.rs",
+            TruncationMode::Middle,
+            8.0,
+            char_width,
+        );
+        assert_eq!(truncated, "C:…in.rs");
+    }
+
+    #[test]
+    fn truncation_keeps_grapheme_clusters_whole() {
+        // Each flag is one cluster of two scalar values
+        let flags = "🇩🇪🇫🇷🇯🇵🇺🇸";
+        let truncated = truncate_to_width(flags, TruncationMode::Tail, 3.0, char_width);
+        assert_eq!(truncated, "🇩🇪🇫🇷…");
+
+        let accented = "cafe\u{301} au lait";
+        let truncated = truncate_to_width(accented, TruncationMode::Tail, 5.0, char_width);
+        assert_eq!(truncated, "cafe\u{301}…");
+    }
+
+    #[test]
+    fn tail_truncation_keeps_the_start() {
+        let truncated = truncate_to_width("Hello, World!", TruncationMode::Tail, 6.0, char_width);
+        assert_eq!(truncated, "Hello…");
+    }
+
+    #[test]
+    fn fitting_text_is_unchanged() {
+        let truncated = truncate_to_width("file.rs", TruncationMode::Middle, 7.0, char_width);
+        assert_eq!(truncated, "file.rs");
+    }
+
+    #[test]
+    fn too_narrow_leaves_only_ellipsis() {
+        let truncated = truncate_to_width("file.rs", TruncationMode::Head, 0.5, char_width);
+        assert_eq!(truncated, "…");
     }
 }
@@ -25,19 +25,28 @@
 //! TextEditor::new(&self.notes_input)
 //! ```
 
-use gpui::{px, App, Entity, IntoElement, Pixels, RenderOnce, Window};
-use gpui_component::input::Input;
+use gpui::{
+    div, px, App, Context, ElementId, Entity, IntoElement, ParentElement, Pixels, RenderOnce,
+    SharedString, Styled, Subscription, Window,
+};
+use gpui_component::input::{Input, InputEvent};
 
 // Use InputState from text_field module
 use super::text_field::InputState;
 
+use crate::components::Text;
+use crate::focus;
 use crate::modifier::Modifier;
+use crate::style::{Color, Font};
 
 /// A multi-line text editor.
 ///
 /// This component wraps gpui-component's Input with multi_line mode.
 /// The InputState should be created with `.multi_line(true)`.
 ///
+/// Bind a focus handle the app keeps with [`Modifier::focused`] to move
+/// the cursor into the field from code.
+///
 /// # Example
 ///
 /// ```rust,ignore
@@ -50,12 +59,18 @@ use crate::modifier::Modifier;
 ///
 /// TextEditor::new(&notes)
 ///     .height(200.0)
+///     .max_length(280)
+///     .character_count(true)
 /// ```
 #[derive(IntoElement)]
 pub struct TextEditor {
     state: Entity<InputState>,
     height: Option<Pixels>,
     disabled: bool,
+    show_line_numbers: Option<bool>,
+    character_count: bool,
+    word_count: bool,
+    max_length: Option<usize>,
 }
 
 impl TextEditor {
@@ -67,6 +82,10 @@ impl TextEditor {
             state: state.clone(),
             height: None,
             disabled: false,
+            show_line_numbers: None,
+            character_count: false,
+            word_count: false,
+            max_length: None,
         }
     }
 
@@ -81,12 +100,55 @@ impl TextEditor {
         self.disabled = disabled;
         self
     }
+
+    /// Show line numbers in a gutter beside the text.
+    ///
+    /// The gutter is drawn by the editor itself, so it scrolls with the text
+    /// and a wrapped line keeps one number. It needs a state in code editor
+    /// mode; `"text"` edits plain text without highlighting:
+    ///
+    /// ```rust,ignore
+    /// let state = cx.new(|cx| InputState::new(window, cx).code_editor("text"));
+    /// TextEditor::new(&state).show_line_numbers(true)
+    /// ```
+    ///
+    /// Other states can't draw line numbers, which debug builds assert.
+    pub fn show_line_numbers(mut self, show: bool) -> Self {
+        self.show_line_numbers = Some(show);
+        self
+    }
+
+    /// Show the number of characters below the editor.
+    ///
+    /// With [`max_length`](Self::max_length) this reads "123 / 500" and
+    /// turns red at the limit.
+    pub fn character_count(mut self, show: bool) -> Self {
+        self.character_count = show;
+        self
+    }
+
+    /// Show the number of words below the editor.
+    pub fn word_count(mut self, show: bool) -> Self {
+        self.word_count = show;
+        self
+    }
+
+    /// Limit the text to a number of characters.
+    ///
+    /// Typing at the limit is blocked, and pasted text is cut to fit.
+    pub fn max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
 }
 
 impl Modifier for TextEditor {}
 
 impl RenderOnce for TextEditor {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        if let Some(handle) = focus::take(cx) {
+            focus::forward_to_input(&handle, &self.state, window, cx);
+        }
         let mut input = Input::new(&self.state);
 
         if let Some(height) = self.height {
@@ -97,6 +159,225 @@ impl RenderOnce for TextEditor {
             input = input.disabled(true);
         }
 
-        input
+        let has_footer = self.character_count || self.word_count;
+        if self.show_line_numbers.is_none() && !has_footer && self.max_length.is_none() {
+            return input.into_any_element();
+        }
+
+        let id = ElementId::NamedChild(
+            Box::new(ElementId::View(self.state.entity_id())),
+            "text-editor".into(),
+        );
+        let state = self.state.clone();
+        let metrics =
+            window.use_keyed_state(id, cx, |window, cx| EditorMetrics::new(&state, window, cx));
+        metrics.update(cx, |metrics, cx| {
+            metrics.max_length = self.max_length;
+            metrics.refresh(&self.state, cx);
+        });
+
+        // The state notifies when changed, so only forward actual changes
+        if let Some(show) = self.show_line_numbers {
+            let changed = metrics.update(cx, |metrics, _| {
+                metrics.line_numbers.replace(show) != Some(show)
+            });
+            if changed {
+                self.state.update(cx, |state, cx| {
+                    state.set_line_number(show, window, cx);
+                });
+            }
+        }
+        let metrics_value = metrics.read(cx);
+        let editor = input.into_any_element();
+
+        if !has_footer {
+            return editor;
+        }
+
+        let mut counts = Vec::new();
+        if self.word_count {
+            let words = metrics_value.words;
+            counts.push(
+                Text::new(if words == 1 {
+                    "1 word".to_string()
+                } else {
+                    format!("{words} words")
+                })
+                .font(Font::caption())
+                .foreground_color(Color::secondary_label())
+                .into_any_element(),
+            );
+        }
+        if self.character_count {
+            let characters = metrics_value.characters;
+            let (label, color) = match self.max_length {
+                Some(max) if characters >= max => {
+                    (format!("{characters} / {max}"), Color::destructive())
+                }
+                Some(max) => (format!("{characters} / {max}"), Color::secondary_label()),
+                None => (format!("{characters}"), Color::secondary_label()),
+            };
+            counts.push(
+                Text::new(label)
+                    .font(Font::caption())
+                    .foreground_color(color)
+                    .into_any_element(),
+            );
+        }
+
+        div()
+            .flex()
+            .flex_col()
+            .gap(px(4.0))
+            .child(editor)
+            .child(
+                div()
+                    .flex()
+                    .flex_row()
+                    .justify_end()
+                    .gap(px(12.0))
+                    .children(counts),
+            )
+            .into_any_element()
+    }
+}
+
+/// Counts derived from an editor's text, kept across renders and only
+/// recomputed when the text changes.
+struct EditorMetrics {
+    /// The last text within the length limit.
+    text: SharedString,
+    characters: usize,
+    words: usize,
+    max_length: Option<usize>,
+    /// The line number setting last applied to the state.
+    line_numbers: Option<bool>,
+    _subscription: Subscription,
+}
+
+impl EditorMetrics {
+    fn new(state: &Entity<InputState>, window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let subscription = cx.subscribe_in(state, window, |this, state, event, window, cx| {
+            if !matches!(event, InputEvent::Change) {
+                return;
+            }
+
+            let text = state.read(cx).value();
+            if let Some(max_length) = this.max_length {
+                if let Some(limited) = limit_length(&text, &this.text, max_length) {
+                    let limited = SharedString::from(limited);
+                    // Record the text first: setting it emits another change
+                    this.set_text(limited.clone());
+                    state.update(cx, |state, cx| state.set_value(limited, window, cx));
+                    cx.notify();
+                    return;
+                }
+            }
+            // Edits can land between renders, so the limit compares against
+            // the latest accepted text rather than the last rendered one
+            this.set_text(text);
+            // Re-render the editor's view so the counts follow the text
+            cx.notify();
+        });
+
+        Self {
+            text: SharedString::default(),
+            characters: 0,
+            words: 0,
+            max_length: None,
+            line_numbers: None,
+            _subscription: subscription,
+        }
+    }
+
+    /// Pick up text set on the state without a change event.
+    fn refresh(&mut self, state: &Entity<InputState>, cx: &mut Context<Self>) {
+        let text = state.read(cx).value();
+        if text != self.text {
+            self.set_text(text);
+        }
+    }
+
+    fn set_text(&mut self, text: SharedString) {
+        if text != self.text {
+            self.characters = text.chars().count();
+            self.words = word_count(&text);
+            self.text = text;
+        }
+    }
+}
+
+/// The number of whitespace-separated words in `text`.
+fn word_count(text: &str) -> usize {
+    text.split_whitespace().count()
+}
+
+/// The text to put back when an edit from `previous` to `text` goes past
+/// `max_length` characters.
+///
+/// The inserted part of the edit is cut so the result fits: typing at the
+/// limit keeps `previous`, and a long paste keeps as much as fits. If the
+/// rest of the text is already too long, `text` is cut to the limit.
+fn limit_length(text: &str, previous: &str, max_length: usize) -> Option<String> {
+    let length = text.chars().count();
+    if length <= max_length {
+        return None;
+    }
+
+    let text: Vec<char> = text.chars().collect();
+    let previous: Vec<char> = previous.chars().collect();
+    let prefix = text
+        .iter()
+        .zip(&previous)
+        .take_while(|(a, b)| a == b)
+        .count();
+    let suffix = text[prefix..]
+        .iter()
+        .rev()
+        .zip(previous[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let kept = prefix + suffix;
+    if kept > max_length {
+        return Some(text[..max_length].iter().collect());
+    }
+    let inserted = &text[prefix..length - suffix];
+    let fits = max_length - kept;
+    Some(
+        text[..prefix]
+            .iter()
+            .chain(&inserted[..fits])
+            .chain(&text[length - suffix..])
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_words() {
+        assert_eq!(word_count(""), 0);
+        assert_eq!(word_count("  hello\n world  "), 2);
+        assert_eq!(word_count("one-word"), 1);
+    }
+
+    #[test]
+    fn limits_length() {
+        assert_eq!(limit_length("abc", "ab", 3), None);
+        assert_eq!(limit_length("abcd", "abc", 3), Some("abc".to_string()));
+        assert_eq!(
+            limit_length("héllo!", "héllo!", 4),
+            Some("héll".to_string())
+        );
+    }
+
+    #[test]
+    fn cuts_pasted_text_to_fit() {
+        assert_eq!(limit_length("héllo!", "", 4), Some("héll".to_string()));
+        assert_eq!(limit_length("aXYZb", "ab", 4), Some("aXYb".to_string()));
+        assert_eq!(limit_length("abXYZ", "ab", 3), Some("abX".to_string()));
     }
 }
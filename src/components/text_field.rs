@@ -26,24 +26,35 @@
 //! TextField::new(&self.email_input)
 //! ```
 
+use std::rc::Rc;
+
 use gpui::{
-    px, App, Entity, Hsla, InteractiveElement, IntoElement, ParentElement, RenderOnce,
-    StatefulInteractiveElement, Styled, Window,
+    div, px, AnyElement, App, Context, ElementId, Entity, Hsla, InteractiveElement, IntoElement,
+    ParentElement, RenderOnce, SharedString, StatefulInteractiveElement, Styled, Subscription,
+    Window,
 };
 use gpui_component::h_flex;
-use gpui_component::input::Input;
+use gpui_component::input::{Input, InputEvent};
 use gpui_component::{ActiveTheme, Icon, IconName};
 
 pub use gpui_component::input::InputState;
 
+use crate::accessibility::{AccessibilityNode, AccessibilityRole, Accessible};
+use crate::binding::Binding;
+use crate::components::Text;
+use crate::environment;
+use crate::focus;
 use crate::modifier::Modifier;
-use crate::style::Color;
+use crate::style::{Color, ColorScheme, Font};
 
 /// A single-line text input field.
 ///
 /// This component wraps gpui-component's Input, providing a SwiftUI-like API.
 /// State is managed via `Entity<InputState>` which must be created in your view.
 ///
+/// Bind a focus handle the app keeps with [`Modifier::focused`] to move
+/// the cursor into the field from code.
+///
 /// # Example
 ///
 /// ```rust,ignore
@@ -57,24 +68,87 @@ use crate::style::Color;
 /// // Use in render
 /// TextField::new(&name_input)
 ///     .cleanable(true)
+///     .on_change(cx.listener(|this, text: &str, _, cx| {
+///         this.name = text.to_string();
+///         cx.notify();
+///     }))
+///
+/// // Accessories and validation
+/// TextField::new(&price_input)
+///     .prefix(Text::new("$"))
+///     .suffix(Text::new("USD"))
+///     .error((!valid).then(|| "Enter a number".into()))
 /// ```
 #[derive(IntoElement)]
 pub struct TextField {
-    state: Entity<InputState>,
+    source: TextFieldSource,
     cleanable: bool,
     disabled: bool,
+    prefix: Option<AnyElement>,
+    suffix: Option<AnyElement>,
+    error: Option<SharedString>,
+    success: bool,
+    handlers: InputHandlers,
 }
 
 impl TextField {
     /// Create a new text field with the given state.
     pub fn new(state: &Entity<InputState>) -> Self {
+        Self::with_source(TextFieldSource::State(state.clone()))
+    }
+
+    /// Create a text field for text read and written through `binding`,
+    /// showing `placeholder` while it's empty.
+    ///
+    /// The field keeps its own `InputState`, keyed by the call site.
+    ///
+    /// ```rust,ignore
+    /// let view = cx.entity();
+    /// TextField::bound("Name", Binding::entity_field(&view, |this| &mut this.name))
+    /// ```
+    #[track_caller]
+    pub fn bound(placeholder: impl Into<SharedString>, binding: Binding<String>) -> Self {
+        Self::with_source(TextFieldSource::Bound {
+            id: ElementId::CodeLocation(*std::panic::Location::caller()),
+            placeholder: placeholder.into(),
+            binding,
+        })
+    }
+
+    fn with_source(source: TextFieldSource) -> Self {
         Self {
-            state: state.clone(),
+            source,
             cleanable: false,
             disabled: false,
+            prefix: None,
+            suffix: None,
+            error: None,
+            success: false,
+            handlers: InputHandlers::default(),
         }
     }
 
+    /// Call a handler with the text when Enter is pressed.
+    pub fn on_submit(mut self, handler: impl Fn(&str, &mut Window, &mut App) + 'static) -> Self {
+        self.handlers.on_submit = Some(Rc::new(handler));
+        self
+    }
+
+    /// Call a handler with the new text whenever it changes.
+    pub fn on_change(mut self, handler: impl Fn(&str, &mut Window, &mut App) + 'static) -> Self {
+        self.handlers.on_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Call a handler with `true` when the field gains focus and `false` when it loses it.
+    pub fn on_focus_change(
+        mut self,
+        handler: impl Fn(&bool, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.handlers.on_focus_change = Some(Rc::new(handler));
+        self
+    }
+
     /// Show a clear button when the field has content.
     pub fn cleanable(mut self, cleanable: bool) -> Self {
         self.cleanable = cleanable;
@@ -86,33 +160,97 @@ impl TextField {
         self.disabled = disabled;
         self
     }
+
+    /// Show a view before the text, such as an icon or a currency symbol.
+    pub fn prefix(mut self, prefix: impl IntoElement) -> Self {
+        self.prefix = Some(prefix.into_any_element());
+        self
+    }
+
+    /// Show a view after the text, such as a unit label.
+    ///
+    /// The clear button of a [`cleanable`](Self::cleanable) field is placed
+    /// after the suffix.
+    pub fn suffix(mut self, suffix: impl IntoElement) -> Self {
+        self.suffix = Some(suffix.into_any_element());
+        self
+    }
+
+    /// Mark the field invalid with a message shown below it.
+    ///
+    /// `None` clears the error state.
+    pub fn error(mut self, message: Option<SharedString>) -> Self {
+        self.error = message;
+        self
+    }
+
+    /// Mark the field valid with a green border.
+    ///
+    /// An [`error`](Self::error) takes precedence over success.
+    pub fn success(mut self, success: bool) -> Self {
+        self.success = success;
+        self
+    }
 }
 
 impl Modifier for TextField {}
 
+/// Where a text field's text lives.
+enum TextFieldSource {
+    /// A state made by the view.
+    State(Entity<InputState>),
+    /// A state kept by the field under `id`, synced with `binding`.
+    Bound {
+        id: ElementId,
+        placeholder: SharedString,
+        binding: Binding<String>,
+    },
+}
+
 impl RenderOnce for TextField {
-    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let is_dark = cx.theme().is_dark();
+    fn render(mut self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let state = match self.source {
+            TextFieldSource::State(state) => state,
+            TextFieldSource::Bound {
+                id,
+                placeholder,
+                binding,
+            } => bound_state(id, placeholder, binding, &mut self.handlers, window, cx),
+        };
+        let accessibility = accessibility(&state, cx);
+        self.handlers.attach(&state, window, cx);
+        if let Some(handle) = focus::take(cx) {
+            focus::forward_to_input(&handle, &state, window, cx);
+        }
+
+        let is_dark = environment::color_scheme(cx) == ColorScheme::Dark;
         let bg_color = Color::text_field_background().resolve(is_dark);
-        let border_color = Color::text_field_border().resolve(is_dark);
-
-        let mut input = Input::new(&self.state)
-            .bg(bg_color)
-            .border_color(border_color);
-
-        if self.cleanable {
-            let has_text = self.state.read(cx).text().len() > 0;
-            if has_text {
-                let state = self.state.clone();
-                let icon_color = Hsla {
-                    a: 0.5,
-                    ..cx.theme().foreground
-                };
-                let hover_bg = Hsla {
-                    a: 0.1,
-                    ..cx.theme().foreground
-                };
-                let clear_button = h_flex()
+        let border_color = if self.error.is_some() {
+            Color::destructive().resolve_in(cx)
+        } else if self.success {
+            Color::green().resolve_in(cx)
+        } else {
+            Color::text_field_border().resolve(is_dark)
+        };
+
+        let mut input = Input::new(&state).bg(bg_color).border_color(border_color);
+
+        if let Some(prefix) = self.prefix {
+            input = input.prefix(prefix);
+        }
+
+        let clear_button = if self.cleanable && state.read(cx).text().len() > 0 {
+            let state = state.clone();
+            let icon_color = Hsla {
+                a: 0.5,
+                ..cx.theme().foreground
+            };
+            let hover_bg = Hsla {
+                a: 0.1,
+                ..cx.theme().foreground
+            };
+            Some(
+                h_flex()
                     .id("clear-input")
                     .items_center()
                     .justify_center()
@@ -125,8 +263,22 @@ impl RenderOnce for TextField {
                         state.update(cx, |state, cx| {
                             state.set_value("", window, cx);
                         });
-                    });
-                input = input.suffix(clear_button);
+                    }),
+            )
+        } else {
+            None
+        };
+
+        match (self.suffix, clear_button) {
+            (None, None) => {}
+            (suffix, clear_button) => {
+                input = input.suffix(
+                    h_flex()
+                        .items_center()
+                        .gap(px(4.0))
+                        .children(suffix)
+                        .children(clear_button),
+                );
             }
         }
 
@@ -134,6 +286,133 @@ impl RenderOnce for TextField {
             input = input.disabled(true);
         }
 
-        input
+        let field = match self.error {
+            Some(message) => div()
+                .flex()
+                .flex_col()
+                .gap(px(4.0))
+                .child(input)
+                .child(
+                    Text::new(message)
+                        .font(Font::caption())
+                        .foreground_color(Color::destructive()),
+                )
+                .into_any_element(),
+            None => input.into_any_element(),
+        };
+        Accessible::new(accessibility, field, cx)
+    }
+}
+
+/// The state of a bound field, updated to the binding's text, with
+/// `handlers` set to write edits back.
+fn bound_state(
+    id: ElementId,
+    placeholder: SharedString,
+    binding: Binding<String>,
+    handlers: &mut InputHandlers,
+    window: &mut Window,
+    cx: &mut App,
+) -> Entity<InputState> {
+    let state = window.use_keyed_state(id, cx, |window, cx| {
+        InputState::new(window, cx).placeholder(placeholder)
+    });
+    let text = binding.get(cx);
+    if state.read(cx).value().as_ref() != text.as_str() {
+        state.update(cx, |state, cx| state.set_value(text, window, cx));
+    }
+
+    let on_change = handlers.on_change.take();
+    handlers.on_change = Some(Rc::new(move |text, window, cx| {
+        binding.set(text.to_string(), window, cx);
+        if let Some(on_change) = &on_change {
+            on_change(text, window, cx);
+        }
+    }));
+    state
+}
+
+/// How a field is described to assistive technology: by the text being
+/// edited.
+fn accessibility(state: &Entity<InputState>, cx: &App) -> AccessibilityNode {
+    AccessibilityNode::new(AccessibilityRole::TextField).value(state.read(cx).value())
+}
+
+type TextHandler = Rc<dyn Fn(&str, &mut Window, &mut App) + 'static>;
+type FocusHandler = Rc<dyn Fn(&bool, &mut Window, &mut App) + 'static>;
+
+/// Callbacks for the events of an `InputState`.
+#[derive(Clone, Default)]
+pub(crate) struct InputHandlers {
+    pub(crate) on_submit: Option<TextHandler>,
+    pub(crate) on_change: Option<TextHandler>,
+    pub(crate) on_focus_change: Option<FocusHandler>,
+}
+
+impl InputHandlers {
+    fn is_empty(&self) -> bool {
+        self.on_submit.is_none() && self.on_change.is_none() && self.on_focus_change.is_none()
+    }
+
+    /// Subscribe the handlers to `state` for as long as the field keeps rendering.
+    ///
+    /// The subscription is kept in element state, so it is made once and the
+    /// handlers are swapped in on every render.
+    pub(crate) fn attach(self, state: &Entity<InputState>, window: &mut Window, cx: &mut App) {
+        if self.is_empty() {
+            return;
+        }
+
+        let id = ElementId::NamedChild(
+            Box::new(ElementId::View(state.entity_id())),
+            "input-handlers".into(),
+        );
+        let subscription = window.use_keyed_state(id, cx, |window, cx| {
+            InputSubscription::new(state, window, cx)
+        });
+        subscription.update(cx, |subscription, _| subscription.handlers = self);
+    }
+}
+
+/// Retained subscription that forwards input events to the latest handlers.
+struct InputSubscription {
+    handlers: InputHandlers,
+    _subscription: Subscription,
+}
+
+impl InputSubscription {
+    fn new(state: &Entity<InputState>, window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let subscription = cx.subscribe_in(state, window, |this, state, event, window, cx| {
+            let handlers = this.handlers.clone();
+            match event {
+                InputEvent::Change => {
+                    if let Some(on_change) = handlers.on_change {
+                        let text = state.read(cx).value();
+                        on_change(&text, window, cx);
+                    }
+                }
+                InputEvent::PressEnter { .. } => {
+                    if let Some(on_submit) = handlers.on_submit {
+                        let text = state.read(cx).value();
+                        on_submit(&text, window, cx);
+                    }
+                }
+                InputEvent::Focus => {
+                    if let Some(on_focus_change) = handlers.on_focus_change {
+                        on_focus_change(&true, window, cx);
+                    }
+                }
+                InputEvent::Blur => {
+                    if let Some(on_focus_change) = handlers.on_focus_change {
+                        on_focus_change(&false, window, cx);
+                    }
+                }
+            }
+        });
+
+        Self {
+            handlers: InputHandlers::default(),
+            _subscription: subscription,
+        }
     }
 }
@@ -1,13 +1,43 @@
 //! Toggle - Boolean switch component.
 //!
-//! A SwiftUI-style toggle that wraps gpui-component's Switch.
+//! A SwiftUI-style toggle that wraps gpui-component's Switch or Checkbox,
+//! or renders as a button that stays highlighted while on.
 
-use gpui::{App, IntoElement, RenderOnce, SharedString, Window};
+use gpui::{
+    div, px, AnyElement, App, ElementId, InteractiveElement, IntoElement, ParentElement,
+    RenderOnce, SharedString, StatefulInteractiveElement, Styled, Window,
+};
+use gpui_component::checkbox::Checkbox;
 use gpui_component::switch::Switch;
 use gpui_component::Disableable;
 
+use crate::accessibility::{self, AccessibilityNode, AccessibilityRole, Accessible};
+use crate::binding::Binding;
+use crate::components::{Button, ButtonStyle};
+use crate::environment::{self, EnvironmentScope};
 use crate::modifier::Modifier;
 
+/// The appearance of a toggle.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ToggleStyle {
+    /// A sliding switch.
+    #[default]
+    Switch,
+    /// A checkbox with a trailing label.
+    Checkbox,
+    /// A bordered button that appears filled while on.
+    Button,
+}
+
+/// Which side of a toggle's control its label is placed on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LabelPlacement {
+    /// Label before the control, like an iOS settings row.
+    Leading,
+    /// Label after the control.
+    Trailing,
+}
+
 /// Handler type for toggle changes with GPUI context access.
 pub type ToggleHandler = Box<dyn Fn(&bool, &mut Window, &mut App) + 'static>;
 
@@ -28,6 +58,13 @@ pub type ToggleHandler = Box<dyn Fn(&bool, &mut Window, &mut App) + 'static>;
 ///         cx.notify();
 ///     })
 /// )
+///
+/// // As a checkbox
+/// Toggle::new("Remember me", remember, |_| {})
+///     .toggle_style(ToggleStyle::Checkbox)
+///
+/// // A settings row: label leading, switch trailing, whole row tappable
+/// Section::new().row(Toggle::new("Airplane Mode", airplane_mode, |_| {}).full_width(true))
 /// ```
 #[derive(IntoElement)]
 pub struct Toggle {
@@ -35,7 +72,12 @@ pub struct Toggle {
     label: Option<SharedString>,
     is_on: bool,
     on_change: Option<ToggleHandler>,
+    style: ToggleStyle,
+    label_placement: Option<LabelPlacement>,
+    full_width: bool,
     disabled: bool,
+    /// Read for the state while rendering, and written when toggled.
+    binding: Option<Binding<bool>>,
 }
 
 impl Toggle {
@@ -55,7 +97,11 @@ impl Toggle {
             on_change: Some(Box::new(move |checked: &bool, _window, _cx| {
                 on_change(*checked);
             })),
+            style: ToggleStyle::default(),
+            label_placement: None,
+            full_width: false,
             disabled: false,
+            binding: None,
         }
     }
 
@@ -81,7 +127,32 @@ impl Toggle {
             label: Some(label_str),
             is_on,
             on_change: Some(Box::new(on_change)),
+            style: ToggleStyle::default(),
+            label_placement: None,
+            full_width: false,
+            disabled: false,
+            binding: None,
+        }
+    }
+
+    /// Create a toggle for a value read and written through `binding`.
+    ///
+    /// ```rust,ignore
+    /// let view = cx.entity();
+    /// Toggle::bound("Wi-Fi", Binding::entity_field(&view, |this| &mut this.wifi))
+    /// ```
+    pub fn bound(label: impl Into<SharedString>, binding: Binding<bool>) -> Self {
+        let label: SharedString = label.into();
+        Self {
+            id: label.clone(),
+            label: Some(label),
+            is_on: false,
+            on_change: None,
+            style: ToggleStyle::default(),
+            label_placement: None,
+            full_width: false,
             disabled: false,
+            binding: Some(binding),
         }
     }
 
@@ -92,7 +163,11 @@ impl Toggle {
             label: None,
             is_on,
             on_change: None,
+            style: ToggleStyle::default(),
+            label_placement: None,
+            full_width: false,
             disabled: false,
+            binding: None,
         }
     }
 
@@ -119,33 +194,215 @@ impl Toggle {
         self
     }
 
+    /// Set the toggle style.
+    pub fn toggle_style(mut self, style: ToggleStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Place the label before or after the control.
+    ///
+    /// Applies to the switch and checkbox styles. With an explicit placement
+    /// the label is part of the tap target.
+    pub fn label_placement(mut self, placement: LabelPlacement) -> Self {
+        self.label_placement = Some(placement);
+        self
+    }
+
+    /// Stretch the toggle across the available width.
+    ///
+    /// The label and control are pushed to opposite edges (label leading
+    /// unless placed otherwise) and tapping anywhere on the row toggles.
+    /// Applies to the switch and checkbox styles. Toggles in a `Form` are
+    /// full width unless given a label placement.
+    pub fn full_width(mut self, full_width: bool) -> Self {
+        self.full_width = full_width;
+        self
+    }
+
     /// Disable the toggle.
     pub fn disabled(mut self, disabled: bool) -> Self {
         self.disabled = disabled;
         self
     }
+
+    /// How the toggle is described to assistive technology: by its label
+    /// and whether it's on.
+    fn accessibility(&self) -> AccessibilityNode {
+        let node = AccessibilityNode::new(AccessibilityRole::Toggle).value(if self.is_on {
+            "on"
+        } else {
+            "off"
+        });
+        match &self.label {
+            Some(label) => node.label(label.clone()),
+            None => node,
+        }
+    }
+
+    /// The control, laid out with its label.
+    fn body(mut self, cx: &App) -> AnyElement {
+        // Form rows spread the label and control across the row
+        if self.label_placement.is_none() && environment::in_form(cx) {
+            self.full_width = true;
+        }
+        let placement = match (self.label_placement, self.full_width) {
+            (Some(placement), _) => Some(placement),
+            (None, true) => Some(LabelPlacement::Leading),
+            (None, false) => None,
+        };
+
+        let Some(placement) = placement.filter(|_| self.style != ToggleStyle::Button) else {
+            return control(
+                self.style,
+                self.id,
+                self.is_on,
+                self.label,
+                self.disabled,
+                self.on_change,
+            );
+        };
+
+        // Lay out the label ourselves and let the whole row handle clicks;
+        // the control gets no handler so a click doesn't toggle twice
+        let is_on = self.is_on;
+        let row_id = ElementId::Name(format!("{}-row", self.id).into());
+        let label = self.label.map(|label| {
+            let label = div().child(label);
+            if self.disabled {
+                label.opacity(0.5)
+            } else {
+                label
+            }
+        });
+        let spacer = self.full_width.then(|| div().flex_1());
+        let control = control(self.style, self.id, is_on, None, self.disabled, None);
+
+        let mut row = div().id(row_id).flex().items_center().gap(px(8.0));
+        if self.full_width {
+            row = row.w_full();
+        }
+        row = match placement {
+            LabelPlacement::Leading => row.children(label).children(spacer).child(control),
+            LabelPlacement::Trailing => row.child(control).children(label).children(spacer),
+        };
+
+        if let Some(on_change) = self.on_change.filter(|_| !self.disabled) {
+            row = row.cursor_pointer().on_click(move |_, window, cx| {
+                on_change(&!is_on, window, cx);
+            });
+        }
+
+        row.into_any_element()
+    }
 }
 
 impl Modifier for Toggle {}
 
 impl RenderOnce for Toggle {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
-        let mut switch = Switch::new(self.id).checked(self.is_on);
+    fn render(mut self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        if let Some(binding) = self.binding.take() {
+            self.is_on = binding.get(cx);
+            let on_change = self.on_change.take();
+            self.on_change = Some(Box::new(move |is_on, window, cx| {
+                binding.set(*is_on, window, cx);
+                if let Some(on_change) = &on_change {
+                    on_change(is_on, window, cx);
+                }
+            }));
+        }
+
+        // The button of a button-style toggle reports the toggle instead
+        // of itself
+        let slot = accessibility::describe(self.accessibility(), cx);
+        EnvironmentScope::new(Accessible::offered(slot.clone(), self.body(cx)))
+            .accessibility(Some(slot))
+    }
+}
 
-        if let Some(label) = self.label {
-            switch = switch.label(label);
+/// The control for a toggle style, with its built-in label if given.
+fn control(
+    style: ToggleStyle,
+    id: SharedString,
+    is_on: bool,
+    label: Option<SharedString>,
+    disabled: bool,
+    on_change: Option<ToggleHandler>,
+) -> AnyElement {
+    match style {
+        ToggleStyle::Switch => {
+            let mut switch = Switch::new(id).checked(is_on);
+
+            if let Some(label) = label {
+                switch = switch.label(label);
+            }
+
+            if disabled {
+                switch = switch.disabled(true);
+            }
+
+            if let Some(on_change) = on_change {
+                switch = switch.on_click(move |checked: &bool, window, cx| {
+                    on_change(checked, window, cx);
+                });
+            }
+
+            switch.into_any_element()
         }
+        ToggleStyle::Checkbox => {
+            let mut checkbox = Checkbox::new(id).checked(is_on);
+
+            if let Some(label) = label {
+                checkbox = checkbox.label(label);
+            }
+
+            if disabled {
+                checkbox = checkbox.disabled(true);
+            }
 
-        if self.disabled {
-            switch = switch.disabled(true);
+            if let Some(on_change) = on_change {
+                checkbox = checkbox.on_click(move |checked: &bool, window, cx| {
+                    on_change(checked, window, cx);
+                });
+            }
+
+            checkbox.into_any_element()
         }
+        ToggleStyle::Button => {
+            let style = if is_on {
+                ButtonStyle::BorderedProminent
+            } else {
+                ButtonStyle::Bordered
+            };
+            let mut button = Button::with_id(id)
+                .label(label.unwrap_or_default())
+                .button_style(style)
+                .disabled(disabled);
 
-        if let Some(on_change) = self.on_change {
-            switch = switch.on_click(move |checked: &bool, window, cx| {
-                on_change(checked, window, cx);
-            });
+            if let Some(on_change) = on_change {
+                button = button.on_click_with(move |_, window, cx| {
+                    on_change(&!is_on, window, cx);
+                });
+            }
+
+            button.into_any_element()
         }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn toggles_report_whether_they_are_on() {
+        let wifi = Toggle::new("Wi-Fi", true, |_| {}).accessibility();
+        assert_eq!(wifi.role, Some(AccessibilityRole::Toggle));
+        assert_eq!(wifi.label, Some("Wi-Fi".into()));
+        assert_eq!(wifi.value, Some("on".into()));
 
-        switch
+        let unlabeled = Toggle::unlabeled("wifi", false).accessibility();
+        assert_eq!(unlabeled.label, None);
+        assert_eq!(unlabeled.value, Some("off".into()));
     }
 }
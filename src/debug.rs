@@ -0,0 +1,453 @@
+//! Debug - outlining the wrappers Allui lays views out with.
+//!
+//! Every modifier wraps its view in a container, so a view with a few
+//! modifiers sits in several boxes. With the overlay on, each box is
+//! outlined in a color for its kind: green for padding, blue for frames,
+//! orange for backgrounds, magenta for tap gestures and gray for the rest.
+//! Hovering a box shows its size, and clicking logs every box under the
+//! pointer to stderr, outermost first.
+//!
+//! ```rust,ignore
+//! // In the root view's render
+//! div()
+//!     .size_full()
+//!     .child(content)
+//!     .child(DebugOverlay::new())
+//!
+//! // In a handler, such as a key binding
+//! allui::debug::toggle_overlay(cx);
+//! ```
+//!
+//! Windows opened with `window::open` draw the overlay already. Setting the
+//! `ALLUI_DEBUG_OVERLAY` environment variable turns it on at launch.
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::rc::Rc;
+use std::sync::OnceLock;
+
+use gpui::{
+    canvas, deferred, fill, hsla, outline, point, px, size, AnyElement, App, BorderStyle, Bounds,
+    ContentMask, Element, ElementId, Global, GlobalElementId, Hsla, InspectorElementId,
+    IntoElement, LayoutId, MouseDownEvent, MouseMoveEvent, Pixels, Point, RenderOnce, SharedString,
+    Styled, TextRun, Window, WindowId,
+};
+
+/// Turn the overlay on or off in every window.
+pub fn set_overlay(cx: &mut App, enabled: bool) {
+    let registry = cx.default_global::<DebugRegistry>();
+    registry.enabled = Some(enabled);
+    registry.windows.clear();
+    registry.painted.clear();
+    cx.refresh_windows();
+}
+
+/// Turn the overlay on if it's off, and off if it's on.
+pub fn toggle_overlay(cx: &mut App) {
+    let enabled = is_overlay_enabled(cx);
+    set_overlay(cx, !enabled);
+}
+
+/// Whether the overlay is on, from [`set_overlay`] or the
+/// `ALLUI_DEBUG_OVERLAY` environment variable until it's set.
+pub fn is_overlay_enabled(cx: &App) -> bool {
+    static FROM_ENV: OnceLock<bool> = OnceLock::new();
+    cx.try_global::<DebugRegistry>()
+        .and_then(|registry| registry.enabled)
+        .unwrap_or_else(|| {
+            *FROM_ENV.get_or_init(|| {
+                std::env::var("ALLUI_DEBUG_OVERLAY").is_ok_and(|value| value != "0")
+            })
+        })
+}
+
+/// What a wrapper does, which picks its outline's color.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum WrapperKind {
+    Padding,
+    Frame,
+    Background,
+    Tap,
+    Other,
+}
+
+impl WrapperKind {
+    fn color(self) -> Hsla {
+        match self {
+            WrapperKind::Padding => hsla(0.33, 0.8, 0.45, 0.9),
+            WrapperKind::Frame => hsla(0.6, 0.9, 0.55, 0.9),
+            WrapperKind::Background => hsla(0.08, 0.95, 0.55, 0.9),
+            WrapperKind::Tap => hsla(0.83, 0.8, 0.55, 0.9),
+            WrapperKind::Other => hsla(0., 0., 0.5, 0.6),
+        }
+    }
+}
+
+/// A wrapper laid out in the last frame.
+#[derive(Clone, Debug)]
+struct Wrapper {
+    kind: WrapperKind,
+    /// The modifier that made the wrapper, like `padding`.
+    modifier: &'static str,
+    /// What it wraps: a view's type, or a tap gesture's id.
+    source: SharedString,
+    bounds: Bounds<Pixels>,
+    /// The part of the window the wrapper isn't clipped out of.
+    visible: Bounds<Pixels>,
+}
+
+/// The wrappers laid out in each window while the overlay is on.
+#[derive(Default)]
+struct DebugRegistry {
+    enabled: Option<bool>,
+    /// Recorded this frame, in the order they were laid out: each wrapper
+    /// before those inside it.
+    windows: HashMap<WindowId, Vec<Wrapper>>,
+    /// Drawn by the overlay in the last frame, for hovering and clicking.
+    painted: HashMap<WindowId, Rc<[Wrapper]>>,
+}
+
+impl Global for DebugRegistry {}
+
+/// Record where `element`, a wrapper made by `modifier` around `source`, is
+/// laid out while the overlay is on.
+pub(crate) fn track(
+    element: impl IntoElement,
+    kind: WrapperKind,
+    modifier: &'static str,
+    source: impl FnOnce() -> SharedString,
+    cx: &App,
+) -> AnyElement {
+    if !is_overlay_enabled(cx) {
+        return element.into_any_element();
+    }
+    Tracked {
+        child: element.into_any_element(),
+        kind,
+        modifier,
+        source: source(),
+    }
+    .into_any_element()
+}
+
+/// The name of `T` without module paths, like `Modified<Text>`.
+pub(crate) fn short_type_name<T: ?Sized>() -> SharedString {
+    let name = std::any::type_name::<T>();
+    let mut short = String::with_capacity(name.len());
+    let mut path = String::new();
+    for c in name.chars() {
+        if c.is_alphanumeric() || c == '_' || c == ':' {
+            path.push(c);
+        } else {
+            short.push_str(path.rsplit("::").next().unwrap_or_default());
+            path.clear();
+            short.push(c);
+        }
+    }
+    short.push_str(path.rsplit("::").next().unwrap_or_default());
+    short.into()
+}
+
+/// Lays out its child as is, recording where.
+struct Tracked {
+    child: AnyElement,
+    kind: WrapperKind,
+    modifier: &'static str,
+    source: SharedString,
+}
+
+impl IntoElement for Tracked {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for Tracked {
+    type RequestLayoutState = ();
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn source_location(&self) -> Option<&'static std::panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        (self.child.request_layout(window, cx), ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Self::PrepaintState {
+        let window_id = window.window_handle().window_id();
+        let wrappers = cx
+            .default_global::<DebugRegistry>()
+            .windows
+            .entry(window_id)
+            .or_default();
+        // Left over when no overlay draws them
+        if wrappers.is_empty() {
+            window.on_next_frame(move |_, cx| {
+                cx.default_global::<DebugRegistry>()
+                    .windows
+                    .remove(&window_id);
+            });
+        }
+        wrappers.push(Wrapper {
+            kind: self.kind,
+            modifier: self.modifier,
+            source: self.source.clone(),
+            bounds,
+            visible: window.content_mask().bounds,
+        });
+        self.child.prepaint(window, cx);
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        _prepaint: &mut Self::PrepaintState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        self.child.paint(window, cx);
+    }
+}
+
+/// Draws the debug overlay over the window while it's on.
+///
+/// Put it last in the root view, like a
+/// [`ToastCenter`](crate::toast::ToastCenter). It draws over everything
+/// else, sheets and popovers included, and takes no clicks from them.
+#[derive(IntoElement, Default)]
+pub struct DebugOverlay {}
+
+impl DebugOverlay {
+    /// Create an overlay for the window it's drawn in.
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl RenderOnce for DebugOverlay {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let enabled = is_overlay_enabled(cx);
+        // Drawn after everything else, so every wrapper has been laid out
+        deferred(
+            canvas(
+                |_, _, _| {},
+                move |_, _, window, cx| {
+                    if enabled {
+                        paint_overlay(window, cx);
+                    }
+                },
+            )
+            .absolute()
+            .size_0(),
+        )
+        .with_priority(usize::MAX)
+    }
+}
+
+/// Outline the wrappers laid out this frame, label the one under the
+/// pointer, and log the wrappers under clicks.
+fn paint_overlay(window: &mut Window, cx: &mut App) {
+    let window_id = window.window_handle().window_id();
+    let registry = cx.default_global::<DebugRegistry>();
+    let wrappers: Rc<[Wrapper]> = registry
+        .windows
+        .remove(&window_id)
+        .unwrap_or_default()
+        .into();
+    registry.painted.insert(window_id, wrappers.clone());
+
+    for wrapper in wrappers.iter() {
+        window.with_content_mask(
+            Some(ContentMask {
+                bounds: wrapper.visible,
+            }),
+            |window| {
+                window.paint_quad(outline(
+                    wrapper.bounds,
+                    wrapper.kind.color(),
+                    BorderStyle::Solid,
+                ))
+            },
+        );
+    }
+    if let Some(hovered) = under(&wrappers, window.mouse_position()).last() {
+        paint_size_label(hovered, window, cx);
+    }
+
+    window.on_mouse_event(|_: &MouseMoveEvent, phase, window, _| {
+        if phase.bubble() {
+            window.refresh();
+        }
+    });
+    window.on_mouse_event(move |event: &MouseDownEvent, phase, _, cx| {
+        if phase.capture() {
+            if let Some(chain) = describe_chain(window_id, event.position, cx) {
+                eprintln!("{chain}");
+            }
+        }
+    });
+}
+
+/// The wrappers drawn at `position`, outermost first.
+fn under(wrappers: &[Wrapper], position: Point<Pixels>) -> impl Iterator<Item = &Wrapper> {
+    wrappers.iter().filter(move |wrapper| {
+        wrapper.bounds.contains(&position) && wrapper.visible.contains(&position)
+    })
+}
+
+/// Show the size of `wrapper` over its top leading corner.
+fn paint_size_label(wrapper: &Wrapper, window: &mut Window, cx: &mut App) {
+    let Bounds {
+        origin,
+        size: wrapper_size,
+    } = wrapper.bounds;
+    let text: SharedString = format!(
+        "{} × {}",
+        f32::from(wrapper_size.width),
+        f32::from(wrapper_size.height)
+    )
+    .into();
+    let font_size = px(10.);
+    let run = TextRun {
+        len: text.len(),
+        font: window.text_style().font(),
+        color: hsla(0., 0., 1., 1.),
+        background_color: None,
+        underline: None,
+        strikethrough: None,
+    };
+    let line = window
+        .text_system()
+        .shape_line(text, font_size, &[run], None);
+
+    let padding = px(3.);
+    let height = font_size + padding * 2.;
+    // Above the wrapper, unless that's off the top of the window
+    let y = if origin.y >= height {
+        origin.y - height
+    } else {
+        origin.y
+    };
+    let label = Bounds::new(point(origin.x, y), size(line.width + padding * 2., height));
+    window.paint_quad(fill(label, wrapper.kind.color()));
+    line.paint(
+        point(label.origin.x + padding, label.origin.y + padding),
+        font_size,
+        window,
+        cx,
+    )
+    .ok();
+}
+
+/// The wrappers drawn at `position` in the window's last frame, one per
+/// line, outermost first, or `None` if there are none.
+fn describe_chain(window_id: WindowId, position: Point<Pixels>, cx: &App) -> Option<String> {
+    let wrappers = cx.try_global::<DebugRegistry>()?.painted.get(&window_id)?;
+    let mut chain = under(wrappers, position).peekable();
+    chain.peek()?;
+    let mut description = format!(
+        "allui: modifiers at ({}, {}), outermost first:",
+        f32::from(position.x),
+        f32::from(position.y)
+    );
+    for wrapper in chain {
+        let Bounds { origin, size } = wrapper.bounds;
+        write!(
+            description,
+            "\n  {} on {}: ({}, {}) {}x{}",
+            wrapper.modifier,
+            wrapper.source,
+            f32::from(origin.x),
+            f32::from(origin.y),
+            f32::from(size.width),
+            f32::from(size.height),
+        )
+        .ok();
+    }
+    Some(description)
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::{div, Context, ParentElement, Render, TestAppContext};
+
+    use super::*;
+    use crate::layout::EmptyView;
+    use crate::modifier::Modifier;
+
+    struct Card;
+
+    impl Render for Card {
+        fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+            div()
+                .size_full()
+                .flex()
+                .items_start()
+                .child(
+                    EmptyView::new()
+                        .frame_size(40.0, 20.0)
+                        .padding(10.0)
+                        .on_tap_gesture("card", || {}),
+                )
+                .child(DebugOverlay::new())
+        }
+    }
+
+    #[test]
+    fn clicks_describe_the_wrappers_under_the_pointer() {
+        let mut cx = TestAppContext::single();
+        cx.update(|cx| set_overlay(cx, true));
+        let (_, cx) = cx.add_window_view(|_, _| Card);
+        cx.run_until_parked();
+
+        let window_id = cx.update(|window, _| window.window_handle().window_id());
+        let chain = cx.update(|_, cx| describe_chain(window_id, point(px(20.), px(20.)), cx));
+        assert_eq!(
+            chain.as_deref(),
+            Some(
+                "allui: modifiers at (20, 20), outermost first:\
+                 \n  on_tap_gesture on card: (0, 0) 60x40\
+                 \n  padding on Modified<EmptyView>: (0, 0) 60x40\
+                 \n  frame on EmptyView: (10, 10) 40x20"
+            )
+        );
+        let outside = cx.update(|_, cx| describe_chain(window_id, point(px(80.), px(20.)), cx));
+        assert_eq!(outside, None);
+    }
+
+    #[test]
+    fn nothing_is_recorded_while_the_overlay_is_off() {
+        let mut cx = TestAppContext::single();
+        cx.update(|cx| set_overlay(cx, false));
+        let (_, cx) = cx.add_window_view(|_, _| Card);
+        cx.run_until_parked();
+
+        let window_id = cx.update(|window, _| window.window_handle().window_id());
+        let chain = cx.update(|_, cx| describe_chain(window_id, point(px(20.), px(20.)), cx));
+        assert_eq!(chain, None);
+    }
+}
@@ -0,0 +1,383 @@
+//! Drag and drop - views that carry a payload and views that accept one.
+//!
+//! `.draggable()` lets a view be dragged with a payload of any type, and
+//! `.on_drop()` accepts payloads of one type, so drop targets ignore drags
+//! they can't take. `.on_file_drop()` accepts files dragged in from the
+//! system's file manager.
+//!
+//! ```rust,ignore
+//! CardView::new(&card)
+//!     .draggable(card.id)
+//!     .id(("card", card.id))
+//!
+//! Column::new(&self.done).on_drop(cx.listener(|this, id: &CardId, _, cx| {
+//!     this.move_to_done(*id);
+//!     cx.notify();
+//! }))
+//! ```
+
+use std::rc::Rc;
+
+use gpui::{
+    canvas, div, prelude::FluentBuilder, AnyElement, App, AppContext, Context, Div, DragMoveEvent,
+    ElementId, Entity, FileDropEvent, InteractiveElement, IntoElement, ParentElement, Render,
+    RenderOnce, Stateful, StatefulInteractiveElement, Styled, Window,
+};
+
+use crate::modifier::{Expansion, Modifier};
+
+/// Builds the view shown under the pointer while dragging.
+type PreviewBuilder = Rc<dyn Fn() -> AnyElement>;
+
+/// Starts a drag with the view's payload.
+type DragStarter = Box<dyn FnOnce(Stateful<Div>, Option<PreviewBuilder>) -> Stateful<Div>>;
+
+/// Called with a dropped payload.
+type DropHandler<T> = Rc<dyn Fn(&T, &mut Window, &mut App)>;
+
+/// Called with `true` when a drag the target accepts moves over it and
+/// `false` when it leaves.
+type DragOverHandler = Rc<dyn Fn(bool, &mut Window, &mut App)>;
+
+/// A view that can be dragged, created by [`Modifier::draggable`].
+///
+/// Nothing follows the pointer unless a [`drag_preview`](Self::drag_preview)
+/// is set; the view itself stays in place.
+pub struct Draggable<V> {
+    pub(crate) child: V,
+    pub(crate) child_expansion: Expansion,
+    pub(crate) id: ElementId,
+    pub(crate) start: DragStarter,
+    pub(crate) preview: Option<PreviewBuilder>,
+}
+
+impl<V> Draggable<V> {
+    /// Show the view `preview` builds under the pointer while dragging.
+    pub fn drag_preview<E: IntoElement>(mut self, preview: impl Fn() -> E + 'static) -> Self {
+        self.preview = Some(Rc::new(move || preview().into_any_element()));
+        self
+    }
+
+    /// Key the drag by `id` instead of by where the view was created. Views
+    /// made in a loop need ids of their own, or the first one takes every
+    /// drag.
+    pub fn id(mut self, id: impl Into<ElementId>) -> Self {
+        self.id = id.into();
+        self
+    }
+}
+
+/// Wrap `payload`, which a [`Draggable`] starts its drags with.
+pub(crate) fn drag_starter<T: 'static>(payload: T) -> DragStarter {
+    Box::new(move |container, preview| {
+        container.on_drag(payload, move |_, _, _, cx| {
+            cx.new(|_| DragPreview {
+                preview: preview.clone(),
+            })
+        })
+    })
+}
+
+impl<V> Modifier for Draggable<V> {
+    fn expansion(&self) -> Expansion {
+        self.child_expansion
+    }
+}
+
+impl<V: IntoElement + 'static> IntoElement for Draggable<V> {
+    type Element = AnyElement;
+
+    fn into_element(self) -> Self::Element {
+        DraggableElement { draggable: self }.into_any_element()
+    }
+}
+
+#[derive(IntoElement)]
+struct DraggableElement<V: IntoElement + 'static> {
+    draggable: Draggable<V>,
+}
+
+impl<V: IntoElement + 'static> RenderOnce for DraggableElement<V> {
+    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+        let Draggable {
+            child,
+            child_expansion: expansion,
+            id,
+            start,
+            preview,
+        } = self.draggable;
+
+        let container = div()
+            .id(id)
+            .cursor_grab()
+            .when(expansion.grows, |container| container.flex_grow())
+            .when(expansion.fills_width, |container| container.w_full())
+            .when(expansion.fills_height, |container| container.h_full());
+        start(container, preview).child(child)
+    }
+}
+
+/// The view GPUI shows under the pointer while dragging.
+struct DragPreview {
+    preview: Option<PreviewBuilder>,
+}
+
+impl Render for DragPreview {
+    fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+        div().children(self.preview.as_ref().map(|preview| preview()))
+    }
+}
+
+/// A view that accepts dropped payloads of type `T`, created by
+/// [`Modifier::on_drop`].
+pub struct DropTarget<V, T> {
+    pub(crate) child: V,
+    pub(crate) child_expansion: Expansion,
+    pub(crate) id: ElementId,
+    pub(crate) on_drop: DropHandler<T>,
+    pub(crate) on_drag_over: Option<DragOverHandler>,
+}
+
+impl<V, T: 'static> DropTarget<V, T> {
+    /// Call `handler` with `true` when a `T` is dragged over the view and
+    /// `false` when it leaves or is dropped, to highlight the target.
+    pub fn on_drag_over(mut self, handler: impl Fn(bool, &mut Window, &mut App) + 'static) -> Self {
+        self.on_drag_over = Some(Rc::new(handler));
+        self
+    }
+
+    /// Keep whether a drag is over the view by `id` instead of by where it
+    /// was created, for targets made in a loop.
+    pub fn id(mut self, id: impl Into<ElementId>) -> Self {
+        self.id = id.into();
+        self
+    }
+}
+
+impl<V, T> Modifier for DropTarget<V, T> {
+    fn expansion(&self) -> Expansion {
+        self.child_expansion
+    }
+}
+
+impl<V: IntoElement + 'static, T: 'static> IntoElement for DropTarget<V, T> {
+    type Element = AnyElement;
+
+    fn into_element(self) -> Self::Element {
+        DropTargetElement { target: self }.into_any_element()
+    }
+}
+
+#[derive(IntoElement)]
+struct DropTargetElement<V: IntoElement + 'static, T: 'static> {
+    target: DropTarget<V, T>,
+}
+
+impl<V: IntoElement + 'static, T: 'static> RenderOnce for DropTargetElement<V, T> {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let DropTarget {
+            child,
+            child_expansion: expansion,
+            id,
+            on_drop,
+            on_drag_over,
+        } = self.target;
+
+        let container = div()
+            .when(expansion.grows, |container| container.flex_grow())
+            .when(expansion.fills_width, |container| container.w_full())
+            .when(expansion.fills_height, |container| container.h_full());
+        // Whether a drag was over the view, so the handler only hears about
+        // changes
+        let over = on_drag_over
+            .as_ref()
+            .map(|_| window.use_keyed_state(id, cx, |_, _| false));
+        let container = match (over.clone(), on_drag_over.clone()) {
+            (Some(over), Some(on_drag_over)) => {
+                let exited = (over.clone(), on_drag_over.clone());
+                container
+                    .on_drag_move(move |event: &DragMoveEvent<T>, window, cx| {
+                        let is_over = event.bounds.contains(&event.event.position);
+                        if set_over(&over, is_over, cx) {
+                            on_drag_over(is_over, window, cx);
+                        }
+                    })
+                    // Files dragged out of the window end the drag without
+                    // a last move
+                    .child(
+                        canvas(
+                            |_, _, _| {},
+                            move |_, _, window, _| {
+                                window.on_mouse_event(
+                                    move |event: &FileDropEvent, _, window, cx| {
+                                        let (over, on_drag_over) = &exited;
+                                        if matches!(event, FileDropEvent::Exited)
+                                            && set_over(over, false, cx)
+                                        {
+                                            on_drag_over(false, window, cx);
+                                        }
+                                    },
+                                )
+                            },
+                        )
+                        .absolute(),
+                    )
+            }
+            _ => container,
+        };
+        container
+            .on_drop(move |payload: &T, window, cx| {
+                if let (Some(over), Some(on_drag_over)) = (&over, &on_drag_over) {
+                    if set_over(over, false, cx) {
+                        on_drag_over(false, window, cx);
+                    }
+                }
+                on_drop(payload, window, cx);
+            })
+            .child(child)
+    }
+}
+
+/// Record whether a drag is over a drop target, returning whether that
+/// changed.
+fn set_over(state: &Entity<bool>, is_over: bool, cx: &mut App) -> bool {
+    state.update(cx, |over, _| std::mem::replace(over, is_over) != is_over)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use gpui::{
+        point, px, ExternalPaths, Modifiers, MouseButton, MouseDownEvent, MouseMoveEvent,
+        MouseUpEvent, Pixels, Point, TestAppContext, VisualTestContext,
+    };
+
+    use super::*;
+    use crate::layout::{EmptyView, HStack};
+
+    /// What the drop targets heard, in order.
+    type Heard = Rc<RefCell<Vec<String>>>;
+
+    /// A 50pt square dragging `7u32`, then targets for `u32` and `String`.
+    struct Board {
+        heard: Heard,
+    }
+
+    fn square() -> impl Modifier + IntoElement {
+        EmptyView::new().frame_size(50.0, 50.0)
+    }
+
+    impl Render for Board {
+        fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+            let (dropped, over, text) =
+                (self.heard.clone(), self.heard.clone(), self.heard.clone());
+            HStack::new()
+                .spacing(0.0)
+                .child(square().draggable(7u32))
+                .child(
+                    square()
+                        .on_drop(move |value: &u32, _, _| {
+                            dropped.borrow_mut().push(format!("dropped {value}"))
+                        })
+                        .on_drag_over(move |is_over, _, _| {
+                            over.borrow_mut().push(format!("over {is_over}"))
+                        }),
+                )
+                .child(square().on_drop(move |value: &String, _, _| {
+                    text.borrow_mut().push(format!("dropped {value}"))
+                }))
+        }
+    }
+
+    fn drag(cx: &mut VisualTestContext, to: Point<Pixels>) {
+        let from = point(px(25.), px(25.));
+        cx.simulate_event(MouseDownEvent {
+            button: MouseButton::Left,
+            position: from,
+            modifiers: Modifiers::default(),
+            click_count: 1,
+            first_mouse: false,
+        });
+        // The first move starts the drag, the second moves it over a target
+        for position in [point(px(35.), px(25.)), to] {
+            cx.simulate_event(MouseMoveEvent {
+                position,
+                pressed_button: Some(MouseButton::Left),
+                modifiers: Modifiers::default(),
+            });
+        }
+        cx.simulate_event(MouseUpEvent {
+            button: MouseButton::Left,
+            position: to,
+            modifiers: Modifiers::default(),
+            click_count: 1,
+        });
+    }
+
+    #[test]
+    fn payloads_drop_only_on_targets_of_their_type() {
+        let heard = Heard::default();
+        let mut cx = TestAppContext::single();
+        let (_, cx) = cx.add_window_view(|_, _| Board {
+            heard: heard.clone(),
+        });
+
+        drag(cx, point(px(125.), px(25.)));
+        assert!(heard.borrow().is_empty());
+
+        drag(cx, point(px(75.), px(25.)));
+        assert_eq!(*heard.borrow(), ["over true", "over false", "dropped 7"]);
+    }
+
+    /// A drop zone for files, recording what it heard.
+    struct DropZone {
+        heard: Heard,
+    }
+
+    impl Render for DropZone {
+        fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+            let (dropped, over) = (self.heard.clone(), self.heard.clone());
+            square()
+                .on_file_drop(move |paths, _, _| {
+                    dropped
+                        .borrow_mut()
+                        .push(format!("dropped {}", paths.len()))
+                })
+                .on_drag_over(move |is_over, _, _| {
+                    over.borrow_mut().push(format!("over {is_over}"))
+                })
+        }
+    }
+
+    #[test]
+    fn files_leaving_the_window_unhighlight_drop_zones() {
+        let heard = Heard::default();
+        let mut cx = TestAppContext::single();
+        let (_, cx) = cx.add_window_view(|_, _| DropZone {
+            heard: heard.clone(),
+        });
+        let position = point(px(25.), px(25.));
+
+        cx.simulate_event(FileDropEvent::Entered {
+            position,
+            paths: ExternalPaths::default(),
+        });
+        cx.simulate_event(FileDropEvent::Exited);
+        cx.simulate_event(FileDropEvent::Entered {
+            position,
+            paths: ExternalPaths::default(),
+        });
+        cx.simulate_event(FileDropEvent::Submit { position });
+        assert_eq!(
+            *heard.borrow(),
+            [
+                "over true",
+                "over false",
+                "over true",
+                "over false",
+                "dropped 0"
+            ]
+        );
+    }
+}
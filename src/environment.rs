@@ -0,0 +1,657 @@
+//! Environment - render-scoped values inherited by nested views.
+//!
+//! SwiftUI propagates values like `foregroundColor` down the view tree through
+//! its environment. GPUI renders `RenderOnce` components lazily while their
+//! parent lays out its children, so a value pushed around that layout pass is
+//! visible to every component rendered inside it.
+//!
+//! Apps can pass their own values down the same way: `.environment(value)`
+//! sets a value of its type for the views inside, which read the nearest
+//! one with [`Environment::get`].
+
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use gpui::{
+    px, AnyElement, App, Axis, Bounds, Element, ElementId, GlobalElementId, InspectorElementId,
+    IntoElement, LayoutId, Pixels, Window,
+};
+
+use gpui_component::ActiveTheme;
+
+use crate::accessibility::AccessibilitySlot;
+use crate::focus::FocusSlot;
+use crate::layout::{EdgeSet, ScrollViewProxy, ToolbarSlot};
+use crate::shortcut::ShortcutSlot;
+use crate::style::{Color, ColorScheme};
+
+/// Stacks of values pushed by enclosing environment modifiers.
+#[derive(Default)]
+struct EnvironmentValues {
+    /// Values set with `.environment()` and the built-in keys, by type.
+    values: HashMap<TypeId, Vec<Rc<dyn Any>>>,
+    default_text_scale: Option<f32>,
+    /// Text sizes set by enclosing components that scaled their text, as
+    /// `(unscaled, scaled)`.
+    scaled_text: Vec<(Pixels, Pixels)>,
+    /// Main axes of enclosing stacks.
+    stack_axis: Vec<Axis>,
+    /// Proxies of enclosing tracked scroll views.
+    scroll_proxy: Vec<ScrollViewProxy>,
+    /// Whether views are laid out as the rows of a `Form`.
+    form: Vec<bool>,
+    /// Where enclosing navigation stacks collect toolbar items.
+    toolbar: Vec<ToolbarSlot>,
+    /// Keystrokes of enclosing keyboard shortcuts, for the first control
+    /// inside to bind.
+    keyboard_shortcut: Vec<ShortcutSlot>,
+    /// Focus handles bound by enclosing `.focused()`, for the first text
+    /// field inside to pass on to its input.
+    focus: Vec<FocusSlot>,
+    /// Nodes set by enclosing accessibility modifiers, for the first
+    /// control inside to merge with its own.
+    accessibility: Vec<AccessibilitySlot>,
+}
+
+impl gpui::Global for EnvironmentValues {}
+
+/// Reads values passed down the view tree, like SwiftUI's `@Environment`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// #[derive(Clone)]
+/// struct Accent(Color);
+///
+/// // In a parent
+/// Card::new().environment(Accent(Color::orange()))
+///
+/// // In a RenderOnce rendered inside it
+/// let accent = Environment::get::<Accent>(cx).map_or(Color::blue(), |a| a.0);
+/// ```
+pub struct Environment;
+
+impl Environment {
+    /// The value of type `T` set by the nearest enclosing
+    /// `.environment()`, or `None` outside of one.
+    ///
+    /// Only views rendered inside the modifier see the value, not their
+    /// siblings.
+    pub fn get<T: Clone + 'static>(cx: &App) -> Option<T> {
+        cx.try_global::<EnvironmentValues>()?
+            .values
+            .get(&TypeId::of::<T>())?
+            .last()?
+            .downcast_ref::<T>()
+            .cloned()
+    }
+}
+
+/// The color set by `.foreground_color()`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ForegroundColor(pub Color);
+
+/// The accent color set by `.tint()`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tint(pub Color);
+
+/// Whether controls accept input, cleared by `.disabled(true)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IsEnabled(pub bool);
+
+/// The text scale factor set by `.text_scale()`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TextScale(pub f32);
+
+/// A color scheme views use in place of the app's.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ColorSchemeOverride(pub ColorScheme);
+
+/// Space at the window's edges covered by its chrome, such as a transparent
+/// titlebar, set by `.window_chrome()`.
+///
+/// `.safe_area_padding()` pads a view by these insets, and the views inside
+/// it see no insets left. `.ignores_safe_area()` gives them back.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct SafeAreaInsets {
+    pub top: f32,
+    pub leading: f32,
+    pub bottom: f32,
+    pub trailing: f32,
+    /// Width the window controls (the traffic lights) cover at the leading
+    /// end of the top inset, for views laid out in the titlebar itself.
+    pub window_controls: f32,
+}
+
+impl SafeAreaInsets {
+    /// These insets on `edges` and none on the others.
+    pub(crate) fn only(self, edges: EdgeSet) -> Self {
+        Self {
+            top: if edges.top { self.top } else { 0.0 },
+            leading: if edges.leading { self.leading } else { 0.0 },
+            bottom: if edges.bottom { self.bottom } else { 0.0 },
+            trailing: if edges.trailing { self.trailing } else { 0.0 },
+            window_controls: if edges.top { self.window_controls } else { 0.0 },
+        }
+    }
+
+    /// Both insets added together, edge by edge.
+    pub(crate) fn add(self, other: Self) -> Self {
+        Self {
+            top: self.top + other.top,
+            leading: self.leading + other.leading,
+            bottom: self.bottom + other.bottom,
+            trailing: self.trailing + other.trailing,
+            window_controls: self.window_controls + other.window_controls,
+        }
+    }
+}
+
+/// The safe area insets enclosing `.safe_area_padding()` modifiers already
+/// padded, which `.ignores_safe_area()` extends back over.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct PaddedSafeArea(pub SafeAreaInsets);
+
+/// A value set for a subtree with `.environment()`, keyed by its type.
+#[derive(Clone)]
+pub struct EnvironmentValue {
+    type_id: TypeId,
+    value: Rc<dyn Any>,
+}
+
+impl EnvironmentValue {
+    pub(crate) fn new<T: 'static>(value: T) -> Self {
+        Self {
+            type_id: TypeId::of::<T>(),
+            value: Rc::new(value),
+        }
+    }
+}
+
+/// The foreground color set by the nearest enclosing `.foreground_color()`.
+///
+/// Components that resolve their own colors (icons, links, button labels)
+/// use this as a default when no explicit color was set on them.
+pub fn foreground_color(cx: &App) -> Option<Color> {
+    Environment::get::<ForegroundColor>(cx).map(|ForegroundColor(color)| color)
+}
+
+/// The color scheme views resolve semantic colors for: the nearest
+/// enclosing `.color_scheme()`, or the theme's.
+pub fn color_scheme(cx: &App) -> ColorScheme {
+    match Environment::get::<ColorSchemeOverride>(cx) {
+        Some(ColorSchemeOverride(scheme)) => scheme,
+        None if cx.theme().is_dark() => ColorScheme::Dark,
+        None => ColorScheme::Light,
+    }
+}
+
+/// Whether controls inside are enabled: false inside any `.disabled(true)`.
+pub fn is_enabled(cx: &App) -> bool {
+    Environment::get::<IsEnabled>(cx).map_or(true, |IsEnabled(enabled)| enabled)
+}
+
+/// The safe area insets left for the current subtree: those of the nearest
+/// enclosing `.window_chrome()`, less what `.safe_area_padding()` already
+/// padded. None outside a window chrome.
+pub fn safe_area_insets(cx: &App) -> SafeAreaInsets {
+    Environment::get::<SafeAreaInsets>(cx).unwrap_or_default()
+}
+
+/// The safe area insets enclosing `.safe_area_padding()` modifiers padded.
+pub(crate) fn padded_safe_area(cx: &App) -> SafeAreaInsets {
+    Environment::get::<PaddedSafeArea>(cx).map_or_else(SafeAreaInsets::default, |padded| padded.0)
+}
+
+/// The text scale factor for the current subtree (default 1.0).
+///
+/// Set by the nearest enclosing `.text_scale()`, falling back to the
+/// app-wide value from [`set_default_text_scale`].
+pub fn text_scale(cx: &App) -> f32 {
+    Environment::get::<TextScale>(cx)
+        .map(|TextScale(factor)| factor)
+        .or_else(|| cx.try_global::<EnvironmentValues>()?.default_text_scale)
+        .unwrap_or(1.0)
+}
+
+/// Set the app-wide text scale factor used outside any `.text_scale()`.
+///
+/// Call `cx.refresh_windows()` afterwards to re-render with the new scale.
+pub fn set_default_text_scale(cx: &mut App, factor: f32) {
+    cx.default_global::<EnvironmentValues>().default_text_scale = Some(factor);
+}
+
+/// The main axis of the nearest enclosing `HStack` or `VStack`.
+///
+/// Lets a `Spacer` apply its minimum length along the stack it is in.
+pub(crate) fn stack_axis(cx: &App) -> Option<Axis> {
+    cx.try_global::<EnvironmentValues>()
+        .and_then(|values| values.stack_axis.last().copied())
+}
+
+/// Whether the nearest enclosing list is a `Form`.
+///
+/// Controls restyle themselves as form rows, like toggles spreading their
+/// label and switch across the row.
+pub(crate) fn in_form(cx: &App) -> bool {
+    cx.try_global::<EnvironmentValues>()
+        .and_then(|values| values.form.last().copied())
+        .unwrap_or(false)
+}
+
+/// Where the nearest enclosing `NavigationStack` collects the toolbar its
+/// showing screen declares.
+pub(crate) fn toolbar_slot(cx: &App) -> Option<ToolbarSlot> {
+    cx.try_global::<EnvironmentValues>()
+        .and_then(|values| values.toolbar.last().cloned())
+}
+
+/// Where the nearest enclosing `.keyboard_shortcut()` hands over its
+/// keystroke.
+pub(crate) fn keyboard_shortcut_slot(cx: &App) -> Option<ShortcutSlot> {
+    cx.try_global::<EnvironmentValues>()
+        .and_then(|values| values.keyboard_shortcut.last().cloned())
+}
+
+/// Where the nearest enclosing `.focused()` hands over its focus handle.
+pub(crate) fn focus_slot(cx: &App) -> Option<FocusSlot> {
+    cx.try_global::<EnvironmentValues>()
+        .and_then(|values| values.focus.last().cloned())
+}
+
+/// Where the nearest enclosing accessibility modifiers hand over their node.
+pub(crate) fn accessibility_slot(cx: &App) -> Option<AccessibilitySlot> {
+    cx.try_global::<EnvironmentValues>()
+        .and_then(|values| values.accessibility.last().cloned())
+}
+
+/// The proxy of the nearest enclosing tracked `ScrollView`.
+///
+/// Views tagged with `.scroll_id()` record their bounds with it.
+pub(crate) fn scroll_proxy(cx: &App) -> Option<ScrollViewProxy> {
+    cx.try_global::<EnvironmentValues>()
+        .and_then(|values| values.scroll_proxy.last().cloned())
+}
+
+/// The font size a text component should render at, scaled by [`text_scale`].
+///
+/// Returns `None` when the inherited size can be used unchanged. Components
+/// without an explicit size scale the size inherited from their parent,
+/// before any enclosing component scaled it, so nested components don't
+/// compound the scale.
+pub(crate) fn scaled_text_size(explicit: Option<f32>, window: &Window, cx: &App) -> Option<Pixels> {
+    let scale = text_scale(cx);
+    match explicit {
+        Some(size) => Some(px(size * scale)),
+        None if scale != 1.0 => Some(unscaled_inherited_size(window, cx) * scale),
+        None => None,
+    }
+}
+
+/// The unscaled size behind [`scaled_text_size`], for
+/// [`EnvironmentScope::scaled_text`] around the component's children.
+pub(crate) fn unscaled_text_size(
+    explicit: Option<f32>,
+    window: &Window,
+    cx: &App,
+) -> Option<Pixels> {
+    match explicit {
+        Some(size) => Some(px(size)),
+        None if text_scale(cx) != 1.0 => Some(unscaled_inherited_size(window, cx)),
+        None => None,
+    }
+}
+
+/// The inherited font size, before an enclosing component scaled it.
+fn unscaled_inherited_size(window: &Window, cx: &App) -> Pixels {
+    let inherited = window.text_style().font_size.to_pixels(window.rem_size());
+    let enclosing = cx
+        .try_global::<EnvironmentValues>()
+        .and_then(|values| values.scaled_text.last().copied());
+    unscaled(inherited, enclosing)
+}
+
+/// `inherited` before the scaling of the nearest enclosing scaled component,
+/// unless something in between set a size of its own.
+fn unscaled(inherited: Pixels, enclosing: Option<(Pixels, Pixels)>) -> Pixels {
+    match enclosing {
+        Some((unscaled, scaled)) if scaled == inherited => unscaled,
+        _ => inherited,
+    }
+}
+
+/// Wraps an element and exposes environment values while it renders.
+///
+/// Values are pushed for the duration of the child's layout and prepaint,
+/// which is when nested components (including lazily rendered list rows)
+/// are rendered.
+pub(crate) struct EnvironmentScope {
+    values: Vec<EnvironmentValue>,
+    scaled_text: Option<(Pixels, Pixels)>,
+    stack_axis: Option<Axis>,
+    scroll_proxy: Option<ScrollViewProxy>,
+    form: Option<bool>,
+    toolbar: Option<ToolbarSlot>,
+    keyboard_shortcut: Option<ShortcutSlot>,
+    focus: Option<FocusSlot>,
+    accessibility: Option<AccessibilitySlot>,
+    child: AnyElement,
+}
+
+impl EnvironmentScope {
+    pub(crate) fn new(child: impl IntoElement) -> Self {
+        Self {
+            values: Vec::new(),
+            scaled_text: None,
+            stack_axis: None,
+            scroll_proxy: None,
+            form: None,
+            toolbar: None,
+            keyboard_shortcut: None,
+            focus: None,
+            accessibility: None,
+            child: child.into_any_element(),
+        }
+    }
+
+    /// Set a value for the child to read with [`Environment::get`].
+    pub(crate) fn value(mut self, value: Option<EnvironmentValue>) -> Self {
+        self.values.extend(value);
+        self
+    }
+
+    pub(crate) fn foreground(self, color: Option<Color>) -> Self {
+        self.value(color.map(|color| EnvironmentValue::new(ForegroundColor(color))))
+    }
+
+    pub(crate) fn text_scale(self, factor: Option<f32>) -> Self {
+        self.value(factor.map(|factor| EnvironmentValue::new(TextScale(factor))))
+    }
+
+    /// Set the safe area insets left for the child, and those padded around
+    /// it.
+    pub(crate) fn safe_area(self, safe_area: Option<(SafeAreaInsets, SafeAreaInsets)>) -> Self {
+        let (insets, padded) = safe_area.unzip();
+        self.value(insets.map(EnvironmentValue::new))
+            .value(padded.map(|padded| EnvironmentValue::new(PaddedSafeArea(padded))))
+    }
+
+    /// Record that the child inherits `scaled`, the text scale applied to
+    /// `unscaled`, so components inside scale from `unscaled` instead.
+    pub(crate) fn scaled_text(mut self, unscaled: Option<Pixels>, scaled: Option<Pixels>) -> Self {
+        self.scaled_text = unscaled.zip(scaled);
+        self
+    }
+
+    /// Set the main axis of the stack the child lays out.
+    pub(crate) fn stack_axis(mut self, axis: Axis) -> Self {
+        self.stack_axis = Some(axis);
+        self
+    }
+
+    /// Set the proxy of the scroll view the child scrolls.
+    pub(crate) fn scroll_proxy(mut self, proxy: Option<ScrollViewProxy>) -> Self {
+        self.scroll_proxy = proxy;
+        self
+    }
+
+    /// Mark whether the child is laid out as form rows.
+    pub(crate) fn form(mut self, in_form: bool) -> Self {
+        self.form = Some(in_form);
+        self
+    }
+
+    /// Collect toolbar items declared by the child in `slot`.
+    pub(crate) fn toolbar(mut self, slot: ToolbarSlot) -> Self {
+        self.toolbar = Some(slot);
+        self
+    }
+
+    /// Offer the first control rendered in the child the keystroke in
+    /// `slot`.
+    pub(crate) fn keyboard_shortcut(mut self, slot: Option<ShortcutSlot>) -> Self {
+        self.keyboard_shortcut = slot;
+        self
+    }
+
+    /// Offer the first text field rendered in the child the focus handle
+    /// in `slot`.
+    pub(crate) fn focus(mut self, slot: Option<FocusSlot>) -> Self {
+        self.focus = slot;
+        self
+    }
+
+    /// Offer the first control rendered in the child the accessibility
+    /// node in `slot`.
+    pub(crate) fn accessibility(mut self, slot: Option<AccessibilitySlot>) -> Self {
+        self.accessibility = slot;
+        self
+    }
+
+    fn scoped<R>(&mut self, cx: &mut App, f: impl FnOnce(&mut AnyElement, &mut App) -> R) -> R {
+        if self.values.is_empty()
+            && self.scaled_text.is_none()
+            && self.stack_axis.is_none()
+            && self.scroll_proxy.is_none()
+            && self.form.is_none()
+            && self.toolbar.is_none()
+            && self.keyboard_shortcut.is_none()
+            && self.focus.is_none()
+            && self.accessibility.is_none()
+        {
+            return f(&mut self.child, cx);
+        }
+
+        let values = cx.default_global::<EnvironmentValues>();
+        for value in &self.values {
+            values
+                .values
+                .entry(value.type_id)
+                .or_default()
+                .push(value.value.clone());
+        }
+        values.scaled_text.extend(self.scaled_text);
+        values.stack_axis.extend(self.stack_axis);
+        values.scroll_proxy.extend(self.scroll_proxy.clone());
+        values.form.extend(self.form);
+        values.toolbar.extend(self.toolbar.clone());
+        values
+            .keyboard_shortcut
+            .extend(self.keyboard_shortcut.clone());
+        values.focus.extend(self.focus.clone());
+        values.accessibility.extend(self.accessibility.clone());
+
+        let result = f(&mut self.child, cx);
+
+        let values = cx.global_mut::<EnvironmentValues>();
+        for value in &self.values {
+            if let Some(stack) = values.values.get_mut(&value.type_id) {
+                stack.pop();
+            }
+        }
+        if self.scaled_text.is_some() {
+            values.scaled_text.pop();
+        }
+        if self.stack_axis.is_some() {
+            values.stack_axis.pop();
+        }
+        if self.scroll_proxy.is_some() {
+            values.scroll_proxy.pop();
+        }
+        if self.form.is_some() {
+            values.form.pop();
+        }
+        if self.toolbar.is_some() {
+            values.toolbar.pop();
+        }
+        if self.keyboard_shortcut.is_some() {
+            values.keyboard_shortcut.pop();
+        }
+        if self.focus.is_some() {
+            values.focus.pop();
+        }
+        if self.accessibility.is_some() {
+            values.accessibility.pop();
+        }
+        result
+    }
+}
+
+impl IntoElement for EnvironmentScope {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for EnvironmentScope {
+    type RequestLayoutState = ();
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn source_location(&self) -> Option<&'static std::panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let layout_id = self.scoped(cx, |child, cx| child.request_layout(window, cx));
+        (layout_id, ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Self::PrepaintState {
+        self.scoped(cx, |child, cx| {
+            child.prepaint(window, cx);
+        });
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        _prepaint: &mut Self::PrepaintState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        self.child.paint(window, cx);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use gpui::{div, Context, ParentElement, Render, RenderOnce, TestAppContext};
+
+    use super::*;
+    use crate::modifier::Modifier;
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Depth(u32);
+
+    type Seen = Rc<RefCell<Vec<(&'static str, Option<Depth>, bool)>>>;
+
+    /// Records the values it sees, then renders its children.
+    #[derive(IntoElement)]
+    struct Probe {
+        name: &'static str,
+        seen: Seen,
+        children: Vec<AnyElement>,
+    }
+
+    impl Probe {
+        fn new(name: &'static str, seen: &Seen) -> Self {
+            Self {
+                name,
+                seen: seen.clone(),
+                children: Vec::new(),
+            }
+        }
+
+        fn child(mut self, child: impl IntoElement) -> Self {
+            self.children.push(child.into_any_element());
+            self
+        }
+    }
+
+    impl Modifier for Probe {}
+
+    impl RenderOnce for Probe {
+        fn render(self, _: &mut Window, cx: &mut App) -> impl IntoElement {
+            self.seen
+                .borrow_mut()
+                .push((self.name, Environment::get::<Depth>(cx), is_enabled(cx)));
+            div().children(self.children)
+        }
+    }
+
+    struct ProbeView(Seen);
+
+    impl Render for ProbeView {
+        fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+            let seen = &self.0;
+            div()
+                .child(
+                    Probe::new("outer", seen)
+                        .child(Probe::new("shadowed", seen).environment(Depth(2)))
+                        .child(Probe::new("inherited", seen))
+                        .child(Probe::new("reenabled", seen).disabled(false))
+                        .environment(Depth(1))
+                        .disabled(true),
+                )
+                .child(Probe::new("sibling", seen))
+        }
+    }
+
+    fn seen_by(seen: &Seen, name: &str) -> (Option<Depth>, bool) {
+        let seen = seen.borrow();
+        let (_, depth, enabled) = seen.iter().find(|(probe, ..)| *probe == name).unwrap();
+        (depth.clone(), *enabled)
+    }
+
+    #[test]
+    fn values_are_scoped_to_the_views_inside() {
+        let seen = Seen::default();
+        let mut cx = TestAppContext::single();
+        cx.add_window(|_, _| ProbeView(seen.clone()));
+        cx.run_until_parked();
+
+        assert_eq!(seen_by(&seen, "outer"), (Some(Depth(1)), false));
+        assert_eq!(seen_by(&seen, "shadowed"), (Some(Depth(2)), false));
+        assert_eq!(seen_by(&seen, "inherited"), (Some(Depth(1)), false));
+        assert_eq!(seen_by(&seen, "reenabled"), (Some(Depth(1)), false));
+        assert_eq!(seen_by(&seen, "sibling"), (None, true));
+        cx.update(|cx| assert_eq!(Environment::get::<Depth>(cx), None));
+    }
+
+    #[test]
+    fn nested_components_scale_once() {
+        // A button scaled 16pt text to 24pt; a label inside inherits 24pt
+        let button = Some((px(16.0), px(24.0)));
+        assert_eq!(unscaled(px(24.0), button), px(16.0));
+
+        // A view in between set its own size, which isn't scaled yet
+        assert_eq!(unscaled(px(12.0), button), px(12.0));
+        assert_eq!(unscaled(px(14.0), None), px(14.0));
+    }
+}
@@ -0,0 +1,250 @@
+//! Focus - tab stops, focus rings and app-held focus handles.
+//!
+//! `.focusable()` gives a view a tab stop of its own, and `.focused()` binds
+//! one the app holds, so `handle.focus(window)` can move focus from code.
+//! Tab and Shift-Tab move through tab stops in the order they are declared.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use gpui::{
+    div, prelude::FluentBuilder, px, AnyElement, App, Context, ElementId, Entity, FocusHandle,
+    InteractiveElement, IntoElement, MouseButton, ParentElement, RenderOnce, Styled, Subscription,
+    Window,
+};
+use gpui_component::input::InputState;
+
+use crate::environment::{self, EnvironmentScope};
+use crate::modifier::{Expansion, Modifier};
+use crate::style::Color;
+
+/// Called with `true` when a view gains focus and `false` when it loses it.
+pub(crate) type FocusHandler = Rc<dyn Fn(bool, &mut Window, &mut App)>;
+
+/// Where [`Modifier::focused`] offers its handle to the first text field
+/// rendered inside it.
+pub(crate) type FocusSlot = Rc<RefCell<Option<FocusHandle>>>;
+
+/// How far the focus ring sits outside the view, in points.
+const RING_OFFSET: f32 = 2.0;
+
+/// A view that can take focus, created by [`Modifier::focusable`],
+/// [`Modifier::focused`] or [`Modifier::on_focus_change`].
+pub struct FocusContent<V> {
+    pub(crate) child: V,
+    pub(crate) child_expansion: Expansion,
+    /// Keeps the view's own handle when it isn't bound to one.
+    pub(crate) id: ElementId,
+    pub(crate) handle: Option<FocusHandle>,
+    pub(crate) on_focus_change: Option<FocusHandler>,
+}
+
+impl<V> FocusContent<V> {
+    /// Bind the view to `handle` instead of a tab stop of its own.
+    pub fn focused(mut self, handle: &FocusHandle) -> Self {
+        self.handle = Some(handle.clone());
+        self
+    }
+
+    /// Call `handler` with `true` when the view gains focus and `false`
+    /// when it loses it.
+    pub fn on_focus_change(
+        mut self,
+        handler: impl Fn(bool, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_focus_change = Some(Rc::new(handler));
+        self
+    }
+}
+
+impl<V> Modifier for FocusContent<V> {
+    fn expansion(&self) -> Expansion {
+        self.child_expansion
+    }
+}
+
+impl<V: IntoElement + 'static> IntoElement for FocusContent<V> {
+    type Element = AnyElement;
+
+    fn into_element(self) -> Self::Element {
+        FocusContentElement { content: self }.into_any_element()
+    }
+}
+
+#[derive(IntoElement)]
+struct FocusContentElement<V: IntoElement + 'static> {
+    content: FocusContent<V>,
+}
+
+impl<V: IntoElement + 'static> RenderOnce for FocusContentElement<V> {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let FocusContent {
+            child,
+            child_expansion: expansion,
+            id,
+            handle: bound,
+            on_focus_change,
+        } = self.content;
+
+        // A bound handle keys its own state, so rebinding starts afresh
+        let key = bound.as_ref().map_or(id, ElementId::from);
+        let state = window.use_keyed_state(key, cx, {
+            let bound = bound.clone();
+            move |window, cx| {
+                let handle = bound.unwrap_or_else(|| cx.focus_handle().tab_stop(true));
+                FocusState::new(handle, window, cx)
+            }
+        });
+        let handle = state.update(cx, |state, _| {
+            state.on_focus_change = on_focus_change;
+            state.handle.clone()
+        });
+
+        // Text fields inside take a bound handle and pass focus on to
+        // their input
+        let slot = bound.map(|handle| Rc::new(RefCell::new(Some(handle))));
+        let ring = FocusRing {
+            state: state.clone(),
+            slot: slot.clone(),
+        };
+
+        div()
+            .relative()
+            .track_focus(&handle)
+            .on_mouse_down(MouseButton::Left, move |_, _, cx| {
+                state.update(cx, |state, _| state.clicked = true);
+            })
+            .when(expansion.grows, |container| container.flex_grow())
+            .when(expansion.fills_width, |container| container.w_full())
+            .when(expansion.fills_height, |container| container.h_full())
+            .child(EnvironmentScope::new(child).focus(slot))
+            .child(ring)
+    }
+}
+
+/// Kept across frames for each focusable view.
+struct FocusState {
+    handle: FocusHandle,
+    /// Whether the view was focused by clicking it, which shows no ring.
+    clicked: bool,
+    on_focus_change: Option<FocusHandler>,
+    _subscriptions: [Subscription; 2],
+}
+
+impl FocusState {
+    fn new(handle: FocusHandle, window: &mut Window, cx: &mut Context<Self>) -> Self {
+        let focus_in = cx.on_focus_in(&handle, window, |this, window, cx| {
+            if let Some(on_focus_change) = this.on_focus_change.clone() {
+                on_focus_change(true, window, cx);
+            }
+        });
+        let focus_out = cx.on_focus_out(&handle, window, |this, _, window, cx| {
+            this.clicked = false;
+            if let Some(on_focus_change) = this.on_focus_change.clone() {
+                on_focus_change(false, window, cx);
+            }
+        });
+        Self {
+            handle,
+            clicked: false,
+            on_focus_change: None,
+            _subscriptions: [focus_in, focus_out],
+        }
+    }
+}
+
+/// A theme-colored outline around a view focused from the keyboard.
+///
+/// Rendered after the view, so it knows whether a text field inside took
+/// the focus, which draws a ring of its own.
+#[derive(IntoElement)]
+struct FocusRing {
+    state: Entity<FocusState>,
+    slot: Option<FocusSlot>,
+}
+
+impl RenderOnce for FocusRing {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let state = self.state.read(cx);
+        let forwarded = self.slot.is_some_and(|slot| slot.borrow().is_none());
+        let visible = state.handle.is_focused(window) && !state.clicked && !forwarded;
+
+        div()
+            .absolute()
+            .top(px(-RING_OFFSET))
+            .left(px(-RING_OFFSET))
+            .right(px(-RING_OFFSET))
+            .bottom(px(-RING_OFFSET))
+            .when(visible, |ring| {
+                ring.border_2()
+                    .rounded(px(6.0))
+                    .border_color(Color::focus_ring().resolve_in(cx))
+            })
+    }
+}
+
+/// Take the handle bound by the nearest enclosing `.focused()`, if no text
+/// field inside it has taken it yet.
+pub(crate) fn take(cx: &App) -> Option<FocusHandle> {
+    environment::focus_slot(cx).and_then(|slot| slot.borrow_mut().take())
+}
+
+/// Move focus into `state`'s input when it lands on `handle`.
+pub(crate) fn forward_to_input(
+    handle: &FocusHandle,
+    state: &Entity<InputState>,
+    window: &mut Window,
+    cx: &mut App,
+) {
+    if handle.is_focused(window) {
+        state.update(cx, |state, cx| state.focus(window, cx));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use gpui::{point, Context, Modifiers, Render, TestAppContext};
+
+    use super::*;
+    use crate::layout::Spacer;
+
+    struct FocusView {
+        handle: FocusHandle,
+        changes: Rc<RefCell<Vec<bool>>>,
+    }
+
+    impl Render for FocusView {
+        fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+            let changes = self.changes.clone();
+            Spacer::new()
+                .frame_size(40.0, 40.0)
+                .focused(&self.handle)
+                .on_focus_change(move |focused, _, _| changes.borrow_mut().push(focused))
+        }
+    }
+
+    #[test]
+    fn bound_views_report_focus_changes() {
+        let changes = Rc::new(RefCell::new(Vec::new()));
+        let mut cx = TestAppContext::single();
+        let (view, cx) = cx.add_window_view(|_, cx| FocusView {
+            handle: cx.focus_handle(),
+            changes: changes.clone(),
+        });
+        cx.update(|window, _| window.activate_window());
+        cx.run_until_parked();
+
+        cx.simulate_click(point(px(10.), px(10.)), Modifiers::none());
+        cx.run_until_parked();
+        let handle = view.read_with(cx, |view, _| view.handle.clone());
+        assert!(cx.update(|window, _| handle.is_focused(window)));
+        assert_eq!(*changes.borrow(), [true]);
+
+        cx.update(|window, _| window.blur());
+        cx.run_until_parked();
+        assert_eq!(*changes.borrow(), [true, false]);
+    }
+}
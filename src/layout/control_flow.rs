@@ -3,7 +3,7 @@
 //! These components enable declarative control flow in Allui views,
 //! similar to SwiftUI's `ForEach` and conditional `if` statements.
 
-use gpui::{div, App, IntoElement, ParentElement, RenderOnce, Window};
+use gpui::{AnyElement, App, IntoElement, RenderOnce, SharedString, Window};
 
 use crate::layout::EmptyView;
 use crate::modifier::Modifier;
@@ -13,6 +13,10 @@ use crate::modifier::Modifier;
 /// Unlike `LazyVStack`, ForEach renders all items immediately.
 /// For large collections, consider using `LazyVStack` or `LazyHStack`.
 ///
+/// Interactive children built in a loop need ids of their own. Derive them
+/// from the items with [`ForEach::with_id`], so each view keeps its state
+/// when the items are reordered.
+///
 /// # Example
 ///
 /// ```rust,ignore
@@ -24,68 +28,111 @@ use crate::modifier::Modifier;
 ///             Text::new(*item)
 ///         })
 ///     )
+///
+/// ForEach::with_id(&self.fruits, |fruit| fruit.name.clone(), |id, fruit| {
+///     Text::new(fruit.name.clone()).on_tap_gesture(id, || {})
+/// })
 /// ```
-pub struct ForEach<T, F, V>
-where
-    F: Fn(&T) -> V,
-    V: IntoElement,
-{
-    items: Vec<T>,
+pub struct ForEach<I, F> {
+    items: I,
     view_builder: F,
 }
 
-impl<T, F, V> ForEach<T, F, V>
-where
-    F: Fn(&T) -> V,
-    V: IntoElement,
-{
-    /// Create a new ForEach from a slice and a view builder function.
-    pub fn new(items: impl IntoIterator<Item = T>, view_builder: F) -> Self {
-        Self {
-            items: items.into_iter().collect(),
-            view_builder,
+impl ForEach<(), ()> {
+    /// Create a new ForEach from a collection and a view builder function.
+    ///
+    /// The items are collected first; use [`ForEach::from_slice`] to
+    /// borrow them instead.
+    pub fn new<T, V>(
+        items: impl IntoIterator<Item = T>,
+        view_builder: impl Fn(&T) -> V,
+    ) -> ForEach<std::vec::IntoIter<T>, impl FnMut(usize, T) -> V> {
+        Self::enumerated(items, move |_, item| view_builder(item))
+    }
+
+    /// Create a ForEach whose view builder also gets each item's index.
+    pub fn enumerated<T, V>(
+        items: impl IntoIterator<Item = T>,
+        view_builder: impl Fn(usize, &T) -> V,
+    ) -> ForEach<std::vec::IntoIter<T>, impl FnMut(usize, T) -> V> {
+        ForEach {
+            items: items.into_iter().collect::<Vec<_>>().into_iter(),
+            view_builder: move |index, item: T| view_builder(index, &item),
+        }
+    }
+
+    /// Create a ForEach whose view builder gets an id derived from each
+    /// item by `id`, to pass on to interactive children such as
+    /// `.on_tap_gesture(id, ..)`.
+    ///
+    /// Ids follow their items, not their positions, so they stay the same
+    /// when the items are reordered. They should be unique among the items.
+    pub fn with_id<T, K: Into<SharedString>, V>(
+        items: impl IntoIterator<Item = T>,
+        id: impl Fn(&T) -> K,
+        view_builder: impl Fn(SharedString, &T) -> V,
+    ) -> ForEach<std::vec::IntoIter<T>, impl FnMut(usize, T) -> V> {
+        Self::enumerated(items, move |_, item| view_builder(id(item).into(), item))
+    }
+
+    /// Create a ForEach over borrowed items, without cloning them.
+    pub fn from_slice<'a, T, V>(
+        items: &'a [T],
+        view_builder: impl Fn(&T) -> V,
+    ) -> ForEach<std::slice::Iter<'a, T>, impl FnMut(usize, &'a T) -> V> {
+        ForEach {
+            items: items.iter(),
+            view_builder: move |_, item: &'a T| view_builder(item),
         }
     }
 }
 
-impl<T, F, V> IntoIterator for ForEach<T, F, V>
+impl<I, F, V> IntoIterator for ForEach<I, F>
 where
-    F: Fn(&T) -> V,
-    V: IntoElement,
+    I: Iterator,
+    F: FnMut(usize, I::Item) -> V,
 {
     type Item = V;
-    type IntoIter = ForEachIter<T, F, V>;
+    type IntoIter = ForEachIter<I, F>;
 
     fn into_iter(self) -> Self::IntoIter {
         ForEachIter {
-            items: self.items.into_iter(),
+            items: self.items.enumerate(),
             view_builder: self.view_builder,
         }
     }
 }
 
 /// Iterator for ForEach that yields views.
-pub struct ForEachIter<T, F, V>
-where
-    F: Fn(&T) -> V,
-    V: IntoElement,
-{
-    items: std::vec::IntoIter<T>,
+pub struct ForEachIter<I, F> {
+    items: std::iter::Enumerate<I>,
     view_builder: F,
 }
 
-impl<T, F, V> Iterator for ForEachIter<T, F, V>
+impl<I, F, V> Iterator for ForEachIter<I, F>
 where
-    F: Fn(&T) -> V,
-    V: IntoElement,
+    I: Iterator,
+    F: FnMut(usize, I::Item) -> V,
 {
     type Item = V;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.items.next().map(|item| (self.view_builder)(&item))
+        let (index, item) = self.items.next()?;
+        Some((self.view_builder)(index, item))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.items.size_hint()
     }
 }
 
+impl<I, F, V> ExactSizeIterator for ForEachIter<I, F>
+where
+    I: ExactSizeIterator,
+    F: FnMut(usize, I::Item) -> V,
+{
+}
+
 /// Conditional rendering based on a boolean condition.
 ///
 /// # Example
@@ -159,17 +206,12 @@ struct IfElement<T: IntoElement + 'static, E: IntoElement + 'static> {
 
 impl<T: IntoElement + 'static, E: IntoElement + 'static> RenderOnce for IfElement<T, E> {
     fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
-        if self.inner.condition {
-            if let Some(view) = self.inner.then_view {
-                div().child(view.into_any_element())
-            } else {
-                div()
-            }
-        } else if let Some(view) = self.inner.else_view {
-            div().child(view.into_any_element())
+        let view = if self.inner.condition {
+            self.inner.then_view.map(IntoElement::into_any_element)
         } else {
-            div()
-        }
+            self.inner.else_view.map(IntoElement::into_any_element)
+        };
+        view.unwrap_or_else(nothing)
     }
 }
 
@@ -237,9 +279,203 @@ impl<T: 'static, F: FnOnce(&T) -> V + 'static, V: IntoElement + 'static> RenderO
         if let (Some(value), Some(builder)) =
             (self.inner.value.as_ref(), self.inner.view_builder.take())
         {
-            div().child(builder(value).into_any_element())
+            builder(value).into_any_element()
         } else {
-            div()
+            nothing()
         }
     }
 }
+
+/// Render one of several views picked by a value, like a `match` on an
+/// enum.
+///
+/// Arms are checked in order and the first whose value equals the
+/// switched-on value is shown; only its builder runs. Arms can build views
+/// of different types. When no arm matches, the [`default`](Self::default)
+/// view is shown, or nothing.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// Switch::on(self.tab)
+///     .case(Tab::Inbox, || InboxView::new())
+///     .case(Tab::Settings, || SettingsView::new())
+///     .default(|| Text::new("Coming soon"))
+/// ```
+#[derive(IntoElement)]
+pub struct Switch<T: 'static> {
+    value: T,
+    view: Option<AnyElement>,
+}
+
+impl<T: PartialEq + 'static> Switch<T> {
+    /// Switch on `value`.
+    pub fn on(value: T) -> Self {
+        Self { value, view: None }
+    }
+
+    /// Show the view `view_builder` builds when the value equals `value`
+    /// and no earlier arm matched.
+    pub fn case<V: IntoElement>(mut self, value: T, view_builder: impl FnOnce() -> V) -> Self {
+        if self.view.is_none() && self.value == value {
+            self.view = Some(view_builder().into_any_element());
+        }
+        self
+    }
+
+    /// Show the view `view_builder` builds when no arm before it matched.
+    pub fn default<V: IntoElement>(mut self, view_builder: impl FnOnce() -> V) -> Self {
+        if self.view.is_none() {
+            self.view = Some(view_builder().into_any_element());
+        }
+        self
+    }
+}
+
+impl<T: 'static> Modifier for Switch<T> {}
+
+impl<T: 'static> RenderOnce for Switch<T> {
+    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+        self.view.unwrap_or_else(nothing)
+    }
+}
+
+/// What a branch that isn't shown renders.
+fn nothing() -> AnyElement {
+    EmptyView::new().into_any_element()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use gpui::{
+        canvas, point, px, size, AnyElement, AvailableSpace, Pixels, Styled, TestAppContext,
+    };
+
+    use super::*;
+    use crate::layout::HStack;
+    use crate::modifier::Frame;
+
+    /// Lay out a 300pt-wide row of `children` 8pt apart, followed by a
+    /// marker, and return where the marker starts.
+    fn marker_x(children: impl FnOnce() -> Vec<AnyElement>) -> Pixels {
+        let mut cx = TestAppContext::single();
+        let cx = cx.add_empty_window();
+        let marker = Rc::new(Cell::new(None));
+        let recorded = marker.clone();
+        cx.draw(
+            point(px(0.), px(0.)),
+            size(
+                AvailableSpace::Definite(px(300.)),
+                AvailableSpace::Definite(px(300.)),
+            ),
+            |_, _| {
+                let marker = canvas(
+                    move |bounds, _, _| recorded.set(Some(bounds)),
+                    |_, _, _, _| {},
+                )
+                .size(px(1.));
+                HStack::new()
+                    .children(children())
+                    .child(marker)
+                    .frame_width(300.0)
+                    .into_any_element()
+            },
+        );
+        marker.get().expect("marker is laid out").origin.x
+    }
+
+    fn square() -> AnyElement {
+        EmptyView::new().frame_size(10.0, 10.0).into_any_element()
+    }
+
+    #[derive(PartialEq)]
+    enum Tab {
+        Inbox,
+        Drafts,
+        Settings,
+    }
+
+    #[test]
+    fn only_the_matching_arm_is_built() {
+        let built = Cell::new([0; 4]);
+        let build = |arm: usize| {
+            let mut counts = built.get();
+            counts[arm] += 1;
+            built.set(counts);
+            EmptyView::new()
+        };
+        let switch = |tab| {
+            Switch::on(tab)
+                .case(Tab::Inbox, || build(0))
+                .case(Tab::Drafts, || build(1).frame_width(10.0))
+                .case(Tab::Drafts, || build(2))
+                .default(|| build(3))
+        };
+
+        switch(Tab::Drafts);
+        assert_eq!(built.get(), [0, 1, 0, 0]);
+        switch(Tab::Settings);
+        assert_eq!(built.get(), [0, 1, 0, 1]);
+    }
+
+    #[test]
+    fn missing_branches_take_no_space() {
+        let without = marker_x(|| vec![square()]);
+        assert_eq!(without, px(18.));
+
+        let hidden = marker_x(|| {
+            vec![
+                square(),
+                If::new(false).then(square).into_any_element(),
+                IfLet::new(None::<u8>, |_| square()).into_any_element(),
+                Switch::on(1).case(2, square).into_any_element(),
+            ]
+        });
+        assert_eq!(hidden, without);
+    }
+
+    #[test]
+    fn filling_views_still_fill() {
+        let x = marker_x(|| {
+            vec![If::new(true)
+                .then(|| EmptyView::new().frame(Frame::fill_width()))
+                .into_any_element()]
+        });
+        assert_eq!(x, px(299.));
+    }
+
+    #[test]
+    fn ids_follow_items_when_reordered() {
+        let ids = |fruits: Vec<&'static str>| {
+            ForEach::with_id(
+                fruits,
+                |fruit| format!("fruit-{fruit}"),
+                |id, fruit| (*fruit, id),
+            )
+            .into_iter()
+            .collect::<Vec<_>>()
+        };
+
+        let before = ids(vec!["apple", "banana", "cherry"]);
+        let after = ids(vec!["cherry", "apple", "banana"]);
+        assert_eq!(before[0], ("apple", SharedString::from("fruit-apple")));
+        assert_eq!(after[1], before[0]);
+        assert_eq!(after[0], before[2]);
+    }
+
+    #[test]
+    fn views_know_their_index_and_count() {
+        let fruits = ["apple", "banana"];
+        let views = ForEach::from_slice(&fruits, |fruit| fruit.len()).into_iter();
+        assert_eq!(views.len(), 2);
+
+        let labels: Vec<_> =
+            ForEach::enumerated(fruits, |index, fruit| format!("{index}. {fruit}"))
+                .into_iter()
+                .collect();
+        assert_eq!(labels, ["0. apple", "1. banana"]);
+    }
+}
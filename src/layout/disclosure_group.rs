@@ -0,0 +1,180 @@
+//! DisclosureGroup - Content collapsed behind a label and a chevron.
+
+use std::rc::Rc;
+use std::time::Duration;
+
+use gpui::{
+    div, ease_in_out, percentage, prelude::FluentBuilder, px, Animation, AnimationExt, AnyElement,
+    App, ElementId, FocusHandle, InteractiveElement, IntoElement, KeyDownEvent, ParentElement,
+    RenderOnce, StatefulInteractiveElement, Styled, Window,
+};
+use gpui_component::{Icon, IconName};
+
+use crate::modifier::Modifier;
+use crate::style::Color;
+
+/// How long the chevron takes to turn.
+const CHEVRON_DURATION: Duration = Duration::from_millis(150);
+
+/// Called with whether a disclosure group is now expanded.
+type ToggleHandler = Rc<dyn Fn(&bool, &mut Window, &mut App)>;
+
+/// Kept across frames for each disclosure group.
+struct DisclosureState {
+    expanded: bool,
+    focus: FocusHandle,
+    /// Whether the group was expanded when last drawn.
+    shown: Option<bool>,
+    /// Bumped each time the group expands or collapses, so the chevron turns.
+    generation: usize,
+}
+
+/// Content shown or hidden by tapping its label, like SwiftUI's
+/// `DisclosureGroup`.
+///
+/// The group keeps whether it is expanded itself, starting collapsed. Pass
+/// [`expanded`](Self::expanded) and [`on_toggle`](Self::on_toggle) to keep
+/// it in your view instead. The label row toggles on click, and on Enter or
+/// Space while focused. In a `Section`, add it as a row to get a collapsible
+/// subsection.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// DisclosureGroup::new(
+///     "advanced",
+///     Text::new("Advanced"),
+///     VStack::new()
+///         .child(Toggle::new("Debug Logging", false, |_| {}))
+///         .child(Toggle::new("Beta Features", false, |_| {})),
+/// )
+/// ```
+#[derive(IntoElement)]
+pub struct DisclosureGroup {
+    id: ElementId,
+    label: AnyElement,
+    content: AnyElement,
+    expanded: Option<bool>,
+    on_toggle: Option<ToggleHandler>,
+}
+
+impl DisclosureGroup {
+    /// Create a collapsed group showing `label`, which reveals `content`.
+    pub fn new(
+        id: impl Into<ElementId>,
+        label: impl IntoElement,
+        content: impl IntoElement,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            label: label.into_any_element(),
+            content: content.into_any_element(),
+            expanded: None,
+            on_toggle: None,
+        }
+    }
+
+    /// Show the content or not, overriding the group's own state.
+    pub fn expanded(mut self, expanded: bool) -> Self {
+        self.expanded = Some(expanded);
+        self
+    }
+
+    /// Call `handler` with the new state when the label is activated.
+    pub fn on_toggle(mut self, handler: impl Fn(&bool, &mut Window, &mut App) + 'static) -> Self {
+        self.on_toggle = Some(Rc::new(handler));
+        self
+    }
+}
+
+impl Modifier for DisclosureGroup {}
+
+impl RenderOnce for DisclosureGroup {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let state = window.use_keyed_state(self.id.clone(), cx, |_, cx| DisclosureState {
+            expanded: false,
+            focus: cx.focus_handle(),
+            shown: None,
+            generation: 0,
+        });
+        let controlled = self.expanded.is_some();
+        let (expanded, focus, generation) = state.update(cx, |state, _| {
+            let expanded = self.expanded.unwrap_or(state.expanded);
+            if state
+                .shown
+                .replace(expanded)
+                .is_some_and(|shown| shown != expanded)
+            {
+                state.generation += 1;
+            }
+            (expanded, state.focus.clone(), state.generation)
+        });
+
+        let toggle = {
+            let on_toggle = self.on_toggle.clone();
+            move |window: &mut Window, cx: &mut App| {
+                let expanded = !expanded;
+                if !controlled {
+                    state.update(cx, |state, cx| {
+                        state.expanded = expanded;
+                        cx.notify();
+                    });
+                }
+                if let Some(on_toggle) = &on_toggle {
+                    on_toggle(&expanded, window, cx);
+                }
+            }
+        };
+        let toggle = Rc::new(toggle);
+
+        // Points right while collapsed and down while expanded, turning
+        // once the group has been toggled
+        let (from, to) = if expanded { (0.0, 0.25) } else { (0.25, 0.0) };
+        let chevron = Icon::new(IconName::ChevronRight)
+            .flex_shrink_0()
+            .text_color(Color::accent().resolve_in(cx));
+        let chevron = if generation == 0 {
+            chevron.rotate(percentage(to)).into_any_element()
+        } else {
+            chevron
+                .with_animation(
+                    ("disclosure-chevron", generation),
+                    Animation::new(CHEVRON_DURATION).with_easing(ease_in_out),
+                    move |icon, delta| icon.rotate(percentage(from + (to - from) * delta)),
+                )
+                .into_any_element()
+        };
+
+        let label = div()
+            .id(self.id.clone())
+            .flex()
+            .flex_row()
+            .items_center()
+            .gap(px(8.0))
+            .w_full()
+            .min_h(px(28.0))
+            .cursor_pointer()
+            .track_focus(&focus)
+            .child(div().flex_1().min_w_0().child(self.label))
+            .child(chevron)
+            .on_click({
+                let toggle = toggle.clone();
+                move |_, window, cx| toggle(window, cx)
+            })
+            .on_key_down(move |event: &KeyDownEvent, window, cx| {
+                if matches!(event.keystroke.key.as_str(), "enter" | "space") {
+                    cx.stop_propagation();
+                    toggle(window, cx);
+                }
+            });
+
+        div()
+            .flex()
+            .flex_col()
+            .w_full()
+            .child(label)
+            .when(expanded, |group| {
+                group.child(div().w_full().pt(px(8.0)).child(self.content))
+            })
+    }
+}
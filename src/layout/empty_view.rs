@@ -1,12 +1,14 @@
 //! EmptyView - A view that displays nothing.
 
-use gpui::{div, App, IntoElement, RenderOnce, Window};
+use gpui::{div, App, IntoElement, RenderOnce, Styled, Window};
 
 use crate::modifier::Modifier;
 
 /// A view that displays nothing and takes up no space.
 ///
-/// Useful as a placeholder or for conditional rendering.
+/// Useful as a placeholder or for conditional rendering. Stacks leave no
+/// gap for it, so it doesn't add spacing next to its siblings. Modifiers
+/// such as `.frame()` still give it a size.
 ///
 /// # Example
 ///
@@ -37,7 +39,74 @@ impl Modifier for EmptyView {}
 
 impl RenderOnce for EmptyView {
     fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
-        // Return an empty div that takes no space
-        div()
+        // Out of the layout, so stacks don't put a gap before or after it
+        div().hidden()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use gpui::{
+        canvas, point, px, size, AnyElement, AvailableSpace, Pixels, Point, TestAppContext,
+    };
+
+    use super::*;
+    use crate::layout::{HStack, If, VStack};
+
+    /// Lay out `stack` around a marker and return where the marker starts.
+    fn marker_origin<E: IntoElement + 'static>(
+        stack: impl FnOnce(AnyElement) -> E,
+    ) -> Point<Pixels> {
+        let mut cx = TestAppContext::single();
+        let cx = cx.add_empty_window();
+        let marker = Rc::new(Cell::new(None));
+        let recorded = marker.clone();
+        cx.draw(
+            point(px(0.), px(0.)),
+            size(
+                AvailableSpace::Definite(px(300.)),
+                AvailableSpace::Definite(px(300.)),
+            ),
+            |_, _| {
+                let marker = canvas(
+                    move |bounds, _, _| recorded.set(Some(bounds)),
+                    |_, _, _, _| {},
+                )
+                .size(px(1.));
+                stack(marker.into_any_element()).into_any_element()
+            },
+        );
+        marker.get().expect("marker is laid out").origin
+    }
+
+    fn square() -> impl IntoElement {
+        EmptyView::new().frame_size(10.0, 10.0)
+    }
+
+    #[test]
+    fn stacks_leave_no_gap_for_empty_views() {
+        let row = marker_origin(|marker| HStack::new().child(square()).child(marker)).x;
+        assert_eq!(row, px(18.));
+        let with_empty = marker_origin(|marker| {
+            HStack::new()
+                .child(square())
+                .child(EmptyView::new())
+                .child(marker)
+        })
+        .x;
+        assert_eq!(with_empty, row);
+
+        let column = marker_origin(|marker| {
+            VStack::new()
+                .child(EmptyView::new())
+                .child(square())
+                .child(If::new(false).then(square).otherwise(EmptyView::new))
+                .child(marker)
+        })
+        .y;
+        assert_eq!(column, px(18.));
     }
 }
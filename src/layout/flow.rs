@@ -0,0 +1,153 @@
+//! Flow - Wrapping horizontal layout.
+
+use gpui::{div, px, App, IntoElement, ParentElement, RenderOnce, Styled, Window};
+
+use crate::alignment::HorizontalAlignment;
+use crate::modifier::{Expansion, Modifier};
+
+/// A view that places its children left to right, wrapping onto a new line
+/// when the row is full.
+///
+/// Useful for a variable number of tags or chips. Each line is as tall as
+/// its tallest child, and shorter children are centered on it. The flow
+/// takes the full width offered to it, so give it a frame to wrap sooner.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// Flow::new()
+///     .spacing(6.0)
+///     .line_spacing(6.0)
+///     .children(tags.iter().map(|tag| Text::new(tag.clone()).padding(4.0)))
+///     .frame_width(300.0)
+/// ```
+#[derive(IntoElement)]
+pub struct Flow {
+    spacing: f32,
+    line_spacing: f32,
+    alignment: HorizontalAlignment,
+    children: Vec<gpui::AnyElement>,
+}
+
+impl Flow {
+    /// Create a new wrapping layout.
+    pub fn new() -> Self {
+        Self {
+            spacing: 8.0,
+            line_spacing: 8.0,
+            alignment: HorizontalAlignment::Leading,
+            children: Vec::new(),
+        }
+    }
+
+    /// Set the spacing between children on a line in points (default 8).
+    pub fn spacing(mut self, spacing: f32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Set the spacing between lines in points (default 8).
+    pub fn line_spacing(mut self, line_spacing: f32) -> Self {
+        self.line_spacing = line_spacing;
+        self
+    }
+
+    /// Set how each line is aligned (default leading).
+    pub fn alignment(mut self, alignment: HorizontalAlignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    impl_child_methods!();
+}
+
+impl Default for Flow {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Modifier for Flow {
+    fn expansion(&self) -> Expansion {
+        Expansion {
+            fills_width: true,
+            ..Expansion::default()
+        }
+    }
+}
+
+impl RenderOnce for Flow {
+    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+        // Flex wrapping breaks lines by child width; items_center centers
+        // each child on its own line, and content_start keeps lines packed
+        // at the top when the flow is taller than its content
+        let container = div()
+            .flex()
+            .flex_row()
+            .flex_wrap()
+            .items_center()
+            .content_start()
+            .w_full()
+            .gap_x(px(self.spacing))
+            .gap_y(px(self.line_spacing));
+        self.alignment
+            .apply_as_justify(container)
+            .children(self.children)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use gpui::{canvas, point, size, AvailableSpace, Bounds, Pixels, TestAppContext};
+
+    use super::*;
+
+    type Recorded = Rc<Cell<Option<Bounds<Pixels>>>>;
+
+    /// A box of `width` by `height` that records where it is laid out.
+    fn marker(width: f32, height: f32, recorded: &Recorded) -> impl IntoElement {
+        let recorded = recorded.clone();
+        canvas(
+            move |bounds, _, _| recorded.set(Some(bounds)),
+            |_, _, _, _| {},
+        )
+        .w(px(width))
+        .h(px(height))
+    }
+
+    #[test]
+    fn children_wrap_below_the_tallest_on_the_line() {
+        let (first, tall, wrapped) = (
+            Recorded::default(),
+            Recorded::default(),
+            Recorded::default(),
+        );
+        let mut cx = TestAppContext::single();
+        let cx = cx.add_empty_window();
+        cx.draw(
+            point(px(0.), px(0.)),
+            size(
+                AvailableSpace::Definite(px(300.)),
+                AvailableSpace::Definite(px(300.)),
+            ),
+            |_, _| {
+                div().w(px(100.)).child(
+                    Flow::new()
+                        .spacing(10.0)
+                        .line_spacing(5.0)
+                        .child(marker(40., 10., &first))
+                        .child(marker(40., 30., &tall))
+                        .child(marker(40., 10., &wrapped)),
+                )
+            },
+        );
+
+        // Centered on the first line, which is as tall as its tallest child
+        assert_eq!(first.get().unwrap().origin, point(px(0.), px(10.)));
+        assert_eq!(tall.get().unwrap().origin, point(px(50.), px(0.)));
+        assert_eq!(wrapped.get().unwrap().origin, point(px(0.), px(35.)));
+    }
+}
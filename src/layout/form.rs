@@ -0,0 +1,156 @@
+//! Form - A container that lays out controls as grouped rows.
+
+use gpui::{AnyElement, App, IntoElement, RenderOnce, SharedString, Window};
+
+use crate::environment::EnvironmentScope;
+use crate::layout::{List, ListStyle, Section};
+use crate::modifier::Modifier;
+
+/// The minimum height of a form row, in points.
+const FORM_ROW_HEIGHT: f32 = 44.0;
+
+enum FormChild {
+    Section(Box<Section>),
+    Row(AnyElement),
+}
+
+/// Controls laid out as the rows of an inset grouped list, like SwiftUI's
+/// `Form`.
+///
+/// Rows get the list's chrome: separators, insets and a 44pt minimum
+/// height. Children added outside a [`Section`] are grouped into a section
+/// of their own. Toggles in a form stretch across their row, with the
+/// label leading and the switch trailing.
+///
+/// The form's ID is derived from the call site; use [`id`](Self::id) when
+/// creating forms in a loop.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// Form::new()
+///     .section(
+///         Section::new()
+///             .header("General")
+///             .row(TextField::new(&self.name))
+///             .row(Toggle::new("Notifications", self.notifications, |_| {})),
+///     )
+///     .section(
+///         Section::new().row(LabeledContent::new("Version", Text::new("1.2.0"))),
+///     )
+/// ```
+#[derive(IntoElement)]
+pub struct Form {
+    id: SharedString,
+    children: Vec<FormChild>,
+}
+
+impl Form {
+    /// Create an empty form.
+    #[track_caller]
+    pub fn new() -> Self {
+        let location = std::panic::Location::caller();
+        Self {
+            id: format!(
+                "{}:{}:{}",
+                location.file(),
+                location.line(),
+                location.column()
+            )
+            .into(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Set the form's ID, which keeps the state of its list.
+    pub fn id(mut self, id: impl Into<SharedString>) -> Self {
+        self.id = id.into();
+        self
+    }
+
+    /// Add a section of rows.
+    pub fn section(mut self, section: Section) -> Self {
+        self.children.push(FormChild::Section(Box::new(section)));
+        self
+    }
+
+    /// Add a row, grouped with the rows added around it.
+    pub fn child(mut self, child: impl IntoElement) -> Self {
+        self.children.push(FormChild::Row(child.into_any_element()));
+        self
+    }
+
+    /// Add several rows, grouped with the rows added around them.
+    pub fn children<I, E>(mut self, children: I) -> Self
+    where
+        I: IntoIterator<Item = E>,
+        E: IntoElement,
+    {
+        self.children.extend(
+            children
+                .into_iter()
+                .map(|child| FormChild::Row(child.into_any_element())),
+        );
+        self
+    }
+}
+
+impl Default for Form {
+    #[track_caller]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Modifier for Form {}
+
+impl RenderOnce for Form {
+    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+        let list = List::new(self.id)
+            .list_style(ListStyle::inset_grouped())
+            .min_row_height(FORM_ROW_HEIGHT)
+            .sections(sections(self.children));
+        EnvironmentScope::new(list).form(true)
+    }
+}
+
+/// The form's sections, with each run of loose rows in a section of its
+/// own.
+fn sections(children: Vec<FormChild>) -> Vec<Section> {
+    let mut sections = Vec::new();
+    let mut rows = Vec::new();
+    for child in children {
+        match child {
+            FormChild::Row(row) => rows.push(row),
+            FormChild::Section(section) => {
+                if !rows.is_empty() {
+                    sections.push(Section::new().rows(std::mem::take(&mut rows)));
+                }
+                sections.push(*section);
+            }
+        }
+    }
+    if !rows.is_empty() {
+        sections.push(Section::new().rows(rows));
+    }
+    sections
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gpui::div;
+
+    #[test]
+    fn loose_rows_are_grouped_between_sections() {
+        let row = || FormChild::Row(div().into_any_element());
+        let children = vec![
+            row(),
+            row(),
+            FormChild::Section(Box::new(Section::new().header("Details"))),
+            row(),
+        ];
+        assert_eq!(sections(children).len(), 3);
+        assert!(sections(Vec::new()).is_empty());
+    }
+}
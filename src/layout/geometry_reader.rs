@@ -0,0 +1,120 @@
+//! GeometryReader - Build content from the size it is given.
+
+use gpui::{
+    relative, AnyElement, App, Bounds, Element, ElementId, GlobalElementId, InspectorElementId,
+    IntoElement, LayoutId, Pixels, Size, Style, Window,
+};
+
+use crate::modifier::{Expansion, Modifier};
+
+/// Builds the content for the measured size.
+type GeometryBuilder = Box<dyn FnOnce(Size<Pixels>) -> AnyElement + 'static>;
+
+/// A container that builds its content from its own laid-out size.
+///
+/// Like SwiftUI's `GeometryReader`, it takes all the space offered to it,
+/// then calls the closure with that size. The content is laid out inside the
+/// measured bounds in the same frame, so there is no lag when the window
+/// resizes. Because the content can't change the reader's size, there is no
+/// relayout loop either.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// // One column when narrow, two when wide
+/// GeometryReader::new(move |size| {
+///     if size.width < px(500.0) {
+///         VStack::new().child(sidebar).child(detail).into_any_element()
+///     } else {
+///         HStack::new().child(sidebar).child(detail).into_any_element()
+///     }
+/// })
+/// ```
+pub struct GeometryReader {
+    build: Option<GeometryBuilder>,
+}
+
+impl GeometryReader {
+    /// Create a reader that builds its content with `build`.
+    pub fn new<E: IntoElement>(build: impl FnOnce(Size<Pixels>) -> E + 'static) -> Self {
+        Self {
+            build: Some(Box::new(move |size| build(size).into_any_element())),
+        }
+    }
+}
+
+impl Modifier for GeometryReader {
+    fn expansion(&self) -> Expansion {
+        Expansion {
+            grows: true,
+            fills_width: true,
+            fills_height: true,
+        }
+    }
+}
+
+impl IntoElement for GeometryReader {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for GeometryReader {
+    type RequestLayoutState = ();
+    type PrepaintState = Option<AnyElement>;
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn source_location(&self) -> Option<&'static core::panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        // Take all the space offered, independent of the content
+        let mut style = Style::default();
+        style.size.width = relative(1.).into();
+        style.size.height = relative(1.).into();
+        style.flex_grow = 1.;
+        (window.request_layout(style, [], cx), ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Self::PrepaintState {
+        let build = self.build.take()?;
+        let mut content = build(bounds.size);
+        content.prepaint_as_root(bounds.origin, bounds.size.into(), window, cx);
+        Some(content)
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        _bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        content: &mut Self::PrepaintState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        if let Some(content) = content {
+            content.paint(window, cx);
+        }
+    }
+}
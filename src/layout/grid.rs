@@ -3,10 +3,14 @@
 //! Grid arranges children in a two-dimensional layout where columns
 //! automatically size to fit their widest cell.
 
-use gpui::{div, px, App, IntoElement, ParentElement, RenderOnce, Styled, Window};
+use gpui::{
+    div, px, AlignSelf, App, Div, Hsla, IntoElement, ParentElement, Pixels, RenderOnce, Styled,
+    Window,
+};
 
-use crate::alignment::{Alignment, VerticalAlignment};
+use crate::alignment::{Alignment, HorizontalAlignment, VerticalAlignment};
 use crate::modifier::Modifier;
+use crate::style::Color;
 
 /// A container that arranges views in a two-dimensional grid layout.
 ///
@@ -29,12 +33,17 @@ use crate::modifier::Modifier;
 ///             .child(Text::new("Width"))
 ///             .child(Text::new("100px"))
 ///     )
+///     .column_alignment(1, HorizontalAlignment::Trailing)
+///     .row_separators(Color::separator())
 /// ```
 #[derive(IntoElement)]
 pub struct Grid {
     horizontal_spacing: f32,
     vertical_spacing: f32,
     alignment: Alignment,
+    column_alignments: Vec<(usize, HorizontalAlignment)>,
+    row_separators: Option<Color>,
+    column_separators: Option<Color>,
     children: Vec<GridChild>,
 }
 
@@ -53,6 +62,9 @@ impl Grid {
             horizontal_spacing: 0.0,
             vertical_spacing: 0.0,
             alignment: Alignment::center(),
+            column_alignments: Vec::new(),
+            row_separators: None,
+            column_separators: None,
             children: Vec::new(),
         }
     }
@@ -82,6 +94,29 @@ impl Grid {
         self
     }
 
+    /// Set the horizontal alignment of every cell in the column at `index`,
+    /// counting from 0.
+    ///
+    /// A cell spanning several columns takes the alignment of the first.
+    pub fn column_alignment(mut self, index: usize, alignment: HorizontalAlignment) -> Self {
+        self.column_alignments
+            .retain(|(column, _)| *column != index);
+        self.column_alignments.push((index, alignment));
+        self
+    }
+
+    /// Draw a hairline between rows, centered in the vertical spacing.
+    pub fn row_separators(mut self, color: impl Into<Color>) -> Self {
+        self.row_separators = Some(color.into());
+        self
+    }
+
+    /// Draw a hairline between columns, centered in the horizontal spacing.
+    pub fn column_separators(mut self, color: impl Into<Color>) -> Self {
+        self.column_separators = Some(color.into());
+        self
+    }
+
     /// Add a GridRow child.
     pub fn child(mut self, row: GridRow) -> Self {
         self.children.push(GridChild::Row(row));
@@ -100,7 +135,7 @@ impl Grid {
         self.children
             .iter()
             .filter_map(|child| match child {
-                GridChild::Row(row) => Some(row.cells.len()),
+                GridChild::Row(row) => Some(row.column_count()),
                 GridChild::Spanning(_) => None,
             })
             .max()
@@ -117,7 +152,7 @@ impl Default for Grid {
 impl Modifier for Grid {}
 
 impl RenderOnce for Grid {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
         let col_count = self.max_columns();
 
         if col_count == 0 {
@@ -135,30 +170,71 @@ impl RenderOnce for Grid {
         let container = self.alignment.horizontal.apply_as_justify(container);
         let mut container = self.alignment.vertical.apply_as_items(container);
 
+        let separators = Separators {
+            row: self.row_separators.map(|color| color.resolve_in(cx)),
+            column: self.column_separators.map(|color| color.resolve_in(cx)),
+            horizontal_spacing: self.horizontal_spacing,
+            vertical_spacing: self.vertical_spacing,
+            row_count: self.children.len(),
+            col_count,
+        };
+
         // Add children
-        for child in self.children {
+        for (row_ix, child) in self.children.into_iter().enumerate() {
             match child {
                 GridChild::Row(row) => {
-                    let row_col_count = row.cells.len();
-                    for (idx, cell) in row.cells.into_iter().enumerate() {
+                    let mut column = 0;
+                    for cell in row.cells {
+                        let span = cell.columns as usize;
+                        let mut cell_container = div().child(cell.element);
+                        if cell.columns > 1 {
+                            cell_container = cell_container.col_span(cell.columns);
+                        }
+                        let column_alignment = self
+                            .column_alignments
+                            .iter()
+                            .find(|(index, _)| *index == column)
+                            .map(|(_, alignment)| *alignment);
+                        if let Some(column_alignment) = column_alignment {
+                            cell_container =
+                                column_alignment.apply_as_justify(cell_container.flex());
+                        }
                         // Apply row-level alignment if specified
-                        let mut cell_container = div().child(cell);
-                        if let Some(row_alignment) = row.alignment {
+                        let row_alignment = row.alignment;
+                        if let Some(row_alignment) = row_alignment {
                             cell_container = row_alignment.apply_as_items(cell_container);
                         }
-                        container = container.child(cell_container);
+                        let vertical = row_alignment.unwrap_or(self.alignment.vertical);
+                        container = container.child(separators.apply(
+                            cell_container,
+                            vertical,
+                            row_ix,
+                            column,
+                            span,
+                        ));
+                        column += span;
+                    }
 
-                        // If this row has fewer columns, add empty cells
-                        if idx == row_col_count - 1 && row_col_count < col_count {
-                            for _ in 0..(col_count - row_col_count) {
-                                container = container.child(div());
-                            }
-                        }
+                    // If this row has fewer columns, add empty cells
+                    for column in column..col_count {
+                        container = container.child(separators.apply(
+                            div(),
+                            self.alignment.vertical,
+                            row_ix,
+                            column,
+                            1,
+                        ));
                     }
                 }
                 GridChild::Spanning(element) => {
                     // Spanning element takes full width
-                    container = container.child(div().col_span_full().child(element));
+                    container = container.child(separators.apply(
+                        div().col_span_full().child(element),
+                        self.alignment.vertical,
+                        row_ix,
+                        0,
+                        col_count,
+                    ));
                 }
             }
         }
@@ -167,6 +243,73 @@ impl RenderOnce for Grid {
     }
 }
 
+/// Hairlines drawn by each cell into the spacing around it, so the lines
+/// of neighbouring cells join up.
+struct Separators {
+    row: Option<Hsla>,
+    column: Option<Hsla>,
+    horizontal_spacing: f32,
+    vertical_spacing: f32,
+    row_count: usize,
+    col_count: usize,
+}
+
+impl Separators {
+    /// Add the separator lines for the cell at `row`, spanning `span`
+    /// columns from `column`.
+    fn apply(
+        &self,
+        mut cell: Div,
+        vertical: VerticalAlignment,
+        row: usize,
+        column: usize,
+        span: usize,
+    ) -> Div {
+        if self.row.is_none() && self.column.is_none() {
+            return cell;
+        }
+
+        // Stretch the cell to the row height so column lines reach across
+        // it, aligning the content inside instead
+        cell.style().align_self = Some(AlignSelf::Stretch);
+        cell = vertical.apply_as_items(cell.relative().flex());
+
+        let half_h = px(self.horizontal_spacing / 2.0);
+        let half_v = px(self.vertical_spacing / 2.0);
+        let reach = |first: bool, gap: Pixels| if first { px(0.0) } else { -gap };
+
+        if let Some(color) = self.row.filter(|_| row > 0) {
+            cell = cell.child(
+                div()
+                    .absolute()
+                    .top(-half_v - px(0.5))
+                    .h(px(1.0))
+                    .left(reach(column == 0, half_h))
+                    .right(reach(column + span >= self.col_count, half_h))
+                    .bg(color),
+            );
+        }
+        if let Some(color) = self.column.filter(|_| column > 0) {
+            cell = cell.child(
+                div()
+                    .absolute()
+                    .left(-half_h - px(0.5))
+                    .w(px(1.0))
+                    .top(reach(row == 0, half_v))
+                    .bottom(reach(row + 1 >= self.row_count, half_v))
+                    .bg(color),
+            );
+        }
+        cell
+    }
+}
+
+/// A cell of a GridRow and the number of columns it spans.
+struct GridCell {
+    element: gpui::AnyElement,
+    columns: u16,
+}
+
 /// A single row within a Grid.
 ///
 /// # Example
@@ -176,9 +319,14 @@ impl RenderOnce for Grid {
 ///     .alignment(VerticalAlignment::Top)
 ///     .child(Text::new("Label"))
 ///     .child(Text::new("Value"))
+///
+/// // A footer label spanning the first two columns
+/// GridRow::new()
+///     .cell_spanning(Text::new("Total"), 2)
+///     .child(Text::new("16.3 KB"))
 /// ```
 pub struct GridRow {
-    cells: Vec<gpui::AnyElement>,
+    cells: Vec<GridCell>,
     alignment: Option<VerticalAlignment>,
 }
 
@@ -199,7 +347,20 @@ impl GridRow {
 
     /// Add a cell to this row.
     pub fn child<E: IntoElement>(mut self, child: E) -> Self {
-        self.cells.push(child.into_any_element());
+        self.cells.push(GridCell {
+            element: child.into_any_element(),
+            columns: 1,
+        });
+        self
+    }
+
+    /// Add a cell spanning `columns` columns, like SwiftUI's
+    /// `gridCellColumns(_:)`.
+    pub fn cell_spanning<E: IntoElement>(mut self, child: E, columns: u16) -> Self {
+        self.cells.push(GridCell {
+            element: child.into_any_element(),
+            columns: columns.max(1),
+        });
         self
     }
 
@@ -210,10 +371,15 @@ impl GridRow {
         E: IntoElement,
     {
         for child in children {
-            self.cells.push(child.into_any_element());
+            self = self.child(child);
         }
         self
     }
+
+    /// The number of columns the row's cells span.
+    fn column_count(&self) -> usize {
+        self.cells.iter().map(|cell| cell.columns as usize).sum()
+    }
 }
 
 impl Default for GridRow {
@@ -221,3 +387,72 @@ impl Default for GridRow {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use gpui::{canvas, point, size, AvailableSpace, Axis, Bounds, Pixels, TestAppContext};
+
+    use super::*;
+
+    type Recorded = Rc<Cell<Option<Bounds<Pixels>>>>;
+
+    /// A box `width` wide that records where it is laid out.
+    fn marker(width: f32, recorded: &Recorded) -> impl IntoElement {
+        let recorded = recorded.clone();
+        canvas(
+            move |bounds, _, _| recorded.set(Some(bounds)),
+            |_, _, _, _| {},
+        )
+        .w(px(width))
+        .h(px(10.))
+    }
+
+    #[test]
+    fn spanning_cells_count_towards_the_column_count() {
+        let grid = Grid::new()
+            .child(GridRow::new().child(div()).child(div()))
+            .child(GridRow::new().cell_spanning(div(), 2).child(div()))
+            .spanning_child(div());
+        assert_eq!(grid.max_columns(), 3);
+        assert_eq!(GridRow::new().cell_spanning(div(), 0).column_count(), 1);
+    }
+
+    #[test]
+    fn unsized_cells_do_not_widen_columns() {
+        let (second, wide) = (Recorded::default(), Recorded::default());
+        let mut cx = TestAppContext::single();
+        let cx = cx.add_empty_window();
+        cx.draw(
+            point(px(0.), px(0.)),
+            size(
+                AvailableSpace::Definite(px(600.)),
+                AvailableSpace::Definite(px(300.)),
+            ),
+            |_, _| {
+                div().flex().items_start().child(
+                    Grid::new()
+                        .horizontal_spacing(10.0)
+                        .child(
+                            GridRow::new()
+                                .child(div().w(px(40.)).h(px(10.)))
+                                .child(marker(40., &second)),
+                        )
+                        .child(
+                            GridRow::new().cell_spanning(
+                                crate::layout::Group::new()
+                                    .child(marker(500., &wide))
+                                    .grid_cell_unsized_axes(Axis::Horizontal),
+                                2,
+                            ),
+                        ),
+                )
+            },
+        );
+
+        assert_eq!(second.get().unwrap().left(), px(50.));
+        assert_eq!(wide.get().unwrap().left(), px(0.));
+    }
+}
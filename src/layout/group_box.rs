@@ -0,0 +1,57 @@
+//! GroupBox - A titled card grouping related content.
+
+use gpui::{
+    div, px, AnyElement, App, FontWeight, IntoElement, ParentElement, RenderOnce, Styled, Window,
+};
+
+use crate::modifier::Modifier;
+use crate::style::Color;
+
+/// Related content in a bordered card under a title, like SwiftUI's
+/// `GroupBox`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// GroupBox::new(
+///     Label::new("heart.fill", "Health"),
+///     VStack::new()
+///         .alignment(HorizontalAlignment::Leading)
+///         .child(Text::new("Steps: 8,204"))
+///         .child(Text::new("Distance: 6.1 km")),
+/// )
+/// ```
+#[derive(IntoElement)]
+pub struct GroupBox {
+    label: AnyElement,
+    content: AnyElement,
+}
+
+impl GroupBox {
+    /// Create a card titled `label` around `content`.
+    pub fn new(label: impl IntoElement, content: impl IntoElement) -> Self {
+        Self {
+            label: label.into_any_element(),
+            content: content.into_any_element(),
+        }
+    }
+}
+
+impl Modifier for GroupBox {}
+
+impl RenderOnce for GroupBox {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let background = Color::secondary_system_background().resolve_in(cx);
+        div()
+            .flex()
+            .flex_col()
+            .gap(px(8.0))
+            .p(px(12.0))
+            .rounded(px(8.0))
+            .border_1()
+            .border_color(Color::border().resolve_in(cx))
+            .bg(background)
+            .child(div().font_weight(FontWeight::SEMIBOLD).child(self.label))
+            .child(self.content)
+    }
+}
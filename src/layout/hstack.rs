@@ -1,8 +1,10 @@
 //! HStack - Horizontal stack layout.
 
-use gpui::{div, px, App, IntoElement, ParentElement, RenderOnce, Styled, Window};
+use gpui::{div, px, App, Axis, IntoElement, ParentElement, RenderOnce, Styled, Window};
 
 use crate::alignment::VerticalAlignment;
+use crate::environment::EnvironmentScope;
+use crate::layout::stack_spacing::{Distribution, SpacingOverrides};
 use crate::modifier::Modifier;
 
 /// A view that arranges its children in a horizontal line.
@@ -22,21 +24,28 @@ use crate::modifier::Modifier;
 #[derive(IntoElement)]
 pub struct HStack {
     spacing: f32,
+    spacing_overrides: SpacingOverrides,
     alignment: VerticalAlignment,
+    distribution: Distribution,
     children: Vec<gpui::AnyElement>,
 }
 
 impl HStack {
     /// Create a new horizontal stack.
+    ///
+    /// Children are 8 points apart by default, like SwiftUI's standard
+    /// spacing. Use `.spacing(0.0)` for children that touch.
     pub fn new() -> Self {
         Self {
             spacing: 8.0,
+            spacing_overrides: SpacingOverrides::default(),
             alignment: VerticalAlignment::Center, // SwiftUI default
+            distribution: Distribution::default(),
             children: Vec::new(),
         }
     }
 
-    /// Set the spacing between children.
+    /// Set the spacing between children in points (default 8).
     pub fn spacing(mut self, spacing: f32) -> Self {
         self.spacing = spacing;
         self
@@ -48,6 +57,24 @@ impl HStack {
         self
     }
 
+    /// Set the spacing after the child at `index`, in points, instead of
+    /// the stack's spacing, such as to set a destructive action apart from
+    /// the rest of a toolbar.
+    ///
+    /// Under a distribution other than [`Distribution::Fill`], the
+    /// overridden gap also takes an extra share of the free space.
+    pub fn spacing_between(mut self, index: usize, spacing: f32) -> Self {
+        self.spacing_overrides.set(index, spacing);
+        self
+    }
+
+    /// Set how children are spread out when the stack is longer than they
+    /// need (default [`Distribution::Fill`]).
+    pub fn distribution(mut self, distribution: Distribution) -> Self {
+        self.distribution = distribution;
+        self
+    }
+
     impl_child_methods!();
 }
 
@@ -66,8 +93,98 @@ impl RenderOnce for HStack {
             .flex_row()
             .overflow_hidden()
             .gap(px(self.spacing));
-        self.alignment
-            .apply_as_items(container)
-            .children(self.children)
+        let children = self
+            .spacing_overrides
+            .apply(self.children, self.spacing, Axis::Horizontal);
+        let stack = self
+            .distribution
+            .apply(self.alignment.apply_as_items(container))
+            .children(children);
+        // Spacers inside apply their minimum length to the width
+        EnvironmentScope::new(stack).stack_axis(Axis::Horizontal)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use gpui::{canvas, point, size, AvailableSpace, Bounds, Pixels, TestAppContext};
+
+    use super::*;
+
+    type Recorded = Rc<Cell<Option<Bounds<Pixels>>>>;
+
+    /// A 10pt square that records where it is laid out.
+    fn marker(recorded: &Recorded) -> impl IntoElement {
+        let recorded = recorded.clone();
+        canvas(
+            move |bounds, _, _| recorded.set(Some(bounds)),
+            |_, _, _, _| {},
+        )
+        .size(px(10.))
+    }
+
+    #[test]
+    fn children_default_to_standard_spacing() {
+        let (first, second) = (Recorded::default(), Recorded::default());
+        let mut cx = TestAppContext::single();
+        let cx = cx.add_empty_window();
+        cx.draw(
+            point(px(0.), px(0.)),
+            size(
+                AvailableSpace::Definite(px(300.)),
+                AvailableSpace::Definite(px(300.)),
+            ),
+            |_, _| {
+                HStack::new()
+                    .child(marker(&first))
+                    .child(marker(&second))
+                    .into_any_element()
+            },
+        );
+
+        let (first, second) = (first.get().unwrap(), second.get().unwrap());
+        assert_eq!(second.left() - first.right(), px(8.));
+    }
+
+    /// Lay out three markers in a 300pt-wide stack built by `stack`.
+    fn lay_out_three(stack: fn(HStack) -> HStack) -> [Bounds<Pixels>; 3] {
+        let recorded: [Recorded; 3] = Default::default();
+        let markers = recorded.clone();
+        let mut cx = TestAppContext::single();
+        let cx = cx.add_empty_window();
+        cx.draw(
+            point(px(0.), px(0.)),
+            size(
+                AvailableSpace::Definite(px(300.)),
+                AvailableSpace::Definite(px(300.)),
+            ),
+            |_, _| {
+                stack(HStack::new())
+                    .children(markers.iter().map(marker))
+                    .frame_width(300.0)
+                    .into_any_element()
+            },
+        );
+        recorded.map(|bounds| bounds.get().unwrap())
+    }
+
+    #[test]
+    fn space_between_puts_children_at_the_edges() {
+        let [first, second, third] =
+            lay_out_three(|stack| stack.distribution(Distribution::SpaceBetween));
+        assert_eq!(first.left(), px(0.));
+        assert_eq!(third.right(), px(300.));
+        assert_eq!(second.left() - first.right(), third.left() - second.right());
+    }
+
+    #[test]
+    fn spacing_between_overrides_one_gap() {
+        let [first, second, third] =
+            lay_out_three(|stack| stack.spacing_between(0, 24.0).spacing_between(1, 2.0));
+        assert_eq!(second.left() - first.right(), px(24.));
+        assert_eq!(third.left() - second.right(), px(2.));
     }
 }
@@ -6,6 +6,7 @@ use std::rc::Rc;
 
 use gpui::{div, px, AnyElement, App, Entity, IntoElement, ParentElement, Render, Styled, Window};
 
+use crate::layout::geometry_reader::GeometryReader;
 use crate::layout::grid_item::{GridItem, GridItemSize};
 use crate::modifier::Modifier;
 
@@ -46,8 +47,7 @@ pub struct LazyHGrid<V: Render + 'static> {
     vertical_spacing: f32,
     item_count: usize,
     render_fn: Option<LazyGridRenderFn<V>>,
-    /// Container height for adaptive row calculation.
-    /// If not set, defaults to 300.0 for adaptive rows.
+    /// Height for adaptive row calculation, overriding the measured height.
     container_height: Option<f32>,
     /// Column width for virtualization. Defaults to 100.0.
     column_width: f32,
@@ -111,10 +111,10 @@ impl<V: Render + 'static> LazyHGrid<V> {
         self
     }
 
-    /// Set the container height for adaptive row calculation.
+    /// Set the height used to calculate `GridItem::adaptive()` rows.
     ///
-    /// This is required for `GridItem::adaptive()` to calculate
-    /// the correct number of rows.
+    /// By default the grid measures its own height, so adaptive rows
+    /// follow it as the window resizes.
     pub fn container_height(mut self, height: f32) -> Self {
         self.container_height = Some(height);
         self
@@ -205,9 +205,15 @@ impl<V: Render + 'static> LazyHGrid<V> {
 
     /// Build and return the virtual grid element.
     pub fn build(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
-        // Use provided container height or default to 300.0 for adaptive calculation
-        let available_height = self.container_height.unwrap_or(300.0);
+        match self.container_height {
+            Some(height) => self.build_list(height).into_any_element(),
+            None => GeometryReader::new(move |size| self.build_list(f32::from(size.height)))
+                .into_any_element(),
+        }
+    }
 
+    /// Build the virtual list for a grid `available_height` tall.
+    fn build_list(self, available_height: f32) -> impl IntoElement {
         // Get effective rows (expanding adaptive if needed)
         let effective_rows = self.effective_rows(available_height);
         let row_count = effective_rows.len();
@@ -218,10 +224,7 @@ impl<V: Render + 'static> LazyHGrid<V> {
         let col_width = self.column_width;
 
         let item_sizes = Rc::new(vec![
-            gpui::size(
-                px(col_width + horizontal_spacing),
-                px(10000.0)
-            );
+            gpui::size(px(col_width + horizontal_spacing), px(0.));
             col_count
         ]);
 
@@ -44,11 +44,17 @@
 //! }
 //! ```
 
+use std::cell::Cell;
+use std::ops::Range;
 use std::rc::Rc;
 
-use gpui::{px, size, AnyElement, App, Entity, IntoElement, Pixels, Render, Size, Window};
+use gpui::{
+    div, px, size, Along, AnyElement, App, Axis, Div, Entity, IntoElement, ParentElement, Pixels,
+    Render, ScrollStrategy, Size, Styled, Window,
+};
+use gpui_component::scroll::ScrollableElement;
 
-use crate::alignment::HorizontalAlignment;
+use crate::alignment::{HorizontalAlignment, VerticalAlignment};
 use crate::modifier::Modifier;
 
 // Re-export for convenience
@@ -57,19 +63,85 @@ pub use gpui_component::VirtualListScrollHandle;
 /// Type alias for the item render function used by lazy stacks.
 type LazyRenderFn<V> = Rc<dyn Fn(&V, usize, &mut Window, &mut App) -> AnyElement>;
 
+/// Type alias for the function giving each item's extent along the scroll
+/// axis.
+pub(crate) type ItemSizeFn = Rc<dyn Fn(usize) -> f32>;
+
+/// Type alias for the scroll handler used by lazy stacks.
+type ScrollHandler = Rc<dyn Fn(&ScrollInfo, &mut Window, &mut App)>;
+
+/// Where an item lands in the viewport after [`LazyScrollHandleExt::scroll_to`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ScrollAnchor {
+    /// At the start of the viewport (default).
+    #[default]
+    Top,
+    /// In the middle of the viewport, where there are enough items around it.
+    Center,
+    /// At the end of the viewport, where there are enough items before it.
+    Bottom,
+}
+
+impl From<ScrollAnchor> for ScrollStrategy {
+    fn from(anchor: ScrollAnchor) -> Self {
+        match anchor {
+            ScrollAnchor::Top => ScrollStrategy::Top,
+            ScrollAnchor::Center => ScrollStrategy::Center,
+            ScrollAnchor::Bottom => ScrollStrategy::Bottom,
+        }
+    }
+}
+
+/// Programmatic scrolling for the handle of a lazy container.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// // "Jump to today"
+/// self.scroll_handle.scroll_to(today_index, ScrollAnchor::Center);
+/// ```
+pub trait LazyScrollHandleExt {
+    /// Scroll so the item at `index` sits at `anchor` on the next frame.
+    ///
+    /// For a `LazyVGrid` or `LazyHGrid` the index is a row or column of the
+    /// grid, counting section headers, rather than an item.
+    fn scroll_to(&self, index: usize, anchor: ScrollAnchor);
+}
+
+impl LazyScrollHandleExt for VirtualListScrollHandle {
+    fn scroll_to(&self, index: usize, anchor: ScrollAnchor) {
+        self.scroll_to_item(index, anchor.into());
+    }
+}
+
+/// The visible part of a lazy stack, reported by `on_scroll`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ScrollInfo {
+    /// The first item at least partly visible.
+    pub first_visible: usize,
+    /// The last item at least partly visible.
+    pub last_visible: usize,
+    /// How far the content is scrolled, from 0.0 at the start to 1.0 at the
+    /// end; 0.0 when it all fits.
+    pub offset_fraction: f32,
+}
+
 /// A vertically scrolling container that only renders visible items.
 ///
 /// Use this for large lists where rendering all items would be inefficient.
-/// Items are rendered lazily as they come into view.
+/// Items are rendered lazily as they come into view. Items share one height
+/// unless given their own with [`item_size_for`](Self::item_size_for).
 pub struct LazyVStack<V: Render + 'static> {
     entity: Entity<V>,
     element_id: &'static str,
     scroll_handle: VirtualListScrollHandle,
     item_count: usize,
     item_height: f32,
+    item_size_for: Option<ItemSizeFn>,
     spacing: f32,
     alignment: HorizontalAlignment,
     render_fn: Option<LazyRenderFn<V>>,
+    on_scroll: Option<ScrollHandler>,
 }
 
 impl<V: Render + 'static> LazyVStack<V> {
@@ -91,9 +163,11 @@ impl<V: Render + 'static> LazyVStack<V> {
             scroll_handle: scroll_handle.clone(),
             item_count: 0,
             item_height: 44.0,
+            item_size_for: None,
             spacing: 0.0,
             alignment: HorizontalAlignment::Center,
             render_fn: None,
+            on_scroll: None,
         }
     }
 
@@ -109,13 +183,40 @@ impl<V: Render + 'static> LazyVStack<V> {
         self
     }
 
+    /// Give each item its own height in pixels, overriding `item_height`.
+    ///
+    /// Called for every index on each render, so heights follow the
+    /// item count and the data without manual invalidation.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// LazyVStack::new(cx.entity().clone(), "chat", &self.scroll_handle)
+    ///     .item_count(self.messages.len())
+    ///     .item_size_for({
+    ///         let heights: Vec<f32> = self.messages.iter().map(bubble_height).collect();
+    ///         move |ix| heights[ix]
+    ///     })
+    /// ```
+    pub fn item_size_for(mut self, size_for: impl Fn(usize) -> f32 + 'static) -> Self {
+        self.item_size_for = Some(Rc::new(size_for));
+        self
+    }
+
+    /// Set the item count and heights from sizes made by
+    /// [`calculate_item_sizes`].
+    pub fn item_sizes(mut self, sizes: Rc<Vec<Size<Pixels>>>) -> Self {
+        self.item_count = sizes.len();
+        self.item_size_for(move |ix| f32::from(sizes[ix].height))
+    }
+
     /// Set the spacing between items.
     pub fn spacing(mut self, spacing: f32) -> Self {
         self.spacing = spacing;
         self
     }
 
-    /// Set the horizontal alignment of items.
+    /// Set the horizontal alignment of items (default center, like VStack).
     pub fn alignment(mut self, alignment: HorizontalAlignment) -> Self {
         self.alignment = alignment;
         self
@@ -136,33 +237,68 @@ impl<V: Render + 'static> LazyVStack<V> {
         self
     }
 
+    /// Call a handler when the visible items or scroll position change.
+    ///
+    /// The handler runs after the frame that changed them, so it can update
+    /// the view, such as showing a "new messages" pill.
+    pub fn on_scroll(
+        mut self,
+        handler: impl Fn(&ScrollInfo, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_scroll = Some(Rc::new(handler));
+        self
+    }
+
     /// Build and return the virtual list element.
-    pub fn build(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+    pub fn build(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
         let item_height = self.item_height;
-        let item_sizes = Rc::new(vec![
-            size(px(10000.0), px(item_height + self.spacing));
-            self.item_count
-        ]);
+        let size_for = self
+            .item_size_for
+            .unwrap_or_else(|| Rc::new(move |_| item_height));
+        let item_sizes = Rc::new(main_axis_sizes(
+            self.item_count,
+            self.spacing,
+            size_for.as_ref(),
+            |extent| size(px(0.), px(extent)),
+        ));
+        let report = ScrollReporter::new(
+            self.on_scroll,
+            self.element_id,
+            &self.scroll_handle,
+            &item_sizes,
+            Axis::Vertical,
+            window,
+            cx,
+        );
         let render_fn = self.render_fn;
-        let _alignment = self.alignment;
+        let alignment = self.alignment;
 
-        gpui_component::v_virtual_list(
+        let list = gpui_component::v_virtual_list(
             self.entity,
             self.element_id,
             item_sizes,
             move |view, visible_range, window, cx| {
+                if let Some(report) = &report {
+                    report.update(visible_range.clone(), window, cx);
+                }
                 visible_range
                     .map(|ix| {
-                        if let Some(ref render) = render_fn {
-                            render(view, ix, window, cx)
-                        } else {
-                            gpui::div().into_any_element()
+                        let row = align_row(alignment);
+                        match render_fn {
+                            Some(ref render) => row.child(render(view, ix, window, cx)),
+                            None => row,
                         }
                     })
                     .collect()
             },
         )
-        .track_scroll(&self.scroll_handle)
+        .track_scroll(&self.scroll_handle);
+
+        div()
+            .relative()
+            .size_full()
+            .child(list)
+            .vertical_scrollbar(&self.scroll_handle)
     }
 }
 
@@ -179,7 +315,9 @@ pub struct LazyHStack<V: Render + 'static> {
     item_count: usize,
     item_width: f32,
     spacing: f32,
+    alignment: VerticalAlignment,
     render_fn: Option<LazyRenderFn<V>>,
+    on_scroll: Option<ScrollHandler>,
 }
 
 impl<V: Render + 'static> LazyHStack<V> {
@@ -202,7 +340,9 @@ impl<V: Render + 'static> LazyHStack<V> {
             item_count: 0,
             item_width: 100.0,
             spacing: 0.0,
+            alignment: VerticalAlignment::Center,
             render_fn: None,
+            on_scroll: None,
         }
     }
 
@@ -224,6 +364,12 @@ impl<V: Render + 'static> LazyHStack<V> {
         self
     }
 
+    /// Set the vertical alignment of items (default center, like HStack).
+    pub fn alignment(mut self, alignment: VerticalAlignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
     /// Set the render function for items.
     ///
     /// The function receives the view, item index, window, and app context,
@@ -239,26 +385,53 @@ impl<V: Render + 'static> LazyHStack<V> {
         self
     }
 
+    /// Call a handler when the visible items or scroll position change.
+    ///
+    /// The handler runs after the frame that changed them, so it can update
+    /// the view, such as showing a "new messages" pill.
+    pub fn on_scroll(
+        mut self,
+        handler: impl Fn(&ScrollInfo, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_scroll = Some(Rc::new(handler));
+        self
+    }
+
     /// Build and return the virtual list element.
-    pub fn build(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+    pub fn build(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
         let item_width = self.item_width;
-        let item_sizes = Rc::new(vec![
-            size(px(item_width + self.spacing), px(10000.0));
-            self.item_count
-        ]);
+        let item_sizes = Rc::new(main_axis_sizes(
+            self.item_count,
+            self.spacing,
+            &move |_| item_width,
+            |extent| size(px(extent), px(0.)),
+        ));
+        let report = ScrollReporter::new(
+            self.on_scroll,
+            self.element_id,
+            &self.scroll_handle,
+            &item_sizes,
+            Axis::Horizontal,
+            window,
+            cx,
+        );
         let render_fn = self.render_fn;
+        let alignment = self.alignment;
 
         gpui_component::h_virtual_list(
             self.entity,
             self.element_id,
             item_sizes,
             move |view, visible_range, window, cx| {
+                if let Some(report) = &report {
+                    report.update(visible_range.clone(), window, cx);
+                }
                 visible_range
                     .map(|ix| {
-                        if let Some(ref render) = render_fn {
-                            render(view, ix, window, cx)
-                        } else {
-                            gpui::div().into_any_element()
+                        let column = align_column(alignment);
+                        match render_fn {
+                            Some(ref render) => column.child(render(view, ix, window, cx)),
+                            None => column,
                         }
                     })
                     .collect()
@@ -270,7 +443,117 @@ impl<V: Render + 'static> LazyHStack<V> {
 
 impl<V: Render + 'static> Modifier for LazyHStack<V> {}
 
+/// The container for an item of a LazyVStack, filling the item's row and
+/// aligning the item across it as VStack does.
+fn align_row(alignment: HorizontalAlignment) -> Div {
+    alignment.apply_as_items(div().size_full().flex().flex_col())
+}
+
+/// The container for an item of a LazyHStack, filling the item's column
+/// and aligning the item along it as HStack does.
+fn align_column(alignment: VerticalAlignment) -> Div {
+    alignment.apply_as_items(div().size_full().flex().flex_row())
+}
+
+/// Reports a lazy stack's visible items to its `on_scroll` handler when
+/// they change.
+struct ScrollReporter {
+    handler: ScrollHandler,
+    scroll_handle: VirtualListScrollHandle,
+    axis: Axis,
+    /// The length of the content along the scroll axis.
+    content: f32,
+    /// The last info reported, kept across renders.
+    last: Entity<Cell<Option<ScrollInfo>>>,
+}
+
+impl ScrollReporter {
+    fn new(
+        handler: Option<ScrollHandler>,
+        element_id: &'static str,
+        scroll_handle: &VirtualListScrollHandle,
+        item_sizes: &[Size<Pixels>],
+        axis: Axis,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Option<Self> {
+        let handler = handler?;
+        let key = gpui::SharedString::from(format!("{element_id}-scroll-info"));
+        let last = window.use_keyed_state(key, cx, |_, _| Cell::new(None));
+        let content = item_sizes
+            .iter()
+            .map(|size| f32::from(size.along(axis)))
+            .sum();
+        Some(Self {
+            handler,
+            scroll_handle: scroll_handle.clone(),
+            axis,
+            content,
+            last,
+        })
+    }
+
+    /// Report `visible` if it or the scroll position changed since the last
+    /// report.
+    fn update(&self, visible: Range<usize>, window: &mut Window, cx: &mut App) {
+        let base = self.scroll_handle.base_handle();
+        let scrolled = -f32::from(base.offset().along(self.axis));
+        let viewport = f32::from(base.bounds().size.along(self.axis));
+        let Some(info) = scroll_info(visible, scrolled, self.content, viewport) else {
+            return;
+        };
+        if self.last.read(cx).replace(Some(info)) == Some(info) {
+            return;
+        }
+        // The view is still rendering, so it can't be updated until after
+        let handler = self.handler.clone();
+        window.defer(cx, move |window, cx| handler(&info, window, cx));
+    }
+}
+
+/// The info for the `visible` items with the content scrolled by `scrolled`
+/// along a `content` long axis, seen through a `viewport` long window.
+fn scroll_info(
+    visible: Range<usize>,
+    scrolled: f32,
+    content: f32,
+    viewport: f32,
+) -> Option<ScrollInfo> {
+    if visible.is_empty() {
+        return None;
+    }
+    let max_scroll = content - viewport;
+    let offset_fraction = if max_scroll > 0.0 {
+        (scrolled / max_scroll).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    Some(ScrollInfo {
+        first_visible: visible.start,
+        last_visible: visible.end - 1,
+        offset_fraction,
+    })
+}
+
+/// The virtual list sizes for `count` items, each `size_for(ix)` plus
+/// `spacing` along the scroll axis.
+///
+/// The cross axis is left at zero: the list lays items out at its own width,
+/// and a larger size there would only widen the scrollable content.
+pub(crate) fn main_axis_sizes(
+    count: usize,
+    spacing: f32,
+    size_for: &dyn Fn(usize) -> f32,
+    to_size: impl Fn(f32) -> Size<Pixels>,
+) -> Vec<Size<Pixels>> {
+    (0..count)
+        .map(|ix| to_size(size_for(ix) + spacing))
+        .collect()
+}
+
 /// Helper function to calculate item sizes for variable-height lists.
+///
+/// Pass the result to [`LazyVStack::item_sizes`].
 pub fn calculate_item_sizes<F>(count: usize, size_fn: F) -> Rc<Vec<Size<Pixels>>>
 where
     F: Fn(usize) -> Size<Pixels>,
@@ -282,3 +565,68 @@ where
 pub fn uniform_size(width: f32, height: f32) -> impl Fn(usize) -> Size<Pixels> {
     move |_| size(px(width), px(height))
 }
+
+#[cfg(test)]
+mod tests {
+    use gpui::AlignItems;
+
+    use super::*;
+
+    #[test]
+    fn each_item_gets_its_own_extent_plus_spacing() {
+        let heights = [40.0, 200.0, 96.0];
+        let sizes = main_axis_sizes(3, 8.0, &|ix| heights[ix], |extent| size(px(0.), px(extent)));
+        assert_eq!(
+            sizes,
+            vec![
+                size(px(0.), px(48.)),
+                size(px(0.), px(208.)),
+                size(px(0.), px(104.)),
+            ]
+        );
+        let total: f32 = sizes.iter().map(|size| f32::from(size.height)).sum();
+        assert_eq!(total, 360.0);
+    }
+
+    #[test]
+    fn item_containers_follow_the_alignment() {
+        let items = |alignment| align_row(alignment).style().align_items;
+        assert_eq!(
+            items(HorizontalAlignment::Leading),
+            Some(AlignItems::FlexStart)
+        );
+        assert_eq!(items(HorizontalAlignment::Center), Some(AlignItems::Center));
+        assert_eq!(
+            items(HorizontalAlignment::Trailing),
+            Some(AlignItems::FlexEnd)
+        );
+
+        let items = |alignment| align_column(alignment).style().align_items;
+        assert_eq!(items(VerticalAlignment::Top), Some(AlignItems::FlexStart));
+        assert_eq!(items(VerticalAlignment::Bottom), Some(AlignItems::FlexEnd));
+        assert_eq!(
+            align_row(HorizontalAlignment::Trailing)
+                .style()
+                .flex_direction,
+            Some(gpui::FlexDirection::Column)
+        );
+    }
+
+    #[test]
+    fn scroll_info_reports_the_visible_range_and_fraction() {
+        assert_eq!(
+            scroll_info(10..20, 250.0, 1000.0, 500.0),
+            Some(ScrollInfo {
+                first_visible: 10,
+                last_visible: 19,
+                offset_fraction: 0.5,
+            })
+        );
+        // Content that fits doesn't scroll
+        assert_eq!(
+            scroll_info(0..3, 0.0, 300.0, 500.0).map(|info| info.offset_fraction),
+            Some(0.0)
+        );
+        assert_eq!(scroll_info(0..0, 0.0, 0.0, 500.0), None);
+    }
+}
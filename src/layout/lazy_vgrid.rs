@@ -1,12 +1,21 @@
 //! LazyVGrid - A vertically-scrolling grid with fixed columns.
 //!
 //! Items flow left-to-right, top-to-bottom. Renders lazily for performance.
+//! Items can be grouped into sections with headers, optionally pinned to the
+//! top while their section scrolls.
 
 use std::rc::Rc;
 
-use gpui::{div, px, AnyElement, App, Entity, IntoElement, ParentElement, Render, Styled, Window};
+use gpui::{
+    div, point, px, relative, AnyElement, App, Bounds, ContentMask, Element, ElementId, Entity,
+    GlobalElementId, InspectorElementId, IntoElement, LayoutId, ParentElement, Pixels, Position,
+    Render, Style, Styled, Window,
+};
+use gpui_component::IndexPath;
 
+use crate::layout::geometry_reader::GeometryReader;
 use crate::layout::grid_item::{GridItem, GridItemSize};
+use crate::layout::lazy_stack::ItemSizeFn;
 use crate::modifier::Modifier;
 
 // Re-export for convenience
@@ -15,6 +24,50 @@ pub use gpui_component::VirtualListScrollHandle;
 /// Type alias for the item render function.
 type LazyGridRenderFn<V> = Rc<dyn Fn(&V, usize, &mut Window, &mut App) -> AnyElement>;
 
+/// Type alias for the sectioned item render function.
+type LazyGridSectionRenderFn<V> = Rc<dyn Fn(&V, IndexPath, &mut Window, &mut App) -> AnyElement>;
+
+/// Type alias for the section header render function.
+type LazyGridHeaderFn = Rc<dyn Fn(&mut Window, &mut App) -> AnyElement>;
+
+/// A section of a [`LazyVGrid`]: a number of items under an optional header.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// LazyGridSection::new(contacts.len())
+///     .header(|_, _| Text::new("A").bold().padding(8.0))
+/// ```
+#[derive(Clone)]
+pub struct LazyGridSection {
+    header: Option<LazyGridHeaderFn>,
+    item_count: usize,
+}
+
+impl LazyGridSection {
+    /// Create a section of `item_count` items.
+    pub fn new(item_count: usize) -> Self {
+        Self {
+            header: None,
+            item_count,
+        }
+    }
+
+    /// Set the header shown above the section's items.
+    ///
+    /// The header is laid out `header_height` tall, as set on the grid.
+    pub fn header<F, E>(mut self, header: F) -> Self
+    where
+        F: Fn(&mut Window, &mut App) -> E + 'static,
+        E: IntoElement,
+    {
+        self.header = Some(Rc::new(move |window, cx| {
+            header(window, cx).into_any_element()
+        }));
+        self
+    }
+}
+
 /// A vertically-scrolling grid with fixed columns.
 ///
 /// Items are laid out left-to-right, top-to-bottom. The grid renders
@@ -37,6 +90,20 @@ type LazyGridRenderFn<V> = Rc<dyn Fn(&V, usize, &mut Window, &mut App) -> AnyEle
 ///         PhotoCell::new(&view.photos[index])
 ///     })
 ///     .build(window, cx)
+///
+/// // Grouped, with the current section's header pinned to the top
+/// LazyVGrid::new(cx.entity().clone(), "contacts", &self.scroll_handle)
+///     .columns(vec![GridItem::flexible(); 3])
+///     .sections(letters.iter().map(|group| {
+///         let letter = group.letter.clone();
+///         LazyGridSection::new(group.contacts.len())
+///             .header(move |_, _| Text::new(letter.clone()).bold())
+///     }).collect())
+///     .pinned_headers(true)
+///     .render_section_item(|view, path, _, _| {
+///         ContactCell::new(&view.groups[path.section].contacts[path.row])
+///     })
+///     .build(window, cx)
 /// ```
 pub struct LazyVGrid<V: Render + 'static> {
     entity: Entity<V>,
@@ -47,11 +114,16 @@ pub struct LazyVGrid<V: Render + 'static> {
     vertical_spacing: f32,
     item_count: usize,
     render_fn: Option<LazyGridRenderFn<V>>,
-    /// Container width for adaptive column calculation.
-    /// If not set, defaults to 400.0 for adaptive columns.
+    sections: Option<Vec<LazyGridSection>>,
+    section_render_fn: Option<LazyGridSectionRenderFn<V>>,
+    pinned_headers: bool,
+    /// Section header height for virtualization. Defaults to 32.0.
+    header_height: f32,
+    /// Width for adaptive column calculation, overriding the measured width.
     container_width: Option<f32>,
     /// Row height for virtualization. Defaults to 100.0.
     row_height: f32,
+    item_size_for: Option<ItemSizeFn>,
 }
 
 impl<V: Render + 'static> LazyVGrid<V> {
@@ -76,8 +148,13 @@ impl<V: Render + 'static> LazyVGrid<V> {
             vertical_spacing: 0.0,
             item_count: 0,
             render_fn: None,
+            sections: None,
+            section_render_fn: None,
+            pinned_headers: false,
+            header_height: 32.0,
             container_width: None,
             row_height: 100.0,
+            item_size_for: None,
         }
     }
 
@@ -112,10 +189,37 @@ impl<V: Render + 'static> LazyVGrid<V> {
         self
     }
 
-    /// Set the container width for adaptive column calculation.
+    /// Group the items into sections, replacing `item_count`.
     ///
-    /// This is required for `GridItem::adaptive()` to calculate
-    /// the correct number of columns.
+    /// Each section starts on a new row, below its header. Render the items
+    /// with [`render_section_item`](Self::render_section_item).
+    pub fn sections(mut self, sections: Vec<LazyGridSection>) -> Self {
+        self.sections = Some(sections);
+        self
+    }
+
+    /// Keep the header of the section at the top of the grid pinned while
+    /// its items scroll beneath, like SwiftUI's
+    /// `pinnedViews: [.sectionHeaders]`.
+    ///
+    /// Give headers a background so the items don't show through.
+    pub fn pinned_headers(mut self, pinned: bool) -> Self {
+        self.pinned_headers = pinned;
+        self
+    }
+
+    /// Set the section header height for virtualization.
+    ///
+    /// Defaults to 32.0.
+    pub fn header_height(mut self, height: f32) -> Self {
+        self.header_height = height;
+        self
+    }
+
+    /// Set the width used to calculate `GridItem::adaptive()` columns.
+    ///
+    /// By default the grid measures its own width, so adaptive columns
+    /// follow it as the window resizes.
     pub fn container_width(mut self, width: f32) -> Self {
         self.container_width = Some(width);
         self
@@ -130,6 +234,15 @@ impl<V: Render + 'static> LazyVGrid<V> {
         self
     }
 
+    /// Give each item its own height in pixels, overriding `row_height`.
+    ///
+    /// Each row is as tall as its tallest item. With sections, the index
+    /// counts items across all sections in order.
+    pub fn item_size_for(mut self, size_for: impl Fn(usize) -> f32 + 'static) -> Self {
+        self.item_size_for = Some(Rc::new(size_for));
+        self
+    }
+
     /// Set the render function for items.
     ///
     /// The function receives the view, item index, window, and app context,
@@ -145,6 +258,21 @@ impl<V: Render + 'static> LazyVGrid<V> {
         self
     }
 
+    /// Set the render function for items in sections.
+    ///
+    /// The function receives the view, the item's section and its row
+    /// within the section as an [`IndexPath`], window, and app context.
+    pub fn render_section_item<F, E>(mut self, render_fn: F) -> Self
+    where
+        F: Fn(&V, IndexPath, &mut Window, &mut App) -> E + 'static,
+        E: IntoElement,
+    {
+        self.section_render_fn = Some(Rc::new(move |view, path, window, cx| {
+            render_fn(view, path, window, cx).into_any_element()
+        }));
+        self
+    }
+
     /// Calculate the number of columns (handling Adaptive sizing).
     ///
     /// For Fixed and Flexible columns, returns the number of column definitions.
@@ -201,53 +329,126 @@ impl<V: Render + 'static> LazyVGrid<V> {
         result
     }
 
-    /// Calculate row count based on item count and column count.
-    fn row_count(&self, col_count: usize) -> usize {
-        if col_count == 0 || self.item_count == 0 {
-            0
-        } else {
-            self.item_count.div_ceil(col_count)
-        }
-    }
-
     /// Build and return the virtual grid element.
     pub fn build(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
-        // Use provided container width or default to 400.0 for adaptive calculation
-        let available_width = self.container_width.unwrap_or(400.0);
+        match self.container_width {
+            Some(width) => self.build_list(width).into_any_element(),
+            None => GeometryReader::new(move |size| self.build_list(f32::from(size.width)))
+                .into_any_element(),
+        }
+    }
 
+    /// Build the virtual list for a grid `available_width` wide.
+    fn build_list(self, available_width: f32) -> impl IntoElement {
         // Get effective columns (expanding adaptive if needed)
         let effective_cols = self.effective_columns(available_width);
         let col_count = effective_cols.len();
-        let row_count = self.row_count(col_count);
-        let item_count = self.item_count;
         let horizontal_spacing = self.horizontal_spacing;
-        let vertical_spacing = self.vertical_spacing;
+        let header_height = self.header_height;
+
+        // Without sections, all items form one section with no header
+        let sections = self
+            .sections
+            .unwrap_or_else(|| vec![LazyGridSection::new(self.item_count)]);
+        let counts: Vec<_> = sections
+            .iter()
+            .map(|section| (section.header.is_some(), section.item_count))
+            .collect();
+        let rows = grid_rows(&counts, col_count);
+
+        // The index of each section's first item across all sections
+        let section_starts: Vec<usize> = counts
+            .iter()
+            .scan(0, |start, (_, count)| {
+                let section_start = *start;
+                *start += count;
+                Some(section_start)
+            })
+            .collect();
         let row_height = self.row_height;
+        let size_for = self.item_size_for;
+        let item_sizes = Rc::new(
+            rows.iter()
+                .map(|row| {
+                    let height = match *row {
+                        GridRowKind::Header { .. } => header_height,
+                        GridRowKind::Items {
+                            section,
+                            first_item,
+                            item_count,
+                        } => {
+                            let start = section_starts[section] + first_item;
+                            let end =
+                                section_starts[section] + item_count.min(first_item + col_count);
+                            let height = match &size_for {
+                                Some(size_for) => {
+                                    (start..end).map(|ix| size_for(ix)).fold(0.0, f32::max)
+                                }
+                                None => row_height,
+                            };
+                            height + self.vertical_spacing
+                        }
+                    };
+                    // The list lays rows out at its own width
+                    gpui::size(px(0.), px(height))
+                })
+                .collect::<Vec<_>>(),
+        );
+
+        // Where each header sits in the scrolled content, for pinning
+        let pinned: Vec<(f32, LazyGridHeaderFn)> = if self.pinned_headers {
+            let mut top = 0.0;
+            rows.iter()
+                .zip(item_sizes.iter())
+                .filter_map(|(row, size)| {
+                    let row_top = top;
+                    top += f32::from(size.height);
+                    match row {
+                        GridRowKind::Header { section } => {
+                            Some((row_top, sections[*section].header.clone()?))
+                        }
+                        GridRowKind::Items { .. } => None,
+                    }
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
 
-        let item_sizes = Rc::new(vec![
-            gpui::size(
-                px(10000.0),
-                px(row_height + vertical_spacing)
-            );
-            row_count
-        ]);
-
+        let headers: Vec<_> = sections.into_iter().map(|section| section.header).collect();
         let render_fn = self.render_fn;
+        let section_render_fn = self.section_render_fn;
         let columns = effective_cols;
 
-        gpui_component::v_virtual_list(
+        let list = gpui_component::v_virtual_list(
             self.entity,
             self.element_id,
             item_sizes,
             move |view, visible_range, window, cx| {
                 visible_range
                     .map(|row_idx| {
+                        let (section, first_item, item_count) = match rows[row_idx] {
+                            GridRowKind::Header { section } => {
+                                let header =
+                                    headers[section].as_ref().map(|header| header(window, cx));
+                                return div()
+                                    .h(px(header_height))
+                                    .children(header)
+                                    .into_any_element();
+                            }
+                            GridRowKind::Items {
+                                section,
+                                first_item,
+                                item_count,
+                            } => (section, first_item, item_count),
+                        };
+
                         // Render a row of items
                         let mut row = div().flex().flex_row().gap(px(horizontal_spacing));
 
                         // Apply column sizing
                         for (col_idx, col) in columns.iter().enumerate() {
-                            let item_idx = row_idx * col_count + col_idx;
+                            let item_idx = first_item + col_idx;
 
                             let mut cell = div();
 
@@ -260,7 +461,10 @@ impl<V: Render + 'static> LazyVGrid<V> {
 
                             // Render item if within bounds
                             if item_idx < item_count {
-                                if let Some(ref render) = render_fn {
+                                if let Some(ref render) = section_render_fn {
+                                    let path = IndexPath::new(item_idx).section(section);
+                                    cell = cell.child(render(view, path, window, cx));
+                                } else if let Some(ref render) = render_fn {
                                     cell = cell.child(render(view, item_idx, window, cx));
                                 }
                             }
@@ -273,8 +477,203 @@ impl<V: Render + 'static> LazyVGrid<V> {
                     .collect()
             },
         )
-        .track_scroll(&self.scroll_handle)
+        .track_scroll(&self.scroll_handle);
+
+        if pinned.is_empty() {
+            return list.into_any_element();
+        }
+        div()
+            .relative()
+            .size_full()
+            .child(list)
+            .child(PinnedHeader {
+                scroll_handle: self.scroll_handle,
+                headers: pinned,
+                height: header_height,
+                content: None,
+            })
+            .into_any_element()
+    }
+}
+
+/// A row of a [`LazyVGrid`]'s virtual list.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum GridRowKind {
+    /// The header of a section.
+    Header { section: usize },
+    /// Up to a row's worth of a section's items, from `first_item`.
+    Items {
+        section: usize,
+        first_item: usize,
+        item_count: usize,
+    },
+}
+
+/// The rows for sections given as `(has_header, item_count)`, with
+/// `col_count` items per row.
+fn grid_rows(sections: &[(bool, usize)], col_count: usize) -> Vec<GridRowKind> {
+    let col_count = col_count.max(1);
+    let mut rows = Vec::new();
+    for (section, &(has_header, item_count)) in sections.iter().enumerate() {
+        if has_header {
+            rows.push(GridRowKind::Header { section });
+        }
+        for first_item in (0..item_count).step_by(col_count) {
+            rows.push(GridRowKind::Items {
+                section,
+                first_item,
+                item_count,
+            });
+        }
+    }
+    rows
+}
+
+/// The header to pin for content scrolled by `scrolled`, given the top of
+/// each header, and how far to shift it up as the next header pushes it
+/// out. `None` until the first header reaches the top.
+fn pinned_header(header_tops: &[f32], scrolled: f32, height: f32) -> Option<(usize, f32)> {
+    let ix = header_tops.iter().rposition(|top| *top <= scrolled)?;
+    let shift = header_tops
+        .get(ix + 1)
+        .map_or(0.0, |next| (next - scrolled - height).min(0.0));
+    Some((ix, shift))
+}
+
+/// The pinned copy of the current section's header, drawn over the top of
+/// the grid.
+///
+/// The header is picked from the scroll offset while the frame is
+/// prepainted, so it follows scrolling without the view re-rendering.
+struct PinnedHeader {
+    scroll_handle: VirtualListScrollHandle,
+    /// The top of each header in the scrolled content, and its builder.
+    headers: Vec<(f32, LazyGridHeaderFn)>,
+    height: f32,
+    content: Option<AnyElement>,
+}
+
+impl IntoElement for PinnedHeader {
+    type Element = Self;
+
+    fn into_element(self) -> Self::Element {
+        self
+    }
+}
+
+impl Element for PinnedHeader {
+    type RequestLayoutState = ();
+    type PrepaintState = ();
+
+    fn id(&self) -> Option<ElementId> {
+        None
+    }
+
+    fn source_location(&self) -> Option<&'static core::panic::Location<'static>> {
+        None
+    }
+
+    fn request_layout(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> (LayoutId, Self::RequestLayoutState) {
+        let mut style = Style {
+            position: Position::Absolute,
+            ..Style::default()
+        };
+        style.inset.top = px(0.).into();
+        style.inset.left = px(0.).into();
+        style.size.width = relative(1.).into();
+        style.size.height = px(self.height).into();
+        (window.request_layout(style, [], cx), ())
+    }
+
+    fn prepaint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        window: &mut Window,
+        cx: &mut App,
+    ) -> Self::PrepaintState {
+        let scrolled = -f32::from(self.scroll_handle.offset().y);
+        let tops: Vec<f32> = self.headers.iter().map(|(top, _)| *top).collect();
+        let Some((ix, shift)) = pinned_header(&tops, scrolled, self.height) else {
+            return;
+        };
+
+        let mut content = (self.headers[ix].1)(window, cx);
+        let origin = bounds.origin + point(px(0.), px(shift));
+        window.with_content_mask(Some(ContentMask { bounds }), |window| {
+            content.prepaint_as_root(origin, bounds.size.into(), window, cx);
+        });
+        self.content = Some(content);
+    }
+
+    fn paint(
+        &mut self,
+        _id: Option<&GlobalElementId>,
+        _inspector_id: Option<&InspectorElementId>,
+        bounds: Bounds<Pixels>,
+        _request_layout: &mut Self::RequestLayoutState,
+        _prepaint: &mut Self::PrepaintState,
+        window: &mut Window,
+        cx: &mut App,
+    ) {
+        if let Some(content) = self.content.as_mut() {
+            window.with_content_mask(Some(ContentMask { bounds }), |window| {
+                content.paint(window, cx);
+            });
+        }
     }
 }
 
 impl<V: Render + 'static> Modifier for LazyVGrid<V> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sections_start_on_new_rows_below_their_headers() {
+        let rows = grid_rows(&[(true, 4), (false, 2), (true, 0)], 3);
+        assert_eq!(
+            rows,
+            vec![
+                GridRowKind::Header { section: 0 },
+                GridRowKind::Items {
+                    section: 0,
+                    first_item: 0,
+                    item_count: 4
+                },
+                GridRowKind::Items {
+                    section: 0,
+                    first_item: 3,
+                    item_count: 4
+                },
+                GridRowKind::Items {
+                    section: 1,
+                    first_item: 0,
+                    item_count: 2
+                },
+                GridRowKind::Header { section: 2 },
+            ]
+        );
+    }
+
+    #[test]
+    fn next_header_pushes_the_pinned_one_up() {
+        let tops = [0.0, 200.0, 500.0];
+        assert_eq!(pinned_header(&tops, 0.0, 30.0), Some((0, 0.0)));
+        assert_eq!(pinned_header(&tops, 100.0, 30.0), Some((0, 0.0)));
+        assert_eq!(pinned_header(&tops, 180.0, 30.0), Some((0, -10.0)));
+        assert_eq!(pinned_header(&tops, 250.0, 30.0), Some((1, 0.0)));
+        assert_eq!(pinned_header(&tops, 900.0, 30.0), Some((2, 0.0)));
+        assert_eq!(pinned_header(&[50.0], 20.0, 30.0), None);
+        assert_eq!(pinned_header(&[], 100.0, 30.0), None);
+    }
+}
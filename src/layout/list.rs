@@ -1,18 +1,37 @@
 //! List - Styled list container with sections.
 
+use std::collections::HashSet;
+use std::rc::Rc;
+use std::time::Duration;
+
 use gpui::{
-    div, px, AnyElement, App, InteractiveElement, IntoElement, ParentElement, RenderOnce,
-    SharedString, Styled, Window,
+    div, pulsating_between, px, relative, rems, Animation, AnimationExt, AnyElement, App,
+    ClickEvent, Div, Entity, FocusHandle, InteractiveElement, IntoElement, KeyDownEvent, Modifiers,
+    ParentElement, RenderOnce, ScrollHandle, SharedString, StatefulInteractiveElement, Styled,
+    Window,
 };
-use gpui_component::scroll::ScrollableElement;
+use gpui_component::scroll::{ScrollableElement, Scrollbar, ScrollbarAxis};
+use gpui_component::{ActiveTheme, Icon, IconName};
 
+use crate::components::{ButtonRole, InputState, NavigationRow, Text, TextField, Toggle};
+use crate::environment;
 use crate::modifier::Modifier;
 use crate::style::Color;
 
+use super::list_editing::{
+    delete_button, drop_target, move_grip, move_state, reorderable, DeleteHandler, MoveHandler,
+    MoveState, RowEditing,
+};
 use super::list_types::{
-    EdgeInsets, EdgeInsetsExt, EdgeSet, ListConfiguration, ListSectionSpacing, RowConfiguration,
-    SectionMargins,
+    EdgeInsets, EdgeInsetsExt, EdgeSet, EditMode, ListConfiguration, ListSectionSpacing,
+    RowConfiguration, RowId, SectionMargins, SelectionMode,
 };
+use super::navigation_stack::{DestinationBuilder, NavigationPath};
+use super::refreshable::{
+    pull_to_refresh, refresh_indicator, refresh_state, RefreshCompletion, RefreshHandler,
+};
+use super::sticky_headers::{pinned_section, sticky_headers, SectionTracking};
+use super::swipe_actions::{RowAction, SwipeRow};
 
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum ListStyle {
@@ -47,10 +66,49 @@ impl ListStyle {
 }
 
 enum ListChild {
-    Section(Section),
+    Section(Box<Section>),
     Element(AnyElement),
 }
 
+/// Called with the new selection when the user changes it.
+type SelectionHandler = Rc<dyn Fn(&HashSet<RowId>, &mut Window, &mut App)>;
+
+/// Selects the row at a position in the list, with the modifiers held.
+type SelectRow = Rc<dyn Fn(usize, &Modifiers, &mut Window, &mut App)>;
+
+/// Focus and the rows keyboard selection moves from, kept across frames.
+struct SelectionState {
+    focus: FocusHandle,
+    /// Where shift-click and shift-arrow ranges start.
+    anchor: Option<RowId>,
+    /// The row last clicked or moved to.
+    cursor: Option<RowId>,
+}
+
+/// What a section needs to draw and select its rows in a selectable list.
+#[derive(Clone)]
+pub(crate) struct RowSelection {
+    selected: Rc<HashSet<RowId>>,
+    select: SelectRow,
+}
+
+impl RowSelection {
+    /// Highlight `row` when selected or hovered, and select it on click.
+    fn apply(&self, row: Div, index: usize, id: &RowId, cx: &App) -> AnyElement {
+        let theme = cx.theme();
+        let row = row.id(("list-row", index));
+        let row = if self.selected.contains(id) {
+            row.bg(theme.selection)
+        } else {
+            let hover = theme.list_hover;
+            row.hover(move |row| row.bg(hover))
+        };
+        let select = self.select.clone();
+        row.on_click(move |event, window, cx| select(index, &event.modifiers(), window, cx))
+            .into_any_element()
+    }
+}
+
 #[derive(IntoElement)]
 pub struct List {
     id: SharedString,
@@ -62,6 +120,19 @@ pub struct List {
     section_spacing: ListSectionSpacing,
     min_row_height: Option<f32>,
     min_header_height: Option<f32>,
+    on_refresh: Option<RefreshHandler>,
+
+    selection_mode: Option<SelectionMode>,
+    selection: HashSet<RowId>,
+    on_selection_change: Option<SelectionHandler>,
+
+    edit_mode: EditMode,
+    on_move: Option<MoveHandler>,
+
+    empty_view: Option<AnyElement>,
+    loading: bool,
+    show_empty_sections: bool,
+    search: Option<ListSearch>,
 }
 
 impl List {
@@ -75,6 +146,16 @@ impl List {
             section_spacing: ListSectionSpacing::Default,
             min_row_height: None,
             min_header_height: None,
+            on_refresh: None,
+            selection_mode: None,
+            selection: HashSet::new(),
+            on_selection_change: None,
+            edit_mode: EditMode::Inactive,
+            on_move: None,
+            empty_view: None,
+            loading: false,
+            show_empty_sections: false,
+            search: None,
         }
     }
 
@@ -84,13 +165,16 @@ impl List {
     }
 
     pub fn section(mut self, section: Section) -> Self {
-        self.children.push(ListChild::Section(section));
+        self.children.push(ListChild::Section(Box::new(section)));
         self
     }
 
     pub fn sections<I: IntoIterator<Item = Section>>(mut self, sections: I) -> Self {
-        self.children
-            .extend(sections.into_iter().map(ListChild::Section));
+        self.children.extend(
+            sections
+                .into_iter()
+                .map(|s| ListChild::Section(Box::new(s))),
+        );
         self
     }
 
@@ -138,6 +222,162 @@ impl List {
         self
     }
 
+    /// Let the user pull the list down past the top to refresh it.
+    ///
+    /// The handler gets a [`RefreshCompletion`]; the refresh indicator
+    /// spins until it is finished.
+    pub fn refreshable(
+        mut self,
+        handler: impl Fn(RefreshCompletion, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_refresh = Some(Rc::new(handler));
+        self
+    }
+
+    /// Let the user select section rows by clicking them, and with the
+    /// arrow keys, Home and End while the list has focus.
+    ///
+    /// The list doesn't keep the selection itself: pass the current one to
+    /// [`selection`](Self::selection) and update it from
+    /// [`on_selection_change`](Self::on_selection_change).
+    pub fn selectable(mut self, mode: SelectionMode) -> Self {
+        self.selection_mode = Some(mode);
+        self
+    }
+
+    /// Set the selected rows, by [`RowId`].
+    pub fn selection(mut self, selection: &HashSet<RowId>) -> Self {
+        self.selection = selection.clone();
+        self
+    }
+
+    /// Called with the new selection when the user changes it.
+    pub fn on_selection_change(
+        mut self,
+        handler: impl Fn(&HashSet<RowId>, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_selection_change = Some(Rc::new(handler));
+        self
+    }
+
+    /// Show the editing controls: delete buttons on rows of sections with
+    /// `on_delete`, and reorder grips when the list has
+    /// [`on_move`](Self::on_move).
+    pub fn edit_mode(mut self, mode: EditMode) -> Self {
+        self.edit_mode = mode;
+        self
+    }
+
+    /// Let the user drag section rows into a new order by their grips in
+    /// edit mode.
+    ///
+    /// The handler gets the row's position in the list, counting across
+    /// sections, and the position it should end up at, so moving the data
+    /// is `items.remove(from)` then `items.insert(to, item)`.
+    pub fn on_move(
+        mut self,
+        handler: impl Fn(usize, usize, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_move = Some(Rc::new(handler));
+        self
+    }
+
+    /// Show `view` centered in the list when it has no rows.
+    pub fn empty_view(mut self, view: impl IntoElement) -> Self {
+        self.empty_view = Some(view.into_any_element());
+        self
+    }
+
+    /// Show pulsing placeholder rows in place of the content while it
+    /// loads.
+    pub fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
+    /// Show sections that have no rows, with their header and footer
+    /// (default false).
+    pub fn show_empty_sections(mut self, show: bool) -> Self {
+        self.show_empty_sections = show;
+        self
+    }
+
+    /// Show a search field above the rows, bound to `state`, and only the
+    /// rows that `filter` keeps while it holds a query.
+    ///
+    /// The filter gets the trimmed query and the position of the row in the
+    /// list, counting across sections. Sections left without rows are
+    /// hidden, and the empty view shows when nothing matches. Rows can't be
+    /// moved while filtered.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// List::new("fruits")
+    ///     .searchable(&self.search, |query, ix| {
+    ///         FRUITS[ix].to_lowercase().contains(&query.to_lowercase())
+    ///     })
+    ///     .section(Section::new().rows(FRUITS.iter().map(|fruit| Text::new(*fruit))))
+    /// ```
+    pub fn searchable(
+        mut self,
+        state: &Entity<InputState>,
+        filter: impl Fn(&str, usize) -> bool + 'static,
+    ) -> Self {
+        self.search = Some(ListSearch {
+            state: state.clone(),
+            filter: Box::new(filter),
+        });
+        self
+    }
+
+    /// Keep only the section rows `filter` matches to `query`.
+    ///
+    /// Kept rows get their positional id as an explicit one, so the
+    /// selection follows them, and delete handlers still get their original
+    /// position in the section.
+    fn filter_rows(&mut self, query: &str, filter: &dyn Fn(&str, usize) -> bool) {
+        let mut first_row = 0;
+        for child in &mut self.children {
+            let ListChild::Section(section) = child else {
+                continue;
+            };
+            let rows = std::mem::take(&mut section.rows);
+            let row_count = rows.len();
+            let mut kept = Vec::new();
+            for (position, mut row) in rows.into_iter().enumerate() {
+                let index = first_row + position;
+                if filter(query, index) {
+                    row.id.get_or_insert_with(|| RowId::from(index));
+                    section.rows.push(row);
+                    kept.push(position);
+                }
+            }
+            first_row += row_count;
+
+            if let Some(on_delete) = section.on_delete.take() {
+                section.on_delete = Some(Rc::new(move |position: &usize, window, cx| {
+                    on_delete(&kept[*position], window, cx)
+                }));
+            }
+        }
+        self.on_move = None;
+    }
+
+    /// The ids of all section rows, in order.
+    fn row_order(&self) -> Vec<RowId> {
+        let mut order = Vec::new();
+        for child in &self.children {
+            if let ListChild::Section(section) = child {
+                for row in &section.rows {
+                    let id = row.id.clone().unwrap_or_else(|| RowId::from(order.len()));
+                    order.push(id);
+                }
+            }
+        }
+        order
+    }
+
     fn build_configuration(&self) -> ListConfiguration {
         ListConfiguration {
             default_row_insets: self.default_row_insets,
@@ -153,7 +393,70 @@ impl List {
 impl Modifier for List {}
 
 impl RenderOnce for List {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+    fn render(mut self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let Some(search) = self.search.take() else {
+            return self.render_rows(window, cx);
+        };
+
+        let query = search.state.read(cx).value();
+        let query = query.trim();
+        if !query.is_empty() && !self.loading {
+            self.filter_rows(query, &search.filter);
+            if self.empty_view.is_none() {
+                self.empty_view = Some(
+                    Text::new(format!("No Results for \u{201c}{query}\u{201d}"))
+                        .foreground_color(Color::secondary_label())
+                        .into_any_element(),
+                );
+            }
+        }
+
+        // The field stays put above the scrolling rows
+        let inset = match self.style {
+            ListStyle::InsetGrouped | ListStyle::Grouped => 16.0,
+            _ => 8.0,
+        };
+        let field = TextField::new(&search.state)
+            .prefix(Icon::new(IconName::Search).text_color(Color::secondary_label().resolve_in(cx)))
+            .cleanable(true)
+            .on_change(|_, window, _| window.refresh());
+        div()
+            .flex()
+            .flex_col()
+            .size_full()
+            .min_h_0()
+            .child(div().flex_shrink_0().px(px(inset)).pt(px(8.0)).child(field))
+            .child(div().flex_1().min_h_0().child(self.render_rows(window, cx)))
+            .into_any_element()
+    }
+}
+
+impl List {
+    fn render_rows(mut self, window: &mut Window, cx: &mut App) -> AnyElement {
+        if self.loading {
+            // Placeholders can't be selected or edited
+            self.children = vec![ListChild::Section(Box::new(skeleton_section(cx)))];
+            self.selection_mode = None;
+            self.edit_mode = EditMode::Inactive;
+        } else if !self.show_empty_sections {
+            self.children.retain(
+                |child| !matches!(child, ListChild::Section(section) if section.rows.is_empty()),
+            );
+        }
+        let is_empty = self
+            .children
+            .iter()
+            .all(|child| matches!(child, ListChild::Section(section) if section.rows.is_empty()));
+        if let Some(empty_view) = self.empty_view.take().filter(|_| is_empty) {
+            return div()
+                .size_full()
+                .flex()
+                .items_center()
+                .justify_center()
+                .child(empty_view)
+                .into_any_element();
+        }
+
         let config = self.build_configuration();
         let section_spacing = self.section_spacing.resolve(self.style);
         let row_spacing = self.default_row_spacing.unwrap_or(0.0);
@@ -172,12 +475,81 @@ impl RenderOnce for List {
         let default_insets = self.default_row_insets;
         let min_row_height = self.min_row_height;
 
+        let selected = Rc::new(std::mem::take(&mut self.selection));
+        let selection = self.selection_mode.map(|mode| {
+            let order = Rc::new(self.row_order());
+            let state = window.use_keyed_state(self.id.clone(), cx, |_, cx| SelectionState {
+                focus: cx.focus_handle(),
+                anchor: None,
+                cursor: None,
+            });
+            let select = selection_handler(
+                mode,
+                selected.clone(),
+                order.clone(),
+                state.clone(),
+                self.on_selection_change.clone(),
+            );
+            (order, state, select)
+        });
+        let row_count = self.row_order().len();
+        let moving = self
+            .on_move
+            .as_ref()
+            .map(|_| move_state(self.id.clone(), window, cx));
+        let editing = RowEditing {
+            editing: self.edit_mode.is_editing(),
+            moving: moving.clone().map(|state| (state, row_count)),
+        };
+
+        // Plain and sidebar lists pin the current section's header to the top
+        let headers: Vec<Option<SharedString>> = self
+            .children
+            .iter()
+            .filter_map(|child| match child {
+                ListChild::Section(section) => Some(section.header.clone()),
+                ListChild::Element(_) => None,
+            })
+            .collect();
+        let sticky = matches!(self.style, ListStyle::Plain | ListStyle::Sidebar)
+            && headers.iter().any(Option::is_some);
+        let handle = (sticky || self.on_refresh.is_some() || moving.is_some()).then(|| {
+            window
+                .use_keyed_state(self.id.clone(), cx, |_, _| ScrollHandle::default())
+                .read(cx)
+                .clone()
+        });
+        let sticky_state =
+            sticky.then(|| sticky_headers(self.id.clone(), headers.len(), window, cx));
+        let mut first_row = 0;
+        let mut section_index = 0;
+
         let children: Vec<AnyElement> = self
             .children
             .into_iter()
             .map(|child| match child {
                 ListChild::Section(section) => {
-                    section.with_list_config(config.clone()).into_any_element()
+                    let mut section = *section;
+                    section.first_row = first_row;
+                    first_row += section.rows.len();
+                    if let Some((state, handle)) = sticky_state.as_ref().zip(handle.as_ref()) {
+                        section = section.with_tracking(SectionTracking {
+                            state: state.clone(),
+                            index: section_index,
+                            handle: handle.clone(),
+                        });
+                    }
+                    section_index += 1;
+                    if let Some((_, _, select)) = &selection {
+                        section = section.with_selection(RowSelection {
+                            selected: selected.clone(),
+                            select: select.clone(),
+                        });
+                    }
+                    section
+                        .with_editing(editing.clone())
+                        .with_list_config(config.clone())
+                        .into_any_element()
                 }
                 ListChild::Element(element) => {
                     let needs_wrapper = default_insets.is_some() || min_row_height.is_some();
@@ -209,18 +581,244 @@ impl RenderOnce for List {
             ListStyle::Sidebar => content.p(px(8.0)),
         };
 
+        let content = content.children(children);
+        let scroll = div().id(self.id.clone()).size_full().min_h_0();
+        let list = match handle {
+            None => scroll
+                .overflow_y_scrollbar()
+                .child(content)
+                .into_any_element(),
+            Some(handle) => {
+                let pinned = sticky_state
+                    .and_then(|state| pinned_section(&state, &handle, cx))
+                    .and_then(|(ix, push)| {
+                        let text = headers.get(ix)?.as_ref()?;
+                        Some(pinned_header(
+                            text,
+                            push,
+                            self.style,
+                            self.min_header_height,
+                            cx,
+                        ))
+                    });
+                tracked_list(
+                    scroll,
+                    content,
+                    handle,
+                    TrackedListOptions {
+                        id: self.id,
+                        on_refresh: self.on_refresh,
+                        moving: moving.zip(self.on_move),
+                        pinned,
+                    },
+                    window,
+                    cx,
+                )
+            }
+        };
+        let Some((order, state, select)) = selection else {
+            return list;
+        };
+
+        // Arrow keys move the selection while the list has focus
+        let focus = state.read(cx).focus.clone();
         div()
-            .id(self.id)
             .size_full()
             .min_h_0()
-            .overflow_y_scrollbar()
-            .child(content.children(children))
+            .track_focus(&focus)
+            .on_key_down(move |event: &KeyDownEvent, window, cx| {
+                let cursor = state
+                    .read(cx)
+                    .cursor
+                    .as_ref()
+                    .and_then(|id| order.iter().position(|row| row == id));
+                let keystroke = &event.keystroke;
+                let Some(target) = key_target(&keystroke.key, cursor, order.len()) else {
+                    return;
+                };
+                cx.stop_propagation();
+                let modifiers = Modifiers {
+                    shift: keystroke.modifiers.shift,
+                    ..Modifiers::default()
+                };
+                select(target, &modifiers, window, cx);
+            })
+            .child(list)
+            .into_any_element()
+    }
+}
+
+/// Whether the row at a position in the list matches a search query.
+type SearchFilter = Box<dyn Fn(&str, usize) -> bool>;
+
+/// The search field of a searchable list, and which rows match its query.
+struct ListSearch {
+    state: Entity<InputState>,
+    filter: SearchFilter,
+}
+
+/// What a list scroll view tracked by its own handle adds to it.
+struct TrackedListOptions {
+    id: SharedString,
+    on_refresh: Option<RefreshHandler>,
+    moving: Option<(Entity<MoveState>, MoveHandler)>,
+    /// A section header pinned over the top of the rows.
+    pinned: Option<Div>,
+}
+
+/// A list scroll view tracked by `handle`, which pulling to refresh,
+/// scrolling while dragging rows and pinning headers need.
+fn tracked_list(
+    scroll: gpui::Stateful<Div>,
+    content: Div,
+    handle: ScrollHandle,
+    options: TrackedListOptions,
+    window: &mut Window,
+    cx: &mut App,
+) -> AnyElement {
+    let TrackedListOptions {
+        id,
+        on_refresh,
+        moving,
+        pinned,
+    } = options;
+    let mut scroll = scroll
+        .overflow_y_scroll()
+        .track_scroll(&handle)
+        .child(content);
+    if let Some((state, on_move)) = moving {
+        scroll = reorderable(scroll, handle.clone(), state, on_move);
+    }
+    let mut indicator = None;
+    if let Some(handler) = on_refresh {
+        let state = refresh_state(id, window, cx);
+        scroll = pull_to_refresh(scroll, handle.clone(), state.clone(), handler);
+        indicator = refresh_indicator(&state, cx);
+    }
+    div()
+        .flex()
+        .flex_col()
+        .size_full()
+        .children(indicator)
+        .child(
+            div()
+                .relative()
+                .flex_1()
+                .min_h_0()
+                .child(scroll)
+                .children(pinned)
+                .child(
+                    div()
+                        .absolute()
+                        .inset_0()
+                        .child(Scrollbar::new(&handle).axis(ScrollbarAxis::Vertical)),
+                ),
+        )
+        .into_any_element()
+}
+
+/// A copy of a section header pinned to the top edge, `push` points above
+/// it as the next section arrives.
+fn pinned_header(
+    text: &SharedString,
+    push: f32,
+    style: ListStyle,
+    min_height: Option<f32>,
+    cx: &App,
+) -> Div {
+    // Lined up with the real header, over the rows scrolling beneath it
+    let (inset, background) = match style {
+        ListStyle::Sidebar => (8.0, cx.theme().sidebar),
+        _ => (0.0, Color::system_background().resolve_in(cx)),
+    };
+    section_header(text, min_height, cx)
+        .absolute()
+        .top(px(push))
+        .left(px(inset))
+        .right(px(inset))
+        .bg(background)
+}
+
+/// Select rows of `order` for a click or key press, reporting the new
+/// selection to `on_change`.
+fn selection_handler(
+    mode: SelectionMode,
+    selected: Rc<HashSet<RowId>>,
+    order: Rc<Vec<RowId>>,
+    state: Entity<SelectionState>,
+    on_change: Option<SelectionHandler>,
+) -> SelectRow {
+    Rc::new(move |target, modifiers, window, cx| {
+        let anchor = state
+            .read(cx)
+            .anchor
+            .as_ref()
+            .and_then(|id| order.iter().position(|row| row == id));
+        let (selection, anchor) = select_row(mode, &selected, &order, anchor, target, modifiers);
+        let focus = state.update(cx, |state, _| {
+            state.anchor = Some(order[anchor].clone());
+            state.cursor = Some(order[target].clone());
+            state.focus.clone()
+        });
+        window.focus(&focus);
+        if let Some(on_change) = &on_change {
+            on_change(&selection, window, cx);
+        }
+    })
+}
+
+/// The selection after picking the row at `target` of `order`, and the
+/// position ranges start from afterwards.
+///
+/// Shift selects the range from `anchor` in multiple mode. Cmd (ctrl off
+/// macOS) adds or removes the row, and in single mode deselects it.
+fn select_row(
+    mode: SelectionMode,
+    selected: &HashSet<RowId>,
+    order: &[RowId],
+    anchor: Option<usize>,
+    target: usize,
+    modifiers: &Modifiers,
+) -> (HashSet<RowId>, usize) {
+    let id = &order[target];
+    match mode {
+        SelectionMode::Multiple if modifiers.shift => {
+            let anchor = anchor.unwrap_or(target);
+            let range = anchor.min(target)..=anchor.max(target);
+            (order[range].iter().cloned().collect(), anchor)
+        }
+        SelectionMode::Multiple if modifiers.secondary() => {
+            let mut selection = selected.clone();
+            if !selection.remove(id) {
+                selection.insert(id.clone());
+            }
+            (selection, target)
+        }
+        SelectionMode::Single if modifiers.secondary() && selected.contains(id) => {
+            (HashSet::new(), target)
+        }
+        _ => (HashSet::from([id.clone()]), target),
+    }
+}
+
+/// The position `key` moves the cursor to in a list of `len` rows, or
+/// `None` for keys that don't move it.
+fn key_target(key: &str, cursor: Option<usize>, len: usize) -> Option<usize> {
+    let last = len.checked_sub(1)?;
+    match (key, cursor) {
+        ("up", Some(ix)) => Some(ix.saturating_sub(1).min(last)),
+        ("down", Some(ix)) => Some((ix + 1).min(last)),
+        ("home", _) | ("down", None) => Some(0),
+        ("end", _) | ("up", None) => Some(last),
+        _ => None,
     }
 }
 
 pub struct SectionRow {
     pub element: AnyElement,
     pub config: RowConfiguration,
+    pub id: Option<RowId>,
+    pub actions: Vec<RowAction>,
 }
 
 #[derive(IntoElement)]
@@ -234,7 +832,15 @@ pub struct Section {
     row_insets_override: Option<EdgeInsets>,
     row_spacing_override: Option<f32>,
 
+    allows_full_swipe: bool,
+    on_delete: Option<DeleteHandler>,
+
     list_config: Option<ListConfiguration>,
+    selection: Option<RowSelection>,
+    editing: Option<RowEditing>,
+    tracking: Option<SectionTracking>,
+    /// The position in the list of the first row.
+    first_row: usize,
 }
 
 impl Section {
@@ -247,7 +853,13 @@ impl Section {
             section_margins: None,
             row_insets_override: None,
             row_spacing_override: None,
+            allows_full_swipe: false,
+            on_delete: None,
             list_config: None,
+            selection: None,
+            editing: None,
+            tracking: None,
+            first_row: 0,
         }
     }
 
@@ -265,6 +877,31 @@ impl Section {
         self.rows.push(SectionRow {
             element: element.into_any_element(),
             config: RowConfiguration::default(),
+            id: None,
+            actions: Vec::new(),
+        });
+        self
+    }
+
+    /// Add a row with its own id, which a selectable list selects it by.
+    pub fn row_id(mut self, id: impl Into<RowId>, element: impl IntoElement) -> Self {
+        self.rows.push(SectionRow {
+            element: element.into_any_element(),
+            config: RowConfiguration::default(),
+            id: Some(id.into()),
+            actions: Vec::new(),
+        });
+        self
+    }
+
+    /// Add a row with actions revealed by swiping it left, or shown at its
+    /// trailing edge on hover.
+    pub fn row_with_actions(mut self, element: impl IntoElement, actions: Vec<RowAction>) -> Self {
+        self.rows.push(SectionRow {
+            element: element.into_any_element(),
+            config: RowConfiguration::default(),
+            id: None,
+            actions,
         });
         self
     }
@@ -273,10 +910,83 @@ impl Section {
         self.rows.push(SectionRow {
             element: element.into_any_element(),
             config,
+            id: None,
+            actions: Vec::new(),
         });
         self
     }
 
+    /// Add a [`NavigationRow`] with a title, an optional gray detail value
+    /// and a chevron, calling `on_tap` when clicked anywhere.
+    pub fn navigation_row(
+        self,
+        title: impl Into<SharedString>,
+        detail: Option<SharedString>,
+        on_tap: impl Fn(&ClickEvent, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        let mut row = NavigationRow::new(title).on_tap(on_tap);
+        if let Some(detail) = detail {
+            row = row.detail(detail);
+        }
+        self.full_bleed_row(row)
+    }
+
+    /// Add a [`NavigationRow`] that pushes a screen titled `title` onto
+    /// `path`, built by `destination`.
+    pub fn navigation_link<V: IntoElement>(
+        self,
+        path: &Entity<NavigationPath>,
+        title: impl Into<SharedString>,
+        destination: impl Fn(&mut Window, &mut App) -> V + 'static,
+    ) -> Self {
+        let title: SharedString = title.into();
+        let destination: DestinationBuilder =
+            Rc::new(move |window, cx| destination(window, cx).into_any_element());
+        let (path, link_title) = (path.clone(), title.clone());
+        self.navigation_row(title, None, move |_, _, cx| {
+            path.update(cx, |path, cx| {
+                path.push_destination(link_title.clone(), destination.clone());
+                cx.notify();
+            });
+        })
+    }
+
+    /// Add a row with a full-width toggle.
+    pub fn toggle_row(
+        self,
+        title: impl Into<SharedString>,
+        is_on: bool,
+        on_change: impl Fn(&bool, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.row(Toggle::new_with_handler(title, is_on, on_change).full_width(true))
+    }
+
+    /// Add a row that acts like a button, with its title in the accent
+    /// color, or red for a destructive role.
+    pub fn button_row(
+        self,
+        title: impl Into<SharedString>,
+        role: Option<ButtonRole>,
+        action: impl Fn(&ClickEvent, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        let color = match role {
+            Some(ButtonRole::Destructive) => Color::red(),
+            _ => Color::accent(),
+        };
+        let row = NavigationRow::new(title)
+            .chevron(false)
+            .foreground_color(color)
+            .on_tap(action);
+        self.full_bleed_row(row)
+    }
+
+    /// Add a row without insets, for rows that pad themselves so their
+    /// pressed background fills the row.
+    fn full_bleed_row(self, element: impl IntoElement) -> Self {
+        let config = RowConfiguration::new().insets(EdgeInsets::init(0.0, 0.0, 0.0, 0.0));
+        self.row_with_config(element, config)
+    }
+
     pub fn rows<I, E>(mut self, elements: I) -> Self
     where
         I: IntoIterator<Item = E>,
@@ -285,6 +995,8 @@ impl Section {
         self.rows.extend(elements.into_iter().map(|e| SectionRow {
             element: e.into_any_element(),
             config: RowConfiguration::default(),
+            id: None,
+            actions: Vec::new(),
         }));
         self
     }
@@ -314,11 +1026,41 @@ impl Section {
         self
     }
 
+    /// Let swiping a row all the way across trigger its first action
+    /// (default false).
+    pub fn allows_full_swipe(mut self, allows: bool) -> Self {
+        self.allows_full_swipe = allows;
+        self
+    }
+
+    /// Let the user delete rows, with a Delete swipe action and a delete
+    /// button in edit mode. The handler gets the row's position in the
+    /// section, and should remove it from the data the rows come from.
+    pub fn on_delete(mut self, handler: impl Fn(&usize, &mut Window, &mut App) + 'static) -> Self {
+        self.on_delete = Some(Rc::new(handler));
+        self
+    }
+
     pub(crate) fn with_list_config(mut self, config: ListConfiguration) -> Self {
         self.list_config = Some(config);
         self
     }
 
+    pub(crate) fn with_selection(mut self, selection: RowSelection) -> Self {
+        self.selection = Some(selection);
+        self
+    }
+
+    pub(crate) fn with_editing(mut self, editing: RowEditing) -> Self {
+        self.editing = Some(editing);
+        self
+    }
+
+    pub(crate) fn with_tracking(mut self, tracking: SectionTracking) -> Self {
+        self.tracking = Some(tracking);
+        self
+    }
+
     fn effective_row_insets(&self) -> EdgeInsets {
         self.row_insets_override
             .or(self.list_config.as_ref().and_then(|c| c.default_row_insets))
@@ -356,18 +1098,15 @@ impl Modifier for Section {}
 
 impl RenderOnce for Section {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        use gpui_component::ActiveTheme;
-
-        let is_dark = cx.theme().is_dark();
-
         let default_row_insets = self.effective_row_insets();
         let row_spacing = self.effective_row_spacing();
         let min_row_height = self.effective_min_row_height();
         let min_header_height = self.effective_min_header_height();
 
-        let label_color = Color::secondary_label().resolve(is_dark);
-        let bg_color = Color::tertiary_system_background().resolve(is_dark);
-        let separator_color = Color::separator().resolve(is_dark);
+        let label_color = Color::secondary_label().resolve_in(cx);
+        let caption_size = rems(0.75 * environment::text_scale(cx));
+        let bg_color = Color::tertiary_system_background().resolve_in(cx);
+        let separator_color = Color::separator().resolve_in(cx);
 
         let mut section = div().flex().flex_col().w_full();
 
@@ -389,18 +1128,11 @@ impl RenderOnce for Section {
         }
 
         if let Some(header_text) = self.header {
-            let mut header = div()
-                .text_xs()
-                .text_color(label_color)
-                .px(px(16.0))
-                .pb(px(8.0))
-                .child(header_text.to_uppercase());
-
-            if let Some(min_h) = min_header_height {
-                header = header.min_h(px(min_h));
-            }
-
-            section = section.child(header);
+            let header = section_header(&header_text, min_header_height, cx);
+            section = section.child(match &self.tracking {
+                Some(tracking) => tracking.header(header),
+                None => header,
+            });
         }
 
         let mut content = div()
@@ -416,6 +1148,14 @@ impl RenderOnce for Section {
         }
 
         let row_count = self.rows.len();
+        let selection = self.selection;
+        let first_row = self.first_row;
+        let allows_full_swipe = self.allows_full_swipe;
+        let on_delete = self.on_delete;
+        let (editing, moving) = match self.editing {
+            Some(RowEditing { editing, moving }) => (editing, moving.filter(|_| editing)),
+            None => (false, None),
+        };
         let row_elements: Vec<_> = self
             .rows
             .into_iter()
@@ -423,21 +1163,66 @@ impl RenderOnce for Section {
             .map(|(index, row)| {
                 let is_last = index == row_count - 1;
                 let effective_insets = row.config.insets.unwrap_or(default_row_insets);
+                let mut actions = row.actions;
 
-                let row_div = div()
+                let mut row_div = div()
                     .flex()
                     .items_center()
                     .min_h(px(min_row_height))
                     .pt(px(effective_insets.top))
                     .pb(px(effective_insets.bottom))
                     .pl(px(effective_insets.leading))
-                    .pr(px(effective_insets.trailing))
-                    .child(row.element);
+                    .pr(px(effective_insets.trailing));
+                if let Some(on_delete) = on_delete.clone() {
+                    if editing {
+                        row_div = row_div.child(delete_button(index, on_delete, cx));
+                    } else {
+                        let delete = RowAction::new("Delete", move |window, cx| {
+                            on_delete(&index, window, cx)
+                        })
+                        .system_image("trash")
+                        .role(ButtonRole::Destructive);
+                        actions.insert(0, delete);
+                    }
+                }
+                if editing {
+                    // Rows don't swipe while their editing controls show
+                    actions.clear();
+                    row_div = row_div.child(div().flex_1().min_w_0().child(row.element));
+                } else {
+                    row_div = row_div.child(row.element);
+                }
+                let index = first_row + index;
+                if let Some((state, list_rows)) = &moving {
+                    row_div = drop_target(row_div, index, state, *list_rows, cx)
+                        .child(move_grip(index, state, cx));
+                }
 
-                if !is_last && row_spacing == 0.0 {
+                let separated = !is_last && row_spacing == 0.0;
+                let id = row.id.unwrap_or_else(|| RowId::from(index));
+                let row_div = if separated && actions.is_empty() {
                     row_div.border_b_1().border_color(separator_color)
                 } else {
                     row_div
+                };
+                let element = match &selection {
+                    Some(selection) => selection.apply(row_div, index, &id, cx),
+                    None => row_div.into_any_element(),
+                };
+                if actions.is_empty() {
+                    return element;
+                }
+
+                // The separator stays put while the row slides
+                let swipe = SwipeRow::new(id, element, actions, allows_full_swipe);
+                if separated {
+                    div()
+                        .border_b_1()
+                        .border_color(separator_color)
+                        .child(swipe)
+                        .into_any_element()
+                } else {
+                    swipe.into_any_element()
                 }
             })
             .collect();
@@ -447,7 +1232,7 @@ impl RenderOnce for Section {
         if let Some(footer_text) = self.footer {
             section = section.child(
                 div()
-                    .text_xs()
+                    .text_size(caption_size)
                     .text_color(label_color)
                     .px(px(16.0))
                     .pt(px(8.0))
@@ -455,6 +1240,121 @@ impl RenderOnce for Section {
             );
         }
 
-        section
+        match self.tracking {
+            Some(tracking) => tracking.section(section),
+            None => section,
+        }
+    }
+}
+
+/// How many placeholder rows a loading list shows.
+const SKELETON_ROWS: usize = 5;
+
+/// Rows of gray bars, of varying widths, pulsing while the content loads.
+fn skeleton_section(cx: &App) -> Section {
+    const WIDTHS: [f32; 3] = [0.7, 0.45, 0.6];
+    let color = Color::muted().resolve_in(cx);
+    Section::new().rows((0..SKELETON_ROWS).map(|ix| {
+        div()
+            .h(px(12.0))
+            .w(relative(WIDTHS[ix % WIDTHS.len()]))
+            .rounded(px(4.0))
+            .bg(color)
+            .with_animation(
+                ("list-skeleton", ix),
+                Animation::new(Duration::from_millis(1200))
+                    .repeat()
+                    .with_easing(pulsating_between(0.4, 1.0)),
+                |bar, delta| bar.opacity(delta),
+            )
+    }))
+}
+
+/// The caption above a section's rows.
+fn section_header(text: &SharedString, min_height: Option<f32>, cx: &App) -> Div {
+    let header = div()
+        .text_size(rems(0.75 * environment::text_scale(cx)))
+        .text_color(Color::secondary_label().resolve_in(cx))
+        .px(px(16.0))
+        .pb(px(8.0))
+        .child(text.to_uppercase());
+    match min_height {
+        Some(min_height) => header.min_h(px(min_height)),
+        None => header,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rows(count: usize) -> Vec<RowId> {
+        (0..count).map(RowId::from).collect()
+    }
+
+    fn ids(indices: &[usize]) -> HashSet<RowId> {
+        indices.iter().copied().map(RowId::from).collect()
+    }
+
+    #[test]
+    fn clicks_select_by_mode() {
+        let order = rows(5);
+        let plain = Modifiers::default();
+        let shift = Modifiers {
+            shift: true,
+            ..Modifiers::default()
+        };
+        let toggle = Modifiers::secondary_key();
+
+        let (single, _) = select_row(SelectionMode::Single, &ids(&[1]), &order, None, 3, &shift);
+        assert_eq!(single, ids(&[3]));
+        let (single, _) = select_row(SelectionMode::Single, &ids(&[3]), &order, None, 3, &toggle);
+        assert!(single.is_empty());
+
+        let multiple = SelectionMode::Multiple;
+        let (selection, anchor) = select_row(multiple, &ids(&[0]), &order, None, 1, &plain);
+        assert_eq!((selection, anchor), (ids(&[1]), 1));
+        let (selection, anchor) = select_row(multiple, &ids(&[1]), &order, Some(1), 3, &shift);
+        assert_eq!((selection, anchor), (ids(&[1, 2, 3]), 1));
+        let (selection, _) = select_row(multiple, &ids(&[1, 2]), &order, Some(1), 2, &toggle);
+        assert_eq!(selection, ids(&[1]));
+    }
+
+    #[test]
+    fn keys_move_within_the_list() {
+        assert_eq!(key_target("down", None, 3), Some(0));
+        assert_eq!(key_target("up", None, 3), Some(2));
+        assert_eq!(key_target("down", Some(2), 3), Some(2));
+        assert_eq!(key_target("up", Some(0), 3), Some(0));
+        assert_eq!(key_target("end", Some(0), 3), Some(2));
+        assert_eq!(key_target("home", Some(2), 3), Some(0));
+        assert_eq!(key_target("a", Some(1), 3), None);
+        assert_eq!(key_target("down", None, 0), None);
+    }
+
+    #[test]
+    fn search_keeps_matching_rows_by_list_position() {
+        let mut list = List::new("fruits")
+            .section(Section::new().row(div()).row(div()).row(div()))
+            .section(Section::new().row_id("kiwi", div()).row(div()));
+        list.filter_rows("query", &|_, ix| ix != 1 && ix != 4);
+
+        let sections: Vec<_> = list
+            .children
+            .iter()
+            .filter_map(|child| match child {
+                ListChild::Section(section) => Some(&section.rows),
+                ListChild::Element(_) => None,
+            })
+            .collect();
+        let kept = |rows: &Vec<SectionRow>| -> Vec<Option<RowId>> {
+            rows.iter().map(|row| row.id.clone()).collect()
+        };
+        assert_eq!(
+            kept(sections[0]),
+            [Some(RowId::from(0)), Some(RowId::from(2))]
+        );
+        // Explicit ids are kept as they are
+        assert_eq!(kept(sections[1]), [Some(RowId::from("kiwi"))]);
     }
 }
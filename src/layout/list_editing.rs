@@ -0,0 +1,283 @@
+//! Deleting and reordering list rows.
+//!
+//! Rows of a section with `on_delete` get a Delete swipe action, and a
+//! delete button while the list is in edit mode. A list with `on_move`
+//! shows a grip on each row in edit mode. Dragging a grip shows a line
+//! where the row will land, and scrolls the list while held near its top or
+//! bottom edge.
+
+use std::rc::Rc;
+use std::time::Duration;
+
+use gpui::{
+    div, px, App, AppContext, Div, DragMoveEvent, ElementId, EmptyView, Entity, EntityId,
+    InteractiveElement, ParentElement, ScrollHandle, Stateful, StatefulInteractiveElement, Styled,
+    Window,
+};
+use gpui_component::{Icon, IconName};
+
+use crate::style::Color;
+
+/// Called with the position in its section of a row to delete.
+pub(crate) type DeleteHandler = Rc<dyn Fn(&usize, &mut Window, &mut App)>;
+
+/// Called with the position a row moves from and the position it ends up at.
+pub(crate) type MoveHandler = Rc<dyn Fn(usize, usize, &mut Window, &mut App)>;
+
+/// How close to an edge of the list a drag starts scrolling it, in points.
+const AUTO_SCROLL_EDGE: f32 = 40.0;
+
+/// How far the list scrolls each frame with the drag right at an edge.
+const AUTO_SCROLL_SPEED: f32 = 12.0;
+
+/// How often the list scrolls while a drag is held near an edge.
+const AUTO_SCROLL_INTERVAL: Duration = Duration::from_millis(16);
+
+/// Where a dragged row would land, kept across frames.
+#[derive(Debug, Default)]
+pub(crate) struct MoveState {
+    /// The gap the row would drop into: 0 above the first row, up to the
+    /// row count below the last.
+    slot: Option<usize>,
+    /// How far to scroll each frame, positive towards the top.
+    scroll_speed: f32,
+    auto_scrolling: bool,
+}
+
+/// The value carried by a row being dragged into place.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct RowMove {
+    list: EntityId,
+    from: usize,
+}
+
+/// What a section needs to show editing controls on its rows.
+#[derive(Clone)]
+pub(crate) struct RowEditing {
+    pub(crate) editing: bool,
+    /// The move state and row count of a list whose rows can be moved.
+    pub(crate) moving: Option<(Entity<MoveState>, usize)>,
+}
+
+/// The move state of the list `id`.
+pub(crate) fn move_state(
+    id: impl Into<ElementId>,
+    window: &mut Window,
+    cx: &mut App,
+) -> Entity<MoveState> {
+    window.use_keyed_state(id, cx, |_, _| MoveState::default())
+}
+
+/// A red button that deletes row `index` of its section.
+pub(crate) fn delete_button(index: usize, on_delete: DeleteHandler, cx: &App) -> Stateful<Div> {
+    div()
+        .id(("row-delete", index))
+        .flex()
+        .items_center()
+        .justify_center()
+        .flex_shrink_0()
+        .size(px(18.0))
+        .mr(px(10.0))
+        .rounded_full()
+        .cursor_pointer()
+        .bg(Color::red().resolve_in(cx))
+        .child(
+            Icon::new(IconName::Minus)
+                .size(px(12.0))
+                .text_color(Color::white().resolve_in(cx)),
+        )
+        .on_click(move |_, window, cx| {
+            cx.stop_propagation();
+            on_delete(&index, window, cx);
+        })
+}
+
+/// A grip that drags row `from` of the list into place.
+pub(crate) fn move_grip(from: usize, state: &Entity<MoveState>, cx: &App) -> Stateful<Div> {
+    let drag = RowMove {
+        list: state.entity_id(),
+        from,
+    };
+    let state = state.clone();
+    div()
+        .id(("row-grip", from))
+        .flex_shrink_0()
+        .ml(px(10.0))
+        .cursor_grab()
+        .child(Icon::new(IconName::Menu).text_color(Color::tertiary_label().resolve_in(cx)))
+        .on_drag(drag, move |_, _, _, cx| {
+            state.update(cx, |state, _| state.slot = None);
+            cx.new(|_| EmptyView)
+        })
+}
+
+/// Let `row`, at `index` of `row_count` rows, be dropped onto, with a line
+/// at its edge while a dragged row would land there.
+pub(crate) fn drop_target(
+    row: Div,
+    index: usize,
+    state: &Entity<MoveState>,
+    row_count: usize,
+    cx: &App,
+) -> Div {
+    let list = state.entity_id();
+    let slot = state.read(cx).slot.filter(|_| cx.has_active_drag());
+    let accent = Color::accent().resolve_in(cx);
+    let line = || div().absolute().left_0().right_0().h(px(2.0)).bg(accent);
+    let row = match slot {
+        Some(slot) if slot == index => row.relative().child(line().top_0()),
+        // Only the last row draws the line below it
+        Some(slot) if slot == row_count && index + 1 == row_count => {
+            row.relative().child(line().bottom_0())
+        }
+        _ => row,
+    };
+
+    let state = state.clone();
+    row.on_drag_move(move |event: &DragMoveEvent<RowMove>, _, cx| {
+        if event.drag(cx).list != list {
+            return;
+        }
+        let (position, bounds) = (event.event.position, event.bounds);
+        if position.y < bounds.top() || position.y >= bounds.bottom() {
+            return;
+        }
+        let slot = if position.y < bounds.center().y {
+            index
+        } else {
+            index + 1
+        };
+        state.update(cx, |state, cx| {
+            if state.slot != Some(slot) {
+                state.slot = Some(slot);
+                cx.notify();
+            }
+        });
+    })
+}
+
+/// Let rows dragged by their grips be dropped in `scroll`, tracked by
+/// `handle`, scrolling it while the drag is near its edges.
+pub(crate) fn reorderable(
+    scroll: Stateful<Div>,
+    handle: ScrollHandle,
+    state: Entity<MoveState>,
+    on_move: MoveHandler,
+) -> Stateful<Div> {
+    let list = state.entity_id();
+    scroll
+        .on_drag_move({
+            let state = state.clone();
+            move |event: &DragMoveEvent<RowMove>, window, cx| {
+                if event.drag(cx).list != list {
+                    return;
+                }
+                let speed = scroll_speed(
+                    f32::from(event.event.position.y),
+                    f32::from(event.bounds.top()),
+                    f32::from(event.bounds.bottom()),
+                );
+                let start = state.update(cx, |state, _| {
+                    state.scroll_speed = speed;
+                    let start = speed != 0.0 && !state.auto_scrolling;
+                    state.auto_scrolling |= start;
+                    start
+                });
+                if start {
+                    auto_scroll(state.clone(), handle.clone(), window, cx);
+                }
+            }
+        })
+        .on_drop(move |drag: &RowMove, window, cx| {
+            if drag.list != list {
+                return;
+            }
+            let slot = state.update(cx, |state, cx| {
+                cx.notify();
+                state.scroll_speed = 0.0;
+                state.slot.take()
+            });
+            if let Some(to) = slot.map(|slot| destination(drag.from, slot)) {
+                if to != drag.from {
+                    on_move(drag.from, to, window, cx);
+                }
+            }
+        })
+}
+
+/// Scroll by the state's speed every frame until the drag ends or leaves
+/// the edges.
+fn auto_scroll(state: Entity<MoveState>, handle: ScrollHandle, window: &mut Window, cx: &mut App) {
+    window
+        .spawn(cx, async move |cx| loop {
+            cx.background_executor().timer(AUTO_SCROLL_INTERVAL).await;
+            let scrolling = cx
+                .update(|window, cx| {
+                    let speed = state.read(cx).scroll_speed;
+                    if speed == 0.0 || !cx.has_active_drag() {
+                        state.update(cx, |state, _| state.auto_scrolling = false);
+                        return false;
+                    }
+                    let mut offset = handle.offset();
+                    offset.y = (offset.y + px(speed)).clamp(-handle.max_offset().height, px(0.0));
+                    handle.set_offset(offset);
+                    window.refresh();
+                    true
+                })
+                .unwrap_or(false);
+            if !scrolling {
+                break;
+            }
+        })
+        .detach();
+}
+
+/// The position a row moved from `from` into the gap `slot` ends up at.
+fn destination(from: usize, slot: usize) -> usize {
+    if slot > from {
+        slot - 1
+    } else {
+        slot
+    }
+}
+
+/// How far to scroll each frame for a drag at `y` in a list from `top` to
+/// `bottom`: positive towards the top near the top edge, negative near the
+/// bottom edge, faster closer to the edge.
+fn scroll_speed(y: f32, top: f32, bottom: f32) -> f32 {
+    let edge = AUTO_SCROLL_EDGE.min((bottom - top) / 2.0);
+    if edge <= 0.0 {
+        0.0
+    } else if y < top + edge {
+        AUTO_SCROLL_SPEED * ((top + edge - y) / edge).min(1.0)
+    } else if y > bottom - edge {
+        -AUTO_SCROLL_SPEED * ((y - (bottom - edge)) / edge).min(1.0)
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rows_land_where_they_are_dropped() {
+        // Dropping into either gap beside the row leaves it in place
+        assert_eq!(destination(2, 2), 2);
+        assert_eq!(destination(2, 3), 2);
+        assert_eq!(destination(2, 0), 0);
+        assert_eq!(destination(0, 3), 2);
+        assert_eq!(destination(0, 5), 4);
+    }
+
+    #[test]
+    fn scrolling_speeds_up_towards_the_edges() {
+        assert_eq!(scroll_speed(200.0, 0.0, 400.0), 0.0);
+        assert_eq!(scroll_speed(0.0, 0.0, 400.0), AUTO_SCROLL_SPEED);
+        assert_eq!(scroll_speed(20.0, 0.0, 400.0), AUTO_SCROLL_SPEED / 2.0);
+        assert_eq!(scroll_speed(400.0, 0.0, 400.0), -AUTO_SCROLL_SPEED);
+        // Dragged past the edge, still at full speed
+        assert_eq!(scroll_speed(-30.0, 0.0, 400.0), AUTO_SCROLL_SPEED);
+    }
+}
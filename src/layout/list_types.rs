@@ -1,5 +1,7 @@
 //! Types for List layout configuration.
 
+use gpui::ElementId;
+
 use crate::modifier::Padding;
 
 use super::ListStyle;
@@ -191,6 +193,45 @@ impl SectionMargins {
     }
 }
 
+/// Identifies a row for selection.
+///
+/// Rows added with `Section::row_id` use their own id. Other rows use their
+/// position in the list, counting across sections from 0, as
+/// `RowId::from(index)`.
+pub type RowId = ElementId;
+
+/// How many rows of a selectable list can be selected at once.
+/// Matches the single and set bindings of SwiftUI's `List(selection:)`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SelectionMode {
+    /// One row at a time (default).
+    #[default]
+    Single,
+    /// Any number of rows, with shift-click for ranges and
+    /// cmd-click (ctrl-click off macOS) to add or remove a row.
+    Multiple,
+}
+
+/// Whether a list shows its editing controls, like SwiftUI's `EditMode`.
+///
+/// While active, rows of sections with `on_delete` show a delete button,
+/// and a list with `on_move` shows grips for dragging rows into place.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EditMode {
+    /// Rows show only their content (default).
+    #[default]
+    Inactive,
+    /// Rows show delete buttons and reorder grips.
+    Active,
+}
+
+impl EditMode {
+    /// Whether the editing controls are shown.
+    pub fn is_editing(self) -> bool {
+        self == EditMode::Active
+    }
+}
+
 /// Configuration for an individual row within a Section.
 #[derive(Clone, Debug, Default)]
 pub struct RowConfiguration {
@@ -12,25 +12,44 @@
 //! - **Spacer**: Expands to fill available space along the stack axis
 //! - **EmptyView**: Renders nothing, takes no space
 //! - **Group**: Transparent grouping, no layout effect
+//! - **Flow**: Places children left to right, wrapping onto new lines
+//!
+//! VStack and HStack spread their children with a [`Distribution`] and can
+//! override their spacing after particular children.
 //!
 //! # Grid Layouts
 //!
 //! - **Grid**: Static 2D table layout with auto-sizing columns
 //! - **GridRow**: A row within a Grid
-//! - **LazyVGrid**: Vertically-scrolling grid with fixed columns
+//! - **LazyVGrid**: Vertically-scrolling grid with fixed columns and optional sections
 //! - **LazyHGrid**: Horizontally-scrolling grid with fixed rows
 //!
+//! # Geometry
+//!
+//! - **GeometryReader**: Builds its content from its own laid-out size
+//!
 //! # Container Components
 //!
 //! - **ScrollView**: Scrollable container (vertical, horizontal, or both)
 //! - **List**: Styled list container with iOS-style appearance
 //! - **Section**: Grouping within List with optional header/footer
+//! - **DisclosureGroup**: Content collapsed behind a label and a chevron
+//! - **OutlineGroup**: Expandable tree over hierarchical data
+//! - **NavigationStack**: Drill-down screens under a navigation bar
+//! - **Form**: Controls laid out as inset grouped rows
+//! - **GroupBox**: Related content in a titled card
+//! - **Toolbar**: Leading, centered and trailing items across a window or screen
 //!
 //! # Control Flow
 //!
 //! - **ForEach**: Iterate over a collection and render views
 //! - **If**: Conditional rendering based on a boolean
 //! - **IfLet**: Render content if an Option is Some
+//! - **Switch**: Render one of several views picked by a value
+//!
+//! # Local State
+//!
+//! - **WithState**: Content built from a value it keeps itself
 //!
 //! # Virtualized Layouts
 //!
@@ -42,39 +61,69 @@
 #[macro_use]
 mod children_macro;
 mod control_flow;
+mod disclosure_group;
 mod empty_view;
+mod flow;
+mod form;
+mod geometry_reader;
 mod grid;
 mod grid_item;
 mod group;
+mod group_box;
 mod hstack;
 mod lazy_hgrid;
 mod lazy_stack;
 mod lazy_vgrid;
 mod list;
+mod list_editing;
 mod list_types;
+mod navigation_stack;
+mod outline_group;
+mod refreshable;
 mod scroll_view;
 mod spacer;
+mod stack_spacing;
+mod sticky_headers;
+mod swipe_actions;
+mod toolbar;
 mod vstack;
+mod with_state;
 mod zstack;
 
 pub use crate::alignment::{Alignment, HorizontalAlignment, VerticalAlignment};
-pub use control_flow::{ForEach, If, IfLet};
+pub use control_flow::{ForEach, If, IfLet, Switch};
+pub use disclosure_group::DisclosureGroup;
 pub use empty_view::EmptyView;
+pub use flow::Flow;
+pub use form::Form;
+pub use geometry_reader::GeometryReader;
 pub use grid::{Grid, GridRow};
 pub use grid_item::{GridItem, GridItemSize};
 pub use group::Group;
+pub use group_box::GroupBox;
 pub use hstack::HStack;
 pub use lazy_hgrid::LazyHGrid;
 pub use lazy_stack::{
-    calculate_item_sizes, uniform_size, LazyHStack, LazyVStack, VirtualListScrollHandle,
+    calculate_item_sizes, uniform_size, LazyHStack, LazyScrollHandleExt, LazyVStack, ScrollAnchor,
+    ScrollInfo, VirtualListScrollHandle,
 };
-pub use lazy_vgrid::LazyVGrid;
+pub use lazy_vgrid::{LazyGridSection, LazyVGrid};
 pub use list::{List, ListStyle, Section, SectionRow};
 pub use list_types::{
-    EdgeInsets, EdgeInsetsExt, EdgeSet, ListConfiguration, ListSectionSpacing, RowConfiguration,
-    SectionMargins,
+    EdgeInsets, EdgeInsetsExt, EdgeSet, EditMode, ListConfiguration, ListSectionSpacing,
+    RowConfiguration, RowId, SectionMargins, SelectionMode,
+};
+pub use navigation_stack::{NavigationPath, NavigationStack};
+pub use outline_group::{OutlineGroup, OutlineState};
+pub use refreshable::RefreshCompletion;
+pub use scroll_view::{
+    ScrollAxes, ScrollIndicatorStyle, ScrollView, ScrollViewProxy, SnapBehavior,
 };
-pub use scroll_view::{ScrollAxes, ScrollView};
 pub use spacer::Spacer;
+pub use stack_spacing::Distribution;
+pub use swipe_actions::RowAction;
+pub(crate) use toolbar::ToolbarSlot;
+pub use toolbar::{Toolbar, ToolbarContent};
 pub use vstack::VStack;
+pub use with_state::{StateHandle, WithState};
 pub use zstack::ZStack;
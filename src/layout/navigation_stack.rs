@@ -0,0 +1,237 @@
+//! NavigationStack - Drill-down navigation with a navigation bar.
+
+use std::rc::Rc;
+
+use gpui::{
+    div, px, AnyElement, App, Entity, InteractiveElement, IntoElement, ParentElement, RenderOnce,
+    SharedString, StatefulInteractiveElement, Styled, Window,
+};
+use gpui_component::{Icon, IconName};
+
+use crate::environment::EnvironmentScope;
+use crate::layout::{Toolbar, ToolbarSlot};
+use crate::modifier::Modifier;
+use crate::style::Color;
+
+/// Builds the view of a pushed screen, on every render.
+pub(crate) type DestinationBuilder = Rc<dyn Fn(&mut Window, &mut App) -> AnyElement>;
+
+/// The height of the navigation bar, in points.
+const BAR_HEIGHT: f32 = 44.0;
+
+/// A screen pushed onto a navigation stack.
+struct Destination {
+    title: SharedString,
+    view: DestinationBuilder,
+}
+
+/// The screens pushed onto a [`NavigationStack`], above its root.
+///
+/// Keep one in an entity held by your view and pass it to the stack. Push
+/// and pop in an update, notifying so the stack redraws.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// self.path.update(cx, |path, cx| {
+///     path.push("Detail", |_, _| Text::new("Details"));
+///     cx.notify();
+/// });
+/// ```
+#[derive(Default)]
+pub struct NavigationPath {
+    stack: Vec<Destination>,
+}
+
+impl NavigationPath {
+    /// Create a path showing the root.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Show a screen titled `title`, built by `view` each time the stack
+    /// renders, so it reflects the app's current state.
+    pub fn push<V: IntoElement>(
+        &mut self,
+        title: impl Into<SharedString>,
+        view: impl Fn(&mut Window, &mut App) -> V + 'static,
+    ) {
+        self.push_destination(
+            title.into(),
+            Rc::new(move |window, cx| view(window, cx).into_any_element()),
+        );
+    }
+
+    pub(crate) fn push_destination(&mut self, title: SharedString, view: DestinationBuilder) {
+        self.stack.push(Destination { title, view });
+    }
+
+    /// Go back to the previous screen. Returns false at the root.
+    pub fn pop(&mut self) -> bool {
+        self.stack.pop().is_some()
+    }
+
+    /// Go back to the root.
+    pub fn pop_to_root(&mut self) {
+        self.stack.clear();
+    }
+
+    /// How many screens are pushed above the root.
+    pub fn len(&self) -> usize {
+        self.stack.len()
+    }
+
+    /// Whether the root is showing.
+    pub fn is_empty(&self) -> bool {
+        self.stack.is_empty()
+    }
+}
+
+/// A root view that screens are pushed over, with a navigation bar showing
+/// the current title and a back button, like SwiftUI's `NavigationStack`.
+///
+/// The back button is labeled with the previous screen's title. Screens
+/// replace each other instantly, and can add items to the bar with
+/// `.toolbar()`.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// NavigationStack::new(
+///     &self.path,
+///     List::new("categories").section(
+///         Section::new().navigation_link(&self.path, "Fruit", |_, _| fruit_list()),
+///     ),
+/// )
+/// .navigation_title("Categories")
+/// ```
+#[derive(IntoElement)]
+pub struct NavigationStack {
+    path: Entity<NavigationPath>,
+    root: AnyElement,
+    title: Option<SharedString>,
+}
+
+impl NavigationStack {
+    /// Create a stack showing `root` until screens are pushed onto `path`.
+    pub fn new(path: &Entity<NavigationPath>, root: impl IntoElement) -> Self {
+        Self {
+            path: path.clone(),
+            root: root.into_any_element(),
+            title: None,
+        }
+    }
+
+    /// Set the title shown in the bar over the root.
+    pub fn navigation_title(mut self, title: impl Into<SharedString>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+}
+
+impl Modifier for NavigationStack {}
+
+impl RenderOnce for NavigationStack {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let titles: Vec<SharedString> = self
+            .path
+            .read(cx)
+            .stack
+            .iter()
+            .map(|destination| destination.title.clone())
+            .collect();
+        let top = self.path.read(cx).stack.last().map(|top| top.view.clone());
+        let (title, content) = match top {
+            Some(view) => (titles.last().cloned(), view(window, cx)),
+            None => (self.title.clone(), self.root),
+        };
+
+        // Back to the screen below, or the root
+        let back = match titles.len() {
+            0 => None,
+            1 => Some(self.title.unwrap_or_else(|| "Back".into())),
+            len => Some(titles[len - 2].clone()),
+        };
+        let accent = Color::accent().resolve_in(cx);
+        let back_button = back.map(|back| {
+            let path = self.path.clone();
+            div()
+                .id("navigation-back")
+                .flex()
+                .flex_row()
+                .items_center()
+                .gap(px(2.0))
+                .max_w(px(120.0))
+                .cursor_pointer()
+                .text_color(accent)
+                .child(Icon::new(IconName::ChevronLeft).text_color(accent))
+                .child(div().truncate().child(back))
+                .on_click(move |_, _, cx| {
+                    path.update(cx, |path, cx| {
+                        path.pop();
+                        cx.notify();
+                    });
+                })
+        });
+
+        let mut bar = Toolbar::new();
+        if let Some(back_button) = back_button {
+            bar = bar.leading(back_button);
+        }
+        if let Some(title) = title {
+            bar = bar.principal(title);
+        }
+        let slot = ToolbarSlot::default();
+
+        // Laid out bottom up, so the screen declares its toolbar items
+        // before the bar renders
+        div()
+            .flex()
+            .flex_col_reverse()
+            .size_full()
+            .child(
+                div()
+                    .flex_1()
+                    .min_h_0()
+                    .child(EnvironmentScope::new(content).toolbar(slot.clone())),
+            )
+            .child(NavigationBar { bar, slot })
+    }
+}
+
+/// The navigation bar, with the items the showing screen declared.
+#[derive(IntoElement)]
+struct NavigationBar {
+    bar: Toolbar,
+    slot: ToolbarSlot,
+}
+
+impl RenderOnce for NavigationBar {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let bar = match self.slot.borrow_mut().take() {
+            Some(declared) => declared.merged_into(self.bar),
+            None => self.bar,
+        };
+        bar.bar(BAR_HEIGHT, cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn screens_pop_back_to_the_root() {
+        let mut path = NavigationPath::new();
+        path.push("Items", |_, _| div());
+        path.push("Detail", |_, _| div());
+        assert_eq!(path.len(), 2);
+        assert!(path.pop());
+        assert_eq!(path.stack[0].title, "Items");
+
+        path.push("Detail", |_, _| div());
+        path.pop_to_root();
+        assert!(path.is_empty());
+        assert!(!path.pop());
+    }
+}
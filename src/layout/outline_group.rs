@@ -0,0 +1,397 @@
+//! OutlineGroup - An expandable tree over hierarchical data.
+
+use std::collections::HashSet;
+
+use gpui::{
+    div, percentage, px, AnyElement, App, AppContext, Div, ElementId, Entity, InteractiveElement,
+    IntoElement, ParentElement, RenderOnce, SharedString, StatefulInteractiveElement, Styled,
+    Window,
+};
+use gpui_component::{ActiveTheme, Icon, IconName};
+
+use crate::modifier::Modifier;
+use crate::style::Color;
+
+use super::list::Section;
+use super::list_types::RowId;
+
+/// The indentation of each level of the tree, in points.
+const DEFAULT_INDENT: f32 = 16.0;
+
+/// The width of the chevron column, in points.
+const CHEVRON_WIDTH: f32 = 16.0;
+
+type ChildrenFn<T> = Box<dyn Fn(&T) -> Option<Vec<T>>>;
+type NodeIdFn<T> = Box<dyn Fn(&T) -> RowId>;
+type RowFn<T> = Box<dyn Fn(&T) -> AnyElement>;
+
+/// Which nodes of an outline are expanded.
+///
+/// Keep one in an entity and pass it to [`OutlineGroup::state`] to expand
+/// and collapse nodes from code, or let the outline keep its own.
+#[derive(Clone, Debug, Default)]
+pub struct OutlineState {
+    expanded: HashSet<RowId>,
+}
+
+impl OutlineState {
+    /// Create a state with every node collapsed.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the node `id` shows its children.
+    pub fn is_expanded(&self, id: &RowId) -> bool {
+        self.expanded.contains(id)
+    }
+
+    /// Show or hide the children of the node `id`.
+    pub fn set_expanded(&mut self, id: impl Into<RowId>, expanded: bool) {
+        let id = id.into();
+        if expanded {
+            self.expanded.insert(id);
+        } else {
+            self.expanded.remove(&id);
+        }
+    }
+
+    /// Expand the node `id` if collapsed, or collapse it if expanded.
+    pub fn toggle(&mut self, id: impl Into<RowId>) {
+        let id = id.into();
+        if !self.expanded.remove(&id) {
+            self.expanded.insert(id);
+        }
+    }
+}
+
+/// A node of the tree that is showing, in order from the top.
+struct VisibleNode<T> {
+    value: T,
+    id: RowId,
+    depth: usize,
+    /// Whether the node has children to disclose, even if none right now.
+    expandable: bool,
+    expanded: bool,
+}
+
+/// An indented tree of rows, each node with children disclosed by a
+/// chevron, like SwiftUI's `OutlineGroup`.
+///
+/// `children` returns `None` for leaves and `Some` for nodes that can
+/// expand. Expansion is keyed by each node's id, which is its path from the
+/// roots unless [`node_id`](Self::node_id) gives a stable one. Clicking a
+/// row with children expands or collapses it.
+///
+/// To select nodes, turn the outline into a section of a selectable `List`
+/// with [`into_section`](Self::into_section): nodes become rows with their
+/// node ids, and the chevrons expand and collapse them.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// OutlineGroup::new(
+///     "files",
+///     vec![root_folder],
+///     |node: &FileNode| node.is_folder().then(|| node.children()),
+///     |node| Label::new(node.symbol(), node.name()),
+/// )
+/// .node_id(|node| node.path().into())
+/// ```
+#[derive(IntoElement)]
+pub struct OutlineGroup<T: 'static> {
+    id: ElementId,
+    roots: Vec<T>,
+    children: ChildrenFn<T>,
+    row: RowFn<T>,
+    node_id: Option<NodeIdFn<T>>,
+    indent: f32,
+    state: Option<Entity<OutlineState>>,
+}
+
+impl<T: 'static> OutlineGroup<T> {
+    /// Create an outline of `roots` and their descendants, drawing each node
+    /// with `row`.
+    pub fn new<V: IntoElement>(
+        id: impl Into<ElementId>,
+        roots: impl IntoIterator<Item = T>,
+        children: impl Fn(&T) -> Option<Vec<T>> + 'static,
+        row: impl Fn(&T) -> V + 'static,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            roots: roots.into_iter().collect(),
+            children: Box::new(children),
+            row: Box::new(move |node| row(node).into_any_element()),
+            node_id: None,
+            indent: DEFAULT_INDENT,
+            state: None,
+        }
+    }
+
+    /// Identify nodes by `id`, so they stay expanded as the data changes.
+    pub fn node_id(mut self, id: impl Fn(&T) -> RowId + 'static) -> Self {
+        self.node_id = Some(Box::new(id));
+        self
+    }
+
+    /// Indent each level by `indent` points (default 16).
+    pub fn indent(mut self, indent: f32) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Keep which nodes are expanded in `state`.
+    pub fn state(mut self, state: &Entity<OutlineState>) -> Self {
+        self.state = Some(state.clone());
+        self
+    }
+
+    /// The outline's nodes as rows of a list section, with their node ids as
+    /// row ids, so a selectable list can select them.
+    ///
+    /// Pass a [`state`](Self::state) for the nodes to stay expanded: without
+    /// one, every render starts collapsed.
+    pub fn into_section(mut self, cx: &mut App) -> Section {
+        let state = match self.state.take() {
+            Some(state) => state,
+            None => cx.new(|_| OutlineState::new()),
+        };
+        self.nodes(&state, cx)
+            .fold(Section::new(), |section, (node, row)| {
+                section.row_id(node.id.clone(), row)
+            })
+    }
+
+    /// The showing nodes and their rows, depth first.
+    fn nodes<'a>(
+        self,
+        state: &'a Entity<OutlineState>,
+        cx: &'a App,
+    ) -> impl Iterator<Item = (VisibleNode<T>, Div)> + 'a {
+        let nodes = visible_nodes(
+            self.roots,
+            &self.children,
+            self.node_id.as_deref(),
+            state.read(cx),
+        );
+        let (row, indent) = (self.row, self.indent);
+        nodes.into_iter().map(move |node| {
+            let content = row(&node.value);
+            let row = node_row(&node, content, indent, state, cx);
+            (node, row)
+        })
+    }
+}
+
+impl<T: 'static> Modifier for OutlineGroup<T> {}
+
+impl<T: 'static> RenderOnce for OutlineGroup<T> {
+    fn render(mut self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let id = self.id.clone();
+        let state = self
+            .state
+            .take()
+            .unwrap_or_else(|| window.use_keyed_state(id.clone(), cx, |_, _| OutlineState::new()));
+        let hover = cx.theme().list_hover;
+        let rows: Vec<AnyElement> = self
+            .nodes(&state, cx)
+            .map(|(node, row)| {
+                let row = row
+                    .id(node.id.clone())
+                    .py(px(4.0))
+                    .rounded(px(4.0))
+                    .hover(move |row| row.bg(hover));
+                if !node.expandable {
+                    return row.into_any_element();
+                }
+                // The whole row expands and collapses
+                let (state, id) = (state.clone(), node.id);
+                row.cursor_pointer()
+                    .on_click(move |_, window, cx| toggle(&state, &id, window, cx))
+                    .into_any_element()
+            })
+            .collect();
+        div().id(id).flex().flex_col().w_full().children(rows)
+    }
+}
+
+/// A node's row: its content after a chevron, indented by its depth.
+fn node_row<T>(
+    node: &VisibleNode<T>,
+    content: AnyElement,
+    indent: f32,
+    state: &Entity<OutlineState>,
+    cx: &App,
+) -> Div {
+    let chevron = node.expandable.then(|| {
+        let (state, id) = (state.clone(), node.id.clone());
+        let rotation = if node.expanded { 0.25 } else { 0.0 };
+        div()
+            .id(ElementId::NamedChild(
+                Box::new(node.id.clone()),
+                SharedString::from("outline-chevron"),
+            ))
+            .cursor_pointer()
+            .child(
+                Icon::new(IconName::ChevronRight)
+                    .size(px(12.0))
+                    .rotate(percentage(rotation))
+                    .text_color(Color::secondary_label().resolve_in(cx)),
+            )
+            .on_click(move |_, window, cx| {
+                cx.stop_propagation();
+                toggle(&state, &id, window, cx);
+            })
+    });
+    div()
+        .flex()
+        .flex_row()
+        .items_center()
+        .gap(px(4.0))
+        .w_full()
+        .pl(px(indent * node.depth as f32))
+        .child(
+            div()
+                .flex()
+                .items_center()
+                .justify_center()
+                .flex_shrink_0()
+                .w(px(CHEVRON_WIDTH))
+                .children(chevron),
+        )
+        .child(div().flex_1().min_w_0().child(content))
+}
+
+/// Expand or collapse the node `id`.
+fn toggle(state: &Entity<OutlineState>, id: &RowId, window: &mut Window, cx: &mut App) {
+    state.update(cx, |state, cx| {
+        state.toggle(id.clone());
+        cx.notify();
+    });
+    // The view showing the outline may not observe a state passed in
+    window.refresh();
+}
+
+/// The nodes of the tree under `roots` that show with `state`, depth first.
+fn visible_nodes<T>(
+    roots: Vec<T>,
+    children: &dyn Fn(&T) -> Option<Vec<T>>,
+    node_id: Option<&dyn Fn(&T) -> RowId>,
+    state: &OutlineState,
+) -> Vec<VisibleNode<T>> {
+    fn collect<T>(
+        level: Vec<T>,
+        depth: usize,
+        path: &mut Vec<usize>,
+        children: &dyn Fn(&T) -> Option<Vec<T>>,
+        node_id: Option<&dyn Fn(&T) -> RowId>,
+        state: &OutlineState,
+        nodes: &mut Vec<VisibleNode<T>>,
+    ) {
+        for (ix, value) in level.into_iter().enumerate() {
+            path.push(ix);
+            let id = match node_id {
+                Some(node_id) => node_id(&value),
+                None => RowId::Name(path_name(path)),
+            };
+            let descendants = children(&value);
+            let expandable = descendants.is_some();
+            let expanded = expandable && state.is_expanded(&id);
+            nodes.push(VisibleNode {
+                value,
+                id,
+                depth,
+                expandable,
+                expanded,
+            });
+            if let Some(descendants) = descendants.filter(|_| expanded) {
+                collect(
+                    descendants,
+                    depth + 1,
+                    path,
+                    children,
+                    node_id,
+                    state,
+                    nodes,
+                );
+            }
+            path.pop();
+        }
+    }
+
+    let mut nodes = Vec::new();
+    collect(
+        roots,
+        0,
+        &mut Vec::new(),
+        children,
+        node_id,
+        state,
+        &mut nodes,
+    );
+    nodes
+}
+
+/// The id of the node at `path`, like "0/2/1".
+fn path_name(path: &[usize]) -> SharedString {
+    let parts: Vec<String> = path.iter().map(usize::to_string).collect();
+    parts.join("/").into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A folder tree: "src" holds "layout", which holds "list.rs".
+    fn children(name: &&'static str) -> Option<Vec<&'static str>> {
+        match *name {
+            "src" => Some(vec!["layout", "lib.rs"]),
+            "layout" => Some(vec!["list.rs"]),
+            "docs" => Some(Vec::new()),
+            _ => None,
+        }
+    }
+
+    fn names(nodes: &[VisibleNode<&'static str>]) -> Vec<(&'static str, usize)> {
+        nodes.iter().map(|node| (node.value, node.depth)).collect()
+    }
+
+    #[test]
+    fn only_expanded_nodes_show_their_children() {
+        let mut state = OutlineState::new();
+        let node_id = |name: &&'static str| RowId::from(*name);
+        let nodes = visible_nodes(vec!["src", "docs"], &children, Some(&node_id), &state);
+        assert_eq!(names(&nodes), [("src", 0), ("docs", 0)]);
+        assert!(nodes.iter().all(|node| node.expandable));
+
+        state.set_expanded("src", true);
+        // Expanded but hidden under a collapsed parent
+        state.set_expanded("list.rs", true);
+        let nodes = visible_nodes(vec!["src", "docs"], &children, Some(&node_id), &state);
+        assert_eq!(
+            names(&nodes),
+            [("src", 0), ("layout", 1), ("lib.rs", 1), ("docs", 0)]
+        );
+        assert!(!nodes[2].expandable);
+
+        state.toggle("layout");
+        let nodes = visible_nodes(vec!["src", "docs"], &children, Some(&node_id), &state);
+        assert_eq!(names(&nodes)[2], ("list.rs", 2));
+    }
+
+    #[test]
+    fn nodes_default_to_their_path() {
+        let mut state = OutlineState::new();
+        state.set_expanded(RowId::Name(path_name(&[0])), true);
+        let nodes = visible_nodes(vec!["src"], &children, None, &state);
+        let ids: Vec<RowId> = nodes.into_iter().map(|node| node.id).collect();
+        assert_eq!(
+            ids,
+            [
+                RowId::Name("0".into()),
+                RowId::Name("0/0".into()),
+                RowId::Name("0/1".into())
+            ]
+        );
+    }
+}
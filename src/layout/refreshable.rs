@@ -0,0 +1,283 @@
+//! Pull-to-refresh for scrolling containers.
+//!
+//! GPUI clamps scrolling at the top of the content, so a refreshable view
+//! watches scroll wheel events itself. While the content is at the top,
+//! scrolling further up pulls an indicator down above it. Letting go past
+//! the threshold starts a refresh that lasts until the app finishes it.
+
+use std::rc::Rc;
+use std::time::Duration;
+
+use gpui::{
+    div, px, AnyElement, App, Context, Div, ElementId, Entity, InteractiveElement, IntoElement,
+    ParentElement, ScrollHandle, ScrollWheelEvent, Stateful, Styled, TouchPhase, Window,
+};
+
+use crate::components::{ProgressView, Text};
+use crate::style::{Color, Font};
+
+/// How far the indicator must be pulled down to refresh, in points.
+const THRESHOLD: f32 = 60.0;
+
+/// How far the indicator can be pulled at most, in points.
+const MAX_PULL: f32 = 160.0;
+
+/// The height of the indicator while refreshing, in points.
+const REFRESHING_HEIGHT: f32 = 44.0;
+
+/// How long scrolling must pause to count as letting go, for mice and
+/// trackpads that don't report when the fingers lift.
+pub(crate) const RELEASE_DELAY: Duration = Duration::from_millis(150);
+
+/// Called when the user pulls to refresh.
+pub(crate) type RefreshHandler = Rc<dyn Fn(RefreshCompletion, &mut Window, &mut App)>;
+
+/// Ends a refresh started by pulling a refreshable view.
+///
+/// Passed to the `.refreshable()` handler. The indicator keeps spinning
+/// until [`finish`](Self::finish) is called, so hold on to it (it is cheap
+/// to clone) and call it when the new content has loaded.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let view = cx.entity().downgrade();
+/// List::new("inbox").refreshable(move |completion, _, cx| {
+///     let view = view.clone();
+///     cx.spawn(async move |cx| {
+///         let messages = fetch_messages().await;
+///         view.update(cx, |this, cx| {
+///             this.messages = messages;
+///             completion.finish(cx);
+///         })
+///     })
+///     .detach();
+/// })
+/// ```
+#[derive(Clone)]
+pub struct RefreshCompletion {
+    state: Entity<RefreshState>,
+}
+
+impl RefreshCompletion {
+    /// Hide the indicator and let the view be pulled again.
+    pub fn finish(&self, cx: &mut App) {
+        self.state.update(cx, |state, cx| {
+            state.refreshing = false;
+            cx.notify();
+        });
+    }
+}
+
+/// How far a refreshable view is pulled, kept across frames.
+#[derive(Debug, Default)]
+pub(crate) struct RefreshState {
+    /// Distance scrolled past the top, before rubber-banding.
+    pull: f32,
+    refreshing: bool,
+    /// Bumped by every pull, so only the last one's release timer fires.
+    generation: usize,
+}
+
+impl RefreshState {
+    /// Pull by `delta` points, positive towards the top, with the content
+    /// `at_top`. Returns whether the pull changed.
+    fn pull_by(&mut self, delta: f32, at_top: bool) -> bool {
+        if self.refreshing || (self.pull == 0.0 && !(at_top && delta > 0.0)) {
+            return false;
+        }
+        self.pull = (self.pull + delta).max(0.0);
+        self.generation = self.generation.wrapping_add(1);
+        true
+    }
+
+    /// Let go, and return whether that starts a refresh.
+    fn release(&mut self) -> bool {
+        let armed = self.is_armed();
+        self.pull = 0.0;
+        self.refreshing = armed;
+        armed
+    }
+
+    /// How far the indicator shows, in points.
+    fn distance(&self) -> f32 {
+        if self.refreshing {
+            REFRESHING_HEIGHT
+        } else {
+            rubber_band(self.pull)
+        }
+    }
+
+    /// Whether letting go now would refresh.
+    fn is_armed(&self) -> bool {
+        rubber_band(self.pull) >= THRESHOLD
+    }
+}
+
+/// The distance shown for a pull of `pull` points, which resists more the
+/// further it goes and never reaches [`MAX_PULL`].
+fn rubber_band(pull: f32) -> f32 {
+    (1.0 - 1.0 / (pull * 0.55 / MAX_PULL + 1.0)) * MAX_PULL
+}
+
+/// The pull state of the refreshable view `id`.
+pub(crate) fn refresh_state(
+    id: impl Into<ElementId>,
+    window: &mut Window,
+    cx: &mut App,
+) -> Entity<RefreshState> {
+    window.use_keyed_state(id, cx, |_, _| RefreshState::default())
+}
+
+/// Let `scroll`, tracked by `handle`, be pulled down to call `handler`.
+pub(crate) fn pull_to_refresh(
+    scroll: Stateful<Div>,
+    handle: ScrollHandle,
+    state: Entity<RefreshState>,
+    handler: RefreshHandler,
+) -> Stateful<Div> {
+    scroll.on_scroll_wheel(move |event: &ScrollWheelEvent, window, cx| {
+        let at_top = handle.offset().y >= px(0.0);
+        let delta = f32::from(event.delta.pixel_delta(window.line_height()).y);
+        let pulled = state.update(cx, |state, cx| {
+            let pulled = state.pull_by(delta, at_top);
+            if pulled {
+                cx.notify();
+            }
+            pulled.then_some(state.generation)
+        });
+        let Some(generation) = pulled else {
+            return;
+        };
+
+        if matches!(event.touch_phase, TouchPhase::Ended) {
+            release(&state, &handler, window, cx);
+            return;
+        }
+        let (state, handler) = (state.clone(), handler.clone());
+        window
+            .spawn(cx, async move |cx| {
+                cx.background_executor().timer(RELEASE_DELAY).await;
+                cx.update(|window, cx| {
+                    // Still pulling
+                    if state.read(cx).generation == generation {
+                        release(&state, &handler, window, cx);
+                    }
+                })
+                .ok();
+            })
+            .detach();
+    })
+}
+
+/// Let go of a pull, refreshing if it went past the threshold.
+fn release(
+    state: &Entity<RefreshState>,
+    handler: &RefreshHandler,
+    window: &mut Window,
+    cx: &mut App,
+) {
+    let refresh = state.update(cx, |state, cx: &mut Context<RefreshState>| {
+        if state.pull == 0.0 {
+            return false;
+        }
+        cx.notify();
+        state.release()
+    });
+    if refresh {
+        handler(
+            RefreshCompletion {
+                state: state.clone(),
+            },
+            window,
+            cx,
+        );
+    }
+}
+
+/// The indicator shown above the content, as tall as the pull.
+pub(crate) fn refresh_indicator(state: &Entity<RefreshState>, cx: &App) -> Option<AnyElement> {
+    let state = state.read(cx);
+    let distance = state.distance();
+    if distance <= 0.0 {
+        return None;
+    }
+
+    let content = if state.refreshing {
+        ProgressView::new().into_any_element()
+    } else {
+        let (progress, label) = if state.is_armed() {
+            (1.0, "Release to refresh")
+        } else {
+            (distance / THRESHOLD, "Pull to refresh")
+        };
+        div()
+            .flex()
+            .flex_row()
+            .items_center()
+            .gap(px(8.0))
+            .child(ProgressView::new().value(progress))
+            .child(
+                Text::new(label)
+                    .font(Font::caption())
+                    .foreground_color(Color::secondary_label()),
+            )
+            .into_any_element()
+    };
+
+    // Revealed from the bottom as the pull grows
+    Some(
+        div()
+            .flex()
+            .flex_col()
+            .flex_shrink_0()
+            .items_center()
+            .justify_end()
+            .w_full()
+            .h(px(distance))
+            .pb(px(8.0))
+            .overflow_hidden()
+            .child(content)
+            .into_any_element(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pulling_starts_only_at_the_top() {
+        let mut state = RefreshState::default();
+        assert!(!state.pull_by(20.0, false));
+        assert!(!state.pull_by(-20.0, true));
+        assert!(state.pull_by(20.0, true));
+        // Once pulled, scrolling back shrinks the pull wherever the content is
+        assert!(state.pull_by(-30.0, false));
+        assert_eq!(state.pull, 0.0);
+    }
+
+    #[test]
+    fn releasing_past_the_threshold_refreshes() {
+        let mut state = RefreshState::default();
+        state.pull_by(100.0, true);
+        assert!(!state.is_armed());
+        assert!(!state.release());
+        assert_eq!(state.distance(), 0.0);
+
+        state.pull_by(300.0, true);
+        assert!(state.is_armed());
+        assert!(state.release());
+        assert_eq!(state.distance(), REFRESHING_HEIGHT);
+        // No pulling again until the refresh finishes
+        assert!(!state.pull_by(300.0, true));
+    }
+
+    #[test]
+    fn pull_resists_and_is_bounded() {
+        assert_eq!(rubber_band(0.0), 0.0);
+        assert!(rubber_band(100.0) < 100.0);
+        assert!(rubber_band(400.0) > rubber_band(200.0));
+        assert!(rubber_band(100_000.0) < MAX_PULL);
+    }
+}
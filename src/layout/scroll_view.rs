@@ -1,11 +1,23 @@
 //! ScrollView - Scrollable container.
 
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
 use gpui::{
-    div, App, InteractiveElement, IntoElement, ParentElement, RenderOnce, SharedString,
-    StatefulInteractiveElement, Styled, Window,
+    div, ease_in_out, point, px, Along, AnyElement, App, Axis, Bounds, Div, ElementId,
+    InteractiveElement, IntoElement, ParentElement, Pixels, Point, RenderOnce, ScrollHandle,
+    SharedString, Size, Stateful, StatefulInteractiveElement, Styled, Window,
 };
-use gpui_component::scroll::ScrollableElement;
+use gpui_component::scroll::{Scrollbar, ScrollbarAxis, ScrollbarShow};
 
+use super::lazy_stack::ScrollAnchor;
+use super::list_types::EdgeInsets;
+use super::refreshable::{
+    pull_to_refresh, refresh_indicator, refresh_state, RefreshCompletion, RefreshHandler,
+};
+use crate::environment::EnvironmentScope;
 use crate::modifier::Modifier;
 
 /// Specifies which axes are scrollable.
@@ -35,6 +47,306 @@ impl ScrollAxes {
     pub fn both() -> Self {
         Self::Both
     }
+
+    /// Make `base` scroll along these axes, without indicators.
+    fn scroll(self, base: Stateful<Div>) -> Stateful<Div> {
+        match self {
+            ScrollAxes::Vertical => base.flex_col().overflow_y_scroll(),
+            ScrollAxes::Horizontal => base.flex_row().overflow_x_scroll(),
+            ScrollAxes::Both => base.flex_col().overflow_scroll(),
+        }
+    }
+}
+
+impl From<ScrollAxes> for ScrollbarAxis {
+    fn from(axes: ScrollAxes) -> Self {
+        match axes {
+            ScrollAxes::Vertical => ScrollbarAxis::Vertical,
+            ScrollAxes::Horizontal => ScrollbarAxis::Horizontal,
+            ScrollAxes::Both => ScrollbarAxis::Both,
+        }
+    }
+}
+
+/// Where a [`ScrollView`] comes to rest after the user scrolls it.
+///
+/// Like SwiftUI's `scrollTargetBehavior`. Snapping follows the main axis:
+/// vertical for vertical scroll views, horizontal otherwise.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SnapBehavior {
+    /// Settle on a multiple of the viewport length, one page at a time.
+    Paging,
+    /// Settle with the nearest view marked `.scroll_target()` at the start
+    /// of the viewport, `spacing` points in from the edge.
+    ToItems {
+        /// Space left before the item, in points.
+        spacing: f32,
+    },
+}
+
+/// When a [`ScrollView`] shows its scroll indicators.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ScrollIndicatorStyle {
+    /// Follow the theme, which by default shows them while scrolling
+    /// (default).
+    #[default]
+    Auto,
+    /// Always show them, even when idle.
+    Always,
+    /// Never show them. The content still scrolls.
+    Never,
+}
+
+/// How long an animated scroll takes.
+const SCROLL_ANIMATION: Duration = Duration::from_millis(300);
+
+/// How long scrolling must pause before a snapping view settles. Scroll
+/// wheel events have no end, and momentum keeps sending them after a fling.
+const SNAP_DELAY: Duration = Duration::from_millis(150);
+
+/// Called with the index of the page or item a snapping view settled on.
+type SnapHandler = Rc<dyn Fn(&usize, &mut Window, &mut App)>;
+
+/// Scrolls a [`ScrollView`] from code, like SwiftUI's `ScrollViewProxy`.
+///
+/// Store one in your view struct and pass it to [`ScrollView::track`]. Its
+/// methods take effect on the next frame; call them on
+/// [`animated`](Self::animated) to ease there instead of jumping.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// struct LogView {
+///     scroll: ScrollViewProxy,
+///     lines: Vec<SharedString>,
+/// }
+///
+/// // In render:
+/// VStack::new()
+///     .child(
+///         ScrollView::new("log")
+///             .track(&self.scroll)
+///             .child(VStack::new().children(self.lines.iter().map(|line| Text::new(line.clone())))),
+///     )
+///     .child(Button::new("Jump to latest", || {}).on_click_with({
+///         let scroll = self.scroll.clone();
+///         move |_, window, _| scroll.animated().scroll_to_bottom(window)
+///     }))
+/// ```
+#[derive(Clone, Default)]
+pub struct ScrollViewProxy {
+    handle: ScrollHandle,
+    /// Bounds of the views tagged with `.scroll_id()`, relative to the
+    /// start of the content.
+    targets: Rc<RefCell<HashMap<ElementId, Bounds<Pixels>>>>,
+    /// Bounds of the views marked with `.scroll_target()`, in the same
+    /// coordinates, in layout order.
+    snap_targets: Rc<RefCell<Vec<Bounds<Pixels>>>>,
+    /// Bumped by every scroll, so a newer one stops an animation.
+    generation: Rc<Cell<usize>>,
+    animated: bool,
+}
+
+/// Computes a scroll offset from the handle's current state.
+type OffsetFn = Rc<dyn Fn(&ScrollHandle) -> Point<Pixels>>;
+
+impl ScrollViewProxy {
+    /// Create a proxy for a scroll view.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A copy of the proxy whose scrolls animate.
+    pub fn animated(&self) -> Self {
+        Self {
+            animated: true,
+            ..self.clone()
+        }
+    }
+
+    /// Scroll to the start of the content.
+    pub fn scroll_to_top(&self, window: &mut Window) {
+        self.scroll(window, Rc::new(|_| Point::default()));
+    }
+
+    /// Scroll to the end of the content.
+    pub fn scroll_to_bottom(&self, window: &mut Window) {
+        // Read the extent as late as possible, so content added in the same
+        // update is included
+        self.scroll(
+            window,
+            Rc::new(|handle| point(px(0.), -handle.max_offset().height)),
+        );
+    }
+
+    /// Scroll by `dx` and `dy` points. Positive values reveal content
+    /// further right and down.
+    pub fn scroll_by(&self, dx: f32, dy: f32, window: &mut Window) {
+        let target = self.handle.offset() - point(px(dx), px(dy));
+        self.scroll(window, Rc::new(move |_| target));
+    }
+
+    /// Scroll so the view tagged `.scroll_id(id)` lands at `anchor` in the
+    /// viewport.
+    ///
+    /// Returns `false`, without scrolling, if no such view was drawn in the
+    /// last frame.
+    pub fn scroll_to_id(
+        &self,
+        id: impl Into<ElementId>,
+        anchor: ScrollAnchor,
+        window: &mut Window,
+    ) -> bool {
+        let Some(target) = self.targets.borrow().get(&id.into()).copied() else {
+            return false;
+        };
+        self.scroll(
+            window,
+            Rc::new(move |handle| offset_for(target, anchor, handle.bounds().size)),
+        );
+        true
+    }
+
+    /// Record where the view tagged `id` was laid out, in window coordinates.
+    pub(crate) fn record_target(&self, id: ElementId, bounds: Bounds<Pixels>) {
+        let bounds = self.content_bounds(bounds);
+        self.targets.borrow_mut().insert(id, bounds);
+    }
+
+    /// Record where a view marked with `.scroll_target()` was laid out, in
+    /// window coordinates.
+    pub(crate) fn record_snap_target(&self, bounds: Bounds<Pixels>) {
+        let bounds = self.content_bounds(bounds);
+        self.snap_targets.borrow_mut().push(bounds);
+    }
+
+    /// `bounds` in window coordinates, relative to the start of the content.
+    fn content_bounds(&self, bounds: Bounds<Pixels>) -> Bounds<Pixels> {
+        let origin = bounds.origin - self.handle.bounds().origin - self.handle.offset();
+        Bounds::new(origin, bounds.size)
+    }
+
+    /// Forget the targets, which record themselves again as they prepaint.
+    fn clear_targets(&self) {
+        self.targets.borrow_mut().clear();
+        self.snap_targets.borrow_mut().clear();
+    }
+
+    /// Stop any animated scroll, as when the user takes over.
+    fn interrupt(&self) -> usize {
+        let generation = self.generation.get().wrapping_add(1);
+        self.generation.set(generation);
+        generation
+    }
+
+    /// Animate to the item `snap` settles on along `axis`, and return its
+    /// index.
+    fn settle(&self, snap: SnapBehavior, axis: Axis, window: &mut Window) -> Option<usize> {
+        let starts: Vec<f32> = self
+            .snap_targets
+            .borrow()
+            .iter()
+            .map(|target| f32::from(target.origin.along(axis)))
+            .collect();
+        let (index, position) = snap_position(
+            snap,
+            -f32::from(self.handle.offset().along(axis)),
+            f32::from(self.handle.bounds().size.along(axis)),
+            &starts,
+        )?;
+        self.animated().scroll(
+            window,
+            Rc::new(move |handle| handle.offset().apply_along(axis, |_| px(-position))),
+        );
+        Some(index)
+    }
+
+    fn scroll(&self, window: &mut Window, target: OffsetFn) {
+        self.interrupt();
+        if self.animated {
+            self.animate(window, target, self.handle.offset(), Instant::now());
+        } else {
+            self.handle
+                .set_offset(clamp_offset(target(&self.handle), self.handle.max_offset()));
+            window.refresh();
+        }
+    }
+
+    fn animate(
+        &self,
+        window: &mut Window,
+        target: OffsetFn,
+        from: Point<Pixels>,
+        started: Instant,
+    ) {
+        let this = self.clone();
+        let generation = self.generation.get();
+        window.on_next_frame(move |window, _| {
+            if this.generation.get() != generation {
+                return;
+            }
+            let progress =
+                (started.elapsed().as_secs_f32() / SCROLL_ANIMATION.as_secs_f32()).min(1.0);
+            let to = clamp_offset(target(&this.handle), this.handle.max_offset());
+            this.handle
+                .set_offset(from + (to - from) * ease_in_out(progress));
+            window.refresh();
+            if progress < 1.0 {
+                this.animate(window, target, from, started);
+            }
+        });
+    }
+}
+
+/// The offset that puts `target`, in content coordinates, at `anchor` in a
+/// viewport of `viewport` size.
+fn offset_for(
+    target: Bounds<Pixels>,
+    anchor: ScrollAnchor,
+    viewport: Size<Pixels>,
+) -> Point<Pixels> {
+    let slack = viewport - target.size;
+    let shift = match anchor {
+        ScrollAnchor::Top => Size::default(),
+        ScrollAnchor::Center => slack / 2.,
+        ScrollAnchor::Bottom => slack,
+    };
+    point(
+        shift.width - target.origin.x,
+        shift.height - target.origin.y,
+    )
+}
+
+/// The item to settle on and where it starts, in points from the start of
+/// the content, for a view scrolled `scrolled` points with a viewport
+/// `viewport` points long.
+fn snap_position(
+    snap: SnapBehavior,
+    scrolled: f32,
+    viewport: f32,
+    starts: &[f32],
+) -> Option<(usize, f32)> {
+    match snap {
+        SnapBehavior::Paging if viewport > 0.0 => {
+            let page = (scrolled / viewport).round().max(0.0);
+            Some((page as usize, page * viewport))
+        }
+        SnapBehavior::Paging => None,
+        SnapBehavior::ToItems { spacing } => starts
+            .iter()
+            .map(|start| (start - spacing).max(0.0))
+            .enumerate()
+            .min_by(|(_, a), (_, b)| (a - scrolled).abs().total_cmp(&(b - scrolled).abs())),
+    }
+}
+
+/// Keep `offset` inside the scrollable range. Offsets are negative as the
+/// content moves up and left.
+fn clamp_offset(offset: Point<Pixels>, max: Size<Pixels>) -> Point<Pixels> {
+    point(
+        offset.x.clamp(-max.width, px(0.)),
+        offset.y.clamp(-max.height, px(0.)),
+    )
 }
 
 /// A view that allows its content to be scrolled.
@@ -55,7 +367,12 @@ impl ScrollAxes {
 pub struct ScrollView {
     id: SharedString,
     axes: ScrollAxes,
-    shows_indicators: bool,
+    indicator_style: ScrollIndicatorStyle,
+    content_insets: Option<EdgeInsets>,
+    proxy: Option<ScrollViewProxy>,
+    snap: Option<SnapBehavior>,
+    on_snap: Option<SnapHandler>,
+    on_refresh: Option<RefreshHandler>,
     children: Vec<gpui::AnyElement>,
 }
 
@@ -67,7 +384,12 @@ impl ScrollView {
         Self {
             id: id.into(),
             axes: ScrollAxes::Vertical,
-            shows_indicators: true,
+            indicator_style: ScrollIndicatorStyle::Auto,
+            content_insets: None,
+            proxy: None,
+            snap: None,
+            on_snap: None,
+            on_refresh: None,
             children: Vec::new(),
         }
     }
@@ -80,9 +402,66 @@ impl ScrollView {
 
     /// Set whether to show scroll indicators.
     ///
-    /// Note: GPUI may not support hiding scroll indicators in all cases.
+    /// Shorthand for [`indicator_style`](Self::indicator_style) with
+    /// `Auto` or `Never`.
     pub fn shows_indicators(mut self, show: bool) -> Self {
-        self.shows_indicators = show;
+        self.indicator_style = if show {
+            ScrollIndicatorStyle::Auto
+        } else {
+            ScrollIndicatorStyle::Never
+        };
+        self
+    }
+
+    /// Set when to show scroll indicators (default auto).
+    pub fn indicator_style(mut self, style: ScrollIndicatorStyle) -> Self {
+        self.indicator_style = style;
+        self
+    }
+
+    /// Inset the content from the edges of the scroll view.
+    ///
+    /// Like SwiftUI's `contentMargins`, the insets pad the scrolled content
+    /// rather than the visible area, so content scrolls under them and
+    /// reaches the edges while scrolling.
+    pub fn content_insets(mut self, insets: impl Into<EdgeInsets>) -> Self {
+        self.content_insets = Some(insets.into());
+        self
+    }
+
+    /// Scroll this view from code through `proxy`, which is stored in your
+    /// view struct.
+    ///
+    /// Views inside tagged with `.scroll_id()` become targets for
+    /// [`ScrollViewProxy::scroll_to_id`].
+    pub fn track(mut self, proxy: &ScrollViewProxy) -> Self {
+        self.proxy = Some(proxy.clone());
+        self
+    }
+
+    /// Settle on pages or items once the user stops scrolling.
+    ///
+    /// Scrolling from code through a [`ScrollViewProxy`] doesn't snap.
+    pub fn snap(mut self, snap: SnapBehavior) -> Self {
+        self.snap = Some(snap);
+        self
+    }
+
+    /// Called with the index of the page or item the view settles on.
+    pub fn on_snap(mut self, handler: impl Fn(&usize, &mut Window, &mut App) + 'static) -> Self {
+        self.on_snap = Some(Rc::new(handler));
+        self
+    }
+
+    /// Let the user pull the content down past the top to refresh it.
+    ///
+    /// The handler gets a [`RefreshCompletion`]; the refresh indicator
+    /// spins until it is finished. Vertical scroll views only.
+    pub fn refreshable(
+        mut self,
+        handler: impl Fn(RefreshCompletion, &mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.on_refresh = Some(Rc::new(handler));
         self
     }
 
@@ -92,35 +471,204 @@ impl ScrollView {
 impl Modifier for ScrollView {}
 
 impl RenderOnce for ScrollView {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
-        let base = div().id(self.id).size_full().flex();
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let axes = self.axes;
+        let children = match self.content_insets {
+            Some(insets) => vec![inset_content(axes, insets, self.children)],
+            None => self.children,
+        };
 
-        match (self.axes, self.shows_indicators) {
-            (ScrollAxes::Vertical, true) => base
-                .flex_col()
-                .overflow_y_scrollbar()
-                .children(self.children)
-                .into_any_element(),
-            (ScrollAxes::Vertical, false) => base
-                .flex_col()
-                .overflow_y_scroll()
-                .children(self.children)
-                .into_any_element(),
-            (ScrollAxes::Horizontal, true) => base
-                .flex_row()
-                .overflow_x_scrollbar()
-                .children(self.children)
-                .into_any_element(),
-            (ScrollAxes::Horizontal, false) => base
-                .flex_row()
-                .overflow_x_scroll()
-                .children(self.children)
-                .into_any_element(),
-            (ScrollAxes::Both, _) => base
+        let proxy = self.proxy.unwrap_or_else(|| {
+            window
+                .use_keyed_state(self.id.clone(), cx, |_, _| ScrollViewProxy::new())
+                .read(cx)
+                .clone()
+        });
+        proxy.clear_targets();
+        let handle = proxy.handle.clone();
+        let mut content = axes
+            .scroll(div().id(self.id.clone()).size_full().flex())
+            .track_scroll(&handle)
+            .children(children);
+        let on_refresh = self.on_refresh.filter(|_| axes == ScrollAxes::Vertical);
+        let mut refresh = None;
+        if let Some(handler) = on_refresh {
+            let state = refresh_state(self.id.clone(), window, cx);
+            content = pull_to_refresh(content, handle.clone(), state.clone(), handler);
+            refresh = Some(state);
+        }
+        if let Some(snap) = self.snap {
+            let axis = match axes {
+                ScrollAxes::Vertical => Axis::Vertical,
+                ScrollAxes::Horizontal | ScrollAxes::Both => Axis::Horizontal,
+            };
+            let (proxy, on_snap) = (proxy.clone(), self.on_snap);
+            content = content.on_scroll_wheel(move |_, window, cx| {
+                let generation = proxy.interrupt();
+                let (proxy, on_snap) = (proxy.clone(), on_snap.clone());
+                window
+                    .spawn(cx, async move |cx| {
+                        cx.background_executor().timer(SNAP_DELAY).await;
+                        cx.update(|window, cx| {
+                            // Still scrolling, or scrolled from code since
+                            if proxy.generation.get() != generation {
+                                return;
+                            }
+                            if let (Some(index), Some(on_snap)) =
+                                (proxy.settle(snap, axis, window), on_snap)
+                            {
+                                on_snap(&index, window, cx);
+                            }
+                        })
+                        .ok();
+                    })
+                    .detach();
+            });
+        }
+
+        // Drawn over the content like gpui-component's scrollable, so the
+        // scrollbar can follow the indicator style instead of the theme
+        let scrollbar = match (self.indicator_style, axes) {
+            (ScrollIndicatorStyle::Never, _) | (ScrollIndicatorStyle::Auto, ScrollAxes::Both) => {
+                None
+            }
+            (ScrollIndicatorStyle::Auto, _) => Some(Scrollbar::new(&handle).axis(axes)),
+            (ScrollIndicatorStyle::Always, _) => Some(
+                Scrollbar::new(&handle)
+                    .axis(axes)
+                    .scrollbar_show(ScrollbarShow::Always),
+            ),
+        };
+
+        let scroll_view = div()
+            .relative()
+            .size_full()
+            .child(EnvironmentScope::new(content).scroll_proxy(Some(proxy)))
+            .children(scrollbar.map(|scrollbar| div().absolute().inset_0().child(scrollbar)));
+        match refresh {
+            // The indicator pushes the whole view down as it is pulled
+            Some(state) => div()
+                .flex()
                 .flex_col()
-                .overflow_scroll()
-                .children(self.children)
+                .size_full()
+                .children(refresh_indicator(&state, cx))
+                .child(scroll_view.flex_1().min_h_0())
                 .into_any_element(),
+            None => scroll_view.into_any_element(),
         }
     }
 }
+
+/// Wrap `children` in a container padded by `insets`, laid out along `axes`.
+fn inset_content(axes: ScrollAxes, insets: EdgeInsets, children: Vec<AnyElement>) -> AnyElement {
+    // Padding the scroll container itself would move to the clip area once
+    // the scrollbar wraps it, so pad a wrapper that scrolls with the content
+    let content = div()
+        .flex()
+        .flex_shrink_0()
+        .pt(px(insets.top))
+        .pl(px(insets.leading))
+        .pb(px(insets.bottom))
+        .pr(px(insets.trailing))
+        .children(children);
+    match axes {
+        ScrollAxes::Horizontal => content.flex_row(),
+        ScrollAxes::Vertical | ScrollAxes::Both => content.flex_col(),
+    }
+    .into_any_element()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use gpui::{canvas, point, size, AvailableSpace, Bounds, Pixels, TestAppContext};
+
+    use super::*;
+
+    #[test]
+    fn content_insets_pad_the_scrolled_content() {
+        let recorded: Rc<Cell<Option<Bounds<Pixels>>>> = Rc::default();
+        let marker = {
+            let recorded = recorded.clone();
+            canvas(
+                move |bounds, _, _| recorded.set(Some(bounds)),
+                |_, _, _, _| {},
+            )
+            .w(px(400.))
+            .h(px(50.))
+        };
+        let mut cx = TestAppContext::single();
+        let cx = cx.add_empty_window();
+        cx.draw(
+            point(px(0.), px(0.)),
+            size(
+                AvailableSpace::Definite(px(200.)),
+                AvailableSpace::Definite(px(100.)),
+            ),
+            |_, _| {
+                div().size(px(200.)).flex().flex_row().child(inset_content(
+                    ScrollAxes::Horizontal,
+                    EdgeInsets::edges(10.0, 16.0, 10.0, 16.0),
+                    vec![marker.into_any_element()],
+                ))
+            },
+        );
+
+        // Inset from the leading edge, and not squeezed to the visible width
+        let bounds = recorded.get().unwrap();
+        assert_eq!(bounds.origin, point(px(16.), px(10.)));
+        assert_eq!(bounds.size.width, px(400.));
+    }
+
+    #[test]
+    fn targets_scroll_to_the_anchor_within_range() {
+        let target = Bounds::new(point(px(0.), px(300.)), size(px(100.), px(40.)));
+        let viewport = size(px(100.), px(200.));
+        assert_eq!(
+            offset_for(target, ScrollAnchor::Top, viewport),
+            point(px(0.), px(-300.))
+        );
+        assert_eq!(
+            offset_for(target, ScrollAnchor::Center, viewport),
+            point(px(0.), px(-220.))
+        );
+        assert_eq!(
+            offset_for(target, ScrollAnchor::Bottom, viewport),
+            point(px(0.), px(-140.))
+        );
+
+        // Near the end, the content stops at its last page
+        let max = size(px(0.), px(250.));
+        assert_eq!(
+            clamp_offset(point(px(0.), px(-300.)), max),
+            point(px(0.), px(-250.))
+        );
+        assert_eq!(
+            clamp_offset(point(px(20.), px(10.)), max),
+            point(px(0.), px(0.))
+        );
+    }
+
+    #[test]
+    fn snapping_settles_on_the_nearest_page_or_item() {
+        let paging = SnapBehavior::Paging;
+        assert_eq!(snap_position(paging, 140.0, 300.0, &[]), Some((0, 0.0)));
+        assert_eq!(snap_position(paging, 160.0, 300.0, &[]), Some((1, 300.0)));
+        assert_eq!(snap_position(paging, 0.0, 0.0, &[]), None);
+
+        let items = SnapBehavior::ToItems { spacing: 16.0 };
+        let starts = [16.0, 216.0, 416.0];
+        assert_eq!(snap_position(items, 90.0, 300.0, &starts), Some((0, 0.0)));
+        assert_eq!(
+            snap_position(items, 120.0, 300.0, &starts),
+            Some((1, 200.0))
+        );
+        assert_eq!(
+            snap_position(items, 380.0, 300.0, &starts),
+            Some((2, 400.0))
+        );
+        assert_eq!(snap_position(items, 120.0, 300.0, &[]), None);
+    }
+}
@@ -1,8 +1,9 @@
 //! Spacer - Flexible space component.
 
-use gpui::{div, px, App, IntoElement, RenderOnce, Styled, Window};
+use gpui::{div, px, App, Axis, IntoElement, RenderOnce, Styled, Window};
 
-use crate::modifier::Modifier;
+use crate::environment;
+use crate::modifier::{Expansion, Modifier};
 
 /// The axis along which a Spacer expands.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
@@ -11,8 +12,8 @@ pub enum SpacerAxis {
     Horizontal,
     /// Expand along the vertical axis (for use in VStack).
     Vertical,
-    /// Expand along both axes (legacy behavior).
-    /// This is the default for backwards compatibility.
+    /// Follow the enclosing stack: horizontal in an HStack, vertical in a
+    /// VStack, and both axes outside any stack.
     #[default]
     Both,
 }
@@ -33,24 +34,42 @@ pub enum SpacerAxis {
 /// // With minimum length (axis-specific)
 /// HStack::new()
 ///     .child(Text::new("Left"))
-///     .child(Spacer::horizontal().min_length(50.0))
+///     .child(Spacer::new().min_length(50.0))
 ///     .child(Text::new("Right"))
+///
+/// // Exactly 16pt, never growing
+/// HStack::new()
+///     .child(icon)
+///     .child(Spacer::fixed(16.0))
+///     .child(Text::new("Title"))
 /// ```
 #[derive(IntoElement)]
 pub struct Spacer {
     min_length: Option<f32>,
     axis: SpacerAxis,
+    fixed: bool,
 }
 
 impl Spacer {
     /// Create a new spacer that expands to fill available space.
     ///
-    /// By default, the spacer expands along both axes. Use `horizontal()` or
-    /// `vertical()` for axis-specific behavior when using `min_length()`.
+    /// A minimum length set with `min_length()` applies along the enclosing
+    /// stack's axis.
     pub fn new() -> Self {
         Self {
             min_length: None,
             axis: SpacerAxis::Both,
+            fixed: false,
+        }
+    }
+
+    /// Create a spacer of exactly `length` along the stack's axis that
+    /// doesn't grow or shrink.
+    pub fn fixed(length: f32) -> Self {
+        Self {
+            min_length: Some(length),
+            axis: SpacerAxis::Both,
+            fixed: true,
         }
     }
 
@@ -61,6 +80,7 @@ impl Spacer {
         Self {
             min_length: None,
             axis: SpacerAxis::Horizontal,
+            fixed: false,
         }
     }
 
@@ -71,13 +91,15 @@ impl Spacer {
         Self {
             min_length: None,
             axis: SpacerAxis::Vertical,
+            fixed: false,
         }
     }
 
-    /// Set a minimum length for the spacer.
+    /// Set a minimum length for the spacer, kept even when the stack is too
+    /// small for its children.
     ///
     /// The axis this applies to depends on how the Spacer was created:
-    /// - `Spacer::new()` - applies to both width and height
+    /// - `Spacer::new()` - the enclosing stack's axis, or both outside a stack
     /// - `Spacer::horizontal()` - applies to width only
     /// - `Spacer::vertical()` - applies to height only
     pub fn min_length(mut self, length: f32) -> Self {
@@ -92,21 +114,145 @@ impl Default for Spacer {
     }
 }
 
-impl Modifier for Spacer {}
+impl Modifier for Spacer {
+    fn expansion(&self) -> Expansion {
+        Expansion {
+            grows: !self.fixed,
+            ..Expansion::default()
+        }
+    }
+}
 
 impl RenderOnce for Spacer {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
-        let mut spacer = div().flex_grow();
-
-        // Apply minimum size if specified, based on axis
-        if let Some(min) = self.min_length {
-            spacer = match self.axis {
-                SpacerAxis::Horizontal => spacer.min_w(px(min)),
-                SpacerAxis::Vertical => spacer.min_h(px(min)),
-                SpacerAxis::Both => spacer.min_w(px(min)).min_h(px(min)),
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let axis = match self.axis {
+            SpacerAxis::Both => match environment::stack_axis(cx) {
+                Some(Axis::Horizontal) => SpacerAxis::Horizontal,
+                Some(Axis::Vertical) => SpacerAxis::Vertical,
+                None => SpacerAxis::Both,
+            },
+            axis => axis,
+        };
+
+        let Some(length) = self.min_length.map(px) else {
+            return div().flex_grow();
+        };
+        if self.fixed {
+            let spacer = div().flex_none();
+            return match axis {
+                SpacerAxis::Horizontal => spacer.w(length),
+                SpacerAxis::Vertical => spacer.h(length),
+                SpacerAxis::Both => spacer.size(length),
             };
         }
 
-        spacer
+        let spacer = div().flex_grow();
+        match axis {
+            SpacerAxis::Horizontal => spacer.min_w(length),
+            SpacerAxis::Vertical => spacer.min_h(length),
+            SpacerAxis::Both => spacer.min_w(length).min_h(length),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use gpui::{
+        canvas, div, point, size, AnyElement, AvailableSpace, Bounds, ParentElement, Pixels,
+        TestAppContext,
+    };
+
+    use super::*;
+    use crate::layout::{HStack, VStack};
+
+    type Recorded = Rc<Cell<Option<Bounds<Pixels>>>>;
+
+    /// A box of `width` by `height` that records where it is laid out.
+    fn marker(width: f32, height: f32, recorded: &Recorded) -> impl IntoElement {
+        let recorded = recorded.clone();
+        canvas(
+            move |bounds, _, _| recorded.set(Some(bounds)),
+            |_, _, _, _| {},
+        )
+        .w(px(width))
+        .h(px(height))
+    }
+
+    /// Lay out `view` in a 300pt by 300pt window, built inside the draw since
+    /// stacks allocate their children in the frame's element arena.
+    fn draw(view: impl FnOnce() -> AnyElement) {
+        let mut cx = TestAppContext::single();
+        let cx = cx.add_empty_window();
+        cx.draw(
+            point(px(0.), px(0.)),
+            size(
+                AvailableSpace::Definite(px(300.)),
+                AvailableSpace::Definite(px(300.)),
+            ),
+            |_, _| view(),
+        );
+    }
+
+    #[test]
+    fn min_length_survives_a_stack_too_narrow_for_its_children() {
+        let (left, right) = (Recorded::default(), Recorded::default());
+        draw(|| {
+            div()
+                .w(px(100.))
+                .child(
+                    HStack::new()
+                        .spacing(0.0)
+                        .child(marker(80., 10., &left))
+                        .child(Spacer::new().min_length(20.0))
+                        .child(marker(80., 10., &right)),
+                )
+                .into_any_element()
+        });
+
+        let (left, right) = (left.get().unwrap(), right.get().unwrap());
+        assert!(right.left() - left.right() >= px(20.));
+    }
+
+    #[test]
+    fn min_length_follows_the_stack_axis() {
+        let below = Recorded::default();
+        draw(|| {
+            VStack::new()
+                .spacing(0.0)
+                .alignment(crate::alignment::HorizontalAlignment::Leading)
+                .child(
+                    HStack::new()
+                        .spacing(0.0)
+                        .child(div().w(px(10.)).h(px(10.)))
+                        .child(Spacer::new().min_length(50.0)),
+                )
+                .child(marker(1., 1., &below))
+                .into_any_element()
+        });
+
+        // The spacer widens the row without making it taller
+        assert_eq!(below.get().unwrap().top(), px(10.));
+    }
+
+    #[test]
+    fn fixed_spacer_does_not_grow() {
+        let after = Recorded::default();
+        draw(|| {
+            div()
+                .w(px(300.))
+                .child(
+                    HStack::new()
+                        .spacing(0.0)
+                        .child(Spacer::fixed(16.0))
+                        .child(marker(1., 1., &after)),
+                )
+                .into_any_element()
+        });
+
+        assert_eq!(after.get().unwrap().left(), px(16.));
+        assert!(!Spacer::fixed(16.0).expansion().grows);
     }
 }
@@ -0,0 +1,81 @@
+//! How stacks space their children along their axis.
+
+use gpui::{div, px, AnyElement, Axis, IntoElement, JustifyContent, Styled};
+
+/// How a stack spreads its children along its axis when it's longer than
+/// they need, like `UIStackView.Distribution` or CSS `justify-content`.
+///
+/// The stack's spacing stays the least space between children.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Distribution {
+    /// Children are packed at the start, leaving the free space after them
+    /// for `Spacer`s and filling children to take.
+    #[default]
+    Fill,
+    /// Free space is split equally between the children and before the
+    /// first and after the last.
+    EqualSpacing,
+    /// Free space is split equally between the children, with the first
+    /// and last at the edges.
+    SpaceBetween,
+    /// Each child gets an equal share of space on both sides, so the
+    /// edges get half as much as the gaps between children.
+    SpaceAround,
+}
+
+impl Distribution {
+    /// Apply as main-axis `justify-content`.
+    pub(crate) fn apply<S: Styled>(self, mut styled: S) -> S {
+        match self {
+            Self::Fill => styled.justify_start(),
+            // GPUI has no shorthand for this one
+            Self::EqualSpacing => {
+                styled.style().justify_content = Some(JustifyContent::SpaceEvenly);
+                styled
+            }
+            Self::SpaceBetween => styled.justify_between(),
+            Self::SpaceAround => styled.justify_around(),
+        }
+    }
+}
+
+/// Spacing after particular children, overriding a stack's spacing, set
+/// with `.spacing_between()`.
+#[derive(Default)]
+pub(crate) struct SpacingOverrides(Vec<(usize, f32)>);
+
+impl SpacingOverrides {
+    /// Use `spacing` after the child at `index`, replacing any earlier
+    /// override for it.
+    pub(crate) fn set(&mut self, index: usize, spacing: f32) {
+        self.0.retain(|(other, _)| *other != index);
+        self.0.push((index, spacing));
+    }
+
+    /// Insert an element after each overridden child that moves the next
+    /// child to the overriding spacing, given the stack's own `spacing`.
+    ///
+    /// The element takes no space but a margin; the stack's gaps on either
+    /// side of it are taken off that margin, so it may be negative.
+    pub(crate) fn apply(
+        mut self,
+        mut children: Vec<AnyElement>,
+        spacing: f32,
+        axis: Axis,
+    ) -> Vec<AnyElement> {
+        // From the last, so inserting doesn't move the children still to go
+        self.0.sort_by(|(a, _), (b, _)| b.cmp(a));
+        for (index, between) in self.0 {
+            if index + 1 >= children.len() {
+                continue;
+            }
+            let margin = px(between - 2.0 * spacing);
+            let adjuster = match axis {
+                Axis::Horizontal => div().flex_none().ml(margin),
+                Axis::Vertical => div().flex_none().mt(margin),
+            };
+            children.insert(index + 1, adjuster.into_any_element());
+        }
+        children
+    }
+}
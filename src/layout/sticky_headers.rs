@@ -0,0 +1,149 @@
+//! Section headers that stay pinned while their rows scroll.
+//!
+//! Sections record where they and their headers are laid out, relative to
+//! the start of the list's content. While a section's header has scrolled
+//! past the top edge but its rows haven't, the list draws a copy of the
+//! header pinned there, pushed up by the next section as it arrives.
+
+use gpui::{canvas, App, Bounds, Div, Entity, ParentElement, Pixels, ScrollHandle, Styled, Window};
+
+/// Where a section is laid out, in points from the start of the content.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct SectionExtent {
+    top: f32,
+    bottom: f32,
+    /// Zero for a section without a header.
+    header_height: f32,
+}
+
+/// The extents of a list's sections, recorded as they prepaint.
+#[derive(Debug, Default)]
+pub(crate) struct StickyHeaders {
+    extents: Vec<SectionExtent>,
+}
+
+/// What a section needs to record its extent.
+#[derive(Clone)]
+pub(crate) struct SectionTracking {
+    pub(crate) state: Entity<StickyHeaders>,
+    pub(crate) index: usize,
+    pub(crate) handle: ScrollHandle,
+}
+
+impl SectionTracking {
+    /// Record the bounds of the whole section, laid out as `section`.
+    pub(crate) fn section(&self, section: Div) -> Div {
+        self.record(section, |extent, bounds| {
+            extent.top = f32::from(bounds.top());
+            extent.bottom = f32::from(bounds.bottom());
+        })
+    }
+
+    /// Record the height of the section's header, laid out as `header`.
+    pub(crate) fn header(&self, header: Div) -> Div {
+        self.record(header, |extent, bounds| {
+            extent.header_height = f32::from(bounds.size.height);
+        })
+    }
+
+    fn record(&self, element: Div, update: fn(&mut SectionExtent, Bounds<Pixels>)) -> Div {
+        let tracking = self.clone();
+        element.relative().child(
+            canvas(
+                move |bounds, _, cx| {
+                    let handle = &tracking.handle;
+                    let origin = bounds.origin - handle.bounds().origin - handle.offset();
+                    let bounds = Bounds::new(origin, bounds.size);
+                    tracking.state.update(cx, |state, _| {
+                        if let Some(extent) = state.extents.get_mut(tracking.index) {
+                            update(extent, bounds);
+                        }
+                    });
+                },
+                |_, _, _, _| {},
+            )
+            .absolute()
+            .size_full(),
+        )
+    }
+}
+
+/// The sticky header state of the list `id`, sized for `section_count`
+/// sections.
+pub(crate) fn sticky_headers(
+    id: impl Into<gpui::ElementId>,
+    section_count: usize,
+    window: &mut Window,
+    cx: &mut App,
+) -> Entity<StickyHeaders> {
+    let state = window.use_keyed_state(id, cx, |_, _| StickyHeaders::default());
+    state.update(cx, |state, _| {
+        state
+            .extents
+            .resize(section_count, SectionExtent::default())
+    });
+    state
+}
+
+/// The section whose header is pinned with the content scrolled by
+/// `handle`, and how far up the next section pushes it.
+pub(crate) fn pinned_section(
+    state: &Entity<StickyHeaders>,
+    handle: &ScrollHandle,
+    cx: &App,
+) -> Option<(usize, f32)> {
+    let scroll_top = -f32::from(handle.offset().y);
+    pinned_header(&state.read(cx).extents, scroll_top)
+}
+
+/// The section whose header pins to the top edge with the content scrolled
+/// `scroll_top` points, and how far up it is pushed.
+fn pinned_header(extents: &[SectionExtent], scroll_top: f32) -> Option<(usize, f32)> {
+    // The last section starting above the edge, skipping any not laid out yet
+    let ix = extents
+        .iter()
+        .rposition(|extent| extent.bottom > extent.top && extent.top < scroll_top)?;
+    let current = extents[ix];
+    if current.header_height <= 0.0 || scroll_top >= current.bottom {
+        return None;
+    }
+    let limit = extents
+        .get(ix + 1)
+        .filter(|next| next.bottom > next.top)
+        .map_or(current.bottom, |next| next.top);
+    let push = (limit - scroll_top - current.header_height).min(0.0);
+    Some((ix, push))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn extent(top: f32, bottom: f32) -> SectionExtent {
+        SectionExtent {
+            top,
+            bottom,
+            header_height: 20.0,
+        }
+    }
+
+    #[test]
+    fn header_pins_once_scrolled_past() {
+        let extents = [extent(0.0, 200.0), extent(210.0, 400.0)];
+        assert_eq!(pinned_header(&extents, 0.0), None);
+        assert_eq!(pinned_header(&extents, 50.0), Some((0, 0.0)));
+        assert_eq!(pinned_header(&extents, 250.0), Some((1, 0.0)));
+        assert_eq!(pinned_header(&extents, 400.0), None);
+    }
+
+    #[test]
+    fn next_section_pushes_the_header_up() {
+        let extents = [extent(0.0, 200.0), extent(210.0, 400.0)];
+        // The next section starts 15 points below the edge
+        assert_eq!(pinned_header(&extents, 195.0), Some((0, -5.0)));
+
+        let mut without_header = extents;
+        without_header[0].header_height = 0.0;
+        assert_eq!(pinned_header(&without_header, 50.0), None);
+    }
+}
@@ -0,0 +1,442 @@
+//! Swipe actions for list rows.
+//!
+//! A row with actions can be dragged left, or swiped left on a trackpad,
+//! to reveal its action buttons at the trailing edge. Letting go past half
+//! of the buttons leaves them open; otherwise the row slides back. Rows also
+//! show small versions of the buttons on hover, for mice that can't swipe.
+
+use std::rc::Rc;
+
+use gpui::{
+    canvas, div, px, AnyElement, App, AppContext, Context, DragMoveEvent, ElementId, EmptyView,
+    Entity, EntityId, InteractiveElement, IntoElement, MouseButton, ParentElement, RenderOnce,
+    ScrollWheelEvent, SharedString, StatefulInteractiveElement, Styled, TouchPhase, Window,
+};
+
+use crate::components::{ButtonRole, Label, LabelStyle, Text};
+use crate::style::{Color, Font};
+
+use super::refreshable::RELEASE_DELAY;
+
+/// The width of each revealed action button, in points.
+const ACTION_WIDTH: f32 = 74.0;
+
+/// How far across the row a full swipe must go, as a fraction of its width.
+const FULL_SWIPE_FRACTION: f32 = 0.6;
+
+/// Called when the user picks a row action.
+type ActionHandler = Rc<dyn Fn(&mut Window, &mut App)>;
+
+/// A button revealed by swiping a list row, like SwiftUI's `swipeActions`.
+///
+/// Add them to a row with `Section::row_with_actions`. The first action
+/// sits at the trailing edge, and is the one a full swipe triggers.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// Section::new().row_with_actions(
+///     Text::new(subject),
+///     vec![
+///         RowAction::new("Delete", move |_, cx| delete(id, cx))
+///             .system_image("trash")
+///             .role(ButtonRole::Destructive),
+///         RowAction::new("Archive", move |_, cx| archive(id, cx))
+///             .system_image("archivebox")
+///             .tint(Color::indigo()),
+///     ],
+/// )
+/// ```
+#[derive(Clone)]
+pub struct RowAction {
+    label: SharedString,
+    system_image: Option<SharedString>,
+    tint: Option<Color>,
+    role: Option<ButtonRole>,
+    handler: ActionHandler,
+}
+
+impl RowAction {
+    /// Create an action that calls `handler` when picked.
+    pub fn new(
+        label: impl Into<SharedString>,
+        handler: impl Fn(&mut Window, &mut App) + 'static,
+    ) -> Self {
+        Self {
+            label: label.into(),
+            system_image: None,
+            tint: None,
+            role: None,
+            handler: Rc::new(handler),
+        }
+    }
+
+    /// Show a system image next to the label.
+    pub fn system_image(mut self, name: impl Into<SharedString>) -> Self {
+        self.system_image = Some(name.into());
+        self
+    }
+
+    /// Set the button color (default gray, or red for destructive actions).
+    pub fn tint(mut self, color: impl Into<Color>) -> Self {
+        self.tint = Some(color.into());
+        self
+    }
+
+    /// Set the semantic role of the action.
+    pub fn role(mut self, role: ButtonRole) -> Self {
+        self.role = Some(role);
+        self
+    }
+
+    fn resolved_tint(&self) -> Color {
+        self.tint.clone().unwrap_or(match self.role {
+            Some(ButtonRole::Destructive) => Color::red(),
+            _ => Color::gray(),
+        })
+    }
+
+    /// The icon and label in white, or just the icon when `compact`.
+    fn content(&self, compact: bool) -> AnyElement {
+        match &self.system_image {
+            Some(name) => Label::new(name.clone(), self.label.clone())
+                .label_style(if compact {
+                    LabelStyle::IconOnly
+                } else {
+                    LabelStyle::TitleAndIcon
+                })
+                .foreground_color(Color::white())
+                .into_any_element(),
+            None => Text::new(self.label.clone())
+                .font(if compact {
+                    Font::caption()
+                } else {
+                    Font::subheadline()
+                })
+                .foreground_color(Color::white())
+                .into_any_element(),
+        }
+    }
+}
+
+/// How far a row is swiped open, kept across frames.
+#[derive(Debug, Default)]
+struct SwipeState {
+    /// How far the content is moved left, in points.
+    offset: f32,
+    /// The pointer x and offset when the mouse went down on the row.
+    drag_start: Option<(f32, f32)>,
+    /// The width of the row when last drawn.
+    width: f32,
+    hovered: bool,
+    /// Bumped by every trackpad swipe, so only the last one's timer fires.
+    generation: usize,
+}
+
+impl SwipeState {
+    /// Move the content to `offset`, as far as the actions allow.
+    fn swipe_to(&mut self, offset: f32, actions_width: f32, full_swipe: bool) {
+        let limit = if full_swipe {
+            self.width.max(actions_width)
+        } else {
+            actions_width
+        };
+        self.offset = offset.clamp(0.0, limit);
+        self.generation = self.generation.wrapping_add(1);
+    }
+
+    /// Whether letting go now would trigger the first action.
+    fn is_armed(&self, actions_width: f32, full_swipe: bool) -> bool {
+        full_swipe && self.offset > actions_width && self.offset >= self.width * FULL_SWIPE_FRACTION
+    }
+
+    /// Let go, leaving the actions open or closed, and return whether that
+    /// triggers the first action.
+    fn release(&mut self, actions_width: f32, full_swipe: bool) -> bool {
+        let trigger = self.is_armed(actions_width, full_swipe);
+        self.offset = if !trigger && self.offset >= actions_width / 2.0 {
+            actions_width
+        } else {
+            0.0
+        };
+        self.drag_start = None;
+        trigger
+    }
+}
+
+/// The value carried by a row being dragged open.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct RowSwipe(EntityId);
+
+/// A list row with actions revealed by swiping it.
+#[derive(IntoElement)]
+pub(crate) struct SwipeRow {
+    id: ElementId,
+    content: AnyElement,
+    actions: Rc<[RowAction]>,
+    allows_full_swipe: bool,
+}
+
+impl SwipeRow {
+    pub(crate) fn new(
+        id: impl Into<ElementId>,
+        content: AnyElement,
+        actions: Vec<RowAction>,
+        allows_full_swipe: bool,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            content,
+            actions: actions.into(),
+            allows_full_swipe,
+        }
+    }
+}
+
+impl RenderOnce for SwipeRow {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let state = window.use_keyed_state(self.id.clone(), cx, |_, _| SwipeState::default());
+        let actions = self.actions;
+        let full_swipe = self.allows_full_swipe;
+        let actions_width = ACTION_WIDTH * actions.len() as f32;
+        let (offset, hovered, armed) = {
+            let state = state.read(cx);
+            let armed = state.is_armed(actions_width, full_swipe);
+            (state.offset, state.hovered, armed)
+        };
+        let drag = RowSwipe(state.entity_id());
+
+        let mut row = div()
+            .id(self.id)
+            .relative()
+            .overflow_hidden()
+            .child(
+                canvas(
+                    {
+                        let state = state.clone();
+                        move |bounds, _, cx| {
+                            let width = f32::from(bounds.size.width);
+                            state.update(cx, |state, _| state.width = width);
+                        }
+                    },
+                    |_, _, _, _| {},
+                )
+                .absolute()
+                .size_full(),
+            )
+            .child(div().relative().left(px(-offset)).child(self.content));
+
+        if offset > 0.0 {
+            // The first action sits at the trailing edge, and takes over
+            // the revealed space once a full swipe would trigger it
+            let shown = if armed { &actions[..1] } else { &actions[..] };
+            row = row.child(
+                div()
+                    .absolute()
+                    .top_0()
+                    .bottom_0()
+                    .right_0()
+                    .w(px(offset))
+                    .flex()
+                    .flex_row()
+                    .children(shown.iter().enumerate().rev().map(|(ix, action)| {
+                        action_button(ix, action, &state, cx)
+                            .flex_1()
+                            .justify_center()
+                            .overflow_hidden()
+                            .child(action.content(false))
+                    })),
+            );
+        } else if hovered {
+            row = row.child(
+                div()
+                    .absolute()
+                    .top_0()
+                    .bottom_0()
+                    .right_0()
+                    .flex()
+                    .flex_row()
+                    .items_center()
+                    .gap(px(4.0))
+                    .pr(px(8.0))
+                    .children(actions.iter().enumerate().rev().map(|(ix, action)| {
+                        action_button(ix, action, &state, cx)
+                            .px(px(8.0))
+                            .py(px(2.0))
+                            .rounded(px(6.0))
+                            .child(action.content(true))
+                    })),
+            );
+        }
+
+        row.on_hover({
+            let state = state.clone();
+            move |hovered, _, cx| {
+                state.update(cx, |state, cx| {
+                    state.hovered = *hovered;
+                    cx.notify();
+                });
+            }
+        })
+        .on_mouse_down(MouseButton::Left, {
+            let state = state.clone();
+            move |event, _, cx| {
+                let x = f32::from(event.position.x);
+                state.update(cx, |state, _| state.drag_start = Some((x, state.offset)));
+            }
+        })
+        .on_drag(drag, |_, _, _, cx| cx.new(|_| EmptyView))
+        .on_drag_move({
+            let state = state.clone();
+            move |event: &DragMoveEvent<RowSwipe>, _, cx| {
+                // Every swipe row sees every row swipe; only follow our own
+                if *event.drag(cx) != drag {
+                    return;
+                }
+                let x = f32::from(event.event.position.x);
+                state.update(cx, |state, cx| {
+                    if let Some((start_x, start_offset)) = state.drag_start {
+                        state.swipe_to(start_offset + start_x - x, actions_width, full_swipe);
+                        cx.notify();
+                    }
+                });
+            }
+        })
+        .on_mouse_up(MouseButton::Left, {
+            let (state, actions) = (state.clone(), actions.clone());
+            move |_, window, cx| release(&state, &actions, full_swipe, window, cx)
+        })
+        .on_mouse_up_out(MouseButton::Left, {
+            let (state, actions) = (state.clone(), actions.clone());
+            move |_, window, cx| {
+                // A click elsewhere closes an open row
+                let swiped = state.read(cx);
+                if swiped.drag_start.is_some() || swiped.offset > 0.0 {
+                    release(&state, &actions, full_swipe, window, cx);
+                }
+            }
+        })
+        .on_scroll_wheel(move |event: &ScrollWheelEvent, window, cx| {
+            let delta = event.delta.pixel_delta(window.line_height());
+            if delta.x.abs() <= delta.y.abs() {
+                return;
+            }
+            cx.stop_propagation();
+            let generation = state.update(cx, |state, cx| {
+                let offset = state.offset - f32::from(delta.x);
+                state.swipe_to(offset, actions_width, full_swipe);
+                cx.notify();
+                state.generation
+            });
+
+            if matches!(event.touch_phase, TouchPhase::Ended) {
+                release(&state, &actions, full_swipe, window, cx);
+                return;
+            }
+            let (state, actions) = (state.clone(), actions.clone());
+            window
+                .spawn(cx, async move |cx| {
+                    cx.background_executor().timer(RELEASE_DELAY).await;
+                    cx.update(|window, cx| {
+                        // Still swiping
+                        if state.read(cx).generation == generation {
+                            release(&state, &actions, full_swipe, window, cx);
+                        }
+                    })
+                    .ok();
+                })
+                .detach();
+        })
+    }
+}
+
+/// A button that closes the row and calls the action at `ix`.
+fn action_button(
+    ix: usize,
+    action: &RowAction,
+    state: &Entity<SwipeState>,
+    cx: &App,
+) -> gpui::Stateful<gpui::Div> {
+    let (state, handler) = (state.clone(), action.handler.clone());
+    div()
+        .id(("row-action", ix))
+        .flex()
+        .items_center()
+        .cursor_pointer()
+        .bg(action.resolved_tint().resolve_in(cx))
+        .on_click(move |_, window, cx| {
+            // The row's own click handlers don't see it
+            cx.stop_propagation();
+            close(&state, cx);
+            handler(window, cx);
+        })
+}
+
+/// Slide the row back closed.
+fn close(state: &Entity<SwipeState>, cx: &mut App) {
+    state.update(cx, |state, cx| {
+        state.offset = 0.0;
+        state.drag_start = None;
+        cx.notify();
+    });
+}
+
+/// Let go of a swipe, triggering the first action on a full swipe.
+fn release(
+    state: &Entity<SwipeState>,
+    actions: &[RowAction],
+    full_swipe: bool,
+    window: &mut Window,
+    cx: &mut App,
+) {
+    let actions_width = ACTION_WIDTH * actions.len() as f32;
+    let trigger = state.update(cx, |state, cx: &mut Context<SwipeState>| {
+        cx.notify();
+        state.release(actions_width, full_swipe)
+    });
+    if let Some(action) = actions.first().filter(|_| trigger) {
+        (action.handler)(window, cx);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn swiped(offset: f32) -> SwipeState {
+        let mut state = SwipeState {
+            width: 300.0,
+            ..SwipeState::default()
+        };
+        state.swipe_to(offset, 148.0, true);
+        state
+    }
+
+    #[test]
+    fn release_opens_past_half_of_the_actions() {
+        let mut state = swiped(60.0);
+        assert!(!state.release(148.0, true));
+        assert_eq!(state.offset, 0.0);
+
+        let mut state = swiped(90.0);
+        assert!(!state.release(148.0, true));
+        assert_eq!(state.offset, 148.0);
+    }
+
+    #[test]
+    fn full_swipe_triggers_only_when_allowed() {
+        let mut state = swiped(250.0);
+        assert!(state.is_armed(148.0, true));
+        assert!(state.release(148.0, true));
+        assert_eq!(state.offset, 0.0);
+
+        // Without full swipe, the row stops at the actions
+        let mut state = SwipeState {
+            width: 300.0,
+            ..SwipeState::default()
+        };
+        state.swipe_to(250.0, 148.0, false);
+        assert_eq!(state.offset, 148.0);
+        assert!(!state.release(148.0, false));
+    }
+}
@@ -0,0 +1,214 @@
+//! Toolbar - A row of leading, centered and trailing items.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use gpui::{
+    div, px, AnyElement, App, Div, FontWeight, IntoElement, ParentElement, RenderOnce, Styled,
+    Window,
+};
+
+use crate::environment;
+use crate::modifier::{Expansion, Modifier};
+use crate::style::Color;
+
+/// The height of a window toolbar, in points.
+const TOOLBAR_HEIGHT: f32 = 38.0;
+
+/// The space between items in a group, in points.
+const ITEM_SPACING: f32 = 8.0;
+
+/// How far the principal item keeps from the bar's edges, in points.
+const PRINCIPAL_INSET: f32 = 128.0;
+
+/// Where a [`NavigationStack`](super::NavigationStack) collects the toolbar
+/// its showing screen declares with `.toolbar()`.
+pub(crate) type ToolbarSlot = Rc<RefCell<Option<Toolbar>>>;
+
+/// A row of items across the top of a window or screen, like SwiftUI's
+/// `toolbar`.
+///
+/// Items sit in a leading group, a trailing group, and an optional
+/// principal view centered across the bar. Add items one at a time, so
+/// buttons and menus can be mixed in a group.
+///
+/// Used on its own, a toolbar draws a 38pt row with a hairline under it.
+/// Passed to `.toolbar()` on a screen of a `NavigationStack`, its items are
+/// merged into the stack's navigation bar instead.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// Toolbar::new()
+///     .leading(Button::new("Back", || {}))
+///     .principal(Text::new("Inbox").font_weight(FontWeight::SEMIBOLD))
+///     .trailing(Menu::new("Sort").item(MenuItem::new("Date", || {})))
+///     .trailing(Button::new("Compose", || {}))
+/// ```
+#[derive(IntoElement, Default)]
+pub struct Toolbar {
+    leading: Vec<AnyElement>,
+    principal: Option<AnyElement>,
+    trailing: Vec<AnyElement>,
+}
+
+impl Toolbar {
+    /// Create an empty toolbar.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add an item after the leading group's other items.
+    pub fn leading(mut self, item: impl IntoElement) -> Self {
+        self.leading.push(item.into_any_element());
+        self
+    }
+
+    /// Set the view centered across the toolbar, such as a title.
+    pub fn principal(mut self, view: impl IntoElement) -> Self {
+        self.principal = Some(view.into_any_element());
+        self
+    }
+
+    /// Add an item after the trailing group's other items.
+    pub fn trailing(mut self, item: impl IntoElement) -> Self {
+        self.trailing.push(item.into_any_element());
+        self
+    }
+
+    /// Put this toolbar's items after `base`'s, its principal view replacing
+    /// `base`'s if it has one.
+    pub(crate) fn merged_into(self, mut base: Toolbar) -> Toolbar {
+        base.leading.extend(self.leading);
+        base.principal = self.principal.or(base.principal);
+        base.trailing.extend(self.trailing);
+        base
+    }
+
+    /// Draw the toolbar as a bar `height` points tall.
+    pub(crate) fn bar(self, height: f32, cx: &App) -> Div {
+        let group = || {
+            div()
+                .flex()
+                .flex_row()
+                .flex_shrink_0()
+                .items_center()
+                .gap(px(ITEM_SPACING))
+        };
+
+        div()
+            .relative()
+            .flex()
+            .flex_row()
+            .flex_shrink_0()
+            .items_center()
+            .h(px(height))
+            .px(px(ITEM_SPACING))
+            .border_b_1()
+            .border_color(Color::separator().resolve_in(cx))
+            .child(
+                // Centered across the bar, whatever the groups' widths
+                div()
+                    .absolute()
+                    .inset_0()
+                    .flex()
+                    .items_center()
+                    .justify_center()
+                    .px(px(PRINCIPAL_INSET))
+                    .children(self.principal.map(|principal| {
+                        div()
+                            .truncate()
+                            .font_weight(FontWeight::SEMIBOLD)
+                            .child(principal)
+                    })),
+            )
+            .child(group().children(self.leading))
+            .child(div().flex_1())
+            .child(group().children(self.trailing))
+    }
+}
+
+impl Modifier for Toolbar {}
+
+impl RenderOnce for Toolbar {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        self.bar(TOOLBAR_HEIGHT, cx)
+    }
+}
+
+/// A view that declares toolbar items, created by [`Modifier::toolbar`].
+pub struct ToolbarContent<V> {
+    pub(crate) child: V,
+    pub(crate) child_expansion: Expansion,
+    pub(crate) toolbar: Toolbar,
+}
+
+impl<V> Modifier for ToolbarContent<V> {
+    fn expansion(&self) -> Expansion {
+        self.child_expansion
+    }
+}
+
+impl<V: IntoElement + 'static> IntoElement for ToolbarContent<V> {
+    type Element = AnyElement;
+
+    fn into_element(self) -> Self::Element {
+        ToolbarContentElement { content: self }.into_any_element()
+    }
+}
+
+#[derive(IntoElement)]
+struct ToolbarContentElement<V: IntoElement + 'static> {
+    content: ToolbarContent<V>,
+}
+
+impl<V: IntoElement + 'static> RenderOnce for ToolbarContentElement<V> {
+    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let ToolbarContent {
+            child,
+            child_expansion: expansion,
+            toolbar,
+        } = self.content;
+
+        // Inside a navigation stack, its bar shows the items
+        if let Some(slot) = environment::toolbar_slot(cx) {
+            *slot.borrow_mut() = Some(toolbar);
+            let mut container = div().child(child);
+            if expansion.grows {
+                container = container.flex_grow();
+            }
+            if expansion.fills_width {
+                container = container.w_full();
+            }
+            if expansion.fills_height {
+                container = container.h_full();
+            }
+            return container;
+        }
+
+        div()
+            .flex()
+            .flex_col()
+            .size_full()
+            .child(toolbar)
+            .child(div().flex_1().min_h_0().child(child))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn declared_items_follow_the_bar_items() {
+        let bar = Toolbar::new().leading(div()).principal("Inbox");
+        let merged = Toolbar::new()
+            .leading(div())
+            .trailing(div())
+            .trailing(div())
+            .merged_into(bar);
+        assert_eq!(merged.leading.len(), 2);
+        assert!(merged.principal.is_some());
+        assert_eq!(merged.trailing.len(), 2);
+    }
+}
@@ -1,8 +1,10 @@
 //! VStack - Vertical stack layout.
 
-use gpui::{div, px, App, IntoElement, ParentElement, RenderOnce, Styled, Window};
+use gpui::{div, px, App, Axis, IntoElement, ParentElement, RenderOnce, Styled, Window};
 
 use crate::alignment::HorizontalAlignment;
+use crate::environment::EnvironmentScope;
+use crate::layout::stack_spacing::{Distribution, SpacingOverrides};
 use crate::modifier::Modifier;
 
 /// A view that arranges its children in a vertical line.
@@ -21,21 +23,28 @@ use crate::modifier::Modifier;
 #[derive(IntoElement)]
 pub struct VStack {
     spacing: f32,
+    spacing_overrides: SpacingOverrides,
     alignment: HorizontalAlignment,
+    distribution: Distribution,
     children: Vec<gpui::AnyElement>,
 }
 
 impl VStack {
     /// Create a new vertical stack.
+    ///
+    /// Children are 8 points apart by default, like SwiftUI's standard
+    /// spacing. Use `.spacing(0.0)` for children that touch.
     pub fn new() -> Self {
         Self {
             spacing: 8.0,
+            spacing_overrides: SpacingOverrides::default(),
             alignment: HorizontalAlignment::Center, // SwiftUI default
+            distribution: Distribution::default(),
             children: Vec::new(),
         }
     }
 
-    /// Set the spacing between children.
+    /// Set the spacing between children in points (default 8).
     pub fn spacing(mut self, spacing: f32) -> Self {
         self.spacing = spacing;
         self
@@ -47,6 +56,24 @@ impl VStack {
         self
     }
 
+    /// Set the spacing after the child at `index`, in points, instead of
+    /// the stack's spacing, such as to set a destructive action apart from
+    /// the rest of a toolbar.
+    ///
+    /// Under a distribution other than [`Distribution::Fill`], the
+    /// overridden gap also takes an extra share of the free space.
+    pub fn spacing_between(mut self, index: usize, spacing: f32) -> Self {
+        self.spacing_overrides.set(index, spacing);
+        self
+    }
+
+    /// Set how children are spread out when the stack is longer than they
+    /// need (default [`Distribution::Fill`]).
+    pub fn distribution(mut self, distribution: Distribution) -> Self {
+        self.distribution = distribution;
+        self
+    }
+
     impl_child_methods!();
 }
 
@@ -61,8 +88,93 @@ impl Modifier for VStack {}
 impl RenderOnce for VStack {
     fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
         let container = div().flex().flex_col().gap(px(self.spacing));
-        self.alignment
-            .apply_as_items(container)
-            .children(self.children)
+        let children = self
+            .spacing_overrides
+            .apply(self.children, self.spacing, Axis::Vertical);
+        let stack = self
+            .distribution
+            .apply(self.alignment.apply_as_items(container))
+            .children(children);
+        // Spacers inside apply their minimum length to the height
+        EnvironmentScope::new(stack).stack_axis(Axis::Vertical)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use gpui::{canvas, point, size, AvailableSpace, Bounds, Pixels, TestAppContext};
+
+    use super::*;
+
+    type Recorded = Rc<Cell<Option<Bounds<Pixels>>>>;
+
+    /// A 10pt square that records where it is laid out.
+    fn marker(recorded: &Recorded) -> impl IntoElement {
+        let recorded = recorded.clone();
+        canvas(
+            move |bounds, _, _| recorded.set(Some(bounds)),
+            |_, _, _, _| {},
+        )
+        .size(px(10.))
+    }
+
+    #[test]
+    fn children_default_to_standard_spacing() {
+        let (first, second) = (Recorded::default(), Recorded::default());
+        let mut cx = TestAppContext::single();
+        let cx = cx.add_empty_window();
+        cx.draw(
+            point(px(0.), px(0.)),
+            size(
+                AvailableSpace::Definite(px(300.)),
+                AvailableSpace::Definite(px(300.)),
+            ),
+            |_, _| {
+                VStack::new()
+                    .child(marker(&first))
+                    .child(marker(&second))
+                    .into_any_element()
+            },
+        );
+
+        let (first, second) = (first.get().unwrap(), second.get().unwrap());
+        assert_eq!(second.top() - first.bottom(), px(8.));
+    }
+
+    #[test]
+    fn equal_spacing_spreads_children_evenly() {
+        let recorded: [Recorded; 3] = Default::default();
+        let markers = recorded.clone();
+        let mut cx = TestAppContext::single();
+        let cx = cx.add_empty_window();
+        cx.draw(
+            point(px(0.), px(0.)),
+            size(
+                AvailableSpace::Definite(px(300.)),
+                AvailableSpace::Definite(px(300.)),
+            ),
+            |_, _| {
+                // A row stretches the stack to its height
+                div()
+                    .flex()
+                    .h(px(100.))
+                    .child(
+                        VStack::new()
+                            .spacing(0.0)
+                            .distribution(Distribution::EqualSpacing)
+                            .children(markers.iter().map(marker)),
+                    )
+                    .into_any_element()
+            },
+        );
+
+        // 70pt of free space in four equal parts
+        let [first, second, third] = recorded.map(|bounds| bounds.get().unwrap());
+        assert_eq!(first.top(), px(17.5));
+        assert_eq!(second.top() - first.bottom(), px(17.5));
+        assert_eq!(third.top() - second.bottom(), px(17.5));
     }
 }
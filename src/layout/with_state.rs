@@ -0,0 +1,174 @@
+//! WithState - Local state for a piece of a view, like SwiftUI's `@State`.
+
+use gpui::{AnyElement, App, ElementId, Entity, IntoElement, RenderOnce, Window};
+
+use crate::binding::Binding;
+use crate::modifier::Modifier;
+
+/// Builds the content from the state's handle.
+type StateBuilder<T> = Box<dyn FnOnce(StateHandle<T>, &mut App) -> AnyElement + 'static>;
+
+/// Content built from a value it keeps itself, without an entity or a field
+/// on the parent view.
+///
+/// The value starts as `initial` on the first render and is kept across
+/// renders for as long as the view is shown. Changing it through the
+/// [`StateHandle`] re-renders the view. The state is keyed by where
+/// `WithState::new` is called; give each one made in a loop its own
+/// [`id`](Self::id).
+///
+/// # Example
+///
+/// ```rust,ignore
+/// WithState::new(0, |count, cx| {
+///     let shown = count.get(cx);
+///     Button::new(format!("Tapped {shown} times"), move |_, cx| {
+///         count.update(cx, |count| *count += 1);
+///     })
+/// })
+/// ```
+#[derive(IntoElement)]
+pub struct WithState<T: 'static> {
+    id: ElementId,
+    initial: T,
+    build: StateBuilder<T>,
+}
+
+impl<T: 'static> WithState<T> {
+    /// Keep a value starting at `initial`, building the content with
+    /// `build`.
+    #[track_caller]
+    pub fn new<E: IntoElement>(
+        initial: T,
+        build: impl FnOnce(StateHandle<T>, &mut App) -> E + 'static,
+    ) -> Self {
+        Self {
+            id: ElementId::CodeLocation(*std::panic::Location::caller()),
+            initial,
+            build: Box::new(move |state, cx| build(state, cx).into_any_element()),
+        }
+    }
+
+    /// Key the state by `id` instead of by where it was created.
+    pub fn id(mut self, id: impl Into<ElementId>) -> Self {
+        self.id = id.into();
+        self
+    }
+}
+
+impl<T: 'static> Modifier for WithState<T> {}
+
+impl<T: 'static> RenderOnce for WithState<T> {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let initial = self.initial;
+        let state = window.use_keyed_state(self.id, cx, |_, _| initial);
+        (self.build)(StateHandle { state }, cx)
+    }
+}
+
+/// Reads and changes the value kept by a [`WithState`].
+///
+/// Handles are cheap to clone, so move them into event handlers.
+pub struct StateHandle<T> {
+    state: Entity<T>,
+}
+
+impl<T> Clone for StateHandle<T> {
+    fn clone(&self) -> Self {
+        Self {
+            state: self.state.clone(),
+        }
+    }
+}
+
+impl<T: 'static> StateHandle<T> {
+    /// A copy of the current value.
+    pub fn get(&self, cx: &App) -> T
+    where
+        T: Clone,
+    {
+        self.state.read(cx).clone()
+    }
+
+    /// The current value.
+    pub fn read<'a>(&self, cx: &'a App) -> &'a T {
+        self.state.read(cx)
+    }
+
+    /// Replace the value, re-rendering the view.
+    pub fn set(&self, value: T, cx: &mut App) {
+        self.update(cx, |state| *state = value);
+    }
+
+    /// Change the value in place, re-rendering the view.
+    pub fn update<R>(&self, cx: &mut App, update: impl FnOnce(&mut T) -> R) -> R {
+        self.state.update(cx, |state, cx| {
+            let result = update(state);
+            cx.notify();
+            result
+        })
+    }
+
+    /// A binding to the value, for a control's `bound` constructor.
+    pub fn binding(&self) -> Binding<T>
+    where
+        T: Clone,
+    {
+        let reader = self.clone();
+        let writer = self.clone();
+        Binding::new(
+            move |cx| reader.get(cx),
+            move |value, _, cx| writer.set(value, cx),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use gpui::{div, Context, Render, TestAppContext};
+
+    use super::*;
+
+    type Seen = Rc<RefCell<Vec<i32>>>;
+    type Handle = Rc<RefCell<Option<StateHandle<i32>>>>;
+
+    struct Counter {
+        seen: Seen,
+        handle: Handle,
+    }
+
+    impl Render for Counter {
+        fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+            let seen = self.seen.clone();
+            let handle = self.handle.clone();
+            WithState::new(1, move |count, cx| {
+                seen.borrow_mut().push(count.get(cx));
+                handle.replace(Some(count));
+                div()
+            })
+        }
+    }
+
+    #[test]
+    fn changes_are_kept_and_re_render() {
+        let seen = Seen::default();
+        let handle = Handle::default();
+        let mut cx = TestAppContext::single();
+        cx.add_window(|_, _| Counter {
+            seen: seen.clone(),
+            handle: handle.clone(),
+        });
+        cx.run_until_parked();
+        assert_eq!(*seen.borrow(), [1]);
+
+        let count = handle.borrow().clone().unwrap();
+        cx.update(|cx| count.update(cx, |count| *count += 1));
+        cx.run_until_parked();
+        cx.update(|cx| count.set(count.get(cx) * 10, cx));
+        cx.run_until_parked();
+        assert_eq!(*seen.borrow(), [1, 2, 20]);
+    }
+}
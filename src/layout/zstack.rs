@@ -7,7 +7,9 @@ use crate::modifier::Modifier;
 
 /// A view that overlays its children, aligning them in both axes.
 ///
-/// By default, ZStack centers its children (matching SwiftUI).
+/// By default, ZStack centers its children (matching SwiftUI). Each child is
+/// positioned within the whole stack, so children added with
+/// [`child_aligned`](Self::child_aligned) can be pinned to different corners.
 ///
 /// # Example
 ///
@@ -16,11 +18,19 @@ use crate::modifier::Modifier;
 ///     .alignment(Alignment::bottom_trailing())
 ///     .child(Image::new("background"))
 ///     .child(Text::new("Badge"))
+///
+/// // Controls in opposite corners of a photo
+/// ZStack::new()
+///     .child(Image::new("photo"))
+///     .child_aligned(close_button, Alignment::top_trailing())
+///     .child_aligned(Text::new("Caption"), Alignment::bottom_leading())
 /// ```
 #[derive(IntoElement)]
 pub struct ZStack {
     alignment: Alignment,
     children: Vec<gpui::AnyElement>,
+    /// Per-child alignments by child index, overriding `alignment`.
+    child_alignments: Vec<Option<Alignment>>,
 }
 
 impl ZStack {
@@ -29,6 +39,7 @@ impl ZStack {
         Self {
             alignment: Alignment::center(), // SwiftUI default
             children: Vec::new(),
+            child_alignments: Vec::new(),
         }
     }
 
@@ -38,6 +49,14 @@ impl ZStack {
         self
     }
 
+    /// Add a child view with its own alignment within the stack.
+    pub fn child_aligned<E: IntoElement>(mut self, child: E, alignment: Alignment) -> Self {
+        self.child_alignments.resize(self.children.len(), None);
+        self.child_alignments.push(Some(alignment));
+        self.children.push(child.into_any_element());
+        self
+    }
+
     impl_child_methods!();
 }
 
@@ -51,29 +70,20 @@ impl Modifier for ZStack {}
 
 impl RenderOnce for ZStack {
     fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
-        // Strategy: Use relative container + absolute positioned children
-        // - Container is relative, sizes to its largest child via a "sizer" approach
-        // - First child determines size (rendered normally)
-        // - All children rendered absolutely on top with alignment
-        //
-        // For proper "size to largest", we render the first child twice:
-        // once for sizing (invisible), and all children absolutely for display.
-        //
-        // Actually simpler: just use absolute positioning with inset-0 and flex
-        // to align within. The container needs an explicit size or to get size
-        // from somewhere.
-        //
-        // The REAL issue: When ZStack is wrapped in frame_size(200, 200), the
-        // Frame modifier creates a container. The ZStack's container with
-        // size_full() should fill that. Then absolute children with inset_0
-        // fill the ZStack container, and flex aligns within.
-
+        // Each child gets a wrapper filling the stack, which aligns the child
+        // within it. The stack fills whatever frame it is given.
         let alignment = self.alignment;
-
+        let child_alignments = self.child_alignments;
         let positioned_children: Vec<_> = self
             .children
             .into_iter()
-            .map(|child| {
+            .enumerate()
+            .map(|(ix, child)| {
+                let alignment = child_alignments
+                    .get(ix)
+                    .copied()
+                    .flatten()
+                    .unwrap_or(alignment);
                 // Absolute positioning fills the container via inset_0
                 // Flex + alignment positions the child within
                 let wrapper = div().absolute().inset_0().flex();
@@ -89,3 +99,55 @@ impl RenderOnce for ZStack {
         div().relative().size_full().children(positioned_children)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use gpui::{canvas, div, point, px, size, AvailableSpace, Bounds, Pixels, TestAppContext};
+
+    use super::*;
+
+    type Recorded = Rc<Cell<Option<Bounds<Pixels>>>>;
+
+    /// A 20pt square that records where it is laid out.
+    fn marker(recorded: &Recorded) -> impl IntoElement {
+        let recorded = recorded.clone();
+        canvas(
+            move |bounds, _, _| recorded.set(Some(bounds)),
+            |_, _, _, _| {},
+        )
+        .size(px(20.))
+    }
+
+    #[test]
+    fn children_can_be_aligned_to_different_corners() {
+        let (centered, top_trailing, bottom_leading) = (
+            Recorded::default(),
+            Recorded::default(),
+            Recorded::default(),
+        );
+        let mut cx = TestAppContext::single();
+        let cx = cx.add_empty_window();
+        cx.draw(
+            point(px(0.), px(0.)),
+            size(
+                AvailableSpace::Definite(px(300.)),
+                AvailableSpace::Definite(px(300.)),
+            ),
+            |_, _| {
+                div().w(px(200.)).h(px(100.)).child(
+                    ZStack::new()
+                        .child(marker(&centered))
+                        .child_aligned(marker(&top_trailing), Alignment::top_trailing())
+                        .child_aligned(marker(&bottom_leading), Alignment::bottom_leading()),
+                )
+            },
+        );
+
+        assert_eq!(centered.get().unwrap().origin, point(px(90.), px(40.)));
+        assert_eq!(top_trailing.get().unwrap().origin, point(px(180.), px(0.)));
+        assert_eq!(bottom_leading.get().unwrap().origin, point(px(0.), px(80.)));
+    }
+}
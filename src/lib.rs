@@ -44,14 +44,39 @@
 //! ```
 
 // Core modules
+pub mod accessibility;
 pub mod alignment;
+pub mod binding;
+pub mod clipboard;
 pub mod components;
+pub mod debug;
+pub mod drag_drop;
+pub mod environment;
+pub mod focus;
 pub mod layout;
 pub mod modifier;
+pub mod presentation;
+pub mod shortcut;
 pub mod style;
+pub mod task;
+pub mod testing;
+pub mod toast;
 pub mod types;
+pub mod window;
 
 pub mod prelude;
 
 // Re-export commonly used items at crate root
+pub use accessibility::{AccessibilityNode, AccessibilityRole};
+pub use binding::Binding;
+pub use clipboard::{Clipboard, Copyable};
+pub use debug::DebugOverlay;
+pub use drag_drop::{Draggable, DropTarget};
+pub use environment::Environment;
+pub use focus::FocusContent;
 pub use modifier::{Modified, Modifier, Tappable};
+pub use presentation::{AlertButton, AlertConfig, Presented};
+pub use shortcut::KeyboardShortcut;
+pub use task::TaskContent;
+pub use toast::{Toast, ToastCenter, ToastStyle};
+pub use window::{WindowChrome, WindowConfig};
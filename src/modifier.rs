@@ -4,14 +4,35 @@
 //! corner_radius, border) are merged onto a single div for correct rendering.
 //! This is necessary because GPUI's overflow clipping doesn't respect border-radius.
 
+use std::cell::RefCell;
+use std::path::PathBuf;
+use std::rc::Rc;
+use std::time::Duration;
+
 use gpui::{
-    div, px, AnyElement, App, ClickEvent, InteractiveElement, IntoElement, ParentElement,
-    RenderOnce, SharedString, StatefulInteractiveElement, Styled, Window,
+    canvas, div, px, AnyElement, App, AsyncWindowContext, Axis, ClickEvent, Div, ElementId,
+    ExternalPaths, FocusHandle, Global, Hsla, InteractiveElement, IntoElement, MouseButton,
+    ParentElement, RenderOnce, SharedString, StatefulInteractiveElement, Styled, Window,
 };
-use gpui_component::ActiveTheme;
 
-use crate::style::Color;
+use crate::accessibility::{self, AccessibilityNode, AccessibilityRole, Accessible};
+use crate::clipboard::Copyable;
+use crate::components::{Shape, ShapeOutline};
+use crate::debug::{self, WrapperKind};
+use crate::drag_drop::{self, Draggable, DropTarget};
+use crate::environment::{
+    self, ColorSchemeOverride, EnvironmentScope, EnvironmentValue, IsEnabled, SafeAreaInsets,
+    Tint as TintKey,
+};
+use crate::focus::FocusContent;
+use crate::layout::{EdgeSet, Toolbar, ToolbarContent};
+use crate::presentation::{AlertConfig, Presentation, Presented};
+use crate::shortcut::{self, KeyboardShortcut};
+use crate::style::{Color, ColorScheme, InteractionStyle, ShapeStyle};
+use crate::task::{self, TaskContent, Work};
+use crate::testing;
 use crate::types::ClickHandler;
+use crate::window::WindowChrome;
 
 pub use crate::alignment::{Alignment, HorizontalAlignment, VerticalAlignment};
 
@@ -19,10 +40,11 @@ pub use crate::alignment::{Alignment, HorizontalAlignment, VerticalAlignment};
 /// Required because GPUI cannot clip overflow to rounded bounds.
 pub struct StyledContainer<V> {
     child: V,
-    background: Option<Color>,
+    background: Option<Box<dyn ShapeStyle>>,
     corner_radius: Option<f32>,
     border_color: Option<Color>,
     border_width: Option<f32>,
+    border_style: BorderStyle,
 }
 
 impl<V> StyledContainer<V> {
@@ -33,11 +55,12 @@ impl<V> StyledContainer<V> {
             corner_radius: None,
             border_color: None,
             border_width: None,
+            border_style: BorderStyle::Solid,
         }
     }
 
-    fn with_background(mut self, color: Color) -> Self {
-        self.background = Some(color);
+    fn with_background(mut self, style: impl ShapeStyle) -> Self {
+        self.background = Some(Box::new(style));
         self
     }
 
@@ -46,9 +69,10 @@ impl<V> StyledContainer<V> {
         self
     }
 
-    fn with_border(mut self, color: Color, width: f32) -> Self {
+    fn with_border(mut self, color: Color, width: f32, style: BorderStyle) -> Self {
         self.border_color = Some(color);
         self.border_width = Some(width);
+        self.border_style = style;
         self
     }
 
@@ -58,8 +82,8 @@ impl<V> StyledContainer<V> {
     }
 
     #[must_use]
-    pub fn border(self, color: impl Into<Color>, width: f32) -> Self {
-        self.with_border(color.into(), width)
+    pub fn border(self, color: impl Into<Color>, width: f32, style: BorderStyle) -> Self {
+        self.with_border(color.into(), width, style)
     }
 }
 
@@ -78,11 +102,17 @@ struct StyledContainerElement<V: IntoElement + 'static> {
 
 impl<V: IntoElement + 'static> RenderOnce for StyledContainerElement<V> {
     fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
-        let is_dark = cx.theme().is_dark();
+        let modifier = if self.container.background.is_some() {
+            "background"
+        } else if self.container.border_color.is_some() {
+            "border"
+        } else {
+            "corner_radius"
+        };
         let mut container = div();
 
-        if let Some(color) = self.container.background {
-            container = container.bg(color.resolve(is_dark));
+        if let Some(style) = self.container.background {
+            container = container.bg(style.resolve_background(cx));
         }
 
         if let Some(radius) = self.container.corner_radius {
@@ -90,21 +120,63 @@ impl<V: IntoElement + 'static> RenderOnce for StyledContainerElement<V> {
         }
 
         if let Some(color) = self.container.border_color {
-            container = container.border_color(color.resolve(is_dark));
-            if let Some(width) = self.container.border_width {
-                container = if width <= 1.0 {
-                    container.border_1()
-                } else if width <= 2.0 {
-                    container.border_2()
-                } else if width <= 4.0 {
-                    container.border_4()
-                } else {
-                    container.border_8()
-                };
-            }
+            let width = self.container.border_width.unwrap_or(1.0);
+            container = apply_border(
+                container,
+                color.resolve_in(cx),
+                width,
+                self.container.border_style,
+                EdgeSet::all(),
+            );
         }
 
-        container.child(self.container.child)
+        debug::track(
+            container.child(self.container.child),
+            WrapperKind::Background,
+            modifier,
+            debug::short_type_name::<V>,
+            cx,
+        )
+    }
+}
+
+/// Applies an exact-width border to the given edges of a container.
+fn apply_border(
+    container: Div,
+    color: Hsla,
+    width: f32,
+    style: BorderStyle,
+    edges: EdgeSet,
+) -> Div {
+    let mut container = container.border_color(color);
+    if edges.top {
+        container = container.border_t(px(width));
+    }
+    if edges.leading {
+        container = container.border_l(px(width));
+    }
+    if edges.bottom {
+        container = container.border_b(px(width));
+    }
+    if edges.trailing {
+        container = container.border_r(px(width));
+    }
+    match style {
+        BorderStyle::Solid => container,
+        BorderStyle::Dashed => container.border_dashed(),
+    }
+}
+
+/// Applies the GPUI shadow closest to a shadow of `radius` points.
+pub(crate) fn apply_shadow<S: Styled>(styled: S, radius: f32) -> S {
+    if radius <= 2.0 {
+        styled.shadow_sm()
+    } else if radius <= 4.0 {
+        styled.shadow_md()
+    } else if radius <= 8.0 {
+        styled.shadow_lg()
+    } else {
+        styled.shadow_xl()
     }
 }
 
@@ -115,6 +187,23 @@ impl<V: IntoElement + 'static> RenderOnce for StyledContainerElement<V> {
 pub struct Modified<V> {
     pub(crate) child: V,
     pub(crate) modifier: ModifierKind,
+    /// How the child expands, so layout wrappers can pass it on.
+    pub(crate) child_expansion: Expansion,
+}
+
+/// How a view expands into the space its container offers.
+///
+/// Layout wrappers such as `.padding()` carry their child's expansion so the
+/// modifier doesn't change whether the view hugs its content.
+#[doc(hidden)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Expansion {
+    /// Grows along the main axis of its stack, like `Spacer`.
+    pub grows: bool,
+    /// Fills the container's width, like `.frame(Frame::fill_width())`.
+    pub fills_width: bool,
+    /// Fills the container's height.
+    pub fills_height: bool,
 }
 
 #[derive(Clone)]
@@ -122,9 +211,12 @@ pub enum ModifierKind {
     Padding(Padding),
     Foreground(Color),
     CornerRadius(f32),
+    ClipShape(ShapeOutline),
     Border {
         color: Color,
         width: f32,
+        style: BorderStyle,
+        edges: EdgeSet,
     },
     Shadow {
         radius: f32,
@@ -133,11 +225,16 @@ pub enum ModifierKind {
         y: f32,
     },
     Opacity(f32),
+    Hovered(InteractionStyle),
+    Pressed(InteractionStyle),
     Frame(Frame),
     Hidden(bool),
+    Removed(bool),
     Disabled(bool),
     Scale(f32),
     Tint(Color),
+    ColorScheme(ColorScheme),
+    TextScale(f32),
     FixedSize {
         horizontal: bool,
         vertical: bool,
@@ -146,6 +243,52 @@ pub enum ModifierKind {
         ratio: f32,
         content_mode: ContentMode,
     },
+    GridCellUnsizedAxes(Axis),
+    ScrollId(ElementId),
+    ScrollTarget,
+    Accessibility(AccessibilityNode),
+    Environment(EnvironmentValue),
+    SafeAreaPadding,
+    IgnoresSafeArea(EdgeSet),
+    WindowChrome(WindowChrome),
+    TestId(SharedString),
+}
+
+impl ModifierKind {
+    /// The modifier that wraps a view this way, and the kind of wrapper
+    /// the debug overlay outlines it as.
+    fn debug_kind(&self) -> (WrapperKind, &'static str) {
+        match self {
+            ModifierKind::Padding(_) => (WrapperKind::Padding, "padding"),
+            ModifierKind::SafeAreaPadding => (WrapperKind::Padding, "safe_area_padding"),
+            ModifierKind::Frame(_) => (WrapperKind::Frame, "frame"),
+            ModifierKind::FixedSize { .. } => (WrapperKind::Frame, "fixed_size"),
+            ModifierKind::AspectRatio { .. } => (WrapperKind::Frame, "aspect_ratio"),
+            ModifierKind::CornerRadius(_) => (WrapperKind::Background, "corner_radius"),
+            ModifierKind::ClipShape(_) => (WrapperKind::Background, "clip_shape"),
+            ModifierKind::Border { .. } => (WrapperKind::Background, "border"),
+            ModifierKind::Shadow { .. } => (WrapperKind::Background, "shadow"),
+            ModifierKind::Foreground(_) => (WrapperKind::Other, "foreground_color"),
+            ModifierKind::Opacity(_) => (WrapperKind::Other, "opacity"),
+            ModifierKind::Hovered(_) => (WrapperKind::Other, "when_hovered"),
+            ModifierKind::Pressed(_) => (WrapperKind::Other, "when_pressed"),
+            ModifierKind::Hidden(_) => (WrapperKind::Other, "hidden"),
+            ModifierKind::Removed(_) => (WrapperKind::Other, "remove_when"),
+            ModifierKind::Disabled(_) => (WrapperKind::Other, "disabled"),
+            ModifierKind::Scale(_) => (WrapperKind::Other, "scale"),
+            ModifierKind::Tint(_) => (WrapperKind::Other, "tint"),
+            ModifierKind::ColorScheme(_) => (WrapperKind::Other, "color_scheme"),
+            ModifierKind::TextScale(_) => (WrapperKind::Other, "text_scale"),
+            ModifierKind::GridCellUnsizedAxes(_) => (WrapperKind::Other, "grid_cell_unsized_axes"),
+            ModifierKind::ScrollId(_) => (WrapperKind::Other, "scroll_id"),
+            ModifierKind::ScrollTarget => (WrapperKind::Other, "scroll_target"),
+            ModifierKind::Accessibility(_) => (WrapperKind::Other, "accessibility"),
+            ModifierKind::Environment(_) => (WrapperKind::Other, "environment"),
+            ModifierKind::IgnoresSafeArea(_) => (WrapperKind::Other, "ignores_safe_area"),
+            ModifierKind::WindowChrome(_) => (WrapperKind::Other, "window_chrome"),
+            ModifierKind::TestId(_) => (WrapperKind::Other, "test_id"),
+        }
+    }
 }
 
 /// Padding values for each edge.
@@ -396,6 +539,16 @@ impl Frame {
     }
 }
 
+/// The stroke style of a border.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BorderStyle {
+    /// A continuous line.
+    #[default]
+    Solid,
+    /// A dashed line.
+    Dashed,
+}
+
 /// Content mode for aspect ratio.
 #[derive(Clone, Copy, Debug, Default)]
 pub enum ContentMode {
@@ -410,9 +563,23 @@ pub enum ContentMode {
 pub trait Modifier: Sized {
     // Layout modifiers
 
+    /// How this view expands into the space its container offers.
+    ///
+    /// Most views hug their content; `Spacer` and fill frames override this.
+    #[doc(hidden)]
+    fn expansion(&self) -> Expansion {
+        Expansion::default()
+    }
+
     /// Add padding around the view.
+    ///
+    /// The padded wrapper sizes itself to the child plus the insets, so a
+    /// padded `Text` next to a `Spacer` keeps its intrinsic width rather than
+    /// competing for the remaining space. Views that expand, such as a
+    /// `Spacer` or a fill frame, still expand when padded.
     fn padding(self, value: impl Into<Padding>) -> Modified<Self> {
         Modified {
+            child_expansion: self.expansion(),
             child: self,
             modifier: ModifierKind::Padding(value.into()),
         }
@@ -423,6 +590,56 @@ pub trait Modifier: Sized {
         self.padding(Padding::edges(top, leading, bottom, trailing))
     }
 
+    /// Pad the view by the safe area insets left for it, such as the
+    /// height of a transparent titlebar, like SwiftUI's `.safeAreaPadding()`.
+    ///
+    /// Views inside see no insets left, so a nested `.safe_area_padding()`
+    /// doesn't pad twice. The insets are set with `.window_chrome()`.
+    fn safe_area_padding(self) -> Modified<Self> {
+        Modified {
+            child_expansion: self.expansion(),
+            child: self,
+            modifier: ModifierKind::SafeAreaPadding,
+        }
+    }
+
+    /// Extend the view on `edges` over the safe area insets that enclosing
+    /// `.safe_area_padding()` modifiers padded, like SwiftUI's
+    /// `.ignoresSafeArea()`.
+    ///
+    /// The insets are left for the views inside again, so a background can
+    /// run under a transparent titlebar while content padded with its own
+    /// `.safe_area_padding()` stays clear of it. The view moves past the
+    /// padding whether or not it touches that edge, so set this on views at
+    /// the edge.
+    ///
+    /// ```rust,ignore
+    /// header
+    ///     .safe_area_padding()
+    ///     .background(Color::secondary_system_background())
+    ///     .ignores_safe_area(EdgeSet::top())
+    /// ```
+    fn ignores_safe_area(self, edges: EdgeSet) -> Modified<Self> {
+        Modified {
+            child_expansion: self.expansion(),
+            child: self,
+            modifier: ModifierKind::IgnoresSafeArea(edges),
+        }
+    }
+
+    /// Set the safe area insets of the window's chrome for the views
+    /// inside, which keep clear of it with `.safe_area_padding()`.
+    ///
+    /// Set it on the window's root view. The insets follow the window, such
+    /// as dropping to none in full screen.
+    fn window_chrome(self, chrome: WindowChrome) -> Modified<Self> {
+        Modified {
+            child_expansion: self.expansion(),
+            child: self,
+            modifier: ModifierKind::WindowChrome(chrome),
+        }
+    }
+
     /// Apply a frame with the specified configuration.
     ///
     /// # Examples
@@ -442,6 +659,7 @@ pub trait Modifier: Sized {
     /// ```
     fn frame(self, frame: Frame) -> Modified<Self> {
         Modified {
+            child_expansion: self.expansion(),
             child: self,
             modifier: ModifierKind::Frame(frame),
         }
@@ -465,9 +683,15 @@ pub trait Modifier: Sized {
         self.frame(Frame::height(height))
     }
 
-    /// Prevent the view from expanding beyond its ideal size.
+    /// Keep the view at its ideal size along the given axes, ignoring the
+    /// size its parent offers, like SwiftUI's `.fixedSize()`.
+    ///
+    /// Horizontally, text inside stays on one line instead of wrapping,
+    /// overflowing a narrower parent. Vertically, wrapped text takes its
+    /// full height even in a shorter parent.
     fn fixed_size(self, horizontal: bool, vertical: bool) -> Modified<Self> {
         Modified {
+            child_expansion: self.expansion(),
             child: self,
             modifier: ModifierKind::FixedSize {
                 horizontal,
@@ -479,6 +703,7 @@ pub trait Modifier: Sized {
     /// Constrain the view to a specific aspect ratio.
     fn aspect_ratio(self, ratio: f32, content_mode: ContentMode) -> Modified<Self> {
         Modified {
+            child_expansion: self.expansion(),
             child: self,
             modifier: ModifierKind::AspectRatio {
                 ratio,
@@ -487,23 +712,113 @@ pub trait Modifier: Sized {
         }
     }
 
+    /// Keep the view from sizing its grid column or row along `axis`.
+    ///
+    /// The view fills whatever size the other cells give the column (for
+    /// `Axis::Horizontal`) or row (for `Axis::Vertical`), like SwiftUI's
+    /// `gridCellUnsizedAxes(_:)`. Use it for dividers and other views that
+    /// would otherwise stretch a grid.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// Grid::new()
+    ///     .child(GridRow::new().child(Text::new("Name")).child(Text::new("Size")))
+    ///     .child(GridRow::new().cell_spanning(
+    ///         Divider::new().grid_cell_unsized_axes(Axis::Horizontal),
+    ///         2,
+    ///     ))
+    /// ```
+    fn grid_cell_unsized_axes(self, axis: Axis) -> Modified<Self> {
+        Modified {
+            child_expansion: self.expansion(),
+            child: self,
+            modifier: ModifierKind::GridCellUnsizedAxes(axis),
+        }
+    }
+
     // Visual modifiers
 
-    fn background(self, color: impl Into<Color>) -> StyledContainer<Self> {
-        StyledContainer::new(self).with_background(color.into())
+    /// Fill the background with a color or gradient.
+    fn background(self, style: impl ShapeStyle) -> StyledContainer<Self> {
+        StyledContainer::new(self).with_background(style)
     }
 
     /// Set the foreground (text) color.
+    ///
+    /// The color cascades to nested components such as `Label` icons, `Link`
+    /// and non-prominent `Button` labels. A closer `.foreground_color()` wins.
     fn foreground_color(self, color: impl Into<Color>) -> Modified<Self> {
         Modified {
+            child_expansion: self.expansion(),
             child: self,
             modifier: ModifierKind::Foreground(color.into()),
         }
     }
 
+    /// Scale all text in this view by `factor` (dynamic type).
+    ///
+    /// Text styles and explicit font sizes are multiplied by the factor,
+    /// except `Text` marked with `.fixed_size_font()`. The nearest
+    /// `.text_scale()` wins; nested factors do not compound.
+    fn text_scale(self, factor: f32) -> Modified<Self> {
+        Modified {
+            child_expansion: self.expansion(),
+            child: self,
+            modifier: ModifierKind::TextScale(factor.max(0.0)),
+        }
+    }
+
+    /// Set a value for the views inside to read with
+    /// [`Environment::get`](crate::environment::Environment::get), like
+    /// SwiftUI's `.environment()`.
+    ///
+    /// The value is keyed by its type, so give each value its own type.
+    /// The nearest enclosing value of a type wins, and sibling views don't
+    /// see it.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// #[derive(Clone)]
+    /// struct Compact(bool);
+    ///
+    /// VStack::new().children(rows).environment(Compact(true))
+    /// ```
+    fn environment<T: Clone + 'static>(self, value: T) -> Modified<Self> {
+        Modified {
+            child_expansion: self.expansion(),
+            child: self,
+            modifier: ModifierKind::Environment(EnvironmentValue::new(value)),
+        }
+    }
+
+    /// Render this view in `scheme`, whatever the app's theme, like
+    /// SwiftUI's `.colorScheme()`.
+    ///
+    /// Semantic colors resolved inside, and text without a color of its
+    /// own, follow `scheme`. Colors backed by the theme, such as
+    /// [`Color::accent`], fall back to their built-in values when `scheme`
+    /// differs from the theme's. Widgets drawn by gpui-component (switches,
+    /// text inputs) keep the theme's colors.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// PreviewCard::new().color_scheme(ColorScheme::Dark)
+    /// ```
+    fn color_scheme(self, scheme: ColorScheme) -> Modified<Self> {
+        Modified {
+            child_expansion: self.expansion(),
+            child: self,
+            modifier: ModifierKind::ColorScheme(scheme),
+        }
+    }
+
     /// Set the tint color for interactive elements.
     fn tint(self, color: impl Into<Color>) -> Modified<Self> {
         Modified {
+            child_expansion: self.expansion(),
             child: self,
             modifier: ModifierKind::Tint(color.into()),
         }
@@ -512,18 +827,70 @@ pub trait Modifier: Sized {
     /// Round the corners.
     fn corner_radius(self, radius: f32) -> Modified<Self> {
         Modified {
+            child_expansion: self.expansion(),
             child: self,
             modifier: ModifierKind::CornerRadius(radius),
         }
     }
 
-    /// Add a border.
-    fn border(self, color: impl Into<Color>, width: f32) -> Modified<Self> {
+    /// Clip the view to the outline of a shape.
+    ///
+    /// GPUI clips to rounded rectangles only, so an `Ellipse` clips like a
+    /// `Capsule`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// Image::new("avatar.png").frame_size(64.0, 64.0).clip_shape(Circle::new())
+    /// ```
+    fn clip_shape(self, shape: impl Shape) -> Modified<Self> {
+        Modified {
+            child_expansion: self.expansion(),
+            child: self,
+            modifier: ModifierKind::ClipShape(shape.outline()),
+        }
+    }
+
+    /// Add a border around all edges.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// // Dashed outline for a drop target
+    /// Text::new("Drop files here")
+    ///     .padding(24.0)
+    ///     .border(Color::gray(), 2.0, BorderStyle::Dashed)
+    /// ```
+    fn border(self, color: impl Into<Color>, width: f32, style: BorderStyle) -> Modified<Self> {
+        Modified {
+            child_expansion: self.expansion(),
+            child: self,
+            modifier: ModifierKind::Border {
+                color: color.into(),
+                width,
+                style,
+                edges: EdgeSet::all(),
+            },
+        }
+    }
+
+    /// Add a solid border to specific edges only.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// // Bottom-only separator for a toolbar
+    /// toolbar.border_edges(Color::separator(), 1.0, EdgeSet::bottom())
+    /// ```
+    fn border_edges(self, color: impl Into<Color>, width: f32, edges: EdgeSet) -> Modified<Self> {
         Modified {
+            child_expansion: self.expansion(),
             child: self,
             modifier: ModifierKind::Border {
                 color: color.into(),
                 width,
+                style: BorderStyle::Solid,
+                edges,
             },
         }
     }
@@ -531,6 +898,7 @@ pub trait Modifier: Sized {
     /// Add a shadow.
     fn shadow(self, radius: f32) -> Modified<Self> {
         Modified {
+            child_expansion: self.expansion(),
             child: self,
             modifier: ModifierKind::Shadow {
                 radius,
@@ -544,6 +912,7 @@ pub trait Modifier: Sized {
     /// Add a shadow with full configuration.
     fn shadow_with(self, radius: f32, color: impl Into<Color>, x: f32, y: f32) -> Modified<Self> {
         Modified {
+            child_expansion: self.expansion(),
             child: self,
             modifier: ModifierKind::Shadow {
                 radius,
@@ -554,27 +923,159 @@ pub trait Modifier: Sized {
         }
     }
 
-    /// Set the opacity.
+    /// Set the opacity of the view and its entire subtree.
+    ///
+    /// The value is clamped to `0.0..=1.0`. GPUI multiplies the opacity into
+    /// every descendant primitive (backgrounds, borders, text, images, icons
+    /// and gpui-component widgets), so nested opacities compound as in
+    /// SwiftUI.
+    ///
+    /// Note: GPUI has no offscreen layer compositing, so overlapping
+    /// descendants are faded individually rather than flattened first.
+    /// Where two translucent children overlap, the lower one shows through.
     fn opacity(self, value: f32) -> Modified<Self> {
         Modified {
+            child_expansion: self.expansion(),
+            child: self,
+            modifier: ModifierKind::Opacity(value.clamp(0.0, 1.0)),
+        }
+    }
+
+    /// Restyle the view while the pointer is over it, with the styles
+    /// `style` sets on an [`InteractionStyle`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// // A card that lifts on hover
+    /// PricingCard::new(plan)
+    ///     .when_hovered(|style| style.corner_radius(12.0).shadow(12.0).offset(0.0, -4.0))
+    /// ```
+    fn when_hovered(
+        self,
+        style: impl FnOnce(InteractionStyle) -> InteractionStyle,
+    ) -> Modified<Self> {
+        Modified {
+            child_expansion: self.expansion(),
             child: self,
-            modifier: ModifierKind::Opacity(value),
+            modifier: ModifierKind::Hovered(style(InteractionStyle::default())),
+        }
+    }
+
+    /// Restyle the view while the mouse button is held down on it, with
+    /// the styles `style` sets on an [`InteractionStyle`].
+    ///
+    /// The view is styled while it's under the pointer with the button
+    /// down, so dragging off it restores its style.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// Card::new()
+    ///     .when_pressed(|style| style.opacity(0.8))
+    ///     .on_tap_gesture("card", open)
+    /// ```
+    fn when_pressed(
+        self,
+        style: impl FnOnce(InteractionStyle) -> InteractionStyle,
+    ) -> Modified<Self> {
+        Modified {
+            child_expansion: self.expansion(),
+            child: self,
+            modifier: ModifierKind::Pressed(style(InteractionStyle::default())),
         }
     }
 
     // Behavior modifiers
 
-    /// Hide the view.
+    /// Tag the view as a target for [`ScrollViewProxy::scroll_to_id`] in
+    /// the enclosing tracked `ScrollView`.
+    ///
+    /// [`ScrollViewProxy::scroll_to_id`]: crate::layout::ScrollViewProxy::scroll_to_id
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// ScrollView::new("chat")
+    ///     .track(&self.scroll)
+    ///     .child(VStack::new().children(messages.iter().map(|message| {
+    ///         MessageRow::new(message).scroll_id(message.id)
+    ///     })))
+    /// ```
+    fn scroll_id(self, id: impl Into<ElementId>) -> Modified<Self> {
+        Modified {
+            child_expansion: self.expansion(),
+            child: self,
+            modifier: ModifierKind::ScrollId(id.into()),
+        }
+    }
+
+    /// Mark the view as an item a `ScrollView` with
+    /// `.snap(SnapBehavior::ToItems { .. })` settles on.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// ScrollView::new("photos")
+    ///     .axes(ScrollAxes::horizontal())
+    ///     .snap(SnapBehavior::ToItems { spacing: 16.0 })
+    ///     .child(HStack::new().children(photos.iter().map(|photo| {
+    ///         PhotoCard::new(photo).scroll_target()
+    ///     })))
+    /// ```
+    fn scroll_target(self) -> Modified<Self> {
+        Modified {
+            child_expansion: self.expansion(),
+            child: self,
+            modifier: ModifierKind::ScrollTarget,
+        }
+    }
+
+    /// Tag the view with `id`, so
+    /// [`render_for_test`](crate::testing::render_for_test) reports where it
+    /// was laid out.
+    ///
+    /// Outside of `render_for_test` the tag does nothing, so views can keep
+    /// it in the app.
+    fn test_id(self, id: impl Into<SharedString>) -> Modified<Self> {
+        Modified {
+            child_expansion: self.expansion(),
+            child: self,
+            modifier: ModifierKind::TestId(id.into()),
+        }
+    }
+
+    /// Hide the view, keeping the space it takes up, like SwiftUI's
+    /// `.hidden()`.
+    ///
+    /// A hidden view is laid out as usual but draws nothing and takes no
+    /// clicks or focus. Use [`remove_when`](Self::remove_when) or `If` to
+    /// collapse the space as well.
     fn hidden(self, is_hidden: bool) -> Modified<Self> {
         Modified {
+            child_expansion: self.expansion(),
             child: self,
             modifier: ModifierKind::Hidden(is_hidden),
         }
     }
 
+    /// Leave the view out of the layout entirely while `is_removed` is
+    /// true, collapsing the space it would take up.
+    fn remove_when(self, is_removed: bool) -> Modified<Self> {
+        Modified {
+            child_expansion: self.expansion(),
+            child: self,
+            modifier: ModifierKind::Removed(is_removed),
+        }
+    }
+
     /// Disable interaction.
+    ///
+    /// Views inside read [`IsEnabled`](crate::environment::IsEnabled) as
+    /// false, even under a nested `.disabled(false)`.
     fn disabled(self, is_disabled: bool) -> Modified<Self> {
         Modified {
+            child_expansion: self.expansion(),
             child: self,
             modifier: ModifierKind::Disabled(is_disabled),
         }
@@ -583,6 +1084,7 @@ pub trait Modifier: Sized {
     /// Apply a scale transform.
     fn scale(self, value: f32) -> Modified<Self> {
         Modified {
+            child_expansion: self.expansion(),
             child: self,
             modifier: ModifierKind::Scale(value),
         }
@@ -590,6 +1092,11 @@ pub trait Modifier: Sized {
 
     /// Add a tap gesture handler.
     ///
+    /// The view dims while it's pressed; see [`Tappable`] to change that.
+    /// The handler runs for every click, except double clicks taken by an
+    /// [`on_tap_gesture_count`](Self::on_tap_gesture_count) gesture
+    /// attached before it.
+    ///
     /// # Example
     ///
     /// ```rust,ignore
@@ -603,11 +1110,7 @@ pub trait Modifier: Sized {
         id: impl Into<SharedString>,
         handler: impl Fn() + 'static,
     ) -> Tappable<Self> {
-        Tappable {
-            child: self,
-            handler: Box::new(move |_, _, _| handler()),
-            id: id.into(),
-        }
+        Tappable::new(self, id.into(), Box::new(move |_, _, _| handler()))
     }
 
     /// Add a tap gesture handler with access to the event.
@@ -616,25 +1119,599 @@ pub trait Modifier: Sized {
         id: impl Into<SharedString>,
         handler: impl Fn(&ClickEvent, &mut Window, &mut App) + 'static,
     ) -> Tappable<Self> {
-        Tappable {
+        Tappable::new(self, id.into(), Box::new(handler))
+    }
+
+    /// Add a handler for taps of exactly `clicks` clicks, such as `2` for
+    /// double taps.
+    ///
+    /// A tap gesture attached after this one doesn't also run for the taps
+    /// it takes, so a view can tell single and double taps apart. The
+    /// single tap still runs for the first click of a double tap.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// PhotoCard::new(photo)
+    ///     .on_tap_gesture_count("like", 2, |_, _, _| like())
+    ///     .on_tap_gesture("open", || open())
+    /// ```
+    fn on_tap_gesture_count(
+        self,
+        id: impl Into<SharedString>,
+        clicks: usize,
+        handler: impl Fn(&ClickEvent, &mut Window, &mut App) + 'static,
+    ) -> Tappable<Self> {
+        let mut tappable = Tappable::new(self, id.into(), Box::new(handler));
+        tappable.clicks = Some(clicks);
+        tappable
+    }
+
+    /// Let this view be dragged, carrying `payload` to the drop targets
+    /// that accept its type.
+    ///
+    /// The drag is kept by call site; give views made in a loop their own
+    /// [`id`](Draggable::id), and a [`drag_preview`](Draggable::drag_preview)
+    /// to show under the pointer.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// CardView::new(&card)
+    ///     .draggable(card.id)
+    ///     .drag_preview(move || Text::new(title.clone()))
+    ///     .id(("card", card.id))
+    /// ```
+    #[track_caller]
+    fn draggable<T: 'static>(self, payload: T) -> Draggable<Self> {
+        Draggable {
+            child_expansion: self.expansion(),
+            child: self,
+            id: ElementId::CodeLocation(*std::panic::Location::caller()),
+            start: drag_drop::drag_starter(payload),
+            preview: None,
+        }
+    }
+
+    /// Call `handler` with the payload of a `T` dropped on this view.
+    ///
+    /// Drags of other types pass over the view without matching. Add
+    /// [`on_drag_over`](DropTarget::on_drag_over) to highlight the view
+    /// while a `T` is over it.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// Column::new(&self.done).on_drop(cx.listener(|this, id: &CardId, _, cx| {
+    ///     this.move_to_done(*id);
+    ///     cx.notify();
+    /// }))
+    /// ```
+    #[track_caller]
+    fn on_drop<T: 'static>(
+        self,
+        handler: impl Fn(&T, &mut Window, &mut App) + 'static,
+    ) -> DropTarget<Self, T> {
+        DropTarget {
+            child_expansion: self.expansion(),
+            child: self,
+            id: ElementId::CodeLocation(*std::panic::Location::caller()),
+            on_drop: Rc::new(handler),
+            on_drag_over: None,
+        }
+    }
+
+    /// Call `handler` with the paths of files dragged onto this view from
+    /// the system's file manager, such as Finder or Explorer.
+    ///
+    /// Several files can be dropped at once. Add
+    /// [`on_drag_over`](DropTarget::on_drag_over) to highlight the view
+    /// while files are over it. On platforms that can't drag files into a
+    /// window, the handler is never called.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let view = cx.entity().downgrade();
+    /// DropZone::new()
+    ///     .on_file_drop(move |paths, _, cx| {
+    ///         view.update(cx, |this, cx| {
+    ///             this.attachments.extend(paths);
+    ///             cx.notify();
+    ///         })
+    ///         .ok();
+    ///     })
+    /// ```
+    #[track_caller]
+    fn on_file_drop(
+        self,
+        handler: impl Fn(Vec<PathBuf>, &mut Window, &mut App) + 'static,
+    ) -> DropTarget<Self, ExternalPaths> {
+        self.on_drop(move |paths: &ExternalPaths, window, cx| {
+            handler(paths.paths().to_vec(), window, cx)
+        })
+    }
+
+    /// Copy `text` to the clipboard when this view is clicked, confirming
+    /// with a brief "Copied!" toast.
+    ///
+    /// Inside `.disabled(true)` the view copies nothing. Give views made in
+    /// a loop their own [`id`](Copyable::id).
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// Text::new(&self.api_key)
+    ///     .font(Font::body().monospaced())
+    ///     .copyable(self.api_key.clone())
+    /// ```
+    #[track_caller]
+    fn copyable(self, text: impl Into<SharedString>) -> Copyable<Self> {
+        Copyable {
+            child_expansion: self.expansion(),
+            child: self,
+            id: ElementId::CodeLocation(*std::panic::Location::caller()),
+            text: text.into(),
+        }
+    }
+
+    /// Click this view's button or tap gesture when `keystroke` is pressed
+    /// while the window has focus, like SwiftUI's `keyboardShortcut`.
+    ///
+    /// `keystroke` names modifiers and a key separated by `-`, such as
+    /// `"cmd-s"`, `"ctrl-shift-n"`, `"escape"` or `"enter"`. The first
+    /// `Button` or `.on_tap_gesture()` view inside takes the shortcut; it
+    /// does nothing while disabled or hidden. When two views shown in a
+    /// window bind the same keystroke, only the first runs, with a warning
+    /// logged in debug builds. Use [`MenuItem::keyboard_shortcut`] for menu
+    /// items, which also shows the keys in the menu.
+    ///
+    /// [`MenuItem::keyboard_shortcut`]: crate::components::MenuItem::keyboard_shortcut
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// Button::new("Save", save).keyboard_shortcut("cmd-s")
+    /// ```
+    fn keyboard_shortcut(self, keystroke: &str) -> KeyboardShortcut<Self> {
+        KeyboardShortcut {
+            child_expansion: self.expansion(),
+            child: self,
+            keystroke: shortcut::parse(keystroke),
+        }
+    }
+
+    /// Give this view a tab stop of its own, with a focus ring drawn
+    /// around it while it's focused from the keyboard.
+    ///
+    /// Tab and Shift-Tab move through focusable views, text fields and
+    /// other controls in the order they are declared. The view's focus is
+    /// kept by call site; bind a handle with [`focused`](Self::focused)
+    /// for views created in a loop.
+    #[track_caller]
+    fn focusable(self) -> FocusContent<Self> {
+        FocusContent {
+            child_expansion: self.expansion(),
+            child: self,
+            id: ElementId::CodeLocation(*std::panic::Location::caller()),
+            handle: None,
+            on_focus_change: None,
+        }
+    }
+
+    /// Bind this view's focus to `handle`, which the app keeps so it can
+    /// check and move focus from code, like SwiftUI's `@FocusState`.
+    ///
+    /// Calling `handle.focus(window)` focuses the view. A `TextField`,
+    /// `SecureField` or `TextEditor` inside passes the focus on to its
+    /// input, moving the cursor there, and `handle.contains_focused()`
+    /// stays true while it's edited. The handle is only a tab stop if it
+    /// was made one with `tab_stop(true)`; leave that off for text fields,
+    /// which are tab stops already.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// // In the view's constructor
+    /// let email_focus = cx.focus_handle();
+    ///
+    /// // In render
+    /// TextField::new(&self.email_input).focused(&self.email_focus)
+    /// Button::new("Focus email", ..) // calls email_focus.focus(window)
+    /// ```
+    #[track_caller]
+    fn focused(self, handle: &FocusHandle) -> FocusContent<Self> {
+        self.focusable().focused(handle)
+    }
+
+    /// Call `handler` with `true` when this view gains focus and `false`
+    /// when it loses it, making the view [`focusable`](Self::focusable).
+    #[track_caller]
+    fn on_focus_change(
+        self,
+        handler: impl Fn(bool, &mut Window, &mut App) + 'static,
+    ) -> FocusContent<Self> {
+        self.focusable().on_focus_change(handler)
+    }
+
+    // Accessibility modifiers
+
+    /// Set what assistive technology reads for this view, in place of the
+    /// label its control provides.
+    ///
+    /// Accessibility modifiers describe the first control inside them, or
+    /// the view itself when there is none. The outermost modifier wins
+    /// when two set the same field.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// Button::with_label(Image::system_name("trash"), delete)
+    ///     .accessibility_label("Delete")
+    ///     .accessibility_hint("Moves the message to the trash")
+    /// ```
+    fn accessibility_label(self, label: impl Into<SharedString>) -> Modified<Self> {
+        Modified {
+            child_expansion: self.expansion(),
+            child: self,
+            modifier: ModifierKind::Accessibility(AccessibilityNode::default().label(label)),
+        }
+    }
+
+    /// Describe what happens when this view is activated.
+    fn accessibility_hint(self, hint: impl Into<SharedString>) -> Modified<Self> {
+        Modified {
+            child_expansion: self.expansion(),
+            child: self,
+            modifier: ModifierKind::Accessibility(AccessibilityNode {
+                hint: Some(hint.into()),
+                ..AccessibilityNode::default()
+            }),
+        }
+    }
+
+    /// Set the value read for this view, in place of its control's own.
+    fn accessibility_value(self, value: impl Into<SharedString>) -> Modified<Self> {
+        Modified {
+            child_expansion: self.expansion(),
+            child: self,
+            modifier: ModifierKind::Accessibility(AccessibilityNode::default().value(value)),
+        }
+    }
+
+    /// Leave this view out of the accessibility tree, such as a decorative
+    /// image.
+    fn accessibility_hidden(self, is_hidden: bool) -> Modified<Self> {
+        Modified {
+            child_expansion: self.expansion(),
+            child: self,
+            modifier: ModifierKind::Accessibility(AccessibilityNode {
+                hidden: Some(is_hidden),
+                ..AccessibilityNode::default()
+            }),
+        }
+    }
+
+    /// Report this view as `role`, such as a header or a tappable view
+    /// acting as a button.
+    fn accessibility_role(self, role: AccessibilityRole) -> Modified<Self> {
+        Modified {
+            child_expansion: self.expansion(),
+            child: self,
+            modifier: ModifierKind::Accessibility(AccessibilityNode::new(role)),
+        }
+    }
+
+    // Lifetime modifiers
+
+    /// Run `task` on the window when this view first appears, cancelling
+    /// it when the view is no longer shown, like SwiftUI's `.task(id:)`.
+    ///
+    /// The task is kept by call site and restarts, cancelling the old one,
+    /// when `key` changes. Give views made in a loop their own
+    /// [`id`](TaskContent::id).
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let view = cx.entity().downgrade();
+    /// Text::new(&self.weather).task(&self.city, async move |cx| {
+    ///     let weather = fetch_weather(&city).await;
+    ///     view.update(cx, |this, cx| {
+    ///         this.weather = weather;
+    ///         cx.notify();
+    ///     })
+    ///     .ok();
+    /// })
+    /// ```
+    #[track_caller]
+    fn task(
+        self,
+        key: impl std::hash::Hash,
+        task: impl AsyncFnOnce(&mut AsyncWindowContext) + 'static,
+    ) -> TaskContent<Self> {
+        TaskContent {
+            child_expansion: self.expansion(),
+            child: self,
+            id: ElementId::CodeLocation(*std::panic::Location::caller()),
+            key: task::hash_key(key),
+            work: Work::task(task),
+        }
+    }
+
+    /// Call `handler` every `interval_secs` seconds while this view is
+    /// shown.
+    ///
+    /// The timer starts when the view appears and stops when it's no
+    /// longer shown; each tick calls the handler from the latest render.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// // A clock that re-renders every second
+    /// let view = cx.entity().downgrade();
+    /// Text::new(now()).on_timer(1.0, move |_, cx| {
+    ///     view.update(cx, |_, cx| cx.notify()).ok();
+    /// })
+    /// ```
+    #[track_caller]
+    fn on_timer(
+        self,
+        interval_secs: f32,
+        handler: impl Fn(&mut Window, &mut App) + 'static,
+    ) -> TaskContent<Self> {
+        TaskContent {
+            child_expansion: self.expansion(),
+            child: self,
+            id: ElementId::CodeLocation(*std::panic::Location::caller()),
+            key: task::hash_key(interval_secs.to_bits()),
+            work: Work::Timer {
+                interval: Duration::from_secs_f32(interval_secs),
+                handler: Rc::new(handler),
+            },
+        }
+    }
+
+    // Presentation modifiers
+
+    /// Present `content` in a sheet over the dimmed window while
+    /// `is_presented` is true.
+    ///
+    /// Clicking outside the sheet or pressing Escape calls `on_dismiss`,
+    /// which should stop presenting it, unless
+    /// [`interactive_dismiss_disabled`](Presented::interactive_dismiss_disabled)
+    /// is set. `content` is only built while presented.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let view = cx.entity().downgrade();
+    /// Button::new("Edit", || {}).sheet(
+    ///     self.editing,
+    ///     move |_, cx| {
+    ///         view.update(cx, |this, cx| {
+    ///             this.editing = false;
+    ///             cx.notify();
+    ///         })
+    ///         .ok();
+    ///     },
+    ///     || EditForm::new(),
+    /// )
+    /// ```
+    fn sheet<E: IntoElement>(
+        self,
+        is_presented: bool,
+        on_dismiss: impl Fn(&mut Window, &mut App) + 'static,
+        content: impl FnOnce() -> E,
+    ) -> Presented<Self> {
+        Presented {
+            child_expansion: self.expansion(),
+            child: self,
+            presentation: is_presented.then(|| Presentation::Sheet(content().into_any_element())),
+            on_dismiss: Some(Rc::new(on_dismiss)),
+            dismiss_disabled: false,
+        }
+    }
+
+    /// Present `content` covering the whole window while `is_presented` is
+    /// true.
+    ///
+    /// Pressing Escape calls `on_dismiss`, unless
+    /// [`interactive_dismiss_disabled`](Presented::interactive_dismiss_disabled)
+    /// is set.
+    fn full_screen_cover<E: IntoElement>(
+        self,
+        is_presented: bool,
+        on_dismiss: impl Fn(&mut Window, &mut App) + 'static,
+        content: impl FnOnce() -> E,
+    ) -> Presented<Self> {
+        Presented {
+            child_expansion: self.expansion(),
+            child: self,
+            presentation: is_presented
+                .then(|| Presentation::FullScreenCover(content().into_any_element())),
+            on_dismiss: Some(Rc::new(on_dismiss)),
+            dismiss_disabled: false,
+        }
+    }
+
+    /// Present an alert with `config`'s title, message and up to three
+    /// buttons while `is_presented` is true.
+    ///
+    /// Two buttons sit side by side, others stack. Escape picks the cancel
+    /// button and Enter the first button without a role; clicking outside
+    /// does nothing.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// Button::new("Sign Out", || {}).alert(
+    ///     self.signing_out,
+    ///     AlertConfig::new("Sign Out?")
+    ///         .button(AlertButton::destructive("Sign Out", sign_out))
+    ///         .button(AlertButton::cancel("Cancel", close)),
+    /// )
+    /// ```
+    fn alert(self, is_presented: bool, config: AlertConfig) -> Presented<Self> {
+        Presented {
+            child_expansion: self.expansion(),
+            child: self,
+            presentation: is_presented.then(|| Presentation::Alert(config)),
+            on_dismiss: None,
+            dismiss_disabled: false,
+        }
+    }
+
+    /// Present `config`'s buttons as a list of options, with its cancel
+    /// button set apart below them, while `is_presented` is true.
+    ///
+    /// Escape and clicking outside pick the cancel button, and Enter the
+    /// first button without a role.
+    fn confirmation_dialog(self, is_presented: bool, config: AlertConfig) -> Presented<Self> {
+        Presented {
+            child_expansion: self.expansion(),
+            child: self,
+            presentation: is_presented.then(|| Presentation::ConfirmationDialog(config)),
+            on_dismiss: None,
+            dismiss_disabled: false,
+        }
+    }
+
+    /// Present `content` in a floating panel beside this view while
+    /// `is_presented` is true.
+    ///
+    /// `attachment` picks the side: `top` and `bottom` alignments place the
+    /// panel above or below, lined up with the view's leading edge, center
+    /// or trailing edge, and `leading` and `trailing` beside it. The panel
+    /// flips to the other side when it wouldn't fit in the window. Clicking
+    /// outside it or pressing Escape calls `on_dismiss`, unless
+    /// [`interactive_dismiss_disabled`](Presented::interactive_dismiss_disabled)
+    /// is set.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// Button::new("Filter", || {}).popover(
+    ///     self.filtering,
+    ///     Alignment::bottom(),
+    ///     close_filters,
+    ///     || FilterMenu::new(),
+    /// )
+    /// ```
+    fn popover<E: IntoElement>(
+        self,
+        is_presented: bool,
+        attachment: Alignment,
+        on_dismiss: impl Fn(&mut Window, &mut App) + 'static,
+        content: impl FnOnce() -> E,
+    ) -> Presented<Self> {
+        Presented {
+            child_expansion: self.expansion(),
+            child: self,
+            presentation: is_presented.then(|| Presentation::Popover {
+                content: content().into_any_element(),
+                attachment,
+            }),
+            on_dismiss: Some(Rc::new(on_dismiss)),
+            dismiss_disabled: false,
+        }
+    }
+
+    /// Declare toolbar items for this view.
+    ///
+    /// On a screen of a `NavigationStack`, the items join the stack's
+    /// navigation bar: leading items after the back button, trailing items
+    /// at its end, and a principal view in place of the title. Elsewhere,
+    /// the toolbar is drawn above the view.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// NavigationStack::new(
+    ///     &self.path,
+    ///     inbox().toolbar(Toolbar::new().trailing(Button::new("Compose", || {}))),
+    /// )
+    /// ```
+    fn toolbar(self, toolbar: Toolbar) -> ToolbarContent<Self> {
+        ToolbarContent {
+            child_expansion: self.expansion(),
             child: self,
-            handler: Box::new(handler),
-            id: id.into(),
+            toolbar,
         }
     }
 }
 
 // Implement Modifier for Modified so modifiers can be chained
-impl<V> Modifier for Modified<V> {}
+impl<V> Modifier for Modified<V> {
+    fn expansion(&self) -> Expansion {
+        let child = self.child_expansion;
+        match &self.modifier {
+            ModifierKind::Frame(frame) => Expansion {
+                grows: child.grows,
+                fills_width: frame.max_width.is_some_and(f32::is_infinite)
+                    || (child.fills_width && frame.width.is_none()),
+                fills_height: frame.max_height.is_some_and(f32::is_infinite)
+                    || (child.fills_height && frame.height.is_none()),
+            },
+            ModifierKind::FixedSize {
+                horizontal,
+                vertical,
+            } => Expansion {
+                grows: child.grows && !(*horizontal || *vertical),
+                fills_width: child.fills_width && !horizontal,
+                fills_height: child.fills_height && !vertical,
+            },
+            ModifierKind::Removed(true) => Expansion::default(),
+            _ => child,
+        }
+    }
+}
 
 // Implement Modifier for StyledContainer so other modifiers can be chained
 impl<V: IntoElement + 'static> Modifier for StyledContainer<V> {}
 
+/// Opacity of a tapped view while it's pressed.
+const PRESSED_OPACITY: f32 = 0.85;
+
 /// A view wrapped with a tap gesture handler.
+///
+/// The view dims while it's pressed. Use [`tap_highlight`](Self::tap_highlight)
+/// to cover it with a color instead, or
+/// [`tap_feedback_disabled`](Self::tap_feedback_disabled) to show nothing.
 pub struct Tappable<V> {
     child: V,
     handler: ClickHandler,
     id: SharedString,
+    /// The clicks a tap takes, or `None` for every click.
+    clicks: Option<usize>,
+    highlight: Option<Color>,
+    feedback_disabled: bool,
+}
+
+impl<V> Tappable<V> {
+    fn new(child: V, id: SharedString, handler: ClickHandler) -> Self {
+        Self {
+            child,
+            handler,
+            id,
+            clicks: None,
+            highlight: None,
+            feedback_disabled: false,
+        }
+    }
+
+    /// Cover the view with `color` while it's pressed, instead of dimming
+    /// it. Use a translucent color to keep the view visible.
+    pub fn tap_highlight(mut self, color: impl Into<Color>) -> Self {
+        self.highlight = Some(color.into());
+        self
+    }
+
+    /// Show no feedback while the view is pressed.
+    pub fn tap_feedback_disabled(mut self, disabled: bool) -> Self {
+        self.feedback_disabled = disabled;
+        self
+    }
 }
 
 // Implement Modifier for Tappable so modifiers can be chained
@@ -654,18 +1731,62 @@ struct TappableElement<V: IntoElement + 'static> {
 }
 
 impl<V: IntoElement + 'static> RenderOnce for TappableElement<V> {
-    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
-        let child = self.tappable.child.into_any_element();
-        let handler = self.tappable.handler;
-        let element_id = self.tappable.id;
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let Tappable {
+            child,
+            handler,
+            id: element_id,
+            clicks,
+            highlight,
+            feedback_disabled,
+        } = self.tappable;
+        let handler = Rc::new(handler);
+
+        if let Some(keystroke) = shortcut::take(cx) {
+            let handler = handler.clone();
+            shortcut::register(
+                ElementId::NamedChild(
+                    Box::new(element_id.clone().into()),
+                    "keyboard-shortcut".into(),
+                ),
+                keystroke,
+                Rc::new(move |window, cx| handler(&ClickEvent::default(), window, cx)),
+                window,
+                cx,
+            );
+        }
 
-        div()
-            .id(element_id)
+        let container = div()
+            .id(element_id.clone())
+            .relative()
             .cursor_pointer()
-            .on_click(move |event, window, cx| {
-                handler(event, window, cx);
+            .on_click(move |event, window, cx| match clicks {
+                None => handler(event, window, cx),
+                // Taken from tap gestures outside, which see it next
+                Some(clicks) if event.click_count() == clicks => {
+                    cx.stop_propagation();
+                    handler(event, window, cx);
+                }
+                Some(_) => {}
             })
-            .child(child)
+            .child(child);
+
+        let source = element_id.clone();
+        let container = match highlight {
+            _ if feedback_disabled => container,
+            None => container.active(|style| style.opacity(PRESSED_OPACITY)),
+            Some(color) => {
+                let color = color.resolve_in(cx);
+                container.group(element_id.clone()).child(
+                    div()
+                        .id("tap-highlight")
+                        .absolute()
+                        .inset_0()
+                        .group_active(element_id, move |style| style.bg(color)),
+                )
+            }
+        };
+        debug::track(container, WrapperKind::Tap, "on_tap_gesture", || source, cx)
     }
 }
 
@@ -685,55 +1806,139 @@ struct ModifiedElement<V: IntoElement + 'static> {
 }
 
 impl<V: IntoElement + 'static> RenderOnce for ModifiedElement<V> {
-    fn render(self, _window: &mut Window, cx: &mut App) -> impl IntoElement {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
         let child = self.modified.child.into_any_element();
-        let is_dark = cx.theme().is_dark();
+
+        // Foreground color is also pushed into the environment so components
+        // that resolve their own colors (icons, links) can inherit it
+        let foreground = match self.modified.modifier {
+            ModifierKind::Foreground(ref color) => Some(color.clone()),
+            _ => None,
+        };
+        let text_scale = match self.modified.modifier {
+            ModifierKind::TextScale(factor) => Some(factor),
+            _ => None,
+        };
+        // Values readable with Environment::get inside
+        let value = match self.modified.modifier {
+            ModifierKind::Environment(ref value) => Some(value.clone()),
+            ModifierKind::Tint(ref color) => Some(EnvironmentValue::new(TintKey(color.clone()))),
+            ModifierKind::ColorScheme(scheme) => {
+                Some(EnvironmentValue::new(ColorSchemeOverride(scheme)))
+            }
+            // How deep tagged views inside are, while recording
+            ModifierKind::TestId(_) => testing::nested_depth(cx),
+            // Disabling is inherited, so re-enabling inside has no effect
+            ModifierKind::Disabled(is_disabled) => Some(EnvironmentValue::new(IsEnabled(
+                !is_disabled && environment::is_enabled(cx),
+            ))),
+            _ => None,
+        };
+        // The safe area insets left for the views inside, and those padded
+        // around them
+        let safe_area = match self.modified.modifier {
+            ModifierKind::SafeAreaPadding => Some((
+                SafeAreaInsets::default(),
+                environment::padded_safe_area(cx).add(environment::safe_area_insets(cx)),
+            )),
+            ModifierKind::IgnoresSafeArea(edges) => {
+                let padded = environment::padded_safe_area(cx);
+                Some((
+                    environment::safe_area_insets(cx).add(padded.only(edges)),
+                    padded.only(EdgeSet {
+                        top: !edges.top,
+                        leading: !edges.leading,
+                        bottom: !edges.bottom,
+                        trailing: !edges.trailing,
+                    }),
+                ))
+            }
+            ModifierKind::WindowChrome(chrome) => {
+                Some((chrome.safe_area_insets(window), SafeAreaInsets::default()))
+            }
+            _ => None,
+        };
+        // Offered to the first control inside, merged with any enclosing
+        // accessibility modifiers' node
+        let accessibility = match self.modified.modifier {
+            ModifierKind::Accessibility(ref node) => {
+                let node = match accessibility::take(cx) {
+                    Some(outer) => outer.or(node.clone()),
+                    None => node.clone(),
+                };
+                Some(Rc::new(RefCell::new(Some(node))))
+            }
+            _ => None,
+        };
+
+        let (debug_kind, debug_modifier) = self.modified.modifier.debug_kind();
 
         // Apply the modifier by wrapping the child in a container
-        match self.modified.modifier {
-            ModifierKind::Padding(padding) => div()
-                .pt(px(padding.top))
-                .pb(px(padding.bottom))
-                .pl(px(padding.leading))
-                .pr(px(padding.trailing))
-                .child(child),
+        let container = match self.modified.modifier {
+            // The wrapper hugs its child unless the child expands (Spacer,
+            // fill frames), in which case it expands the same way
+            ModifierKind::Padding(padding) => {
+                let container = div()
+                    .pt(px(padding.top))
+                    .pb(px(padding.bottom))
+                    .pl(px(padding.leading))
+                    .pr(px(padding.trailing));
+                expanding(container, self.modified.child_expansion).child(child)
+            }
 
-            ModifierKind::Foreground(color) => {
-                div().text_color(color.resolve(is_dark)).child(child)
+            ModifierKind::Foreground(ref color) => {
+                div().text_color(color.resolve_in(cx)).child(child)
             }
             ModifierKind::CornerRadius(radius) => {
                 div().rounded(px(radius)).overflow_hidden().child(child)
             }
-            ModifierKind::Border { color, width } => {
-                let container = div().border_color(color.resolve(is_dark));
-                let container = if width <= 1.0 {
-                    container.border_1()
-                } else if width <= 2.0 {
-                    container.border_2()
-                } else if width <= 4.0 {
-                    container.border_4()
-                } else {
-                    container.border_8()
+            ModifierKind::ClipShape(outline) => {
+                let container = match outline {
+                    ShapeOutline::Rectangle => div(),
+                    ShapeOutline::RoundedRectangle(radius) => div().rounded(px(radius)),
+                    // GPUI clamps the radius to half the shorter side
+                    ShapeOutline::Circle | ShapeOutline::Capsule | ShapeOutline::Ellipse => {
+                        div().rounded_full()
+                    }
                 };
-                container.child(child)
+                container.overflow_hidden().child(child)
             }
+            ModifierKind::Border {
+                color,
+                width,
+                style,
+                edges,
+            } => apply_border(div(), color.resolve_in(cx), width, style, edges).child(child),
             ModifierKind::Shadow {
                 radius,
                 color: _,
                 x: _,
                 y: _,
-            } => {
-                let container = if radius <= 2.0 {
-                    div().shadow_sm()
-                } else if radius <= 4.0 {
-                    div().shadow_md()
-                } else if radius <= 8.0 {
-                    div().shadow_lg()
-                } else {
-                    div().shadow_xl()
-                };
-                container.child(child)
+            } => apply_shadow(div(), radius).child(child),
+            ModifierKind::Hovered(style) => {
+                let hovered = style.refinement(cx);
+                style.apply_base(div()).hover(hovered).child(child)
+            }
+            // Styled as hovered while the button is down, since GPUI's
+            // `active()` needs an id, which views made in a loop can't share
+            ModifierKind::Pressed(style) => {
+                let mut container = style.apply_base(div());
+                if cx.try_global::<PointerDown>().is_some_and(|down| down.0) {
+                    container = container.hover(style.refinement(cx));
+                }
+                container
+                    .on_mouse_down(MouseButton::Left, |_, window, cx| {
+                        set_pointer_down(true, window, cx)
+                    })
+                    .on_mouse_up(MouseButton::Left, |_, window, cx| {
+                        set_pointer_down(false, window, cx)
+                    })
+                    .on_mouse_up_out(MouseButton::Left, |_, window, cx| {
+                        set_pointer_down(false, window, cx)
+                    })
+                    .child(child)
             }
+            // GPUI applies element opacity to all primitives painted inside this div
             ModifierKind::Opacity(value) => div().opacity(value).child(child),
             ModifierKind::Frame(frame) => {
                 let mut container = div();
@@ -784,9 +1989,16 @@ impl<V: IntoElement + 'static> RenderOnce for ModifiedElement<V> {
                 }
                 container.child(child_wrapper.child(child))
             }
+            // Hidden views are still laid out, but GPUI skips painting
+            // them and registering their listeners
             ModifierKind::Hidden(is_hidden) => {
-                if is_hidden {
-                    div() // Return empty div when hidden
+                let container = if is_hidden { div().invisible() } else { div() };
+                container.child(child)
+            }
+            ModifierKind::Removed(is_removed) => {
+                if is_removed {
+                    // Out of the layout, leaving no gap in a stack either
+                    div().hidden()
                 } else {
                     div().child(child)
                 }
@@ -794,7 +2006,8 @@ impl<V: IntoElement + 'static> RenderOnce for ModifiedElement<V> {
             ModifierKind::Disabled(_is_disabled) => {
                 // NOTE: Disabled state is handled at the component level.
                 // Use component-specific disabled props (e.g., TextField.disabled()).
-                // This modifier exists for SwiftUI API compatibility but is a no-op.
+                // Built-in controls don't read IsEnabled yet, so beyond setting
+                // it for views inside this modifier is a no-op.
                 div().child(child)
             }
             ModifierKind::Scale(_value) => {
@@ -805,20 +2018,25 @@ impl<V: IntoElement + 'static> RenderOnce for ModifiedElement<V> {
             }
             ModifierKind::Tint(_color) => {
                 // NOTE: Tint requires color blending/compositing not available in GPUI.
-                // This modifier is a no-op until GPUI adds blend mode support.
+                // Beyond setting Tint for views inside, this modifier is a no-op
+                // until GPUI adds blend mode support.
                 // For images, consider using pre-tinted assets.
                 div().child(child)
             }
+            // Text components read the scale from the environment
+            ModifierKind::TextScale(_) => div().child(child),
             ModifierKind::FixedSize {
                 horizontal,
                 vertical,
             } => {
                 let mut container = div();
-                // Horizontal: prevent growing and shrinking in x-axis
+                // Horizontal: prevent growing and shrinking in x-axis, and
+                // keep text inside on one line, overflowing if need be
                 if horizontal {
-                    container = container.flex_none();
+                    container = container.flex_none().whitespace_nowrap();
                 }
-                // Vertical: prevent shrinking to preserve natural height
+                // Vertical: prevent shrinking to preserve natural height,
+                // which wrapped text takes in full over a shorter parent
                 if vertical {
                     container = container.flex_shrink_0();
                 }
@@ -834,6 +2052,386 @@ impl<V: IntoElement + 'static> RenderOnce for ModifiedElement<V> {
                 // For now, this is a no-op. Use explicit frame dimensions as workaround.
                 div().child(child)
             }
+            // No size of its own, but at least the size of its grid area
+            ModifierKind::GridCellUnsizedAxes(Axis::Horizontal) => {
+                div().w(px(0.0)).min_w_full().child(child)
+            }
+            ModifierKind::GridCellUnsizedAxes(Axis::Vertical) => {
+                div().h(px(0.0)).min_h_full().child(child)
+            }
+            ModifierKind::ScrollId(id) => {
+                scroll_target(child, Some(id), self.modified.child_expansion, cx)
+            }
+            ModifierKind::ScrollTarget => {
+                scroll_target(child, None, self.modified.child_expansion, cx)
+            }
+            ModifierKind::Environment(_) => div().child(child),
+            // Text inherits the label color of the scheme, not the theme's
+            ModifierKind::ColorScheme(scheme) => div()
+                .text_color(Color::label().resolve(scheme == ColorScheme::Dark))
+                .child(child),
+            ModifierKind::Accessibility(_) => {
+                let slot = accessibility.clone().unwrap_or_default();
+                div().child(Accessible::offered(slot, child))
+            }
+            ModifierKind::SafeAreaPadding => {
+                let insets = environment::safe_area_insets(cx);
+                let container = div()
+                    .pt(px(insets.top))
+                    .pb(px(insets.bottom))
+                    .pl(px(insets.leading))
+                    .pr(px(insets.trailing));
+                expanding(container, self.modified.child_expansion).child(child)
+            }
+            // Negative margins move the view over the padding without
+            // moving the views after it
+            ModifierKind::IgnoresSafeArea(edges) => {
+                let ignored = environment::padded_safe_area(cx).only(edges);
+                let container = div()
+                    .mt(px(-ignored.top))
+                    .mb(px(-ignored.bottom))
+                    .ml(px(-ignored.leading))
+                    .mr(px(-ignored.trailing));
+                expanding(container, self.modified.child_expansion).child(child)
+            }
+            ModifierKind::WindowChrome(_) => {
+                expanding(div(), self.modified.child_expansion).child(child)
+            }
+            ModifierKind::TestId(id) => {
+                let container = expanding(div(), self.modified.child_expansion);
+                testing::record(container, id, child, cx)
+            }
+        };
+
+        let container = debug::track(
+            container,
+            debug_kind,
+            debug_modifier,
+            debug::short_type_name::<V>,
+            cx,
+        );
+        EnvironmentScope::new(container)
+            .foreground(foreground)
+            .text_scale(text_scale)
+            .value(value)
+            .safe_area(safe_area)
+            .accessibility(accessibility)
+    }
+}
+
+/// Whether the mouse button went down on a `.when_pressed()` view and
+/// hasn't been released yet.
+#[derive(Default)]
+struct PointerDown(bool);
+
+impl Global for PointerDown {}
+
+/// Record whether the button is down, restyling pressed views when that
+/// changes.
+fn set_pointer_down(is_down: bool, window: &mut Window, cx: &mut App) {
+    if cx.default_global::<PointerDown>().0 != is_down {
+        cx.set_global(PointerDown(is_down));
+        window.refresh();
+    }
+}
+
+/// Wrap `child` so it records its bounds with the enclosing scroll view as
+/// it prepaints: under `id` for `.scroll_id()`, or as a snap target.
+fn scroll_target(child: AnyElement, id: Option<ElementId>, expansion: Expansion, cx: &App) -> Div {
+    let proxy = environment::scroll_proxy(cx);
+    let container = div().relative().child(child).child(
+        canvas(
+            move |bounds, _, _| match (proxy, id) {
+                (Some(proxy), Some(id)) => proxy.record_target(id, bounds),
+                (Some(proxy), None) => proxy.record_snap_target(bounds),
+                (None, _) => {}
+            },
+            |_, _, _, _| {},
+        )
+        .absolute()
+        .inset_0(),
+    );
+    expanding(container, expansion)
+}
+
+/// Let a wrapper around a child that expands expand the same way, so
+/// wrapping doesn't change whether the view hugs its content.
+fn expanding(mut container: Div, expansion: Expansion) -> Div {
+    if expansion.grows {
+        container = container.flex_grow();
+    }
+    if expansion.fills_width {
+        container = container.w_full();
+    }
+    if expansion.fills_height {
+        container = container.h_full();
+    }
+    container
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    use gpui::{
+        canvas, div, point, px, size, AvailableSpace, Context, Modifiers, MouseButton,
+        MouseDownEvent, MouseUpEvent, ParentElement, Pixels, Point, Render, Styled, TestAppContext,
+    };
+
+    use super::*;
+    use crate::components::Text;
+    use crate::layout::{EmptyView, HStack, Spacer, VStack};
+
+    /// Lay out the view built by `child` in a 300pt-wide HStack followed by a
+    /// marker and, optionally, a Spacer, and return where the marker starts.
+    ///
+    /// Views are built inside the draw, since stacks allocate their children
+    /// in the frame's element arena.
+    fn marker_x<E: IntoElement + 'static>(child: impl FnOnce() -> E, spacer: bool) -> Pixels {
+        let mut cx = TestAppContext::single();
+        let cx = cx.add_empty_window();
+        let marker = Rc::new(Cell::new(None));
+        let recorded = marker.clone();
+        cx.draw(
+            point(px(0.), px(0.)),
+            size(
+                AvailableSpace::Definite(px(300.)),
+                AvailableSpace::Definite(px(100.)),
+            ),
+            |_, _| {
+                div().w(px(300.)).child(
+                    HStack::new()
+                        .spacing(0.0)
+                        .child(child())
+                        .child(
+                            canvas(
+                                move |bounds, _, _| recorded.set(Some(bounds)),
+                                |_, _, _, _| {},
+                            )
+                            .size(px(1.)),
+                        )
+                        .children(spacer.then(Spacer::new)),
+                )
+            },
+        );
+        marker.get().expect("marker is laid out").origin.x
+    }
+
+    #[test]
+    fn padded_text_hugs_its_content_next_to_a_spacer() {
+        let plain = marker_x(|| Text::new("Hello"), true);
+        let padded = marker_x(|| Text::new("Hello").padding(8.0), true);
+
+        assert!(plain > px(0.));
+        assert_eq!(padded, plain + px(16.));
+    }
+
+    #[test]
+    fn padded_spacer_still_grows() {
+        assert_eq!(marker_x(|| Spacer::new().padding(8.0), false), px(299.));
+    }
+
+    #[test]
+    fn padded_fill_frame_still_fills() {
+        let unpadded = marker_x(|| Text::new("Hello").frame(Frame::fill_width()), false);
+        let padded = marker_x(
+            || {
+                Text::new("Hello")
+                    .frame(Frame::fill_width())
+                    .padding(8.0)
+                    .padding(4.0)
+            },
+            false,
+        );
+
+        assert_eq!(unpadded, px(299.));
+        assert_eq!(padded, px(299.));
+    }
+
+    /// Where the marker after a row of three words lands, with the middle
+    /// one modified by `middle`.
+    fn row_width(middle: Option<fn(Text) -> Modified<Text>>) -> Pixels {
+        marker_x(
+            move || {
+                HStack::new()
+                    .spacing(8.0)
+                    .child(Text::new("One"))
+                    .children(middle.map(|middle| middle(Text::new("Two"))))
+                    .child(Text::new("Three"))
+            },
+            false,
+        )
+    }
+
+    #[test]
+    fn hidden_views_keep_their_space() {
+        let visible = row_width(Some(|text| text.hidden(false)));
+        assert_eq!(row_width(Some(|text| text.hidden(true))), visible);
+        assert_eq!(
+            row_width(Some(|text| text.remove_when(true))),
+            row_width(None)
+        );
+        assert_eq!(row_width(Some(|text| text.remove_when(false))), visible);
+    }
+
+    const LONG_TEXT: &str = "The quick brown fox jumps over the lazy dog";
+
+    /// Lay out the view `stack` builds around a marker and return where the
+    /// marker starts.
+    fn marker_origin<E: IntoElement + 'static>(
+        stack: impl FnOnce(AnyElement) -> E,
+    ) -> Point<Pixels> {
+        let mut cx = TestAppContext::single();
+        let cx = cx.add_empty_window();
+        let marker = Rc::new(Cell::new(None));
+        let recorded = marker.clone();
+        cx.draw(
+            point(px(0.), px(0.)),
+            size(
+                AvailableSpace::Definite(px(300.)),
+                AvailableSpace::Definite(px(300.)),
+            ),
+            |_, _| {
+                let marker = canvas(
+                    move |bounds, _, _| recorded.set(Some(bounds)),
+                    |_, _, _, _| {},
+                )
+                .size(px(1.));
+                div().w(px(300.)).child(stack(marker.into_any_element()))
+            },
+        );
+        marker.get().expect("marker is laid out").origin
+    }
+
+    /// Where a marker lands in the views `content` builds, padded clear of
+    /// a 30pt titlebar.
+    fn under_titlebar(content: impl FnOnce(AnyElement) -> VStack) -> Pixels {
+        let titlebar = SafeAreaInsets {
+            top: 30.0,
+            ..SafeAreaInsets::default()
+        };
+        marker_origin(|marker| content(marker).safe_area_padding().environment(titlebar)).y
+    }
+
+    #[test]
+    fn views_ignoring_the_safe_area_reach_under_the_titlebar() {
+        let background = under_titlebar(|marker| {
+            VStack::new().child(
+                VStack::new()
+                    .child(marker)
+                    .ignores_safe_area(EdgeSet::top()),
+            )
+        });
+        let content = under_titlebar(|marker| {
+            VStack::new().child(
+                VStack::new()
+                    .child(marker)
+                    .safe_area_padding()
+                    .ignores_safe_area(EdgeSet::top()),
+            )
+        });
+        let below = under_titlebar(|marker| {
+            VStack::new()
+                .spacing(0.0)
+                .child(
+                    VStack::new()
+                        .child(EmptyView::new().frame_height(10.0))
+                        .safe_area_padding()
+                        .ignores_safe_area(EdgeSet::top()),
+                )
+                .child(marker)
+        });
+
+        assert_eq!(background, px(0.));
+        assert_eq!(content, px(30.));
+        assert_eq!(below, px(40.));
+    }
+
+    /// How far below the top a marker under `text` in a 100pt-wide frame
+    /// lands.
+    fn height_in_frame(text: impl Modifier + IntoElement + 'static) -> Pixels {
+        marker_origin(|marker| {
+            VStack::new()
+                .spacing(0.0)
+                .child(text.frame_width(100.0))
+                .child(marker)
+        })
+        .y
+    }
+
+    #[test]
+    fn horizontally_fixed_text_stays_on_one_line() {
+        let one_line = height_in_frame(Text::new("Fox"));
+        let wrapped = height_in_frame(Text::new(LONG_TEXT));
+        assert!(wrapped > one_line);
+
+        let fixed = height_in_frame(Text::new(LONG_TEXT).fixed_size(true, false));
+        assert_eq!(fixed, one_line);
+    }
+
+    #[test]
+    fn vertically_fixed_text_takes_its_wrapped_height() {
+        let wrapped = height_in_frame(Text::new(LONG_TEXT));
+        let fixed = marker_origin(|marker| {
+            VStack::new()
+                .spacing(0.0)
+                .child(Text::new(LONG_TEXT).fixed_size(false, true))
+                .child(marker)
+                .frame_size(100.0, 30.0)
+        });
+        assert!(wrapped > px(30.));
+        assert_eq!(fixed.y, wrapped);
+    }
+
+    /// Counts the single and double taps on a word.
+    #[derive(Default)]
+    struct TapCounter {
+        taps: Rc<Cell<(u32, u32)>>,
+    }
+
+    impl Render for TapCounter {
+        fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+            let (single, double) = (self.taps.clone(), self.taps.clone());
+            Text::new("Tap")
+                .on_tap_gesture_count("double", 2, move |_, _, _| {
+                    double.set((double.get().0, double.get().1 + 1))
+                })
+                .on_tap_gesture("single", move || {
+                    single.set((single.get().0 + 1, single.get().1))
+                })
+        }
+    }
+
+    #[test]
+    fn double_taps_are_not_single_taps() {
+        let mut cx = TestAppContext::single();
+        let (view, cx) = cx.add_window_view(|_, _| TapCounter::default());
+        let taps = view.read_with(cx, |view, _| view.taps.clone());
+        let position = point(px(5.), px(5.));
+        for click_count in [1, 2] {
+            cx.simulate_event(MouseDownEvent {
+                button: MouseButton::Left,
+                position,
+                modifiers: Modifiers::default(),
+                click_count,
+                first_mouse: false,
+            });
+            cx.simulate_event(MouseUpEvent {
+                button: MouseButton::Left,
+                position,
+                modifiers: Modifiers::default(),
+                click_count,
+            });
         }
+
+        assert_eq!(taps.get(), (1, 1));
+    }
+
+    #[test]
+    fn fixed_frames_stop_expansion() {
+        let fixed = Spacer::new().frame(Frame::fill_width()).frame_width(40.0);
+        assert!(!fixed.expansion().fills_width);
+        assert!(Spacer::new().padding(8.0).expansion().grows);
     }
 }
@@ -8,34 +8,61 @@
 
 // Layout primitives
 pub use crate::layout::{
-    Alignment, EdgeInsets, EdgeInsetsExt, EdgeSet, EmptyView, ForEach, Grid, GridItem,
-    GridItemSize, GridRow, Group, HStack, HorizontalAlignment, If, IfLet, LazyHGrid, LazyHStack,
-    LazyVGrid, LazyVStack, List, ListSectionSpacing, ListStyle, RowConfiguration, ScrollAxes,
-    ScrollView, Section, SectionMargins, Spacer, VStack, VerticalAlignment,
-    VirtualListScrollHandle, ZStack,
+    Alignment, DisclosureGroup, Distribution, EdgeInsets, EdgeInsetsExt, EdgeSet, EditMode,
+    EmptyView, Flow, ForEach, Form, GeometryReader, Grid, GridItem, GridItemSize, GridRow, Group,
+    GroupBox, HStack, HorizontalAlignment, If, IfLet, LazyGridSection, LazyHGrid, LazyHStack,
+    LazyScrollHandleExt, LazyVGrid, LazyVStack, List, ListSectionSpacing, ListStyle,
+    NavigationPath, NavigationStack, OutlineGroup, OutlineState, RefreshCompletion, RowAction,
+    RowConfiguration, RowId, ScrollAnchor, ScrollAxes, ScrollIndicatorStyle, ScrollInfo,
+    ScrollView, ScrollViewProxy, Section, SectionMargins, SelectionMode, SnapBehavior, Spacer,
+    StateHandle, Switch, Toolbar, ToolbarContent, VStack, VerticalAlignment,
+    VirtualListScrollHandle, WithState, ZStack,
 };
 
 // Display components
 pub use crate::components::{
-    Button, ButtonStyle, Divider, Image, Label, Link, ProgressView, ProgressViewStyle, Text,
-    TruncationMode,
+    Avatar, AvatarStack, AvatarStatus, Button, ButtonRole, ButtonStyle, ButtonStyleConfiguration,
+    ButtonStyling, Canvas, Capsule, Circle, ControlSize, CopyButton, Divider, Ellipse, Image,
+    Label, LabelStyle, LabeledContent, Link, Markdown, MarkdownStyle, Menu, MenuItem,
+    NavigationRow, Painter, ProgressView, ProgressViewStyle, Rectangle, RoundedRectangle, Shape,
+    ShapeOutline, Text, TextAlignment, TextCase, TruncationMode,
 };
 
+pub use crate::components::register_symbol_mapping;
+
 // Re-export IconName from gpui-component for Label::with_icon
 pub use gpui_component::IconName;
 
 // Input components
 pub use crate::components::{
-    IndexPath, InputState, Picker, PickerDelegate, PickerEvent, PickerGroup, PickerItem,
-    PickerState, SearchableVec, SecureField, Slider, SliderEvent, SliderState, SliderValue,
-    StepAction, Stepper, StepperEvent, TextEditor, TextField, Toggle,
+    Axis, ColorPicker, ComboBox, IndexPath, InputState, LabelPlacement, NumberField,
+    PasswordStrength, Picker, PickerDelegate, PickerEvent, PickerGroup, PickerItem, PickerState,
+    PickerStyle, SearchableVec, SecureField, Slider, SliderEvent, SliderState, SliderValue,
+    StepAction, Stepper, StepperEvent, TagField, TextEditor, TextField, Toggle, ToggleStyle,
 };
 
 // Modifier trait and types
-pub use crate::modifier::{ContentMode, Frame, Modified, Modifier, Padding, Tappable};
+pub use crate::accessibility::{AccessibilityNode, AccessibilityRole};
+pub use crate::clipboard::{Clipboard, Copyable};
+pub use crate::debug::DebugOverlay;
+pub use crate::drag_drop::{Draggable, DropTarget};
+pub use crate::environment::{
+    ColorSchemeOverride, Environment, ForegroundColor, IsEnabled, SafeAreaInsets, TextScale, Tint,
+};
+pub use crate::focus::FocusContent;
+pub use crate::modifier::{BorderStyle, ContentMode, Frame, Modified, Modifier, Padding, Tappable};
+pub use crate::presentation::{AlertButton, AlertConfig, Presented};
+pub use crate::shortcut::KeyboardShortcut;
+pub use crate::task::TaskContent;
+pub use crate::toast::{Toast, ToastCenter, ToastStyle};
+pub use crate::window::{WindowChrome, WindowConfig};
 
 // Common types
+pub use crate::binding::Binding;
 pub use crate::types::ClickHandler;
 
 // Styling
-pub use crate::style::{Color, Font, FontDesign, FontWeight, SemanticColor};
+pub use crate::style::{
+    Color, ColorPalette, ColorScheme, Font, FontDesign, FontWeight, InteractionStyle,
+    LinearGradient, SemanticColor, ShapeStyle, UnitPoint,
+};
@@ -0,0 +1,640 @@
+//! Modal presentation of sheets, full-screen covers, alerts, confirmation
+//! dialogs and popovers.
+//!
+//! Presented content is drawn deferred, anchored to the window's origin, so
+//! it covers the whole window wherever the presenting view sits and isn't
+//! clipped by scroll containers. The cover takes focus when it appears, so
+//! Escape and Enter reach it.
+
+use std::rc::Rc;
+
+use gpui::{
+    anchored, canvas, deferred, div, point, px, AnchoredPositionMode, AnyElement, App, Bounds, Div,
+    Entity, FocusHandle, FontWeight, InteractiveElement, IntoElement, KeyDownEvent, MouseButton,
+    ParentElement, Pixels, Point, RenderOnce, SharedString, Size, StatefulInteractiveElement,
+    Styled, Window,
+};
+use gpui_component::ActiveTheme;
+
+use crate::alignment::{Alignment, HorizontalAlignment, VerticalAlignment};
+use crate::components::{ButtonRole, Text};
+use crate::modifier::{Expansion, Modifier};
+use crate::style::{Color, Font};
+
+/// Called when the user dismisses presented content, or picks an alert
+/// button.
+pub(crate) type DismissHandler = Rc<dyn Fn(&mut Window, &mut App)>;
+
+/// The widest a sheet grows, in points.
+const SHEET_MAX_WIDTH: f32 = 540.0;
+
+/// The width of an alert, in points.
+const ALERT_WIDTH: f32 = 270.0;
+
+/// The width of a confirmation dialog, in points.
+const DIALOG_WIDTH: f32 = 320.0;
+
+/// The most buttons an alert shows.
+const MAX_ALERT_BUTTONS: usize = 3;
+
+/// The space between a popover and its view, in points.
+const POPOVER_GAP: f32 = 6.0;
+
+/// The closest a popover comes to the window's edges, in points.
+const POPOVER_MARGIN: f32 = 8.0;
+
+/// A button of an alert or confirmation dialog.
+#[derive(Clone)]
+pub struct AlertButton {
+    label: SharedString,
+    role: Option<ButtonRole>,
+    handler: DismissHandler,
+}
+
+impl AlertButton {
+    /// Create a button that calls `handler` when picked.
+    pub fn new(
+        label: impl Into<SharedString>,
+        handler: impl Fn(&mut Window, &mut App) + 'static,
+    ) -> Self {
+        Self {
+            label: label.into(),
+            role: None,
+            handler: Rc::new(handler),
+        }
+    }
+
+    /// Create a button with the cancel role, picked by Escape.
+    pub fn cancel(
+        label: impl Into<SharedString>,
+        handler: impl Fn(&mut Window, &mut App) + 'static,
+    ) -> Self {
+        Self::new(label, handler).role(ButtonRole::Cancel)
+    }
+
+    /// Create a button with the destructive role, labeled in red.
+    pub fn destructive(
+        label: impl Into<SharedString>,
+        handler: impl Fn(&mut Window, &mut App) + 'static,
+    ) -> Self {
+        Self::new(label, handler).role(ButtonRole::Destructive)
+    }
+
+    /// Set the button's role.
+    pub fn role(mut self, role: ButtonRole) -> Self {
+        self.role = Some(role);
+        self
+    }
+}
+
+/// The title, message and buttons of an alert or confirmation dialog.
+///
+/// Picking a button calls its handler, which should stop presenting the
+/// alert. Escape picks the cancel button, and Enter the first button
+/// without a role.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// AlertConfig::new("Delete Photo?")
+///     .message("This can't be undone.")
+///     .button(AlertButton::destructive("Delete", delete))
+///     .button(AlertButton::cancel("Cancel", close))
+/// ```
+#[derive(Clone)]
+pub struct AlertConfig {
+    title: SharedString,
+    message: Option<SharedString>,
+    buttons: Vec<AlertButton>,
+}
+
+impl AlertConfig {
+    /// Create an alert titled `title`, without buttons yet.
+    pub fn new(title: impl Into<SharedString>) -> Self {
+        Self {
+            title: title.into(),
+            message: None,
+            buttons: Vec::new(),
+        }
+    }
+
+    /// Show `message` below the title.
+    pub fn message(mut self, message: impl Into<SharedString>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    /// Add a button. Alerts show the first three.
+    pub fn button(mut self, button: AlertButton) -> Self {
+        self.buttons.push(button);
+        self
+    }
+
+    /// The button Escape picks.
+    fn cancel_button(&self) -> Option<&AlertButton> {
+        self.buttons
+            .iter()
+            .find(|button| button.role == Some(ButtonRole::Cancel))
+    }
+
+    /// The button Enter picks.
+    fn default_button(&self) -> Option<&AlertButton> {
+        self.buttons.iter().find(|button| button.role.is_none())
+    }
+}
+
+/// What is presented, and how it covers the window.
+pub(crate) enum Presentation {
+    /// A card centered over a dimmed window.
+    Sheet(AnyElement),
+    /// Content filling the whole window.
+    FullScreenCover(AnyElement),
+    /// A narrow card with a title, message and buttons.
+    Alert(AlertConfig),
+    /// A list of options with Cancel below them.
+    ConfirmationDialog(AlertConfig),
+    /// A floating panel beside the presenting view, on the side `attachment`
+    /// names.
+    Popover {
+        content: AnyElement,
+        attachment: Alignment,
+    },
+}
+
+/// A view that presents modal content over the window while asked to.
+///
+/// Created by [`Modifier::sheet`], [`Modifier::full_screen_cover`],
+/// [`Modifier::alert`], [`Modifier::confirmation_dialog`] and
+/// [`Modifier::popover`].
+pub struct Presented<V> {
+    pub(crate) child: V,
+    pub(crate) child_expansion: Expansion,
+    /// Only set while presented.
+    pub(crate) presentation: Option<Presentation>,
+    pub(crate) on_dismiss: Option<DismissHandler>,
+    pub(crate) dismiss_disabled: bool,
+}
+
+impl<V> Presented<V> {
+    /// Keep the content presented when the user clicks outside it or
+    /// presses Escape, until the app stops presenting it.
+    pub fn interactive_dismiss_disabled(mut self, disabled: bool) -> Self {
+        self.dismiss_disabled = disabled;
+        self
+    }
+}
+
+impl<V> Modifier for Presented<V> {
+    fn expansion(&self) -> Expansion {
+        self.child_expansion
+    }
+}
+
+impl<V: IntoElement + 'static> IntoElement for Presented<V> {
+    type Element = AnyElement;
+
+    fn into_element(self) -> Self::Element {
+        PresentedElement { presented: self }.into_any_element()
+    }
+}
+
+#[derive(IntoElement)]
+struct PresentedElement<V: IntoElement + 'static> {
+    presented: Presented<V>,
+}
+
+/// Kept while content is presented.
+struct PresentationState {
+    focus: FocusHandle,
+    focused: bool,
+    /// Where the presenting view was last drawn, for popovers.
+    view_bounds: Option<Bounds<Pixels>>,
+    /// The size of the popover when last drawn.
+    panel_size: Option<Size<Pixels>>,
+}
+
+impl<V: IntoElement + 'static> RenderOnce for PresentedElement<V> {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let presented = self.presented;
+        let expansion = presented.child_expansion;
+        let mut container = div().child(presented.child);
+        if expansion.grows {
+            container = container.flex_grow();
+        }
+        if expansion.fills_width {
+            container = container.w_full();
+        }
+        if expansion.fills_height {
+            container = container.h_full();
+        }
+        let Some(presentation) = presented.presentation else {
+            return container;
+        };
+
+        // Focus the cover once as it appears; the state goes when it does
+        let state = window.use_state(cx, |_, cx| PresentationState {
+            focus: cx.focus_handle(),
+            focused: false,
+            view_bounds: None,
+            panel_size: None,
+        });
+        let (focus, appeared) = state.update(cx, |state, _| {
+            let appeared = !state.focused;
+            state.focused = true;
+            (state.focus.clone(), appeared)
+        });
+        if appeared {
+            window.focus(&focus);
+        }
+
+        let dismiss = presented.on_dismiss.filter(|_| !presented.dismiss_disabled);
+        let (on_escape, on_enter, on_scrim) = match &presentation {
+            Presentation::Sheet(_) | Presentation::Popover { .. } => {
+                (dismiss.clone(), None, dismiss)
+            }
+            Presentation::FullScreenCover(_) => (dismiss, None, None),
+            Presentation::Alert(config) | Presentation::ConfirmationDialog(config) => {
+                let cancel = config.cancel_button().map(|button| button.handler.clone());
+                let default = config.default_button().map(|button| button.handler.clone());
+                // Clicking outside a dialog cancels it, but not an alert
+                let on_scrim = match presentation {
+                    Presentation::ConfirmationDialog(_) => cancel.clone(),
+                    _ => None,
+                };
+                (cancel, default, on_scrim)
+            }
+        };
+
+        let viewport = window.viewport_size();
+        let cover = div()
+            .id("presentation")
+            .track_focus(&focus)
+            .occlude()
+            .w(viewport.width)
+            .h(viewport.height)
+            .flex()
+            .flex_col()
+            .on_key_down(move |event: &KeyDownEvent, window, cx| {
+                let handler = match event.keystroke.key.as_str() {
+                    "escape" => &on_escape,
+                    "enter" => &on_enter,
+                    _ => return,
+                };
+                if let Some(handler) = handler {
+                    cx.stop_propagation();
+                    handler(window, cx);
+                }
+            });
+        let cover = match presentation {
+            Presentation::FullScreenCover(content) => cover
+                .bg(Color::system_background().resolve_in(cx))
+                .child(content),
+            Presentation::Popover {
+                content,
+                attachment,
+            } => {
+                container = container.relative().child(measure(&state, |state, bounds| {
+                    state.view_bounds.replace(bounds) != Some(bounds)
+                }));
+                let panel = popover_panel(content, attachment, &state, viewport, cx);
+                cover
+                    .on_mouse_down(MouseButton::Left, move |_, window, cx| {
+                        if let Some(on_scrim) = &on_scrim {
+                            on_scrim(window, cx);
+                        }
+                    })
+                    .child(panel)
+            }
+            presentation => {
+                let card = match presentation {
+                    Presentation::Alert(config) => alert(config, cx),
+                    Presentation::ConfirmationDialog(config) => confirmation_dialog(config, cx),
+                    Presentation::Sheet(content)
+                    | Presentation::FullScreenCover(content)
+                    | Presentation::Popover { content, .. } => div()
+                        .id("sheet")
+                        .flex()
+                        .flex_col()
+                        .w_full()
+                        .max_w(px(SHEET_MAX_WIDTH))
+                        .max_h_full()
+                        .overflow_y_scroll()
+                        .rounded(px(12.0))
+                        .shadow_lg()
+                        .bg(Color::system_background().resolve_in(cx))
+                        .child(content)
+                        .into_any_element(),
+                };
+                cover
+                    .items_center()
+                    .justify_center()
+                    .p(px(24.0))
+                    .bg(Color::black().opacity(0.4).resolve_in(cx))
+                    .on_mouse_down(MouseButton::Left, move |_, window, cx| {
+                        if let Some(on_scrim) = &on_scrim {
+                            on_scrim(window, cx);
+                        }
+                    })
+                    .child(
+                        // Clicks on the card don't reach the scrim
+                        div()
+                            .on_mouse_down(MouseButton::Left, |_, _, cx| cx.stop_propagation())
+                            .child(card),
+                    )
+            }
+        };
+
+        container.child(deferred(
+            anchored()
+                .position_mode(AnchoredPositionMode::Window)
+                .position(point(px(0.0), px(0.0)))
+                .child(cover),
+        ))
+    }
+}
+
+/// A popover's panel, placed beside the presenting view once both have been
+/// measured, and hidden until then.
+fn popover_panel(
+    content: AnyElement,
+    attachment: Alignment,
+    state: &Entity<PresentationState>,
+    viewport: Size<Pixels>,
+    cx: &App,
+) -> Div {
+    let (view_bounds, panel_size) = {
+        let state = state.read(cx);
+        (state.view_bounds, state.panel_size)
+    };
+    let panel = div()
+        .absolute()
+        .rounded(px(12.0))
+        .shadow_lg()
+        .border_1()
+        .border_color(Color::separator().resolve_in(cx))
+        .bg(Color::system_background().resolve_in(cx))
+        // Clicks in the panel don't reach the scrim
+        .on_mouse_down(MouseButton::Left, |_, _, cx| cx.stop_propagation())
+        .child(content)
+        .child(measure(state, |state, bounds| {
+            state.panel_size.replace(bounds.size) != Some(bounds.size)
+        }));
+    match view_bounds.zip(panel_size) {
+        Some((view, size)) => {
+            let origin = popover_origin(attachment, view, size, viewport);
+            panel.left(origin.x).top(origin.y)
+        }
+        None => panel.opacity(0.0),
+    }
+}
+
+/// Record the bounds an element is drawn at with `update`, drawing again
+/// when it reports a change.
+fn measure(
+    state: &Entity<PresentationState>,
+    update: fn(&mut PresentationState, Bounds<Pixels>) -> bool,
+) -> impl IntoElement {
+    let state = state.clone();
+    canvas(
+        move |bounds, window, cx| {
+            if state.update(cx, |state, _| update(state, bounds)) {
+                window.request_animation_frame();
+            }
+        },
+        |_, _, _, _| {},
+    )
+    .absolute()
+    .size_full()
+}
+
+/// Where a popover of `size` goes beside `view`, on the side `attachment`
+/// names, flipped to the opposite side when it doesn't fit in `viewport`
+/// and kept inside its edges.
+///
+/// Above and below the view, the popover lines up with the view's leading
+/// edge, center or trailing edge. Without a vertical side it goes to the
+/// leading or trailing side, centered vertically, or below when centered.
+fn popover_origin(
+    attachment: Alignment,
+    view: Bounds<Pixels>,
+    size: Size<Pixels>,
+    viewport: Size<Pixels>,
+) -> Point<Pixels> {
+    let gap = px(POPOVER_GAP);
+    let margin = px(POPOVER_MARGIN);
+    let center = view.center();
+
+    let above = view.top() - gap - size.height;
+    let below = view.bottom() + gap;
+    let before = view.left() - gap - size.width;
+    let after = view.right() + gap;
+    let fits_above = above >= margin;
+    let fits_below = below + size.height <= viewport.height - margin;
+    let fits_before = before >= margin;
+    let fits_after = after + size.width <= viewport.width - margin;
+
+    let aligned_x = match attachment.horizontal {
+        HorizontalAlignment::Leading => view.left(),
+        HorizontalAlignment::Center => center.x - size.width * 0.5,
+        HorizontalAlignment::Trailing => view.right() - size.width,
+    };
+    let centered_y = center.y - size.height * 0.5;
+    let (x, y) = match (attachment.vertical, attachment.horizontal) {
+        (VerticalAlignment::Top, _) if fits_above || !fits_below => (aligned_x, above),
+        (VerticalAlignment::Top, _) => (aligned_x, below),
+        (VerticalAlignment::Center, HorizontalAlignment::Leading) if fits_before || !fits_after => {
+            (before, centered_y)
+        }
+        (VerticalAlignment::Center, HorizontalAlignment::Leading) => (after, centered_y),
+        (VerticalAlignment::Center, HorizontalAlignment::Trailing)
+            if fits_after || !fits_before =>
+        {
+            (after, centered_y)
+        }
+        (VerticalAlignment::Center, HorizontalAlignment::Trailing) => (before, centered_y),
+        _ if fits_below || !fits_above => (aligned_x, below),
+        _ => (aligned_x, above),
+    };
+
+    // Slide along the window's edges rather than leave it
+    let x = x.min(viewport.width - margin - size.width).max(margin);
+    let y = y.min(viewport.height - margin - size.height).max(margin);
+    point(x, y)
+}
+
+/// A narrow alert card: title and message above its buttons, side by side
+/// for two and stacked otherwise.
+fn alert(config: AlertConfig, cx: &App) -> AnyElement {
+    let separator = Color::separator().resolve_in(cx);
+    let mut buttons = config.buttons;
+    buttons.truncate(MAX_ALERT_BUTTONS);
+    let side_by_side = buttons.len() == 2;
+
+    let buttons = buttons.into_iter().enumerate().map(|(ix, button)| {
+        let row = alert_button(ix, button, cx).flex_1().h(px(44.0));
+        match (side_by_side, ix) {
+            (true, 1) => row.border_l_1().border_color(separator),
+            _ => row,
+        }
+    });
+    let mut button_area = div().flex().border_t_1().border_color(separator);
+    button_area = if side_by_side {
+        button_area.flex_row().children(buttons)
+    } else {
+        // Each stacked button is divided from the one above
+        button_area
+            .flex_col()
+            .children(buttons.enumerate().map(|(ix, row)| {
+                if ix > 0 {
+                    row.border_t_1().border_color(separator)
+                } else {
+                    row
+                }
+            }))
+    };
+
+    div()
+        .flex()
+        .flex_col()
+        .w(px(ALERT_WIDTH))
+        .rounded(px(14.0))
+        .overflow_hidden()
+        .shadow_lg()
+        .bg(Color::secondary_system_background().resolve_in(cx))
+        .child(alert_text(&config.title, config.message.as_ref()).p(px(16.0)))
+        .child(button_area)
+        .into_any_element()
+}
+
+/// A dialog listing its options, with Cancel in a card of its own below.
+fn confirmation_dialog(config: AlertConfig, cx: &App) -> AnyElement {
+    let separator = Color::separator().resolve_in(cx);
+    let background = Color::secondary_system_background().resolve_in(cx);
+    let (cancel, options): (Vec<_>, Vec<_>) = config
+        .buttons
+        .into_iter()
+        .enumerate()
+        .partition(|(_, button)| button.role == Some(ButtonRole::Cancel));
+
+    let card = || {
+        div()
+            .flex()
+            .flex_col()
+            .w_full()
+            .rounded(px(14.0))
+            .overflow_hidden()
+            .bg(background)
+    };
+    let options = card()
+        .child(alert_text(&config.title, config.message.as_ref()).p(px(12.0)))
+        .children(options.into_iter().map(|(ix, button)| {
+            alert_button(ix, button, cx)
+                .h(px(52.0))
+                .border_t_1()
+                .border_color(separator)
+        }));
+    let cancel = cancel
+        .into_iter()
+        .next()
+        .map(|(ix, button)| card().child(alert_button(ix, button, cx).h(px(52.0))));
+
+    div()
+        .flex()
+        .flex_col()
+        .gap(px(8.0))
+        .w(px(DIALOG_WIDTH))
+        .child(options)
+        .children(cancel)
+        .into_any_element()
+}
+
+/// The centered title and message of an alert.
+fn alert_text(title: &SharedString, message: Option<&SharedString>) -> Div {
+    div()
+        .flex()
+        .flex_col()
+        .items_center()
+        .gap(px(4.0))
+        .text_center()
+        .child(Text::new(title.clone()).font(Font::headline()))
+        .children(message.map(|message| {
+            Text::new(message.clone())
+                .font(Font::footnote())
+                .foreground_color(Color::secondary_label())
+        }))
+}
+
+/// A full-width button of an alert, colored by its role.
+fn alert_button(ix: usize, button: AlertButton, cx: &App) -> gpui::Stateful<Div> {
+    let color = match button.role {
+        Some(ButtonRole::Destructive) => Color::red(),
+        _ => Color::accent(),
+    };
+    let pressed = cx.theme().list_active;
+    let handler = button.handler;
+    let mut row = div()
+        .id(("alert-button", ix))
+        .flex()
+        .items_center()
+        .justify_center()
+        .px(px(8.0))
+        .cursor_pointer()
+        .text_color(color.resolve_in(cx))
+        .active(move |row| row.bg(pressed))
+        .child(button.label)
+        .on_click(move |_, window, cx| handler(window, cx));
+    if button.role == Some(ButtonRole::Cancel) {
+        row = row.font_weight(FontWeight::SEMIBOLD);
+    }
+    row
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gpui::size;
+
+    #[test]
+    fn popover_flips_when_it_does_not_fit() {
+        let viewport = size(px(400.0), px(300.0));
+        let panel = size(px(100.0), px(80.0));
+        let view = Bounds::new(point(px(150.0), px(100.0)), size(px(60.0), px(20.0)));
+        assert_eq!(
+            popover_origin(Alignment::bottom(), view, panel, viewport),
+            point(px(130.0), px(126.0))
+        );
+        assert_eq!(
+            popover_origin(Alignment::top_leading(), view, panel, viewport),
+            point(px(150.0), px(14.0))
+        );
+
+        // Near the bottom edge, below flips to above
+        let low = Bounds::new(point(px(150.0), px(250.0)), size(px(60.0), px(20.0)));
+        assert_eq!(
+            popover_origin(Alignment::bottom(), low, panel, viewport),
+            point(px(130.0), px(164.0))
+        );
+
+        // Near the left edge, leading flips to trailing
+        let left = Bounds::new(point(px(10.0), px(100.0)), size(px(60.0), px(20.0)));
+        assert_eq!(
+            popover_origin(Alignment::leading(), left, panel, viewport),
+            point(px(76.0), px(70.0))
+        );
+    }
+
+    #[test]
+    fn keys_pick_the_cancel_and_default_buttons() {
+        let config = AlertConfig::new("Delete?")
+            .button(AlertButton::destructive("Delete", |_, _| {}))
+            .button(AlertButton::new("Archive", |_, _| {}))
+            .button(AlertButton::cancel("Cancel", |_, _| {}));
+        assert_eq!(config.cancel_button().unwrap().label, "Cancel");
+        assert_eq!(config.default_button().unwrap().label, "Archive");
+
+        let config =
+            AlertConfig::new("Delete?").button(AlertButton::destructive("Delete", |_, _| {}));
+        assert!(config.cancel_button().is_none());
+        assert!(config.default_button().is_none());
+    }
+}
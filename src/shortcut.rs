@@ -0,0 +1,193 @@
+//! Keyboard shortcuts - keys that act like clicking a control.
+//!
+//! `.keyboard_shortcut()` hands its keystroke to the first button or tap
+//! gesture rendered inside it. Controls bind their shortcuts while they're
+//! drawn, so a shortcut stops working once its control is no longer shown.
+
+use std::cell::RefCell;
+use std::rc::{Rc, Weak};
+
+use gpui::{
+    AnyElement, AnyWindowHandle, App, ElementId, Global, IntoElement, Keystroke, RenderOnce,
+    Subscription, Window,
+};
+
+use crate::environment::{self, EnvironmentScope};
+use crate::modifier::{Expansion, Modifier};
+
+/// Called when a bound keystroke is pressed.
+pub(crate) type ShortcutAction = Rc<dyn Fn(&mut Window, &mut App)>;
+
+/// Where a [`KeyboardShortcut`] hands its keystroke to the first control
+/// rendered inside it.
+pub(crate) type ShortcutSlot = Rc<RefCell<Option<Keystroke>>>;
+
+/// A keystroke bound by a control, kept alive while the control is drawn.
+#[derive(Clone)]
+struct Registration {
+    window: AnyWindowHandle,
+    keystroke: Keystroke,
+    action: ShortcutAction,
+}
+
+/// The shortcuts bound in every window, and the interceptor that runs them.
+#[derive(Default)]
+struct Shortcuts {
+    registrations: Vec<Weak<RefCell<Registration>>>,
+    interceptor: Option<Subscription>,
+}
+
+impl Global for Shortcuts {}
+
+/// A view whose button or tap gesture is clicked by a keystroke, created by
+/// [`Modifier::keyboard_shortcut`].
+pub struct KeyboardShortcut<V> {
+    pub(crate) child: V,
+    pub(crate) child_expansion: Expansion,
+    pub(crate) keystroke: Option<Keystroke>,
+}
+
+impl<V> Modifier for KeyboardShortcut<V> {
+    fn expansion(&self) -> Expansion {
+        self.child_expansion
+    }
+}
+
+impl<V: IntoElement + 'static> IntoElement for KeyboardShortcut<V> {
+    type Element = AnyElement;
+
+    fn into_element(self) -> Self::Element {
+        KeyboardShortcutElement { shortcut: self }.into_any_element()
+    }
+}
+
+#[derive(IntoElement)]
+struct KeyboardShortcutElement<V: IntoElement + 'static> {
+    shortcut: KeyboardShortcut<V>,
+}
+
+impl<V: IntoElement + 'static> RenderOnce for KeyboardShortcutElement<V> {
+    fn render(self, _window: &mut Window, _cx: &mut App) -> impl IntoElement {
+        let KeyboardShortcut {
+            child, keystroke, ..
+        } = self.shortcut;
+        let slot = keystroke.map(|keystroke| Rc::new(RefCell::new(Some(keystroke))));
+        EnvironmentScope::new(child).keyboard_shortcut(slot)
+    }
+}
+
+/// Parse a keystroke like `"cmd-s"`, warning in debug builds when it's
+/// not one.
+pub(crate) fn parse(source: &str) -> Option<Keystroke> {
+    match Keystroke::parse(source) {
+        Ok(keystroke) => Some(keystroke),
+        Err(error) => {
+            if cfg!(debug_assertions) {
+                log::warn!("ignoring keyboard shortcut: {error}");
+            }
+            None
+        }
+    }
+}
+
+/// Take the keystroke of the nearest enclosing `.keyboard_shortcut()`, if
+/// no control inside it has taken it yet.
+pub(crate) fn take(cx: &App) -> Option<Keystroke> {
+    environment::keyboard_shortcut_slot(cx).and_then(|slot| slot.borrow_mut().take())
+}
+
+/// Run `action` when `keystroke` is pressed in this window, until the
+/// caller stops calling this on each render.
+///
+/// `key` identifies the binding among the caller's siblings, like the key
+/// of `use_keyed_state`.
+pub(crate) fn register(
+    key: impl Into<ElementId>,
+    keystroke: Keystroke,
+    action: ShortcutAction,
+    window: &mut Window,
+    cx: &mut App,
+) {
+    let registration = Registration {
+        window: window.window_handle(),
+        keystroke,
+        action,
+    };
+    let entry = window.use_keyed_state(key, cx, {
+        let registration = registration.clone();
+        move |_, _| Rc::new(RefCell::new(registration))
+    });
+    // Keep the latest action, which captures this render's values
+    let entry = entry.read(cx).clone();
+    *entry.borrow_mut() = registration;
+
+    if cx
+        .try_global::<Shortcuts>()
+        .map_or(true, |shortcuts| shortcuts.interceptor.is_none())
+    {
+        let interceptor = cx.intercept_keystrokes(|event, window, cx| {
+            let Some(shortcuts) = cx.try_global::<Shortcuts>() else {
+                return;
+            };
+            let handle = window.window_handle();
+            let actions: Vec<ShortcutAction> = shortcuts
+                .registrations
+                .iter()
+                .filter_map(Weak::upgrade)
+                .filter(|entry| {
+                    let entry = entry.borrow();
+                    entry.window == handle && matches(&entry.keystroke, &event.keystroke)
+                })
+                .map(|entry| entry.borrow().action.clone())
+                .collect();
+            let Some(action) = actions.first() else {
+                return;
+            };
+            if actions.len() > 1 && cfg!(debug_assertions) {
+                log::warn!(
+                    "{} controls share the keyboard shortcut {}; only the first runs",
+                    actions.len(),
+                    event.keystroke
+                );
+            }
+            cx.stop_propagation();
+            action(window, cx);
+        });
+        cx.default_global::<Shortcuts>().interceptor = Some(interceptor);
+    }
+
+    let shortcuts = cx.global_mut::<Shortcuts>();
+    shortcuts
+        .registrations
+        .retain(|registration| registration.strong_count() > 0);
+    let entry = Rc::downgrade(&entry);
+    if !shortcuts
+        .registrations
+        .iter()
+        .any(|registration| registration.ptr_eq(&entry))
+    {
+        shortcuts.registrations.push(entry);
+    }
+}
+
+/// Whether `typed` presses the keys of `shortcut`.
+fn matches(shortcut: &Keystroke, typed: &Keystroke) -> bool {
+    typed.modifiers == shortcut.modifiers && typed.key.eq_ignore_ascii_case(&shortcut.key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shortcuts_match_their_exact_modifiers() {
+        let save = parse("cmd-s").unwrap();
+        assert!(matches(&save, &Keystroke::parse("cmd-s").unwrap()));
+        assert!(!matches(&save, &Keystroke::parse("s").unwrap()));
+        assert!(!matches(&save, &Keystroke::parse("cmd-shift-s").unwrap()));
+        assert!(matches(
+            &parse("escape").unwrap(),
+            &Keystroke::parse("escape").unwrap()
+        ));
+    }
+}
@@ -4,7 +4,13 @@
 //! Semantic colors like `Color::label()` automatically resolve to the correct value
 //! based on the current theme mode at render time.
 
-use gpui::Hsla;
+use std::sync::Arc;
+
+use gpui::{App, Hsla, Rgba, SharedString};
+use gpui_component::{ActiveTheme, Theme};
+
+use super::palette::ColorPalette;
+use crate::environment;
 
 /// Semantic color variants that adapt to light/dark mode.
 ///
@@ -82,11 +88,20 @@ impl SemanticColor {
     }
 }
 
+/// Whether views appear light or dark, like SwiftUI's `ColorScheme`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColorScheme {
+    Light,
+    Dark,
+}
+
 /// A color value that can be used for backgrounds, foregrounds, borders, etc.
 ///
 /// Colors can be either:
 /// - **Static**: A specific HSLA value that doesn't change
 /// - **Semantic**: An adaptive color that resolves differently in light/dark mode
+/// - **Adaptive**: An app-defined light/dark pair, either inline via
+///   [`Color::adaptive`] or registered by name in the [`ColorPalette`]
 ///
 /// # Example
 ///
@@ -100,12 +115,147 @@ impl SemanticColor {
 /// // Resolve semantic color at render time
 /// let resolved = label.resolve(is_dark_mode);
 /// ```
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Color {
     /// The underlying GPUI color representation (for static colors or default).
     pub(crate) hsla: Hsla,
     /// If Some, this is a semantic color that adapts to dark mode.
     pub(crate) semantic: Option<SemanticColor>,
+    /// If Some, this is an app-defined adaptive color.
+    pub(crate) adaptive: Option<AdaptiveColor>,
+    /// If Some, a transform applied after resolving a semantic color.
+    pub(crate) transform: Option<ColorTransform>,
+    /// Alpha adjustment applied on resolve, so semantic colors keep adapting.
+    pub(crate) alpha: Alpha,
+}
+
+/// How a color's alpha is adjusted once it is resolved.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum Alpha {
+    /// Multiply the resolved alpha.
+    Multiply(f32),
+    /// Replace the resolved alpha.
+    Set(f32),
+}
+
+impl Alpha {
+    fn apply(self, alpha: f32) -> f32 {
+        match self {
+            Alpha::Multiply(factor) => alpha * factor,
+            Alpha::Set(alpha) => alpha,
+        }
+    }
+}
+
+/// App-defined adaptive color variants.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum AdaptiveColor {
+    /// Inline light/dark pair; the light variant is stored in `Color::hsla`.
+    Pair { dark: Hsla },
+    /// Name looked up in the [`ColorPalette`] at resolve time.
+    Named(SharedString),
+    /// Color read from the active gpui-component theme by [`Color::resolve_in`].
+    Theme(ThemeKey),
+    /// Another color, resolved first; used to chain derived transforms.
+    Derived(Arc<Color>),
+}
+
+/// Colors that Allui reads from the active gpui-component [`Theme`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum ThemeKey {
+    Accent,
+    AccentForeground,
+    Destructive,
+    Muted,
+    MutedForeground,
+    SelectionBackground,
+    Border,
+    FocusRing,
+}
+
+impl ThemeKey {
+    fn resolve(self, theme: &Theme) -> Hsla {
+        match self {
+            ThemeKey::Accent => theme.primary,
+            ThemeKey::AccentForeground => theme.primary_foreground,
+            ThemeKey::Destructive => theme.danger,
+            ThemeKey::Muted => theme.muted,
+            ThemeKey::MutedForeground => theme.muted_foreground,
+            ThemeKey::SelectionBackground => theme.selection,
+            ThemeKey::Border => theme.border,
+            ThemeKey::FocusRing => theme.ring,
+        }
+    }
+}
+
+/// A derived-color transform, applied after the base color is resolved.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum ColorTransform {
+    Lighten(f32),
+    Darken(f32),
+    /// Mix towards another color, resolved alongside the base.
+    Mix {
+        other: Arc<Color>,
+        amount: f32,
+    },
+}
+
+impl ColorTransform {
+    fn apply(
+        &self,
+        color: Hsla,
+        dark_mode: bool,
+        theme: Option<&Theme>,
+        palette: Option<&ColorPalette>,
+    ) -> Hsla {
+        match self {
+            &ColorTransform::Lighten(amount) => Hsla {
+                l: (color.l + amount).clamp(0.0, 1.0),
+                ..color
+            },
+            &ColorTransform::Darken(amount) => Hsla {
+                l: (color.l - amount).clamp(0.0, 1.0),
+                ..color
+            },
+            ColorTransform::Mix { other, amount } => mix_hsla(
+                color,
+                other.resolve_with(dark_mode, theme, palette),
+                *amount,
+            ),
+        }
+    }
+
+    /// Whether the transform differs between light and dark mode.
+    fn is_adaptive(&self) -> bool {
+        matches!(self, ColorTransform::Mix { other, .. } if other.is_semantic())
+    }
+}
+
+/// Linearly interpolate two colors in RGB space.
+fn mix_hsla(from: Hsla, to: Hsla, amount: f32) -> Hsla {
+    let t = amount.clamp(0.0, 1.0);
+    let (from, to) = (from.to_rgb(), to.to_rgb());
+    let lerp = |a: f32, b: f32| a + (b - a) * t;
+    Rgba {
+        r: lerp(from.r, to.r),
+        g: lerp(from.g, to.g),
+        b: lerp(from.b, to.b),
+        a: lerp(from.a, to.a),
+    }
+    .into()
+}
+
+/// WCAG relative luminance of a color.
+fn relative_luminance(color: Hsla) -> f32 {
+    let rgb = color.to_rgb();
+    let channel = |c: f32| {
+        if c <= 0.03928 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    0.2126 * channel(rgb.r) + 0.7152 * channel(rgb.g) + 0.0722 * channel(rgb.b)
 }
 
 impl Color {
@@ -129,6 +279,9 @@ impl Color {
             )
             .into(),
             semantic: None,
+            adaptive: None,
+            transform: None,
+            alpha: Alpha::Multiply(1.0),
         }
     }
 
@@ -137,6 +290,9 @@ impl Color {
         Self {
             hsla: gpui::rgb(hex).into(),
             semantic: None,
+            adaptive: None,
+            transform: None,
+            alpha: Alpha::Multiply(1.0),
         }
     }
 
@@ -164,27 +320,189 @@ impl Color {
         Self {
             hsla: Hsla { h, s, l, a },
             semantic: None,
+            adaptive: None,
+            transform: None,
+            alpha: Alpha::Multiply(1.0),
         }
     }
 
-    /// Modify the alpha/opacity of this color.
+    /// Create a color with separate light and dark mode variants.
     ///
-    /// Note: This converts semantic colors to static colors with the opacity applied.
+    /// ```rust,ignore
+    /// let surface = Color::adaptive(Color::hex(0xF2F0FF), Color::hex(0x1E1B2E));
+    /// ```
+    pub fn adaptive(light: impl Into<Color>, dark: impl Into<Color>) -> Self {
+        Self {
+            hsla: light.into().resolve(false),
+            semantic: None,
+            adaptive: Some(AdaptiveColor::Pair {
+                dark: dark.into().resolve(true),
+            }),
+            transform: None,
+            alpha: Alpha::Multiply(1.0),
+        }
+    }
+
+    /// Reference a color registered with [`ColorPalette::set`].
+    ///
+    /// The name is looked up by [`Color::resolve_in`] at render time, so it
+    /// may be registered or replaced after this color is created.
+    /// Unregistered names, and [`Color::resolve`] without an app, resolve to
+    /// a transparent color.
+    pub fn named(name: impl Into<SharedString>) -> Self {
+        Self {
+            hsla: Hsla::transparent_black(),
+            semantic: None,
+            adaptive: Some(AdaptiveColor::Named(name.into())),
+            transform: None,
+            alpha: Alpha::Multiply(1.0),
+        }
+    }
+
+    /// Set the alpha/opacity of this color, replacing its own alpha.
+    ///
+    /// The alpha is applied when the color is resolved, so semantic colors
+    /// stay adaptive: `Color::label().opacity(0.5)` is translucent black in
+    /// light mode and translucent white in dark mode. Use
+    /// [`Color::multiply_opacity`] to fade a color that may already be
+    /// translucent.
     pub fn opacity(self, alpha: f32) -> Self {
-        // Resolve semantic first, then apply opacity
-        let base_hsla = if let Some(semantic) = self.semantic {
-            // Use light mode as base when opacity is applied
-            semantic.resolve(false)
-        } else {
-            self.hsla
+        Self {
+            alpha: Alpha::Set(alpha.clamp(0.0, 1.0)),
+            ..self
+        }
+    }
+
+    /// Multiply the alpha/opacity of this color by `factor`, keeping any
+    /// translucency it already has: `Color::separator().multiply_opacity(0.5)`
+    /// is half as opaque as the separator.
+    pub fn multiply_opacity(self, factor: f32) -> Self {
+        let factor = factor.clamp(0.0, 1.0);
+        let alpha = match self.alpha {
+            Alpha::Multiply(alpha) => Alpha::Multiply(alpha * factor),
+            Alpha::Set(alpha) => Alpha::Set(alpha * factor),
         };
+        Self { alpha, ..self }
+    }
 
+    /// Internal: Create a theme-backed color with a fallback for `resolve()`.
+    fn themed(key: ThemeKey, fallback: Color) -> Self {
         Self {
-            hsla: Hsla {
-                a: alpha,
-                ..base_hsla
-            },
-            semantic: None, // Opacity converts to static
+            adaptive: Some(AdaptiveColor::Theme(key)),
+            ..fallback
+        }
+    }
+
+    // ========================================================================
+    // Theme colors (read from the gpui-component theme)
+    // ========================================================================
+
+    /// The app's accent color (the theme's primary color).
+    ///
+    /// Theme colors are read from `cx.theme()` by [`Color::resolve_in`];
+    /// [`Color::resolve`] has no theme access and returns an iOS fallback.
+    pub fn accent() -> Self {
+        Self::themed(ThemeKey::Accent, Self::blue())
+    }
+
+    /// Foreground color for content drawn on top of [`Color::accent`].
+    pub fn accent_foreground() -> Self {
+        Self::themed(ThemeKey::AccentForeground, Self::white())
+    }
+
+    /// Color for destructive actions (the theme's danger color).
+    pub fn destructive() -> Self {
+        Self::themed(ThemeKey::Destructive, Self::red())
+    }
+
+    /// Subtle background for de-emphasized content.
+    pub fn muted() -> Self {
+        Self::themed(ThemeKey::Muted, Self::secondary_system_background())
+    }
+
+    /// Text color for de-emphasized content.
+    pub fn muted_foreground() -> Self {
+        Self::themed(ThemeKey::MutedForeground, Self::secondary_label())
+    }
+
+    /// Border color for controls such as bordered buttons.
+    pub fn border() -> Self {
+        Self::themed(ThemeKey::Border, Self::separator())
+    }
+
+    /// Outline drawn around a view focused from the keyboard.
+    pub fn focus_ring() -> Self {
+        Self::themed(ThemeKey::FocusRing, Self::blue())
+    }
+
+    /// Background for selected text and items.
+    pub fn selection_background() -> Self {
+        Self::themed(
+            ThemeKey::SelectionBackground,
+            Self::from(Self::blue().opacity(0.2).resolve(false)),
+        )
+    }
+
+    // ========================================================================
+    // Derived colors
+    // ========================================================================
+
+    /// Increase lightness by `amount` (0.0-1.0).
+    ///
+    /// For semantic colors the adjustment is applied after resolution, so
+    /// the light and dark variants are each lightened.
+    pub fn lighten(self, amount: f32) -> Self {
+        self.derive(ColorTransform::Lighten(amount))
+    }
+
+    /// Decrease lightness by `amount` (0.0-1.0).
+    pub fn darken(self, amount: f32) -> Self {
+        self.derive(ColorTransform::Darken(amount))
+    }
+
+    /// Blend towards `other`; `t = 0.0` is this color, `t = 1.0` is `other`.
+    ///
+    /// `other` is resolved together with this color, so theme-backed colors
+    /// such as [`Color::accent`] follow the theme.
+    pub fn mix(self, other: impl Into<Color>, t: f32) -> Self {
+        self.derive(ColorTransform::Mix {
+            other: Arc::new(other.into()),
+            amount: t,
+        })
+    }
+
+    /// WCAG 2 contrast ratio between this color and `other` (1.0-21.0).
+    ///
+    /// Semantic colors are resolved for the color scheme given by
+    /// `dark_mode`, as in [`Color::resolve`].
+    pub fn contrast_ratio(&self, other: impl Into<Color>, dark_mode: bool) -> f32 {
+        let a = relative_luminance(self.resolve(dark_mode));
+        let b = relative_luminance(other.into().resolve(dark_mode));
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    fn derive(self, transform: ColorTransform) -> Self {
+        if !self.is_semantic() && !transform.is_adaptive() {
+            return Self {
+                hsla: transform.apply(self.hsla, false, None, None),
+                ..self
+            };
+        }
+        // A color carries one transform; an existing one becomes the base.
+        let base = if self.transform.is_some() {
+            Self {
+                semantic: None,
+                adaptive: Some(AdaptiveColor::Derived(Arc::new(self.clone()))),
+                transform: None,
+                alpha: Alpha::Multiply(1.0),
+                ..self
+            }
+        } else {
+            self
+        };
+        Self {
+            transform: Some(transform),
+            ..base
         }
     }
 
@@ -203,17 +521,83 @@ impl Color {
     /// let label = Color::label();
     /// let resolved = label.resolve(cx.theme().is_dark());
     /// ```
-    pub fn resolve(self, dark_mode: bool) -> Hsla {
-        if let Some(semantic) = self.semantic {
-            semantic.resolve(dark_mode)
-        } else {
-            self.hsla
+    pub fn resolve(&self, dark_mode: bool) -> Hsla {
+        self.resolve_with(dark_mode, None, None)
+    }
+
+    /// Resolve this color using the active theme.
+    ///
+    /// Like [`Color::resolve`] for the environment's color scheme, but
+    /// theme-backed colors such as [`Color::accent`] read their value from
+    /// the theme, and [`Color::named`] colors from the app's
+    /// [`ColorPalette`]. Components should prefer this at render time.
+    ///
+    /// Inside a `.color_scheme()` that differs from the theme's, theme-backed
+    /// colors use their built-in fallbacks, since the theme only has colors
+    /// for its own scheme.
+    pub fn resolve_in(&self, cx: &App) -> Hsla {
+        let theme = cx.theme();
+        let dark_mode = environment::color_scheme(cx) == ColorScheme::Dark;
+        let theme = (dark_mode == theme.is_dark()).then_some(theme);
+        self.resolve_with(dark_mode, theme, cx.try_global())
+    }
+
+    /// Resolve for a color scheme, reading theme-backed colors from `theme`
+    /// and named colors from `palette` when there are ones.
+    pub(super) fn resolve_with(
+        &self,
+        dark_mode: bool,
+        theme: Option<&Theme>,
+        palette: Option<&ColorPalette>,
+    ) -> Hsla {
+        let base = match (&self.adaptive, theme) {
+            (Some(AdaptiveColor::Theme(key)), Some(theme)) => key.resolve(theme),
+            (Some(AdaptiveColor::Derived(color)), _) => {
+                color.resolve_with(dark_mode, theme, palette)
+            }
+            _ => self.base(dark_mode, palette),
+        };
+        self.finish(base, dark_mode, theme, palette)
+    }
+
+    /// The color before derived transforms and opacity are applied.
+    fn base(&self, dark_mode: bool, palette: Option<&ColorPalette>) -> Hsla {
+        match (self.semantic, &self.adaptive) {
+            (Some(semantic), _) => semantic.resolve(dark_mode),
+            (None, Some(AdaptiveColor::Pair { dark })) if dark_mode => *dark,
+            (None, Some(AdaptiveColor::Named(name))) => palette
+                .and_then(|palette| palette.resolve(name, dark_mode))
+                .unwrap_or(self.hsla),
+            _ => self.hsla,
+        }
+    }
+
+    /// Apply the derived transform and alpha adjustment to a resolved base.
+    fn finish(
+        &self,
+        base: Hsla,
+        dark_mode: bool,
+        theme: Option<&Theme>,
+        palette: Option<&ColorPalette>,
+    ) -> Hsla {
+        let base = match &self.transform {
+            Some(transform) => transform.apply(base, dark_mode, theme, palette),
+            None => base,
+        };
+        Hsla {
+            a: self.alpha.apply(base.a),
+            ..base
         }
     }
 
     /// Check if this is a semantic (adaptive) color.
     pub fn is_semantic(&self) -> bool {
         self.semantic.is_some()
+            || self.adaptive.is_some()
+            || self
+                .transform
+                .as_ref()
+                .is_some_and(ColorTransform::is_adaptive)
     }
 
     // ========================================================================
@@ -309,6 +693,9 @@ impl Color {
         Self {
             hsla: SemanticColor::Label.resolve(false), // Default to light
             semantic: Some(SemanticColor::Label),
+            adaptive: None,
+            transform: None,
+            alpha: Alpha::Multiply(1.0),
         }
     }
 
@@ -319,6 +706,9 @@ impl Color {
         Self {
             hsla: SemanticColor::SecondaryLabel.resolve(false),
             semantic: Some(SemanticColor::SecondaryLabel),
+            adaptive: None,
+            transform: None,
+            alpha: Alpha::Multiply(1.0),
         }
     }
 
@@ -329,6 +719,9 @@ impl Color {
         Self {
             hsla: SemanticColor::TertiaryLabel.resolve(false),
             semantic: Some(SemanticColor::TertiaryLabel),
+            adaptive: None,
+            transform: None,
+            alpha: Alpha::Multiply(1.0),
         }
     }
 
@@ -339,6 +732,9 @@ impl Color {
         Self {
             hsla: SemanticColor::SystemBackground.resolve(false),
             semantic: Some(SemanticColor::SystemBackground),
+            adaptive: None,
+            transform: None,
+            alpha: Alpha::Multiply(1.0),
         }
     }
 
@@ -349,6 +745,9 @@ impl Color {
         Self {
             hsla: SemanticColor::SecondarySystemBackground.resolve(false),
             semantic: Some(SemanticColor::SecondarySystemBackground),
+            adaptive: None,
+            transform: None,
+            alpha: Alpha::Multiply(1.0),
         }
     }
 
@@ -359,6 +758,9 @@ impl Color {
         Self {
             hsla: SemanticColor::TertiarySystemBackground.resolve(false),
             semantic: Some(SemanticColor::TertiarySystemBackground),
+            adaptive: None,
+            transform: None,
+            alpha: Alpha::Multiply(1.0),
         }
     }
 
@@ -369,6 +771,9 @@ impl Color {
         Self {
             hsla: SemanticColor::Separator.resolve(false),
             semantic: Some(SemanticColor::Separator),
+            adaptive: None,
+            transform: None,
+            alpha: Alpha::Multiply(1.0),
         }
     }
 
@@ -379,6 +784,9 @@ impl Color {
         Self {
             hsla: SemanticColor::OpaqueSeparator.resolve(false),
             semantic: Some(SemanticColor::OpaqueSeparator),
+            adaptive: None,
+            transform: None,
+            alpha: Alpha::Multiply(1.0),
         }
     }
 
@@ -386,6 +794,9 @@ impl Color {
         Self {
             hsla: SemanticColor::TextFieldBackground.resolve(false),
             semantic: Some(SemanticColor::TextFieldBackground),
+            adaptive: None,
+            transform: None,
+            alpha: Alpha::Multiply(1.0),
         }
     }
 
@@ -393,6 +804,9 @@ impl Color {
         Self {
             hsla: SemanticColor::TextFieldBorder.resolve(false),
             semantic: Some(SemanticColor::TextFieldBorder),
+            adaptive: None,
+            transform: None,
+            alpha: Alpha::Multiply(1.0),
         }
     }
 
@@ -485,18 +899,27 @@ impl Color {
     }
 }
 
+impl From<&Color> for Color {
+    fn from(color: &Color) -> Self {
+        color.clone()
+    }
+}
+
 impl From<Hsla> for Color {
     fn from(hsla: Hsla) -> Self {
         Self {
             hsla,
             semantic: None,
+            adaptive: None,
+            transform: None,
+            alpha: Alpha::Multiply(1.0),
         }
     }
 }
 
 impl From<Color> for Hsla {
     fn from(color: Color) -> Self {
-        color.hsla
+        color.resolve(false)
     }
 }
 
@@ -511,15 +934,126 @@ mod tests {
     use super::*;
 
     #[test]
-    fn opacity_resolves_semantic_to_static() {
+    fn opacity_keeps_semantic_color() {
         let semantic = Color::label();
         assert!(semantic.is_semantic());
 
         let with_opacity = semantic.opacity(0.5);
         assert!(
-            !with_opacity.is_semantic(),
-            "applying opacity should resolve semantic color to static"
+            with_opacity.is_semantic(),
+            "applying opacity should keep the semantic color"
         );
+        assert_eq!(with_opacity.alpha, Alpha::Set(0.5));
+    }
+
+    #[test]
+    fn opacity_sets_and_multiply_opacity_scales() {
+        let translucent = Color::blue().opacity(0.5);
+        assert_eq!(translucent.clone().opacity(0.8).resolve(false).a, 0.8);
+        assert_eq!(translucent.multiply_opacity(0.5).resolve(false).a, 0.25);
+
+        let separator = Color::separator().resolve(false).a;
+        let faded = Color::separator().multiply_opacity(0.5).resolve(false).a;
+        assert!((faded - separator * 0.5).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn semantic_opacity_resolves_in_light_mode() {
+        let resolved = Color::label().opacity(0.5).resolve(false);
+
+        assert!(resolved.l < 0.1, "label should stay dark in light mode");
+        assert!((resolved.a - 0.5).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn semantic_opacity_resolves_in_dark_mode() {
+        let resolved = Color::label().opacity(0.5).resolve(true);
+
+        assert!(resolved.l > 0.9, "label should become light in dark mode");
+        assert!((resolved.a - 0.5).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn adaptive_color_resolves_per_mode() {
+        let color = Color::adaptive(Color::white(), Color::black());
+        assert!(color.is_semantic());
+
+        assert!(color.resolve(false).l > 0.9);
+        assert!(color.resolve(true).l < 0.1);
+    }
+
+    #[test]
+    fn lighten_and_darken_are_symmetric() {
+        let base = Color::hsla(0.6, 0.5, 0.5, 1.0);
+        let round_trip = base.clone().lighten(0.2).darken(0.2).resolve(false);
+
+        assert!((round_trip.l - 0.5).abs() < 1e-6);
+        assert!((base.lighten(0.2).resolve(false).l - 0.7).abs() < 1e-6);
+    }
+
+    #[test]
+    fn derived_semantic_color_applies_after_resolution() {
+        let bg = Color::secondary_system_background();
+        let darker = bg.clone().darken(0.05);
+        assert!(darker.is_semantic());
+
+        let light = darker.resolve(false).l;
+        let dark = darker.resolve(true).l;
+        assert!((light - (bg.resolve(false).l - 0.05)).abs() < 1e-6);
+        assert!((dark - (bg.resolve(true).l - 0.05)).abs() < 1e-6);
+
+        let round_trip = bg.clone().lighten(0.02).darken(0.02).resolve(true).l;
+        assert!((round_trip - bg.resolve(true).l).abs() < 1e-6);
+    }
+
+    #[test]
+    fn mix_endpoints_match_inputs() {
+        let (black, white) = (Color::black(), Color::white());
+
+        let mix = |t| black.clone().mix(white.clone(), t).resolve(false);
+        assert_eq!(mix(0.0), black.resolve(false));
+        assert!(mix(1.0).l > 0.999);
+
+        let mid = mix(0.5).to_rgb();
+        assert!((mid.r - 0.5).abs() < 0.01);
+    }
+
+    #[test]
+    fn chained_transforms_keep_adapting() {
+        let bg = Color::secondary_system_background();
+        let chained = bg.clone().darken(0.1).mix(Color::label(), 0.5);
+        assert!(chained.is_semantic());
+
+        for dark_mode in [false, true] {
+            let darker = bg.clone().darken(0.1).resolve(dark_mode);
+            let expected = mix_hsla(darker, Color::label().resolve(dark_mode), 0.5);
+            assert_eq!(chained.resolve(dark_mode), expected);
+        }
+    }
+
+    #[test]
+    fn mix_resolves_target_lazily() {
+        let mut palette = ColorPalette::default();
+        let tinted = Color::black().mix(Color::named("mixTarget"), 1.0);
+        palette.insert("mixTarget".into(), Color::white(), Color::white());
+        assert!(tinted.resolve_with(false, None, Some(&palette)).l > 0.999);
+
+        palette.insert("mixTarget".into(), Color::black(), Color::black());
+        assert!(tinted.resolve_with(false, None, Some(&palette)).l < 0.001);
+    }
+
+    #[test]
+    fn contrast_ratio_matches_wcag() {
+        let ratio = Color::black().contrast_ratio(Color::white(), false);
+        assert!((ratio - 21.0).abs() < 0.01);
+
+        assert!((Color::white().contrast_ratio(Color::black(), false) - ratio).abs() < 1e-6);
+        assert!((Color::red().contrast_ratio(Color::red(), false) - 1.0).abs() < 1e-6);
+
+        // Semantic colors resolve for the scheme asked for
+        let on_background = |dark_mode| Color::label().contrast_ratio(Color::white(), dark_mode);
+        assert!(on_background(false) > 15.0);
+        assert!(on_background(true) < 1.5);
     }
 
     #[test]
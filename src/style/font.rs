@@ -1,5 +1,7 @@
 //! Font types for Allui.
 
+use gpui::SharedString;
+
 /// Font configuration for text rendering.
 #[derive(Clone, Debug)]
 pub struct Font {
@@ -11,6 +13,8 @@ pub struct Font {
     pub design: FontDesign,
     /// Whether the font is italic.
     pub italic: bool,
+    /// Custom font family. When set, wins over `design`.
+    pub family: Option<SharedString>,
 }
 
 impl Font {
@@ -27,6 +31,26 @@ impl Font {
         }
     }
 
+    /// Create a system font with the given size, weight, and design.
+    pub fn system(size: f32, weight: FontWeight, design: FontDesign) -> Self {
+        Self {
+            size: Some(size),
+            weight,
+            design,
+            ..Default::default()
+        }
+    }
+
+    /// Create a font using a custom family, e.g. `"Inter"` or a bundled font.
+    ///
+    /// If the family isn't installed, GPUI falls back to the default font.
+    pub fn custom(family: impl Into<SharedString>) -> Self {
+        Self {
+            family: Some(family.into()),
+            ..Default::default()
+        }
+    }
+
     // SwiftUI text styles
 
     /// Large title style (~34pt).
@@ -36,6 +60,7 @@ impl Font {
             weight: FontWeight::Regular,
             design: FontDesign::Default,
             italic: false,
+            family: None,
         }
     }
 
@@ -46,6 +71,7 @@ impl Font {
             weight: FontWeight::Regular,
             design: FontDesign::Default,
             italic: false,
+            family: None,
         }
     }
 
@@ -56,6 +82,7 @@ impl Font {
             weight: FontWeight::Regular,
             design: FontDesign::Default,
             italic: false,
+            family: None,
         }
     }
 
@@ -66,6 +93,7 @@ impl Font {
             weight: FontWeight::Regular,
             design: FontDesign::Default,
             italic: false,
+            family: None,
         }
     }
 
@@ -76,6 +104,7 @@ impl Font {
             weight: FontWeight::Semibold,
             design: FontDesign::Default,
             italic: false,
+            family: None,
         }
     }
 
@@ -86,6 +115,7 @@ impl Font {
             weight: FontWeight::Regular,
             design: FontDesign::Default,
             italic: false,
+            family: None,
         }
     }
 
@@ -96,6 +126,7 @@ impl Font {
             weight: FontWeight::Regular,
             design: FontDesign::Default,
             italic: false,
+            family: None,
         }
     }
 
@@ -106,6 +137,7 @@ impl Font {
             weight: FontWeight::Regular,
             design: FontDesign::Default,
             italic: false,
+            family: None,
         }
     }
 
@@ -116,6 +148,7 @@ impl Font {
             weight: FontWeight::Regular,
             design: FontDesign::Default,
             italic: false,
+            family: None,
         }
     }
 
@@ -126,6 +159,7 @@ impl Font {
             weight: FontWeight::Regular,
             design: FontDesign::Default,
             italic: false,
+            family: None,
         }
     }
 
@@ -136,6 +170,7 @@ impl Font {
             weight: FontWeight::Regular,
             design: FontDesign::Default,
             italic: false,
+            family: None,
         }
     }
 
@@ -170,6 +205,20 @@ impl Font {
         self.design = FontDesign::Monospaced;
         self
     }
+
+    /// Use a custom font family.
+    pub fn family(mut self, family: impl Into<SharedString>) -> Self {
+        self.family = Some(family.into());
+        self
+    }
+
+    /// The font family to render with: the custom family if set, otherwise
+    /// the platform family for `design` (`None` means the system default).
+    pub fn font_family(&self) -> Option<SharedString> {
+        self.family
+            .clone()
+            .or_else(|| self.design.font_family().map(SharedString::new_static))
+    }
 }
 
 impl Default for Font {
@@ -179,6 +228,7 @@ impl Default for Font {
             weight: FontWeight::Regular,
             design: FontDesign::Default,
             italic: false,
+            family: None,
         }
     }
 }
@@ -292,3 +342,39 @@ impl FontDesign {
         "sans-serif"
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn custom_family_wins_over_design() {
+        let font = Font::custom("Inter").design(FontDesign::Monospaced);
+
+        assert_eq!(font.font_family(), Some(SharedString::from("Inter")));
+    }
+
+    #[test]
+    fn design_family_used_without_custom_family() {
+        let font = Font::system(14.0, FontWeight::Bold, FontDesign::Monospaced);
+
+        assert_eq!(font.size, Some(14.0));
+        assert_eq!(font.weight, FontWeight::Bold);
+        assert_eq!(
+            font.font_family(),
+            FontDesign::Monospaced.font_family().map(SharedString::from)
+        );
+        assert_eq!(Font::body().font_family(), None);
+    }
+
+    #[test]
+    fn missing_family_is_passed_through() {
+        // Unknown families are left for GPUI's font fallback to handle
+        let font = Font::custom("Definitely Not Installed");
+
+        assert_eq!(
+            font.font_family(),
+            Some(SharedString::from("Definitely Not Installed"))
+        );
+    }
+}
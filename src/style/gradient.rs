@@ -0,0 +1,189 @@
+//! Gradient fills and shape styles for Allui.
+//!
+//! A [`ShapeStyle`] is anything that can fill a view's background: a solid
+//! [`Color`] or a [`LinearGradient`]. Like colors, styles are resolved at
+//! render time so semantic colors inside gradient stops follow the theme.
+
+use gpui::{linear_color_stop, linear_gradient, App, Background, Hsla};
+
+use super::color::Color;
+
+/// A fill that can be used as a view's background.
+///
+/// Implemented by [`Color`], [`LinearGradient`] and GPUI's `Hsla`.
+pub trait ShapeStyle: 'static {
+    /// Resolve this style to a GPUI background using the active theme.
+    fn resolve_background(&self, cx: &App) -> Background;
+}
+
+impl ShapeStyle for Color {
+    fn resolve_background(&self, cx: &App) -> Background {
+        self.resolve_in(cx).into()
+    }
+}
+
+impl ShapeStyle for Hsla {
+    fn resolve_background(&self, _cx: &App) -> Background {
+        (*self).into()
+    }
+}
+
+/// A normalized 2D point in a view's coordinate space.
+///
+/// `(0, 0)` is the top-leading corner and `(1, 1)` the bottom-trailing corner.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UnitPoint {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl UnitPoint {
+    /// Create a unit point from normalized coordinates.
+    pub fn new(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
+
+    pub fn top_leading() -> Self {
+        Self::new(0.0, 0.0)
+    }
+
+    pub fn top() -> Self {
+        Self::new(0.5, 0.0)
+    }
+
+    pub fn top_trailing() -> Self {
+        Self::new(1.0, 0.0)
+    }
+
+    pub fn leading() -> Self {
+        Self::new(0.0, 0.5)
+    }
+
+    pub fn center() -> Self {
+        Self::new(0.5, 0.5)
+    }
+
+    pub fn trailing() -> Self {
+        Self::new(1.0, 0.5)
+    }
+
+    pub fn bottom_leading() -> Self {
+        Self::new(0.0, 1.0)
+    }
+
+    pub fn bottom() -> Self {
+        Self::new(0.5, 1.0)
+    }
+
+    pub fn bottom_trailing() -> Self {
+        Self::new(1.0, 1.0)
+    }
+}
+
+/// A linear gradient between two colors.
+///
+/// Runs from top to bottom unless `start_point` and `end_point` are set.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// Text::new("Hi")
+///     .padding(12.0)
+///     .background(LinearGradient::new(Color::blue(), Color::purple()))
+///
+/// LinearGradient::new(Color::system_background(), Color::secondary_label())
+///     .start_point(UnitPoint::leading())
+///     .end_point(UnitPoint::trailing())
+/// ```
+///
+/// GPUI gradients interpolate between exactly two stops, so a gradient has
+/// one color at each end.
+#[derive(Clone, Debug)]
+pub struct LinearGradient {
+    from: (Color, f32),
+    to: (Color, f32),
+    start_point: UnitPoint,
+    end_point: UnitPoint,
+}
+
+impl LinearGradient {
+    /// Create a gradient from one color to another.
+    pub fn new(from: impl Into<Color>, to: impl Into<Color>) -> Self {
+        Self {
+            from: (from.into(), 0.0),
+            to: (to.into(), 1.0),
+            start_point: UnitPoint::top(),
+            end_point: UnitPoint::bottom(),
+        }
+    }
+
+    /// Set where the gradient starts, with the `from` color (default top).
+    pub fn start_point(mut self, point: UnitPoint) -> Self {
+        self.start_point = point;
+        self
+    }
+
+    /// Set where the gradient ends, with the `to` color (default bottom).
+    pub fn end_point(mut self, point: UnitPoint) -> Self {
+        self.end_point = point;
+        self
+    }
+
+    /// Set where along the gradient (0.0-1.0) each color is fully shown.
+    ///
+    /// Defaults to `0.0` and `1.0`, the two ends.
+    pub fn locations(mut self, from: f32, to: f32) -> Self {
+        self.from.1 = from;
+        self.to.1 = to;
+        self
+    }
+
+    /// The CSS-style gradient angle in degrees (0 = towards top, clockwise).
+    fn angle(&self) -> f32 {
+        let dx = self.end_point.x - self.start_point.x;
+        let dy = self.end_point.y - self.start_point.y;
+        dx.atan2(-dy).to_degrees().rem_euclid(360.0)
+    }
+}
+
+impl ShapeStyle for LinearGradient {
+    fn resolve_background(&self, cx: &App) -> Background {
+        let (from, from_location) = &self.from;
+        let (to, to_location) = &self.to;
+        linear_gradient(
+            self.angle(),
+            linear_color_stop(from.resolve_in(cx), from_location.clamp(0.0, 1.0)),
+            linear_color_stop(to.resolve_in(cx), to_location.clamp(0.0, 1.0)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn angle_follows_css_convention() {
+        let angle = |start, end| {
+            LinearGradient::new(Color::red(), Color::blue())
+                .start_point(start)
+                .end_point(end)
+                .angle()
+        };
+
+        assert_eq!(angle(UnitPoint::bottom(), UnitPoint::top()), 0.0);
+        assert_eq!(angle(UnitPoint::leading(), UnitPoint::trailing()), 90.0);
+        assert_eq!(angle(UnitPoint::top(), UnitPoint::bottom()), 180.0);
+        assert_eq!(angle(UnitPoint::trailing(), UnitPoint::leading()), 270.0);
+    }
+
+    #[test]
+    fn defaults_to_top_to_bottom_across_the_whole_view() {
+        let gradient = LinearGradient::new(Color::red(), Color::blue());
+        assert_eq!(gradient.angle(), 180.0);
+        assert_eq!((gradient.from.1, gradient.to.1), (0.0, 1.0));
+
+        let gradient = gradient.locations(0.2, 0.8);
+        assert_eq!((gradient.from.1, gradient.to.1), (0.2, 0.8));
+    }
+}
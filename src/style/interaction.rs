@@ -0,0 +1,144 @@
+//! Interaction styles - how a view looks while hovered or pressed.
+
+use std::rc::Rc;
+
+use gpui::{px, App, Div, Hsla, StyleRefinement, Styled};
+
+use super::{Color, ShapeStyle};
+use crate::modifier::apply_shadow;
+
+/// Styles applied while a view is hovered or pressed, built in
+/// [`Modifier::when_hovered`] and [`Modifier::when_pressed`].
+///
+/// Backgrounds, borders and shadows are drawn around the view, in the shape
+/// set by [`corner_radius`](Self::corner_radius).
+///
+/// [`Modifier::when_hovered`]: crate::modifier::Modifier::when_hovered
+/// [`Modifier::when_pressed`]: crate::modifier::Modifier::when_pressed
+///
+/// # Example
+///
+/// ```rust,ignore
+/// PricingCard::new(plan)
+///     .when_hovered(|style| style.corner_radius(12.0).shadow(12.0).offset(0.0, -4.0))
+///     .when_pressed(|style| style.opacity(0.9))
+/// ```
+#[derive(Clone, Default)]
+pub struct InteractionStyle {
+    background: Option<Rc<dyn ShapeStyle>>,
+    border: Option<(Color, f32)>,
+    corner_radius: Option<f32>,
+    opacity: Option<f32>,
+    shadow: Option<f32>,
+    offset: Option<(f32, f32)>,
+}
+
+impl InteractionStyle {
+    /// Fill the view's background.
+    pub fn background(mut self, style: impl ShapeStyle) -> Self {
+        self.background = Some(Rc::new(style));
+        self
+    }
+
+    /// Draw a border around the view. Its width is kept clear in every
+    /// state, so the view doesn't move when it appears.
+    pub fn border(mut self, color: impl Into<Color>, width: f32) -> Self {
+        self.border = Some((color.into(), width));
+        self
+    }
+
+    /// Round the corners of the background, border and shadow.
+    pub fn corner_radius(mut self, radius: f32) -> Self {
+        self.corner_radius = Some(radius);
+        self
+    }
+
+    /// Set the opacity of the view and its subtree.
+    pub fn opacity(mut self, value: f32) -> Self {
+        self.opacity = Some(value.clamp(0.0, 1.0));
+        self
+    }
+
+    /// Cast a shadow of about `radius` points, like [`Modifier::shadow`].
+    ///
+    /// [`Modifier::shadow`]: crate::modifier::Modifier::shadow
+    pub fn shadow(mut self, radius: f32) -> Self {
+        self.shadow = Some(radius);
+        self
+    }
+
+    /// Move the view by `x` and `y` points without changing its layout,
+    /// such as up a little for a card that lifts.
+    pub fn offset(mut self, x: f32, y: f32) -> Self {
+        self.offset = Some((x, y));
+        self
+    }
+
+    /// Style `container` for every state, so the interaction only changes
+    /// colors, shadows and position.
+    pub(crate) fn apply_base(&self, container: Div) -> Div {
+        let mut container = container.relative();
+        if let Some(radius) = self.corner_radius {
+            container = container.rounded(px(radius));
+        }
+        if let Some((_, width)) = self.border {
+            container = container
+                .border(px(width))
+                .border_color(gpui::transparent_black());
+        }
+        container
+    }
+
+    /// The refinement for the hovered or pressed state, with colors
+    /// resolved against the current theme.
+    pub(crate) fn refinement(
+        &self,
+        cx: &App,
+    ) -> impl FnOnce(StyleRefinement) -> StyleRefinement + 'static {
+        let background = self
+            .background
+            .as_ref()
+            .map(|style| style.resolve_background(cx));
+        let border: Option<Hsla> = self.border.as_ref().map(|(color, _)| color.resolve_in(cx));
+        let opacity = self.opacity;
+        let shadow = self.shadow;
+        let offset = self.offset;
+        move |mut style| {
+            if let Some(background) = background {
+                style = style.bg(background);
+            }
+            if let Some(color) = border {
+                style = style.border_color(color);
+            }
+            if let Some(opacity) = opacity {
+                style = style.opacity(opacity);
+            }
+            if let Some(radius) = shadow {
+                style = apply_shadow(style, radius);
+            }
+            if let Some((x, y)) = offset {
+                style = style.left(px(x)).top(px(y));
+            }
+            style
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::TestAppContext;
+
+    use super::*;
+
+    #[test]
+    fn refinements_set_only_what_was_styled() {
+        let style = InteractionStyle::default().opacity(1.5).offset(0.0, -4.0);
+        let refined =
+            TestAppContext::single().update(|cx| style.refinement(cx))(StyleRefinement::default());
+
+        assert_eq!(refined.opacity, Some(1.0));
+        assert_eq!(refined.inset.top, Some(px(-4.0).into()));
+        assert_eq!(refined.background, None);
+        assert_eq!(refined.box_shadow, None);
+    }
+}
@@ -2,6 +2,12 @@
 
 mod color;
 mod font;
+mod gradient;
+mod interaction;
+mod palette;
 
-pub use color::{Color, SemanticColor};
+pub use color::{Color, ColorScheme, SemanticColor};
 pub use font::{Font, FontDesign, FontWeight};
+pub use gradient::{LinearGradient, ShapeStyle, UnitPoint};
+pub use interaction::InteractionStyle;
+pub use palette::ColorPalette;
@@ -0,0 +1,89 @@
+//! Color palette - app-defined adaptive color tokens.
+
+use std::collections::HashMap;
+
+use gpui::{App, Global, Hsla, SharedString};
+
+use super::color::Color;
+
+/// App-wide registry of named adaptive colors, kept as a GPUI global.
+///
+/// Tokens are looked up when a color is resolved with [`Color::resolve_in`],
+/// so updating a token (or switching between light and dark mode) is picked
+/// up on the next render.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// ColorPalette::set(cx, "brandSurface", Color::hex(0xF2F0FF), Color::hex(0x1E1B2E));
+///
+/// Text::new("Hello")
+///     .padding(12.0)
+///     .background(Color::named("brandSurface"))
+/// ```
+#[derive(Default)]
+pub struct ColorPalette {
+    colors: HashMap<SharedString, (Hsla, Hsla)>,
+}
+
+impl Global for ColorPalette {}
+
+impl ColorPalette {
+    /// Register or replace a named color with light and dark variants.
+    ///
+    /// Windows are refreshed so views using the color redraw with it.
+    pub fn set(
+        cx: &mut App,
+        name: impl Into<SharedString>,
+        light: impl Into<Color>,
+        dark: impl Into<Color>,
+    ) {
+        cx.default_global::<ColorPalette>()
+            .insert(name.into(), light.into(), dark.into());
+        cx.refresh_windows();
+    }
+
+    /// Check whether a named color has been registered.
+    pub fn contains(cx: &App, name: &str) -> bool {
+        cx.try_global::<ColorPalette>()
+            .is_some_and(|palette| palette.colors.contains_key(name))
+    }
+
+    pub(super) fn insert(&mut self, name: SharedString, light: Color, dark: Color) {
+        self.colors
+            .insert(name, (light.resolve(false), dark.resolve(true)));
+    }
+
+    pub(crate) fn resolve(&self, name: &str, dark_mode: bool) -> Option<Hsla> {
+        self.colors
+            .get(name)
+            .map(|&(light, dark)| if dark_mode { dark } else { light })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn named_colors_resolve_from_the_palette() {
+        let color = Color::named("surface");
+        let mut palette = ColorPalette::default();
+        assert_eq!(
+            color.resolve_with(false, None, Some(&palette)).a,
+            0.0,
+            "unregistered names are clear"
+        );
+
+        palette.insert("surface".into(), Color::white(), Color::black());
+        assert!(color.resolve_with(false, None, Some(&palette)).l > 0.9);
+        assert!(color.resolve_with(true, None, Some(&palette)).l < 0.1);
+
+        // Looked up when resolved, so replacing the token recolors it
+        palette.insert("surface".into(), Color::red(), Color::blue());
+        assert_eq!(
+            color.resolve_with(false, None, Some(&palette)),
+            Color::red().resolve(false)
+        );
+    }
+}
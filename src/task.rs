@@ -0,0 +1,198 @@
+//! Task - async work that runs while a view is shown.
+//!
+//! `.task()` starts a future when its view first appears, like SwiftUI's
+//! `.task(id:)`, and `.on_timer()` calls a handler on an interval, like a
+//! `TimelineView`. Both stop when the view is no longer rendered: the
+//! spawned task is kept in the view's element state, which GPUI drops once
+//! a frame is drawn without it, cancelling the task.
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::rc::Rc;
+use std::time::Duration;
+
+use gpui::{
+    div, prelude::FluentBuilder, AnyElement, App, AsyncWindowContext, Context, ElementId,
+    IntoElement, ParentElement, RenderOnce, Styled, Task, Window,
+};
+
+use crate::modifier::{Expansion, Modifier};
+
+/// Called each time a timer fires.
+pub(crate) type TimerHandler = Rc<dyn Fn(&mut Window, &mut App)>;
+
+/// Spawns a view's task.
+type TaskStarter = Box<dyn FnOnce(&mut Window, &mut App) -> Task<()>>;
+
+/// The work a [`TaskContent`] does while shown.
+pub(crate) enum Work {
+    Task(TaskStarter),
+    Timer {
+        interval: Duration,
+        handler: TimerHandler,
+    },
+}
+
+impl Work {
+    /// Wrap `task`, which [`Modifier::task`] runs on the window.
+    pub(crate) fn task(task: impl AsyncFnOnce(&mut AsyncWindowContext) + 'static) -> Self {
+        Self::Task(Box::new(move |window, cx| window.spawn(cx, task)))
+    }
+}
+
+/// A view that runs async work while it's shown, created by
+/// [`Modifier::task`] or [`Modifier::on_timer`].
+pub struct TaskContent<V> {
+    pub(crate) child: V,
+    pub(crate) child_expansion: Expansion,
+    pub(crate) id: ElementId,
+    /// The work restarts when this changes.
+    pub(crate) key: u64,
+    pub(crate) work: Work,
+}
+
+impl<V> TaskContent<V> {
+    /// Keep the task by `id` instead of by where it was created, for views
+    /// made in a loop.
+    pub fn id(mut self, id: impl Into<ElementId>) -> Self {
+        self.id = id.into();
+        self
+    }
+}
+
+/// Hash a task's key, so keys of any hashable type can be compared.
+pub(crate) fn hash_key(key: impl Hash) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    key.hash(&mut hasher);
+    hasher.finish()
+}
+
+impl<V> Modifier for TaskContent<V> {
+    fn expansion(&self) -> Expansion {
+        self.child_expansion
+    }
+}
+
+impl<V: IntoElement + 'static> IntoElement for TaskContent<V> {
+    type Element = AnyElement;
+
+    fn into_element(self) -> Self::Element {
+        TaskContentElement { content: self }.into_any_element()
+    }
+}
+
+#[derive(IntoElement)]
+struct TaskContentElement<V: IntoElement + 'static> {
+    content: TaskContent<V>,
+}
+
+impl<V: IntoElement + 'static> RenderOnce for TaskContentElement<V> {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let TaskContent {
+            child,
+            child_expansion: expansion,
+            id,
+            key,
+            work,
+        } = self.content;
+
+        let state = window.use_keyed_state(id, cx, |_, _| TaskState {
+            key: None,
+            handler: None,
+            _task: None,
+        });
+        state.update(cx, |state, cx| {
+            // Timers call the latest handler without restarting
+            if let Work::Timer { handler, .. } = &work {
+                state.handler = Some(handler.clone());
+            }
+            if state.key != Some(key) {
+                state.key = Some(key);
+                state._task = Some(TaskState::start(work, window, cx));
+            }
+        });
+
+        div()
+            .when(expansion.grows, |container| container.flex_grow())
+            .when(expansion.fills_width, |container| container.w_full())
+            .when(expansion.fills_height, |container| container.h_full())
+            .child(child)
+    }
+}
+
+/// Kept across frames for each view with a task, until it disappears.
+struct TaskState {
+    key: Option<u64>,
+    handler: Option<TimerHandler>,
+    /// Dropping the task cancels it.
+    _task: Option<Task<()>>,
+}
+
+impl TaskState {
+    fn start(work: Work, window: &mut Window, cx: &mut Context<Self>) -> Task<()> {
+        match work {
+            Work::Task(start) => start(window, cx),
+            Work::Timer { interval, .. } => {
+                let state = cx.weak_entity();
+                window.spawn(cx, async move |cx| loop {
+                    cx.background_executor().timer(interval).await;
+                    let Ok(Some(handler)) = state.read_with(cx, |state, _| state.handler.clone())
+                    else {
+                        break;
+                    };
+                    if cx.update(|window, cx| handler(window, cx)).is_err() {
+                        break;
+                    }
+                })
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use gpui::{Render, TestAppContext};
+
+    use super::*;
+    use crate::layout::EmptyView;
+
+    struct Ticking {
+        shown: bool,
+        ticks: Rc<Cell<u32>>,
+    }
+
+    impl Render for Ticking {
+        fn render(&mut self, _: &mut Window, _: &mut Context<Self>) -> impl IntoElement {
+            let ticks = self.ticks.clone();
+            div().when(self.shown, |this| {
+                this.child(EmptyView::new().on_timer(1.0, move |_, _| ticks.set(ticks.get() + 1)))
+            })
+        }
+    }
+
+    #[test]
+    fn timers_fire_while_shown() {
+        let ticks = Rc::new(Cell::new(0));
+        let mut cx = TestAppContext::single();
+        let window = cx.add_window(|_, _| Ticking {
+            shown: true,
+            ticks: ticks.clone(),
+        });
+        cx.run_until_parked();
+
+        cx.executor().advance_clock(Duration::from_millis(3500));
+        assert_eq!(ticks.get(), 3);
+
+        window
+            .update(&mut cx, |view, _, cx| {
+                view.shown = false;
+                cx.notify();
+            })
+            .unwrap();
+        cx.run_until_parked();
+        cx.executor().advance_clock(Duration::from_secs(3));
+        assert_eq!(ticks.get(), 3);
+    }
+}
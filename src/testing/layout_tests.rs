@@ -0,0 +1,284 @@
+//! Layout behaviors that must match SwiftUI, checked with the harness.
+//!
+//! Each view is laid out in a 300 by 200 point space, centered like a
+//! window's content.
+
+use gpui::px;
+
+use super::*;
+use crate::alignment::HorizontalAlignment;
+use crate::components::Text;
+use crate::layout::{Distribution, EmptyView, HStack, Spacer, VStack, ZStack};
+use crate::modifier::{Frame, Modified, Modifier};
+
+const PROPOSED: (f32, f32) = (300.0, 200.0);
+
+/// A view of exactly `width` by `height` points.
+fn block(width: f32, height: f32) -> Modified<EmptyView> {
+    EmptyView::new().frame_size(width, height)
+}
+
+#[test]
+fn vstack_centers_its_children() {
+    let tree = render_for_test(
+        || {
+            VStack::new()
+                .spacing(0.0)
+                .child(block(100.0, 20.0).test_id("wide"))
+                .child(block(50.0, 20.0).test_id("narrow"))
+                .test_id("stack")
+        },
+        PROPOSED,
+    );
+
+    assert_bounds(&tree, "stack", rect(100.0, 80.0, 100.0, 40.0));
+    assert_bounds(&tree, "wide", rect(100.0, 80.0, 100.0, 20.0));
+    assert_bounds(&tree, "narrow", rect(125.0, 100.0, 50.0, 20.0));
+}
+
+#[test]
+fn vstack_aligns_children_to_the_leading_edge() {
+    let tree = render_for_test(
+        || {
+            VStack::new()
+                .spacing(0.0)
+                .alignment(HorizontalAlignment::Leading)
+                .child(block(100.0, 20.0))
+                .child(block(50.0, 20.0).test_id("narrow"))
+        },
+        PROPOSED,
+    );
+
+    assert_bounds(&tree, "narrow", rect(100.0, 100.0, 50.0, 20.0));
+}
+
+#[test]
+fn hstack_centers_its_children_vertically() {
+    let tree = render_for_test(
+        || {
+            HStack::new()
+                .spacing(0.0)
+                .child(block(20.0, 40.0))
+                .child(block(20.0, 20.0).test_id("short"))
+                .test_id("stack")
+        },
+        PROPOSED,
+    );
+
+    assert_bounds(&tree, "stack", rect(130.0, 80.0, 40.0, 40.0));
+    assert_bounds(&tree, "short", rect(150.0, 90.0, 20.0, 20.0));
+}
+
+#[test]
+fn spacing_separates_stacked_children() {
+    let tree = render_for_test(
+        || {
+            VStack::new()
+                .spacing(10.0)
+                .child(block(20.0, 20.0).test_id("first"))
+                .child(block(20.0, 20.0).test_id("second"))
+        },
+        PROPOSED,
+    );
+
+    assert_bounds(&tree, "first", rect(140.0, 75.0, 20.0, 20.0));
+    assert_bounds(&tree, "second", rect(140.0, 105.0, 20.0, 20.0));
+}
+
+#[test]
+fn spacer_pushes_views_to_the_edges() {
+    let tree = render_for_test(
+        || {
+            HStack::new()
+                .spacing(0.0)
+                .child(block(20.0, 20.0).test_id("leading"))
+                .child(Spacer::new())
+                .child(block(20.0, 20.0).test_id("trailing"))
+                .frame_width(300.0)
+        },
+        PROPOSED,
+    );
+
+    assert_bounds(&tree, "leading", rect(0.0, 90.0, 20.0, 20.0));
+    assert_bounds(&tree, "trailing", rect(280.0, 90.0, 20.0, 20.0));
+}
+
+#[test]
+fn spacers_share_the_free_space_equally() {
+    let tree = render_for_test(
+        || {
+            HStack::new()
+                .spacing(0.0)
+                .child(Spacer::new())
+                .child(block(15.0, 20.0).test_id("first"))
+                .child(Spacer::new())
+                .child(block(15.0, 20.0).test_id("second"))
+                .child(Spacer::new())
+                .frame_width(300.0)
+        },
+        PROPOSED,
+    );
+
+    // 270 points left over, 90 for each spacer
+    assert_bounds(&tree, "first", rect(90.0, 90.0, 15.0, 20.0));
+    assert_bounds(&tree, "second", rect(195.0, 90.0, 15.0, 20.0));
+}
+
+#[test]
+fn padding_insets_the_view() {
+    let tree = render_for_test(
+        || {
+            block(40.0, 20.0)
+                .test_id("content")
+                .padding(10.0)
+                .test_id("padded")
+        },
+        PROPOSED,
+    );
+
+    assert_bounds(&tree, "padded", rect(120.0, 80.0, 60.0, 40.0));
+    assert_bounds(&tree, "content", rect(130.0, 90.0, 40.0, 20.0));
+}
+
+#[test]
+fn padding_outside_a_frame_grows_it() {
+    let inside = render_for_test(
+        || {
+            block(20.0, 20.0)
+                .padding(10.0)
+                .frame_size(100.0, 100.0)
+                .test_id("view")
+        },
+        PROPOSED,
+    );
+    let outside = render_for_test(
+        || {
+            block(20.0, 20.0)
+                .frame_size(100.0, 100.0)
+                .padding(10.0)
+                .test_id("view")
+        },
+        PROPOSED,
+    );
+
+    assert_bounds(&inside, "view", rect(100.0, 50.0, 100.0, 100.0));
+    assert_bounds(&outside, "view", rect(90.0, 40.0, 120.0, 120.0));
+}
+
+#[test]
+fn fixed_frames_size_the_view() {
+    let tree = render_for_test(
+        || block(20.0, 20.0).frame_size(100.0, 60.0).test_id("frame"),
+        PROPOSED,
+    );
+
+    assert_bounds(&tree, "frame", rect(100.0, 70.0, 100.0, 60.0));
+}
+
+#[test]
+fn space_between_puts_children_at_the_edges() {
+    let tree = render_for_test(
+        || {
+            HStack::new()
+                .distribution(Distribution::SpaceBetween)
+                .child(block(20.0, 20.0).test_id("first"))
+                .child(block(20.0, 20.0).test_id("middle"))
+                .child(block(20.0, 20.0).test_id("last"))
+                .frame_width(300.0)
+        },
+        PROPOSED,
+    );
+
+    assert_bounds(&tree, "first", rect(0.0, 90.0, 20.0, 20.0));
+    assert_bounds(&tree, "middle", rect(140.0, 90.0, 20.0, 20.0));
+    assert_bounds(&tree, "last", rect(280.0, 90.0, 20.0, 20.0));
+}
+
+#[test]
+fn fill_width_frames_take_the_proposed_width() {
+    let tree = render_for_test(
+        || {
+            Text::new("Title")
+                .frame(Frame::fill_width())
+                .test_id("title")
+        },
+        PROPOSED,
+    );
+
+    let title = tree.bounds("title");
+    assert_eq!(title.origin.x, px(0.));
+    assert_eq!(title.size.width, px(300.));
+}
+
+#[test]
+fn zstack_centers_its_layers() {
+    let tree = render_for_test(
+        || {
+            ZStack::new()
+                .child(block(100.0, 60.0).test_id("back"))
+                .child(block(20.0, 20.0).test_id("front"))
+        },
+        PROPOSED,
+    );
+
+    assert_bounds(&tree, "back", rect(100.0, 70.0, 100.0, 60.0));
+    assert_bounds(&tree, "front", rect(140.0, 90.0, 20.0, 20.0));
+}
+
+#[test]
+fn hidden_views_keep_their_space() {
+    let tree = render_for_test(
+        || {
+            VStack::new()
+                .spacing(10.0)
+                .child(block(20.0, 20.0).test_id("first"))
+                .child(block(20.0, 20.0).hidden(true))
+                .child(block(20.0, 20.0).test_id("last"))
+        },
+        PROPOSED,
+    );
+
+    assert_bounds(&tree, "first", rect(140.0, 60.0, 20.0, 20.0));
+    assert_bounds(&tree, "last", rect(140.0, 120.0, 20.0, 20.0));
+}
+
+#[test]
+fn removed_views_leave_no_gap() {
+    let tree = render_for_test(
+        || {
+            VStack::new()
+                .spacing(10.0)
+                .child(block(20.0, 20.0).test_id("first"))
+                .child(block(20.0, 20.0).remove_when(true))
+                .child(block(20.0, 20.0).test_id("last"))
+        },
+        PROPOSED,
+    );
+
+    assert_bounds(&tree, "first", rect(140.0, 75.0, 20.0, 20.0));
+    assert_bounds(&tree, "last", rect(140.0, 105.0, 20.0, 20.0));
+}
+
+#[test]
+fn nested_views_match_their_snapshot() {
+    let tree = render_for_test(
+        || {
+            VStack::new()
+                .spacing(8.0)
+                .alignment(HorizontalAlignment::Leading)
+                .child(
+                    HStack::new()
+                        .spacing(8.0)
+                        .child(block(32.0, 32.0).test_id("avatar"))
+                        .child(block(120.0, 16.0).test_id("name"))
+                        .test_id("header"),
+                )
+                .child(block(200.0, 40.0).test_id("body"))
+                .padding(12.0)
+                .test_id("card")
+        },
+        PROPOSED,
+    );
+
+    assert_snapshot(&tree, "src/testing/snapshots/card.snap");
+}
@@ -0,0 +1,264 @@
+//! Testing - checking layout without opening a window.
+//!
+//! Tag views with `.test_id()`, lay them out with [`render_for_test`], and
+//! check where they landed with [`assert_bounds`] or against a golden file
+//! with [`assert_snapshot`]. `render_for_test` needs GPUI's test support,
+//! enabled by this crate's `testing` feature:
+//!
+//! ```toml
+//! [dev-dependencies]
+//! allui = { version = "0.1", features = ["testing"] }
+//! ```
+//!
+//! ```rust,ignore
+//! let tree = render_for_test(
+//!     || {
+//!         VStack::new()
+//!             .child(Text::new("Title").test_id("title"))
+//!             .child(Spacer::new())
+//!             .frame_height(200.0)
+//!     },
+//!     (400.0, 300.0),
+//! );
+//! assert_eq!(tree.bounds("title").origin.y, px(50.0));
+//! assert_snapshot(&tree, "tests/snapshots/title.snap");
+//! ```
+//!
+//! `.test_id()` records nothing outside of `render_for_test`, so tagged
+//! views can stay tagged in the app.
+
+use std::cell::RefCell;
+use std::fmt;
+use std::path::Path;
+use std::rc::Rc;
+
+use gpui::{
+    canvas, point, px, size, App, Bounds, Div, Global, IntoElement, ParentElement, Pixels,
+    SharedString, Styled,
+};
+
+use crate::environment::{Environment, EnvironmentValue};
+
+#[cfg(test)]
+mod layout_tests;
+
+/// Where each view tagged with `.test_id()` was laid out, in the order they
+/// were rendered: each view before the tagged views inside it.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LayoutTree {
+    nodes: Vec<LayoutNode>,
+}
+
+/// A view tagged with `.test_id()` and where it was laid out.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LayoutNode {
+    /// The id the view was tagged with.
+    pub id: SharedString,
+    /// How many tagged views the view is inside.
+    pub depth: usize,
+    /// Where the view was laid out, from the top leading corner of the
+    /// proposed space.
+    pub bounds: Bounds<Pixels>,
+}
+
+impl LayoutTree {
+    /// The tagged views, each before the tagged views inside it.
+    pub fn nodes(&self) -> &[LayoutNode] {
+        &self.nodes
+    }
+
+    /// Where the first view tagged `id` was laid out, or `None` if no view
+    /// was.
+    pub fn get(&self, id: &str) -> Option<Bounds<Pixels>> {
+        self.nodes
+            .iter()
+            .find(|node| node.id == id)
+            .map(|node| node.bounds)
+    }
+
+    /// Where the first view tagged `id` was laid out.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no view was tagged `id`.
+    #[track_caller]
+    pub fn bounds(&self, id: &str) -> Bounds<Pixels> {
+        self.get(id)
+            .unwrap_or_else(|| panic!("no view tagged \"{id}\" was laid out in:\n{self}"))
+    }
+}
+
+/// One line per view, indented by how deep it is:
+/// `id (x, y) width x height`.
+impl fmt::Display for LayoutTree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for node in &self.nodes {
+            let Bounds { origin, size } = node.bounds;
+            writeln!(
+                f,
+                "{:indent$}{} ({}, {}) {}x{}",
+                "",
+                node.id,
+                f32::from(origin.x),
+                f32::from(origin.y),
+                f32::from(size.width),
+                f32::from(size.height),
+                indent = node.depth * 2,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Bounds at `x`, `y` of `width` by `height` points, for
+/// [`assert_bounds`].
+pub fn rect(x: f32, y: f32, width: f32, height: f32) -> Bounds<Pixels> {
+    Bounds::new(point(px(x), px(y)), size(px(width), px(height)))
+}
+
+/// Assert that the view tagged `id` was laid out at `expected`, to within
+/// a hundredth of a point.
+///
+/// Layout is rounded to device pixels, half points in tests, so sizes that
+/// split unevenly land on the nearest half point.
+///
+/// # Panics
+///
+/// Panics, printing the whole tree, if it wasn't or no view was tagged
+/// `id`.
+#[track_caller]
+pub fn assert_bounds(tree: &LayoutTree, id: &str, expected: Bounds<Pixels>) {
+    let actual = tree.bounds(id);
+    let close = |a: Pixels, b: Pixels| (f32::from(a) - f32::from(b)).abs() < 0.01;
+    let matches = close(actual.origin.x, expected.origin.x)
+        && close(actual.origin.y, expected.origin.y)
+        && close(actual.size.width, expected.size.width)
+        && close(actual.size.height, expected.size.height);
+    assert!(
+        matches,
+        "\"{id}\" was laid out at {actual:?}, not {expected:?}, in:\n{tree}"
+    );
+}
+
+/// Assert that `tree` matches the golden file at `path`, as text.
+///
+/// The file is written when it doesn't exist yet, or when the
+/// `ALLUI_UPDATE_SNAPSHOTS` environment variable is set, so review the
+/// changes to it like code.
+///
+/// # Panics
+///
+/// Panics, printing both trees, if they differ, or if the file can't be
+/// read or written.
+#[track_caller]
+pub fn assert_snapshot(tree: &LayoutTree, path: impl AsRef<Path>) {
+    let path = path.as_ref();
+    let actual = tree.to_string();
+    if std::env::var_os("ALLUI_UPDATE_SNAPSHOTS").is_some() || !path.exists() {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .unwrap_or_else(|error| panic!("can't create {}: {error}", parent.display()));
+        }
+        std::fs::write(path, &actual)
+            .unwrap_or_else(|error| panic!("can't write {}: {error}", path.display()));
+        return;
+    }
+    let expected = std::fs::read_to_string(path)
+        .unwrap_or_else(|error| panic!("can't read {}: {error}", path.display()));
+    assert!(
+        actual == expected,
+        "layout doesn't match {}; set ALLUI_UPDATE_SNAPSHOTS=1 to accept it.\n\
+         expected:\n{expected}\nactual:\n{actual}",
+        path.display()
+    );
+}
+
+/// Lay out the view `view` builds in `proposed_size` points, without a
+/// window, and return where its tagged views landed.
+///
+/// Like a window's content, the view is centered in the proposed space,
+/// and views that fill their container fill it. Build the view in `view`,
+/// as stacks need the frame they're drawn in.
+#[cfg(any(test, feature = "testing"))]
+pub fn render_for_test<E: IntoElement + 'static>(
+    view: impl FnOnce() -> E,
+    proposed_size: (f32, f32),
+) -> LayoutTree {
+    use gpui::{div, AvailableSpace, TestAppContext};
+
+    let mut cx = TestAppContext::single();
+    let cx = cx.add_empty_window();
+    let recorder = LayoutRecorder::default();
+    cx.update(|_, cx| cx.set_global(recorder.clone()));
+
+    let (width, height) = proposed_size;
+    cx.draw(
+        point(px(0.), px(0.)),
+        size(
+            AvailableSpace::Definite(px(width)),
+            AvailableSpace::Definite(px(height)),
+        ),
+        |_, _| {
+            div()
+                .size_full()
+                .flex()
+                .flex_col()
+                .items_center()
+                .justify_center()
+                .child(view())
+        },
+    );
+    let nodes = recorder.0.take();
+    LayoutTree { nodes }
+}
+
+/// Collects the views tagged with `.test_id()` while [`render_for_test`]
+/// lays them out.
+#[derive(Clone, Default)]
+pub(crate) struct LayoutRecorder(Rc<RefCell<Vec<LayoutNode>>>);
+
+impl Global for LayoutRecorder {}
+
+/// How many tagged views the views inside a tagged view are in.
+#[derive(Clone, Copy)]
+struct TestIdDepth(usize);
+
+/// How deep views tagged inside the view being rendered are, for the
+/// environment of a `.test_id()` while recording.
+pub(crate) fn nested_depth(cx: &App) -> Option<EnvironmentValue> {
+    cx.try_global::<LayoutRecorder>()?;
+    Some(EnvironmentValue::new(TestIdDepth(depth(cx) + 1)))
+}
+
+fn depth(cx: &App) -> usize {
+    Environment::get::<TestIdDepth>(cx).map_or(0, |TestIdDepth(depth)| depth)
+}
+
+/// Record where `child` is laid out under `id` while recording, in
+/// `container`.
+pub(crate) fn record(container: Div, id: SharedString, child: impl IntoElement, cx: &App) -> Div {
+    let Some(recorder) = cx.try_global::<LayoutRecorder>().cloned() else {
+        return container.child(child);
+    };
+    let index = {
+        let mut nodes = recorder.0.borrow_mut();
+        nodes.push(LayoutNode {
+            id,
+            depth: depth(cx),
+            bounds: Bounds::default(),
+        });
+        nodes.len() - 1
+    };
+    container.relative().child(child).child(
+        canvas(
+            move |bounds, _, _| {
+                if let Some(node) = recorder.0.borrow_mut().get_mut(index) {
+                    node.bounds = bounds;
+                }
+            },
+            |_, _, _, _| {},
+        )
+        .absolute()
+        .inset_0(),
+    )
+}
@@ -0,0 +1,428 @@
+//! Transient notifications, like "Saved" or "Copied to clipboard".
+//!
+//! Toasts are queued app-wide by [`ToastCenter::show`], from any handler,
+//! and drawn by a [`ToastCenter`] placed once in the window's root view.
+//! Each toast hides itself once its time is up, counting only while the
+//! pointer isn't over it.
+
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+use gpui::{
+    anchored, deferred, div, ease_out_quint, point, px, Animation, AnimationExt, App, FontWeight,
+    Global, Hsla, InteractiveElement, IntoElement, ParentElement, RenderOnce, SharedString,
+    StatefulInteractiveElement, Styled, Window,
+};
+use gpui_component::{ActiveTheme, Icon, IconName};
+
+use crate::alignment::{Alignment, VerticalAlignment};
+use crate::style::Color;
+
+/// Called when a toast's action button is clicked.
+type ToastAction = Rc<dyn Fn(&mut Window, &mut App)>;
+
+/// How long a toast shows by default.
+const DEFAULT_DURATION: Duration = Duration::from_secs(3);
+
+/// How often a paused toast checks whether it can resume counting down.
+const PAUSED_POLL: Duration = Duration::from_millis(200);
+
+/// How long a toast takes to fade in.
+const APPEAR_DURATION: Duration = Duration::from_millis(200);
+
+/// The width of a toast, in points.
+const TOAST_WIDTH: f32 = 320.0;
+
+/// What a toast reports, which picks its icon and color.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ToastStyle {
+    /// Neutral information.
+    #[default]
+    Info,
+    /// Something finished as asked.
+    Success,
+    /// Something needs attention.
+    Warning,
+    /// Something failed.
+    Error,
+}
+
+impl ToastStyle {
+    fn icon(self) -> IconName {
+        match self {
+            Self::Info => IconName::Info,
+            Self::Success => IconName::CircleCheck,
+            Self::Warning => IconName::TriangleAlert,
+            Self::Error => IconName::CircleX,
+        }
+    }
+
+    fn color(self, cx: &App) -> Hsla {
+        let theme = cx.theme();
+        match self {
+            Self::Info => theme.info,
+            Self::Success => theme.success,
+            Self::Warning => theme.warning,
+            Self::Error => theme.danger,
+        }
+    }
+}
+
+/// A transient notification, shown with [`ToastCenter::show`].
+///
+/// # Example
+///
+/// ```rust,ignore
+/// ToastCenter::show(
+///     cx,
+///     Toast::new("Message deleted")
+///         .style(ToastStyle::Success)
+///         .duration_secs(2.5)
+///         .action("Undo", |_, cx| restore_message(cx)),
+/// );
+/// ```
+#[derive(Clone)]
+pub struct Toast {
+    message: SharedString,
+    style: ToastStyle,
+    /// How long the toast shows, or `None` to show it until dismissed.
+    duration: Option<Duration>,
+    action: Option<(SharedString, ToastAction)>,
+}
+
+impl Toast {
+    /// Create an info toast showing `message` for three seconds.
+    pub fn new(message: impl Into<SharedString>) -> Self {
+        Self {
+            message: message.into(),
+            style: ToastStyle::default(),
+            duration: Some(DEFAULT_DURATION),
+            action: None,
+        }
+    }
+
+    /// Set what the toast reports.
+    pub fn style(mut self, style: ToastStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set how long the toast shows, in seconds.
+    ///
+    /// A non-finite or too large value, such as `f32::INFINITY`, shows the
+    /// toast until it is dismissed or its action is used.
+    pub fn duration_secs(mut self, secs: f32) -> Self {
+        self.duration = if secs.is_finite() {
+            Duration::try_from_secs_f32(secs.max(0.0)).ok()
+        } else {
+            None
+        };
+        self
+    }
+
+    /// Add a button that calls `handler` and hides the toast.
+    pub fn action(
+        mut self,
+        label: impl Into<SharedString>,
+        handler: impl Fn(&mut Window, &mut App) + 'static,
+    ) -> Self {
+        self.action = Some((label.into(), Rc::new(handler)));
+        self
+    }
+}
+
+/// Counts down a toast's time, pausing while it is hovered.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct ToastTimer {
+    /// When the toast hides, unless paused.
+    deadline: Option<Instant>,
+    /// The time left when paused.
+    remaining: Duration,
+}
+
+impl ToastTimer {
+    /// Start counting down `duration`, or `None` if the toast never hides
+    /// by itself.
+    fn start(duration: Option<Duration>, now: Instant) -> Option<Self> {
+        let duration = duration?;
+        Some(Self {
+            deadline: Some(now.checked_add(duration)?),
+            remaining: duration,
+        })
+    }
+
+    fn pause(&mut self, now: Instant) {
+        if let Some(deadline) = self.deadline.take() {
+            self.remaining = deadline.saturating_duration_since(now);
+        }
+    }
+
+    fn resume(&mut self, now: Instant) {
+        if self.deadline.is_none() {
+            self.deadline = now.checked_add(self.remaining);
+        }
+    }
+
+    /// How long until the toast should be checked again, or `None` once its
+    /// time is up.
+    fn wait(&self, now: Instant) -> Option<Duration> {
+        match self.deadline {
+            Some(deadline) if deadline <= now => None,
+            Some(deadline) => Some(deadline - now),
+            None => Some(PAUSED_POLL),
+        }
+    }
+}
+
+struct ShownToast {
+    id: u64,
+    toast: Toast,
+    /// The countdown, or `None` for a toast shown until dismissed.
+    timer: Option<ToastTimer>,
+}
+
+/// The toasts showing, app-wide.
+#[derive(Default)]
+struct ToastQueue {
+    toasts: Vec<ShownToast>,
+    next_id: u64,
+}
+
+impl Global for ToastQueue {}
+
+impl ToastQueue {
+    fn update_timer(cx: &mut App, id: u64, update: impl FnOnce(&mut ToastTimer, Instant)) {
+        let queue = cx.default_global::<ToastQueue>();
+        if let Some(shown) = queue.toasts.iter_mut().find(|shown| shown.id == id) {
+            if let Some(timer) = &mut shown.timer {
+                update(timer, Instant::now());
+            }
+        }
+    }
+}
+
+/// Draws the toasts shown with [`ToastCenter::show`], stacked in a corner
+/// of the window.
+///
+/// Place one in your root view, over everything else. Toasts draw above
+/// the window's content without blocking clicks beside them.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// // In the root view's render
+/// div()
+///     .size_full()
+///     .child(content)
+///     .child(ToastCenter::new().corner(Alignment::top_trailing()))
+///
+/// // In any handler
+/// ToastCenter::show(cx, Toast::new("Saved").style(ToastStyle::Success));
+/// ```
+#[derive(IntoElement)]
+pub struct ToastCenter {
+    corner: Alignment,
+}
+
+impl ToastCenter {
+    /// Create a toast center stacking toasts in the bottom trailing corner.
+    pub fn new() -> Self {
+        Self {
+            corner: Alignment::bottom_trailing(),
+        }
+    }
+
+    /// Set the corner, or edge, toasts stack in.
+    pub fn corner(mut self, corner: Alignment) -> Self {
+        self.corner = corner;
+        self
+    }
+
+    /// Show `toast` until its time is up.
+    pub fn show(cx: &mut App, toast: Toast) {
+        let queue = cx.default_global::<ToastQueue>();
+        let id = queue.next_id;
+        queue.next_id += 1;
+        let timer = ToastTimer::start(toast.duration, Instant::now());
+        queue.toasts.push(ShownToast { id, timer, toast });
+        cx.refresh_windows();
+        if timer.is_none() {
+            return;
+        }
+
+        cx.spawn(async move |cx| loop {
+            let wait = cx
+                .update(|cx| {
+                    let queue = cx.try_global::<ToastQueue>()?;
+                    let shown = queue.toasts.iter().find(|shown| shown.id == id)?;
+                    shown.timer.map(|timer| timer.wait(Instant::now()))
+                })
+                .ok()
+                .flatten();
+            match wait {
+                // Gone already, or the app quit
+                None => break,
+                Some(Some(wait)) => cx.background_executor().timer(wait).await,
+                Some(None) => {
+                    cx.update(|cx| Self::dismiss(cx, id)).ok();
+                    break;
+                }
+            }
+        })
+        .detach();
+    }
+
+    /// Hide every toast.
+    pub fn dismiss_all(cx: &mut App) {
+        cx.default_global::<ToastQueue>().toasts.clear();
+        cx.refresh_windows();
+    }
+
+    fn dismiss(cx: &mut App, id: u64) {
+        cx.default_global::<ToastQueue>()
+            .toasts
+            .retain(|shown| shown.id != id);
+        cx.refresh_windows();
+    }
+}
+
+impl Default for ToastCenter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RenderOnce for ToastCenter {
+    fn render(self, window: &mut Window, cx: &mut App) -> impl IntoElement {
+        let Some(queue) = cx
+            .try_global::<ToastQueue>()
+            .filter(|q| !q.toasts.is_empty())
+        else {
+            return div();
+        };
+        let mut toasts: Vec<_> = queue
+            .toasts
+            .iter()
+            .map(|shown| toast_view(shown.id, &shown.toast, cx))
+            .collect();
+        // The newest toast sits nearest the corner
+        let at_top = self.corner.vertical == VerticalAlignment::Top;
+        if at_top {
+            toasts.reverse();
+        }
+
+        let viewport = window.viewport_size();
+        let mut stack = div()
+            .flex()
+            .flex_col()
+            .gap(px(8.0))
+            .p(px(16.0))
+            .w(viewport.width)
+            .h(viewport.height)
+            .children(toasts);
+        stack = self.corner.horizontal.apply_as_items(stack);
+        stack = match self.corner.vertical {
+            VerticalAlignment::Top => stack.justify_start(),
+            VerticalAlignment::Bottom => stack.justify_end(),
+            _ => stack.justify_center(),
+        };
+
+        div().child(
+            deferred(anchored().position(point(px(0.0), px(0.0))).child(stack)).with_priority(2),
+        )
+    }
+}
+
+/// A toast: its style's icon, the message and the action button, fading in
+/// as it appears.
+fn toast_view(id: u64, toast: &Toast, cx: &App) -> impl IntoElement {
+    let accent = toast.style.color(cx);
+    let action = toast.action.clone().map(|(label, handler)| {
+        div()
+            .id("toast-action")
+            .flex_shrink_0()
+            .cursor_pointer()
+            .font_weight(FontWeight::SEMIBOLD)
+            .text_color(Color::accent().resolve_in(cx))
+            .child(label)
+            .on_click(move |_, window, cx| {
+                ToastCenter::dismiss(cx, id);
+                handler(window, cx);
+            })
+    });
+
+    div()
+        .id(("toast", id as usize))
+        .occlude()
+        .flex()
+        .flex_row()
+        .items_center()
+        .gap(px(10.0))
+        .w(px(TOAST_WIDTH))
+        .px(px(14.0))
+        .py(px(10.0))
+        .rounded(px(10.0))
+        .shadow_lg()
+        .border_1()
+        .border_color(Color::separator().resolve_in(cx))
+        .bg(Color::secondary_system_background().resolve_in(cx))
+        .child(
+            Icon::new(toast.style.icon())
+                .flex_shrink_0()
+                .text_color(accent),
+        )
+        .child(div().flex_1().min_w_0().child(toast.message.clone()))
+        .children(action)
+        .on_hover(move |hovered, _, cx| {
+            ToastQueue::update_timer(cx, id, |timer, now| {
+                if *hovered {
+                    timer.pause(now);
+                } else {
+                    timer.resume(now);
+                }
+            })
+        })
+        .with_animation(
+            ("toast-appear", id as usize),
+            Animation::new(APPEAR_DURATION).with_easing(ease_out_quint()),
+            |toast, delta| toast.opacity(delta),
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hovering_pauses_the_countdown() {
+        let start = Instant::now();
+        let mut timer = ToastTimer::start(Some(Duration::from_secs(3)), start).unwrap();
+        assert_eq!(timer.wait(start), Some(Duration::from_secs(3)));
+
+        // Paused a second in, two seconds are left however long it's hovered
+        timer.pause(start + Duration::from_secs(1));
+        assert_eq!(
+            timer.wait(start + Duration::from_secs(60)),
+            Some(PAUSED_POLL)
+        );
+        timer.resume(start + Duration::from_secs(60));
+        assert_eq!(
+            timer.wait(start + Duration::from_secs(61)),
+            Some(Duration::from_secs(1))
+        );
+        assert_eq!(timer.wait(start + Duration::from_secs(62)), None);
+    }
+
+    #[test]
+    fn non_finite_durations_never_hide() {
+        for secs in [f32::INFINITY, f32::NAN, f32::MAX] {
+            let toast = Toast::new("Pinned").duration_secs(secs);
+            assert_eq!(toast.duration, None, "{secs}");
+            assert_eq!(ToastTimer::start(toast.duration, Instant::now()), None);
+        }
+        assert_eq!(
+            Toast::new("Gone").duration_secs(-1.0).duration,
+            Some(Duration::ZERO)
+        );
+    }
+}
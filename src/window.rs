@@ -0,0 +1,340 @@
+//! Window - opening an app's windows and the chrome around them.
+//!
+//! [`open`] opens a window showing a view, set up the way Allui needs: the
+//! view sits in gpui-component's `Root`, so sheets, dialogs and
+//! notifications work in it, and the window re-renders when the theme
+//! changes.
+//!
+//! ```rust,ignore
+//! Button::new("Preferences…", || {}).on_click_with(|_, _, cx| {
+//!     window::open(cx, WindowConfig::dialog("Preferences"), |_, cx| {
+//!         cx.new(|_| Preferences::default())
+//!     })
+//!     .ok();
+//! })
+//! ```
+//!
+//! A window with a transparent titlebar draws its content under the
+//! titlebar and the traffic lights. Describe the titlebar with a
+//! [`WindowChrome`] and open the window with it, and views inside can keep
+//! clear of it:
+//!
+//! ```rust,ignore
+//! let config = WindowConfig::panel("Inspector").chrome(WindowChrome::transparent_titlebar());
+//! window::open(cx, config, |_, cx| cx.new(|_| Inspector))?;
+//!
+//! // In the inspector's render
+//! VStack::new()
+//!     .child(
+//!         header
+//!             .safe_area_padding()
+//!             .background(Color::secondary_system_background())
+//!             .ignores_safe_area(EdgeSet::top()),
+//!     )
+//!     .child(content)
+//!     .safe_area_padding()
+//! ```
+//!
+//! Windows opened another way set the chrome on their content with
+//! `.window_chrome()`.
+
+use gpui::{
+    div, point, px, size, AnyView, App, AppContext, Bounds, Context, Entity, FocusHandle,
+    InteractiveElement, IntoElement, ParentElement, Render, SharedString, Styled, TitlebarOptions,
+    Window, WindowBounds, WindowHandle, WindowKind, WindowOptions,
+};
+use gpui_component::theme::Theme;
+use gpui_component::Root;
+
+use crate::debug::DebugOverlay;
+use crate::environment::{EnvironmentScope, SafeAreaInsets};
+
+/// The titlebar of a window, and the space it covers over the content.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct WindowChrome {
+    transparent_titlebar: bool,
+    titlebar_height: f32,
+    traffic_light_position: (f32, f32),
+}
+
+impl Default for WindowChrome {
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+impl WindowChrome {
+    /// The platform's opaque titlebar, which covers none of the content.
+    pub fn standard() -> Self {
+        Self {
+            transparent_titlebar: false,
+            titlebar_height: 34.0,
+            traffic_light_position: (9.0, 9.0),
+        }
+    }
+
+    /// A transparent titlebar the content extends under, on macOS and
+    /// Windows. Other platforms keep their own titlebar, so nothing is
+    /// covered there.
+    pub fn transparent_titlebar() -> Self {
+        Self {
+            transparent_titlebar: true,
+            ..Self::standard()
+        }
+    }
+
+    /// Set the height of the titlebar, the top inset of the content.
+    pub fn titlebar_height(mut self, height: f32) -> Self {
+        self.titlebar_height = height.max(0.0);
+        self
+    }
+
+    /// Set where the traffic lights sit on macOS, from the window's top
+    /// leading corner.
+    pub fn traffic_light_position(mut self, x: f32, y: f32) -> Self {
+        self.traffic_light_position = (x, y);
+        self
+    }
+
+    /// The titlebar options to open the window with.
+    pub fn titlebar(&self, title: impl Into<SharedString>) -> TitlebarOptions {
+        let (x, y) = self.traffic_light_position;
+        TitlebarOptions {
+            title: Some(title.into()),
+            appears_transparent: self.transparent_titlebar,
+            traffic_light_position: self.transparent_titlebar.then(|| point(px(x), px(y))),
+        }
+    }
+
+    /// The space the titlebar covers over the content of `window`.
+    ///
+    /// Full screen windows hide their titlebar until the pointer reaches
+    /// the top of the screen, so they have no insets.
+    pub fn safe_area_insets(&self, window: &Window) -> SafeAreaInsets {
+        let covers_content = self.transparent_titlebar
+            && cfg!(any(target_os = "macos", target_os = "windows"))
+            && !window.is_fullscreen();
+        if !covers_content {
+            return SafeAreaInsets::default();
+        }
+        // Three 12pt buttons, 8pt apart, with the same margin on both sides
+        let (x, _) = self.traffic_light_position;
+        let window_controls = if cfg!(target_os = "macos") {
+            x + 3.0 * 12.0 + 2.0 * 8.0 + x
+        } else {
+            0.0
+        };
+        SafeAreaInsets {
+            top: self.titlebar_height,
+            window_controls,
+            ..SafeAreaInsets::default()
+        }
+    }
+}
+
+/// How to open a window with [`open`]: its title, size and chrome.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// WindowConfig::new("Library").size(900.0, 600.0).min_size(600.0, 400.0)
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct WindowConfig {
+    title: SharedString,
+    size: (f32, f32),
+    min_size: Option<(f32, f32)>,
+    resizable: bool,
+    minimizable: bool,
+    kind: WindowKind,
+    chrome: WindowChrome,
+}
+
+impl WindowConfig {
+    /// A resizable 800 by 600 point window titled `title`.
+    pub fn new(title: impl Into<SharedString>) -> Self {
+        Self {
+            title: title.into(),
+            size: (800.0, 600.0),
+            min_size: None,
+            resizable: true,
+            minimizable: true,
+            kind: WindowKind::Normal,
+            chrome: WindowChrome::standard(),
+        }
+    }
+
+    /// A narrow window that floats over the app's other windows, for
+    /// inspectors and palettes.
+    pub fn panel(title: impl Into<SharedString>) -> Self {
+        Self {
+            size: (320.0, 480.0),
+            minimizable: false,
+            kind: WindowKind::Floating,
+            ..Self::new(title)
+        }
+    }
+
+    /// A small window of a fixed size, for preferences and other forms.
+    pub fn dialog(title: impl Into<SharedString>) -> Self {
+        Self {
+            size: (480.0, 360.0),
+            resizable: false,
+            minimizable: false,
+            ..Self::new(title)
+        }
+    }
+
+    /// Set the window's size in points.
+    pub fn size(mut self, width: f32, height: f32) -> Self {
+        self.size = (width, height);
+        self
+    }
+
+    /// Keep the window at least `width` by `height` points when resized.
+    pub fn min_size(mut self, width: f32, height: f32) -> Self {
+        self.min_size = Some((width, height));
+        self
+    }
+
+    /// Set whether the window can be resized.
+    pub fn resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+
+    /// Set whether the window can be minimized.
+    pub fn minimizable(mut self, minimizable: bool) -> Self {
+        self.minimizable = minimizable;
+        self
+    }
+
+    /// Set the window's titlebar, whose insets views inside keep clear of
+    /// with `.safe_area_padding()`.
+    pub fn chrome(mut self, chrome: WindowChrome) -> Self {
+        self.chrome = chrome;
+        self
+    }
+
+    /// The options GPUI opens the window with, centered on the main
+    /// display.
+    fn options(&self, cx: &App) -> WindowOptions {
+        let (width, height) = self.size;
+        let bounds = Bounds::centered(None, size(px(width), px(height)), cx);
+        WindowOptions {
+            window_bounds: Some(WindowBounds::Windowed(bounds)),
+            titlebar: Some(self.chrome.titlebar(self.title.clone())),
+            kind: self.kind,
+            is_resizable: self.resizable,
+            is_minimizable: self.minimizable,
+            window_min_size: self
+                .min_size
+                .map(|(width, height)| size(px(width), px(height))),
+            ..WindowOptions::default()
+        }
+    }
+}
+
+/// Open a window showing the view `build` makes, configured by `config`.
+///
+/// The view is wrapped in gpui-component's `Root`, takes focus so key
+/// bindings work in the window straight away, and re-renders when the
+/// theme changes, such as from another window. It draws the
+/// [`DebugOverlay`] while that's on. Call
+/// `gpui_component::init` before opening windows.
+pub fn open<V: Render>(
+    cx: &mut App,
+    config: WindowConfig,
+    build: impl FnOnce(&mut Window, &mut App) -> Entity<V>,
+) -> gpui::Result<WindowHandle<Root>> {
+    let options = config.options(cx);
+    let chrome = config.chrome;
+    cx.open_window(options, move |window, cx| {
+        let content = build(window, cx);
+        let focus_handle = cx.focus_handle();
+        focus_handle.focus(window);
+        // Theme changes only refresh the window they're made from
+        window
+            .observe_global::<Theme>(cx, |window, _| window.refresh())
+            .detach();
+        let content = cx.new(|_| WindowContent {
+            content: content.into(),
+            chrome,
+            focus_handle,
+        });
+        cx.new(|cx| Root::new(content, window, cx))
+    })
+}
+
+/// Close the active window, such as from a button in it.
+///
+/// The window closes once the handler calling this returns.
+pub fn close_window(cx: &mut App) {
+    if let Some(window) = cx.active_window() {
+        cx.defer(move |cx| {
+            window
+                .update(cx, |_, window, _| window.remove_window())
+                .ok();
+        });
+    }
+}
+
+/// The view of a window opened with [`open`], around the app's view.
+struct WindowContent {
+    content: AnyView,
+    chrome: WindowChrome,
+    /// Focused when the window opens, so actions dispatch in it before the
+    /// app's view focuses anything.
+    focus_handle: FocusHandle,
+}
+
+impl Render for WindowContent {
+    fn render(&mut self, window: &mut Window, _cx: &mut Context<Self>) -> impl IntoElement {
+        let content = div()
+            .track_focus(&self.focus_handle)
+            .size_full()
+            .child(self.content.clone())
+            .child(DebugOverlay::new());
+        EnvironmentScope::new(content).safe_area(Some((
+            self.chrome.safe_area_insets(window),
+            SafeAreaInsets::default(),
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use gpui::{Empty, TestAppContext};
+
+    use super::*;
+
+    #[test]
+    fn dialogs_open_at_a_fixed_size() {
+        let cx = TestAppContext::single();
+        let options = cx.update(|cx| WindowConfig::dialog("Preferences").options(cx));
+
+        assert!(!options.is_resizable);
+        assert!(!options.is_minimizable);
+        assert_eq!(
+            options.window_bounds.map(|bounds| bounds.get_bounds().size),
+            Some(size(px(480.), px(360.)))
+        );
+        assert_eq!(
+            options.titlebar.and_then(|titlebar| titlebar.title),
+            Some("Preferences".into())
+        );
+    }
+
+    #[test]
+    fn close_window_closes_the_active_window() {
+        let mut cx = TestAppContext::single();
+        let window = cx.add_window(|_, _| Empty);
+        window
+            .update(&mut cx, |_, window, _| window.activate_window())
+            .unwrap();
+
+        cx.update(close_window);
+        cx.run_until_parked();
+        assert!(cx.windows().is_empty());
+    }
+}